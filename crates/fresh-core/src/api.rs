@@ -257,6 +257,151 @@ fn default_action_count() -> u32 {
     1
 }
 
+/// A location to load into the quickfix list
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(deny_unknown_fields)]
+#[ts(export)]
+pub struct QuickfixEntry {
+    /// File path (absolute, or relative to the working directory)
+    pub file: String,
+    /// Line number (1-indexed)
+    pub line: u32,
+    /// Column number (1-indexed, default 1)
+    #[serde(default = "default_quickfix_column")]
+    pub column: u32,
+    /// Short description shown when the entry is visited
+    #[serde(default)]
+    pub text: String,
+}
+
+fn default_quickfix_column() -> u32 {
+    1
+}
+
+/// Side of the status bar a plugin item is shown on
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "lowercase")]
+#[ts(export)]
+pub enum StatusBarAlignment {
+    /// After the built-in file and cursor segments
+    #[default]
+    Left,
+    /// Before the built-in indicators on the right
+    Right,
+}
+
+/// Options for a plugin status bar item
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[serde(deny_unknown_fields)]
+#[ts(export)]
+pub struct StatusBarItemOptions {
+    /// Side of the status bar (default "left")
+    #[serde(default)]
+    #[ts(optional)]
+    pub alignment: Option<StatusBarAlignment>,
+    /// Items with higher priority are shown first on their side (default 0)
+    #[serde(default)]
+    #[ts(optional)]
+    pub priority: Option<i32>,
+}
+
+/// What to do with the range matched by a plugin text object
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "lowercase")]
+#[ts(export)]
+pub enum TextObjectOperation {
+    /// Select the range, keeping the cursor at its end
+    Select,
+    /// Delete the range
+    Delete,
+    /// Grow the selection to the range, as part of expand-selection
+    Expand,
+}
+
+/// Cursor context passed to a plugin text object matcher
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct TextObjectQuery {
+    /// Buffer the cursor is in
+    #[ts(type = "number")]
+    pub buffer_id: BufferId,
+    /// Byte offset of the primary cursor
+    #[ts(type = "number")]
+    pub position: usize,
+    /// Selection anchor of the primary cursor, if any
+    #[ts(type = "number | null")]
+    pub anchor: Option<usize>,
+    /// Operation the range will be used for
+    pub operation: TextObjectOperation,
+}
+
+/// A single-key binding as listed by `getKeybindings`
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct KeybindingInfo {
+    /// Key in the same form `setKeybinding` accepts (e.g. "C-s", "M-x", "F7")
+    pub key: String,
+    /// Action name (e.g. "move_line_end") or plugin command name
+    pub command: String,
+    /// Context the binding applies in (e.g. "normal", "prompt")
+    pub context: String,
+}
+
+/// Byte range returned by a plugin text object matcher
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct TextObjectRange {
+    /// Start byte offset (inclusive)
+    #[ts(type = "number")]
+    pub start: usize,
+    /// End byte offset (exclusive)
+    #[ts(type = "number")]
+    pub end: usize,
+}
+
+/// One replacement in an `applyEdits` batch
+///
+/// Offsets refer to the buffer content before any edit in the batch is applied.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(deny_unknown_fields)]
+#[ts(export)]
+pub struct BufferEdit {
+    /// Start byte offset (inclusive)
+    #[ts(type = "number")]
+    pub start: usize,
+    /// End byte offset (exclusive); equal to `start` for a pure insertion
+    #[ts(type = "number")]
+    pub end: usize,
+    /// Replacement text (empty for a pure deletion)
+    pub text: String,
+}
+
+/// Sort a batch of edits by start offset and reject malformed or overlapping ranges
+///
+/// Edits starting at the same offset are ordered by end offset, so an insertion
+/// comes before a replacement starting where it is inserted; insertions at the
+/// same offset keep their original order. Ranges that merely touch (one ends
+/// where the next starts) are not considered overlapping.
+pub fn sort_buffer_edits(mut edits: Vec<BufferEdit>) -> Result<Vec<BufferEdit>, String> {
+    if let Some(edit) = edits.iter().find(|e| e.start > e.end) {
+        return Err(format!(
+            "invalid edit range {}..{}: start is after end",
+            edit.start, edit.end
+        ));
+    }
+    edits.sort_by_key(|e| (e.start, e.end));
+    for pair in edits.windows(2) {
+        if pair[0].end > pair[1].start {
+            return Err(format!(
+                "edit ranges {}..{} and {}..{} overlap",
+                pair[0].start, pair[0].end, pair[1].start, pair[1].end
+            ));
+        }
+    }
+    Ok(edits)
+}
+
 /// Information about a buffer
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export)]
@@ -345,6 +490,23 @@ pub struct ViewportInfo {
     pub height: u16,
 }
 
+/// Detailed position of the primary cursor in the active buffer
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export, rename_all = "camelCase")]
+pub struct CursorDetails {
+    /// Line number of the cursor (0-indexed)
+    pub line: usize,
+    /// Byte column of the cursor within its line (0-indexed)
+    pub column: usize,
+    /// Byte offset of the cursor
+    pub byte: usize,
+    /// Total size of the buffer in bytes
+    pub total_bytes: usize,
+    /// Total number of lines, if known (large files may not have a line count)
+    pub total_lines: Option<usize>,
+}
+
 /// Layout hints supplied by plugins (e.g., Compose mode)
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[serde(rename_all = "camelCase")]
@@ -557,6 +719,8 @@ pub struct EditorStateSnapshot {
     pub primary_cursor: Option<CursorInfo>,
     /// All cursor positions for the active buffer
     pub all_cursors: Vec<CursorInfo>,
+    /// Line, column and file size details for the primary cursor
+    pub cursor_details: Option<CursorDetails>,
     /// Viewport information for the active buffer
     pub viewport: Option<ViewportInfo>,
     /// Cursor positions per buffer (for buffers other than active)
@@ -585,6 +749,10 @@ pub struct EditorStateSnapshot {
     /// Global editor mode for modal editing (e.g., "vi-normal", "vi-insert")
     /// When set, this mode's keybindings take precedence over normal key handling
     pub editor_mode: Option<String>,
+    /// Persistent plugin storage: plugin name -> key -> value
+    /// Seeded from disk at startup and updated by `setStorage` as it is called
+    #[ts(type = "any")]
+    pub plugin_storage: HashMap<String, serde_json::Map<String, JsonValue>>,
 }
 
 impl EditorStateSnapshot {
@@ -596,6 +764,7 @@ impl EditorStateSnapshot {
             buffer_saved_diffs: HashMap::new(),
             primary_cursor: None,
             all_cursors: Vec::new(),
+            cursor_details: None,
             viewport: None,
             buffer_cursor_positions: HashMap::new(),
             buffer_text_properties: HashMap::new(),
@@ -606,6 +775,7 @@ impl EditorStateSnapshot {
             config: serde_json::Value::Null,
             user_config: serde_json::Value::Null,
             editor_mode: None,
+            plugin_storage: HashMap::new(),
         }
     }
 }
@@ -616,6 +786,14 @@ impl Default for EditorStateSnapshot {
     }
 }
 
+/// Largest serialized size, in bytes, of one plugin's storage
+pub const MAX_PLUGIN_STORAGE_BYTES: usize = 256 * 1024;
+
+/// Whether a plugin's storage stays within [`MAX_PLUGIN_STORAGE_BYTES`]
+pub fn plugin_storage_fits(storage: &serde_json::Map<String, JsonValue>) -> bool {
+    serde_json::to_vec(storage).is_ok_and(|bytes| bytes.len() <= MAX_PLUGIN_STORAGE_BYTES)
+}
+
 /// Position for inserting menu items or menus
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export)]
@@ -647,6 +825,13 @@ pub enum PluginCommand {
         range: Range<usize>,
     },
 
+    /// Apply several replacements to a buffer as a single undoable step
+    ApplyEdits {
+        buffer_id: BufferId,
+        /// Edits sorted by start offset, non-overlapping, in original buffer offsets
+        edits: Vec<BufferEdit>,
+    },
+
     /// Add an overlay to a buffer, returns handle via response channel
     AddOverlay {
         buffer_id: BufferId,
@@ -763,6 +948,25 @@ pub enum PluginCommand {
         namespace: OverlayNamespace,
     },
 
+    /// Add a plugin decoration over a byte range
+    HighlightRange {
+        buffer_id: BufferId,
+        plugin_name: String,
+        /// Group the decoration belongs to, for clearing it later
+        group: String,
+        range: Range<usize>,
+        /// Predefined decoration kind ("error", "warning", "info", "hint",
+        /// "highlight", "emphasis") or a theme color key ("diagnostic.error_bg")
+        style: String,
+    },
+
+    /// Remove a plugin's decorations in one group
+    ClearHighlights {
+        buffer_id: BufferId,
+        plugin_name: String,
+        group: String,
+    },
+
     /// Remove all overlays that overlap with a byte range
     /// Used for targeted invalidation when content in a range changes
     ClearOverlaysInRange {
@@ -863,6 +1067,67 @@ pub enum PluginCommand {
         namespace: String,
     },
 
+    /// Replace the quickfix list with the given locations
+    SetQuickfixList {
+        /// Locations in the order they should be visited
+        entries: Vec<QuickfixEntry>,
+    },
+
+    /// Add or update a plugin status bar item
+    SetStatusBarItem {
+        /// Item identifier, used to update or remove it later
+        id: String,
+        /// Text to display
+        text: String,
+        options: StatusBarItemOptions,
+    },
+
+    /// Remove a plugin status bar item
+    RemoveStatusBarItem { id: String },
+
+    /// Store a value in a plugin's persistent storage
+    SetPluginStorage {
+        plugin_name: String,
+        key: String,
+        /// New value; null removes the key
+        #[ts(type = "any")]
+        value: JsonValue,
+    },
+
+    /// Register a plugin text object, exposed as select and delete commands
+    RegisterTextObject {
+        /// Text object name, used in command names and action arguments
+        name: String,
+        plugin_name: String,
+    },
+
+    /// Apply a range matched by a plugin text object to the primary cursor
+    ApplyTextObject {
+        buffer_id: BufferId,
+        range: Range<usize>,
+        operation: TextObjectOperation,
+    },
+
+    /// Register a plugin handler that provides the content of matching paths
+    RegisterOpenHandler {
+        /// Regular expression matched against the path as given, then the resolved path
+        pattern: String,
+        plugin_name: String,
+    },
+
+    /// Fill a buffer opened through a plugin open handler
+    SetOpenedBufferContent {
+        buffer_id: BufferId,
+        content: String,
+    },
+
+    /// A plugin open handler failed or did not return a string; the editor
+    /// closes the placeholder buffer and reports the error
+    OpenHandlerFailed { buffer_id: BufferId, error: String },
+
+    /// A plugin was unloaded; the editor drops state the plugin registered
+    PluginUnloaded { plugin_name: String },
+
     /// Open a file at a specific line and column
     /// Line and column are 1-indexed to match git grep output
     OpenFileAtLocation {
@@ -1114,6 +1379,15 @@ pub enum PluginCommand {
         request_id: u64,
     },
 
+    /// Send an LSP request to the server for the active buffer's language
+    /// and return the raw JSON response
+    LspRequest {
+        request_id: u64,
+        method: String,
+        #[ts(type = "any")]
+        params: Option<JsonValue>,
+    },
+
     /// Set the internal clipboard content
     SetClipboard { text: String },
 
@@ -1172,6 +1446,32 @@ pub enum PluginCommand {
         request_id: u64,
     },
 
+    /// List the single-key bindings of a context (async)
+    GetKeybindings {
+        /// Context name (e.g. "normal", "prompt", "global")
+        context: String,
+        /// Request ID for async response
+        request_id: u64,
+    },
+
+    /// Bind a key in a context, replacing any existing binding for that key
+    SetKeybinding {
+        /// Key string (e.g. "C-k", "M-x", "F7")
+        key: String,
+        /// Action name or plugin command name
+        command: String,
+        /// Context name (e.g. "normal", "prompt", "global")
+        context: String,
+    },
+
+    /// Unbind a key in a context, including any default binding
+    RemoveKeybinding {
+        /// Key string (e.g. "C-k", "M-x", "F7")
+        key: String,
+        /// Context name (e.g. "normal", "prompt", "global")
+        context: String,
+    },
+
     /// Set the global editor mode (for modal editing like vi mode)
     /// When set, the mode's keybindings take precedence over normal editing
     SetEditorMode {
@@ -1582,6 +1882,36 @@ mod fromjs_impls {
         }
     }
 
+    impl<'js> FromJs<'js> for QuickfixEntry {
+        fn from_js(_ctx: &Ctx<'js>, value: Value<'js>) -> rquickjs::Result<Self> {
+            rquickjs_serde::from_value(value).map_err(|e| rquickjs::Error::FromJs {
+                from: "object",
+                to: "QuickfixEntry",
+                message: Some(e.to_string()),
+            })
+        }
+    }
+
+    impl<'js> FromJs<'js> for BufferEdit {
+        fn from_js(_ctx: &Ctx<'js>, value: Value<'js>) -> rquickjs::Result<Self> {
+            rquickjs_serde::from_value(value).map_err(|e| rquickjs::Error::FromJs {
+                from: "object",
+                to: "BufferEdit",
+                message: Some(e.to_string()),
+            })
+        }
+    }
+
+    impl<'js> FromJs<'js> for StatusBarItemOptions {
+        fn from_js(_ctx: &Ctx<'js>, value: Value<'js>) -> rquickjs::Result<Self> {
+            rquickjs_serde::from_value(value).map_err(|e| rquickjs::Error::FromJs {
+                from: "object",
+                to: "StatusBarItemOptions",
+                message: Some(e.to_string()),
+            })
+        }
+    }
+
     impl<'js> FromJs<'js> for ActionPopupAction {
         fn from_js(_ctx: &Ctx<'js>, value: Value<'js>) -> rquickjs::Result<Self> {
             rquickjs_serde::from_value(value).map_err(|e| rquickjs::Error::FromJs {
@@ -2439,4 +2769,43 @@ mod tests {
             err
         );
     }
+
+    #[test]
+    fn test_sort_buffer_edits_orders_and_rejects_overlaps() {
+        let edit = |start, end, text: &str| BufferEdit {
+            start,
+            end,
+            text: text.to_string(),
+        };
+
+        let sorted =
+            sort_buffer_edits(vec![edit(8, 9, "c"), edit(0, 2, "a"), edit(2, 4, "b")]).unwrap();
+        let starts: Vec<usize> = sorted.iter().map(|e| e.start).collect();
+        assert_eq!(starts, vec![0, 2, 8]);
+
+        assert!(sort_buffer_edits(vec![edit(0, 3, ""), edit(2, 4, "x")]).is_err());
+        assert!(sort_buffer_edits(vec![edit(5, 1, "x")]).is_err());
+        assert!(sort_buffer_edits(vec![edit(3, 3, "x"), edit(3, 3, "y")]).is_ok());
+    }
+
+    #[test]
+    fn test_sort_buffer_edits_insertion_at_replacement_start_in_either_order() {
+        let edit = |start, end, text: &str| BufferEdit {
+            start,
+            end,
+            text: text.to_string(),
+        };
+        let expected = vec![edit(5, 5, "y"), edit(5, 8, "x")];
+
+        let sorted = sort_buffer_edits(vec![edit(5, 8, "x"), edit(5, 5, "y")]).unwrap();
+        assert_eq!(sorted, expected);
+        let sorted = sort_buffer_edits(vec![edit(5, 5, "y"), edit(5, 8, "x")]).unwrap();
+        assert_eq!(sorted, expected);
+
+        // Insertions at the same offset keep their order around the replacement
+        let sorted =
+            sort_buffer_edits(vec![edit(5, 5, "a"), edit(5, 8, "x"), edit(5, 5, "b")]).unwrap();
+        let texts: Vec<&str> = sorted.iter().map(|e| e.text.as_str()).collect();
+        assert_eq!(texts, vec!["a", "b", "x"]);
+    }
 }
@@ -221,6 +221,17 @@ pub enum HookArgs {
         height: u16,
     },
 
+    /// Primary cursor position changed (coalesced to at most once per frame)
+    CursorPositionChanged {
+        buffer_id: BufferId,
+        /// Byte offset of the primary cursor
+        position: usize,
+        /// Line number (1-indexed)
+        line: usize,
+        /// Byte column within the line (1-indexed)
+        column: usize,
+    },
+
     /// LSP server failed to start or crashed
     LspServerError {
         /// The language that failed
@@ -646,6 +657,19 @@ pub fn hook_args_to_json(args: &HookArgs) -> Result<String> {
                 "height": height,
             })
         }
+        HookArgs::CursorPositionChanged {
+            buffer_id,
+            position,
+            line,
+            column,
+        } => {
+            serde_json::json!({
+                "buffer_id": buffer_id.0,
+                "position": position,
+                "line": line,
+                "column": column,
+            })
+        }
         HookArgs::LspServerError {
             language,
             server_command,
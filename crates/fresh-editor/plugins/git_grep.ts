@@ -48,7 +48,17 @@ async function searchWithGitGrep(query: string): Promise<GrepMatch[]> {
   );
 
   if (result.exit_code === 0) {
-    return parseGrepOutput(result.stdout, 100) as GrepMatch[];
+    const matches = parseGrepOutput(result.stdout, 100) as GrepMatch[];
+    // Keep the latest results in the quickfix list for next/previous stepping
+    editor.setQuickfixList(
+      matches.map((m) => ({
+        file: m.file,
+        line: m.line,
+        column: m.column,
+        text: m.content.trim(),
+      }))
+    );
+    return matches;
   }
   return [];
 }
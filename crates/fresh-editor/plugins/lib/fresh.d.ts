@@ -251,6 +251,20 @@ type BufferInfo = {
 	*/
 	length: number;
 };
+type BufferEdit = {
+	/**
+	* Start byte offset (inclusive)
+	*/
+	start: number;
+	/**
+	* End byte offset (exclusive); equal to `start` for a pure insertion
+	*/
+	end: number;
+	/**
+	* Replacement text (empty for a pure deletion)
+	*/
+	text: string;
+};
 type JsDiagnostic = {
 	/**
 	* Document URI
@@ -349,6 +363,78 @@ type FileExplorerDecoration = {
 	*/
 	priority: number;
 };
+type QuickfixEntry = {
+	/**
+	* File path (absolute, or relative to the working directory)
+	*/
+	file: string;
+	/**
+	* Line number (1-indexed)
+	*/
+	line: number;
+	/**
+	* Column number (1-indexed, default 1)
+	*/
+	column: number;
+	/**
+	* Short description shown when the entry is visited
+	*/
+	text: string;
+};
+type StatusBarAlignment = "left" | "right";
+type StatusBarItemOptions = {
+	/**
+	* Side of the status bar (default "left")
+	*/
+	alignment?: StatusBarAlignment;
+	/**
+	* Items with higher priority are shown first on their side (default 0)
+	*/
+	priority?: number;
+};
+type TextObjectOperation = "select" | "delete" | "expand";
+type TextObjectQuery = {
+	/**
+	* Buffer the cursor is in
+	*/
+	bufferId: number;
+	/**
+	* Byte offset of the primary cursor
+	*/
+	position: number;
+	/**
+	* Selection anchor of the primary cursor, if any
+	*/
+	anchor: number | null;
+	/**
+	* Operation the range will be used for
+	*/
+	operation: TextObjectOperation;
+};
+type TextObjectRange = {
+	/**
+	* Start byte offset (inclusive)
+	*/
+	start: number;
+	/**
+	* End byte offset (exclusive)
+	*/
+	end: number;
+};
+type KeybindingInfo = {
+	/**
+	* Key in the same form `setKeybinding` accepts (e.g. "C-s", "M-x", "F7")
+	*/
+	key: string;
+	/**
+	* Action name (e.g. "move_line_end") or plugin command name
+	*/
+	command: string;
+	/**
+	* Context the binding applies in (e.g. "normal", "prompt")
+	*/
+	context: string;
+};
 type BackgroundProcessResult = {
 	/**
 	* Unique process ID for later reference
@@ -523,6 +609,28 @@ type CreateVirtualBufferOptions = {
 	*/
 	entries?: Array<TextPropertyEntry>;
 };
+type CursorDetails = {
+	/**
+	* Line number of the cursor (0-indexed)
+	*/
+	line: number;
+	/**
+	* Byte column of the cursor within its line (0-indexed)
+	*/
+	column: number;
+	/**
+	* Byte offset of the cursor
+	*/
+	byte: number;
+	/**
+	* Total size of the buffer in bytes
+	*/
+	totalBytes: number;
+	/**
+	* Total number of lines, if known (large files may not have a line count)
+	*/
+	totalLines: number | null;
+};
 type SpawnResult = {
 	/**
 	* Complete stdout as string
@@ -598,6 +706,16 @@ interface EditorAPI {
 	warn(msg: string): void;
 	error(msg: string): void;
 	setStatus(msg: string): void;
+	/**
+	* Add or update a status bar item, identified by `id`
+	* 
+	* Options: `alignment` ("left" or "right") and `priority` (higher first)
+	*/
+	setStatusBarItem(id: string, text: string, options?: StatusBarItemOptions): boolean;
+	/**
+	* Remove a status bar item added with `setStatusBarItem`
+	*/
+	removeStatusBarItem(id: string): boolean;
 	copyToClipboard(text: string): void;
 	setClipboard(text: string): void;
 	/**
@@ -618,6 +736,21 @@ interface EditorAPI {
 	*/
 	executeAction(actionName: string): boolean;
 	/**
+	* Register a text object - `handlerName` is a global function called with a
+	* `TextObjectQuery` that returns a `TextObjectRange` (or a Promise of one), or null
+	* when there is no match. Adds "Select"/"Delete" commands for the text object, and
+	* expand-selection grows to the smallest text object around the selection
+	*/
+	registerTextObject(name: string, handlerName: string): boolean;
+	/**
+	* Move the cursor to the next diagnostic in the active buffer (wraps around)
+	*/
+	gotoNextDiagnostic(): boolean;
+	/**
+	* Move the cursor to the previous diagnostic in the active buffer (wraps around)
+	*/
+	gotoPrevDiagnostic(): boolean;
+	/**
 	* Translate a string - reads plugin name from __pluginName__ global
 	* Args is optional - can be omitted, undefined, null, or an object
 	*/
@@ -668,6 +801,10 @@ interface EditorAPI {
 	*/
 	getCursorLine(): number;
 	/**
+	* Get line, column, byte offset and buffer size for the primary cursor
+	*/
+	getCursorDetails(): CursorDetails | null;
+	/**
 	* Get the byte offset of the start of a line (0-indexed line number)
 	* Returns null if the line number is out of range
 	*/
@@ -689,6 +826,13 @@ interface EditorAPI {
 	*/
 	deleteRange(bufferId: number, start: number, end: number): boolean;
 	/**
+	* Apply several `{start, end, text}` replacements as one undoable edit
+	* 
+	* Offsets refer to the buffer before the batch; later edits are shifted
+	* automatically. Returns false if any range is inverted or ranges overlap.
+	*/
+	applyEdits(bufferId: number, edits: BufferEdit[]): boolean;
+	/**
 	* Insert text at cursor position in active buffer
 	*/
 	insertAtCursor(text: string): boolean;
@@ -701,6 +845,12 @@ interface EditorAPI {
 	*/
 	openFileInSplit(splitId: number, path: string, line: number, column: number): boolean;
 	/**
+	* Register an open handler - paths matching the `pattern` regex are not read
+	* from disk; instead `handlerName` is a global function called with the path
+	* that returns the buffer content (or a Promise of it)
+	*/
+	registerOpenHandler(pattern: string, handlerName: string): boolean;
+	/**
 	* Show a buffer in the current split
 	*/
 	showBuffer(bufferId: number): boolean;
@@ -762,6 +912,17 @@ interface EditorAPI {
 	*/
 	readDir(path: string): DirEntry[];
 	/**
+	* Get a value stored by this plugin with `setStorage`, or null if unset
+	*/
+	getStorage(key: string): unknown;
+	/**
+	* Store a JSON-serializable value for this plugin, kept across sessions
+	*
+	* Storing null or undefined removes the key. Returns false if the
+	* plugin's storage would grow past its size limit (256 KiB)
+	*/
+	setStorage(key: string, value: unknown): boolean;
+	/**
 	* Get current config as JS object
 	*/
 	getConfig(): unknown;
@@ -843,6 +1004,19 @@ interface EditorAPI {
 	*/
 	clearNamespace(bufferId: number, namespace: string): boolean;
 	/**
+	* Highlight a byte range with a decoration style
+	* 
+	* `style` is a predefined kind ("error", "warning", "info", "hint",
+	* "highlight", "emphasis") or a theme color key such as
+	* "diagnostic.error_bg". Decorations are grouped per plugin so
+	* `clearHighlights` removes only this plugin's group
+	*/
+	highlightRange(bufferId: number, start: number, end: number, style: string, group?: string): boolean;
+	/**
+	* Remove this plugin's decorations in a group (default group if omitted)
+	*/
+	clearHighlights(bufferId: number, group?: string): boolean;
+	/**
 	* Clear all overlays from a buffer
 	*/
 	clearAllOverlays(bufferId: number): boolean;
@@ -876,6 +1050,12 @@ interface EditorAPI {
 	*/
 	clearFileExplorerDecorations(namespace: string): boolean;
 	/**
+	* Replace the quickfix list with the given locations
+	* 
+	* The user steps through them with the next/previous quickfix item actions
+	*/
+	setQuickfixList(entries: QuickfixEntry[]): boolean;
+	/**
 	* Add virtual text (inline text that doesn't exist in the buffer)
 	*/
 	addVirtualText(bufferId: number, virtualTextId: string, position: number, text: string, r: number, g: number, b: number, before: boolean, useBg: boolean): boolean;
@@ -931,6 +1111,19 @@ interface EditorAPI {
 	*/
 	getEditorMode(): string | null;
 	/**
+	* List the single-key bindings of a context ("normal" when omitted)
+	*/
+	getKeybindings(context?: string): Promise<KeybindingInfo[]>;
+	/**
+	* Bind a key (e.g. "C-k", "M-x", "F7") to an action or plugin command in a
+	* context ("normal" when omitted), replacing any existing binding for the key
+	*/
+	setKeybinding(key: string, command: string, context?: string): boolean;
+	/**
+	* Unbind a key in a context ("normal" when omitted), including default bindings
+	*/
+	removeKeybinding(key: string, context?: string): boolean;
+	/**
 	* Close a split
 	*/
 	closeSplit(splitId: number): boolean;
@@ -1049,6 +1242,13 @@ interface EditorAPI {
 	*/
 	sendLspRequest(language: string, method: string, params: Record<string, unknown> | null): Promise<unknown>;
 	/**
+	* Send an LSP request to the server for the active buffer's language
+	* (async, returns request_id)
+	* 
+	* Rejects if no server is available or it does not answer in time
+	*/
+	lsp(method: string, params?: unknown): Promise<unknown>;
+	/**
 	* Spawn a background process (async, returns request_id which is also process_id)
 	*/
 	spawnBackgroundProcess(command: string, args: string[], cwd?: string): ProcessHandle<BackgroundProcessResult>;
@@ -1391,6 +1391,8 @@ function createDefaultTheme(): Record<string, unknown> {
     search: {
       match_bg: [100, 100, 20],
       match_fg: [255, 255, 255],
+      current_match_bg: [180, 100, 0],
+      current_match_fg: [255, 255, 255],
     },
     diagnostic: {
       error_fg: "Red",
@@ -383,6 +383,21 @@ globalThis.vi_doc_end = function (): void {
   editor.executeAction("move_document_end");
 };
 
+globalThis.vi_screen_top = function (): void {
+  consumeCount(); // Count doesn't apply
+  editor.executeAction("cursor_to_viewport_top");
+};
+
+globalThis.vi_screen_middle = function (): void {
+  consumeCount(); // Count doesn't apply
+  editor.executeAction("cursor_to_viewport_middle");
+};
+
+globalThis.vi_screen_bottom = function (): void {
+  consumeCount(); // Count doesn't apply
+  editor.executeAction("cursor_to_viewport_bottom");
+};
+
 globalThis.vi_page_down = function (): void {
   executeWithCount("page_down");
 };
@@ -1495,6 +1510,9 @@ editor.defineMode("vi-normal", null, [
   ["^", "vi_first_non_blank"],
   ["g g", "vi_doc_start"],
   ["G", "vi_doc_end"],
+  ["H", "vi_screen_top"],
+  ["M", "vi_screen_middle"],
+  ["L", "vi_screen_bottom"],
   ["C-f", "vi_page_down"],
   ["C-b", "vi_page_up"],
   ["C-d", "vi_half_page_down"],
@@ -1558,6 +1576,7 @@ editor.defineMode("vi-normal", null, [
 
   // Other
   ["J", "vi_join"],
+  ["C-6", "switch_to_previous_tab"], // Ctrl-^ (alternate buffer)
 
   // Command mode
   [":", "vi_command_mode"],
@@ -1879,6 +1898,9 @@ const navCommands = [
   ["vi_line_end", "move_line_end"],
   ["vi_doc_start", "move_doc_start"],
   ["vi_doc_end", "move_doc_end"],
+  ["vi_screen_top", "move_screen_top"],
+  ["vi_screen_middle", "move_screen_middle"],
+  ["vi_screen_bottom", "move_screen_bottom"],
   ["vi_page_down", "page_down"],
   ["vi_page_up", "page_up"],
   ["vi_half_page_down", "half_page_down"],
@@ -2291,7 +2313,7 @@ async function executeCommand(
       // :e! - force reload (discard changes)
       if (!args) {
         if (force) {
-          editor.executeAction("revert");
+          editor.executeAction("force_revert");
           return { message: editor.t("status.file_reverted_discarded") };
         }
         const bufferId = editor.getActiveBufferId();
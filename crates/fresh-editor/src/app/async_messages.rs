@@ -14,7 +14,7 @@ use crate::services::async_bridge::{
 };
 use crate::services::lsp::manager::detect_language;
 use crate::state::{SemanticTokenSpan, SemanticTokenStore};
-use crate::view::file_tree::{FileTreeView, NodeId};
+use crate::view::file_tree::{FileTreeView, GitStatusMap, NodeId};
 use lsp_types::{
     Diagnostic, InlayHint, SemanticToken, SemanticTokensEdit, SemanticTokensFullDeltaResult,
     SemanticTokensLegend, SemanticTokensRangeResult, SemanticTokensResult,
@@ -786,6 +786,11 @@ impl Editor {
     ) {
         use fresh_core::api::JsCallbackId;
         tracing::debug!("Received plugin LSP response (request_id={})", request_id);
+        if self.plugin_lsp_timed_out.remove(&request_id).is_some() {
+            tracing::debug!("Dropping LSP response for timed out request {}", request_id);
+            return;
+        }
+        self.plugin_lsp_deadlines.remove(&request_id);
         let callback_id = JsCallbackId::from(request_id);
         match result {
             Ok(value) => {
@@ -935,6 +940,13 @@ impl Editor {
         self.file_explorer = Some(view);
         self.file_explorer_sync_in_progress = false;
     }
+
+    /// Apply git status loaded in the background after a file explorer refresh
+    pub(super) fn handle_file_explorer_git_status_loaded(&mut self, git_status: GitStatusMap) {
+        if let Some(explorer) = &mut self.file_explorer {
+            explorer.set_git_status(git_status);
+        }
+    }
 }
 
 // =============================================================================
@@ -1041,6 +1053,8 @@ impl Editor {
             self.status_message = Some(message.clone());
 
             if success {
+                // A new server never answers requests sent to the old one
+                self.plugin_lsp_timed_out.clear();
                 self.resend_did_open_for_language(&language);
             }
         }
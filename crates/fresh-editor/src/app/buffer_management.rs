@@ -22,6 +22,9 @@ use crate::view::split::SplitViewState;
 use super::help;
 use super::Editor;
 
+/// How much of a newly opened file is examined to detect its indentation width
+const INDENT_DETECTION_SAMPLE_BYTES: usize = 64 * 1024;
+
 impl Editor {
     /// Open a file and return its buffer ID
     ///
@@ -57,16 +60,19 @@ impl Editor {
             .map(|m| m.display_name.clone())
             .unwrap_or_else(|| path.display().to_string());
 
-        // Check if buffer is binary for status message
-        let is_binary = self
+        // Check if buffer is binary or has mixed line endings for status message
+        let (is_binary, mixed_line_endings) = self
             .buffers
             .get(&buffer_id)
-            .map(|s| s.buffer.is_binary())
-            .unwrap_or(false);
+            .map(|s| (s.buffer.is_binary(), s.buffer.has_mixed_line_endings()))
+            .unwrap_or((false, false));
 
         // Show appropriate status message for binary vs regular files
         if is_binary {
             self.status_message = Some(t!("buffer.opened_binary", name = display_name).to_string());
+        } else if mixed_line_endings {
+            self.status_message =
+                Some(t!("buffer.opened_mixed_line_endings", name = display_name).to_string());
         } else {
             self.status_message = Some(t!("buffer.opened", name = display_name).to_string());
         }
@@ -81,6 +87,12 @@ impl Editor {
     ///
     /// If the file doesn't exist, creates an unsaved buffer with that filename.
     pub fn open_file_no_focus(&mut self, path: &Path) -> anyhow::Result<BufferId> {
+        // Paths claimed by a plugin open handler never touch the filesystem.
+        // Pseudo-paths such as `git://HEAD/src/main.rs` only match as given.
+        if let Some(buffer_id) = self.open_with_plugin_handler(path) {
+            return Ok(buffer_id);
+        }
+
         // Resolve relative paths against working_dir, not process current directory
         let resolved_path = if path.is_relative() {
             self.working_dir.join(path)
@@ -120,6 +132,11 @@ impl Editor {
         };
         let path = canonical_path.as_path();
 
+        // Filesystem-style patterns also match the resolved path
+        if let Some(buffer_id) = self.open_with_plugin_handler(path) {
+            return Ok(buffer_id);
+        }
+
         // Check if the path is a directory (after following symlinks via canonicalize)
         // Directories cannot be opened as files in the editor
         if path.is_dir() {
@@ -179,38 +196,14 @@ impl Editor {
             new_state
         };
         // Note: line_wrap_enabled is set on SplitViewState.viewport when the split is created
-
-        // Check if the buffer contains binary content
+        self.apply_file_settings(&mut state, path, file_exists);
         let is_binary = state.buffer.is_binary();
-        if is_binary {
-            // Make binary buffers read-only
-            state.editing_disabled = true;
-            tracing::info!("Detected binary file: {}", path.display());
-        }
-
-        // Set show_whitespace_tabs, use_tabs, and tab_size based on language config
-        // with fallback to global editor config for tab_size
-        if let Some(language) = detect_language(path, &self.config.languages) {
-            if let Some(lang_config) = self.config.languages.get(&language) {
-                state.show_whitespace_tabs = lang_config.show_whitespace_tabs;
-                state.use_tabs = lang_config.use_tabs;
-                // Use language-specific tab_size if set, otherwise fall back to global
-                state.tab_size = lang_config.tab_size.unwrap_or(self.config.editor.tab_size);
-            } else {
-                state.tab_size = self.config.editor.tab_size;
-            }
-        } else {
-            state.tab_size = self.config.editor.tab_size;
-        }
-
-        // Apply line_numbers default from config
-        state
-            .margins
-            .set_line_numbers(self.config.editor.line_numbers);
 
         self.buffers.insert(buffer_id, state);
-        self.event_logs
-            .insert(buffer_id, crate::model::event::EventLog::new());
+        self.event_logs.insert(buffer_id, self.new_event_log());
+        if file_exists && !is_binary {
+            self.restore_undo_history(buffer_id);
+        }
 
         // Create metadata for this buffer
         let mut metadata =
@@ -235,8 +228,6 @@ impl Editor {
         let active_split = self.split_manager.active_split();
         if let Some(view_state) = self.split_view_states.get_mut(&active_split) {
             view_state.add_buffer(buffer_id);
-            // Apply line_wrap default from config (per-view setting, applies to split)
-            view_state.viewport.line_wrap_enabled = self.config.editor.line_wrap;
         }
 
         // Restore global file state (scroll/cursor position) if available
@@ -267,6 +258,154 @@ impl Editor {
         Ok(buffer_id)
     }
 
+    /// Open a path through the first plugin open handler whose pattern matches it
+    ///
+    /// The buffer starts empty and read-only; the plugin fills in its content
+    /// asynchronously. Returns None when no handler claims the path.
+    fn open_with_plugin_handler(&mut self, path: &Path) -> Option<BufferId> {
+        let name = path.to_string_lossy().into_owned();
+        let pattern = self
+            .plugin_open_handlers
+            .iter()
+            .find(|(_, re)| re.is_match(&name))?
+            .1
+            .as_str()
+            .to_string();
+
+        // Already opened through the handler - return the existing buffer
+        let already_open = self
+            .buffer_metadata
+            .iter()
+            .find(|(_, m)| m.is_virtual() && m.display_name == name)
+            .map(|(id, _)| *id);
+        if let Some(id) = already_open {
+            return Some(id);
+        }
+
+        let buffer_id = self.create_virtual_buffer(name.clone(), "special".to_string(), true);
+        self.plugin_manager
+            .open_with_handler(&pattern, &name, buffer_id);
+        Some(buffer_id)
+    }
+
+    /// Set up a buffer loaded from `path`: binary files become read-only,
+    /// and the language config, `.editorconfig` and the file's detected
+    /// indentation set its indentation, line numbers and wrapping.
+    ///
+    /// Used both when a file is opened and when it is reverted.
+    pub(super) fn apply_file_settings(
+        &self,
+        state: &mut EditorState,
+        path: &Path,
+        file_exists: bool,
+    ) {
+        // Check if the buffer contains binary content
+        let is_binary = state.buffer.is_binary();
+        if is_binary {
+            // Make binary buffers read-only
+            state.editing_disabled = true;
+            tracing::info!("Detected binary file: {}", path.display());
+        }
+
+        // Set show_whitespace_tabs, use_tabs, and tab_size based on language config
+        // with fallback to global editor config for tab_size
+        let lang_config = detect_language(path, &self.config.languages)
+            .and_then(|language| self.config.languages.get(&language));
+        if let Some(lang_config) = lang_config {
+            state.show_whitespace_tabs = lang_config.show_whitespace_tabs;
+            state.use_tabs = lang_config.use_tabs;
+            // Use language-specific tab_size if set, otherwise fall back to global
+            state.tab_size = lang_config.tab_size.unwrap_or(self.config.editor.tab_size);
+        } else {
+            state.tab_size = self.config.editor.tab_size;
+        }
+        // Per-language default for line numbers
+        let line_numbers = lang_config
+            .and_then(|c| c.line_numbers)
+            .unwrap_or(self.config.editor.line_numbers);
+        // Per-language wrapping stays with this buffer; splits resolve it when rendering
+        state.line_wrap = lang_config.and_then(|c| c.line_wrap);
+
+        let editorconfig_width = !is_binary && self.apply_editorconfig(state, path);
+
+        // Space-indented files keep their own indentation width
+        if self.config.editor.detect_indent_width
+            && file_exists
+            && !is_binary
+            && !state.use_tabs
+            && !editorconfig_width
+        {
+            let sample_len = state.buffer.len().min(INDENT_DETECTION_SAMPLE_BYTES);
+            if let Ok(sample) = state.buffer.get_text_range_mut(0, sample_len) {
+                let sample = String::from_utf8_lossy(&sample);
+                if let Some(width) = crate::primitives::indent::detect_indent_width(&sample) {
+                    state.tab_size = width;
+                    state.detected_indent_width = Some(width);
+                    state.setting_overrides.tab_size = true;
+                }
+            }
+        }
+
+        // Apply line_numbers default from config (language override wins)
+        state.margins.set_line_numbers(line_numbers);
+    }
+
+    /// Apply the `.editorconfig` properties for `path` to a newly loaded
+    /// buffer, except for settings the project or session config sets
+    /// explicitly. Returns whether the indentation width was set.
+    fn apply_editorconfig(&self, state: &mut EditorState, path: &Path) -> bool {
+        use crate::config_io::ConfigLayer;
+        use crate::primitives::editorconfig::properties_for_file;
+
+        let properties = properties_for_file(path);
+        if properties.is_empty() {
+            return false;
+        }
+
+        let explicit = |key: &str| {
+            matches!(
+                self.config_layer_sources.get(key),
+                Some(ConfigLayer::Project | ConfigLayer::Session)
+            )
+        };
+        let language = detect_language(path, &self.config.languages).unwrap_or_default();
+        let language_key = |field: &str| format!("/languages/{language}/{field}");
+
+        if let Some(use_tabs) = properties.use_tabs {
+            if !explicit(&language_key("use_tabs")) {
+                state.use_tabs = use_tabs;
+                state.setting_overrides.use_tabs = true;
+                state.editorconfig_indent = true;
+            }
+        }
+        let mut indent_set = false;
+        if let Some(width) = properties.indent_width() {
+            if !explicit("/editor/tab_size") && !explicit(&language_key("tab_size")) {
+                state.tab_size = width;
+                state.setting_overrides.tab_size = true;
+                state.editorconfig_indent = true;
+                indent_set = true;
+            }
+        }
+        // Existing files keep the line endings they were written with
+        if let Some(line_ending) = properties.end_of_line {
+            if state.buffer.is_empty() && !explicit("/editor/default_line_ending") {
+                state.buffer.set_default_line_ending(line_ending);
+            }
+        }
+        if let Some(insert) = properties.insert_final_newline {
+            if !explicit("/editor/insert_final_newline") {
+                state.insert_final_newline = Some(insert);
+            }
+        }
+        if let Some(trim) = properties.trim_trailing_whitespace {
+            if !explicit("/editor/trim_trailing_whitespace") {
+                state.trim_trailing_whitespace = Some(trim);
+            }
+        }
+        indent_set
+    }
+
     /// Restore global file state (cursor and scroll position) for a newly opened file
     ///
     /// This looks up the file's saved state from the global file states store
@@ -359,7 +498,8 @@ impl Editor {
 
     /// Navigate to a specific line and column in the active buffer.
     ///
-    /// Line and column are 1-indexed (matching typical editor conventions).
+    /// Line and column are 1-indexed (matching typical editor conventions),
+    /// and the column counts characters.
     /// If the line is out of bounds, navigates to the last line.
     /// If the column is out of bounds, navigates to the end of the line.
     pub fn goto_line_col(&mut self, line: usize, column: Option<usize>) {
@@ -367,59 +507,111 @@ impl Editor {
             return; // Line numbers are 1-indexed
         }
 
-        let buffer_id = self.active_buffer();
         let estimated_line_length = self.config.editor.estimated_line_length;
+        let state = self.active_state_mut();
+        let buffer_len = state.buffer.len();
+        // Convert 1-indexed line to 0-indexed
+        let target_line = line - 1;
+
+        let line_start = if let Some(line_count) = state.buffer.line_count() {
+            // Small file mode: use exact line position
+            let max_line = line_count.saturating_sub(1);
+            state
+                .buffer
+                .line_start_offset(target_line.min(max_line))
+                .unwrap_or(buffer_len)
+        } else {
+            // Large file mode: estimate byte offset based on line number, then
+            // use LineIterator to find the actual line start at the estimated position
+            let estimated_offset = (target_line * estimated_line_length).min(buffer_len);
+            state
+                .buffer
+                .line_iterator(estimated_offset, estimated_line_length)
+                .current_position()
+        };
 
-        if let Some(state) = self.buffers.get(&buffer_id) {
-            let cursor_id = state.cursors.primary_id();
-            let old_position = state.cursors.primary().position;
-            let old_anchor = state.cursors.primary().anchor;
-            let old_sticky_column = state.cursors.primary().sticky_column;
-            let is_large_file = state.buffer.line_count().is_none();
-            let buffer_len = state.buffer.len();
-
-            // Convert 1-indexed line to 0-indexed
-            let target_line = line.saturating_sub(1);
-            // Column is also 1-indexed, convert to 0-indexed
-            let target_col = column.map(|c| c.saturating_sub(1)).unwrap_or(0);
-
-            let position = if is_large_file {
-                // Large file mode: estimate byte offset based on line number
-                let estimated_offset = target_line * estimated_line_length;
-                let clamped_offset = estimated_offset.min(buffer_len);
-
-                // Use LineIterator to find the actual line start at the estimated position
-                if let Some(state) = self.buffers.get_mut(&buffer_id) {
-                    let iter = state
-                        .buffer
-                        .line_iterator(clamped_offset, estimated_line_length);
-                    let line_start = iter.current_position();
-                    // Add column offset, clamped to buffer length
-                    (line_start + target_col).min(buffer_len)
-                } else {
-                    clamped_offset
-                }
-            } else {
-                // Small file mode: use exact line position
-                let max_line = state.buffer.line_count().unwrap_or(1).saturating_sub(1);
-                let actual_line = target_line.min(max_line);
-                state.buffer.line_col_to_position(actual_line, target_col)
-            };
+        self.move_cursor_to_column(line_start, column.unwrap_or(1));
+    }
 
-            let event = Event::MoveCursor {
-                cursor_id,
-                old_position,
-                new_position: position,
-                old_anchor,
-                new_anchor: None,
-                old_sticky_column,
-                new_sticky_column: target_col,
-            };
+    /// Navigate to a 1-indexed character column on the cursor's current line,
+    /// clamped to the end of the line
+    pub fn goto_column(&mut self, column: usize) {
+        let estimated_line_length = self.config.editor.estimated_line_length;
+        let state = self.active_state_mut();
+        let position = state.cursors.primary().position;
+        let line_start = state
+            .buffer
+            .line_iterator(position, estimated_line_length)
+            .current_position();
+        self.move_cursor_to_column(line_start, column);
+    }
 
-            if let Some(state) = self.buffers.get_mut(&buffer_id) {
-                state.apply(&event);
-            }
-        }
+    /// Move the primary cursor to the 1-indexed character `column` of the line
+    /// starting at `line_start`, clamped to the line's text
+    fn move_cursor_to_column(&mut self, line_start: usize, column: usize) {
+        let estimated_line_length = self.config.editor.estimated_line_length;
+        let state = self.active_state_mut();
+        let target_col = column.saturating_sub(1);
+
+        // Exclude the line ending so an overlong column stops at the end of the line
+        let column_offset = if target_col == 0 {
+            0
+        } else {
+            let content = state
+                .buffer
+                .line_iterator(line_start, estimated_line_length)
+                .next_line()
+                .map(|(_, content)| content)
+                .unwrap_or_default();
+            let text = content.trim_end_matches(['\n', '\r']);
+            text.char_indices()
+                .nth(target_col)
+                .map_or(text.len(), |(offset, _)| offset)
+        };
+        let position = (line_start + column_offset).min(state.buffer.len());
+
+        let cursor = *state.cursors.primary();
+        let event = Event::MoveCursor {
+            cursor_id: state.cursors.primary_id(),
+            old_position: cursor.position,
+            new_position: position,
+            old_anchor: cursor.anchor,
+            new_anchor: None,
+            old_sticky_column: cursor.sticky_column,
+            new_sticky_column: target_col,
+        };
+        state.apply(&event);
+    }
+
+    /// Go to the start of the line containing the byte at `percent` through the buffer
+    ///
+    /// Percentages above 100 are clamped to the end of the buffer. Useful in
+    /// large files where the exact line number is unknown.
+    pub fn goto_percent(&mut self, percent: usize) {
+        let buffer_id = self.active_buffer();
+        let estimated_line_length = self.config.editor.estimated_line_length;
+
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return;
+        };
+        let buffer_len = state.buffer.len();
+        let target = (buffer_len as u128 * percent.min(100) as u128 / 100) as usize;
+        let position = state
+            .buffer
+            .line_iterator(target, estimated_line_length)
+            .current_position();
+
+        let cursor = state.cursors.primary();
+        let event = Event::MoveCursor {
+            cursor_id: state.cursors.primary_id(),
+            old_position: cursor.position,
+            new_position: position,
+            old_anchor: cursor.anchor,
+            new_anchor: None,
+            old_sticky_column: cursor.sticky_column,
+            new_sticky_column: 0,
+        };
+        state.apply(&event);
     }
 
     /// Create a new empty buffer
@@ -452,8 +644,7 @@ impl Editor {
             .buffer
             .set_default_line_ending(self.config.editor.default_line_ending.to_line_ending());
         self.buffers.insert(buffer_id, state);
-        self.event_logs
-            .insert(buffer_id, crate::model::event::EventLog::new());
+        self.event_logs.insert(buffer_id, self.new_event_log());
         self.buffer_metadata
             .insert(buffer_id, crate::app::types::BufferMetadata::new());
 
@@ -543,8 +734,7 @@ impl Editor {
             .set_line_numbers(self.config.editor.line_numbers);
 
         self.buffers.insert(buffer_id, state);
-        self.event_logs
-            .insert(buffer_id, crate::model::event::EventLog::new());
+        self.event_logs.insert(buffer_id, self.new_event_log());
 
         // Create metadata for this buffer (no file path)
         let metadata =
@@ -710,8 +900,7 @@ impl Editor {
             .set_line_numbers(self.config.editor.line_numbers);
 
         self.buffers.insert(buffer_id, state);
-        self.event_logs
-            .insert(buffer_id, crate::model::event::EventLog::new());
+        self.event_logs.insert(buffer_id, self.new_event_log());
 
         // Set virtual buffer metadata
         let metadata = super::types::BufferMetadata::virtual_buffer(name, mode, read_only);
@@ -886,6 +1075,30 @@ impl Editor {
         self.set_active_buffer(buffer_id);
     }
 
+    /// Show line, word and character counts of the selection, or of the whole
+    /// buffer when nothing is selected
+    pub fn show_word_count(&mut self) {
+        let state = self.active_state_mut();
+        let selected = state.cursors.primary().selection_range().is_some();
+        let stats = state.text_stats();
+        let message = if selected {
+            t!(
+                "status.word_count_selection",
+                lines = stats.lines,
+                words = stats.words,
+                chars = stats.chars
+            )
+        } else {
+            t!(
+                "status.word_count",
+                lines = stats.lines,
+                words = stats.words,
+                chars = stats.chars
+            )
+        };
+        self.set_status_message(message.to_string());
+    }
+
     /// Show warnings by opening the warning log file directly
     ///
     /// If there are no warnings, shows a brief status message.
@@ -990,6 +1203,7 @@ impl Editor {
     fn close_buffer_internal(&mut self, id: BufferId) -> anyhow::Result<()> {
         // Save file state before closing (for per-file session persistence)
         self.save_file_state_on_close(id);
+        self.persist_undo_history(id);
 
         // If closing a terminal buffer, clean up terminal-related data structures
         if let Some(terminal_id) = self.terminal_buffers.remove(&id) {
@@ -3,16 +3,26 @@
 //! This module contains clipboard operations and multi-cursor actions:
 //! - Copy/cut/paste operations
 //! - Copy with formatting (HTML with syntax highlighting)
+//! - Export of the buffer or selection as an HTML fragment
+//! - Yank-pop through the kill ring of earlier copies
+//! - Named registers selected with the register prefix
 //! - Multi-cursor add above/below/at next match
 
 use rust_i18n::t;
+use std::ops::Range;
 
+use crate::input::actions::{block_selection_rect, line_range_at_visual_columns};
 use crate::input::multi_cursor::{
     add_cursor_above, add_cursor_at_next_match, add_cursor_below, AddCursorResult,
 };
+use crate::model::buffer::Buffer;
 use crate::model::event::{CursorId, Event};
+use crate::primitives::display_width::str_width;
+use crate::primitives::indent::reindent_text;
 use crate::primitives::word_navigation::{find_word_start_left, find_word_start_right};
+use crate::services::clipboard::ClipboardKind;
 
+use super::types::PasteRecord;
 use super::Editor;
 
 // These are the clipboard and multi-cursor operations on Editor.
@@ -31,6 +41,12 @@ impl Editor {
     ///
     /// If no selection exists, copies the entire current line (like VSCode/Rider/Zed).
     pub fn copy_selection(&mut self) {
+        // A block selection on the primary cursor is copied as a rectangle
+        if self.active_state().cursors.primary().has_block_selection() {
+            self.copy_block_selection();
+            return;
+        }
+
         // Check if any cursor has a selection
         let has_selection = {
             let state = self.active_state();
@@ -61,8 +77,8 @@ impl Editor {
                 text.push_str(&range_text);
             }
 
-            if !text.is_empty() {
-                self.clipboard.copy(text);
+            if !text.is_empty() && !self.copy_to_pending_register(&text) {
+                self.copy_or_append_kill(text);
                 self.status_message = Some(t!("clipboard.copied").to_string());
             }
         } else {
@@ -84,13 +100,130 @@ impl Editor {
                 }
             }
 
-            if !text.is_empty() {
-                self.clipboard.copy(text);
+            if !text.is_empty() && !self.copy_to_pending_register(&text) {
+                self.copy_or_append_kill(text);
                 self.status_message = Some(t!("clipboard.copied_line").to_string());
             }
         }
     }
 
+    /// Copy the primary cursor's block selection, one row per line
+    ///
+    /// The block's edges are visual columns, so rows with multibyte or wide
+    /// text are cut where they appear on screen. Lines shorter than the block
+    /// contribute what they have.
+    fn copy_block_selection(&mut self) {
+        let text = {
+            let state = self.active_state();
+            let Some((first_line, first_col, last_line, last_col)) =
+                block_selection_rect(&state.buffer, state.cursors.primary())
+            else {
+                return;
+            };
+            let rows: Vec<String> = (first_line..=last_line)
+                .map(|line| {
+                    line_range_at_visual_columns(&state.buffer, line, first_col, last_col)
+                        .map(|range| {
+                            String::from_utf8_lossy(&state.buffer.slice_bytes(range)).into_owned()
+                        })
+                        .unwrap_or_default()
+                })
+                .collect();
+            rows.join("\n")
+        };
+
+        if !self.copy_to_pending_register(&text) {
+            self.clipboard.copy_block(text);
+            self.status_message = Some(t!("clipboard.copied").to_string());
+        }
+    }
+
+    /// Copy text to the clipboard, or append it to the previous kill when
+    /// this cut directly follows another one
+    fn copy_or_append_kill(&mut self, text: String) {
+        if self.appending_kill {
+            self.clipboard.append_kill(text);
+        } else {
+            self.clipboard.copy(text);
+        }
+    }
+
+    /// Whether Delete to Line End/Start copy what they delete, as emacs and
+    /// macOS kill commands do
+    pub(super) fn line_kills_to_clipboard(&self) -> bool {
+        self.config.editor.kill_line_to_clipboard
+            || matches!(&*self.config.active_keybinding_map, "emacs" | "macos")
+    }
+
+    /// Put the text removed by a kill-line action into the kill ring
+    ///
+    /// Directly after another kill the text joins the previous entry: after it
+    /// when killing toward the line end, before it when killing toward the
+    /// line start, so repeated kills read back in buffer order.
+    pub(super) fn kill_deleted_text(&mut self, events: &[Event], backward: bool) {
+        let mut deleted: Vec<(usize, &str)> = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Delete {
+                    range,
+                    deleted_text,
+                    ..
+                } => Some((range.start, deleted_text.as_str())),
+                _ => None,
+            })
+            .collect();
+        deleted.sort_by_key(|(start, _)| *start);
+        let text = deleted
+            .iter()
+            .map(|(_, text)| *text)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        if text.is_empty() || self.copy_to_pending_register(&text) {
+            return;
+        }
+        if !self.appending_kill {
+            self.clipboard.copy(text);
+        } else if backward {
+            self.clipboard.prepend_kill(text);
+        } else {
+            self.clipboard.append_kill(text);
+        }
+    }
+
+    /// Use `name` for the next copy, cut or paste instead of the clipboard
+    pub fn select_register(&mut self, name: char) {
+        self.pending_register = Some(name);
+        self.status_message = Some(t!("clipboard.register_selected", register = name).to_string());
+    }
+
+    /// Store copied text in the selected letter register, if there is one
+    ///
+    /// Returns false when no letter register was selected, in which case the
+    /// text should go to the clipboard. Copying with a numbered register
+    /// selected is an ordinary copy, which lands in register `0`.
+    fn copy_to_pending_register(&mut self, text: &str) -> bool {
+        match self.pending_register.take() {
+            Some(name) if name.is_ascii_lowercase() => {
+                self.clipboard.set_register(name, text.to_string());
+                self.status_message =
+                    Some(t!("clipboard.copied_to_register", register = name).to_string());
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Paste the content of a register as plain text
+    fn paste_from_register(&mut self, name: char, reindent: bool) {
+        let Some(text) = self.clipboard.register(name).map(str::to_string) else {
+            self.status_message = Some(t!("clipboard.register_empty", register = name).to_string());
+            return;
+        };
+        self.insert_pasted_text(text, reindent);
+        self.last_paste = None;
+    }
+
     /// Copy selection with a specific theme's formatting
     ///
     /// If theme_name is empty, opens a prompt to select a theme.
@@ -218,6 +351,73 @@ impl Editor {
         }
     }
 
+    /// Export the selection, or the whole buffer without one, as HTML
+    ///
+    /// The fragment uses inline styles from the active theme and syntax
+    /// highlighting, optionally numbers the lines, and opens in a new buffer.
+    pub fn export_html(&mut self, line_numbers: bool) {
+        use crate::services::styled_html::{
+            render_styled_html, render_styled_html_with_line_numbers,
+        };
+
+        let source_name = self.get_buffer_display_name(self.active_buffer());
+        let theme = self.theme.clone();
+        let context_bytes = self.config.editor.highlight_context_bytes;
+
+        let (text, spans, first_line) = {
+            let state = self.active_state_mut();
+            let range = state
+                .cursors
+                .primary()
+                .selection_range()
+                .unwrap_or(0..state.buffer.len());
+            let text = state.get_text_range(range.start, range.end);
+            let first_line = state.buffer.get_line_number(range.start) + 1;
+            let spans: Vec<_> = state
+                .highlighter
+                .highlight_viewport(&state.buffer, range.start, range.end, &theme, context_bytes)
+                .into_iter()
+                .filter_map(|span| {
+                    let start = span.range.start.max(range.start) - range.start;
+                    let end = span.range.end.min(range.end).saturating_sub(range.start);
+                    (start < end).then_some(crate::primitives::highlighter::HighlightSpan {
+                        range: start..end,
+                        color: span.color,
+                    })
+                })
+                .collect();
+            (text, spans, first_line)
+        };
+
+        if text.is_empty() {
+            self.status_message = Some(t!("clipboard.no_text").to_string());
+            return;
+        }
+
+        let html = if line_numbers {
+            render_styled_html_with_line_numbers(&text, &spans, &theme, first_line)
+        } else {
+            render_styled_html(&text, &spans, &theme)
+        };
+
+        // new_buffer() switches to the buffer it creates
+        let buffer_id = self.new_buffer();
+        let cursor_id = self.active_state().cursors.primary_id();
+        let insert = Event::Insert {
+            position: 0,
+            text: html,
+            cursor_id,
+        };
+        self.active_event_log_mut().append(insert.clone());
+        self.apply_event_to_active_buffer(&insert);
+
+        let buffer_name = format!("*HTML: {}*", source_name);
+        if let Some(metadata) = self.buffer_metadata.get_mut(&buffer_id) {
+            metadata.display_name = buffer_name.clone();
+        }
+        self.set_status_message(t!("export.html_created", buffer = buffer_name).to_string());
+    }
+
     /// Start the theme selection prompt for copy with formatting
     fn start_copy_with_formatting_prompt(&mut self) {
         use crate::view::prompt::PromptType;
@@ -364,6 +564,69 @@ impl Editor {
         }
     }
 
+    /// Copy the selected text into a new unnamed buffer and switch to it
+    ///
+    /// With `remove_from_source`, the selection is also deleted from the
+    /// original buffer as a single undoable change. Multiple selections are
+    /// joined with newlines in buffer order.
+    pub fn extract_selection_to_new_buffer(&mut self, remove_from_source: bool) {
+        let mut ranges: Vec<_> = self
+            .active_state()
+            .cursors
+            .iter()
+            .filter_map(|(_, cursor)| cursor.selection_range())
+            .collect();
+        if ranges.is_empty() {
+            self.set_status_message(t!("extract.no_selection").to_string());
+            return;
+        }
+        ranges.sort_by_key(|range| range.start);
+
+        let state = self.active_state_mut();
+        let text = ranges
+            .iter()
+            .map(|range| state.get_text_range(range.start, range.end))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        if remove_from_source {
+            let primary_id = state.cursors.primary_id();
+            let events: Vec<_> = ranges
+                .iter()
+                .rev()
+                .map(|range| Event::Delete {
+                    range: range.clone(),
+                    deleted_text: state.get_text_range(range.start, range.end),
+                    cursor_id: primary_id,
+                })
+                .collect();
+            let batch = Event::Batch {
+                events,
+                description: "Move selection to new buffer".to_string(),
+            };
+            self.active_event_log_mut().append(batch.clone());
+            self.apply_event_to_active_buffer(&batch);
+        }
+
+        // new_buffer() switches to the buffer it creates
+        self.new_buffer();
+        let cursor_id = self.active_state().cursors.primary_id();
+        let insert = Event::Insert {
+            position: 0,
+            text,
+            cursor_id,
+        };
+        self.active_event_log_mut().append(insert.clone());
+        self.apply_event_to_active_buffer(&insert);
+
+        let message = if remove_from_source {
+            t!("extract.moved")
+        } else {
+            t!("extract.copied")
+        };
+        self.set_status_message(message.to_string());
+    }
+
     /// Paste the clipboard content at all cursor positions
     ///
     /// Handles:
@@ -371,14 +634,244 @@ impl Editor {
     /// - Multi-cursor paste (pastes at each cursor)
     /// - Selection replacement (deletes selection before inserting)
     /// - Atomic undo (single undo step for entire operation)
+    /// - Reindenting multi-line text when `reindent_on_paste` is enabled
     pub fn paste(&mut self) {
+        if let Some(name) = self.pending_register.take() {
+            let reindent = self.config.editor.reindent_on_paste;
+            self.paste_from_register(name, reindent);
+            return;
+        }
+
         // Get content from clipboard (tries system first, falls back to internal)
         let text = match self.clipboard.paste() {
             Some(text) => text,
             None => return,
         };
 
-        // Use paste_text which handles line ending normalization
+        let reindent = self.config.editor.reindent_on_paste;
+        self.paste_clipboard_text(text, reindent);
+    }
+
+    /// Paste the clipboard content reindented to the cursor's indentation
+    ///
+    /// Like `paste`, but always shifts multi-line text so its base indentation
+    /// matches the line being pasted into, regardless of `reindent_on_paste`.
+    pub fn paste_and_indent(&mut self) {
+        if let Some(name) = self.pending_register.take() {
+            self.paste_from_register(name, true);
+            return;
+        }
+
+        let text = match self.clipboard.paste() {
+            Some(text) => text,
+            None => return,
+        };
+
+        self.last_paste = self
+            .insert_pasted_text(text, true)
+            .map(|(range, text)| self.paste_record(range, text));
+    }
+
+    /// Paste text taken from the clipboard, honouring how it was copied
+    ///
+    /// Block content is inserted as a rectangle when there is a single cursor
+    /// without a selection; everything else is pasted linearly.
+    fn paste_clipboard_text(&mut self, text: String, reindent: bool) {
+        let as_block = self.clipboard.kind() == ClipboardKind::Block && self.prompt.is_none() && {
+            let cursors = &self.active_state().cursors;
+            cursors.count() == 1 && cursors.primary().selection_range().is_none()
+        };
+
+        if as_block {
+            self.paste_block(&text);
+            self.last_paste = None;
+        } else {
+            self.last_paste = self
+                .insert_pasted_text(text, reindent)
+                .map(|(range, text)| self.paste_record(range, text));
+        }
+    }
+
+    /// Remember a paste made from the front of the kill ring
+    fn paste_record(&self, range: Range<usize>, text: String) -> PasteRecord {
+        PasteRecord {
+            buffer_id: self.active_buffer(),
+            range,
+            text,
+            ring_index: 0,
+        }
+    }
+
+    /// Replace the text just pasted with the previous kill ring entry
+    ///
+    /// Repeating the command keeps cycling back through the ring, wrapping
+    /// around to the most recent entry. Only valid straight after a paste (or
+    /// another yank-pop) with a single cursor, while the pasted text is still
+    /// in place.
+    pub fn yank_pop(&mut self) {
+        let record = match self.last_paste.take() {
+            Some(record) if record.buffer_id == self.active_buffer() => record,
+            _ => {
+                self.status_message = Some(t!("clipboard.yank_pop_no_paste").to_string());
+                return;
+            }
+        };
+
+        let still_in_place = {
+            let state = self.active_state_mut();
+            let cursor = state.cursors.primary();
+            state.cursors.count() == 1
+                && cursor.position == record.range.end
+                && cursor.selection_range().is_none()
+                && record.range.end <= state.buffer.len()
+                && state.get_text_range(record.range.start, record.range.end) == record.text
+        };
+        if !still_in_place {
+            self.status_message = Some(t!("clipboard.yank_pop_no_paste").to_string());
+            return;
+        }
+
+        let total = self.clipboard.kill_ring_len();
+        if total < 2 {
+            self.last_paste = Some(record);
+            self.status_message = Some(t!("clipboard.kill_ring_single").to_string());
+            return;
+        }
+        let ring_index = (record.ring_index + 1) % total;
+        let entry = self
+            .clipboard
+            .kill_ring_entry(ring_index)
+            .unwrap_or_default();
+        let text = self.active_state().buffer.line_ending().apply_to(entry);
+        let cursor_id = self.active_state().cursors.primary_id();
+        let events = vec![
+            Event::Delete {
+                range: record.range.clone(),
+                deleted_text: record.text,
+                cursor_id,
+            },
+            Event::Insert {
+                position: record.range.start,
+                text: text.clone(),
+                cursor_id,
+            },
+        ];
+        if let Some(bulk_edit) = self.apply_events_as_bulk_edit(events, "Yank Pop".to_string()) {
+            self.active_event_log_mut().append(bulk_edit);
+        }
+
+        self.last_paste = Some(PasteRecord {
+            buffer_id: record.buffer_id,
+            range: record.range.start..record.range.start + text.len(),
+            text,
+            ring_index,
+        });
+        self.status_message =
+            Some(t!("clipboard.yank_pop", index = ring_index + 1, total = total).to_string());
+    }
+
+    /// Paste rows as a rectangle starting at the primary cursor
+    ///
+    /// Each row is inserted at the cursor's visual column on successive
+    /// lines. Lines shorter than that column are padded with spaces, and lines
+    /// are appended when the block runs past the end of the buffer. The whole
+    /// paste is a single undo step.
+    fn paste_block(&mut self, text: &str) {
+        let normalized = text.replace("\r\n", "\n").replace('\r', "\n");
+
+        let events: Vec<Event> = {
+            let state = self.active_state();
+            let cursor_id = state.cursors.primary_id();
+            let position = state.cursors.primary().position;
+            let first_line = state.buffer.get_line_number(position);
+            let line_start = state.buffer.line_start_offset(first_line).unwrap_or(0);
+            let column = str_width(&String::from_utf8_lossy(
+                &state.buffer.slice_bytes(line_start..position),
+            ));
+            let line_ending = state.buffer.line_ending().as_str();
+
+            let mut inserts = Vec::new();
+            let mut appended = String::new();
+            for (i, row) in normalized.split('\n').enumerate() {
+                let line = first_line + i;
+                match (
+                    state.buffer.line_start_offset(line),
+                    line_content(&state.buffer, line),
+                ) {
+                    (Some(line_start), Some(content)) => {
+                        let width = str_width(&String::from_utf8_lossy(&content));
+                        let padding = column.saturating_sub(width);
+                        if row.is_empty() && padding == 0 {
+                            continue;
+                        }
+                        let offset = line_range_at_visual_columns(&state.buffer, line, 0, column)
+                            .map_or(line_start + content.len(), |range| range.end);
+                        inserts.push((offset, format!("{}{}", " ".repeat(padding), row)));
+                    }
+                    _ => {
+                        appended.push_str(line_ending);
+                        appended.push_str(&" ".repeat(column));
+                        appended.push_str(row);
+                    }
+                }
+            }
+
+            // Apply from the bottom up so earlier positions stay valid. Appended
+            // lines go first: anything later inserted at the same offset then
+            // lands in front of them.
+            let mut events = Vec::new();
+            if !appended.is_empty() {
+                events.push(Event::Insert {
+                    position: state.buffer.len(),
+                    text: appended,
+                    cursor_id,
+                });
+            }
+            for (position, text) in inserts.into_iter().rev() {
+                events.push(Event::Insert {
+                    position,
+                    text,
+                    cursor_id,
+                });
+            }
+            events
+        };
+
+        if events.len() > 1 {
+            if let Some(bulk_edit) = self.apply_events_as_bulk_edit(events, "Paste".to_string()) {
+                self.active_event_log_mut().append(bulk_edit);
+            }
+        } else if let Some(event) = events.into_iter().next() {
+            self.active_event_log_mut().append(event.clone());
+            self.apply_event_to_active_buffer(&event);
+        }
+
+        self.status_message = Some(t!("clipboard.pasted").to_string());
+    }
+
+    /// Handle a bracketed paste from the host terminal
+    ///
+    /// The pasted block arrives as one event, so it is inserted literally in a
+    /// single undo step rather than replayed as keystrokes that would trigger
+    /// auto-indent and auto-close on every line. It goes to whatever has
+    /// focus: the integrated terminal, the settings dialog, or the buffer.
+    pub fn handle_paste(&mut self, text: String) {
+        if self.is_terminal_mode() {
+            self.send_terminal_input(text.as_bytes());
+            return;
+        }
+
+        if self.is_settings_open() {
+            if let Some(dialog) = self
+                .settings_state
+                .as_mut()
+                .and_then(|settings| settings.entry_dialog_mut())
+            {
+                dialog.insert_str(&text);
+            }
+            return;
+        }
+
         self.paste_text(text);
     }
 
@@ -392,8 +885,20 @@ impl Editor {
     /// - Atomic undo (single undo step for entire operation)
     /// - Routing to prompt if one is open
     pub fn paste_text(&mut self, paste_text: String) {
+        self.insert_pasted_text(paste_text, false);
+    }
+
+    /// Shared implementation of `paste_text`, optionally reindenting the text
+    /// to each cursor's indentation
+    ///
+    /// With a single cursor, returns the range and text that were inserted.
+    fn insert_pasted_text(
+        &mut self,
+        paste_text: String,
+        reindent: bool,
+    ) -> Option<(Range<usize>, String)> {
         if paste_text.is_empty() {
-            return;
+            return None;
         }
 
         // Normalize line endings: first convert all to LF, then to buffer's format
@@ -405,16 +910,12 @@ impl Editor {
             prompt.insert_str(&normalized);
             self.update_prompt_suggestions();
             self.status_message = Some(t!("clipboard.pasted").to_string());
-            return;
+            return None;
         }
 
         // Convert to buffer's line ending format
         let buffer_line_ending = self.active_state().buffer.line_ending();
-        let paste_text = match buffer_line_ending {
-            crate::model::buffer::LineEnding::LF => normalized,
-            crate::model::buffer::LineEnding::CRLF => normalized.replace('\n', "\r\n"),
-            crate::model::buffer::LineEnding::CR => normalized.replace('\n', "\r"),
-        };
+        let tab_size = self.active_state().tab_size;
 
         let mut events = Vec::new();
 
@@ -434,7 +935,7 @@ impl Editor {
             .collect();
         cursor_data.sort_by_key(|(_, _, pos)| std::cmp::Reverse(*pos));
 
-        // Get deleted text for each selection
+        // Get deleted text and the text to insert for each selection
         let cursor_data_with_text: Vec<_> = {
             let state = self.active_state_mut();
             cursor_data
@@ -443,13 +944,30 @@ impl Editor {
                     let deleted_text = selection
                         .as_ref()
                         .map(|r| state.get_text_range(r.start, r.end));
-                    (cursor_id, selection, insert_position, deleted_text)
+                    let text = if reindent {
+                        let (target_indent, at_indent) =
+                            indent_at_position(&state.buffer, insert_position);
+                        reindent_text(&normalized, &target_indent, at_indent, tab_size)
+                    } else {
+                        normalized.clone()
+                    };
+                    (
+                        cursor_id,
+                        selection,
+                        insert_position,
+                        deleted_text,
+                        buffer_line_ending.apply_to(&text),
+                    )
                 })
                 .collect()
         };
 
         // Build events for each cursor
-        for (cursor_id, selection, insert_position, deleted_text) in cursor_data_with_text {
+        let single_cursor = cursor_data_with_text.len() == 1;
+        let mut pasted = None;
+        for (cursor_id, selection, insert_position, deleted_text, paste_text) in
+            cursor_data_with_text
+        {
             if let (Some(range), Some(text)) = (selection, deleted_text) {
                 events.push(Event::Delete {
                     range,
@@ -457,9 +975,15 @@ impl Editor {
                     cursor_id,
                 });
             }
+            if single_cursor {
+                pasted = Some((
+                    insert_position..insert_position + paste_text.len(),
+                    paste_text.clone(),
+                ));
+            }
             events.push(Event::Insert {
                 position: insert_position,
-                text: paste_text.clone(),
+                text: paste_text,
                 cursor_id,
             });
         }
@@ -476,6 +1000,7 @@ impl Editor {
         }
 
         self.status_message = Some(t!("clipboard.pasted").to_string());
+        pasted
     }
 
     /// Set clipboard content for testing purposes
@@ -498,7 +1023,8 @@ impl Editor {
         };
 
         // Use the same paste logic as the regular paste method
-        self.paste_text(paste_text);
+        let reindent = self.config.editor.reindent_on_paste;
+        self.paste_clipboard_text(paste_text, reindent);
     }
 
     /// Get clipboard content for testing purposes
@@ -776,3 +1302,38 @@ impl Editor {
         }
     }
 }
+
+/// Content of a line without its line ending, or None past the end of the buffer
+fn line_content(buffer: &Buffer, line: usize) -> Option<Vec<u8>> {
+    let mut content = buffer.get_line(line)?;
+    if content.last() == Some(&b'\n') {
+        content.pop();
+    }
+    if content.last() == Some(&b'\r') {
+        content.pop();
+    }
+    Some(content)
+}
+
+/// Indentation that pasted lines should take at `position`
+///
+/// Returns the whitespace to prefix each following line with, and whether the
+/// position sits within the line's leading whitespace (so that whitespace already
+/// indents the first pasted line).
+fn indent_at_position(buffer: &Buffer, position: usize) -> (String, bool) {
+    let line = buffer.get_line_number(position);
+    let line_start = buffer.line_start_offset(line).unwrap_or(0);
+    let content = line_content(buffer, line).unwrap_or_default();
+    let column = position.saturating_sub(line_start).min(content.len());
+
+    let before = &content[..column];
+    if before.iter().all(|b| *b == b' ' || *b == b'\t') {
+        return (String::from_utf8_lossy(before).into_owned(), true);
+    }
+    let indent: Vec<u8> = content
+        .iter()
+        .take_while(|b| **b == b' ' || **b == b'\t')
+        .copied()
+        .collect();
+    (String::from_utf8_lossy(&indent).into_owned(), false)
+}
@@ -166,8 +166,7 @@ impl Editor {
         self.buffers.insert(buffer_id, state);
 
         // Create an event log entry (required for many editor operations)
-        self.event_logs
-            .insert(buffer_id, crate::model::event::EventLog::new());
+        self.event_logs.insert(buffer_id, self.new_event_log());
 
         // Register with the active split so it appears in tabs
         let split_id = self.split_manager.active_split();
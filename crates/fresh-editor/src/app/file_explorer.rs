@@ -2,7 +2,7 @@ use anyhow::Result as AnyhowResult;
 use rust_i18n::t;
 
 use super::*;
-use crate::view::file_tree::TreeNode;
+use crate::view::file_tree::{GitStatusMap, TreeNode};
 use std::path::PathBuf;
 
 /// Get the parent directory path from a file tree node.
@@ -144,6 +144,7 @@ impl Editor {
             let sender = bridge.sender();
 
             runtime.spawn(async move {
+                let git_root = root_path.clone();
                 match FileTree::new(root_path, fs_manager).await {
                     Ok(mut tree) => {
                         let root_id = tree.root_id();
@@ -151,7 +152,12 @@ impl Editor {
                             tracing::warn!("Failed to expand root directory: {}", e);
                         }
 
-                        let view = FileTreeView::new(tree);
+                        let mut view = FileTreeView::new(tree);
+                        let git_status =
+                            tokio::task::spawn_blocking(move || GitStatusMap::load(&git_root))
+                                .await
+                                .unwrap_or_default();
+                        view.set_git_status(git_status);
                         let _ = sender.send(AsyncMessage::FileExplorerInitialized(view));
                     }
                     Err(e) => {
@@ -365,6 +371,18 @@ impl Editor {
                 }
             }
         }
+
+        // `git status` can be slow in large repos, so reload it off the UI thread
+        if let (Some(runtime), Some(bridge)) = (&self.tokio_runtime, &self.async_bridge) {
+            let sender = bridge.sender();
+            let git_root = self.working_dir.clone();
+            runtime.spawn(async move {
+                let git_status = tokio::task::spawn_blocking(move || GitStatusMap::load(&git_root))
+                    .await
+                    .unwrap_or_default();
+                let _ = sender.send(AsyncMessage::FileExplorerGitStatusLoaded(git_status));
+            });
+        }
     }
 
     pub fn file_explorer_new_file(&mut self) {
@@ -387,6 +405,7 @@ impl Editor {
                                     get_parent_node_id(explorer.tree(), selected_id, node.is_dir());
                                 let tree = explorer.tree_mut();
                                 let _ = runtime.block_on(tree.refresh_node(parent_id));
+                                explorer.navigate_to_path(&path_clone);
                                 self.set_status_message(
                                     t!("explorer.created_file", name = &filename).to_string(),
                                 );
@@ -440,6 +459,7 @@ impl Editor {
                                     get_parent_node_id(explorer.tree(), selected_id, node.is_dir());
                                 let tree = explorer.tree_mut();
                                 let _ = runtime.block_on(tree.refresh_node(parent_id));
+                                explorer.navigate_to_path(&path_clone);
                                 self.set_status_message(
                                     t!("explorer.created_dir", name = &dirname_clone).to_string(),
                                 );
@@ -520,7 +540,7 @@ impl Editor {
 
                             // After refresh, select the next best node:
                             // Try to stay at the same index, or select the last visible item
-                            let visible = explorer.tree().get_visible_nodes();
+                            let visible = explorer.visible_nodes();
                             if !visible.is_empty() {
                                 let new_index = if let Some(idx) = deleted_index {
                                     idx.min(visible.len().saturating_sub(1))
@@ -591,11 +611,24 @@ impl Editor {
             return;
         }
 
+        if new_name.contains(['/', '\\']) || new_name == "." || new_name == ".." {
+            self.set_status_message(t!("explorer.invalid_name", name = &new_name).to_string());
+            return;
+        }
+
         let new_path = original_path
             .parent()
             .map(|p| p.join(&new_name))
             .unwrap_or_else(|| original_path.clone());
 
+        // Never overwrite an existing entry (a case-only rename resolves to the same file)
+        let same_file =
+            std::fs::canonicalize(&new_path).ok() == std::fs::canonicalize(&original_path).ok();
+        if new_path.exists() && !same_file {
+            self.set_status_message(t!("explorer.already_exists", name = &new_name).to_string());
+            return;
+        }
+
         if let Some(runtime) = &self.tokio_runtime {
             let result =
                 runtime.block_on(async { tokio::fs::rename(&original_path, &new_path).await });
@@ -604,8 +637,18 @@ impl Editor {
                 Ok(_) => {
                     // Refresh the parent directory and select the renamed item
                     if let Some(explorer) = &mut self.file_explorer {
-                        if let Some(selected_id) = explorer.get_selected() {
-                            let parent_id = get_parent_node_id(explorer.tree(), selected_id, false);
+                        // Refresh the directory that held the renamed entry, which may not
+                        // be the parent of the current selection
+                        let parent_id = explorer
+                            .tree()
+                            .get_node_by_path(&original_path)
+                            .and_then(|node| node.parent)
+                            .or_else(|| {
+                                explorer.get_selected().map(|selected_id| {
+                                    get_parent_node_id(explorer.tree(), selected_id, false)
+                                })
+                            });
+                        if let Some(parent_id) = parent_id {
                             let tree = explorer.tree_mut();
                             let _ = runtime.block_on(tree.refresh_node(parent_id));
                         }
@@ -666,6 +709,26 @@ impl Editor {
         }
     }
 
+    /// Prompt for a name filter, applied to the explorer as the user types
+    pub fn file_explorer_start_filter(&mut self) {
+        let Some(explorer) = &self.file_explorer else {
+            return;
+        };
+        let current = explorer.filter().unwrap_or_default().to_string();
+        self.prompt = Some(crate::view::prompt::Prompt::with_initial_text(
+            t!("explorer.filter_prompt").to_string(),
+            PromptType::FileExplorerFilter,
+            current,
+        ));
+    }
+
+    /// Filter the explorer to entries whose names fuzzy-match `filter`
+    pub fn file_explorer_apply_filter(&mut self, filter: &str) {
+        if let Some(explorer) = &mut self.file_explorer {
+            explorer.set_filter(filter);
+        }
+    }
+
     pub fn file_explorer_toggle_hidden(&mut self) {
         if let Some(explorer) = &mut self.file_explorer {
             explorer.toggle_show_hidden();
@@ -297,6 +297,7 @@ impl Editor {
         if let Err(e) = self.open_file(&path) {
             self.set_status_message(t!("file.error_opening", error = e.to_string()).to_string());
         } else {
+            self.last_file_dialog_dir = path.parent().map(|p| p.to_path_buf());
             self.set_status_message(
                 t!("file.opened", path = path.display().to_string()).to_string(),
             );
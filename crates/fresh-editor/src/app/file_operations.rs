@@ -15,7 +15,7 @@ use std::path::{Path, PathBuf};
 use lsp_types::TextDocumentContentChangeEvent;
 use rust_i18n::t;
 
-use crate::model::event::{BufferId, EventLog};
+use crate::model::event::BufferId;
 use crate::services::lsp::manager::{detect_language, LspSpawnResult};
 use crate::state::EditorState;
 
@@ -30,6 +30,9 @@ impl Editor {
             .file_path()
             .map(|p| p.to_path_buf());
 
+        self.trim_trailing_whitespace_before_save();
+        self.insert_final_newline_before_save();
+
         match self.active_state_mut().buffer.save() {
             Ok(()) => self.finalize_save(path),
             Err(e) => {
@@ -68,6 +71,8 @@ impl Editor {
 
         // Update file modification time after save
         if let Some(ref p) = path {
+            // Saving recreates a file that was deleted externally
+            self.deleted_files.remove(p);
             if let Ok(metadata) = std::fs::metadata(p) {
                 if let Ok(mtime) = metadata.modified() {
                     self.file_mod_times.insert(p.clone(), mtime);
@@ -81,6 +86,8 @@ impl Editor {
         // Delete recovery file (buffer is now saved)
         let _ = self.delete_buffer_recovery(self.active_buffer());
 
+        self.persist_undo_history(self.active_buffer());
+
         // Emit control event
         if let Some(ref p) = path {
             self.emit_event(
@@ -160,12 +167,39 @@ impl Editor {
             &self.grammar_registry,
             &self.config.languages,
         )?;
+        self.apply_file_settings(&mut new_state, &path, true);
+
+        // Settings changed by hand for this buffer survive the reload
+        let old_state = self.active_state();
+        let overrides = old_state.setting_overrides;
+        let from_file = old_state.detected_indent_width.is_some() || old_state.editorconfig_indent;
+        if overrides.tab_size && !from_file {
+            new_state.tab_size = old_state.tab_size;
+            new_state.detected_indent_width = None;
+            new_state.editorconfig_indent = false;
+            new_state.setting_overrides.tab_size = true;
+        }
+        if overrides.use_tabs && !old_state.editorconfig_indent {
+            new_state.use_tabs = old_state.use_tabs;
+            new_state.setting_overrides.use_tabs = true;
+        }
+        if overrides.show_whitespace_tabs {
+            new_state.show_whitespace_tabs = old_state.show_whitespace_tabs;
+            new_state.setting_overrides.show_whitespace_tabs = true;
+        }
+        new_state.line_wrap = old_state.line_wrap;
+        new_state
+            .margins
+            .set_line_numbers(old_state.margins.show_line_numbers);
 
         // Restore cursor positions (clamped to valid range for new file size)
         let new_file_size = new_state.buffer.len();
         let mut restored_cursors = old_cursors;
         restored_cursors.map(|cursor| {
-            cursor.position = cursor.position.min(new_file_size);
+            // The content may have changed under the cursor, so keep it on a char boundary
+            cursor.position = new_state
+                .buffer
+                .snap_to_char_boundary(cursor.position.min(new_file_size));
             // Clear selection since the content may have changed
             cursor.clear_selection();
         });
@@ -186,8 +220,9 @@ impl Editor {
         }
 
         // Clear the undo/redo history for this buffer
+        let new_log = self.new_event_log();
         if let Some(event_log) = self.event_logs.get_mut(&buffer_id) {
-            *event_log = EventLog::new();
+            *event_log = new_log;
         }
 
         // Clear seen_byte_ranges so plugins get notified of all visible lines
@@ -220,11 +255,14 @@ impl Editor {
 
     /// Poll for file changes (called from main loop)
     ///
-    /// Checks modification times of open files to detect external changes.
+    /// Checks modification times of open files to detect external changes,
+    /// and flags open files that were deleted externally.
     /// Returns true if any file was changed (requires re-render).
     pub fn poll_file_changes(&mut self) -> bool {
-        // Skip if auto-revert is disabled
-        if !self.auto_revert_enabled {
+        let detect_deleted = self.config.editor.detect_deleted_files;
+
+        // Skip if neither auto-revert nor deleted-file detection is enabled
+        if !self.auto_revert_enabled && !detect_deleted {
             return false;
         }
 
@@ -254,13 +292,34 @@ impl Editor {
         for path in files_to_check {
             // Get current mtime
             let current_mtime = match std::fs::metadata(&path) {
-                Ok(meta) => match meta.modified() {
-                    Ok(mtime) => mtime,
-                    Err(_) => continue,
-                },
-                Err(_) => continue, // File might have been deleted
+                Ok(meta) => {
+                    // A file flagged as deleted is back on disk
+                    if self.deleted_files.remove(&path) {
+                        any_changed = true;
+                    }
+                    match meta.modified() {
+                        Ok(mtime) => mtime,
+                        Err(_) => continue,
+                    }
+                }
+                Err(e) => {
+                    // Only flag files that existed on disk when we last saw them,
+                    // not new buffers whose file hasn't been written yet
+                    if detect_deleted
+                        && e.kind() == std::io::ErrorKind::NotFound
+                        && self.file_mod_times.contains_key(&path)
+                        && self.mark_file_deleted(&path)
+                    {
+                        any_changed = true;
+                    }
+                    continue;
+                }
             };
 
+            if !self.auto_revert_enabled {
+                continue;
+            }
+
             // Check if mtime has changed
             if let Some(&stored_mtime) = self.file_mod_times.get(&path) {
                 if current_mtime != stored_mtime {
@@ -281,6 +340,27 @@ impl Editor {
         any_changed
     }
 
+    /// Flag an open file as deleted on disk, keeping its buffer content so a
+    /// save recreates it. Returns true if the file was not already flagged.
+    fn mark_file_deleted(&mut self, path: &Path) -> bool {
+        if !self.deleted_files.insert(path.to_path_buf()) {
+            return false;
+        }
+        tracing::info!("File deleted externally: {}", path.display());
+        let name = path
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string();
+        self.status_message = Some(t!("file.deleted_on_disk", name = name).to_string());
+        true
+    }
+
+    /// Whether an open file has been deleted outside the editor
+    pub fn is_file_deleted_on_disk(&self, path: &Path) -> bool {
+        self.deleted_files.contains(path)
+    }
+
     /// Poll for file tree changes (called from main loop)
     ///
     /// Checks modification times of expanded directories to detect new/deleted files.
@@ -647,8 +727,9 @@ impl Editor {
         }
 
         // Clear the undo/redo history for this buffer
+        let new_log = self.new_event_log();
         if let Some(event_log) = self.event_logs.get_mut(&buffer_id) {
-            *event_log = EventLog::new();
+            *event_log = new_log;
         }
 
         // Clear seen_byte_ranges so plugins get notified of all visible lines
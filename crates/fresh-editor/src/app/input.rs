@@ -1,4 +1,6 @@
 use super::*;
+use crate::primitives::sort_lines::LineOrder;
+use crate::services::clipboard::is_register_name;
 use crate::services::plugins::hooks::HookArgs;
 use anyhow::Result as AnyhowResult;
 use rust_i18n::t;
@@ -82,6 +84,19 @@ impl Editor {
                 // Recalculate context now that popup is gone
                 context = self.get_key_context();
             }
+        } else if matches!(
+            context,
+            crate::input::keybindings::KeyContext::Prompt
+                | crate::input::keybindings::KeyContext::Menu
+        ) && self
+            .active_state()
+            .popups
+            .top()
+            .is_some_and(|p| p.transient)
+        {
+            // A transient popup under a prompt or menu (e.g. context help) doesn't
+            // take the key, but it goes away once the user keeps typing
+            self.hide_popup();
         }
 
         // Try hierarchical modal input dispatch first (Settings, Menu, Prompt, Popup)
@@ -126,6 +141,7 @@ impl Editor {
                     // This could be the start of a chord - add to state and wait
                     tracing::debug!("Potential chord prefix in editor mode");
                     self.chord_state.push((code, modifiers));
+                    self.chord_started_at = Some(self.time_source.now());
                     return Ok(());
                 }
 
@@ -181,6 +197,7 @@ impl Editor {
                 // Partial match - add to chord state and wait for more keys
                 tracing::debug!("Partial chord match - waiting for next key");
                 self.chord_state.push((code, modifiers));
+                self.chord_started_at = Some(self.time_source.now());
                 return Ok(());
             }
             crate::input::keybindings::ChordResolution::NoMatch => {
@@ -225,21 +242,36 @@ impl Editor {
 
         // Record action to macro if recording
         self.record_macro_action(&action);
+        self.record_last_edit(&action);
+
+        // Consecutive cuts and line kills accumulate into a single kill ring entry
+        let is_kill = match action {
+            Action::Cut => true,
+            Action::DeleteToLineEnd | Action::DeleteToLineStart => self.line_kills_to_clipboard(),
+            _ => false,
+        };
+        self.appending_kill = is_kill && self.last_action_was_kill;
+        self.last_action_was_kill = is_kill;
+
+        // Yank-pop only applies straight after a paste or another yank-pop
+        if !matches!(
+            action,
+            Action::Paste | Action::PasteAndIndent | Action::YankPop
+        ) {
+            self.last_paste = None;
+        }
 
         match action {
             Action::Quit => self.quit(),
             Action::ForceQuit => {
                 self.should_quit = true;
             }
+            Action::SaveSession => self.save_session_command(),
+            Action::RestoreSession => self.restore_session_command(),
             Action::Save => {
                 // Check if buffer has a file path - if not, redirect to SaveAs
                 if self.active_state().buffer.file_path().is_none() {
-                    self.start_prompt_with_initial_text(
-                        t!("file.save_as_prompt").to_string(),
-                        PromptType::SaveFileAs,
-                        String::new(),
-                    );
-                    self.init_file_open_state();
+                    self.prompt_save_as();
                 } else if self.check_save_conflict().is_some() {
                     // Check if file was modified externally since we opened/saved it
                     self.start_prompt(
@@ -250,27 +282,7 @@ impl Editor {
                     self.save()?;
                 }
             }
-            Action::SaveAs => {
-                // Get current filename as default suggestion
-                let current_path = self
-                    .active_state()
-                    .buffer
-                    .file_path()
-                    .map(|p| {
-                        // Make path relative to working_dir if possible
-                        p.strip_prefix(&self.working_dir)
-                            .unwrap_or(p)
-                            .to_string_lossy()
-                            .to_string()
-                    })
-                    .unwrap_or_default();
-                self.start_prompt_with_initial_text(
-                    t!("file.save_as_prompt").to_string(),
-                    PromptType::SaveFileAs,
-                    current_path,
-                );
-                self.init_file_open_state();
-            }
+            Action::SaveAs => self.prompt_save_as(),
             Action::Open => {
                 self.start_prompt(t!("file.open_prompt").to_string(), PromptType::OpenFile);
                 self.prefill_open_file_prompt();
@@ -287,6 +299,28 @@ impl Editor {
                 t!("file.goto_line_prompt").to_string(),
                 PromptType::GotoLine,
             ),
+            Action::GotoPercent(percent) => {
+                self.goto_percent(percent);
+                self.set_status_message(t!("goto.jumped_percent", percent = percent).to_string());
+            }
+            Action::CursorToViewportTop
+            | Action::CursorToViewportMiddle
+            | Action::CursorToViewportBottom => {
+                self.apply_action_as_events(action)?;
+                // The target row may sit inside the scroll margin; keep the view where it is
+                let active_split = self.split_manager.active_split();
+                if let Some(view_state) = self.split_view_states.get_mut(&active_split) {
+                    view_state.viewport.set_skip_ensure_visible();
+                }
+            }
+            Action::ScrollLeft => self.scroll_active_split_horizontally(-1),
+            Action::ScrollRight => self.scroll_active_split_horizontally(1),
+            Action::ScrollCursorToTop => {
+                self.scroll_cursor_line_to(crate::input::actions::ViewportRow::Top);
+            }
+            Action::ScrollCursorToBottom => {
+                self.scroll_cursor_line_to(crate::input::actions::ViewportRow::Bottom);
+            }
             Action::New => {
                 self.new_buffer();
             }
@@ -299,7 +333,10 @@ impl Editor {
             }
             Action::Revert => {
                 // Check if buffer has unsaved changes - prompt for confirmation
-                if self.active_state().buffer.is_modified() {
+                if self.active_state().buffer.file_path().is_none() {
+                    // Nothing to reload from; don't ask to discard changes first
+                    self.set_status_message(t!("status.no_file_to_revert").to_string());
+                } else if self.active_state().buffer.is_modified() {
                     let revert_key = t!("prompt.key.revert").to_string();
                     let cancel_key = t!("prompt.key.cancel").to_string();
                     self.start_prompt(
@@ -320,6 +357,14 @@ impl Editor {
                     }
                 }
             }
+            Action::ForceRevert => {
+                // Discard unsaved changes without asking
+                if let Err(e) = self.revert_file() {
+                    self.set_status_message(
+                        t!("error.failed_to_revert", error = e.to_string()).to_string(),
+                    );
+                }
+            }
             Action::ToggleAutoRevert => {
                 self.toggle_auto_revert();
             }
@@ -352,6 +397,17 @@ impl Editor {
                 self.copy_selection()
             }
             Action::CopyWithTheme(theme) => self.copy_selection_with_theme(&theme),
+            Action::ExpandSelection => {
+                let before = *self.active_state().cursors.primary();
+                self.apply_action_as_events(action)?;
+                self.expand_selection_to_text_objects(before);
+            }
+            Action::SelectTextObject(name) => {
+                self.match_text_object(&name, fresh_core::api::TextObjectOperation::Select)
+            }
+            Action::DeleteTextObject(name) => {
+                self.match_text_object(&name, fresh_core::api::TextObjectOperation::Delete)
+            }
             Action::Cut => {
                 if self.is_editing_disabled() {
                     self.set_status_message(t!("buffer.editing_disabled").to_string());
@@ -359,6 +415,16 @@ impl Editor {
                 }
                 self.cut_selection()
             }
+            Action::ExtractSelectionToNewBuffer => self.extract_selection_to_new_buffer(false),
+            Action::ExportHtml => self.export_html(false),
+            Action::ExportHtmlWithLineNumbers => self.export_html(true),
+            Action::MoveSelectionToNewBuffer => {
+                if self.is_editing_disabled() {
+                    self.set_status_message(t!("buffer.editing_disabled").to_string());
+                    return Ok(());
+                }
+                self.extract_selection_to_new_buffer(true)
+            }
             Action::Paste => {
                 if self.is_editing_disabled() {
                     self.set_status_message(t!("buffer.editing_disabled").to_string());
@@ -366,6 +432,46 @@ impl Editor {
                 }
                 self.paste()
             }
+            Action::PasteAndIndent => {
+                if self.is_editing_disabled() {
+                    self.set_status_message(t!("buffer.editing_disabled").to_string());
+                    return Ok(());
+                }
+                self.paste_and_indent()
+            }
+            Action::YankPop => {
+                if self.is_editing_disabled() {
+                    self.set_status_message(t!("buffer.editing_disabled").to_string());
+                    return Ok(());
+                }
+                self.yank_pop()
+            }
+            Action::CopyToRegister(name) => {
+                if !is_register_name(name) {
+                    self.set_status_message(t!("register.must_be_letter_or_digit").to_string());
+                    return Ok(());
+                }
+                self.pending_register = Some(name);
+                self.copy_selection()
+            }
+            Action::PasteFromRegister(name) => {
+                if self.is_editing_disabled() {
+                    self.set_status_message(t!("buffer.editing_disabled").to_string());
+                    return Ok(());
+                }
+                if !is_register_name(name) {
+                    self.set_status_message(t!("register.must_be_letter_or_digit").to_string());
+                    return Ok(());
+                }
+                self.pending_register = Some(name);
+                self.paste()
+            }
+            Action::PromptSelectRegister => {
+                self.start_prompt(
+                    t!("register.select_prompt").to_string(),
+                    PromptType::SelectRegister,
+                );
+            }
             Action::YankWordForward => self.yank_word_forward(),
             Action::YankWordBackward => self.yank_word_backward(),
             Action::YankToLineEnd => self.yank_to_line_end(),
@@ -376,12 +482,16 @@ impl Editor {
             Action::Redo => {
                 self.handle_redo();
             }
+            Action::RepeatLastEdit => self.repeat_last_edit(),
             Action::ShowHelp => {
-                self.open_help_manual();
+                self.open_help();
             }
             Action::ShowKeyboardShortcuts => {
                 self.open_keyboard_shortcuts();
             }
+            Action::ShowKeybindings => {
+                self.start_show_keybindings_prompt();
+            }
             Action::ShowWarnings => {
                 self.show_warnings_popup();
             }
@@ -420,20 +530,88 @@ impl Editor {
                 );
             }
             Action::ToggleLineWrap => {
-                self.config.editor.line_wrap = !self.config.editor.line_wrap;
+                // A buffer with its own setting (e.g. a per-language default)
+                // toggles just that buffer; otherwise the global setting flips
+                let buffer_id = self.active_buffer();
+                let enabled = match self
+                    .buffers
+                    .get_mut(&buffer_id)
+                    .and_then(|state| state.line_wrap.as_mut())
+                {
+                    Some(wrap) => {
+                        *wrap = !*wrap;
+                        *wrap
+                    }
+                    None => {
+                        self.config.editor.line_wrap = !self.config.editor.line_wrap;
+                        self.config.editor.line_wrap
+                    }
+                };
 
                 // Update all viewports to reflect the new line wrap setting
-                for view_state in self.split_view_states.values_mut() {
-                    view_state.viewport.line_wrap_enabled = self.config.editor.line_wrap;
-                }
+                self.resolve_split_line_wrap();
 
-                let state = if self.config.editor.line_wrap {
+                let state = if enabled {
                     t!("view.state_enabled").to_string()
                 } else {
                     t!("view.state_disabled").to_string()
                 };
                 self.set_status_message(t!("view.line_wrap_state", state = state).to_string());
             }
+            Action::ToggleIndentGuides => {
+                self.config.editor.show_indent_guides = !self.config.editor.show_indent_guides;
+
+                let state = if self.config.editor.show_indent_guides {
+                    t!("view.state_enabled").to_string()
+                } else {
+                    t!("view.state_disabled").to_string()
+                };
+                self.set_status_message(t!("view.indent_guides_state", state = state).to_string());
+            }
+            Action::ToggleRenderWhitespace => {
+                self.config.editor.render_whitespace = !self.config.editor.render_whitespace;
+
+                let state = if self.config.editor.render_whitespace {
+                    t!("view.state_enabled").to_string()
+                } else {
+                    t!("view.state_disabled").to_string()
+                };
+                self.set_status_message(
+                    t!("view.render_whitespace_state", state = state).to_string(),
+                );
+            }
+            Action::ToggleRenderTrailingWhitespace => {
+                self.config.editor.render_trailing_whitespace =
+                    !self.config.editor.render_trailing_whitespace;
+
+                let state = if self.config.editor.render_trailing_whitespace {
+                    t!("view.state_enabled").to_string()
+                } else {
+                    t!("view.state_disabled").to_string()
+                };
+                self.set_status_message(
+                    t!("view.render_trailing_whitespace_state", state = state).to_string(),
+                );
+            }
+            Action::ToggleStickyScroll => {
+                self.config.editor.sticky_scroll = !self.config.editor.sticky_scroll;
+
+                let state = if self.config.editor.sticky_scroll {
+                    t!("view.state_enabled").to_string()
+                } else {
+                    t!("view.state_disabled").to_string()
+                };
+                self.set_status_message(t!("view.sticky_scroll_state", state = state).to_string());
+            }
+            Action::ToggleInsertMode => {
+                self.overwrite_mode = !self.overwrite_mode;
+                let msg = if self.overwrite_mode {
+                    t!("status.overwrite_mode")
+                } else {
+                    t!("status.insert_mode")
+                };
+                self.set_status_message(msg.to_string());
+            }
             Action::ToggleComposeMode => {
                 self.handle_toggle_compose_mode();
             }
@@ -493,6 +671,8 @@ impl Editor {
             Action::LspSignatureHelp => {
                 self.request_signature_help()?;
             }
+            Action::LspFormat => self.request_formatting(),
+            Action::LspDocumentSymbols => self.request_document_symbols(),
             Action::LspCodeActions => {
                 self.request_code_actions()?;
             }
@@ -549,6 +729,30 @@ impl Editor {
                     false,
                 );
             }
+            Action::ReplaceAll => {
+                // Replace every match of the current search, asking for the
+                // search term first when there is none
+                self.search_confirm_each = false;
+                match self
+                    .search_state
+                    .as_ref()
+                    .map(|s| (s.query.clone(), s.use_regex))
+                {
+                    Some((search, use_regex)) => {
+                        // Match the search the same way it was run
+                        self.search_use_regex = use_regex;
+                        self.start_prompt(
+                            t!("replace.prompt", search = &search).to_string(),
+                            PromptType::Replace { search },
+                        )
+                    }
+                    None => self.start_search_prompt(
+                        t!("file.replace_prompt").to_string(),
+                        PromptType::ReplaceSearch,
+                        false,
+                    ),
+                }
+            }
             Action::QueryReplace => {
                 // Enable confirm mode by default for query-replace
                 self.search_confirm_each = true;
@@ -558,6 +762,12 @@ impl Editor {
                     false,
                 );
             }
+            Action::ProjectSearch => {
+                self.start_prompt(
+                    t!("search.project_prompt").to_string(),
+                    PromptType::ProjectSearch,
+                );
+            }
             Action::FindInSelection => {
                 self.start_search_prompt(
                     t!("file.search_prompt").to_string(),
@@ -577,6 +787,10 @@ impl Editor {
             Action::FindSelectionPrevious => {
                 self.find_selection_previous();
             }
+            Action::ClearSearchHighlight => {
+                self.clear_search_highlight();
+                self.set_status_message(t!("search.highlights_cleared").to_string());
+            }
             Action::AddCursorNextMatch => self.add_cursor_at_next_match(),
             Action::AddCursorAbove => self.add_cursor_above(),
             Action::AddCursorBelow => self.add_cursor_below(),
@@ -621,10 +835,16 @@ impl Editor {
             Action::PrevSplit => self.prev_split(),
             Action::IncreaseSplitSize => self.adjust_split_size(0.05),
             Action::DecreaseSplitSize => self.adjust_split_size(-0.05),
+            Action::EqualizeSplits => self.equalize_splits(),
+            Action::MoveBufferToNextSplit => self.move_buffer_to_adjacent_split(true),
+            Action::MoveBufferToPrevSplit => self.move_buffer_to_adjacent_split(false),
+            Action::SwapBufferWithNextSplit => self.swap_buffer_with_next_split(),
             Action::ToggleMaximizeSplit => self.toggle_maximize_split(),
             Action::ToggleFileExplorer => self.toggle_file_explorer(),
             Action::ToggleMenuBar => self.toggle_menu_bar(),
             Action::ToggleTabBar => self.toggle_tab_bar(),
+            Action::ToggleCursorDetails => self.toggle_cursor_details(),
+            Action::ShowWordCount => self.show_word_count(),
             Action::ToggleLineNumbers => self.toggle_line_numbers(),
             Action::ToggleMouseCapture => self.toggle_mouse_capture(),
             Action::ToggleMouseHover => self.toggle_mouse_hover(),
@@ -648,6 +868,7 @@ impl Editor {
             Action::ToggleIndentationStyle => {
                 if let Some(state) = self.buffers.get_mut(&self.active_buffer()) {
                     state.use_tabs = !state.use_tabs;
+                    state.setting_overrides.use_tabs = true;
                     let status = if state.use_tabs {
                         "Indentation: Tabs"
                     } else {
@@ -656,9 +877,12 @@ impl Editor {
                     self.set_status_message(status.to_string());
                 }
             }
+            Action::ConvertIndentationToSpaces => self.convert_indentation(false),
+            Action::ConvertIndentationToTabs => self.convert_indentation(true),
             Action::ToggleTabIndicators => {
                 if let Some(state) = self.buffers.get_mut(&self.active_buffer()) {
                     state.show_whitespace_tabs = !state.show_whitespace_tabs;
+                    state.setting_overrides.show_whitespace_tabs = true;
                     let status = if state.show_whitespace_tabs {
                         "Tab indicators: Visible"
                     } else {
@@ -682,9 +906,12 @@ impl Editor {
             Action::FileExplorerNewDirectory => self.file_explorer_new_directory(),
             Action::FileExplorerDelete => self.file_explorer_delete(),
             Action::FileExplorerRename => self.file_explorer_rename(),
+            Action::FileExplorerFilter => self.file_explorer_start_filter(),
             Action::FileExplorerToggleHidden => self.file_explorer_toggle_hidden(),
             Action::FileExplorerToggleGitignored => self.file_explorer_toggle_gitignored(),
             Action::RemoveSecondaryCursors => {
+                // Escape also dismisses the highlights left by a finished search
+                self.clear_search_highlight();
                 // Convert action to events and apply them
                 if let Some(events) = self.action_to_events(Action::RemoveSecondaryCursors) {
                     // Wrap in batch for atomic undo
@@ -747,8 +974,7 @@ impl Editor {
                     self.config.active_keybinding_map = map_name.clone().into();
 
                     // Reload the keybinding resolver with the new map
-                    self.keybindings =
-                        crate::input::keybindings::KeybindingResolver::new(&self.config);
+                    self.rebuild_keybindings();
 
                     self.set_status_message(
                         t!("view.keybindings_switched", map = map_name).to_string(),
@@ -766,6 +992,21 @@ impl Editor {
             Action::ToggleComment => {
                 self.toggle_comment();
             }
+            Action::AlignOnDelimiter => {
+                if self.active_state().cursors.primary().anchor.is_some() {
+                    self.start_prompt(
+                        t!("lines.align_prompt").to_string(),
+                        PromptType::AlignOnDelimiter,
+                    );
+                } else {
+                    self.set_status_message(t!("lines.align_needs_selection").to_string());
+                }
+            }
+            Action::SortLines => self.sort_lines(LineOrder::Ascending),
+            Action::SortLinesDescending => self.sort_lines(LineOrder::Descending),
+            Action::SortLinesCaseInsensitive => self.sort_lines(LineOrder::CaseInsensitive),
+            Action::UniqueLines => self.unique_lines(false),
+            Action::UniqueAdjacentLines => self.unique_lines(true),
             Action::GoToMatchingBracket => {
                 self.goto_matching_bracket();
             }
@@ -775,6 +1016,12 @@ impl Editor {
             Action::JumpToPreviousError => {
                 self.jump_to_previous_error();
             }
+            Action::QuickfixNext => {
+                self.quickfix_next();
+            }
+            Action::QuickfixPrev => {
+                self.quickfix_prev();
+            }
             Action::SetBookmark(key) => {
                 self.set_bookmark(key);
             }
@@ -911,6 +1158,16 @@ impl Editor {
                     self.set_status_message(t!("status.no_macro_recorded").to_string());
                 }
             }
+            Action::PromptPlayLastMacroTimes => {
+                if self.last_macro_register.is_some() {
+                    self.start_prompt(
+                        t!("macro.times_prompt").to_string(),
+                        PromptType::PlayLastMacroTimes,
+                    );
+                } else {
+                    self.set_status_message(t!("status.no_macro_recorded").to_string());
+                }
+            }
             Action::PromptSetBookmark => {
                 self.start_prompt("Set bookmark (0-9): ".to_string(), PromptType::SetBookmark);
             }
@@ -936,8 +1193,9 @@ impl Editor {
                             self.active_event_log_mut().append(bulk_edit);
                         }
                     } else {
+                        let now = self.time_source.now();
                         for event in events {
-                            self.active_event_log_mut().append(event.clone());
+                            self.active_event_log_mut().append_typed(event.clone(), now);
                             self.apply_event_to_active_buffer(&event);
                         }
                     }
@@ -1200,7 +1458,7 @@ impl Editor {
             {
                 // Scroll the file explorer
                 if let Some(explorer) = &mut self.file_explorer {
-                    let visible = explorer.tree().get_visible_nodes();
+                    let visible = explorer.visible_nodes();
                     if visible.is_empty() {
                         return Ok(());
                     }
@@ -1300,6 +1558,23 @@ impl Editor {
         Ok(())
     }
 
+    /// Scroll the active split horizontally by `columns` without moving the cursor
+    pub(super) fn scroll_active_split_horizontally(&mut self, columns: isize) {
+        let buffer_id = self.active_buffer();
+        if self.is_composite_buffer(buffer_id) {
+            return;
+        }
+        let active_split = self.split_manager.active_split();
+        if let (Some(state), Some(view_state)) = (
+            self.buffers.get_mut(&buffer_id),
+            self.split_view_states.get_mut(&active_split),
+        ) {
+            view_state
+                .viewport
+                .scroll_horizontal(&mut state.buffer, columns);
+        }
+    }
+
     /// Handle scrollbar drag with relative movement (when dragging from thumb)
     pub(super) fn handle_scrollbar_drag_relative(
         &mut self,
@@ -2137,7 +2412,7 @@ impl Editor {
             self.config.active_keybinding_map = map_name.to_string().into();
 
             // Reload the keybinding resolver with the new map
-            self.keybindings = crate::input::keybindings::KeybindingResolver::new(&self.config);
+            self.rebuild_keybindings();
 
             // Persist to config file
             self.save_keybinding_map_to_config();
@@ -2216,13 +2491,9 @@ impl Editor {
         use crate::config::CursorStyle;
 
         if let Some(style) = CursorStyle::parse(style_name) {
-            // Update the config in memory
+            // Update the config in memory (written to the terminal on the next render)
             self.config.editor.cursor_style = style;
 
-            // Apply the cursor style to the terminal
-            use std::io::stdout;
-            let _ = crossterm::execute!(stdout(), style.to_crossterm_style());
-
             // Persist to config file
             self.save_cursor_style_to_config();
 
@@ -2467,6 +2738,101 @@ impl Editor {
         }
     }
 
+    /// Start the keybindings prompt listing every binding active in the current context
+    fn start_show_keybindings_prompt(&mut self) {
+        // Capture the context before the prompt itself becomes the active context
+        let context = self.get_key_context();
+
+        let suggestions: Vec<crate::input::commands::Suggestion> = self
+            .keybindings
+            .get_bindings_for_context(context)
+            .into_iter()
+            .map(
+                |(category, key, action)| crate::input::commands::Suggestion {
+                    text: action,
+                    description: Some(category),
+                    value: None,
+                    disabled: false,
+                    keybinding: Some(key),
+                    source: None,
+                },
+            )
+            .collect();
+
+        self.start_prompt_with_suggestions(
+            t!(
+                "prompt.show_keybindings",
+                context = context.to_when_clause()
+            )
+            .to_string(),
+            PromptType::ShowKeybindings,
+            suggestions,
+        );
+    }
+
+    /// Open help for the current context
+    ///
+    /// Inside a prompt, popup or menu this lists the keys of that context;
+    /// during normal editing it opens the manual.
+    pub(crate) fn open_help(&mut self) {
+        if self.menu_state.active_menu.is_some()
+            || self.prompt.is_some()
+            || self.active_state().popups.is_visible()
+        {
+            self.show_context_help();
+        } else {
+            self.open_help_manual();
+        }
+    }
+
+    /// Show a dismissible help popup listing the keys of the current context
+    ///
+    /// Unlike the keybindings prompt this doesn't take focus: while a prompt or
+    /// menu is open, typing keeps going to it and the popup goes away on the
+    /// next key press.
+    fn show_context_help(&mut self) {
+        use crate::view::popup::{Popup, PopupPosition};
+        use ratatui::style::Style;
+
+        let context = self.get_key_context();
+        let category = context.to_when_clause();
+
+        let bindings: Vec<(String, String)> = self
+            .keybindings
+            .get_own_bindings_for_context(context)
+            .into_iter()
+            .map(|(_, key, action)| (key, action))
+            .collect();
+
+        if bindings.is_empty() {
+            self.set_status_message(t!("help.context_empty", context = category).to_string());
+            return;
+        }
+
+        let key_width = bindings
+            .iter()
+            .map(|(key, _)| key.chars().count())
+            .max()
+            .unwrap_or(0);
+        let lines: Vec<String> = bindings
+            .iter()
+            .map(|(key, action)| format!("{:<width$}  {}", key, action, width = key_width))
+            .collect();
+
+        let mut popup = Popup::text(lines, &self.theme);
+        popup.title = Some(t!("help.context_title", context = category).to_string());
+        popup.transient = true;
+        popup.position = PopupPosition::Centered;
+        popup.width = 60;
+        popup.max_height = 20;
+        popup.border_style = Style::default().fg(self.theme.popup_border_fg);
+        popup.background_style = Style::default().bg(self.theme.popup_bg);
+
+        if let Some(state) = self.buffers.get_mut(&self.active_buffer()) {
+            state.popups.show(popup);
+        }
+    }
+
     /// Switch to a tab by its BufferId
     pub(crate) fn switch_to_tab(&mut self, buffer_id: BufferId) {
         // Verify the buffer exists and is open in the current split
@@ -2536,7 +2902,18 @@ impl Editor {
         // Cancel any pending LSP requests since the text is changing
         self.cancel_pending_lsp_requests();
 
-        if let Some(events) = self.action_to_events(Action::InsertChar(c)) {
+        let events = if self.overwrite_mode {
+            // Replacing a character is a delete plus an insert; applied below as
+            // one bulk edit so a single undo restores it
+            Some(crate::input::actions::overwrite_char_events(
+                self.active_state_mut(),
+                c,
+            ))
+        } else {
+            self.action_to_events(Action::InsertChar(c))
+        };
+
+        if let Some(events) = events {
             if events.len() > 1 {
                 // Multi-cursor: use optimized bulk edit (O(n) instead of O(n²))
                 let description = format!("Insert '{}'", c);
@@ -2545,9 +2922,10 @@ impl Editor {
                     self.active_event_log_mut().append(bulk_edit);
                 }
             } else {
-                // Single cursor - apply normally
+                // Single cursor - apply normally, grouping runs of typing for undo
+                let now = self.time_source.now();
                 for event in events {
-                    self.active_event_log_mut().append(event.clone());
+                    self.active_event_log_mut().append_typed(event.clone(), now);
                     self.apply_event_to_active_buffer(&event);
                 }
             }
@@ -2585,6 +2963,8 @@ impl Editor {
         let is_editing_action = matches!(
             action,
             Action::InsertNewline
+                | Action::OpenLineBelow
+                | Action::OpenLineAbove
                 | Action::InsertTab
                 | Action::DeleteForward
                 | Action::DeleteWordBackward
@@ -2592,6 +2972,7 @@ impl Editor {
                 | Action::DeleteLine
                 | Action::DedentSelection
                 | Action::ToggleComment
+                | Action::AlignOnDelimiter
         );
 
         if is_editing_action && self.is_editing_disabled() {
@@ -2599,7 +2980,15 @@ impl Editor {
             return Ok(());
         }
 
+        let kills_line = matches!(action, Action::DeleteToLineEnd | Action::DeleteToLineStart)
+            && self.line_kills_to_clipboard();
+        let kills_backward = action == Action::DeleteToLineStart;
+
         if let Some(events) = self.action_to_events(action) {
+            if kills_line {
+                self.kill_deleted_text(&events, kills_backward);
+            }
+
             if events.len() > 1 {
                 // Check if this batch contains buffer modifications
                 let has_buffer_mods = events
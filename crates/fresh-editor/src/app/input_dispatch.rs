@@ -72,6 +72,19 @@ impl Editor {
             return Some(result);
         }
 
+        // Help is available from every modal context, before the modal's own
+        // handler gets a chance to consume the key
+        if self.menu_state.active_menu.is_some()
+            || self.prompt.is_some()
+            || self.active_state().popups.is_visible()
+        {
+            let action = self.keybindings.resolve(event, self.get_key_context());
+            if matches!(action, Action::ShowHelp) {
+                self.open_help();
+                return Some(InputResult::Consumed);
+            }
+        }
+
         // Menu is next
         if self.menu_state.active_menu.is_some() {
             let all_menus: Vec<crate::config::Menu> = self
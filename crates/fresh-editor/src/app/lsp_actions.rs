@@ -44,6 +44,9 @@ impl Editor {
             return;
         }
 
+        // A new server never answers requests sent to the old one
+        self.plugin_lsp_timed_out.clear();
+
         // Re-send didOpen for all buffers of this language
         self.reopen_buffers_for_language(&language);
     }
@@ -17,6 +17,7 @@ use std::time::{Duration, Instant};
 
 use lsp_types::TextDocumentContentChangeEvent;
 
+use crate::input::quickfix::QuickfixItem;
 use crate::model::event::{BufferId, Event};
 use crate::primitives::word_navigation::{find_word_end, find_word_start};
 use crate::services::lsp::manager::detect_language;
@@ -192,6 +193,11 @@ impl Editor {
             return Ok(());
         }
 
+        if self.config.editor.peek_lsp_locations {
+            self.show_location_peek(t!("lsp.peek_definition").to_string(), &locations);
+            return Ok(());
+        }
+
         // For now, just jump to the first location
         let location = &locations[0];
 
@@ -227,6 +233,7 @@ impl Editor {
                     state.apply(&event);
                 }
             }
+            self.reveal_cursor_after_jump();
 
             self.status_message = Some(
                 t!(
@@ -243,6 +250,113 @@ impl Editor {
         Ok(())
     }
 
+    /// Show LSP result locations in a peek popup instead of jumping to them.
+    ///
+    /// Each location gets a header line followed by its surrounding lines, read
+    /// from the open buffer if there is one and from disk otherwise. Every list
+    /// item remembers the location it stands for, so confirming jumps to the
+    /// selected line.
+    fn show_location_peek(&mut self, title: String, locations: &[lsp_types::Location]) {
+        use crate::model::event::{
+            PopupContentData, PopupData, PopupListItemData, PopupPositionData,
+        };
+
+        let mut targets = Vec::new();
+        let mut items = Vec::new();
+        let mut selected = None;
+        for location in locations {
+            let Ok(path) = uri_to_path(&location.uri) else {
+                continue;
+            };
+            let line = location.range.start.line as usize;
+            let column = location.range.start.character as usize + 1;
+            let display_path = path
+                .strip_prefix(&self.working_dir)
+                .unwrap_or(&path)
+                .display()
+                .to_string();
+
+            let item = |text: String, index: usize| PopupListItemData {
+                text,
+                detail: None,
+                icon: None,
+                data: Some(index.to_string()),
+            };
+            items.push(item(
+                format!("{}:{}", display_path, line + 1),
+                targets.len(),
+            ));
+            targets.push(QuickfixItem::new(path.clone(), line + 1, column, ""));
+
+            for (n, text) in self.peek_context_lines(&path, line) {
+                let is_target = n == line;
+                if is_target && selected.is_none() {
+                    selected = Some(items.len());
+                }
+                let marker = if is_target { '›' } else { ' ' };
+                items.push(item(
+                    format!("{} {:>4} {}", marker, n + 1, text),
+                    targets.len(),
+                ));
+                let column = if is_target { column } else { 1 };
+                targets.push(QuickfixItem::new(path.clone(), n + 1, column, ""));
+            }
+        }
+
+        if targets.is_empty() {
+            self.set_status_message(t!("lsp.cannot_open_definition").to_string());
+            return;
+        }
+
+        self.peek_locations = Some((title.clone(), targets));
+        self.show_popup(PopupData {
+            title: Some(title),
+            description: Some(t!("lsp.peek_hint").to_string()),
+            transient: false,
+            content: PopupContentData::List {
+                items,
+                selected: selected.unwrap_or(0),
+            },
+            position: PopupPositionData::BelowCursor,
+            width: self.terminal_width.saturating_sub(4).min(100),
+            max_height: 15,
+            bordered: true,
+        });
+    }
+
+    /// Lines around `line` (0-indexed) in `path`, as (line number, text) pairs
+    fn peek_context_lines(&self, path: &std::path::Path, line: usize) -> Vec<(usize, String)> {
+        const CONTEXT_LINES: usize = 2;
+        let first = line.saturating_sub(CONTEXT_LINES);
+        let last = line + CONTEXT_LINES;
+        let clean = |text: &str| text.trim_end_matches(['\n', '\r']).replace('\t', "    ");
+
+        if let Some(state) = self
+            .buffers
+            .values()
+            .find(|state| state.buffer.file_path() == Some(path))
+        {
+            return (first..=last)
+                .filter_map(|n| {
+                    let bytes = state.buffer.get_line(n)?;
+                    Some((n, clean(&String::from_utf8_lossy(&bytes))))
+                })
+                .collect();
+        }
+
+        std::fs::read_to_string(path)
+            .map(|content| {
+                content
+                    .lines()
+                    .enumerate()
+                    .skip(first)
+                    .take(last - first + 1)
+                    .map(|(n, text)| (n, clean(text)))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Check if there are any pending LSP requests
     pub fn has_pending_lsp_requests(&self) -> bool {
         self.pending_completion_request.is_some() || self.pending_goto_definition_request.is_some()
@@ -1062,10 +1176,23 @@ impl Editor {
                 (line as u32, character as u32, line as u32, character as u32)
             };
 
-        // Get diagnostics at cursor position for context
-        // TODO: Implement diagnostic retrieval when needed
-        let diagnostics: Vec<lsp_types::Diagnostic> = Vec::new();
         let buffer_id = self.active_buffer();
+
+        // Pass the diagnostics on the requested lines as context, so the server
+        // can offer quick fixes for them
+        let diagnostics: Vec<lsp_types::Diagnostic> = self
+            .buffer_metadata
+            .get(&buffer_id)
+            .and_then(|metadata| metadata.file_uri())
+            .and_then(|uri| self.stored_diagnostics.get(uri.as_str()))
+            .map(|diagnostics| {
+                diagnostics
+                    .iter()
+                    .filter(|d| d.range.start.line <= end_line && d.range.end.line >= start_line)
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
         let request_id = self.next_lsp_request_id;
 
         // Use helper to ensure didOpen is sent before the request
@@ -1124,45 +1251,278 @@ impl Editor {
             return;
         }
 
-        // Build the display content
-        let mut lines: Vec<String> = Vec::new();
-        lines.push(format!("Code Actions ({}):", actions.len()));
-        lines.push(String::new());
+        use crate::model::event::{
+            PopupContentData, PopupData, PopupListItemData, PopupPositionData,
+        };
 
-        for (i, action) in actions.iter().enumerate() {
-            let title = match action {
-                lsp_types::CodeActionOrCommand::Command(cmd) => &cmd.title,
-                lsp_types::CodeActionOrCommand::CodeAction(ca) => &ca.title,
-            };
-            lines.push(format!("  {}. {}", i + 1, title));
+        let items = actions
+            .iter()
+            .enumerate()
+            .map(|(i, action)| {
+                let (title, kind) = match action {
+                    lsp_types::CodeActionOrCommand::Command(cmd) => (&cmd.title, None),
+                    lsp_types::CodeActionOrCommand::CodeAction(ca) => (
+                        &ca.title,
+                        ca.kind.as_ref().map(|kind| kind.as_str().to_string()),
+                    ),
+                };
+                PopupListItemData {
+                    text: title.clone(),
+                    detail: kind,
+                    icon: None,
+                    data: Some(i.to_string()),
+                }
+            })
+            .collect();
+
+        tracing::info!("Showing code actions popup with {} actions", actions.len());
+        self.code_actions = Some(actions);
+        self.show_popup(PopupData {
+            title: Some(t!("lsp.popup_code_actions").to_string()),
+            description: Some(t!("lsp.code_action_hint").to_string()),
+            transient: false,
+            content: PopupContentData::List { items, selected: 0 },
+            position: PopupPositionData::BelowCursor,
+            width: 60,
+            max_height: 15,
+            bordered: true,
+        });
+    }
+
+    /// Request the symbols defined in the active buffer
+    pub(crate) fn request_document_symbols(&mut self) {
+        let buffer_id = self.active_buffer();
+        let request_id = self.next_lsp_request_id;
+        let sent = self
+            .with_lsp_for_buffer(buffer_id, |handle, uri, _language| {
+                handle.document_symbols(request_id, uri.clone()).is_ok()
+            })
+            .unwrap_or(false);
+
+        if sent {
+            self.next_lsp_request_id += 1;
+            self.pending_document_symbols_request = Some(request_id);
+            self.lsp_status = "LSP: document symbols...".to_string();
+        } else {
+            self.set_status_message(t!("lsp.no_server_active").to_string());
         }
+    }
 
-        lines.push(String::new());
-        lines.push(t!("lsp.code_action_hint").to_string());
+    /// Handle document symbols response from LSP
+    ///
+    /// Shows the symbols as an outline, children indented under their parents.
+    /// The popup reuses the peek machinery, so confirming jumps to the selected
+    /// symbol and records the jump in the position history.
+    pub(crate) fn handle_document_symbols_response(
+        &mut self,
+        request_id: u64,
+        symbols: Option<lsp_types::DocumentSymbolResponse>,
+    ) {
+        if self.pending_document_symbols_request != Some(request_id) {
+            tracing::debug!("Ignoring stale document symbols response: {}", request_id);
+            return;
+        }
+        self.pending_document_symbols_request = None;
+        self.lsp_status.clear();
 
-        // Create a popup with the code actions
-        use crate::view::popup::{Popup, PopupPosition};
-        use ratatui::style::Style;
+        let outline = symbols.map(flatten_document_symbols).unwrap_or_default();
+        let Some(path) = self
+            .buffer_metadata
+            .get(&self.active_buffer())
+            .and_then(|metadata| metadata.file_path())
+            .cloned()
+        else {
+            return;
+        };
+        if outline.is_empty() {
+            self.set_status_message(t!("lsp.no_symbols").to_string());
+            return;
+        }
 
-        let mut popup = Popup::text(lines, &self.theme);
-        popup.title = Some(t!("lsp.popup_code_actions").to_string());
-        popup.position = PopupPosition::BelowCursor;
-        popup.width = 60;
-        popup.max_height = 15;
-        popup.border_style = Style::default().fg(self.theme.popup_border_fg);
-        popup.background_style = Style::default().bg(self.theme.popup_bg);
+        use crate::model::event::{
+            PopupContentData, PopupData, PopupListItemData, PopupPositionData,
+        };
 
-        // Show the popup
-        if let Some(state) = self.buffers.get_mut(&self.active_buffer()) {
-            state.popups.show(popup);
-            tracing::info!("Showing code actions popup with {} actions", actions.len());
+        // Preselect the last symbol starting at or above the cursor
+        let state = self.active_state();
+        let (cursor_line, _) = state
+            .buffer
+            .position_to_lsp_position(state.cursors.primary().position);
+        let selected = outline
+            .iter()
+            .rposition(|symbol| symbol.position.line as usize <= cursor_line)
+            .unwrap_or(0);
+
+        let mut targets = Vec::new();
+        let items = outline
+            .into_iter()
+            .enumerate()
+            .map(|(i, symbol)| {
+                targets.push(QuickfixItem::new(
+                    path.clone(),
+                    symbol.position.line as usize + 1,
+                    symbol.position.character as usize + 1,
+                    "",
+                ));
+                PopupListItemData {
+                    text: symbol.name,
+                    detail: symbol.detail,
+                    icon: Some(format!(
+                        "{}{}",
+                        "  ".repeat(symbol.depth),
+                        symbol_kind_icon(symbol.kind)
+                    )),
+                    data: Some(i.to_string()),
+                }
+            })
+            .collect();
+
+        let title = t!("lsp.popup_document_symbols").to_string();
+        self.peek_locations = Some((title.clone(), targets));
+        self.show_popup(PopupData {
+            title: Some(title),
+            description: Some(t!("lsp.symbols_hint").to_string()),
+            transient: false,
+            content: PopupContentData::List { items, selected },
+            position: PopupPositionData::BelowCursor,
+            width: 60,
+            max_height: 20,
+            bordered: true,
+        });
+    }
+
+    /// Request LSP formatting of the active buffer, or of the selection if there is one
+    pub(crate) fn request_formatting(&mut self) {
+        if self.is_editing_disabled() {
+            self.set_status_message(t!("buffer.editing_disabled").to_string());
+            return;
         }
 
-        // Note: Executing code actions would require storing the actions and handling
-        // key presses to select and apply them. This is left for future enhancement.
-        self.set_status_message(
-            t!("lsp.code_actions_not_implemented", count = actions.len()).to_string(),
-        );
+        let state = self.active_state();
+        let range = state.cursors.primary().selection_range().map(|range| {
+            let (s_line, s_char) = state.buffer.position_to_lsp_position(range.start);
+            let (e_line, e_char) = state.buffer.position_to_lsp_position(range.end);
+            lsp_types::Range {
+                start: lsp_types::Position::new(s_line as u32, s_char as u32),
+                end: lsp_types::Position::new(e_line as u32, e_char as u32),
+            }
+        });
+        let options = lsp_types::FormattingOptions {
+            tab_size: state.tab_size as u32,
+            insert_spaces: !state.use_tabs,
+            ..Default::default()
+        };
+
+        let buffer_id = self.active_buffer();
+        let request_id = self.next_lsp_request_id;
+        let sent = self
+            .with_lsp_for_buffer(buffer_id, |handle, uri, _language| {
+                handle
+                    .formatting(request_id, uri.clone(), range, options)
+                    .is_ok()
+            })
+            .unwrap_or(false);
+
+        if sent {
+            self.next_lsp_request_id += 1;
+            let version = self.active_state().buffer.version();
+            self.pending_formatting_request = Some((request_id, buffer_id, version));
+            self.lsp_status = "LSP: formatting...".to_string();
+        } else {
+            self.set_status_message(t!("lsp.no_server_active").to_string());
+        }
+    }
+
+    /// Handle formatting response from LSP
+    ///
+    /// The edits are applied as a single undoable change to the buffer the
+    /// request was made for, unless the buffer was edited while the server
+    /// was formatting it.
+    pub(crate) fn handle_formatting_response(
+        &mut self,
+        request_id: u64,
+        result: Result<Vec<lsp_types::TextEdit>, String>,
+    ) {
+        let Some((pending_id, buffer_id, version)) = self.pending_formatting_request else {
+            return;
+        };
+        if pending_id != request_id {
+            tracing::debug!("Ignoring stale formatting response: {}", request_id);
+            return;
+        }
+        self.pending_formatting_request = None;
+        self.lsp_status.clear();
+
+        let current_version = self.buffers.get(&buffer_id).map(|s| s.buffer.version());
+        if result.is_ok() && current_version != Some(version) {
+            tracing::debug!(
+                "Discarding formatting response {}: buffer {:?} changed",
+                request_id,
+                buffer_id
+            );
+            self.set_status_message(t!("lsp.format_stale").to_string());
+            return;
+        }
+
+        match result {
+            Ok(edits) if edits.is_empty() => {
+                self.set_status_message(t!("lsp.format_no_changes").to_string());
+            }
+            Ok(edits) => match self.apply_lsp_text_edits(buffer_id, edits, "LSP Format") {
+                Ok(count) => {
+                    self.set_status_message(t!("lsp.formatted", count = count).to_string());
+                }
+                Err(e) => {
+                    self.set_status_message(
+                        t!("lsp.format_failed", error = e.to_string()).to_string(),
+                    );
+                }
+            },
+            Err(error) => {
+                self.set_status_message(t!("lsp.format_failed", error = &error).to_string());
+            }
+        }
+    }
+
+    /// Apply a code action chosen from the code actions popup
+    ///
+    /// Edit-style actions have their workspace edit applied directly; command-style
+    /// actions (and code actions carrying a command) are sent to the server with
+    /// `workspace/executeCommand`, which typically answers with `workspace/applyEdit`.
+    pub(crate) fn apply_code_action(&mut self, action: lsp_types::CodeActionOrCommand) {
+        let (title, edit, command) = match action {
+            lsp_types::CodeActionOrCommand::Command(cmd) => (cmd.title.clone(), None, Some(cmd)),
+            lsp_types::CodeActionOrCommand::CodeAction(ca) => (ca.title, ca.edit, ca.command),
+        };
+
+        if edit.is_none() && command.is_none() {
+            self.set_status_message(t!("lsp.code_action_unresolved", title = &title).to_string());
+            return;
+        }
+
+        if let Some(edit) = edit {
+            if let Err(e) = self.apply_workspace_edit(edit) {
+                self.set_status_message(
+                    t!("lsp.code_action_failed", error = e.to_string()).to_string(),
+                );
+                return;
+            }
+        }
+
+        if let Some(command) = command {
+            let buffer_id = self.active_buffer();
+            let sent = self
+                .with_lsp_for_buffer(buffer_id, |handle, _uri, _language| {
+                    handle.execute_command(command).is_ok()
+                })
+                .unwrap_or(false);
+            if !sent {
+                self.set_status_message(t!("lsp.no_server_active").to_string());
+                return;
+            }
+        }
+
+        self.set_status_message(t!("lsp.code_action_applied", title = &title).to_string());
     }
 
     /// Handle find references response from LSP
@@ -1211,12 +1571,35 @@ impl Editor {
             })
             .collect();
 
+        // Load the results into the quickfix list so they can be stepped through
+        self.set_quickfix_list(
+            locations
+                .iter()
+                .filter_map(|loc| {
+                    Some(QuickfixItem::new(
+                        uri_to_path(&loc.uri).ok()?,
+                        loc.range.start.line as usize + 1,
+                        loc.range.start.character as usize + 1,
+                        String::new(),
+                    ))
+                })
+                .collect(),
+        );
+
         let count = lsp_locations.len();
         let symbol = std::mem::take(&mut self.pending_references_symbol);
         self.set_status_message(
             t!("lsp.found_references", count = count, symbol = &symbol).to_string(),
         );
 
+        if self.config.editor.peek_lsp_locations {
+            self.show_location_peek(
+                t!("lsp.peek_references", symbol = &symbol, count = count).to_string(),
+                &locations,
+            );
+            return Ok(());
+        }
+
         // Fire the lsp_references hook so plugins can display the results
         self.plugin_manager.run_hook(
             "lsp_references",
@@ -1241,6 +1624,7 @@ impl Editor {
         &mut self,
         buffer_id: BufferId,
         mut edits: Vec<lsp_types::TextEdit>,
+        description: &str,
     ) -> AnyhowResult<usize> {
         if edits.is_empty() {
             return Ok(0);
@@ -1312,7 +1696,7 @@ impl Editor {
                 let cursor_id = state.cursors.primary_id();
                 let insert_event = Event::Insert {
                     position: start_pos,
-                    text: edit.new_text.clone(),
+                    text: state.buffer.line_ending().apply_to(&edit.new_text),
                     cursor_id,
                 };
                 batch_events.push(insert_event);
@@ -1321,18 +1705,100 @@ impl Editor {
             changes += 1;
         }
 
-        // Apply all rename changes using bulk edit for O(n) performance
+        // Apply all changes using bulk edit for O(n) performance
         if !batch_events.is_empty() {
             self.apply_events_to_buffer_as_bulk_edit(
                 buffer_id,
                 batch_events,
-                "LSP Rename".to_string(),
+                description.to_string(),
             )?;
         }
 
         Ok(changes)
     }
 
+    /// Apply an LSP workspace edit across buffers, opening files as needed.
+    ///
+    /// Returns the number of text edits applied.
+    pub(crate) fn apply_workspace_edit(
+        &mut self,
+        workspace_edit: lsp_types::WorkspaceEdit,
+    ) -> AnyhowResult<usize> {
+        let mut total_changes = 0;
+
+        // Handle changes (map of URI -> Vec<TextEdit>)
+        if let Some(changes) = workspace_edit.changes {
+            for (uri, edits) in changes {
+                if let Ok(path) = uri_to_path(&uri) {
+                    let buffer_id = self.open_file(&path)?;
+                    total_changes += self.apply_lsp_text_edits(buffer_id, edits, "LSP Edit")?;
+                }
+            }
+        }
+
+        // Handle document_changes (TextDocumentEdit[])
+        // This is what rust-analyzer sends instead of changes
+        if let Some(document_changes) = workspace_edit.document_changes {
+            use lsp_types::DocumentChanges;
+
+            let text_edits = match document_changes {
+                DocumentChanges::Edits(edits) => edits,
+                DocumentChanges::Operations(ops) => {
+                    // Extract TextDocumentEdit from operations
+                    ops.into_iter()
+                        .filter_map(|op| {
+                            if let lsp_types::DocumentChangeOperation::Edit(edit) = op {
+                                Some(edit)
+                            } else {
+                                None
+                            }
+                        })
+                        .collect()
+                }
+            };
+
+            for text_doc_edit in text_edits {
+                let uri = text_doc_edit.text_document.uri;
+
+                if let Ok(path) = uri_to_path(&uri) {
+                    let buffer_id = self.open_file(&path)?;
+
+                    // Extract TextEdit from OneOf<TextEdit, AnnotatedTextEdit>
+                    let edits: Vec<lsp_types::TextEdit> = text_doc_edit
+                        .edits
+                        .into_iter()
+                        .map(|one_of| match one_of {
+                            lsp_types::OneOf::Left(text_edit) => text_edit,
+                            lsp_types::OneOf::Right(annotated) => annotated.text_edit,
+                        })
+                        .collect();
+
+                    // Log the edits for debugging
+                    tracing::info!(
+                        "Applying {} edits from rust-analyzer for {:?}:",
+                        edits.len(),
+                        path
+                    );
+                    for (i, edit) in edits.iter().enumerate() {
+                        tracing::info!(
+                            "  Edit {}: line {}:{}-{}:{} -> {:?}",
+                            i,
+                            edit.range.start.line,
+                            edit.range.start.character,
+                            edit.range.end.line,
+                            edit.range.end.character,
+                            edit.new_text
+                        );
+                    }
+
+                    total_changes += self.apply_lsp_text_edits(buffer_id, edits, "LSP Edit")?;
+                }
+            }
+        }
+
+        Ok(total_changes)
+    }
+
     /// Handle rename response from LSP
     pub fn handle_rename_response(
         &mut self,
@@ -1354,79 +1820,7 @@ impl Editor {
                     })
                 );
 
-                // Apply the workspace edit
-                let mut total_changes = 0;
-
-                // Handle changes (map of URI -> Vec<TextEdit>)
-                if let Some(changes) = workspace_edit.changes {
-                    for (uri, edits) in changes {
-                        if let Ok(path) = uri_to_path(&uri) {
-                            let buffer_id = self.open_file(&path)?;
-                            total_changes += self.apply_lsp_text_edits(buffer_id, edits)?;
-                        }
-                    }
-                }
-
-                // Handle document_changes (TextDocumentEdit[])
-                // This is what rust-analyzer sends instead of changes
-                if let Some(document_changes) = workspace_edit.document_changes {
-                    use lsp_types::DocumentChanges;
-
-                    let text_edits = match document_changes {
-                        DocumentChanges::Edits(edits) => edits,
-                        DocumentChanges::Operations(ops) => {
-                            // Extract TextDocumentEdit from operations
-                            ops.into_iter()
-                                .filter_map(|op| {
-                                    if let lsp_types::DocumentChangeOperation::Edit(edit) = op {
-                                        Some(edit)
-                                    } else {
-                                        None
-                                    }
-                                })
-                                .collect()
-                        }
-                    };
-
-                    for text_doc_edit in text_edits {
-                        let uri = text_doc_edit.text_document.uri;
-
-                        if let Ok(path) = uri_to_path(&uri) {
-                            let buffer_id = self.open_file(&path)?;
-
-                            // Extract TextEdit from OneOf<TextEdit, AnnotatedTextEdit>
-                            let edits: Vec<lsp_types::TextEdit> = text_doc_edit
-                                .edits
-                                .into_iter()
-                                .map(|one_of| match one_of {
-                                    lsp_types::OneOf::Left(text_edit) => text_edit,
-                                    lsp_types::OneOf::Right(annotated) => annotated.text_edit,
-                                })
-                                .collect();
-
-                            // Log the edits for debugging
-                            tracing::info!(
-                                "Applying {} edits from rust-analyzer for {:?}:",
-                                edits.len(),
-                                path
-                            );
-                            for (i, edit) in edits.iter().enumerate() {
-                                tracing::info!(
-                                    "  Edit {}: line {}:{}-{}:{} -> {:?}",
-                                    i,
-                                    edit.range.start.line,
-                                    edit.range.start.character,
-                                    edit.range.end.line,
-                                    edit.range.end.character,
-                                    edit.new_text
-                                );
-                            }
-
-                            total_changes += self.apply_lsp_text_edits(buffer_id, edits)?;
-                        }
-                    }
-                }
-
+                let total_changes = self.apply_workspace_edit(workspace_edit)?;
                 self.status_message = Some(t!("lsp.renamed", count = total_changes).to_string());
             }
             Err(error) => {
@@ -1572,8 +1966,13 @@ impl Editor {
             new_tree: Some(new_tree),
             old_cursors,
             new_cursors,
+            edits: edits
+                .iter()
+                .map(|(pos, del_len, text)| (*pos, *del_len, text.len()))
+                .collect(),
             description,
         };
+        self.adjust_bookmarks_for_event(buffer_id, &bulk_edit);
 
         // Add to event log
         if let Some(event_log) = self.event_logs.get_mut(&buffer_id) {
@@ -2180,6 +2579,72 @@ impl Editor {
     }
 }
 
+/// A document symbol flattened into outline order
+#[derive(Debug, PartialEq)]
+struct OutlineSymbol {
+    name: String,
+    detail: Option<String>,
+    kind: lsp_types::SymbolKind,
+    /// Nesting depth, 0 for top-level symbols
+    depth: usize,
+    /// Where jumping to the symbol lands
+    position: lsp_types::Position,
+}
+
+/// Flatten a document symbols response into a pre-order list of symbols
+///
+/// Hierarchical responses keep their nesting as depth. Flat responses have no
+/// hierarchy, so their container name is shown as the detail instead.
+fn flatten_document_symbols(response: lsp_types::DocumentSymbolResponse) -> Vec<OutlineSymbol> {
+    fn walk(symbols: Vec<lsp_types::DocumentSymbol>, depth: usize, out: &mut Vec<OutlineSymbol>) {
+        for symbol in symbols {
+            out.push(OutlineSymbol {
+                name: symbol.name,
+                detail: symbol.detail,
+                kind: symbol.kind,
+                depth,
+                position: symbol.selection_range.start,
+            });
+            walk(symbol.children.unwrap_or_default(), depth + 1, out);
+        }
+    }
+
+    match response {
+        lsp_types::DocumentSymbolResponse::Nested(symbols) => {
+            let mut out = Vec::new();
+            walk(symbols, 0, &mut out);
+            out
+        }
+        lsp_types::DocumentSymbolResponse::Flat(symbols) => {
+            let mut symbols: Vec<_> = symbols
+                .into_iter()
+                .map(|symbol| OutlineSymbol {
+                    name: symbol.name,
+                    detail: symbol.container_name,
+                    kind: symbol.kind,
+                    depth: 0,
+                    position: symbol.location.range.start,
+                })
+                .collect();
+            symbols.sort_by_key(|symbol| (symbol.position.line, symbol.position.character));
+            symbols
+        }
+    }
+}
+
+/// Outline icon for a symbol kind, matching the completion popup's icons
+fn symbol_kind_icon(kind: lsp_types::SymbolKind) -> &'static str {
+    use lsp_types::SymbolKind;
+    match kind {
+        SymbolKind::FUNCTION | SymbolKind::METHOD | SymbolKind::CONSTRUCTOR => "λ",
+        SymbolKind::CLASS | SymbolKind::STRUCT | SymbolKind::INTERFACE | SymbolKind::ENUM => "S",
+        SymbolKind::VARIABLE | SymbolKind::FIELD | SymbolKind::PROPERTY => "v",
+        SymbolKind::CONSTANT | SymbolKind::ENUM_MEMBER => "c",
+        SymbolKind::MODULE | SymbolKind::NAMESPACE | SymbolKind::PACKAGE => "m",
+        _ => "·",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Editor;
@@ -2256,4 +2721,46 @@ mod tests {
 
         assert!(state.virtual_texts.is_empty());
     }
+
+    #[test]
+    fn test_flatten_nested_document_symbols() {
+        let range = |line: u32| {
+            serde_json::json!({
+                "start": {"line": line, "character": 4},
+                "end": {"line": line, "character": 8}
+            })
+        };
+        let response: lsp_types::DocumentSymbolResponse = serde_json::from_value(serde_json::json!([
+            {
+                "name": "Point", "kind": 23, "range": range(0), "selectionRange": range(0),
+                "children": [
+                    {"name": "x", "kind": 8, "detail": "i32", "range": range(1), "selectionRange": range(1)}
+                ]
+            },
+            {"name": "main", "kind": 12, "range": range(4), "selectionRange": range(4)}
+        ]))
+        .unwrap();
+
+        let outline = super::flatten_document_symbols(response);
+        let summary: Vec<_> = outline
+            .iter()
+            .map(|s| {
+                (
+                    s.name.as_str(),
+                    s.depth,
+                    s.position.line,
+                    s.detail.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("Point", 0, 0, None),
+                ("x", 1, 1, Some("i32")),
+                ("main", 0, 4, None)
+            ]
+        );
+        assert_eq!(super::symbol_kind_icon(outline[2].kind), "λ");
+    }
 }
@@ -16,6 +16,10 @@ impl Editor {
         // Simple state lookups
         let line_numbers = self.is_line_numbers_visible();
         let line_wrap = self.is_line_wrap_enabled();
+        let indent_guides = self.config.editor.show_indent_guides;
+        let render_whitespace = self.config.editor.render_whitespace;
+        let render_trailing_whitespace = self.config.editor.render_trailing_whitespace;
+        let sticky_scroll = self.config.editor.sticky_scroll;
         let compose_mode = self.is_compose_mode();
         let file_explorer_visible = self.file_explorer_visible;
         let file_explorer_focused = self.is_file_explorer_focused();
@@ -38,6 +42,13 @@ impl Editor {
             .context
             .set(context_keys::LINE_NUMBERS, line_numbers)
             .set(context_keys::LINE_WRAP, line_wrap)
+            .set(context_keys::INDENT_GUIDES, indent_guides)
+            .set(context_keys::RENDER_WHITESPACE, render_whitespace)
+            .set(
+                context_keys::RENDER_TRAILING_WHITESPACE,
+                render_trailing_whitespace,
+            )
+            .set(context_keys::STICKY_SCROLL, sticky_scroll)
             .set(context_keys::COMPOSE_MODE, compose_mode)
             .set(context_keys::FILE_EXPLORER, file_explorer_visible)
             .set(context_keys::FILE_EXPLORER_FOCUSED, file_explorer_focused)
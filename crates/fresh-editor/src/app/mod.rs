@@ -19,7 +19,9 @@ mod mouse_input;
 mod on_save_actions;
 mod plugin_commands;
 mod popup_actions;
+mod project_search;
 mod prompt_actions;
+mod quickfix_actions;
 mod recovery_actions;
 mod render;
 pub mod session;
@@ -73,10 +75,10 @@ pub(crate) fn normalize_path(path: &std::path::Path) -> std::path::PathBuf {
 
 use self::types::{
     Bookmark, CachedLayout, EventLineInfo, InteractiveReplaceState, LspMessageEntry,
-    LspProgressInfo, MacroRecordingState, MouseState, SearchState, TabContextMenu,
-    DEFAULT_BACKGROUND_FILE,
+    LspProgressInfo, MacroRecordingState, MouseState, PasteRecord, PendingTextObject,
+    ProjectSearchState, SearchState, TabContextMenu, DEFAULT_BACKGROUND_FILE,
 };
-use crate::config::Config;
+use crate::config::{Config, CursorStyle, SaveAsDirectory};
 use crate::config_io::{ConfigLayer, ConfigResolver, DirectoryContext};
 use crate::input::actions::action_to_events as convert_action_to_events;
 use crate::input::buffer_mode::ModeRegistry;
@@ -84,6 +86,7 @@ use crate::input::command_registry::CommandRegistry;
 use crate::input::commands::Suggestion;
 use crate::input::keybindings::{Action, KeyContext, KeybindingResolver};
 use crate::input::position_history::PositionHistory;
+use crate::input::quickfix::QuickfixList;
 use crate::model::event::{Event, EventLog, SplitDirection, SplitId};
 use crate::services::async_bridge::{AsyncBridge, AsyncMessage};
 use crate::services::fs::{FsBackend, FsManager, LocalFsBackend};
@@ -193,6 +196,10 @@ pub struct Editor {
     /// Keybinding resolver
     keybindings: KeybindingResolver,
 
+    /// Keys bound (`Some(command)`) or unbound (`None`) by plugins, reapplied
+    /// whenever the resolver is rebuilt from the config
+    plugin_keybindings: HashMap<(KeyContext, KeyCode, KeyModifiers), Option<String>>,
+
     /// Shared clipboard (handles both internal and system clipboard)
     clipboard: crate::services::clipboard::Clipboard,
 
@@ -209,6 +216,31 @@ pub struct Editor {
     /// Plugin-provided status message (displayed alongside the core status)
     plugin_status_message: Option<String>,
 
+    /// Plugin-contributed status bar items, kept in display order
+    plugin_status_items: Vec<crate::view::ui::status_bar::PluginStatusItem>,
+
+    /// Values plugins keep across sessions with `editor.setStorage`
+    plugin_storage: crate::services::plugins::storage::PluginStorage,
+
+    /// Deadlines of `editor.lsp` requests still waiting for a response,
+    /// keyed by request ID
+    plugin_lsp_deadlines: HashMap<u64, std::time::Instant>,
+
+    /// `editor.lsp` requests rejected for timing out, whose late responses
+    /// are dropped, with when to stop waiting for those responses
+    plugin_lsp_timed_out: HashMap<u64, std::time::Instant>,
+
+    /// Path patterns claimed by plugin open handlers, in registration order,
+    /// with the name of the plugin that registered each
+    plugin_open_handlers: Vec<(String, regex::Regex)>,
+
+    /// Plugin text objects as (plugin name, text object name), in
+    /// registration order; expand-selection queries all of them
+    plugin_text_objects: Vec<(String, String)>,
+
+    /// Cursor context of the latest plugin text object query
+    pending_text_object: Option<PendingTextObject>,
+
     /// Accumulated plugin errors (for test assertions)
     /// These are collected when plugin error messages are received
     plugin_errors: Vec<String>,
@@ -248,6 +280,10 @@ pub struct Editor {
     /// Used to detect viewport changes that occur between renders (e.g., scroll events)
     previous_viewports: HashMap<SplitId, (usize, u16, u16)>,
 
+    /// Primary cursor (buffer, byte position) at the end of the last render frame
+    /// Used to fire a single cursor_position_changed hook per frame
+    previous_cursor_position: Option<(BufferId, usize)>,
+
     /// Scroll sync manager for anchor-based synchronized scrolling
     /// Used for side-by-side diff views where two panes need to scroll together
     scroll_sync_manager: ScrollSyncManager,
@@ -291,6 +327,9 @@ pub struct Editor {
     /// Whether tab bar is visible
     tab_bar_visible: bool,
 
+    /// Whether the status bar shows the cursor's byte offset and file size
+    cursor_details_visible: bool,
+
     /// Whether mouse capture is enabled
     mouse_enabled: bool,
 
@@ -320,6 +359,9 @@ pub struct Editor {
     /// Flag to prevent recording movements during navigation
     in_navigation: bool,
 
+    /// Quickfix list filled by references and grep results
+    quickfix: QuickfixList,
+
     /// Next LSP request ID
     next_lsp_request_id: u64,
 
@@ -348,6 +390,13 @@ pub struct Editor {
     /// Pending LSP code actions request ID (if any)
     pending_code_actions_request: Option<u64>,
 
+    /// Pending LSP formatting request ID, the buffer being formatted and its
+    /// version when the request was sent
+    pending_formatting_request: Option<(u64, BufferId, u64)>,
+
+    /// Pending LSP document symbols request ID (if any)
+    pending_document_symbols_request: Option<u64>,
+
     /// Pending LSP inlay hints request ID (if any)
     pending_inlay_hints_request: Option<u64>,
 
@@ -398,6 +447,12 @@ pub struct Editor {
     /// Interactive replace state (if interactive replace is active)
     interactive_replace_state: Option<InteractiveReplaceState>,
 
+    /// Project-wide search in progress or most recently run
+    project_search: Option<ProjectSearchState>,
+
+    /// ID assigned to the next project-wide search
+    next_project_search_id: u64,
+
     /// LSP status indicator for status bar
     lsp_status: String,
 
@@ -484,6 +539,16 @@ pub struct Editor {
     /// Flag to prevent recursive macro playback
     macro_playing: bool,
 
+    /// The most recent run of consecutive editing actions, for RepeatLastEdit
+    last_edit: Vec<Action>,
+
+    /// Whether the previous action was an edit, so the next edit extends `last_edit`
+    /// instead of replacing it
+    extending_last_edit: bool,
+
+    /// Flag to keep RepeatLastEdit from recording its own replay
+    repeating_last_edit: bool,
+
     /// Pending plugin action receivers (for async action execution)
     #[cfg(feature = "plugins")]
     pending_plugin_actions: Vec<(
@@ -499,6 +564,13 @@ pub struct Editor {
     /// Stores the keys pressed so far in a chord sequence
     chord_state: Vec<(crossterm::event::KeyCode, crossterm::event::KeyModifiers)>,
 
+    /// When the last key of the pending chord was pressed (for chord_timeout_ms)
+    chord_started_at: Option<std::time::Instant>,
+
+    /// Seconds left on the chord countdown when it was last checked
+    /// Used to redraw the status bar only when the displayed value changes
+    chord_countdown_shown: Option<u64>,
+
     /// Pending LSP confirmation - language name awaiting user confirmation
     /// When Some, a confirmation popup is shown asking user to approve LSP spawn
     pending_lsp_confirmation: Option<String>,
@@ -520,6 +592,25 @@ pub struct Editor {
     /// Maps file path to last known modification time
     file_mod_times: HashMap<PathBuf, std::time::SystemTime>,
 
+    /// Last known modification times of the config files (for live reload)
+    config_mod_times: HashMap<PathBuf, std::time::SystemTime>,
+
+    /// Last time we polled the config files for changes
+    last_config_poll: std::time::Instant,
+
+    /// A config file changed on the last poll; reload if it has settled
+    config_reload_pending: bool,
+
+    /// Which config layer sets each setting (JSON pointer path), loaded with
+    /// the config so opening a file doesn't re-read every layer
+    config_layer_sources: HashMap<String, ConfigLayer>,
+
+    /// Open files that were deleted externally (shown as "deleted on disk")
+    deleted_files: HashSet<PathBuf>,
+
+    /// Directory a file was last saved to or opened from via the file browser
+    last_file_dialog_dir: Option<PathBuf>,
+
     /// Last known modification times for expanded directories (for file tree refresh)
     /// Maps directory path to last known modification time
     dir_mod_times: HashMap<PathBuf, std::time::SystemTime>,
@@ -540,6 +631,24 @@ pub struct Editor {
     /// Request a full terminal clear and redraw on the next frame
     full_redraw_requested: bool,
 
+    /// Cursor style last written to the terminal (None = not written yet)
+    applied_cursor_style: Option<CursorStyle>,
+
+    /// Whether typed characters replace the character under the cursor
+    overwrite_mode: bool,
+
+    /// Last clipboard paste, replaced in place by yank-pop
+    last_paste: Option<PasteRecord>,
+
+    /// Whether the previous action was a cut, so the next cut joins its kill ring entry
+    last_action_was_kill: bool,
+
+    /// Set while a cut that directly follows another cut is copying its text
+    appending_kill: bool,
+
+    /// Register chosen with the register prefix, used by the next copy, cut or paste
+    pending_register: Option<char>,
+
     /// Time source for testable time operations
     time_source: SharedTimeSource,
 
@@ -596,6 +705,10 @@ pub struct Editor {
     /// Double-click is only detected if both clicks are at the same position
     previous_click_position: Option<(u16, u16)>,
 
+    /// Number of consecutive clicks at the previous position within the
+    /// double-click time (for double and triple-click detection)
+    previous_click_count: u8,
+
     /// Settings UI state (when settings modal is open)
     pub(crate) settings_state: Option<crate::view::settings::SettingsState>,
 
@@ -615,6 +728,14 @@ pub struct Editor {
     /// Stores (popup_id, Vec<(action_id, action_label)>)
     active_action_popup: Option<(String, Vec<(String, String)>)>,
 
+    /// Active LSP peek popup (for definitions/references)
+    /// Stores (popup_title, location behind each list item)
+    peek_locations: Option<(String, Vec<crate::input::quickfix::QuickfixItem>)>,
+
+    /// Active LSP code actions popup
+    /// Stores the code action behind each list item
+    code_actions: Option<Vec<lsp_types::CodeActionOrCommand>>,
+
     /// Composite buffers (separate from regular buffers)
     /// These display multiple source buffers in a single tab
     composite_buffers: HashMap<BufferId, crate::model::composite_buffer::CompositeBuffer>,
@@ -773,7 +894,13 @@ impl Editor {
         // Note: line_wrap_enabled is now stored in SplitViewState.viewport
         tracing::info!("EditorState created for buffer {:?}", buffer_id);
         buffers.insert(buffer_id, state);
-        event_logs.insert(buffer_id, EventLog::new());
+        event_logs.insert(
+            buffer_id,
+            EventLog::with_limits(
+                config.editor.undo_history_max_entries,
+                config.editor.undo_history_max_bytes as usize,
+            ),
+        );
 
         // Create metadata for the initial empty buffer
         let mut buffer_metadata = HashMap::new();
@@ -830,6 +957,10 @@ impl Editor {
         // Initialize command registry (always available, used by both plugins and core)
         let command_registry = Arc::new(RwLock::new(CommandRegistry::new()));
 
+        let plugin_storage = crate::services::plugins::storage::PluginStorage::load(
+            &dir_context.plugin_storage_dir(),
+        );
+
         // Initialize plugin manager (handles both enabled and disabled cases internally)
         let plugin_manager = PluginManager::new(
             enable_plugins,
@@ -843,6 +974,7 @@ impl Editor {
         if let Some(snapshot_handle) = plugin_manager.state_snapshot_handle() {
             let mut snapshot = snapshot_handle.write().unwrap();
             snapshot.working_dir = working_dir.clone();
+            snapshot.plugin_storage = plugin_storage.all().clone();
         }
 
         // Load TypeScript plugins from multiple directories:
@@ -920,6 +1052,7 @@ impl Editor {
         let check_for_updates = config.check_for_updates;
         let show_menu_bar = config.editor.show_menu_bar;
         let show_tab_bar = config.editor.show_tab_bar;
+        let show_cursor_details = config.editor.show_cursor_details;
 
         // Start periodic update checker if enabled (also sends daily telemetry)
         let update_checker = if check_for_updates {
@@ -936,6 +1069,9 @@ impl Editor {
             None
         };
 
+        let mut clipboard = crate::services::clipboard::Clipboard::new();
+        clipboard.set_osc52_enabled(config.editor.osc52_clipboard);
+
         let mut editor = Editor {
             buffers,
             event_logs,
@@ -948,11 +1084,19 @@ impl Editor {
             ansi_background_path: None,
             background_fade: crate::primitives::ansi_background::DEFAULT_BACKGROUND_FADE,
             keybindings,
-            clipboard: crate::services::clipboard::Clipboard::new(),
+            plugin_keybindings: HashMap::new(),
+            clipboard,
             should_quit: false,
             restart_with_dir: None,
             status_message: None,
             plugin_status_message: None,
+            plugin_status_items: Vec::new(),
+            plugin_storage,
+            plugin_lsp_deadlines: HashMap::new(),
+            plugin_lsp_timed_out: HashMap::new(),
+            plugin_open_handlers: Vec::new(),
+            plugin_text_objects: Vec::new(),
+            pending_text_object: None,
             plugin_errors: Vec::new(),
             prompt: None,
             terminal_width: width,
@@ -965,6 +1109,7 @@ impl Editor {
             split_manager,
             split_view_states,
             previous_viewports: HashMap::new(),
+            previous_cursor_position: None,
             scroll_sync_manager: ScrollSyncManager::new(),
             file_explorer: None,
             fs_manager,
@@ -979,6 +1124,7 @@ impl Editor {
                 crate::view::file_tree::FileExplorerDecorationCache::default(),
             menu_bar_auto_shown: false,
             tab_bar_visible: show_tab_bar,
+            cursor_details_visible: show_cursor_details,
             mouse_enabled: true,
             mouse_cursor_position: None,
             gpm_active: false,
@@ -988,6 +1134,7 @@ impl Editor {
             working_dir,
             position_history: PositionHistory::new(),
             in_navigation: false,
+            quickfix: QuickfixList::new(),
             next_lsp_request_id: 0,
             pending_completion_request: None,
             completion_items: None,
@@ -997,6 +1144,8 @@ impl Editor {
             pending_references_symbol: String::new(),
             pending_signature_help_request: None,
             pending_code_actions_request: None,
+            pending_formatting_request: None,
+            pending_document_symbols_request: None,
             pending_inlay_hints_request: None,
             pending_semantic_token_requests: HashMap::new(),
             semantic_tokens_in_flight: HashMap::new(),
@@ -1017,6 +1166,8 @@ impl Editor {
             ),
             pending_search_range: None,
             interactive_replace_state: None,
+            project_search: None,
+            next_project_search_id: 0,
             lsp_status: String::new(),
             mouse_state: MouseState::default(),
             tab_context_menu: None,
@@ -1049,7 +1200,7 @@ impl Editor {
             stored_diagnostics: HashMap::new(),
             event_broadcaster: crate::model::control_event::EventBroadcaster::default(),
             bookmarks: HashMap::new(),
-            search_case_sensitive: true,
+            search_case_sensitive: false,
             search_whole_word: false,
             search_use_regex: false,
             search_confirm_each: false,
@@ -1057,17 +1208,28 @@ impl Editor {
             macro_recording: None,
             last_macro_register: None,
             macro_playing: false,
+            last_edit: Vec::new(),
+            extending_last_edit: false,
+            repeating_last_edit: false,
             #[cfg(feature = "plugins")]
             pending_plugin_actions: Vec::new(),
             #[cfg(feature = "plugins")]
             plugin_render_requested: false,
             chord_state: Vec::new(),
+            chord_started_at: None,
+            chord_countdown_shown: None,
             pending_lsp_confirmation: None,
             pending_close_buffer: None,
             auto_revert_enabled: true,
             last_auto_revert_poll: time_source.now(),
             last_file_tree_poll: time_source.now(),
             file_mod_times: HashMap::new(),
+            config_mod_times: HashMap::new(),
+            last_config_poll: time_source.now(),
+            config_reload_pending: false,
+            config_layer_sources: HashMap::new(),
+            deleted_files: HashSet::new(),
+            last_file_dialog_dir: None,
             dir_mod_times: HashMap::new(),
             file_rapid_change_counts: HashMap::new(),
             file_open_state: None,
@@ -1081,6 +1243,12 @@ impl Editor {
                 RecoveryService::with_config_and_dir(recovery_config, dir_context.recovery_dir())
             },
             full_redraw_requested: false,
+            applied_cursor_style: None,
+            overwrite_mode: false,
+            last_paste: None,
+            last_action_was_kill: false,
+            appending_kill: false,
+            pending_register: None,
             time_source: time_source.clone(),
             last_auto_save: time_source.now(),
             active_custom_contexts: HashSet::new(),
@@ -1097,6 +1265,7 @@ impl Editor {
             terminal_mode_resume: std::collections::HashSet::new(),
             previous_click_time: None,
             previous_click_position: None,
+            previous_click_count: 0,
             settings_state: None,
             calibration_wizard: None,
             key_translator: crate::input::key_translator::KeyTranslator::load_default()
@@ -1105,9 +1274,14 @@ impl Editor {
             stdin_streaming: None,
             review_hunks: Vec::new(),
             active_action_popup: None,
+            peek_locations: None,
+            code_actions: None,
             composite_buffers: HashMap::new(),
             composite_view_states: HashMap::new(),
         };
+        editor.config_mod_times = editor.config_file_mod_times();
+        editor.config_layer_sources = editor.load_config_layer_sources();
+        editor.report_skipped_project_toml();
 
         #[cfg(feature = "plugins")]
         {
@@ -1523,6 +1697,44 @@ impl Editor {
         true
     }
 
+    /// Time left before the pending chord is abandoned
+    ///
+    /// Returns None when no chord is pending or no chord timeout is configured.
+    pub(crate) fn chord_time_remaining(&self) -> Option<std::time::Duration> {
+        if self.config.editor.chord_timeout_ms == 0 || self.chord_state.is_empty() {
+            return None;
+        }
+        let started_at = self.chord_started_at?;
+        let timeout = std::time::Duration::from_millis(self.config.editor.chord_timeout_ms);
+        let elapsed = self.time_source.now().saturating_duration_since(started_at);
+        Some(timeout.saturating_sub(elapsed))
+    }
+
+    /// Check if the pending chord has timed out and clear it
+    ///
+    /// Returns true if a redraw is needed, either because the chord was abandoned
+    /// or because the countdown shown in the status bar has changed.
+    pub fn check_chord_timeout(&mut self) -> bool {
+        let Some(remaining) = self.chord_time_remaining() else {
+            return false;
+        };
+
+        if remaining.is_zero() {
+            tracing::debug!("Chord sequence timed out, clearing state");
+            self.chord_state.clear();
+            self.chord_started_at = None;
+            self.chord_countdown_shown = None;
+            return true;
+        }
+
+        let seconds = remaining.as_millis().div_ceil(1000) as u64;
+        if self.chord_countdown_shown == Some(seconds) {
+            return false;
+        }
+        self.chord_countdown_shown = Some(seconds);
+        true
+    }
+
     /// Check if semantic highlight debounce timer has expired
     ///
     /// Returns true if a redraw is needed because the debounce period has elapsed
@@ -1539,6 +1751,15 @@ impl Editor {
         false
     }
 
+    /// Check if any split is in the middle of a smooth scroll
+    ///
+    /// Returns true while frames are still needed to finish the animation.
+    pub fn is_scroll_animating(&self) -> bool {
+        self.split_view_states
+            .values()
+            .any(|vs| vs.viewport.is_scroll_animating())
+    }
+
     /// Load an ANSI background image from a user-provided path
     fn load_ansi_background(&mut self, input: &str) -> AnyhowResult<()> {
         let trimmed = input.trim();
@@ -1830,6 +2051,9 @@ impl Editor {
         // 2. Adjust cursors in other splits that share the same buffer
         self.adjust_other_split_cursors_for_event(event);
 
+        // 2b. Keep bookmarks in this buffer anchored to the same text
+        self.adjust_bookmarks_for_event(self.active_buffer(), event);
+
         // 3. Clear search highlights on edit (Insert/Delete events)
         // This preserves highlights while navigating but clears them when modifying text
         // EXCEPT during interactive replace where we want to keep highlights visible
@@ -2038,6 +2262,10 @@ impl Editor {
             new_tree: Some(new_tree),
             old_cursors,
             new_cursors,
+            edits: edits
+                .iter()
+                .map(|(pos, del_len, text)| (*pos, *del_len, text.len()))
+                .collect(),
             description,
         };
 
@@ -2045,6 +2273,7 @@ impl Editor {
         self.sync_editor_state_to_split_view_state();
         self.invalidate_layouts_for_buffer(self.active_buffer());
         self.adjust_other_split_cursors_for_event(&bulk_edit);
+        self.adjust_bookmarks_for_event(self.active_buffer(), &bulk_edit);
         // Note: Do NOT clear search overlays - markers track through edits for F3/Shift+F3
 
         Some(bulk_edit)
@@ -2344,6 +2573,14 @@ impl Editor {
 
     /// Handle Recenter event using SplitViewState's viewport
     fn handle_recenter_event(&mut self) {
+        self.scroll_cursor_line_to(crate::input::actions::ViewportRow::Middle);
+    }
+
+    /// Scroll the active split (and the splits synced with it) so each cursor's
+    /// line sits at the top, middle or bottom of the viewport
+    pub(crate) fn scroll_cursor_line_to(&mut self, row: crate::input::actions::ViewportRow) {
+        use crate::input::actions::ViewportRow;
+
         let active_split = self.split_manager.active_split();
 
         // Find other splits in the same sync group if any
@@ -2351,14 +2588,14 @@ impl Editor {
             .split_view_states
             .get(&active_split)
             .and_then(|vs| vs.sync_group);
-        let splits_to_recenter = if let Some(group_id) = sync_group {
+        let splits_to_scroll = if let Some(group_id) = sync_group {
             self.split_manager
                 .get_splits_in_group(group_id, &self.split_view_states)
         } else {
             vec![active_split]
         };
 
-        for split_id in splits_to_recenter {
+        for split_id in splits_to_scroll {
             let buffer_id = if let Some(id) = self.split_manager.buffer_for_split(split_id) {
                 id
             } else {
@@ -2366,31 +2603,50 @@ impl Editor {
             };
 
             if let Some(state) = self.buffers.get_mut(&buffer_id) {
-                let buffer = &mut state.buffer;
                 let view_state = self.split_view_states.get_mut(&split_id);
 
                 if let Some(view_state) = view_state {
-                    // Recenter viewport on cursor
-                    let cursor = *view_state.cursors.primary();
-                    let viewport_height = view_state.viewport.visible_line_count();
-                    let target_rows_from_top = viewport_height / 2;
-
-                    // Move backwards from cursor position target_rows_from_top lines
-                    let mut iter = buffer.line_iterator(cursor.position, 80);
-                    for _ in 0..target_rows_from_top {
-                        if iter.prev().is_none() {
-                            break;
-                        }
+                    // The active split's cursors live in the buffer state; other
+                    // splits keep theirs in the view state
+                    let position = if split_id == active_split {
+                        state.cursors.primary().position
+                    } else {
+                        view_state.cursors.primary().position
+                    };
+                    let buffer = &mut state.buffer;
+                    let viewport = &mut view_state.viewport;
+                    match row {
+                        ViewportRow::Top => viewport.scroll_line_to_top(buffer, position),
+                        ViewportRow::Middle => viewport.center_on(buffer, position),
+                        ViewportRow::Bottom => viewport.scroll_line_to_bottom(buffer, position),
                     }
-                    let new_top_byte = iter.current_position();
-                    view_state.viewport.top_byte = new_top_byte;
-                    // Mark to skip ensure_visible on next render so the scroll isn't undone
-                    view_state.viewport.set_skip_ensure_visible();
                 }
             }
         }
     }
 
+    /// Bring the primary cursor into view after a jump (search match, definition,
+    /// diagnostic), centering it when `center_on_jump` is enabled
+    pub(crate) fn reveal_cursor_after_jump(&mut self) {
+        let active_split = self.split_manager.active_split();
+        let active_buffer = self.active_buffer();
+        let center = self.config.editor.center_on_jump;
+        if let (Some(state), Some(view_state)) = (
+            self.buffers.get_mut(&active_buffer),
+            self.split_view_states.get_mut(&active_split),
+        ) {
+            let cursor = *state.cursors.primary();
+            view_state
+                .viewport
+                .ensure_visible(&mut state.buffer, &cursor);
+            if center {
+                view_state
+                    .viewport
+                    .center_on(&mut state.buffer, cursor.position);
+            }
+        }
+    }
+
     /// Invalidate layouts for all splits viewing a specific buffer
     ///
     /// Called after buffer content changes (Insert/Delete) to mark
@@ -2454,6 +2710,8 @@ impl Editor {
     /// Used after external commands have messed up the terminal state.
     pub fn request_full_redraw(&mut self) {
         self.full_redraw_requested = true;
+        // The terminal may have reset the cursor shape too
+        self.applied_cursor_style = None;
     }
 
     /// Check if a full redraw was requested, and clear the flag.
@@ -2463,6 +2721,36 @@ impl Editor {
         requested
     }
 
+    /// The cursor style the terminal should show right now
+    ///
+    /// Overwrite mode uses a block (keeping the configured blink) so it's
+    /// distinguishable from the usual bar or underline.
+    pub fn cursor_style(&self) -> CursorStyle {
+        let style = self.config.editor.cursor_style;
+        if !self.overwrite_mode {
+            return style;
+        }
+        match style {
+            CursorStyle::SteadyBar | CursorStyle::SteadyUnderline => CursorStyle::SteadyBlock,
+            CursorStyle::BlinkingBar | CursorStyle::BlinkingUnderline => CursorStyle::BlinkingBlock,
+            CursorStyle::Default => CursorStyle::SteadyBlock,
+            block => block,
+        }
+    }
+
+    /// Write the cursor style escape sequence if it changed since the last frame.
+    /// Called after each render so config changes take effect immediately.
+    pub fn write_cursor_style<W: std::io::Write>(&mut self, out: &mut W) -> std::io::Result<()> {
+        let style = self.cursor_style();
+        if self.applied_cursor_style == Some(style) {
+            return Ok(());
+        }
+        crossterm::queue!(out, style.to_crossterm_style())?;
+        out.flush()?;
+        self.applied_cursor_style = Some(style);
+        Ok(())
+    }
+
     pub fn request_restart(&mut self, new_working_dir: PathBuf) {
         tracing::info!(
             "Restart requested with new working directory: {}",
@@ -2717,6 +3005,11 @@ impl Editor {
                 .unwrap_or_else(|| self.working_dir.clone())
         };
 
+        self.init_file_open_state_in(initial_dir);
+    }
+
+    /// Initialize the file open dialog state starting in the given directory
+    fn init_file_open_state_in(&mut self, initial_dir: PathBuf) {
         // Create the file open state with config-based show_hidden setting
         let show_hidden = self.config.file_browser.show_hidden;
         self.file_open_state = Some(file_open::FileOpenState::new(
@@ -2728,6 +3021,48 @@ impl Editor {
         self.load_file_open_directory(initial_dir);
     }
 
+    /// Start the Save As prompt with the file browser
+    ///
+    /// Buffers with a file suggest their current path. Buffers without one start
+    /// in the directory chosen by `editor.save_as_default_dir`.
+    fn prompt_save_as(&mut self) {
+        let current_path = self.active_state().buffer.file_path().map(|p| {
+            // Make path relative to working_dir if possible
+            p.strip_prefix(&self.working_dir)
+                .unwrap_or(p)
+                .to_string_lossy()
+                .to_string()
+        });
+        let initial_dir = if current_path.is_some() {
+            None
+        } else {
+            match self.config.editor.save_as_default_dir {
+                // A new buffer has no directory of its own, so use the file
+                // that was active before it in this split
+                SaveAsDirectory::ActiveBuffer => self
+                    .split_view_states
+                    .get(&self.split_manager.active_split())
+                    .and_then(|vs| vs.previous_buffer)
+                    .and_then(|id| self.buffers.get(&id))
+                    .and_then(|state| state.buffer.file_path())
+                    .and_then(|path| path.parent())
+                    .map(|dir| dir.to_path_buf()),
+                SaveAsDirectory::WorkspaceRoot => Some(self.working_dir.clone()),
+                SaveAsDirectory::LastUsed => self.last_file_dialog_dir.clone(),
+            }
+        };
+
+        self.start_prompt_with_initial_text(
+            t!("file.save_as_prompt").to_string(),
+            PromptType::SaveFileAs,
+            current_path.unwrap_or_default(),
+        );
+        match initial_dir {
+            Some(dir) => self.init_file_open_state_in(dir),
+            None => self.init_file_open_state(),
+        }
+    }
+
     /// Initialize the folder open dialog state
     ///
     /// Called when the Switch Project prompt is started. Starts from the current working
@@ -2872,6 +3207,11 @@ impl Editor {
                     self.file_open_state = None;
                     self.file_browser_layout = None;
                 }
+                PromptType::FileExplorerFilter => {
+                    if let Some(explorer) = &mut self.file_explorer {
+                        explorer.clear_filter();
+                    }
+                }
                 PromptType::AsyncPrompt => {
                     // Resolve the pending async prompt callback with null (cancelled)
                     if let Some(callback_id) = self.pending_async_prompt_callback.take() {
@@ -2997,9 +3337,10 @@ impl Editor {
     fn prompt_type_to_history_key(prompt_type: &crate::view::prompt::PromptType) -> Option<String> {
         use crate::view::prompt::PromptType;
         match prompt_type {
-            PromptType::Search | PromptType::ReplaceSearch | PromptType::QueryReplaceSearch => {
-                Some("search".to_string())
-            }
+            PromptType::Search
+            | PromptType::ReplaceSearch
+            | PromptType::QueryReplaceSearch
+            | PromptType::ProjectSearch => Some("search".to_string()),
             PromptType::Replace { .. } | PromptType::QueryReplace { .. } => {
                 Some("replace".to_string())
             }
@@ -3135,6 +3476,9 @@ impl Editor {
                 // For OpenFile/SwitchProject/SaveFileAs, update the file browser filter (native implementation)
                 self.update_file_open_filter();
             }
+            PromptType::FileExplorerFilter => {
+                self.file_explorer_apply_filter(&input);
+            }
             PromptType::Plugin { custom_type } => {
                 // Reset history navigation when user types - allows Up to navigate history
                 let key = format!("plugin:{}", custom_type);
@@ -3165,6 +3509,12 @@ impl Editor {
                     prompt.filter_suggestions(false);
                 }
             }
+            PromptType::ShowKeybindings => {
+                // Keybindings also match on description (the binding category)
+                if let Some(prompt) = &mut self.prompt {
+                    prompt.filter_suggestions(true);
+                }
+            }
             PromptType::SelectLocale => {
                 // Locale selection also matches on description (language names)
                 if let Some(prompt) = &mut self.prompt {
@@ -3347,6 +3697,21 @@ impl Editor {
                 } => {
                     self.handle_code_actions_response(request_id, actions);
                 }
+                AsyncMessage::LspFormatting { request_id, result } => {
+                    self.handle_formatting_response(request_id, result);
+                }
+                AsyncMessage::LspDocumentSymbols {
+                    request_id,
+                    symbols,
+                } => {
+                    self.handle_document_symbols_response(request_id, symbols);
+                }
+                AsyncMessage::LspApplyEdit { edit, label } => {
+                    tracing::info!("Applying workspace edit from server: {:?}", label);
+                    if let Err(e) = self.apply_workspace_edit(edit) {
+                        tracing::error!("Failed to apply workspace edit: {}", e);
+                    }
+                }
                 AsyncMessage::LspPulledDiagnostics {
                     request_id: _,
                     uri,
@@ -3392,6 +3757,9 @@ impl Editor {
                 AsyncMessage::FileExplorerExpandedToPath(view) => {
                     self.handle_file_explorer_expanded_to_path(view);
                 }
+                AsyncMessage::FileExplorerGitStatusLoaded(git_status) => {
+                    self.handle_file_explorer_git_status_loaded(git_status);
+                }
                 AsyncMessage::Plugin(plugin_msg) => {
                     use fresh_core::api::{JsCallbackId, PluginAsyncMessage};
                     match plugin_msg {
@@ -3490,6 +3858,12 @@ impl Editor {
                 AsyncMessage::FileOpenDirectoryLoaded(result) => {
                     self.handle_file_open_directory_loaded(result);
                 }
+                AsyncMessage::ProjectSearchResults { search_id, matches } => {
+                    self.handle_project_search_results(search_id, matches);
+                }
+                AsyncMessage::ProjectSearchFinished { search_id, total } => {
+                    self.handle_project_search_finished(search_id, total);
+                }
                 AsyncMessage::TerminalOutput { terminal_id } => {
                     // Terminal output received - check if we should auto-jump back to terminal mode
                     tracing::trace!("Terminal output received for {:?}", terminal_id);
@@ -3613,6 +3987,9 @@ impl Editor {
         // Process pending LSP server restarts (with exponential backoff)
         self.process_pending_lsp_restarts();
 
+        // Reject plugin LSP requests the server has not answered in time
+        self.expire_plugin_lsp_requests();
+
         // Check and clear the plugin render request flag
         #[cfg(feature = "plugins")]
         let plugin_render = {
@@ -3632,9 +4009,15 @@ impl Editor {
         // Poll for file changes (auto-revert) and file tree changes
         let file_changes = self.poll_file_changes();
         let tree_changes = self.poll_file_tree_changes();
+        let config_changes = self.poll_config_changes();
 
         // Trigger render if any async messages, plugin commands were processed, or plugin requested render
-        needs_render || processed_any_commands || plugin_render || file_changes || tree_changes
+        needs_render
+            || processed_any_commands
+            || plugin_render
+            || file_changes
+            || tree_changes
+            || config_changes
     }
 
     /// Update LSP status bar string from active progress operations
@@ -3700,7 +4083,7 @@ impl Editor {
     fn update_plugin_state_snapshot(&mut self) {
         // Update TypeScript plugin manager state
         if let Some(snapshot_handle) = self.plugin_manager.state_snapshot_handle() {
-            use fresh_core::api::{BufferInfo, CursorInfo, ViewportInfo};
+            use fresh_core::api::{BufferInfo, CursorDetails, CursorInfo, ViewportInfo};
             let mut snapshot = snapshot_handle.write().unwrap();
 
             // Update active buffer ID
@@ -3769,6 +4152,19 @@ impl Editor {
                     selection: primary_selection.clone(),
                 });
 
+                // Line/column and file size for the primary cursor
+                let line_start = active_state
+                    .buffer
+                    .line_iterator(primary_position, 80)
+                    .current_position();
+                snapshot.cursor_details = Some(CursorDetails {
+                    line: active_state.primary_cursor_line_number.value(),
+                    column: primary_position.saturating_sub(line_start),
+                    byte: primary_position,
+                    total_bytes: active_state.buffer.total_bytes(),
+                    total_lines: active_state.buffer.line_count(),
+                });
+
                 // Selected text from primary cursor (for clipboard plugin)
                 snapshot.selected_text = primary_selection
                     .map(|range| active_state.get_text_range(range.start, range.end));
@@ -3837,6 +4233,9 @@ impl Editor {
             PluginCommand::DeleteRange { buffer_id, range } => {
                 self.handle_delete_range(buffer_id, range);
             }
+            PluginCommand::ApplyEdits { buffer_id, edits } => {
+                self.handle_apply_edits(buffer_id, edits);
+            }
             PluginCommand::InsertAtCursor { text } => {
                 self.handle_insert_at_cursor(text);
             }
@@ -3880,6 +4279,22 @@ impl Editor {
             } => {
                 self.handle_clear_namespace(buffer_id, namespace);
             }
+            PluginCommand::HighlightRange {
+                buffer_id,
+                plugin_name,
+                group,
+                range,
+                style,
+            } => {
+                self.handle_highlight_range(buffer_id, plugin_name, group, range, style);
+            }
+            PluginCommand::ClearHighlights {
+                buffer_id,
+                plugin_name,
+                group,
+            } => {
+                self.handle_clear_highlights(buffer_id, plugin_name, group);
+            }
             PluginCommand::ClearOverlaysInRange {
                 buffer_id,
                 start,
@@ -4051,11 +4466,52 @@ impl Editor {
             PluginCommand::ClearFileExplorerDecorations { namespace } => {
                 self.handle_clear_file_explorer_decorations(&namespace);
             }
+            PluginCommand::SetQuickfixList { entries } => {
+                self.handle_set_quickfix_list(entries);
+            }
 
             // ==================== Status/Prompt Commands ====================
             PluginCommand::SetStatus { message } => {
                 self.handle_set_status(message);
             }
+            PluginCommand::SetStatusBarItem { id, text, options } => {
+                self.handle_set_status_bar_item(id, text, options);
+            }
+            PluginCommand::SetPluginStorage {
+                plugin_name,
+                key,
+                value,
+            } => {
+                self.handle_set_plugin_storage(plugin_name, key, value);
+            }
+            PluginCommand::RegisterTextObject { name, plugin_name } => {
+                self.handle_register_text_object(name, plugin_name);
+            }
+            PluginCommand::ApplyTextObject {
+                buffer_id,
+                range,
+                operation,
+            } => {
+                self.handle_apply_text_object(buffer_id, range, operation);
+            }
+            PluginCommand::RegisterOpenHandler {
+                pattern,
+                plugin_name,
+            } => {
+                self.handle_register_open_handler(pattern, plugin_name);
+            }
+            PluginCommand::SetOpenedBufferContent { buffer_id, content } => {
+                self.handle_set_opened_buffer_content(buffer_id, content);
+            }
+            PluginCommand::OpenHandlerFailed { buffer_id, error } => {
+                self.handle_open_handler_failed(buffer_id, error);
+            }
+            PluginCommand::PluginUnloaded { plugin_name } => {
+                self.handle_plugin_unloaded(&plugin_name);
+            }
+            PluginCommand::RemoveStatusBarItem { id } => {
+                self.plugin_status_items.retain(|item| item.id != id);
+            }
             PluginCommand::ApplyTheme { theme_name } => {
                 self.apply_theme(&theme_name);
             }
@@ -4130,6 +4586,13 @@ impl Editor {
             } => {
                 self.handle_send_lsp_request(language, method, params, request_id);
             }
+            PluginCommand::LspRequest {
+                request_id,
+                method,
+                params,
+            } => {
+                self.handle_lsp_request(request_id, method, params);
+            }
 
             // ==================== Clipboard Commands ====================
             PluginCommand::SetClipboard { text } => {
@@ -4537,6 +5000,7 @@ impl Editor {
                     state.margins.set_line_numbers(show_line_numbers);
                     state.show_cursors = show_cursors;
                     state.editing_disabled = editing_disabled;
+                    state.line_wrap = line_wrap;
                     tracing::debug!(
                         "Set buffer {:?} view options: show_line_numbers={}, show_cursors={}, editing_disabled={}",
                         buffer_id,
@@ -4663,6 +5127,7 @@ impl Editor {
                     state.margins.set_line_numbers(show_line_numbers);
                     state.show_cursors = show_cursors;
                     state.editing_disabled = editing_disabled;
+                    state.line_wrap = line_wrap;
                 }
 
                 // Set the content
@@ -4750,6 +5215,22 @@ impl Editor {
             PluginCommand::SetEditorMode { mode } => {
                 self.handle_set_editor_mode(mode);
             }
+            PluginCommand::GetKeybindings {
+                context,
+                request_id,
+            } => {
+                self.handle_get_keybindings(context, request_id);
+            }
+            PluginCommand::SetKeybinding {
+                key,
+                command,
+                context,
+            } => {
+                self.handle_set_keybinding(key, command, context);
+            }
+            PluginCommand::RemoveKeybinding { key, context } => {
+                self.handle_remove_keybinding(key, context);
+            }
 
             // ==================== LSP Helper Commands ====================
             PluginCommand::ShowActionPopup {
@@ -4910,6 +5391,7 @@ impl Editor {
 
     /// Save a buffer to a specific file path (for :w filename)
     fn handle_save_buffer_to_path(&mut self, buffer_id: BufferId, path: std::path::PathBuf) {
+        self.insert_final_newline_before_save_in(buffer_id);
         if let Some(state) = self.buffers.get_mut(&buffer_id) {
             // Save to the specified path
             match state.buffer.save_to_file(&path) {
@@ -5138,6 +5620,53 @@ fn parse_key_string(key_str: &str) -> Option<(KeyCode, KeyModifiers)> {
     Some((code, modifiers))
 }
 
+/// Format a key as a string `parse_key_string` accepts, like "RET", "C-n", "M-x", "Q"
+///
+/// Returns None for keys that `parse_key_string` has no name for (e.g. Insert).
+fn format_key_string(code: KeyCode, modifiers: KeyModifiers) -> Option<String> {
+    let mut key = String::new();
+    if modifiers.contains(KeyModifiers::CONTROL) {
+        key.push_str("C-");
+    }
+    if modifiers.contains(KeyModifiers::ALT) {
+        key.push_str("M-");
+    }
+    let shift = modifiers.contains(KeyModifiers::SHIFT);
+
+    // Shifted letters are written uppercase, matching how they are parsed
+    if let KeyCode::Char(c) = code {
+        if shift && c.is_ascii_alphabetic() {
+            key.push(c.to_ascii_uppercase());
+            return Some(key);
+        }
+    }
+
+    let name = match code {
+        KeyCode::Char(' ') => "SPC".to_string(),
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::Enter => "RET".to_string(),
+        KeyCode::Tab => "TAB".to_string(),
+        KeyCode::Esc => "ESC".to_string(),
+        KeyCode::Delete => "DEL".to_string(),
+        KeyCode::Backspace => "BS".to_string(),
+        KeyCode::Up => "Up".to_string(),
+        KeyCode::Down => "Down".to_string(),
+        KeyCode::Left => "Left".to_string(),
+        KeyCode::Right => "Right".to_string(),
+        KeyCode::Home => "Home".to_string(),
+        KeyCode::End => "End".to_string(),
+        KeyCode::PageUp => "PageUp".to_string(),
+        KeyCode::PageDown => "PageDown".to_string(),
+        KeyCode::F(n) => format!("F{}", n),
+        _ => return None,
+    };
+    if shift {
+        key.push_str("S-");
+    }
+    key.push_str(&name);
+    Some(key)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -6016,6 +6545,48 @@ mod tests {
         assert_eq!(editor.active_state().cursors.primary().position, 10);
     }
 
+    #[test]
+    fn test_goto_matching_bracket_skips_strings_and_comments() {
+        let config = Config::default();
+        let (dir_context, _temp) = test_dir_context();
+        let mut editor = Editor::new(
+            config,
+            80,
+            24,
+            dir_context,
+            crate::view::color_support::ColorCapability::TrueColor,
+        )
+        .unwrap();
+
+        let text = "f(\")\", // )\n x)";
+        let state = editor.active_state_mut();
+        state.language = "rust".to_string();
+        state.apply(&Event::Insert {
+            position: 0,
+            text: text.to_string(),
+            cursor_id: state.cursors.primary_id(),
+        });
+        state.apply(&Event::MoveCursor {
+            cursor_id: state.cursors.primary_id(),
+            old_position: text.len(),
+            new_position: 1,
+            old_anchor: None,
+            new_anchor: None,
+            old_sticky_column: 0,
+            new_sticky_column: 0,
+        });
+
+        editor.goto_matching_bracket();
+        assert_eq!(
+            editor.active_state().cursors.primary().position,
+            text.len() - 1
+        );
+
+        // And back again from the closing bracket
+        editor.goto_matching_bracket();
+        assert_eq!(editor.active_state().cursors.primary().position, 1);
+    }
+
     #[test]
     fn test_search_case_sensitive() {
         let config = Config::default();
@@ -6111,6 +6682,49 @@ mod tests {
         assert_eq!(search_state.matches[1], 27, "Second match at position 27");
     }
 
+    #[test]
+    fn test_search_regex() {
+        let config = Config::default();
+        let (dir_context, _temp) = test_dir_context();
+        let mut editor = Editor::new(
+            config,
+            80,
+            24,
+            dir_context,
+            crate::view::color_support::ColorCapability::TrueColor,
+        )
+        .unwrap();
+
+        let state = editor.active_state_mut();
+        state.apply(&Event::Insert {
+            position: 0,
+            text: "v1 v22 v".to_string(),
+            cursor_id: state.cursors.primary_id(),
+        });
+
+        // Literal search treats the pattern as plain text
+        editor.perform_search(r"v\d*");
+        assert!(
+            editor.search_state.is_none(),
+            "Literal pattern is not in the text"
+        );
+
+        // Regex search matches the pattern, skipping empty matches
+        editor.search_use_regex = true;
+        editor.perform_search(r"v\d*");
+        let search_state = editor.search_state.as_ref().unwrap();
+        assert!(search_state.use_regex);
+        assert_eq!(search_state.matches, vec![0, 3, 7]);
+
+        editor.perform_search(r"\d*");
+        let search_state = editor.search_state.as_ref().unwrap();
+        assert_eq!(search_state.matches, vec![1, 4]);
+
+        // An invalid pattern clears the search
+        editor.perform_search("v(");
+        assert!(editor.search_state.is_none());
+    }
+
     #[test]
     fn test_bookmarks() {
         let config = Config::default();
@@ -6169,6 +6783,115 @@ mod tests {
         assert!(!editor.bookmarks.contains_key(&'1'));
     }
 
+    #[test]
+    fn test_bookmarks_follow_edits() {
+        let config = Config::default();
+        let (dir_context, _temp) = test_dir_context();
+        let mut editor = Editor::new(
+            config,
+            80,
+            24,
+            dir_context,
+            crate::view::color_support::ColorCapability::TrueColor,
+        )
+        .unwrap();
+
+        let cursor_id = editor.active_state().cursors.primary_id();
+        editor.apply_event_to_active_buffer(&Event::Insert {
+            position: 0,
+            text: "Line 1\nLine 2\nLine 3".to_string(),
+            cursor_id,
+        });
+
+        // Bookmark the start of "Line 3"
+        editor.apply_event_to_active_buffer(&Event::MoveCursor {
+            cursor_id,
+            old_position: 20,
+            new_position: 14,
+            old_anchor: None,
+            new_anchor: None,
+            old_sticky_column: 0,
+            new_sticky_column: 0,
+        });
+        editor.set_bookmark('1');
+
+        // Insert a line above the bookmark
+        editor.apply_event_to_active_buffer(&Event::Insert {
+            position: 0,
+            text: "New line\n".to_string(),
+            cursor_id,
+        });
+        assert_eq!(editor.bookmarks.get(&'1').unwrap().position, 23);
+
+        // Delete "Line 1\n" above the bookmark
+        editor.apply_event_to_active_buffer(&Event::Delete {
+            range: 9..16,
+            deleted_text: "Line 1\n".to_string(),
+            cursor_id,
+        });
+        assert_eq!(editor.bookmarks.get(&'1').unwrap().position, 16);
+
+        // Edits after the bookmark leave it alone
+        editor.apply_event_to_active_buffer(&Event::Insert {
+            position: 22,
+            text: "!".to_string(),
+            cursor_id,
+        });
+        assert_eq!(editor.bookmarks.get(&'1').unwrap().position, 16);
+
+        // Jumping lands on the adjusted position, still the start of "Line 3"
+        editor.jump_to_bookmark('1');
+        let position = editor.active_state().cursors.primary().position;
+        assert_eq!(position, 16);
+        let content = editor.active_state().buffer.to_string().unwrap();
+        assert_eq!(&content[position..], "Line 3!");
+    }
+
+    #[test]
+    fn test_bookmarks_follow_bulk_edit_undo_and_redo() {
+        let config = Config::default();
+        let (dir_context, _temp) = test_dir_context();
+        let mut editor = Editor::new(
+            config,
+            80,
+            24,
+            dir_context,
+            crate::view::color_support::ColorCapability::TrueColor,
+        )
+        .unwrap();
+
+        let cursor_id = editor.active_state().cursors.primary_id();
+        editor.apply_event_to_active_buffer(&Event::Insert {
+            position: 0,
+            text: "foo bar foo\nLine 2\nfoo".to_string(),
+            cursor_id,
+        });
+
+        // Bookmark the start of "Line 2"
+        editor.apply_event_to_active_buffer(&Event::MoveCursor {
+            cursor_id,
+            old_position: 22,
+            new_position: 12,
+            old_anchor: None,
+            new_anchor: None,
+            old_sticky_column: 0,
+            new_sticky_column: 0,
+        });
+        editor.set_bookmark('1');
+
+        // Replace All applies a single bulk edit
+        editor.perform_replace("foo", "x");
+        assert_eq!(editor.bookmarks.get(&'1').unwrap().position, 8);
+
+        editor.handle_undo();
+        assert_eq!(editor.bookmarks.get(&'1').unwrap().position, 12);
+
+        editor.handle_redo();
+        assert_eq!(editor.bookmarks.get(&'1').unwrap().position, 8);
+        let content = editor.active_state().buffer.to_string().unwrap();
+        assert_eq!(&content[8..], "Line 2\nx");
+    }
+
     #[test]
     fn test_action_enum_new_variants() {
         // Test that new actions can be parsed from strings
@@ -6733,6 +7456,110 @@ mod tests {
         );
     }
 
+    /// Build an editor holding `initial`, plus the BulkEdit event that restores the
+    /// tree produced by applying `edits` (position, delete_len, insert_text) to it.
+    fn editor_with_tree_restore(
+        initial: &str,
+        edits: &[(usize, usize, &str)],
+    ) -> (Editor, Event, tempfile::TempDir) {
+        use crate::model::buffer::Buffer;
+
+        let (dir_context, temp) = test_dir_context();
+        let mut editor = Editor::new(
+            Config::default(),
+            80,
+            24,
+            dir_context,
+            crate::view::color_support::ColorCapability::TrueColor,
+        )
+        .unwrap();
+        editor.active_state_mut().buffer = Buffer::from_str(initial, 1024 * 1024);
+
+        let buffer = &mut editor.active_state_mut().buffer;
+        let old_tree = buffer.snapshot_piece_tree();
+        buffer.apply_bulk_edits(edits);
+        let new_tree = buffer.snapshot_piece_tree();
+        buffer.restore_piece_tree(&old_tree);
+
+        let event = Event::BulkEdit {
+            old_tree: Some(old_tree),
+            new_tree: Some(new_tree),
+            old_cursors: vec![],
+            new_cursors: vec![],
+            edits: vec![],
+            description: "Redo".to_string(),
+        };
+        (editor, event, temp)
+    }
+
+    /// Restoring a tree snapshot (undo/redo of a bulk edit) sends only the changed
+    /// span within a single line
+    #[test]
+    fn test_lsp_tree_restore_single_line_change() {
+        let (editor, event, _temp) =
+            editor_with_tree_restore("fn foo(val: i32) {\n    val + 1\n}\n", &[(23, 3, "value")]);
+
+        let changes = editor.collect_lsp_changes(&event);
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!(
+            changes[0].range,
+            Some(LspRange::new(Position::new(1, 7), Position::new(1, 7)))
+        );
+        assert_eq!(changes[0].text, "ue");
+    }
+
+    /// Edits on different lines are sent as one span covering both
+    #[test]
+    fn test_lsp_tree_restore_multi_line_change() {
+        let (editor, event, _temp) = editor_with_tree_restore(
+            "fn foo(val: i32) {\n    val + 1\n}\n",
+            &[(23, 3, "value"), (7, 3, "value")],
+        );
+
+        let changes = editor.collect_lsp_changes(&event);
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!(
+            changes[0].range,
+            Some(LspRange::new(Position::new(0, 10), Position::new(1, 7)))
+        );
+        assert_eq!(changes[0].text, "ue: i32) {\n    value");
+    }
+
+    /// A retyped span is narrowed to the characters that differ
+    #[test]
+    fn test_lsp_tree_restore_narrows_to_changed_characters() {
+        let (editor, event, _temp) =
+            editor_with_tree_restore("abc hallo def\n", &[(4, 5, "hello")]);
+
+        let changes = editor.collect_lsp_changes(&event);
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!(
+            changes[0].range,
+            Some(LspRange::new(Position::new(0, 5), Position::new(0, 6)))
+        );
+        assert_eq!(changes[0].text, "e");
+    }
+
+    /// Restoring an identical tree sends nothing
+    #[test]
+    fn test_lsp_tree_restore_without_changes() {
+        let (editor, _event, _temp) = editor_with_tree_restore("abc\n", &[]);
+        let tree = editor.active_state().buffer.snapshot_piece_tree();
+        let event = Event::BulkEdit {
+            old_tree: Some(tree.clone()),
+            new_tree: Some(tree),
+            old_cursors: vec![],
+            new_cursors: vec![],
+            edits: vec![],
+            description: "Redo".to_string(),
+        };
+
+        assert!(editor.collect_lsp_changes(&event).is_empty());
+    }
+
     #[test]
     fn test_ensure_active_tab_visible_static_offset() {
         let config = Config::default();
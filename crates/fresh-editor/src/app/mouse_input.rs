@@ -28,11 +28,15 @@ impl Editor {
         let col = mouse_event.column;
         let row = mouse_event.row;
 
-        // Detect double-click for left button down events (used by all handlers)
-        let is_double_click = if matches!(mouse_event.kind, MouseEventKind::Down(MouseButton::Left))
-        {
+        // Clicking between cuts ends the run of kills that append to one entry
+        if matches!(mouse_event.kind, MouseEventKind::Down(_)) {
+            self.last_action_was_kill = false;
+        }
+
+        // Count consecutive clicks for left button down events (used by all handlers)
+        let click_count = if matches!(mouse_event.kind, MouseEventKind::Down(MouseButton::Left)) {
             let now = self.time_source.now();
-            let is_double = if let (Some(previous_time), Some(previous_pos)) =
+            let is_repeat = if let (Some(previous_time), Some(previous_pos)) =
                 (self.previous_click_time, self.previous_click_position)
             {
                 let double_click_threshold =
@@ -44,18 +48,21 @@ impl Editor {
                 false
             };
 
-            // Update click tracking
-            if is_double {
-                self.previous_click_time = None;
-                self.previous_click_position = None;
+            // Update click tracking; a triple click ends the sequence so the
+            // next click starts over as a single click
+            let count = if is_repeat {
+                self.previous_click_count + 1
             } else {
-                self.previous_click_time = Some(now);
-                self.previous_click_position = Some((col, row));
-            }
-            is_double
+                1
+            };
+            self.previous_click_count = if count >= 3 { 0 } else { count };
+            self.previous_click_time = Some(now);
+            self.previous_click_position = Some((col, row));
+            count
         } else {
-            false
+            0
         };
+        let is_double_click = click_count == 2;
 
         // When settings modal is open, capture all mouse events
         if self.settings_state.as_ref().is_some_and(|s| s.visible) {
@@ -99,6 +106,10 @@ impl Editor {
                     needs_render = true;
                     return Ok(needs_render);
                 }
+                if click_count == 3 && self.handle_mouse_triple_click(col, row)? {
+                    needs_render = true;
+                    return Ok(needs_render);
+                }
                 self.handle_mouse_click(col, row)?;
                 needs_render = true;
             }
@@ -187,12 +198,13 @@ impl Editor {
                 self.update_lsp_hover_state(col, row);
             }
             MouseEventKind::ScrollUp => {
+                let lines = self.config.editor.mouse_scroll_lines as i32;
                 // Check if file browser is active and should handle scroll
-                if self.is_file_open_active() && self.handle_file_open_scroll(-3) {
+                if self.is_file_open_active() && self.handle_file_open_scroll(-lines) {
                     needs_render = true;
                 } else if self.is_mouse_over_any_popup(col, row) {
                     // Scroll the popup content (works for all popups including completion)
-                    self.scroll_popup(-3);
+                    self.scroll_popup(-lines);
                     needs_render = true;
                 } else {
                     // If in terminal mode, exit to scrollback mode first so scrolling works
@@ -203,19 +215,20 @@ impl Editor {
                     }
                     // Dismiss hover/signature help popups on scroll
                     self.dismiss_transient_popups();
-                    self.handle_mouse_scroll(col, row, -3)?;
+                    self.handle_mouse_scroll(col, row, -lines)?;
                     // Sync viewport from SplitViewState to EditorState so rendering sees the scroll
                     self.sync_split_view_state_to_editor_state();
                     needs_render = true;
                 }
             }
             MouseEventKind::ScrollDown => {
+                let lines = self.config.editor.mouse_scroll_lines as i32;
                 // Check if file browser is active and should handle scroll
-                if self.is_file_open_active() && self.handle_file_open_scroll(3) {
+                if self.is_file_open_active() && self.handle_file_open_scroll(lines) {
                     needs_render = true;
                 } else if self.is_mouse_over_any_popup(col, row) {
                     // Scroll the popup content (works for all popups including completion)
-                    self.scroll_popup(3);
+                    self.scroll_popup(lines);
                     needs_render = true;
                 } else {
                     // If in terminal mode, exit to scrollback mode first so scrolling works
@@ -226,12 +239,24 @@ impl Editor {
                     }
                     // Dismiss hover/signature help popups on scroll
                     self.dismiss_transient_popups();
-                    self.handle_mouse_scroll(col, row, 3)?;
+                    self.handle_mouse_scroll(col, row, lines)?;
                     // Sync viewport from SplitViewState to EditorState so rendering sees the scroll
                     self.sync_split_view_state_to_editor_state();
                     needs_render = true;
                 }
             }
+            MouseEventKind::ScrollLeft | MouseEventKind::ScrollRight => {
+                if !self.is_mouse_over_any_popup(col, row) {
+                    self.dismiss_transient_popups();
+                    let columns = if matches!(mouse_event.kind, MouseEventKind::ScrollLeft) {
+                        -3
+                    } else {
+                        3
+                    };
+                    self.scroll_active_split_horizontally(columns);
+                    needs_render = true;
+                }
+            }
             MouseEventKind::Down(MouseButton::Right) => {
                 // Handle right-click for context menus
                 self.handle_right_click(col, row)?;
@@ -877,7 +902,14 @@ impl Editor {
                 self.key_context = crate::input::keybindings::KeyContext::Normal;
 
                 // Position cursor at click location and select word
-                self.handle_editor_double_click(col, row, *split_id, *buffer_id, *content_rect)?;
+                self.handle_editor_multi_click(
+                    col,
+                    row,
+                    *split_id,
+                    *buffer_id,
+                    *content_rect,
+                    Action::SelectWord,
+                )?;
                 return Ok(());
             }
         }
@@ -885,14 +917,50 @@ impl Editor {
         Ok(())
     }
 
-    /// Handle double-click in editor content area - selects the word under cursor
-    fn handle_editor_double_click(
+    /// Handle mouse triple click (down event)
+    /// Triple-click in editor area selects the whole line under the cursor.
+    /// Returns false when the click is outside an editor split, so it can be
+    /// handled as an ordinary click.
+    fn handle_mouse_triple_click(&mut self, col: u16, row: u16) -> AnyhowResult<bool> {
+        let split_areas = self.cached_layout.split_areas.clone();
+        for (split_id, buffer_id, content_rect, _scrollbar_rect, _thumb_start, _thumb_end) in
+            &split_areas
+        {
+            if col >= content_rect.x
+                && col < content_rect.x + content_rect.width
+                && row >= content_rect.y
+                && row < content_rect.y + content_rect.height
+            {
+                if self.is_terminal_buffer(*buffer_id) {
+                    return Ok(false);
+                }
+
+                self.key_context = crate::input::keybindings::KeyContext::Normal;
+                self.handle_editor_multi_click(
+                    col,
+                    row,
+                    *split_id,
+                    *buffer_id,
+                    *content_rect,
+                    Action::SelectLine,
+                )?;
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Handle a double or triple click in editor content area - moves the
+    /// cursor to the click and runs `select` (word or line selection)
+    fn handle_editor_multi_click(
         &mut self,
         col: u16,
         row: u16,
         split_id: crate::model::event::SplitId,
         buffer_id: BufferId,
         content_rect: ratatui::layout::Rect,
+        select: Action,
     ) -> AnyhowResult<()> {
         use crate::model::event::Event;
 
@@ -947,8 +1015,8 @@ impl Editor {
             state.apply(&event);
         }
 
-        // Now select the word under cursor
-        self.handle_action(Action::SelectWord)?;
+        // Now select the word or line under cursor
+        self.handle_action(select)?;
 
         Ok(())
     }
@@ -1637,6 +1705,22 @@ impl Editor {
             state.apply(&event);
         }
 
+        // Dragging past the top or bottom edge extends the selection one line
+        // beyond the viewport, which scrolls it into view on the next render
+        let edge_action = if row < content_rect.y {
+            Some(Action::SelectUp)
+        } else if row >= content_rect.y + content_rect.height {
+            Some(Action::SelectDown)
+        } else {
+            None
+        };
+        if let Some(action) = edge_action {
+            if let Some(view_state) = self.split_view_states.get_mut(&split_id) {
+                view_state.viewport.clear_skip_ensure_visible();
+            }
+            self.handle_action(action)?;
+        }
+
         Ok(())
     }
 
@@ -1849,6 +1933,13 @@ impl Editor {
             None
         };
 
+        // Built-in git status, used when no plugin decorates the entry
+        let git_symbol = self
+            .file_explorer
+            .as_ref()
+            .and_then(|explorer| explorer.git_status().status(&path))
+            .and_then(|status| status.symbol());
+
         // Check if file/folder has unsaved changes in editor
         let has_unsaved_changes = if is_directory {
             // Check if any buffer under this directory has unsaved changes
@@ -1876,9 +1967,8 @@ impl Editor {
         // Build tooltip content
         let mut lines: Vec<String> = Vec::new();
 
-        if let Some(decoration) = &decoration {
-            let symbol = &decoration.symbol;
-            let explanation = match symbol.as_str() {
+        let explain = |symbol: &str| {
+            let explanation = match symbol {
                 "U" => "Untracked - File is not tracked by git",
                 "M" => "Modified - File has unstaged changes",
                 "A" => "Added - File is staged for commit",
@@ -1889,9 +1979,15 @@ impl Editor {
                 "●" => "Has changes - Contains modified files",
                 _ => "Unknown status",
             };
-            lines.push(format!("{} - {}", symbol, explanation));
-        } else if bubbled_decoration.is_some() {
+            format!("{} - {}", symbol, explanation)
+        };
+
+        if let Some(decoration) = &decoration {
+            lines.push(explain(&decoration.symbol));
+        } else if bubbled_decoration.is_some() || (is_directory && git_symbol.is_some()) {
             lines.push("● - Contains modified files".to_string());
+        } else if let Some(symbol) = git_symbol {
+            lines.push(explain(symbol));
         } else if has_unsaved_changes {
             if is_directory {
                 lines.push("● - Contains unsaved changes".to_string());
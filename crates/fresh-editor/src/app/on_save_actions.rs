@@ -10,7 +10,7 @@ use std::time::Duration;
 
 use super::Editor;
 use crate::config::{FormatterConfig, OnSaveAction};
-use crate::model::event::Event;
+use crate::model::event::{BufferId, Event};
 use crate::services::lsp::manager::detect_language;
 use rust_i18n::t;
 
@@ -99,6 +99,171 @@ impl Editor {
         Ok(ran_any_action)
     }
 
+    /// Append a line ending to the active buffer before it is written, if
+    /// `insert_final_newline` is enabled and the buffer doesn't end with one.
+    ///
+    /// This is a regular undoable edit; cursors are left where they were.
+    pub(crate) fn insert_final_newline_before_save(&mut self) {
+        if let Some(batch) = self.final_newline_event(self.active_buffer()) {
+            self.active_event_log_mut().append(batch.clone());
+            self.apply_event_to_active_buffer(&batch);
+        }
+    }
+
+    /// Like `insert_final_newline_before_save`, for a buffer that may not be
+    /// the active one (e.g. saved by a plugin)
+    pub(crate) fn insert_final_newline_before_save_in(&mut self, buffer_id: BufferId) {
+        if buffer_id == self.active_buffer() {
+            self.insert_final_newline_before_save();
+            return;
+        }
+        let Some(batch) = self.final_newline_event(buffer_id) else {
+            return;
+        };
+        if let Some(state) = self.buffers.get_mut(&buffer_id) {
+            state.apply(&batch);
+            self.adjust_bookmarks_for_event(buffer_id, &batch);
+            if let Some(log) = self.event_logs.get_mut(&buffer_id) {
+                log.append(batch);
+            }
+        }
+    }
+
+    /// The edit that appends a final line ending to `buffer_id`, if
+    /// `insert_final_newline` calls for one
+    fn final_newline_event(&self, buffer_id: BufferId) -> Option<Event> {
+        let state = self.buffers.get(&buffer_id)?;
+        if !state
+            .insert_final_newline
+            .unwrap_or(self.config.editor.insert_final_newline)
+        {
+            return None;
+        }
+
+        let buffer_len = state.buffer.len();
+        if buffer_len == 0 {
+            return None;
+        }
+        let last_byte = state.buffer.slice_bytes(buffer_len - 1..buffer_len);
+        if matches!(last_byte.as_slice(), [] | [b'\n'] | [b'\r']) {
+            return None;
+        }
+
+        let text = state.buffer.line_ending().as_str().to_string();
+        let primary_id = state.cursors.primary_id();
+        // Where each position ends up after the insert, which moves the
+        // inserting (primary) cursor and anything at the end of the buffer
+        let shifted = |pos: usize| {
+            if pos >= buffer_len {
+                pos + text.len()
+            } else {
+                pos
+            }
+        };
+
+        let mut events = vec![Event::Insert {
+            position: buffer_len,
+            text: text.clone(),
+            cursor_id: primary_id,
+        }];
+        for (cursor_id, cursor) in state.cursors.iter() {
+            let (old_position, old_anchor) = if cursor_id == primary_id {
+                (buffer_len + text.len(), None)
+            } else {
+                (shifted(cursor.position), cursor.anchor.map(shifted))
+            };
+            if (old_position, old_anchor) != (cursor.position, cursor.anchor) {
+                events.push(Event::MoveCursor {
+                    cursor_id,
+                    old_position,
+                    new_position: cursor.position,
+                    old_anchor,
+                    new_anchor: cursor.anchor,
+                    old_sticky_column: cursor.sticky_column,
+                    new_sticky_column: cursor.sticky_column,
+                });
+            }
+        }
+
+        Some(Event::Batch {
+            events,
+            description: "Insert final newline".to_string(),
+        })
+    }
+
+    /// Remove trailing spaces and tabs from every line when
+    /// `trim_trailing_whitespace` is enabled, as one undoable edit.
+    pub(crate) fn trim_trailing_whitespace_before_save(&mut self) {
+        use crate::model::event::CursorId;
+
+        let estimated_line_length = self.config.editor.estimated_line_length;
+        let trim_by_default = self.config.editor.trim_trailing_whitespace;
+        let state = self.active_state_mut();
+        if !state.trim_trailing_whitespace.unwrap_or(trim_by_default) || state.editing_disabled {
+            return;
+        }
+
+        // Byte ranges of trailing whitespace, in buffer order
+        let mut ranges: Vec<std::ops::Range<usize>> = Vec::new();
+        {
+            let mut iter = state.buffer.line_iterator(0, estimated_line_length);
+            while let Some((line_start, bytes)) = iter.next_line_bytes() {
+                let content = bytes.strip_suffix(b"\n").unwrap_or(&bytes);
+                let content = content.strip_suffix(b"\r").unwrap_or(content);
+                let trimmed_len = content
+                    .iter()
+                    .rposition(|b| !matches!(b, b' ' | b'\t'))
+                    .map_or(0, |i| i + 1);
+                if trimmed_len < content.len() {
+                    ranges.push(line_start + trimmed_len..line_start + content.len());
+                }
+            }
+        }
+        if ranges.is_empty() {
+            return;
+        }
+
+        // Positions inside a removed run move to its start
+        let map_position = |pos: usize| -> usize {
+            let mut removed = 0;
+            for range in &ranges {
+                if pos <= range.start {
+                    break;
+                }
+                removed += pos.min(range.end) - range.start;
+            }
+            pos - removed
+        };
+
+        let state = self.active_state_mut();
+        let mut events = Vec::new();
+        // Delete from the end so earlier ranges stay valid
+        for range in ranges.iter().rev() {
+            events.push(Event::Delete {
+                range: range.clone(),
+                deleted_text: state.get_text_range(range.start, range.end),
+                cursor_id: CursorId::UNDO_SENTINEL,
+            });
+        }
+        for (cursor_id, cursor) in state.cursors.iter() {
+            events.push(Event::MoveCursor {
+                cursor_id,
+                old_position: cursor.position,
+                new_position: map_position(cursor.position),
+                old_anchor: cursor.anchor,
+                new_anchor: cursor.anchor.map(map_position),
+                old_sticky_column: 0,
+                new_sticky_column: 0,
+            });
+        }
+
+        if let Some(bulk_edit) =
+            self.apply_events_as_bulk_edit(events, "Trim trailing whitespace".to_string())
+        {
+            self.active_event_log_mut().append(bulk_edit);
+        }
+    }
+
     /// Format the current buffer using the configured formatter.
     /// Returns Ok(()) if formatting succeeded, or Err with an error message.
     pub fn format_buffer(&mut self) -> Result<(), String> {
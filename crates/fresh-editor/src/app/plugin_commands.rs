@@ -2,14 +2,63 @@
 //!
 //! This module groups plugin commands by domain for better maintainability.
 
+use crate::input::keybindings::KeyContext;
+use crate::model::cursor::Cursor;
 use crate::model::event::{BufferId, CursorId, Event, SplitId};
 use crate::view::overlay::{OverlayHandle, OverlayNamespace};
 use crate::view::split::SplitViewState;
 use anyhow::Result as AnyhowResult;
-use fresh_core::api::{LayoutHints, MenuPosition, PluginResponse, ViewTransformPayload};
+use fresh_core::api::{
+    BufferEdit, LayoutHints, MenuPosition, PluginResponse, ViewTransformPayload,
+};
+use rust_i18n::t;
 
+use super::types::PendingTextObject;
 use super::Editor;
 
+/// How long a plugin LSP request may wait for the server before its promise
+/// is rejected
+const PLUGIN_LSP_REQUEST_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Overlay namespace holding one plugin's decorations in `group`, so plugins
+/// and groups can be cleared independently
+fn decoration_namespace(plugin_name: &str, group: &str) -> OverlayNamespace {
+    OverlayNamespace::from_string(format!("decoration:{plugin_name}:{group}"))
+}
+
+/// Overlay face for a decoration style: one of the predefined kinds, or a
+/// theme color key used as the background
+fn decoration_face(
+    theme: &crate::view::theme::Theme,
+    style: &str,
+) -> Option<crate::model::event::OverlayFace> {
+    use crate::model::event::{OverlayFace, UnderlineStyle};
+    use crate::view::theme::color_to_rgb;
+
+    let underline =
+        |color, style| color_to_rgb(color).map(|color| OverlayFace::Underline { color, style });
+    match style {
+        "error" => underline(theme.diagnostic_error_fg, UnderlineStyle::Wavy),
+        "warning" => underline(theme.diagnostic_warning_fg, UnderlineStyle::Wavy),
+        "info" => underline(theme.diagnostic_info_fg, UnderlineStyle::Wavy),
+        "hint" => underline(theme.diagnostic_hint_fg, UnderlineStyle::Dotted),
+        "highlight" => {
+            color_to_rgb(theme.search_match_bg).map(|color| OverlayFace::Background { color })
+        }
+        "emphasis" => color_to_rgb(theme.editor_fg).map(|color| OverlayFace::Style {
+            color,
+            bg_color: None,
+            bold: true,
+            italic: false,
+            underline: false,
+        }),
+        key => theme
+            .color_by_key(key)
+            .and_then(color_to_rgb)
+            .map(|color| OverlayFace::Background { color }),
+    }
+}
+
 impl Editor {
     // ==================== Menu Helpers ====================
 
@@ -98,6 +147,47 @@ impl Editor {
         }
     }
 
+    /// Handle HighlightRange command
+    pub(super) fn handle_highlight_range(
+        &mut self,
+        buffer_id: BufferId,
+        plugin_name: String,
+        group: String,
+        range: std::ops::Range<usize>,
+        style: String,
+    ) {
+        let Some(face) = decoration_face(&self.theme, &style) else {
+            tracing::warn!(
+                "highlightRange: plugin '{}' used unknown style '{}'",
+                plugin_name,
+                style
+            );
+            return;
+        };
+        if let Some(state) = self.buffers.get_mut(&buffer_id) {
+            let event = Event::AddOverlay {
+                namespace: Some(decoration_namespace(&plugin_name, &group)),
+                range,
+                face,
+                priority: 10,
+                message: None,
+                extend_to_line_end: false,
+            };
+            state.apply(&event);
+            // Note: Overlays are ephemeral, not added to event log for undo/redo
+        }
+    }
+
+    /// Handle ClearHighlights command
+    pub(super) fn handle_clear_highlights(
+        &mut self,
+        buffer_id: BufferId,
+        plugin_name: String,
+        group: String,
+    ) {
+        self.handle_clear_namespace(buffer_id, decoration_namespace(&plugin_name, &group));
+    }
+
     /// Handle ClearOverlaysInRange command
     pub(super) fn handle_clear_overlays_in_range(
         &mut self,
@@ -633,6 +723,7 @@ impl Editor {
                 cursor_id: CursorId(0),
             };
             state.apply(&event);
+            self.adjust_bookmarks_for_event(buffer_id, &event);
             if let Some(log) = self.event_logs.get_mut(&buffer_id) {
                 log.append(event);
             }
@@ -653,12 +744,76 @@ impl Editor {
                 cursor_id: CursorId(0),
             };
             state.apply(&event);
+            self.adjust_bookmarks_for_event(buffer_id, &event);
             if let Some(log) = self.event_logs.get_mut(&buffer_id) {
                 log.append(event);
             }
         }
     }
 
+    /// Handle ApplyEdits command
+    ///
+    /// Edit offsets refer to the buffer as it was before the batch. The edits are
+    /// re-validated, applied in order with each one shifted by the length change of
+    /// the ones before it, and logged as a single undoable batch.
+    pub(super) fn handle_apply_edits(&mut self, buffer_id: BufferId, edits: Vec<BufferEdit>) {
+        let Some(state) = self.buffers.get_mut(&buffer_id) else {
+            return;
+        };
+        let edits = match fresh_core::api::sort_buffer_edits(edits) {
+            Ok(edits) => edits,
+            Err(e) => {
+                tracing::warn!("applyEdits on buffer {:?} rejected: {}", buffer_id, e);
+                return;
+            }
+        };
+        let buffer_len = state.buffer.len();
+        if let Some(edit) = edits.iter().find(|e| e.end > buffer_len) {
+            tracing::warn!(
+                "applyEdits on buffer {:?} rejected: range {}..{} exceeds buffer length {}",
+                buffer_id,
+                edit.start,
+                edit.end,
+                buffer_len
+            );
+            return;
+        }
+
+        let mut events = Vec::new();
+        let mut shift: isize = 0;
+        for edit in edits {
+            let start = (edit.start as isize + shift) as usize;
+            if edit.end > edit.start {
+                events.push(Event::Delete {
+                    range: start..start + (edit.end - edit.start),
+                    deleted_text: state.get_text_range(edit.start, edit.end),
+                    cursor_id: CursorId(0),
+                });
+            }
+            shift += edit.text.len() as isize - (edit.end - edit.start) as isize;
+            if !edit.text.is_empty() {
+                events.push(Event::Insert {
+                    position: start,
+                    text: edit.text,
+                    cursor_id: CursorId(0),
+                });
+            }
+        }
+        if events.is_empty() {
+            return;
+        }
+
+        let batch = Event::Batch {
+            events,
+            description: "Plugin edits".to_string(),
+        };
+        state.apply(&batch);
+        self.adjust_bookmarks_for_event(buffer_id, &batch);
+        if let Some(log) = self.event_logs.get_mut(&buffer_id) {
+            log.append(batch);
+        }
+    }
+
     /// Handle InsertAtCursor command
     pub(super) fn handle_insert_at_cursor(&mut self, text: String) {
         // Insert text at current cursor position in active buffer
@@ -957,6 +1112,50 @@ impl Editor {
         }
     }
 
+    /// Handle SetStatusBarItem command
+    pub(super) fn handle_set_status_bar_item(
+        &mut self,
+        id: String,
+        text: String,
+        options: fresh_core::api::StatusBarItemOptions,
+    ) {
+        use crate::view::ui::status_bar::{PluginStatusItem, MAX_PLUGIN_STATUS_ITEMS};
+
+        let item = PluginStatusItem::new(id, &text, &options);
+        if let Some(existing) = self
+            .plugin_status_items
+            .iter_mut()
+            .find(|existing| existing.id == item.id)
+        {
+            *existing = item;
+        } else if self.plugin_status_items.len() >= MAX_PLUGIN_STATUS_ITEMS {
+            tracing::warn!(
+                "Ignoring status bar item '{}': limit of {} items reached",
+                item.id,
+                MAX_PLUGIN_STATUS_ITEMS
+            );
+            return;
+        } else {
+            self.plugin_status_items.push(item);
+        }
+
+        // Higher priority first; ties keep a stable order by id
+        self.plugin_status_items
+            .sort_by(|a, b| b.priority.cmp(&a.priority).then_with(|| a.id.cmp(&b.id)));
+    }
+
+    /// Handle SetPluginStorage command
+    pub(super) fn handle_set_plugin_storage(
+        &mut self,
+        plugin_name: String,
+        key: String,
+        value: serde_json::Value,
+    ) {
+        if let Err(e) = self.plugin_storage.set(&plugin_name, key, value) {
+            tracing::warn!("Failed to update plugin storage: {}", e);
+        }
+    }
+
     /// Handle StartPrompt command
     pub(super) fn handle_start_prompt(&mut self, label: String, prompt_type: String) {
         // Create a plugin-controlled prompt
@@ -1109,6 +1308,261 @@ impl Editor {
             .register(internal_command);
     }
 
+    /// Handle RegisterTextObject command
+    ///
+    /// Text objects are matched by the plugin; the editor exposes a select and a
+    /// delete command for each one, and expand-selection grows to them.
+    pub(super) fn handle_register_text_object(&mut self, name: String, plugin_name: String) {
+        use crate::input::commands::{Command as EditorCommand, CommandSource};
+        use crate::input::keybindings::Action;
+
+        if !self.plugin_text_objects.iter().any(|(_, n)| *n == name) {
+            self.plugin_text_objects
+                .push((plugin_name.clone(), name.clone()));
+        }
+
+        let registry = self.command_registry.read().unwrap();
+        registry.register(EditorCommand {
+            name: t!("cmd.select_text_object", name = &name).to_string(),
+            description: t!("cmd.select_text_object_desc", name = &name).to_string(),
+            action: Action::SelectTextObject(name.clone()),
+            contexts: vec![],
+            custom_contexts: vec![],
+            source: CommandSource::Plugin(plugin_name.clone()),
+        });
+        registry.register(EditorCommand {
+            name: t!("cmd.delete_text_object", name = &name).to_string(),
+            description: t!("cmd.delete_text_object_desc", name = &name).to_string(),
+            action: Action::DeleteTextObject(name),
+            contexts: vec![],
+            custom_contexts: vec![],
+            source: CommandSource::Plugin(plugin_name),
+        });
+    }
+
+    /// Ask the plugin that registered a text object for its range around the
+    /// primary cursor. The range arrives later as an ApplyTextObject command.
+    pub(super) fn match_text_object(
+        &mut self,
+        name: &str,
+        operation: fresh_core::api::TextObjectOperation,
+    ) {
+        let cursor = *self.active_state().cursors.primary();
+        let query = self.start_text_object_query(cursor, operation);
+        self.plugin_manager.match_text_object(name, query);
+    }
+
+    /// After expand-selection, ask every plugin text object for its range around
+    /// the selection it started from (`before`). The smallest range that
+    /// strictly contains that selection replaces the built-in expansion.
+    pub(super) fn expand_selection_to_text_objects(&mut self, before: Cursor) {
+        if self.plugin_text_objects.is_empty() || self.active_state().cursors.count() > 1 {
+            return;
+        }
+        let query =
+            self.start_text_object_query(before, fresh_core::api::TextObjectOperation::Expand);
+        // Matches apply to the cursor as expand-selection left it
+        let cursor = *self.active_state().cursors.primary();
+        if let Some(pending) = self.pending_text_object.as_mut() {
+            pending.cursor = (cursor.position, cursor.anchor);
+        }
+        for (_, name) in &self.plugin_text_objects {
+            self.plugin_manager.match_text_object(name, query.clone());
+        }
+    }
+
+    /// Record the cursor context of a text object query so that matches made
+    /// stale by later edits or cursor movement are dropped
+    fn start_text_object_query(
+        &mut self,
+        cursor: Cursor,
+        operation: fresh_core::api::TextObjectOperation,
+    ) -> fresh_core::api::TextObjectQuery {
+        let buffer_id = self.active_buffer();
+        self.pending_text_object = Some(PendingTextObject {
+            buffer_id,
+            version: self.active_state().buffer.version(),
+            operation,
+            cursor: (cursor.position, cursor.anchor),
+            selection: cursor
+                .selection_range()
+                .unwrap_or(cursor.position..cursor.position),
+            expanded_len: None,
+        });
+        fresh_core::api::TextObjectQuery {
+            buffer_id,
+            position: cursor.position,
+            anchor: cursor.anchor,
+            operation,
+        }
+    }
+
+    /// Handle ApplyTextObject command
+    pub(super) fn handle_apply_text_object(
+        &mut self,
+        buffer_id: BufferId,
+        range: std::ops::Range<usize>,
+        operation: fresh_core::api::TextObjectOperation,
+    ) {
+        use fresh_core::api::TextObjectOperation;
+
+        // Drop the match if the buffer or cursor changed while the matcher ran
+        let Some(pending) = self.pending_text_object.clone() else {
+            return;
+        };
+        let state = self.active_state();
+        let cursor_id = state.cursors.primary_id();
+        let cursor = *state.cursors.primary();
+        if buffer_id != self.active_buffer()
+            || pending.buffer_id != buffer_id
+            || pending.operation != operation
+            || pending.version != state.buffer.version()
+            || pending.cursor != (cursor.position, cursor.anchor)
+        {
+            tracing::debug!(
+                "Ignoring stale text object range {:?} for buffer {:?}",
+                range,
+                buffer_id
+            );
+            return;
+        }
+        if range.start > range.end
+            || !state.buffer.is_char_boundary(range.start)
+            || !state.buffer.is_char_boundary(range.end)
+        {
+            tracing::warn!(
+                "Ignoring text object range {}..{}: not on character boundaries within buffer length {}",
+                range.start,
+                range.end,
+                state.buffer.len()
+            );
+            return;
+        }
+
+        match operation {
+            TextObjectOperation::Select | TextObjectOperation::Expand => {
+                if operation == TextObjectOperation::Expand {
+                    // Only a range that grows the original selection, and is
+                    // smaller than any text object applied so far, is used
+                    let contains = range.start <= pending.selection.start
+                        && range.end >= pending.selection.end
+                        && range.len() > pending.selection.len();
+                    if !contains || pending.expanded_len.is_some_and(|len| range.len() >= len) {
+                        return;
+                    }
+                    self.pending_text_object = Some(PendingTextObject {
+                        cursor: (range.end, Some(range.start)),
+                        expanded_len: Some(range.len()),
+                        ..pending
+                    });
+                } else {
+                    self.pending_text_object = None;
+                }
+                let event = Event::MoveCursor {
+                    cursor_id,
+                    old_position: cursor.position,
+                    new_position: range.end,
+                    old_anchor: cursor.anchor,
+                    new_anchor: Some(range.start),
+                    old_sticky_column: cursor.sticky_column,
+                    new_sticky_column: 0,
+                };
+                self.active_event_log_mut().append(event.clone());
+                self.apply_event_to_active_buffer(&event);
+            }
+            TextObjectOperation::Delete => {
+                self.pending_text_object = None;
+                if range.is_empty() {
+                    return;
+                }
+                if self.is_editing_disabled() {
+                    self.set_status_message(t!("buffer.editing_disabled").to_string());
+                    return;
+                }
+                let deleted_text = self
+                    .active_state_mut()
+                    .get_text_range(range.start, range.end);
+                let event = Event::Delete {
+                    range,
+                    deleted_text,
+                    cursor_id,
+                };
+                self.active_event_log_mut().append(event.clone());
+                self.apply_event_to_active_buffer(&event);
+            }
+        }
+    }
+
+    /// Handle RegisterOpenHandler command
+    ///
+    /// Re-registering a pattern keeps its original position, so the first
+    /// registered matching pattern always wins.
+    pub(super) fn handle_register_open_handler(&mut self, pattern: String, plugin_name: String) {
+        if self
+            .plugin_open_handlers
+            .iter()
+            .any(|(_, re)| re.as_str() == pattern)
+        {
+            return;
+        }
+        match regex::Regex::new(&pattern) {
+            Ok(re) => self.plugin_open_handlers.push((plugin_name, re)),
+            Err(e) => tracing::error!(
+                "Plugin '{}' registered an invalid open handler pattern '{}': {}",
+                plugin_name,
+                pattern,
+                e
+            ),
+        }
+    }
+
+    /// Handle SetOpenedBufferContent command
+    pub(super) fn handle_set_opened_buffer_content(
+        &mut self,
+        buffer_id: BufferId,
+        content: String,
+    ) {
+        let entries = vec![crate::primitives::text_property::TextPropertyEntry::text(
+            content,
+        )];
+        if let Err(e) = self.set_virtual_buffer_content(buffer_id, entries) {
+            tracing::warn!(
+                "Failed to fill buffer {:?} from open handler: {}",
+                buffer_id,
+                e
+            );
+        }
+    }
+
+    /// Handle OpenHandlerFailed command
+    ///
+    /// Closes the empty placeholder buffer created for the path and reports
+    /// the error.
+    pub(super) fn handle_open_handler_failed(&mut self, buffer_id: BufferId, error: String) {
+        let Some(path) = self
+            .buffer_metadata
+            .get(&buffer_id)
+            .map(|m| m.display_name.clone())
+        else {
+            return;
+        };
+        tracing::warn!("Open handler failed for {}: {}", path, error);
+        if let Err(e) = self.force_close_buffer(buffer_id) {
+            tracing::warn!("Failed to close buffer {:?}: {}", buffer_id, e);
+        }
+        self.set_status_message(
+            t!("buffer.open_handler_failed", path = path, error = error).to_string(),
+        );
+    }
+
+    /// Handle PluginUnloaded command
+    pub(super) fn handle_plugin_unloaded(&mut self, plugin_name: &str) {
+        self.plugin_open_handlers
+            .retain(|(owner, _)| owner != plugin_name);
+        self.plugin_text_objects
+            .retain(|(owner, _)| owner != plugin_name);
+    }
+
     /// Handle UnregisterCommand command
     pub(super) fn handle_unregister_command(&self, name: String) {
         self.command_registry.read().unwrap().unregister(&name);
@@ -1169,16 +1623,128 @@ impl Editor {
         tracing::info!("Registered buffer mode '{}'", name);
     }
 
+    // ==================== Keybinding Commands ====================
+
+    /// Handle GetKeybindings command - resolve with the bindings of a context
+    pub(super) fn handle_get_keybindings(&mut self, context: String, request_id: u64) {
+        use super::format_key_string;
+        use fresh_core::api::{JsCallbackId, KeybindingInfo};
+
+        let callback_id = JsCallbackId::from(request_id);
+        let Some(key_context) = KeyContext::from_when_clause(&context) else {
+            self.plugin_manager
+                .reject_callback(callback_id, format!("Unknown key context '{}'", context));
+            return;
+        };
+
+        let bindings: Vec<KeybindingInfo> = self
+            .keybindings
+            .list_bindings(key_context)
+            .into_iter()
+            .filter_map(|(code, modifiers, command)| {
+                Some(KeybindingInfo {
+                    key: format_key_string(code, modifiers)?,
+                    command,
+                    context: key_context.to_when_clause().to_string(),
+                })
+            })
+            .collect();
+        let json = serde_json::to_string(&bindings).unwrap_or_else(|_| "[]".to_string());
+        self.plugin_manager.resolve_callback(callback_id, json);
+    }
+
+    /// Handle SetKeybinding command - bind a key, replacing any existing binding
+    pub(super) fn handle_set_keybinding(&mut self, key: String, command: String, context: String) {
+        use super::parse_key_string;
+
+        let (Some(key_context), Some((code, modifiers))) = (
+            KeyContext::from_when_clause(&context),
+            parse_key_string(&key),
+        ) else {
+            tracing::warn!(
+                "setKeybinding: invalid key '{}' or context '{}'",
+                key,
+                context
+            );
+            return;
+        };
+
+        self.plugin_keybindings
+            .insert((key_context, code, modifiers), Some(command.clone()));
+        if let Some(previous) = self
+            .keybindings
+            .set_binding(key_context, code, modifiers, &command)
+        {
+            tracing::warn!(
+                "setKeybinding: '{}' in {} context was bound to {:?}, now bound to '{}'",
+                key,
+                context,
+                previous,
+                command
+            );
+        }
+    }
+
+    /// Rebuild the keybinding resolver from the config, keeping the keys that
+    /// plugins have bound or unbound
+    pub(crate) fn rebuild_keybindings(&mut self) {
+        self.keybindings = crate::input::keybindings::KeybindingResolver::new(&self.config);
+        for ((context, code, modifiers), command) in &self.plugin_keybindings {
+            match command {
+                Some(command) => {
+                    self.keybindings
+                        .set_binding(*context, *code, *modifiers, command);
+                }
+                None => {
+                    self.keybindings.remove_binding(*context, *code, *modifiers);
+                }
+            }
+        }
+    }
+
+    /// Handle RemoveKeybinding command - unbind a key, including default bindings
+    pub(super) fn handle_remove_keybinding(&mut self, key: String, context: String) {
+        use super::parse_key_string;
+
+        let (Some(key_context), Some((code, modifiers))) = (
+            KeyContext::from_when_clause(&context),
+            parse_key_string(&key),
+        ) else {
+            tracing::warn!(
+                "removeKeybinding: invalid key '{}' or context '{}'",
+                key,
+                context
+            );
+            return;
+        };
+
+        self.plugin_keybindings
+            .insert((key_context, code, modifiers), None);
+        if self
+            .keybindings
+            .remove_binding(key_context, code, modifiers)
+            .is_none()
+        {
+            tracing::debug!(
+                "removeKeybinding: '{}' was not bound in {} context",
+                key,
+                context
+            );
+        }
+    }
+
     // ==================== LSP Commands ====================
 
     /// Handle SendLspRequest command
+    ///
+    /// Returns whether the request was sent; if not, its promise is rejected.
     pub(super) fn handle_send_lsp_request(
         &mut self,
         language: String,
         method: String,
         params: Option<serde_json::Value>,
         request_id: u64,
-    ) {
+    ) -> bool {
         tracing::debug!(
             "Plugin LSP request {} for language '{}': method={}",
             request_id,
@@ -1201,12 +1767,79 @@ impl Editor {
         } else {
             Some("LSP manager not initialized".to_string())
         };
-        if let Some(err_msg) = error {
-            self.plugin_manager
-                .reject_callback(fresh_core::api::JsCallbackId::from(request_id), err_msg);
+        match error {
+            Some(err_msg) => {
+                self.plugin_manager
+                    .reject_callback(fresh_core::api::JsCallbackId::from(request_id), err_msg);
+                false
+            }
+            None => true,
         }
     }
 
+    /// Handle LspRequest command: an LSP request for the active buffer's language
+    ///
+    /// Unlike `sendLspRequest`, these requests are rejected if the server has
+    /// not answered within `PLUGIN_LSP_REQUEST_TIMEOUT`.
+    pub(super) fn handle_lsp_request(
+        &mut self,
+        request_id: u64,
+        method: String,
+        params: Option<serde_json::Value>,
+    ) {
+        let language = self.active_state().language.clone();
+        if !self.config.lsp.contains_key(&language) {
+            self.plugin_manager.reject_callback(
+                fresh_core::api::JsCallbackId::from(request_id),
+                format!("No LSP server configured for '{}'", language),
+            );
+            return;
+        }
+        if self.handle_send_lsp_request(language, method, params, request_id) {
+            self.plugin_lsp_deadlines.insert(
+                request_id,
+                self.time_source.now() + PLUGIN_LSP_REQUEST_TIMEOUT,
+            );
+        }
+    }
+
+    /// Reject `editor.lsp` requests that have waited past their deadline
+    ///
+    /// A rejected request's late response is dropped if it arrives within
+    /// another `PLUGIN_LSP_REQUEST_TIMEOUT`; after that the request is
+    /// forgotten, so requests the server never answers are not kept forever.
+    pub(super) fn expire_plugin_lsp_requests(&mut self) {
+        if self.plugin_lsp_deadlines.is_empty() && self.plugin_lsp_timed_out.is_empty() {
+            return;
+        }
+        let now = self.time_source.now();
+        self.plugin_lsp_timed_out
+            .retain(|_, forget_at| *forget_at > now);
+        let expired: Vec<u64> = self
+            .plugin_lsp_deadlines
+            .iter()
+            .filter(|(_, deadline)| **deadline <= now)
+            .map(|(request_id, _)| *request_id)
+            .collect();
+        for request_id in expired {
+            self.plugin_lsp_deadlines.remove(&request_id);
+            self.plugin_lsp_timed_out
+                .insert(request_id, now + PLUGIN_LSP_REQUEST_TIMEOUT);
+            self.plugin_manager.reject_callback(
+                fresh_core::api::JsCallbackId::from(request_id),
+                format!(
+                    "LSP request timed out after {}s",
+                    PLUGIN_LSP_REQUEST_TIMEOUT.as_secs()
+                ),
+            );
+        }
+    }
+
+    /// Number of `editor.lsp` requests still tracked, waiting or timed out (for testing)
+    pub fn tracked_plugin_lsp_request_count(&self) -> usize {
+        self.plugin_lsp_deadlines.len() + self.plugin_lsp_timed_out.len()
+    }
+
     // ==================== Clipboard Commands ====================
 
     /// Handle SetClipboard command
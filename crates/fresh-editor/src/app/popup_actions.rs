@@ -47,6 +47,50 @@ impl Editor {
             return PopupConfirmResult::EarlyReturn;
         }
 
+        // Check if this is an LSP peek popup: jump to the selected line
+        if let Some((title, locations)) = self.peek_locations.take() {
+            if let Some(popup) = self
+                .active_state()
+                .popups
+                .top()
+                .filter(|p| p.title.as_deref() == Some(title.as_str()))
+            {
+                let target = popup
+                    .selected_item()
+                    .and_then(|item| item.data.as_deref())
+                    .and_then(|data| data.parse::<usize>().ok())
+                    .and_then(|index| locations.get(index))
+                    .cloned();
+                self.hide_popup();
+                if let Some(target) = target {
+                    self.goto_location(&target);
+                }
+                return PopupConfirmResult::EarlyReturn;
+            }
+        }
+
+        // Check if this is an LSP code actions popup: apply the selected action
+        if let Some(actions) = self.code_actions.take() {
+            let title = t!("lsp.popup_code_actions").to_string();
+            if let Some(popup) = self
+                .active_state()
+                .popups
+                .top()
+                .filter(|p| p.title.as_deref() == Some(title.as_str()))
+            {
+                let action = popup
+                    .selected_item()
+                    .and_then(|item| item.data.as_deref())
+                    .and_then(|data| data.parse::<usize>().ok())
+                    .and_then(|index| actions.into_iter().nth(index));
+                self.hide_popup();
+                if let Some(action) = action {
+                    self.apply_code_action(action);
+                }
+                return PopupConfirmResult::EarlyReturn;
+            }
+        }
+
         // Check if this is an LSP confirmation popup
         let lsp_confirmation_action = if let Some(popup) = self.active_state().popups.top() {
             if let Some(title) = &popup.title {
@@ -104,6 +148,12 @@ impl Editor {
             (text, None)
         };
 
+        // Servers send LF; match the buffer so a CRLF file stays consistent
+        let line_ending = self.active_state().buffer.line_ending();
+        let cursor_offset =
+            cursor_offset.map(|offset| line_ending.apply_to(&insert_text[..offset]).len());
+        let insert_text = line_ending.apply_to(&insert_text);
+
         let (cursor_id, cursor_pos, word_start) = {
             let state = self.active_state();
             let cursor_id = state.cursors.primary_id();
@@ -191,6 +241,8 @@ impl Editor {
             return;
         }
 
+        self.peek_locations = None;
+        self.code_actions = None;
         if self.pending_lsp_confirmation.is_some() {
             self.pending_lsp_confirmation = None;
             self.set_status_message(t!("lsp.startup_cancelled_msg").to_string());
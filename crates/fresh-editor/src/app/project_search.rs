@@ -0,0 +1,209 @@
+//! Project-wide search for the Editor.
+//!
+//! The query is compiled with the buffer search options (case, whole word,
+//! regex) and matched against every file under the working directory on a
+//! background task. Matches stream back file by file into the quickfix list
+//! and a results popup; confirming a popup entry opens the file at the match.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use rust_i18n::t;
+
+use crate::input::quickfix::QuickfixItem;
+use crate::services::async_bridge::AsyncMessage;
+use crate::services::project_search::{
+    search_project, ProjectSearchOptions, MAX_PROJECT_SEARCH_RESULTS,
+};
+use crate::view::popup::PopupListItem;
+
+use super::types::ProjectSearchState;
+use super::Editor;
+
+impl Editor {
+    /// Search all files under the working directory for `query`
+    pub(super) fn start_project_search(&mut self, query: &str) {
+        if query.is_empty() {
+            self.set_status_message(t!("search.cancelled").to_string());
+            return;
+        }
+        let regex = match self.build_search_regex(query) {
+            Ok(regex) => regex,
+            Err(e) => {
+                self.set_status_message(
+                    t!("error.invalid_regex", error = e.to_string()).to_string(),
+                );
+                return;
+            }
+        };
+
+        // Supersede any search still running
+        if let Some(previous) = self.project_search.take() {
+            previous.cancelled.store(true, Ordering::Relaxed);
+            if self.is_project_search_popup_open(&previous.title) {
+                self.hide_popup();
+                self.peek_locations = None;
+            }
+        }
+
+        let id = self.next_project_search_id;
+        self.next_project_search_id += 1;
+        let cancelled = Arc::new(AtomicBool::new(false));
+        self.project_search = Some(ProjectSearchState {
+            id,
+            query: query.to_string(),
+            title: t!("search.project_results_title", query = query).to_string(),
+            cancelled: cancelled.clone(),
+            popup_shown: false,
+        });
+        self.set_quickfix_list(Vec::new());
+        self.set_status_message(t!("search.project_searching", query = query).to_string());
+
+        let root = self.working_dir.clone();
+        let options = ProjectSearchOptions {
+            include_hidden: self.config.file_explorer.show_hidden,
+            respect_gitignore: self.config.file_explorer.respect_gitignore,
+        };
+
+        if let (Some(runtime), Some(bridge)) = (&self.tokio_runtime, &self.async_bridge) {
+            let sender = bridge.sender();
+            runtime.spawn_blocking(move || {
+                let total = search_project(&root, &regex, options, &cancelled, |matches| {
+                    let _ = sender.send(AsyncMessage::ProjectSearchResults {
+                        search_id: id,
+                        matches,
+                    });
+                });
+                let _ = sender.send(AsyncMessage::ProjectSearchFinished {
+                    search_id: id,
+                    total,
+                });
+            });
+        } else {
+            // No runtime: search synchronously
+            let mut results = Vec::new();
+            let total = search_project(&root, &regex, options, &cancelled, |matches| {
+                results.extend(matches)
+            });
+            self.handle_project_search_results(id, results);
+            self.handle_project_search_finished(id, total);
+        }
+    }
+
+    /// Add matches from one file to the quickfix list and the results popup
+    pub(super) fn handle_project_search_results(
+        &mut self,
+        search_id: u64,
+        matches: Vec<QuickfixItem>,
+    ) {
+        let Some(search) = self
+            .project_search
+            .as_ref()
+            .filter(|search| search.id == search_id)
+        else {
+            return;
+        };
+        let title = search.title.clone();
+        let popup_shown = search.popup_shown;
+        self.quickfix.extend(matches.iter().cloned());
+
+        if self.is_project_search_popup_open(&title) {
+            let first = self
+                .peek_locations
+                .as_ref()
+                .map_or(0, |(_, targets)| targets.len());
+            let items: Vec<_> = matches
+                .iter()
+                .enumerate()
+                .map(|(i, item)| {
+                    PopupListItem::new(self.project_search_item_text(item))
+                        .with_data((first + i).to_string())
+                })
+                .collect();
+            if let Some((_, targets)) = self.peek_locations.as_mut() {
+                targets.extend(matches);
+            }
+            if let Some(popup) = self.active_state_mut().popups.top_mut() {
+                if let crate::view::popup::PopupContent::List {
+                    items: popup_items, ..
+                } = &mut popup.content
+                {
+                    popup_items.extend(items);
+                }
+            }
+        } else if !popup_shown {
+            if let Some(search) = self.project_search.as_mut() {
+                search.popup_shown = true;
+            }
+            self.show_project_search_popup(title, matches);
+        }
+    }
+
+    /// Report the outcome once the background walk is done
+    pub(super) fn handle_project_search_finished(&mut self, search_id: u64, total: usize) {
+        let Some(search) = self
+            .project_search
+            .as_ref()
+            .filter(|search| search.id == search_id)
+        else {
+            return;
+        };
+        let message = if total == 0 {
+            t!("search.no_occurrences", search = &search.query)
+        } else if total >= MAX_PROJECT_SEARCH_RESULTS {
+            t!("search.project_found_limited", count = total)
+        } else {
+            t!("search.project_found", count = total)
+        };
+        self.set_status_message(message.to_string());
+    }
+
+    fn show_project_search_popup(&mut self, title: String, matches: Vec<QuickfixItem>) {
+        use crate::model::event::{
+            PopupContentData, PopupData, PopupListItemData, PopupPositionData,
+        };
+
+        let items = matches
+            .iter()
+            .enumerate()
+            .map(|(i, item)| PopupListItemData {
+                text: self.project_search_item_text(item),
+                detail: None,
+                icon: None,
+                data: Some(i.to_string()),
+            })
+            .collect();
+        self.peek_locations = Some((title.clone(), matches));
+        self.show_popup(PopupData {
+            title: Some(title),
+            description: Some(t!("search.project_hint").to_string()),
+            transient: false,
+            content: PopupContentData::List { items, selected: 0 },
+            position: PopupPositionData::Centered,
+            width: self.terminal_width.saturating_sub(4).min(120),
+            max_height: 20,
+            bordered: true,
+        });
+    }
+
+    /// Whether the results popup titled `title` is the topmost popup
+    fn is_project_search_popup_open(&self, title: &str) -> bool {
+        self.peek_locations
+            .as_ref()
+            .is_some_and(|(peek_title, _)| peek_title == title)
+            && self
+                .active_state()
+                .popups
+                .top()
+                .is_some_and(|popup| popup.title.as_deref() == Some(title))
+    }
+
+    /// Popup line for a match: `path:line: text`
+    fn project_search_item_text(&self, item: &QuickfixItem) -> String {
+        let path = item
+            .path
+            .strip_prefix(&self.working_dir)
+            .unwrap_or(&item.path);
+        format!("{}:{}: {}", path.display(), item.line, item.text)
+    }
+}
@@ -10,6 +10,7 @@ use super::BufferMetadata;
 use super::Editor;
 use crate::input::keybindings::Action;
 use crate::primitives::path_utils::expand_tilde;
+use crate::services::clipboard::is_register_name;
 use crate::services::plugins::hooks::HookArgs;
 use crate::view::prompt::PromptType;
 
@@ -23,6 +24,25 @@ pub enum PromptResult {
     EarlyReturn,
 }
 
+/// Parse go-to-line input of the form `line`, `line:col` or `:col`.
+///
+/// Returns `(line, column)`, or `None` if the input isn't in one of those forms.
+fn parse_goto_line_input(input: &str) -> Option<(Option<usize>, Option<usize>)> {
+    let input = input.trim();
+    match input.split_once(':') {
+        Some((line, col)) => {
+            let col = col.trim().parse().ok()?;
+            let line = line.trim();
+            if line.is_empty() {
+                Some((None, Some(col)))
+            } else {
+                Some((Some(line.parse().ok()?), Some(col)))
+            }
+        }
+        None => Some((Some(input.parse().ok()?), None)),
+    }
+}
+
 impl Editor {
     /// Handle prompt confirmation based on the prompt type.
     ///
@@ -105,6 +125,9 @@ impl Editor {
                     },
                 );
             }
+            PromptType::ProjectSearch => {
+                self.start_project_search(&input);
+            }
             PromptType::QueryReplace { search } => {
                 if self.search_confirm_each {
                     self.start_interactive_replace(&search, &input);
@@ -129,15 +152,42 @@ impl Editor {
                     );
                 }
             }
-            PromptType::GotoLine => match input.trim().parse::<usize>() {
-                Ok(line_num) if line_num > 0 => {
+            PromptType::GotoLine if input.trim().ends_with('%') => {
+                let percent = input.trim().trim_end_matches('%').trim();
+                match percent.parse::<usize>() {
+                    Ok(percent) => {
+                        let percent = percent.min(100);
+                        self.goto_percent(percent);
+                        self.set_status_message(
+                            t!("goto.jumped_percent", percent = percent).to_string(),
+                        );
+                    }
+                    Err(_) => {
+                        self.set_status_message(
+                            t!("error.invalid_percent", input = &input).to_string(),
+                        );
+                    }
+                }
+            }
+            PromptType::GotoLine => match parse_goto_line_input(&input) {
+                Some((Some(0), _)) | Some((_, Some(0))) => {
+                    self.set_status_message(t!("goto.line_must_be_positive").to_string());
+                }
+                Some((Some(line_num), None)) => {
                     self.goto_line_col(line_num, None);
                     self.set_status_message(t!("goto.jumped", line = line_num).to_string());
                 }
-                Ok(_) => {
-                    self.set_status_message(t!("goto.line_must_be_positive").to_string());
+                Some((Some(line_num), Some(col))) => {
+                    self.goto_line_col(line_num, Some(col));
+                    self.set_status_message(
+                        t!("goto.jumped_line_col", line = line_num, column = col).to_string(),
+                    );
                 }
-                Err(_) => {
+                Some((None, Some(col))) => {
+                    self.goto_column(col);
+                    self.set_status_message(t!("goto.jumped_col", column = col).to_string());
+                }
+                Some((None, None)) | None => {
                     self.set_status_message(t!("error.invalid_line", input = &input).to_string());
                 }
             },
@@ -177,6 +227,16 @@ impl Editor {
             PromptType::PlayMacro => {
                 self.handle_register_input(&input, |editor, c| editor.play_macro(c), "Macro");
             }
+            PromptType::PlayLastMacroTimes => match input.trim().parse::<usize>() {
+                Ok(times) if times > 0 => {
+                    if let Some(key) = self.last_macro_register {
+                        self.play_macro_times(key, times);
+                    }
+                }
+                _ => {
+                    self.set_status_message(t!("macro.invalid_times", input = &input).to_string());
+                }
+            },
             PromptType::SetBookmark => {
                 self.handle_register_input(&input, |editor, c| editor.set_bookmark(c), "Bookmark");
             }
@@ -187,6 +247,13 @@ impl Editor {
                     "Bookmark",
                 );
             }
+            PromptType::SelectRegister => match input.trim().chars().next() {
+                Some(c) if is_register_name(c) => self.select_register(c),
+                Some(_) => {
+                    self.set_status_message(t!("register.must_be_letter_or_digit").to_string())
+                }
+                None => self.set_status_message(t!("register.not_specified").to_string()),
+            },
             PromptType::Plugin { custom_type } => {
                 tracing::info!(
                     "prompt_confirmed: dispatching hook for prompt_type='{}', input='{}', selected_index={:?}",
@@ -319,6 +386,15 @@ impl Editor {
             } => {
                 self.perform_file_explorer_rename(original_path, original_name, input, is_new_file);
             }
+            PromptType::FileExplorerFilter => {
+                self.file_explorer_apply_filter(&input);
+                let message = if input.is_empty() {
+                    t!("explorer.filter_cleared")
+                } else {
+                    t!("explorer.filter_applied", filter = &input)
+                };
+                self.set_status_message(message.to_string());
+            }
             PromptType::ConfirmDeleteFile { path, is_dir } => {
                 let input_lower = input.trim().to_lowercase();
                 if input_lower == "y" || input_lower == "yes" {
@@ -350,6 +426,9 @@ impl Editor {
                     self.switch_to_tab(BufferId(id));
                 }
             }
+            PromptType::ShowKeybindings => {
+                // Read-only listing: confirming just closes the prompt
+            }
             PromptType::QueryReplaceConfirm => {
                 // This is handled by InsertChar, not PromptConfirm
                 // But if somehow Enter is pressed, treat it as skip (n)
@@ -363,6 +442,9 @@ impl Editor {
             PromptType::SetLineEnding => {
                 self.handle_set_line_ending(&input);
             }
+            PromptType::AlignOnDelimiter => {
+                self.align_on_delimiter(input.trim());
+            }
             PromptType::ShellCommand { replace } => {
                 self.handle_shell_command(&input, replace);
             }
@@ -415,6 +497,9 @@ impl Editor {
 
     /// Perform the actual SaveFileAs operation (called after confirmation if needed).
     pub(crate) fn perform_save_file_as(&mut self, full_path: std::path::PathBuf) {
+        self.trim_trailing_whitespace_before_save();
+        self.insert_final_newline_before_save();
+
         let before_idx = self.active_event_log().current_index();
         let before_len = self.active_event_log().len();
         tracing::debug!(
@@ -435,6 +520,7 @@ impl Editor {
 
                 let metadata = BufferMetadata::with_file(full_path.clone(), &self.working_dir);
                 self.buffer_metadata.insert(self.active_buffer(), metadata);
+                self.last_file_dialog_dir = full_path.parent().map(|p| p.to_path_buf());
 
                 // Auto-detect language if it's currently "text"
                 // This ensures syntax highlighting works immediately after "Save As"
@@ -540,6 +626,9 @@ impl Editor {
             Ok(val) if val > 0 => {
                 if let Some(state) = self.buffers.get_mut(&buffer_id) {
                     state.tab_size = val;
+                    state.detected_indent_width = None;
+                    state.editorconfig_indent = false;
+                    state.setting_overrides.tab_size = true;
                 }
                 self.set_status_message(t!("settings.tab_size_set", value = val).to_string());
             }
@@ -569,10 +658,14 @@ impl Editor {
 
         match line_ending {
             Some(le) => {
+                let was_mixed = self.active_state().buffer.has_mixed_line_endings();
                 self.active_state_mut().buffer.set_line_ending(le);
-                self.set_status_message(
-                    t!("settings.line_ending_set", value = le.display_name()).to_string(),
-                );
+                let message = if was_mixed {
+                    t!("settings.line_ending_normalized", value = le.display_name())
+                } else {
+                    t!("settings.line_ending_set", value = le.display_name())
+                };
+                self.set_status_message(message.to_string());
             }
             None => {
                 self.set_status_message(t!("error.unknown_line_ending", input = input).to_string());
@@ -636,11 +729,7 @@ impl Editor {
                 }
             } else {
                 self.pending_close_buffer = Some(buffer_id);
-                self.start_prompt_with_initial_text(
-                    t!("file.save_as_prompt").to_string(),
-                    PromptType::SaveFileAs,
-                    String::new(),
-                );
+                self.prompt_save_as();
             }
         } else if first_char == discard_first {
             // Discard and close
@@ -0,0 +1,120 @@
+//! Quickfix list actions for the Editor.
+//!
+//! This module contains the operations that fill the quickfix list and
+//! step through it:
+//! - Replace the list with a new result set (references, grep results)
+//! - Jump to the next/previous item, opening its file if needed
+//! - Jump to an arbitrary location (also used by the LSP peek popup)
+
+use std::path::PathBuf;
+
+use fresh_core::api::QuickfixEntry;
+use rust_i18n::t;
+
+use crate::input::quickfix::{QuickfixItem, QuickfixList};
+
+use super::Editor;
+
+impl Editor {
+    /// The quickfix list
+    pub fn quickfix_list(&self) -> &QuickfixList {
+        &self.quickfix
+    }
+
+    /// Replace the quickfix list with a new set of locations
+    pub fn set_quickfix_list(&mut self, items: Vec<QuickfixItem>) {
+        self.quickfix.set(items);
+    }
+
+    /// Handle SetQuickfixList plugin command
+    pub(super) fn handle_set_quickfix_list(&mut self, entries: Vec<QuickfixEntry>) {
+        let items = entries
+            .into_iter()
+            .map(|entry| {
+                let path = PathBuf::from(&entry.file);
+                let path = if path.is_absolute() {
+                    path
+                } else {
+                    self.working_dir.join(path)
+                };
+                QuickfixItem::new(path, entry.line as usize, entry.column as usize, entry.text)
+            })
+            .collect();
+        self.set_quickfix_list(items);
+    }
+
+    /// Jump to the next item in the quickfix list
+    pub fn quickfix_next(&mut self) {
+        self.step_quickfix(true);
+    }
+
+    /// Jump to the previous item in the quickfix list
+    pub fn quickfix_prev(&mut self) {
+        self.step_quickfix(false);
+    }
+
+    fn step_quickfix(&mut self, forward: bool) {
+        let item = if forward {
+            self.quickfix.next_item()
+        } else {
+            self.quickfix.prev_item()
+        };
+        let Some(item) = item.cloned() else {
+            self.set_status_message(t!("quickfix.empty").to_string());
+            return;
+        };
+
+        if !self.goto_location(&item) {
+            return;
+        }
+
+        let path = item
+            .path
+            .strip_prefix(&self.working_dir)
+            .unwrap_or(&item.path);
+        let location = format!("{}:{}:{}", path.display(), item.line, item.column);
+        let index = self.quickfix.current_index().map_or(0, |i| i + 1);
+        let mut message = t!(
+            "quickfix.item",
+            index = index,
+            total = self.quickfix.len(),
+            location = location
+        )
+        .to_string();
+        if !item.text.is_empty() {
+            message.push(' ');
+            message.push_str(item.text.trim());
+        }
+        self.set_status_message(message);
+    }
+
+    /// Open a location's file and move the cursor there, recording the jump
+    /// in position history. Returns false if the file could not be opened.
+    pub(super) fn goto_location(&mut self, item: &QuickfixItem) -> bool {
+        // Record where the jump started so navigate back returns here
+        if !self.in_navigation {
+            let cursor = *self.active_state().cursors.primary();
+            self.position_history.commit_pending_movement();
+            self.position_history.record_movement(
+                self.active_buffer(),
+                cursor.position,
+                cursor.anchor,
+            );
+            self.position_history.commit_pending_movement();
+        }
+
+        if let Err(e) = self.open_file(&item.path) {
+            self.set_status_message(t!("file.error_opening", error = e.to_string()).to_string());
+            return false;
+        }
+        self.jump_to_line_column(Some(item.line), Some(item.column));
+
+        if !self.in_navigation {
+            let cursor = *self.active_state().cursors.primary();
+            self.position_history
+                .record_movement(self.active_buffer(), cursor.position, None);
+            self.position_history.commit_pending_movement();
+        }
+        true
+    }
+}
@@ -1,7 +1,15 @@
 use super::*;
+use crate::primitives::bracket_match::{find_matching_bracket, is_bracket, BracketSyntax};
+use crate::primitives::sort_lines::LineOrder;
 use anyhow::Result as AnyhowResult;
 use rust_i18n::t;
 
+/// Overlay priority of the current search match, above the other matches
+const CURRENT_SEARCH_MATCH_PRIORITY: crate::view::overlay::Priority = 11;
+
+/// Buffers up to this size are scanned whole when matching brackets
+const BRACKET_SCAN_LIMIT: usize = 1024 * 1024;
+
 impl Editor {
     /// Render the editor to the terminal
     pub fn render(&mut self, frame: &mut Frame) {
@@ -13,6 +21,7 @@ impl Editor {
         // Otherwise, cursor movements like 'G' (go to end) won't sync properly because
         // viewport.top_byte hasn't been updated yet.
         let active_split = self.split_manager.active_split();
+        self.resolve_split_line_wrap();
         self.pre_sync_ensure_visible(active_split);
 
         // Synchronize scroll sync groups (anchor-based scroll for side-by-side diffs)
@@ -84,6 +93,7 @@ impl Editor {
                     | PromptType::Replace { .. }
                     | PromptType::QueryReplaceSearch
                     | PromptType::QueryReplace { .. }
+                    | PromptType::ProjectSearch
             )
         });
 
@@ -379,6 +389,19 @@ impl Editor {
                 hovered_maximize_split,
                 is_maximized,
                 self.config.editor.relative_line_numbers,
+                self.config.editor.show_indent_guides,
+                self.config.editor.caret_control_chars,
+                crate::view::ui::view_pipeline::WhitespaceRender {
+                    all: self.config.editor.render_whitespace,
+                    trailing: self.config.editor.render_trailing_whitespace,
+                },
+                &self.config.editor.rulers,
+                self.config.editor.sticky_scroll,
+                self.config.editor.smooth_scroll.then(|| {
+                    std::time::Duration::from_millis(self.config.editor.smooth_scroll_duration_ms)
+                }),
+                self.time_source.now(),
+                self.config.editor.highlight_word_occurrences,
                 self.tab_bar_visible,
                 self.config.editor.use_terminal_bg,
             );
@@ -444,6 +467,28 @@ impl Editor {
             );
         }
 
+        // Fire cursor_position_changed once per frame when the primary cursor moved,
+        // coalescing all the MoveCursor events applied since the previous render
+        let buffer_id = self.active_buffer();
+        let position = self.active_state().cursors.primary().position;
+        let current_cursor = Some((buffer_id, position));
+        if self.plugin_manager.is_active()
+            && self.previous_cursor_position.is_some()
+            && self.previous_cursor_position != current_cursor
+        {
+            let (line, column) = self.active_state().buffer.position_to_line_col(position);
+            self.plugin_manager.run_hook(
+                "cursor_position_changed",
+                crate::services::plugins::hooks::HookArgs::CursorPositionChanged {
+                    buffer_id,
+                    position,
+                    line: line + 1,
+                    column: column + 1,
+                },
+            );
+        }
+        self.previous_cursor_position = current_cursor;
+
         // Render terminal content on top of split content for terminal buffers
         self.render_terminal_splits(frame, &split_areas);
 
@@ -517,11 +562,16 @@ impl Editor {
         }
 
         // Clone all immutable values before the mutable borrow
-        let display_name = self
+        let mut display_name = self
             .buffer_metadata
             .get(&self.active_buffer())
             .map(|m| m.display_name.clone())
             .unwrap_or_else(|| "[No Name]".to_string());
+        if let Some(path) = self.active_state().buffer.file_path() {
+            if self.deleted_files.contains(path) {
+                display_name = format!("{} {}", display_name, t!("status.deleted_on_disk"));
+            }
+        }
         let status_message = self.status_message.clone();
         let plugin_status_message = self.plugin_status_message.clone();
         let prompt = self.prompt.clone();
@@ -557,6 +607,12 @@ impl Editor {
                 _ => StatusBarHover::None,
             };
 
+            let overwrite_mode = self.overwrite_mode;
+            let plugin_status_items = self.plugin_status_items.clone();
+            let search_match = self.search_match_indicator();
+            let cursor_details_visible = self.cursor_details_visible;
+            let chord_remaining = self.chord_time_remaining();
+            let status_bar_segments = self.config.status_bar.clone();
             let status_bar_layout = StatusBarRenderer::render_status_bar(
                 frame,
                 main_chunks[status_bar_idx],
@@ -568,10 +624,16 @@ impl Editor {
                 &display_name,
                 &keybindings_cloned,         // Pass the cloned keybindings
                 &chord_state_cloned,         // Pass the cloned chord state
+                overwrite_mode,              // Pass insert/overwrite mode for the indicator
                 update_available.as_deref(), // Pass update availability
                 warning_level,               // Pass warning level for colored indicator
                 general_warning_count,       // Pass general warning count for badge
                 status_bar_hover,            // Pass hover state for indicator styling
+                &plugin_status_items,        // Pass plugin-contributed segments
+                search_match,                // Pass current search match and count
+                cursor_details_visible,      // Pass whether to show byte offset and file size
+                chord_remaining,             // Pass time left on the pending chord
+                &status_bar_segments,        // Pass configured segment order
             );
 
             // Store status bar layout for click detection
@@ -615,6 +677,7 @@ impl Editor {
                 frame,
                 main_chunks[search_options_idx],
                 self.search_case_sensitive,
+                !self.search_case_sensitive && self.config.editor.search_smart_case,
                 self.search_whole_word,
                 self.search_use_regex,
                 confirm_each,
@@ -1709,10 +1772,64 @@ impl Editor {
                 }
                 all_changes
             }
+            Event::BulkEdit {
+                new_tree: Some(new_tree),
+                ..
+            } => {
+                // Undo/redo of a bulk edit restores a whole tree snapshot, so
+                // diff it against the current tree to find what changed
+                tracing::trace!("collect_lsp_changes: processing BulkEdit tree restore");
+                self.collect_lsp_tree_changes(new_tree)
+            }
             _ => Vec::new(), // Ignore cursor movements and other events
         }
     }
 
+    /// Collect the LSP change for replacing the active buffer's piece tree with `target`
+    ///
+    /// Sends only the span that differs between the two trees, narrowed to the
+    /// characters that changed. Falls back to a full document sync when line
+    /// counts are unknown (large file mode).
+    fn collect_lsp_tree_changes(
+        &self,
+        target: &crate::model::piece_tree::PieceTree,
+    ) -> Vec<TextDocumentContentChangeEvent> {
+        let buffer = &self.active_state().buffer;
+        let diff = buffer.diff_to_snapshot(target);
+        if diff.equal {
+            return Vec::new();
+        }
+
+        let narrowed = diff
+            .line_ranges
+            .is_some()
+            .then(|| buffer.narrow_snapshot_diff(target, &diff))
+            .flatten();
+        let (range, replacement) = if let Some((before_span, after_span)) = narrowed {
+            let (start_line, start_char) = buffer.position_to_lsp_position(before_span.start);
+            let (end_line, end_char) = buffer.position_to_lsp_position(before_span.end);
+            let lsp_range = LspRange::new(
+                Position::new(start_line as u32, start_char as u32),
+                Position::new(end_line as u32, end_char as u32),
+            );
+            (Some(lsp_range), after_span)
+        } else {
+            (None, 0..target.total_bytes())
+        };
+
+        let Some(text) =
+            buffer.get_snapshot_text_range(target, replacement.start, replacement.len())
+        else {
+            tracing::warn!("collect_lsp_changes: restored tree text is not loaded");
+            return Vec::new();
+        };
+        vec![TextDocumentContentChangeEvent {
+            range,
+            range_length: None,
+            text: String::from_utf8_lossy(&text).into_owned(),
+        }]
+    }
+
     /// Calculate line information for an event (before buffer modification)
     /// This provides accurate line numbers for plugin hooks to track changes.
     ///
@@ -1883,8 +2000,9 @@ impl Editor {
     /// Convert an action into a list of events to apply to the active buffer
     /// Returns None for actions that don't generate events (like Quit)
     pub fn action_to_events(&mut self, action: Action) -> Option<Vec<Event>> {
-        let tab_size = self.config.editor.tab_size;
+        let tab_size = self.active_state().tab_size;
         let auto_indent = self.config.editor.auto_indent;
+        let auto_close = self.config.editor.auto_close;
         let estimated_line_length = self.config.editor.estimated_line_length;
 
         // Get viewport height from SplitViewState (the authoritative source)
@@ -1895,16 +2013,111 @@ impl Editor {
             .map(|vs| vs.viewport.height)
             .unwrap_or(24);
 
+        if action == Action::DeleteBackward && self.config.editor.smart_backspace {
+            if let Some(events) =
+                crate::input::actions::unindent_backspace_events(self.active_state_mut(), tab_size)
+            {
+                return Some(events);
+            }
+        }
+
+        if matches!(action, Action::DeleteToLineEnd | Action::DeleteToLineStart) {
+            let view_rows = self.wrapped_view_rows(active_split);
+            let join_at_eol = self.config.editor.kill_line_joins_lines;
+            return Some(crate::input::actions::delete_to_line_boundary_events(
+                self.active_state_mut(),
+                action == Action::DeleteToLineEnd,
+                join_at_eol,
+                &view_rows,
+                estimated_line_length,
+            ));
+        }
+
+        use crate::input::actions::ViewportRow;
+        let viewport_row = match action {
+            Action::CursorToViewportTop => Some(ViewportRow::Top),
+            Action::CursorToViewportMiddle => Some(ViewportRow::Middle),
+            Action::CursorToViewportBottom => Some(ViewportRow::Bottom),
+            _ => None,
+        };
+        if let Some(target) = viewport_row {
+            let view_rows = self.wrapped_view_rows(active_split);
+            let (top_byte, visible_line_count) = self
+                .split_view_states
+                .get(&active_split)
+                .map(|vs| (vs.viewport.top_byte, vs.viewport.visible_line_count()))
+                .unwrap_or((0, viewport_height as usize));
+            return Some(crate::input::actions::cursor_to_viewport_row_events(
+                self.active_state_mut(),
+                target,
+                top_byte,
+                visible_line_count,
+                &view_rows,
+                estimated_line_length,
+            ));
+        }
+
         convert_action_to_events(
             self.active_state_mut(),
             action,
             tab_size,
             auto_indent,
+            auto_close,
             estimated_line_length,
             viewport_height,
         )
     }
 
+    /// Set each split's line wrapping from the buffer it shows: the buffer's own
+    /// setting if it has one, else the global `editor.line_wrap`
+    ///
+    /// Terminal buffers never wrap, and Compose mode leaves wrapping to the view
+    /// transform.
+    pub(super) fn resolve_split_line_wrap(&mut self) {
+        for (split_id, view_state) in self.split_view_states.iter_mut() {
+            if matches!(view_state.view_mode, crate::state::ViewMode::Compose) {
+                continue;
+            }
+            let Some(buffer_id) = self.split_manager.get_buffer_id(*split_id) else {
+                continue;
+            };
+            view_state.viewport.line_wrap_enabled = !self.terminal_buffers.contains_key(&buffer_id)
+                && self
+                    .buffers
+                    .get(&buffer_id)
+                    .and_then(|state| state.line_wrap)
+                    .unwrap_or(self.config.editor.line_wrap);
+        }
+    }
+
+    /// Byte ranges of the display rows last rendered in a split with line wrapping
+    ///
+    /// Each row runs from its first source byte to the start of the next row; the
+    /// last one is open-ended and gets clamped to its source line by the caller.
+    /// Empty when wrapping is off, since rows then match source lines.
+    fn wrapped_view_rows(&self, split_id: SplitId) -> Vec<std::ops::Range<usize>> {
+        let wrapping = self
+            .split_view_states
+            .get(&split_id)
+            .is_some_and(|vs| vs.viewport.line_wrap_enabled);
+        let Some(mappings) = self.cached_layout.view_line_mappings.get(&split_id) else {
+            return Vec::new();
+        };
+        if !wrapping {
+            return Vec::new();
+        }
+
+        let starts: Vec<usize> = mappings
+            .iter()
+            .filter_map(|mapping| mapping.char_source_bytes.iter().flatten().next().copied())
+            .collect();
+        starts
+            .iter()
+            .enumerate()
+            .map(|(i, &start)| start..starts.get(i + 1).copied().unwrap_or(usize::MAX))
+            .collect()
+    }
+
     // === Search and Replace Methods ===
 
     /// Clear all search highlights from the active buffer and reset search state
@@ -1914,6 +2127,16 @@ impl Editor {
         self.search_state = None;
     }
 
+    /// Remove search match highlighting from every buffer and end the active search,
+    /// without moving the cursor
+    pub(super) fn clear_search_highlight(&mut self) {
+        let ns = self.search_namespace.clone();
+        for state in self.buffers.values_mut() {
+            state.overlays.clear_namespace(&ns, &mut state.marker_list);
+        }
+        self.search_state = None;
+    }
+
     /// Clear only the visual search overlays, preserving search state for F3/Shift+F3
     /// This is used when the buffer is modified - highlights become stale but F3 should still work
     pub(super) fn clear_search_overlays(&mut self) {
@@ -1934,33 +2157,9 @@ impl Editor {
         // Get theme colors and search settings before borrowing state
         let search_bg = self.theme.search_match_bg;
         let search_fg = self.theme.search_match_fg;
-        let case_sensitive = self.search_case_sensitive;
-        let whole_word = self.search_whole_word;
-        let use_regex = self.search_use_regex;
         let ns = self.search_namespace.clone();
 
-        // Build regex pattern if regex mode is enabled, or escape for literal search
-        let regex_pattern = if use_regex {
-            if whole_word {
-                format!(r"\b{}\b", query)
-            } else {
-                query.to_string()
-            }
-        } else {
-            let escaped = regex::escape(query);
-            if whole_word {
-                format!(r"\b{}\b", escaped)
-            } else {
-                escaped
-            }
-        };
-
-        // Build regex with case sensitivity
-        let regex = regex::RegexBuilder::new(&regex_pattern)
-            .case_insensitive(!case_sensitive)
-            .build();
-
-        let regex = match regex {
+        let regex = match self.build_search_regex(query) {
             Ok(r) => r,
             Err(_) => {
                 // Invalid regex, clear highlights and return
@@ -2004,7 +2203,7 @@ impl Editor {
         let visible_text = state.get_text_range(visible_start, visible_end);
 
         // Find all matches using regex
-        for mat in regex.find_iter(&visible_text) {
+        for mat in regex.find_iter(&visible_text).filter(|m| !m.is_empty()) {
             let absolute_pos = visible_start + mat.start();
             let match_len = mat.end() - mat.start();
 
@@ -2024,6 +2223,37 @@ impl Editor {
         }
     }
 
+    /// Regex pattern for a search query under the whole-word and regex options
+    fn search_pattern(&self, query: &str) -> String {
+        // Escape the query unless regex mode is enabled
+        let pattern = if self.search_use_regex {
+            query.to_string()
+        } else {
+            regex::escape(query)
+        };
+        if self.search_whole_word {
+            format!(r"\b{}\b", pattern)
+        } else {
+            pattern
+        }
+    }
+
+    /// Whether a search query is matched case-sensitively
+    ///
+    /// With smart case, a query containing an uppercase letter is always
+    /// case-sensitive.
+    pub(super) fn search_is_case_sensitive(&self, query: &str) -> bool {
+        self.search_case_sensitive
+            || (self.config.editor.search_smart_case && query.chars().any(char::is_uppercase))
+    }
+
+    /// Build the regex for a search query from the case, whole-word and regex options
+    pub(super) fn build_search_regex(&self, query: &str) -> Result<regex::Regex, regex::Error> {
+        regex::RegexBuilder::new(&self.search_pattern(query))
+            .case_insensitive(!self.search_is_case_sensitive(query))
+            .build()
+    }
+
     /// Perform a search and update search state
     pub(super) fn perform_search(&mut self, query: &str) {
         // Don't clear search highlights here - keep them from incremental search
@@ -2059,11 +2289,6 @@ impl Editor {
             }
         };
 
-        // Get search settings
-        let case_sensitive = self.search_case_sensitive;
-        let whole_word = self.search_whole_word;
-        let use_regex = self.search_use_regex;
-
         // Determine search boundaries
         let (search_start, search_end) = if let Some(ref range) = search_range {
             (range.start, range.end)
@@ -2071,27 +2296,7 @@ impl Editor {
             (0, buffer_content.len())
         };
 
-        // Build regex pattern
-        let regex_pattern = if use_regex {
-            if whole_word {
-                format!(r"\b{}\b", query)
-            } else {
-                query.to_string()
-            }
-        } else {
-            let escaped = regex::escape(query);
-            if whole_word {
-                format!(r"\b{}\b", escaped)
-            } else {
-                escaped
-            }
-        };
-
-        // Build regex with case sensitivity
-        let regex = match regex::RegexBuilder::new(&regex_pattern)
-            .case_insensitive(!case_sensitive)
-            .build()
-        {
+        let regex = match self.build_search_regex(query) {
             Ok(r) => r,
             Err(e) => {
                 self.search_state = None;
@@ -2104,8 +2309,11 @@ impl Editor {
 
         // Find all matches within the search range (store position and length for overlays)
         let search_slice = &buffer_content[search_start..search_end];
+        // Patterns such as `a*` also match the empty string, which can't be
+        // highlighted or jumped between, so those matches are skipped
         let match_ranges: Vec<(usize, usize)> = regex
             .find_iter(search_slice)
+            .filter(|m| !m.is_empty())
             .map(|m| (search_start + m.start(), m.end() - m.start()))
             .collect();
 
@@ -2163,19 +2371,11 @@ impl Editor {
         // Move cursor to the first match
         let match_pos = matches[current_match_index];
         {
-            let active_split = self.split_manager.active_split();
-            let active_buffer = self.active_buffer();
             let state = self.active_state_mut();
             state.cursors.primary_mut().position = match_pos;
             state.cursors.primary_mut().anchor = None;
-            // Ensure cursor is visible - get viewport from SplitViewState
-            if let Some(view_state) = self.split_view_states.get_mut(&active_split) {
-                let state = self.buffers.get_mut(&active_buffer).unwrap();
-                view_state
-                    .viewport
-                    .ensure_visible(&mut state.buffer, state.cursors.primary());
-            }
         }
+        self.reveal_cursor_after_jump();
 
         let num_matches = matches.len();
 
@@ -2186,7 +2386,9 @@ impl Editor {
             current_match_index: Some(current_match_index),
             wrap_search: search_range.is_none(), // Only wrap if not searching in selection
             search_range,
+            use_regex: self.search_use_regex,
         });
+        self.highlight_current_search_match();
 
         let msg = if self.search_state.as_ref().unwrap().search_range.is_some() {
             format!(
@@ -2206,6 +2408,59 @@ impl Editor {
         self.set_status_message(msg);
     }
 
+    /// Restyle the match the cursor is on so it stands out from the other matches
+    ///
+    /// The highlight lives in the search namespace, so it is cleared along with
+    /// the other match highlights when the search ends.
+    fn highlight_current_search_match(&mut self) {
+        let current = self.search_state.as_ref().and_then(|search_state| {
+            search_state
+                .current_match_index
+                .and_then(|index| search_state.matches.get(index).copied())
+        });
+        let style = ratatui::style::Style::default()
+            .fg(self.theme.search_current_match_fg)
+            .bg(self.theme.search_current_match_bg);
+        let ns = self.search_namespace.clone();
+        let state = self.active_state_mut();
+
+        let previous: Vec<_> = state
+            .overlays
+            .all()
+            .iter()
+            .filter(|o| {
+                o.namespace.as_ref() == Some(&ns) && o.priority == CURRENT_SEARCH_MATCH_PRIORITY
+            })
+            .map(|o| o.handle.clone())
+            .collect();
+        for handle in previous {
+            state
+                .overlays
+                .remove_by_handle(&handle, &mut state.marker_list);
+        }
+
+        let Some(position) = current else {
+            return;
+        };
+        let range = state
+            .overlays
+            .all()
+            .iter()
+            .filter(|o| o.namespace.as_ref() == Some(&ns))
+            .map(|o| o.range(&state.marker_list))
+            .find(|range| range.start == position);
+        if let Some(range) = range {
+            let overlay = crate::view::overlay::Overlay::with_namespace(
+                &mut state.marker_list,
+                range,
+                crate::view::overlay::OverlayFace::Style { style },
+                ns,
+            )
+            .with_priority_value(CURRENT_SEARCH_MATCH_PRIORITY);
+            state.overlays.add(overlay);
+        }
+    }
+
     /// Get current match positions from search overlays (which use markers that track edits)
     /// This ensures positions are always up-to-date even after buffer modifications
     fn get_search_match_positions(&self) -> Vec<usize> {
@@ -2228,6 +2483,18 @@ impl Editor {
         positions
     }
 
+    /// The current search match (1-based) and the total match count, shown in the
+    /// status bar while a search is active
+    pub(super) fn search_match_indicator(&self) -> Option<(usize, usize)> {
+        if !self.config.editor.show_search_match_count {
+            return None;
+        }
+        let search_state = self.search_state.as_ref()?;
+        let current = search_state.current_match_index?;
+        let total = search_state.matches.len();
+        (current < total).then_some((current + 1, total))
+    }
+
     /// Find the next match
     pub(super) fn find_next(&mut self) {
         // Get current positions from overlay markers (auto-updated with buffer edits)
@@ -2261,30 +2528,28 @@ impl Editor {
             search_state.current_match_index = Some(next_index);
             let match_pos = match_positions[next_index];
             let matches_len = match_positions.len();
+            // Keep the cached matches in sync with edits so the status bar count is current
+            search_state.matches = match_positions;
 
             {
-                let active_split = self.split_manager.active_split();
-                let active_buffer = self.active_buffer();
                 let state = self.active_state_mut();
                 state.cursors.primary_mut().position = match_pos;
                 state.cursors.primary_mut().anchor = None;
-                // Ensure cursor is visible - get viewport from SplitViewState
-                if let Some(view_state) = self.split_view_states.get_mut(&active_split) {
-                    let state = self.buffers.get_mut(&active_buffer).unwrap();
-                    view_state
-                        .viewport
-                        .ensure_visible(&mut state.buffer, state.cursors.primary());
-                }
             }
+            self.reveal_cursor_after_jump();
+            self.highlight_current_search_match();
 
-            self.set_status_message(
-                t!(
-                    "search.match_of",
-                    current = next_index + 1,
-                    total = matches_len
-                )
-                .to_string(),
-            );
+            // The status bar shows the match count itself when enabled
+            if !self.config.editor.show_search_match_count {
+                self.set_status_message(
+                    t!(
+                        "search.match_of",
+                        current = next_index + 1,
+                        total = matches_len
+                    )
+                    .to_string(),
+                );
+            }
         } else {
             let find_key = self
                 .get_keybinding_for_action("find")
@@ -2327,30 +2592,28 @@ impl Editor {
             search_state.current_match_index = Some(prev_index);
             let match_pos = match_positions[prev_index];
             let matches_len = match_positions.len();
+            // Keep the cached matches in sync with edits so the status bar count is current
+            search_state.matches = match_positions;
 
             {
-                let active_split = self.split_manager.active_split();
-                let active_buffer = self.active_buffer();
                 let state = self.active_state_mut();
                 state.cursors.primary_mut().position = match_pos;
                 state.cursors.primary_mut().anchor = None;
-                // Ensure cursor is visible - get viewport from SplitViewState
-                if let Some(view_state) = self.split_view_states.get_mut(&active_split) {
-                    let state = self.buffers.get_mut(&active_buffer).unwrap();
-                    view_state
-                        .viewport
-                        .ensure_visible(&mut state.buffer, state.cursors.primary());
-                }
             }
+            self.reveal_cursor_after_jump();
+            self.highlight_current_search_match();
 
-            self.set_status_message(
-                t!(
-                    "search.match_of",
-                    current = prev_index + 1,
-                    total = matches_len
-                )
-                .to_string(),
-            );
+            // The status bar shows the match count itself when enabled
+            if !self.config.editor.show_search_match_count {
+                self.set_status_message(
+                    t!(
+                        "search.match_of",
+                        current = prev_index + 1,
+                        total = matches_len
+                    )
+                    .to_string(),
+                );
+            }
         } else {
             let find_key = self
                 .get_keybinding_for_action("find")
@@ -2516,34 +2779,51 @@ impl Editor {
     /// Perform a replace-all operation
     /// Replaces all occurrences of the search query with the replacement text
     ///
+    /// Matches honour the case, whole-word and regex search options. In regex
+    /// mode the replacement may refer to capture groups (`$1`, `${name}`).
+    ///
     /// OPTIMIZATION: Uses BulkEdit for O(n) tree operations instead of O(n²)
-    /// This directly edits the piece tree without loading the entire buffer into memory
     pub(super) fn perform_replace(&mut self, search: &str, replacement: &str) {
         if search.is_empty() {
             self.set_status_message(t!("replace.empty_query").to_string());
             return;
         }
 
-        // Find all matches first (before making any modifications)
-        let matches = {
-            let state = self.active_state();
-            let buffer_len = state.buffer.len();
-            let mut matches = Vec::new();
-            let mut current_pos = 0;
-
-            while current_pos < buffer_len {
-                if let Some(offset) = state.buffer.find_next_in_range(
-                    search,
-                    current_pos,
-                    Some(current_pos..buffer_len),
-                ) {
-                    matches.push(offset);
-                    current_pos = offset + search.len();
-                } else {
-                    break;
-                }
+        let regex = match regex::bytes::RegexBuilder::new(&self.search_pattern(search))
+            .case_insensitive(!self.search_is_case_sensitive(search))
+            .build()
+        {
+            Ok(r) => r,
+            Err(e) => {
+                self.set_status_message(
+                    t!("error.invalid_regex", error = e.to_string()).to_string(),
+                );
+                return;
             }
-            matches
+        };
+
+        // Find all matches first (before making any modifications)
+        let use_regex = self.search_use_regex;
+        let matches: Vec<(Range<usize>, String, String)> = {
+            let state = self.active_state_mut();
+            let ranges = state
+                .buffer
+                .find_all_regex_matches(0, state.buffer.len(), &regex);
+            ranges
+                .into_iter()
+                .map(|range| {
+                    let matched = state.get_text_range(range.start, range.end);
+                    let text = match regex.captures(matched.as_bytes()) {
+                        Some(caps) if use_regex => {
+                            let mut expanded = Vec::new();
+                            caps.expand(replacement.as_bytes(), &mut expanded);
+                            String::from_utf8_lossy(&expanded).into_owned()
+                        }
+                        _ => replacement.to_string(),
+                    };
+                    (range, matched, text)
+                })
+                .collect()
         };
 
         let count = matches.len();
@@ -2559,17 +2839,18 @@ impl Editor {
         // Create Delete+Insert events for each match
         // Events will be processed in reverse order by apply_events_as_bulk_edit
         let mut events = Vec::with_capacity(count * 2);
-        for &match_pos in &matches {
+        for (range, deleted_text, text) in matches {
+            let position = range.start;
             // Delete the matched text
             events.push(Event::Delete {
-                range: match_pos..match_pos + search.len(),
-                deleted_text: search.to_string(), // We know what text is being deleted
+                range,
+                deleted_text,
                 cursor_id,
             });
             // Insert the replacement
             events.push(Event::Insert {
-                position: match_pos,
-                text: replacement.to_string(),
+                position,
+                text,
                 cursor_id,
             });
         }
@@ -2607,21 +2888,43 @@ impl Editor {
             return;
         }
 
+        // Compile the pattern once for the whole session
+        let regex = match regex::bytes::RegexBuilder::new(&self.search_pattern(search))
+            .case_insensitive(!self.search_is_case_sensitive(search))
+            .build()
+        {
+            Ok(r) => r,
+            Err(e) => {
+                self.set_status_message(
+                    t!("error.invalid_regex", error = e.to_string()).to_string(),
+                );
+                return;
+            }
+        };
+
         // Find the first match lazily (don't find all matches upfront)
         let state = self.active_state();
         let start_pos = state.cursors.primary().position;
-        let first_match = state.buffer.find_next(search, start_pos);
+        let first_match = state
+            .buffer
+            .find_regex_match(start_pos, state.buffer.len(), &regex)
+            .or_else(|| state.buffer.find_regex_match(0, start_pos, &regex))
+            .filter(|range| !range.is_empty());
 
-        let Some(first_match_pos) = first_match else {
+        let Some(first_match) = first_match else {
             self.set_status_message(t!("search.no_occurrences", search = search).to_string());
             return;
         };
+        let first_match_pos = first_match.start;
 
         // Initialize interactive replace state with just the current match
         self.interactive_replace_state = Some(InteractiveReplaceState {
             search: search.to_string(),
+            regex,
+            use_regex: self.search_use_regex,
             replacement: replacement.to_string(),
             current_match_pos: first_match_pos,
+            current_match_len: first_match.len(),
             start_pos: first_match_pos,
             has_wrapped: false,
             replacements_made: 0,
@@ -2660,15 +2963,21 @@ impl Editor {
         match c {
             'y' | 'Y' => {
                 // Replace current match
-                self.replace_current_match(&ir_state)?;
+                let inserted_len = self.replace_current_match(&ir_state)?;
                 ir_state.replacements_made += 1;
+                // Matches before the original start shift the wrap boundary
+                if ir_state.has_wrapped {
+                    ir_state.start_pos = (ir_state.start_pos + inserted_len)
+                        .saturating_sub(ir_state.current_match_len);
+                }
 
                 // Find next match lazily (after the replacement)
-                let search_pos = ir_state.current_match_pos + ir_state.replacement.len();
+                let search_pos = ir_state.current_match_pos + inserted_len;
                 if let Some((next_match, wrapped)) =
                     self.find_next_match_for_replace(&ir_state, search_pos)
                 {
-                    ir_state.current_match_pos = next_match;
+                    ir_state.current_match_pos = next_match.start;
+                    ir_state.current_match_len = next_match.len();
                     if wrapped {
                         ir_state.has_wrapped = true;
                     }
@@ -2680,11 +2989,12 @@ impl Editor {
             }
             'n' | 'N' => {
                 // Skip current match and find next
-                let search_pos = ir_state.current_match_pos + ir_state.search.len();
+                let search_pos = ir_state.current_match_pos + ir_state.current_match_len;
                 if let Some((next_match, wrapped)) =
                     self.find_next_match_for_replace(&ir_state, search_pos)
                 {
-                    ir_state.current_match_pos = next_match;
+                    ir_state.current_match_pos = next_match.start;
+                    ir_state.current_match_len = next_match.len();
                     if wrapped {
                         ir_state.has_wrapped = true;
                     }
@@ -2709,15 +3019,16 @@ impl Editor {
                     temp_state.has_wrapped = false; // Reset wrap state to find current match
 
                     // First, include the current match
-                    matches.push(ir_state.current_match_pos);
-                    let mut current_pos = ir_state.current_match_pos + ir_state.search.len();
+                    let current_end = ir_state.current_match_pos + ir_state.current_match_len;
+                    matches.push(ir_state.current_match_pos..current_end);
+                    let mut current_pos = current_end;
 
                     // Find all remaining matches
                     while let Some((next_match, wrapped)) =
                         self.find_next_match_for_replace(&temp_state, current_pos)
                     {
+                        current_pos = next_match.end;
                         matches.push(next_match);
-                        current_pos = next_match + temp_state.search.len();
                         if wrapped {
                             temp_state.has_wrapped = true;
                         }
@@ -2733,15 +3044,17 @@ impl Editor {
 
                     // Create Delete+Insert events for each match
                     let mut events = Vec::with_capacity(total_count * 2);
-                    for &match_pos in &all_matches {
+                    for range in all_matches {
+                        let (deleted_text, text) = self.replacement_for_match(&ir_state, &range);
+                        let position = range.start;
                         events.push(Event::Delete {
-                            range: match_pos..match_pos + ir_state.search.len(),
-                            deleted_text: ir_state.search.clone(),
+                            range,
+                            deleted_text,
                             cursor_id,
                         });
                         events.push(Event::Insert {
-                            position: match_pos,
-                            text: ir_state.replacement.clone(),
+                            position,
+                            text,
                             cursor_id,
                         });
                     }
@@ -2773,66 +3086,70 @@ impl Editor {
     }
 
     /// Find the next match for interactive replace (lazy search with wrap-around)
+    ///
+    /// Returns the matched byte range and whether the search has wrapped.
     pub(super) fn find_next_match_for_replace(
         &self,
         ir_state: &InteractiveReplaceState,
         start_pos: usize,
-    ) -> Option<(usize, bool)> {
+    ) -> Option<(Range<usize>, bool)> {
         let state = self.active_state();
+        let find = |start: usize, end: usize| {
+            state
+                .buffer
+                .find_regex_match(start, end, &ir_state.regex)
+                .filter(|range| !range.is_empty())
+        };
 
         if ir_state.has_wrapped {
             // We've already wrapped - only search from start_pos up to (but not including) the original start position
-            // Use find_next_in_range to avoid wrapping again
-            let search_range = Some(start_pos..ir_state.start_pos);
-            if let Some(match_pos) =
-                state
-                    .buffer
-                    .find_next_in_range(&ir_state.search, start_pos, search_range)
-            {
-                return Some((match_pos, true));
-            }
-            None // No more matches before original start position
-        } else {
-            // Haven't wrapped yet - search normally from start_pos
-            // First try from start_pos to end of buffer
-            let buffer_len = state.buffer.len();
-            let search_range = Some(start_pos..buffer_len);
-            if let Some(match_pos) =
-                state
-                    .buffer
-                    .find_next_in_range(&ir_state.search, start_pos, search_range)
-            {
-                return Some((match_pos, false));
-            }
-
-            // No match from start_pos to end - wrap to beginning
-            // Search from 0 to start_pos (original position)
-            let wrap_range = Some(0..ir_state.start_pos);
-            if let Some(match_pos) =
-                state
-                    .buffer
-                    .find_next_in_range(&ir_state.search, 0, wrap_range)
-            {
-                return Some((match_pos, true)); // Found match after wrapping
-            }
+            return find(start_pos, ir_state.start_pos).map(|range| (range, true));
+        }
 
-            None // No matches found anywhere
+        // Haven't wrapped yet - first try from start_pos to end of buffer
+        if let Some(range) = find(start_pos, state.buffer.len()) {
+            return Some((range, false));
         }
+
+        // No match from start_pos to end - wrap to beginning
+        // Search from 0 to start_pos (original position)
+        find(0, ir_state.start_pos).map(|range| (range, true))
+    }
+
+    /// The text of a match and the text to replace it with
+    ///
+    /// In regex mode, capture references such as `$1` in the replacement are
+    /// expanded from the match.
+    fn replacement_for_match(
+        &mut self,
+        ir_state: &InteractiveReplaceState,
+        range: &Range<usize>,
+    ) -> (String, String) {
+        let matched = self
+            .active_state_mut()
+            .get_text_range(range.start, range.end);
+        let text = match ir_state.regex.captures(matched.as_bytes()) {
+            Some(caps) if ir_state.use_regex => {
+                let mut expanded = Vec::new();
+                caps.expand(ir_state.replacement.as_bytes(), &mut expanded);
+                String::from_utf8_lossy(&expanded).into_owned()
+            }
+            _ => ir_state.replacement.clone(),
+        };
+        (matched, text)
     }
 
     /// Replace the current match in interactive replace mode
+    ///
+    /// Returns the length in bytes of the inserted replacement.
     pub(super) fn replace_current_match(
         &mut self,
         ir_state: &InteractiveReplaceState,
-    ) -> AnyhowResult<()> {
+    ) -> AnyhowResult<usize> {
         let match_pos = ir_state.current_match_pos;
-        let search_len = ir_state.search.len();
-        let range = match_pos..(match_pos + search_len);
-
-        // Get the deleted text for the event
-        let deleted_text = self
-            .active_state_mut()
-            .get_text_range(range.start, range.end);
+        let range = match_pos..(match_pos + ir_state.current_match_len);
+        let (deleted_text, replacement) = self.replacement_for_match(ir_state, &range);
+        let inserted_len = replacement.len();
 
         // Capture current cursor state for undo
         let cursor_id = self.active_state().cursors.primary_id();
@@ -2860,7 +3177,7 @@ impl Editor {
             },
             Event::Insert {
                 position: match_pos,
-                text: ir_state.replacement.clone(),
+                text: replacement,
                 cursor_id,
             },
         ];
@@ -2878,7 +3195,7 @@ impl Editor {
         self.active_event_log_mut().append(batch.clone());
         self.apply_event_to_active_buffer(&batch);
 
-        Ok(())
+        Ok(inserted_len)
     }
 
     /// Move cursor to the current match in interactive replace
@@ -2919,10 +3236,8 @@ impl Editor {
         self.interactive_replace_state = None;
         self.prompt = None; // Clear the query-replace prompt
 
-        // Clear search highlights
-        let ns = self.search_namespace.clone();
-        let state = self.active_state_mut();
-        state.overlays.clear_namespace(&ns, &mut state.marker_list);
+        // Clear search highlights and state, since match positions are now invalid
+        self.clear_search_highlights();
 
         self.set_status_message(t!("search.replaced_count", count = replacements_made).to_string());
     }
@@ -3159,144 +3474,433 @@ impl Editor {
         );
     }
 
-    /// Go to matching bracket
-    pub(super) fn goto_matching_bracket(&mut self) {
-        let state = self.active_state_mut();
-        let cursor = *state.cursors.primary();
-        let cursor_id = state.cursors.primary_id();
-
-        let pos = cursor.position;
-        if pos >= state.buffer.len() {
-            self.set_status_message(t!("diagnostics.bracket_none").to_string());
+    /// Rewrite the leading indentation of every line in the active buffer
+    /// using tabs or spaces at the buffer's tab size, as a single undo step.
+    ///
+    /// Whitespace after the first non-blank character is left alone so that
+    /// alignment inside lines survives. Cursors inside an indentation run keep
+    /// their visual column; cursors after it move with their text.
+    pub(super) fn convert_indentation(&mut self, use_tabs: bool) {
+        use crate::model::event::CursorId;
+        use crate::primitives::indent::convert_indentation;
+
+        if self.is_editing_disabled() {
+            self.set_status_message(t!("buffer.editing_disabled").to_string());
             return;
         }
 
-        let bytes = state.buffer.slice_bytes(pos..pos + 1);
-        if bytes.is_empty() {
-            self.set_status_message(t!("diagnostics.bracket_none").to_string());
-            return;
+        let estimated_line_length = self.config.editor.estimated_line_length;
+        let state = self.active_state_mut();
+        let tab_size = state.tab_size.max(1);
+
+        // (line_start, old indentation, new indentation) for each line that changes
+        let mut changes: Vec<(usize, String, String)> = Vec::new();
+        let mut iter = state.buffer.line_iterator(0, estimated_line_length);
+        while let Some((line_start, content)) = iter.next_line() {
+            let indent_len = content
+                .bytes()
+                .take_while(|b| *b == b' ' || *b == b'\t')
+                .count();
+            let old_indent = &content[..indent_len];
+            let new_indent = convert_indentation(old_indent, use_tabs, tab_size);
+            if new_indent != old_indent {
+                changes.push((line_start, old_indent.to_string(), new_indent));
+            }
         }
 
-        let ch = bytes[0] as char;
-        let (opening, closing, forward) = match ch {
-            '(' => ('(', ')', true),
-            ')' => ('(', ')', false),
-            '[' => ('[', ']', true),
-            ']' => ('[', ']', false),
-            '{' => ('{', '}', true),
-            '}' => ('{', '}', false),
-            '<' => ('<', '>', true),
-            '>' => ('<', '>', false),
-            _ => {
-                self.set_status_message(t!("diagnostics.bracket_none").to_string());
-                return;
-            }
-        };
+        state.use_tabs = use_tabs;
+        state.setting_overrides.use_tabs = true;
+        if changes.is_empty() {
+            self.set_status_message(t!("settings.indentation_unchanged").to_string());
+            return;
+        }
 
-        // Find matching bracket
-        let buffer_len = state.buffer.len();
-        let mut depth = 1;
-        let matching_pos = if forward {
-            let mut search_pos = pos + 1;
-            let mut found = None;
-            while search_pos < buffer_len && depth > 0 {
-                let b = state.buffer.slice_bytes(search_pos..search_pos + 1);
-                if !b.is_empty() {
-                    let c = b[0] as char;
-                    if c == opening {
-                        depth += 1;
-                    } else if c == closing {
-                        depth -= 1;
-                        if depth == 0 {
-                            found = Some(search_pos);
-                        }
-                    }
+        // Map a byte offset in the old text to the matching offset afterwards
+        let map_position = |pos: usize| -> usize {
+            let mut shift: isize = 0;
+            for (line_start, old_indent, new_indent) in &changes {
+                let old_end = line_start + old_indent.len();
+                if pos <= *line_start {
+                    break;
                 }
-                search_pos += 1;
-            }
-            found
-        } else {
-            let mut search_pos = pos.saturating_sub(1);
-            let mut found = None;
-            loop {
-                let b = state.buffer.slice_bytes(search_pos..search_pos + 1);
-                if !b.is_empty() {
-                    let c = b[0] as char;
-                    if c == closing {
-                        depth += 1;
-                    } else if c == opening {
-                        depth -= 1;
-                        if depth == 0 {
-                            found = Some(search_pos);
+                if pos < old_end {
+                    // Inside the indentation: keep the visual column
+                    let column =
+                        convert_indentation(&old_indent[..pos - line_start], false, tab_size).len();
+                    let mut offset = 0;
+                    let mut col = 0;
+                    for c in new_indent.chars() {
+                        if col >= column {
                             break;
                         }
+                        col += if c == '\t' {
+                            tab_size - col % tab_size
+                        } else {
+                            1
+                        };
+                        offset += 1;
                     }
+                    return (*line_start as isize + shift) as usize + offset;
                 }
-                if search_pos == 0 {
-                    break;
-                }
-                search_pos -= 1;
+                shift += new_indent.len() as isize - old_indent.len() as isize;
             }
-            found
+            (pos as isize + shift) as usize
         };
 
-        if let Some(new_pos) = matching_pos {
-            let event = Event::MoveCursor {
+        let mut events = Vec::new();
+        for (line_start, old_indent, new_indent) in changes.iter().rev() {
+            // Edits use the undo sentinel so no cursor is moved implicitly;
+            // every cursor gets an explicit MoveCursor below
+            if !old_indent.is_empty() {
+                events.push(Event::Delete {
+                    range: *line_start..line_start + old_indent.len(),
+                    deleted_text: old_indent.clone(),
+                    cursor_id: CursorId::UNDO_SENTINEL,
+                });
+            }
+            if !new_indent.is_empty() {
+                events.push(Event::Insert {
+                    position: *line_start,
+                    text: new_indent.clone(),
+                    cursor_id: CursorId::UNDO_SENTINEL,
+                });
+            }
+        }
+
+        let state = self.active_state();
+        for (cursor_id, cursor) in state.cursors.iter() {
+            events.push(Event::MoveCursor {
                 cursor_id,
                 old_position: cursor.position,
-                new_position: new_pos,
+                new_position: map_position(cursor.position),
                 old_anchor: cursor.anchor,
-                new_anchor: None,
-                old_sticky_column: cursor.sticky_column,
+                new_anchor: cursor.anchor.map(map_position),
+                old_sticky_column: 0,
                 new_sticky_column: 0,
-            };
-            self.active_event_log_mut().append(event.clone());
-            self.apply_event_to_active_buffer(&event);
+            });
+        }
+
+        let count = changes.len();
+        let (description, status) = if use_tabs {
+            (
+                "Convert indentation to tabs",
+                t!("settings.indentation_to_tabs", count = count),
+            )
         } else {
-            self.set_status_message(t!("diagnostics.bracket_no_match").to_string());
+            (
+                "Convert indentation to spaces",
+                t!("settings.indentation_to_spaces", count = count),
+            )
+        };
+        if let Some(bulk_edit) = self.apply_events_as_bulk_edit(events, description.to_string()) {
+            self.active_event_log_mut().append(bulk_edit);
         }
+
+        self.set_status_message(status.to_string());
     }
 
-    /// Jump to next error/diagnostic
-    pub(super) fn jump_to_next_error(&mut self) {
-        let diagnostic_ns = self.lsp_diagnostic_namespace.clone();
+    /// Align the lines covered by the primary selection on `delimiter`, as a
+    /// single undo step
+    ///
+    /// Lines without the delimiter are left alone. Cursors on a changed line
+    /// keep their offset into it, clamped to the new line length.
+    pub(super) fn align_on_delimiter(&mut self, delimiter: &str) {
+        use crate::model::event::CursorId;
+        use crate::primitives::align::align_on_delimiter;
+
+        if delimiter.is_empty() {
+            return;
+        }
+        if self.is_editing_disabled() {
+            self.set_status_message(t!("buffer.editing_disabled").to_string());
+            return;
+        }
+
+        let estimated_line_length = self.config.editor.estimated_line_length;
+        let single_space = self.config.editor.align_delimiter_single_space;
         let state = self.active_state_mut();
-        let cursor_pos = state.cursors.primary().position;
-        let cursor_id = state.cursors.primary_id();
-        let cursor = *state.cursors.primary();
+        let Some(range) = state.cursors.primary().selection_range() else {
+            self.set_status_message(t!("lines.align_needs_selection").to_string());
+            return;
+        };
 
-        // Get all diagnostic overlay positions
-        let mut diagnostic_positions: Vec<usize> = state
-            .overlays
-            .all()
+        // (line_start, line text without its line ending) for each selected line;
+        // lines that aren't valid UTF-8 are left out
+        let mut lines: Vec<(usize, String)> = Vec::new();
+        let mut iter = state
+            .buffer
+            .line_iterator(range.start, estimated_line_length);
+        while let Some((line_start, bytes)) = iter.next_line_bytes() {
+            if line_start >= range.end && line_start > range.start {
+                break;
+            }
+            let Ok(content) = String::from_utf8(bytes) else {
+                continue;
+            };
+            let text = content.strip_suffix('\n').unwrap_or(&content);
+            let text = text.strip_suffix('\r').unwrap_or(text);
+            lines.push((line_start, text.to_string()));
+        }
+
+        let texts: Vec<&str> = lines.iter().map(|(_, text)| text.as_str()).collect();
+        let aligned = align_on_delimiter(&texts, delimiter, single_space);
+
+        // (line_start, old text, new text) for each line that changes
+        let changes: Vec<(usize, &str, String)> = lines
             .iter()
-            .filter_map(|overlay| {
-                // Only consider LSP diagnostics (those in the diagnostic namespace)
-                if overlay.namespace.as_ref() == Some(&diagnostic_ns) {
-                    Some(overlay.range(&state.marker_list).start)
-                } else {
-                    None
-                }
-            })
+            .zip(aligned)
+            .filter(|((_, old), new)| old != new)
+            .map(|((line_start, old), new)| (*line_start, old.as_str(), new))
             .collect();
+        if changes.is_empty() {
+            self.set_status_message(t!("lines.align_unchanged", delimiter = delimiter).to_string());
+            return;
+        }
 
-        if diagnostic_positions.is_empty() {
-            self.set_status_message(t!("diagnostics.none").to_string());
+        // Map a byte offset in the old text to the matching offset afterwards
+        let map_position = |pos: usize| -> usize {
+            let mut shift: isize = 0;
+            for (line_start, old, new) in &changes {
+                if pos < *line_start {
+                    break;
+                }
+                if pos <= line_start + old.len() {
+                    let offset = if pos == line_start + old.len() {
+                        new.len()
+                    } else {
+                        (pos - line_start).min(new.len())
+                    };
+                    return (*line_start as isize + shift) as usize + offset;
+                }
+                shift += new.len() as isize - old.len() as isize;
+            }
+            (pos as isize + shift) as usize
+        };
+
+        let mut events = Vec::new();
+        for (line_start, old, new) in changes.iter().rev() {
+            if !old.is_empty() {
+                events.push(Event::Delete {
+                    range: *line_start..line_start + old.len(),
+                    deleted_text: old.to_string(),
+                    cursor_id: CursorId::UNDO_SENTINEL,
+                });
+            }
+            events.push(Event::Insert {
+                position: *line_start,
+                text: new.clone(),
+                cursor_id: CursorId::UNDO_SENTINEL,
+            });
+        }
+
+        let state = self.active_state();
+        for (cursor_id, cursor) in state.cursors.iter() {
+            events.push(Event::MoveCursor {
+                cursor_id,
+                old_position: cursor.position,
+                new_position: map_position(cursor.position),
+                old_anchor: cursor.anchor,
+                new_anchor: cursor.anchor.map(map_position),
+                old_sticky_column: 0,
+                new_sticky_column: 0,
+            });
+        }
+
+        let count = changes.len();
+        if let Some(bulk_edit) =
+            self.apply_events_as_bulk_edit(events, format!("Align on '{}'", delimiter))
+        {
+            self.active_event_log_mut().append(bulk_edit);
+        }
+
+        self.set_status_message(
+            t!("lines.aligned", count = count, delimiter = delimiter).to_string(),
+        );
+    }
+
+    /// Sort the lines covered by the primary selection, or the whole buffer
+    /// when nothing is selected
+    pub(super) fn sort_lines(&mut self, order: LineOrder) {
+        use crate::primitives::sort_lines::sort_lines;
+
+        if let Some((count, _)) = self.rewrite_lines("Sort lines", |lines| sort_lines(lines, order))
+        {
+            self.set_status_message(t!("lines.sorted", count = count).to_string());
+        }
+    }
+
+    /// Remove duplicate lines from the selection, or the whole buffer when
+    /// nothing is selected
+    pub(super) fn unique_lines(&mut self, adjacent_only: bool) {
+        use crate::primitives::sort_lines::unique_lines;
+
+        if let Some((before, after)) = self.rewrite_lines("Remove duplicate lines", |lines| {
+            unique_lines(lines, adjacent_only)
+        }) {
+            self.set_status_message(
+                t!("lines.duplicates_removed", count = before - after).to_string(),
+            );
+        }
+    }
+
+    /// Replace the lines covered by the primary selection (or every line when
+    /// nothing is selected) with `transform` applied to them, as a single undo
+    /// step
+    ///
+    /// Line endings between the new lines follow the buffer's line ending, and
+    /// the line ending after the last line is left in place, so a final newline
+    /// survives. Returns the number of lines before and after, or None when
+    /// nothing could be rewritten.
+    fn rewrite_lines(
+        &mut self,
+        description: &str,
+        transform: impl FnOnce(&[&str]) -> Vec<String>,
+    ) -> Option<(usize, usize)> {
+        use crate::model::event::CursorId;
+
+        if self.is_editing_disabled() {
+            self.set_status_message(t!("buffer.editing_disabled").to_string());
+            return None;
+        }
+
+        let estimated_line_length = self.config.editor.estimated_line_length;
+        let state = self.active_state_mut();
+        let range = state
+            .cursors
+            .primary()
+            .selection_range()
+            .unwrap_or(0..state.buffer.len());
+
+        // (line_start, line text without its line ending) for each covered line
+        let mut lines: Vec<(usize, String)> = Vec::new();
+        let mut valid_utf8 = true;
+        {
+            let mut iter = state
+                .buffer
+                .line_iterator(range.start, estimated_line_length);
+            while let Some((line_start, bytes)) = iter.next_line_bytes() {
+                if line_start >= range.end && line_start > range.start {
+                    break;
+                }
+                let Ok(content) = String::from_utf8(bytes) else {
+                    valid_utf8 = false;
+                    break;
+                };
+                let text = content.strip_suffix('\n').unwrap_or(&content);
+                let text = text.strip_suffix('\r').unwrap_or(text);
+                lines.push((line_start, text.to_string()));
+            }
+        }
+        if !valid_utf8 {
+            self.set_status_message(t!("lines.not_utf8").to_string());
+            return None;
+        }
+        let state = self.active_state_mut();
+        let (Some((block_start, _)), Some((last_start, last_text))) = (lines.first(), lines.last())
+        else {
+            return None;
+        };
+        let block_start = *block_start;
+        let block_end = last_start + last_text.len();
+
+        let texts: Vec<&str> = lines.iter().map(|(_, text)| text.as_str()).collect();
+        let new_lines = transform(&texts);
+        let counts = (lines.len(), new_lines.len());
+
+        let old_text = state.get_text_range(block_start, block_end);
+        let new_text = new_lines.join(state.buffer.line_ending().as_str());
+        if new_text == old_text {
+            return Some(counts);
+        }
+
+        // Positions inside the block are clamped to its new extent
+        let map_position = |pos: usize| -> usize {
+            if pos <= block_start {
+                pos
+            } else if pos >= block_end {
+                pos - old_text.len() + new_text.len()
+            } else {
+                pos.min(block_start + new_text.len())
+            }
+        };
+
+        let mut events = Vec::new();
+        if !old_text.is_empty() {
+            events.push(Event::Delete {
+                range: block_start..block_end,
+                deleted_text: old_text.clone(),
+                cursor_id: CursorId::UNDO_SENTINEL,
+            });
+        }
+        if !new_text.is_empty() {
+            events.push(Event::Insert {
+                position: block_start,
+                text: new_text.clone(),
+                cursor_id: CursorId::UNDO_SENTINEL,
+            });
+        }
+        for (cursor_id, cursor) in state.cursors.iter() {
+            events.push(Event::MoveCursor {
+                cursor_id,
+                old_position: cursor.position,
+                new_position: map_position(cursor.position),
+                old_anchor: cursor.anchor,
+                new_anchor: cursor.anchor.map(map_position),
+                old_sticky_column: 0,
+                new_sticky_column: 0,
+            });
+        }
+
+        if let Some(bulk_edit) = self.apply_events_as_bulk_edit(events, description.to_string()) {
+            self.active_event_log_mut().append(bulk_edit);
+        }
+        Some(counts)
+    }
+
+    /// Go to matching bracket
+    pub(super) fn goto_matching_bracket(&mut self) {
+        let syntax = self
+            .config
+            .languages
+            .get(&self.active_state().language)
+            .and_then(|lang_config| {
+                BracketSyntax::for_language(
+                    &self.active_state().language,
+                    lang_config.comment_prefix.as_deref(),
+                )
+            });
+
+        let state = self.active_state_mut();
+        let cursor = *state.cursors.primary();
+        let cursor_id = state.cursors.primary_id();
+
+        let pos = cursor.position;
+        let buffer_len = state.buffer.len();
+        if pos >= buffer_len {
+            self.set_status_message(t!("diagnostics.bracket_none").to_string());
             return;
         }
 
-        // Sort positions
-        diagnostic_positions.sort_unstable();
-        diagnostic_positions.dedup();
+        // Small buffers are scanned whole so comments and strings can be told
+        // apart from code; in larger ones only a window around the cursor is
+        // read, which could start inside a comment, so no syntax is applied
+        let (window_start, window_end, syntax) = if buffer_len <= BRACKET_SCAN_LIMIT {
+            (0, buffer_len, syntax)
+        } else {
+            let half = BRACKET_SCAN_LIMIT / 2;
+            (pos.saturating_sub(half), buffer_len.min(pos + half), None)
+        };
+        let text = state.buffer.slice_bytes(window_start..window_end);
 
-        // Find next diagnostic after cursor position
-        let next_pos = diagnostic_positions
-            .iter()
-            .find(|&&pos| pos > cursor_pos)
-            .or_else(|| diagnostic_positions.first()) // Wrap around
-            .copied();
+        if !is_bracket(&text, pos - window_start) {
+            self.set_status_message(t!("diagnostics.bracket_none").to_string());
+            return;
+        }
+
+        let matching_pos = find_matching_bracket(&text, pos - window_start, syntax.as_ref())
+            .map(|offset| window_start + offset);
 
-        if let Some(new_pos) = next_pos {
+        if let Some(new_pos) = matching_pos {
             let event = Event::MoveCursor {
                 cursor_id,
                 old_position: cursor.position,
@@ -3308,25 +3912,26 @@ impl Editor {
             };
             self.active_event_log_mut().append(event.clone());
             self.apply_event_to_active_buffer(&event);
-
-            // Show diagnostic message in status bar
-            let state = self.active_state();
-            if let Some(msg) = state.overlays.all().iter().find_map(|overlay| {
-                let range = overlay.range(&state.marker_list);
-                if range.start == new_pos && overlay.namespace.as_ref() == Some(&diagnostic_ns) {
-                    overlay.message.clone()
-                } else {
-                    None
-                }
-            }) {
-                self.set_status_message(msg);
-            }
+        } else {
+            self.set_status_message(t!("diagnostics.bracket_no_match").to_string());
         }
     }
 
+    /// Jump to next error/diagnostic
+    pub(super) fn jump_to_next_error(&mut self) {
+        self.jump_to_diagnostic(true);
+    }
+
     /// Jump to previous error/diagnostic
     pub(super) fn jump_to_previous_error(&mut self) {
+        self.jump_to_diagnostic(false);
+    }
+
+    /// Move the primary cursor to the next (or previous) diagnostic in the
+    /// active buffer, wrapping around, and show its message in the status bar
+    fn jump_to_diagnostic(&mut self, forward: bool) {
         let diagnostic_ns = self.lsp_diagnostic_namespace.clone();
+        let buffer_id = self.active_buffer();
         let state = self.active_state_mut();
         let cursor_pos = state.cursors.primary().position;
         let cursor_id = state.cursors.primary_id();
@@ -3356,15 +3961,22 @@ impl Editor {
         diagnostic_positions.sort_unstable();
         diagnostic_positions.dedup();
 
-        // Find previous diagnostic before cursor position
-        let prev_pos = diagnostic_positions
-            .iter()
-            .rev()
-            .find(|&&pos| pos < cursor_pos)
-            .or_else(|| diagnostic_positions.last()) // Wrap around
-            .copied();
+        // Find the next diagnostic past the cursor in the requested direction,
+        // wrapping around at either end of the buffer
+        let target = if forward {
+            diagnostic_positions
+                .iter()
+                .find(|&&pos| pos > cursor_pos)
+                .or_else(|| diagnostic_positions.first())
+        } else {
+            diagnostic_positions
+                .iter()
+                .rev()
+                .find(|&&pos| pos < cursor_pos)
+                .or_else(|| diagnostic_positions.last())
+        };
 
-        if let Some(new_pos) = prev_pos {
+        if let Some(&new_pos) = target {
             let event = Event::MoveCursor {
                 cursor_id,
                 old_position: cursor.position,
@@ -3376,6 +3988,18 @@ impl Editor {
             };
             self.active_event_log_mut().append(event.clone());
             self.apply_event_to_active_buffer(&event);
+            self.reveal_cursor_after_jump();
+
+            // Record the jump so navigate back returns to where it started
+            if !self.in_navigation {
+                self.position_history.commit_pending_movement();
+                self.position_history
+                    .record_movement(buffer_id, cursor.position, cursor.anchor);
+                self.position_history.commit_pending_movement();
+                self.position_history
+                    .record_movement(buffer_id, new_pos, None);
+                self.position_history.commit_pending_movement();
+            }
 
             // Show diagnostic message in status bar
             let state = self.active_state();
@@ -3487,6 +4111,11 @@ impl Editor {
 
     /// Play back a recorded macro
     pub(super) fn play_macro(&mut self, key: char) {
+        self.play_macro_times(key, 1);
+    }
+
+    /// Play back a recorded macro `times` times in a row
+    pub(super) fn play_macro_times(&mut self, key: char, times: usize) {
         // Prevent recursive macro playback
         if self.macro_playing {
             return;
@@ -3503,22 +4132,92 @@ impl Editor {
             self.macro_playing = true;
 
             let action_count = actions.len();
-            for action in actions {
-                let _ = self.handle_action(action);
+            for _ in 0..times {
+                for action in actions.iter().cloned() {
+                    let _ = self.handle_action(action);
+                }
             }
 
             // Restore recording and playing state
             self.macro_recording = was_recording;
             self.macro_playing = false;
 
-            self.set_status_message(
-                t!("macro.played", key = key, count = action_count).to_string(),
-            );
+            let message = if times == 1 {
+                t!("macro.played", key = key, count = action_count)
+            } else {
+                t!(
+                    "macro.played_times",
+                    key = key,
+                    times = times,
+                    count = action_count
+                )
+            };
+            self.set_status_message(message.to_string());
         } else {
             self.set_status_message(t!("macro.not_found", key = key).to_string());
         }
     }
 
+    /// Replay the last run of editing actions at the current cursor
+    pub(super) fn repeat_last_edit(&mut self) {
+        if self.last_edit.is_empty() {
+            self.set_status_message(t!("status.no_edit_to_repeat").to_string());
+            return;
+        }
+
+        self.repeating_last_edit = true;
+        for action in self.last_edit.clone() {
+            let _ = self.handle_action(action);
+        }
+        self.repeating_last_edit = false;
+    }
+
+    /// Remember `action` for RepeatLastEdit if it edits the buffer
+    ///
+    /// Consecutive edits (e.g. typing a word) are stored as one run. Any other
+    /// action ends the run without forgetting it, so moving the cursor and then
+    /// repeating replays the run at the new position.
+    pub(super) fn record_last_edit(&mut self, action: &Action) {
+        if self.repeating_last_edit || matches!(action, Action::RepeatLastEdit) {
+            return;
+        }
+
+        let is_repeatable_edit = !self.is_prompting()
+            && matches!(
+                action,
+                Action::InsertChar(_)
+                    | Action::InsertNewline
+                    | Action::InsertTab
+                    | Action::DeleteBackward
+                    | Action::DeleteForward
+                    | Action::DeleteWordBackward
+                    | Action::DeleteWordForward
+                    | Action::DeleteLine
+                    | Action::DeleteToLineEnd
+                    | Action::DeleteToLineStart
+                    | Action::DeleteTextObject(_)
+                    | Action::TransposeChars
+                    | Action::OpenLine
+                    | Action::OpenLineBelow
+                    | Action::OpenLineAbove
+                    | Action::Cut
+                    | Action::Paste
+                    | Action::PasteAndIndent
+                    | Action::DedentSelection
+                    | Action::ToggleComment
+                    | Action::ToUpperCase
+                    | Action::ToLowerCase
+            );
+
+        if is_repeatable_edit {
+            if !self.extending_last_edit {
+                self.last_edit.clear();
+            }
+            self.last_edit.push(action.clone());
+        }
+        self.extending_last_edit = is_repeatable_edit;
+    }
+
     /// Record an action to the current macro (if recording)
     pub(super) fn record_macro_action(&mut self, action: &Action) {
         if let Some(state) = &mut self.macro_recording {
@@ -3532,7 +4231,8 @@ impl Editor {
                 | Action::ListMacros
                 | Action::PromptRecordMacro
                 | Action::PromptPlayMacro
-                | Action::PlayLastMacro => {}
+                | Action::PlayLastMacro
+                | Action::PromptPlayLastMacroTimes => {}
                 // When recording PromptConfirm, capture the current prompt text
                 // so it can be replayed correctly
                 Action::PromptConfirm => {
@@ -3594,7 +4294,7 @@ impl Editor {
             .set_line_numbers(self.config.editor.line_numbers);
 
         self.buffers.insert(buffer_id, state);
-        self.event_logs.insert(buffer_id, EventLog::new());
+        self.event_logs.insert(buffer_id, self.new_event_log());
 
         // Set buffer content
         if let Some(state) = self.buffers.get_mut(&buffer_id) {
@@ -3667,7 +4367,7 @@ impl Editor {
             .set_line_numbers(self.config.editor.line_numbers);
 
         self.buffers.insert(buffer_id, state);
-        self.event_logs.insert(buffer_id, EventLog::new());
+        self.event_logs.insert(buffer_id, self.new_event_log());
 
         // Set buffer content
         if let Some(state) = self.buffers.get_mut(&buffer_id) {
@@ -3759,6 +4459,56 @@ impl Editor {
         }
     }
 
+    /// Shift bookmarks in a buffer past an edit so they stay on the same text
+    ///
+    /// Uses the same rules as cursors: bookmarks after the edit move by its
+    /// length delta, and bookmarks inside a deleted range move to its end.
+    pub(super) fn adjust_bookmarks_for_edit(
+        &mut self,
+        buffer_id: BufferId,
+        edit_pos: usize,
+        old_len: usize,
+        new_len: usize,
+    ) {
+        for bookmark in self.bookmarks.values_mut() {
+            if bookmark.buffer_id != buffer_id || edit_pos > bookmark.position {
+                continue;
+            }
+            bookmark.position = if edit_pos + old_len <= bookmark.position {
+                bookmark.position - old_len + new_len
+            } else {
+                edit_pos + new_len
+            };
+        }
+    }
+
+    /// Shift bookmarks in `buffer_id` for an Insert, Delete, Batch or BulkEdit event
+    pub(super) fn adjust_bookmarks_for_event(&mut self, buffer_id: BufferId, event: &Event) {
+        if self.bookmarks.is_empty() {
+            return;
+        }
+        match event {
+            Event::Insert { position, text, .. } => {
+                self.adjust_bookmarks_for_edit(buffer_id, *position, 0, text.len());
+            }
+            Event::Delete { range, .. } => {
+                self.adjust_bookmarks_for_edit(buffer_id, range.start, range.len(), 0);
+            }
+            Event::Batch { events, .. } => {
+                for event in events {
+                    self.adjust_bookmarks_for_event(buffer_id, event);
+                }
+            }
+            Event::BulkEdit { edits, .. } => {
+                // Edits are sorted descending, so each one's position is still valid
+                for (position, old_len, new_len) in edits {
+                    self.adjust_bookmarks_for_edit(buffer_id, *position, *old_len, *new_len);
+                }
+            }
+            _ => {}
+        }
+    }
+
     /// List all bookmarks
     pub(super) fn list_bookmarks(&mut self) {
         if self.bookmarks.is_empty() {
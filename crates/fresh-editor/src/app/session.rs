@@ -26,15 +26,18 @@ use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::time::Instant;
 
+use rust_i18n::t;
+
 use crate::state::EditorState;
 
 use crate::model::event::{BufferId, SplitDirection, SplitId};
 use crate::services::terminal::TerminalId;
 use crate::session::{
     FileExplorerState, PersistedFileSession, SearchOptions, SerializedBookmark, SerializedCursor,
-    SerializedFileState, SerializedScroll, SerializedSplitDirection, SerializedSplitNode,
-    SerializedSplitViewState, SerializedTabRef, SerializedTerminalSession, SerializedViewMode,
-    Session, SessionConfigOverrides, SessionError, SessionHistories, SESSION_VERSION,
+    SerializedFileState, SerializedMacro, SerializedScroll, SerializedSplitDirection,
+    SerializedSplitNode, SerializedSplitViewState, SerializedTabRef, SerializedTerminalSession,
+    SerializedViewMode, Session, SessionConfigOverrides, SessionError, SessionHistories,
+    SESSION_VERSION,
 };
 use crate::state::ViewMode;
 use crate::view::split::{SplitNode, SplitViewState};
@@ -268,6 +271,8 @@ impl Editor {
             .buffer_metadata
             .values()
             .filter_map(|meta| meta.file_path())
+            // Unnamed buffers carry an empty path
+            .filter(|abs_path| !abs_path.as_os_str().is_empty())
             .filter(|abs_path| abs_path.strip_prefix(&self.working_dir).is_err())
             .cloned()
             .collect();
@@ -275,6 +280,14 @@ impl Editor {
             tracing::debug!("Captured {} external files", external_files.len());
         }
 
+        // Capture the last recorded macro
+        let last_macro = self.last_macro_register.and_then(|register| {
+            self.macros.get(&register).map(|actions| SerializedMacro {
+                register,
+                actions: actions.clone(),
+            })
+        });
+
         Session {
             version: SESSION_VERSION,
             working_dir: self.working_dir.clone(),
@@ -288,6 +301,7 @@ impl Editor {
             bookmarks,
             terminals,
             external_files,
+            last_macro,
             saved_at: std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap_or_default()
@@ -430,6 +444,103 @@ impl Editor {
         }
     }
 
+    /// Save the session on user request (Save Session command)
+    ///
+    /// Unnamed buffers with content cannot be stored in the session; they are
+    /// counted and reported in the status bar so the user knows to save them.
+    pub fn save_session_command(&mut self) {
+        let skipped = self.unsaved_scratch_buffer_count();
+        match self.save_session() {
+            Ok(()) => {
+                let message = if skipped > 0 {
+                    t!("session.saved_skipped", count = skipped)
+                } else {
+                    t!("session.saved")
+                };
+                self.set_status_message(message.to_string());
+            }
+            Err(e) => {
+                self.set_status_message(
+                    t!("session.save_failed", error = e.to_string()).to_string(),
+                );
+            }
+        }
+    }
+
+    /// Restore the saved session on user request (Restore Session command)
+    ///
+    /// Collapses the current layout to the active split so the saved layout is
+    /// rebuilt from scratch rather than nested inside existing splits. Files that
+    /// no longer exist are skipped and reported in the status bar.
+    pub fn restore_session_command(&mut self) {
+        let session = match Session::load(&self.working_dir) {
+            Ok(Some(session)) => session,
+            Ok(None) => {
+                self.set_status_message(t!("session.none").to_string());
+                return;
+            }
+            Err(e) => {
+                self.set_status_message(
+                    t!("session.restore_failed", error = e.to_string()).to_string(),
+                );
+                return;
+            }
+        };
+
+        let missing = collect_file_paths_from_states(&session.split_states)
+            .iter()
+            .map(|rel_path| self.working_dir.join(rel_path))
+            .chain(session.external_files.iter().cloned())
+            .filter(|path| !path.exists())
+            .count();
+
+        let active_split = self.split_manager.active_split();
+        for split_id in self.split_manager.root().leaf_split_ids() {
+            if split_id != active_split && self.split_manager.close_split(split_id).is_ok() {
+                self.split_view_states.remove(&split_id);
+            }
+        }
+
+        match self.apply_session(&session) {
+            Ok(()) => {
+                let message = if missing > 0 {
+                    t!("session.restored_missing", count = missing)
+                } else {
+                    t!("session.restored")
+                };
+                self.set_status_message(message.to_string());
+            }
+            Err(e) => {
+                self.set_status_message(
+                    t!("session.restore_failed", error = e.to_string()).to_string(),
+                );
+            }
+        }
+    }
+
+    /// Count open unnamed buffers with content, which a session cannot store
+    fn unsaved_scratch_buffer_count(&self) -> usize {
+        let open_buffers: HashSet<BufferId> = self
+            .split_view_states
+            .values()
+            .flat_map(|vs| vs.open_buffers.iter().copied())
+            .collect();
+        open_buffers
+            .into_iter()
+            .filter(|id| !self.terminal_buffers.contains_key(id))
+            .filter(|id| {
+                // Unnamed buffers carry an empty path
+                self.buffer_metadata.get(id).is_some_and(|meta| {
+                    !meta.is_virtual()
+                        && meta
+                            .file_path()
+                            .is_none_or(|path| path.as_os_str().is_empty())
+                })
+            })
+            .filter(|id| self.buffers.get(id).is_some_and(|s| !s.buffer.is_empty()))
+            .count()
+    }
+
     /// Apply a loaded session to the editor
     pub fn apply_session(&mut self, session: &Session) -> Result<(), SessionError> {
         tracing::debug!(
@@ -617,6 +728,13 @@ impl Editor {
             }
         }
 
+        // 8. Restore the last recorded macro
+        if let Some(last_macro) = &session.last_macro {
+            self.macros
+                .insert(last_macro.register, last_macro.actions.clone());
+            self.last_macro_register = Some(last_macro.register);
+        }
+
         tracing::debug!(
             "Session restore complete: {} splits, {} buffers",
             self.split_view_states.len(),
@@ -8,7 +8,6 @@
 //! - Incrementing/decrementing numeric values
 
 use crate::config_io::{ConfigLayer, ConfigResolver};
-use crate::input::keybindings::KeybindingResolver;
 use anyhow::Result as AnyhowResult;
 use rust_i18n::t;
 
@@ -130,7 +129,11 @@ impl Editor {
         self.apply_plugin_config_changes(&old_plugins);
 
         // Update keybindings
-        self.keybindings = KeybindingResolver::new(&self.config);
+        self.rebuild_keybindings();
+
+        self.apply_undo_history_limits();
+        self.clipboard
+            .set_osc52_enabled(self.config.editor.osc52_clipboard);
 
         // Save ONLY the changes to disk (preserves external edits to the config file)
         let resolver = ConfigResolver::new(self.dir_context.clone(), self.working_dir.clone());
@@ -144,6 +147,7 @@ impl Editor {
 
         match resolver.save_changes_to_layer(&pending_changes, &pending_deletions, target_layer) {
             Ok(()) => {
+                self.config_layer_sources = self.load_config_layer_sources();
                 self.set_status_message(
                     t!("settings.saved_to_layer", layer = layer_name).to_string(),
                 );
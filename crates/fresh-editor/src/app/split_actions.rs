@@ -7,10 +7,12 @@
 //! - Managing per-split view states (cursors, viewport)
 //! - Split size adjustment and maximize
 
+use ratatui::layout::Rect;
 use rust_i18n::t;
 
 use crate::model::event::{BufferId, Event, SplitDirection, SplitId};
 use crate::view::split::SplitViewState;
+use crate::view::viewport::Viewport;
 
 use super::Editor;
 
@@ -125,6 +127,101 @@ impl Editor {
         }
     }
 
+    /// Move the active buffer to the next or previous split
+    ///
+    /// The destination split shows the buffer with a fresh viewport, keeping
+    /// its cursors. Does nothing when there is only one split.
+    pub fn move_buffer_to_adjacent_split(&mut self, next: bool) {
+        let Some(target) = self.adjacent_split(next) else {
+            self.set_status_message(t!("split.no_other_split").to_string());
+            return;
+        };
+        self.save_current_split_view_state();
+        let source = self.split_manager.active_split();
+        let buffer_id = self.active_buffer();
+        let cursors = self
+            .split_view_states
+            .get(&source)
+            .map(|vs| vs.cursors.clone());
+
+        self.move_tab_to_split(buffer_id, source, target, None);
+
+        if let Some(view_state) = self.split_view_states.get_mut(&target) {
+            let mut viewport = Viewport::new(self.terminal_width, self.terminal_height);
+            viewport.line_wrap_enabled = self.config.editor.line_wrap;
+            view_state.viewport = viewport;
+            if let Some(cursors) = cursors {
+                view_state.cursors = cursors;
+            }
+            view_state.invalidate_layout();
+        }
+        self.restore_current_split_view_state();
+    }
+
+    /// Swap the active buffer with the buffer shown in the next split
+    ///
+    /// Each buffer keeps its cursors and scroll position, and focus follows
+    /// the active buffer. Does nothing when there is only one split.
+    pub fn swap_buffer_with_next_split(&mut self) {
+        let Some(target) = self.adjacent_split(true) else {
+            self.set_status_message(t!("split.no_other_split").to_string());
+            return;
+        };
+        self.save_current_split_view_state();
+        let source = self.split_manager.active_split();
+        let source_buffer = self.active_buffer();
+        let Some(target_buffer) = self.split_manager.get_buffer_id(target) else {
+            return;
+        };
+
+        // Exchange the tabs, keeping each at its position in the tab bar
+        for (split_id, old, new) in [
+            (source, source_buffer, target_buffer),
+            (target, target_buffer, source_buffer),
+        ] {
+            if let Some(view_state) = self.split_view_states.get_mut(&split_id) {
+                let tabs = &mut view_state.open_buffers;
+                let index = tabs.iter().position(|&id| id == old).unwrap_or(tabs.len());
+                tabs.retain(|&id| id != old && id != new);
+                tabs.insert(index.min(tabs.len()), new);
+                view_state.invalidate_layout();
+            }
+            let _ = self.split_manager.set_split_buffer(split_id, new);
+        }
+
+        // Each buffer takes its cursors and scroll position along
+        if let (Some(mut source_view), Some(mut target_view)) = (
+            self.split_view_states.remove(&source),
+            self.split_view_states.remove(&target),
+        ) {
+            std::mem::swap(&mut source_view.cursors, &mut target_view.cursors);
+            std::mem::swap(&mut source_view.viewport, &mut target_view.viewport);
+            self.split_view_states.insert(source, source_view);
+            self.split_view_states.insert(target, target_view);
+        }
+
+        self.split_manager.set_active_split(target);
+        self.restore_current_split_view_state();
+        self.set_status_message(t!("split.buffers_swapped").to_string());
+    }
+
+    /// The split after (or before) the active one, if there is another split
+    fn adjacent_split(&self, next: bool) -> Option<SplitId> {
+        let leaf_ids = self.split_manager.root().leaf_split_ids();
+        if leaf_ids.len() < 2 {
+            return None;
+        }
+        let pos = leaf_ids
+            .iter()
+            .position(|&id| id == self.split_manager.active_split())?;
+        let adjacent = if next {
+            (pos + 1) % leaf_ids.len()
+        } else {
+            (pos + leaf_ids.len() - 1) % leaf_ids.len()
+        };
+        Some(leaf_ids[adjacent])
+    }
+
     /// Switch to next split
     pub fn next_split(&mut self) {
         self.switch_split(true);
@@ -285,7 +382,14 @@ impl Editor {
     /// Adjust the size of the active split
     pub fn adjust_split_size(&mut self, delta: f32) {
         let active_split = self.split_manager.active_split();
-        if let Err(e) = self.split_manager.adjust_ratio(active_split, delta) {
+        let viewport = self
+            .cached_layout
+            .editor_content_area
+            .unwrap_or_else(|| Rect::new(0, 0, self.terminal_width, self.terminal_height));
+        if let Err(e) = self
+            .split_manager
+            .resize_split(active_split, delta, viewport)
+        {
             self.set_status_message(t!("split.cannot_adjust", error = e).to_string());
         } else {
             let percent = (delta * 100.0) as i32;
@@ -295,6 +399,14 @@ impl Editor {
         }
     }
 
+    /// Give every split an equal share of the space
+    pub fn equalize_splits(&mut self) {
+        self.split_manager.distribute_splits_evenly();
+        self.set_status_message(t!("split.equalized").to_string());
+        // Resize visible terminals to match new split dimensions
+        self.resize_visible_terminals();
+    }
+
     /// Toggle maximize state for the active split
     pub fn toggle_maximize_split(&mut self) {
         match self.split_manager.toggle_maximize() {
@@ -241,7 +241,7 @@ impl Editor {
     }
 
     /// Move a tab from one split to another
-    fn move_tab_to_split(
+    pub(super) fn move_tab_to_split(
         &mut self,
         buffer_id: BufferId,
         source_split_id: SplitId,
@@ -176,8 +176,7 @@ impl Editor {
         self.terminal_buffers.insert(buffer_id, terminal_id);
 
         // Initialize event log for undo/redo
-        self.event_logs
-            .insert(buffer_id, crate::model::event::EventLog::new());
+        self.event_logs.insert(buffer_id, self.new_event_log());
 
         // Set up split view state
         if let Some(view_state) = self.split_view_states.get_mut(&split_id) {
@@ -231,8 +230,7 @@ impl Editor {
         );
         self.buffer_metadata.insert(buffer_id, metadata);
         self.terminal_buffers.insert(buffer_id, terminal_id);
-        self.event_logs
-            .insert(buffer_id, crate::model::event::EventLog::new());
+        self.event_logs.insert(buffer_id, self.new_event_log());
 
         buffer_id
     }
@@ -1,17 +1,23 @@
 //! Toggle actions and configuration operations for the Editor.
 //!
 //! This module contains toggle methods and configuration operations:
-//! - Toggle line numbers, debug highlights, menu bar
+//! - Toggle line numbers, debug highlights, menu bar, cursor details
 //! - Toggle mouse capture, mouse hover, inlay hints
 //! - Reset buffer settings
 //! - Config dump, save, and reload
+//! - Live reload when a config file changes on disk
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
 
 use rust_i18n::t;
 
 use crate::config::Config;
 use crate::config_io::{ConfigLayer, ConfigResolver};
-use crate::input::keybindings::KeybindingResolver;
+use crate::model::event::BufferId;
 use crate::services::lsp::manager::detect_language;
+use crate::state::BufferSettingOverrides;
 
 use super::Editor;
 
@@ -78,6 +84,33 @@ impl Editor {
         self.tab_bar_visible
     }
 
+    /// Toggle the cursor byte offset / file size segment in the status bar
+    pub fn toggle_cursor_details(&mut self) {
+        self.cursor_details_visible = !self.cursor_details_visible;
+        let status = if self.cursor_details_visible {
+            t!("toggle.cursor_details_shown")
+        } else {
+            t!("toggle.cursor_details_hidden")
+        };
+        self.set_status_message(status.to_string());
+    }
+
+    /// Buffer settings (tab_size, use_tabs, show_whitespace_tabs) the config
+    /// gives a buffer, with language-specific values taking precedence
+    fn config_buffer_settings(&self, path: Option<&Path>) -> (usize, bool, bool) {
+        let lang_config = path
+            .and_then(|path| detect_language(path, &self.config.languages))
+            .and_then(|language| self.config.languages.get(&language));
+        match lang_config {
+            Some(lang_config) => (
+                lang_config.tab_size.unwrap_or(self.config.editor.tab_size),
+                lang_config.use_tabs,
+                lang_config.show_whitespace_tabs,
+            ),
+            None => (self.config.editor.tab_size, false, true),
+        }
+    }
+
     /// Reset buffer settings (tab_size, use_tabs, show_whitespace_tabs) to config defaults
     pub fn reset_buffer_settings(&mut self) {
         let buffer_id = self.active_buffer();
@@ -87,36 +120,52 @@ impl Editor {
             .buffer_metadata
             .get(&buffer_id)
             .and_then(|m| m.file_path().cloned());
-
-        // Determine settings from config (with language fallback)
-        let (tab_size, use_tabs, show_whitespace_tabs) = if let Some(path) = &file_path {
-            if let Some(language) = detect_language(path, &self.config.languages) {
-                if let Some(lang_config) = self.config.languages.get(&language) {
-                    (
-                        lang_config.tab_size.unwrap_or(self.config.editor.tab_size),
-                        lang_config.use_tabs,
-                        lang_config.show_whitespace_tabs,
-                    )
-                } else {
-                    (self.config.editor.tab_size, false, true)
-                }
-            } else {
-                (self.config.editor.tab_size, false, true)
-            }
-        } else {
-            (self.config.editor.tab_size, false, true)
-        };
+        let (tab_size, use_tabs, show_whitespace_tabs) =
+            self.config_buffer_settings(file_path.as_deref());
 
         // Apply settings to buffer
         if let Some(state) = self.buffers.get_mut(&buffer_id) {
             state.tab_size = tab_size;
+            state.detected_indent_width = None;
+            state.editorconfig_indent = false;
             state.use_tabs = use_tabs;
             state.show_whitespace_tabs = show_whitespace_tabs;
+            state.setting_overrides = BufferSettingOverrides::default();
         }
 
         self.set_status_message(t!("toggle.buffer_settings_reset").to_string());
     }
 
+    /// Apply the config's buffer settings to every open file buffer, except
+    /// the settings a buffer has its own value for
+    fn apply_config_buffer_settings(&mut self) {
+        let buffer_ids: Vec<BufferId> = self.buffers.keys().copied().collect();
+        for buffer_id in buffer_ids {
+            let Some(metadata) = self.buffer_metadata.get(&buffer_id) else {
+                continue;
+            };
+            if metadata.is_virtual() {
+                continue;
+            }
+            let file_path = metadata.file_path().cloned();
+            let (tab_size, use_tabs, show_whitespace_tabs) =
+                self.config_buffer_settings(file_path.as_deref());
+
+            if let Some(state) = self.buffers.get_mut(&buffer_id) {
+                let overrides = state.setting_overrides;
+                if !overrides.tab_size {
+                    state.tab_size = tab_size;
+                }
+                if !overrides.use_tabs {
+                    state.use_tabs = use_tabs;
+                }
+                if !overrides.show_whitespace_tabs {
+                    state.show_whitespace_tabs = show_whitespace_tabs;
+                }
+            }
+        }
+    }
+
     /// Toggle mouse capture on/off
     pub fn toggle_mouse_capture(&mut self) {
         use std::io::stdout;
@@ -241,8 +290,19 @@ impl Editor {
     /// and emits a config_changed event so plugins can update their state accordingly.
     /// Uses the layered config system to properly merge with defaults.
     pub fn reload_config(&mut self) {
+        let config = Config::load_with_layers(&self.dir_context, &self.working_dir);
+        self.apply_config(config);
+        self.report_skipped_project_toml();
+    }
+
+    /// Replace the running configuration with `config`
+    ///
+    /// Applies runtime changes (theme, keybindings, LSP settings) and emits a
+    /// config_changed event for plugins.
+    fn apply_config(&mut self, config: Config) {
         let old_theme = self.config.theme.clone();
-        self.config = Config::load_with_layers(&self.dir_context, &self.working_dir);
+        self.config = config;
+        self.config_layer_sources = self.load_config_layer_sources();
 
         // Apply theme change if needed
         if old_theme != self.config.theme {
@@ -257,7 +317,12 @@ impl Editor {
         }
 
         // Always reload keybindings (complex types don't implement PartialEq)
-        self.keybindings = KeybindingResolver::new(&self.config);
+        self.rebuild_keybindings();
+
+        self.apply_config_buffer_settings();
+        self.apply_undo_history_limits();
+        self.clipboard
+            .set_osc52_enabled(self.config.editor.osc52_clipboard);
 
         // Update LSP configs
         if let Some(ref mut lsp) = self.lsp {
@@ -275,4 +340,95 @@ impl Editor {
             }),
         );
     }
+
+    /// Modification times of the config files that make up the layered config
+    pub(super) fn config_file_mod_times(&self) -> HashMap<PathBuf, SystemTime> {
+        let resolver = ConfigResolver::new(self.dir_context.clone(), self.working_dir.clone());
+        let mut paths = vec![
+            resolver.user_config_path(),
+            resolver.project_config_path(),
+            resolver.session_config_path(),
+        ];
+        paths.extend(resolver.user_platform_config_path());
+        paths.extend(resolver.project_toml_path());
+
+        paths
+            .into_iter()
+            .filter_map(|path| {
+                let mtime = std::fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+                Some((path, mtime))
+            })
+            .collect()
+    }
+
+    /// Show the error if the project `.fresh.toml` can't be read, in which
+    /// case the config was loaded without it. Returns whether it was skipped.
+    pub(super) fn report_skipped_project_toml(&mut self) -> bool {
+        let resolver = ConfigResolver::new(self.dir_context.clone(), self.working_dir.clone());
+        match resolver.load_project_toml_layer() {
+            Ok(_) => false,
+            Err(e) => {
+                self.set_status_message(
+                    t!("config.project_toml_skipped", error = e.to_string()).to_string(),
+                );
+                true
+            }
+        }
+    }
+
+    /// Which config layer sets each setting, read from the config files
+    pub(super) fn load_config_layer_sources(&self) -> HashMap<String, ConfigLayer> {
+        let resolver = ConfigResolver::new(self.dir_context.clone(), self.working_dir.clone());
+        resolver.get_layer_sources().unwrap_or_default()
+    }
+
+    /// Poll the config files and reload the config when one changes on disk
+    ///
+    /// A change is applied on the poll after it is seen, and only if the files
+    /// have not changed again in between, so a burst of writes triggers a
+    /// single reload. A config that fails to parse keeps the running config and
+    /// shows the error instead. Returns true if a re-render is needed.
+    pub fn poll_config_changes(&mut self) -> bool {
+        let poll_interval = Duration::from_millis(self.config.editor.auto_revert_poll_interval_ms);
+        if self.time_source.elapsed_since(self.last_config_poll) < poll_interval {
+            return false;
+        }
+        self.last_config_poll = self.time_source.now();
+
+        let mod_times = self.config_file_mod_times();
+        if mod_times != self.config_mod_times {
+            self.config_mod_times = mod_times;
+            self.config_reload_pending = true;
+            return false;
+        }
+        if !std::mem::take(&mut self.config_reload_pending) {
+            return false;
+        }
+
+        let resolver = ConfigResolver::new(self.dir_context.clone(), self.working_dir.clone());
+        match resolver.resolve() {
+            Ok(config) => {
+                let skipped = self.report_skipped_project_toml();
+                // Our own writes (e.g. saving from the settings UI) already
+                // match the running config, but may still move a setting
+                // between layers
+                if serde_json::to_value(&config).ok() == serde_json::to_value(&self.config).ok() {
+                    self.config_layer_sources = self.load_config_layer_sources();
+                    return skipped;
+                }
+                tracing::info!("Config changed on disk, reloading");
+                self.apply_config(config);
+                if !skipped {
+                    self.set_status_message(t!("config.reloaded").to_string());
+                }
+            }
+            Err(e) => {
+                tracing::warn!("Ignoring invalid config change: {}", e);
+                self.set_status_message(
+                    t!("config.reload_failed", error = e.to_string()).to_string(),
+                );
+            }
+        }
+        true
+    }
 }
@@ -35,6 +35,23 @@ pub(super) struct SearchState {
     pub wrap_search: bool,
     /// Optional search range (for search in selection)
     pub search_range: Option<Range<usize>>,
+    /// Whether the query was matched as a regular expression
+    pub use_regex: bool,
+}
+
+/// A project-wide search whose results are still streaming in
+#[derive(Debug)]
+pub(super) struct ProjectSearchState {
+    /// Identifies the search so results from a superseded one are dropped
+    pub id: u64,
+    /// The search query
+    pub query: String,
+    /// Title of the results popup
+    pub title: String,
+    /// Set to stop the background walk early
+    pub cancelled: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    /// Whether the results popup has been shown (it is not reopened once dismissed)
+    pub popup_shown: bool,
 }
 
 /// A bookmark in the editor (position in a specific buffer)
@@ -46,15 +63,53 @@ pub(super) struct Bookmark {
     pub position: usize,
 }
 
+/// The most recent single-cursor paste, so yank-pop can replace it
+#[derive(Debug, Clone)]
+pub(super) struct PasteRecord {
+    /// Buffer the text was pasted into
+    pub buffer_id: BufferId,
+    /// Byte range the pasted text occupies
+    pub range: Range<usize>,
+    /// The text as inserted into the buffer
+    pub text: String,
+    /// Kill ring entry the text came from (0 = most recent)
+    pub ring_index: usize,
+}
+
+/// The cursor context a plugin text object query was made in, so that a late
+/// or stale match is ignored
+#[derive(Debug, Clone)]
+pub(super) struct PendingTextObject {
+    /// Buffer the query was made in
+    pub buffer_id: BufferId,
+    /// Buffer version when the query was made
+    pub version: u64,
+    /// Operation the query was made for
+    pub operation: fresh_core::api::TextObjectOperation,
+    /// Primary cursor position and anchor the match applies to
+    pub cursor: (usize, Option<usize>),
+    /// Selection being expanded by expand-selection (empty at the cursor when
+    /// there is none); a match must strictly contain it
+    pub selection: Range<usize>,
+    /// Length of the smallest range expand-selection applied so far
+    pub expanded_len: Option<usize>,
+}
+
 /// State for interactive replace (query-replace)
 #[derive(Debug, Clone)]
 pub(super) struct InteractiveReplaceState {
     /// The search pattern
     pub search: String,
+    /// The search pattern compiled with the search options, built once per query
+    pub regex: regex::bytes::Regex,
+    /// Whether `$1`-style capture references in the replacement are expanded
+    pub use_regex: bool,
     /// The replacement text
     pub replacement: String,
     /// Current match position (byte offset of the match we're at)
     pub current_match_pos: usize,
+    /// Length in bytes of the current match
+    pub current_match_len: usize,
     /// Starting position (to detect when we've wrapped around full circle)
     pub start_pos: usize,
     /// Whether we've wrapped around to the beginning
@@ -1,9 +1,83 @@
 //! Undo and redo action handlers.
 
 use super::Editor;
+use crate::model::event::{BufferId, EventLog};
+use crate::services::undo_history::UndoHistoryStore;
 use rust_i18n::t;
 
 impl Editor {
+    /// Create an event log bounded by the configured undo history limits.
+    pub(crate) fn new_event_log(&self) -> EventLog {
+        EventLog::with_limits(
+            self.config.editor.undo_history_max_entries,
+            self.config.editor.undo_history_max_bytes as usize,
+        )
+    }
+
+    /// Re-apply the configured undo history limits to every open buffer.
+    pub(crate) fn apply_undo_history_limits(&mut self) {
+        let max_entries = self.config.editor.undo_history_max_entries;
+        let max_bytes = self.config.editor.undo_history_max_bytes as usize;
+        for event_log in self.event_logs.values_mut() {
+            event_log.set_limits(max_entries, max_bytes);
+        }
+    }
+
+    fn undo_history_store(&self) -> UndoHistoryStore {
+        UndoHistoryStore::new(self.dir_context.undo_history_dir())
+    }
+
+    /// Write the undo history of an unmodified file buffer to disk when
+    /// persistent undo is enabled.
+    pub(crate) fn persist_undo_history(&self, buffer_id: BufferId) {
+        if !self.config.editor.persistent_undo {
+            return;
+        }
+        let (Some(state), Some(event_log)) = (
+            self.buffers.get(&buffer_id),
+            self.event_logs.get(&buffer_id),
+        ) else {
+            return;
+        };
+        if state.buffer.is_modified() || state.buffer.is_large_file() {
+            return;
+        }
+        let (Some(path), Some(content)) = (state.buffer.file_path(), state.buffer.get_all_text())
+        else {
+            return;
+        };
+        if let Err(e) = self.undo_history_store().save(path, &content, event_log) {
+            tracing::warn!("Failed to save undo history for {}: {}", path.display(), e);
+        }
+    }
+
+    /// Load the undo history kept for a freshly opened file buffer, if its
+    /// content still matches.
+    pub(crate) fn restore_undo_history(&mut self, buffer_id: BufferId) {
+        if !self.config.editor.persistent_undo {
+            return;
+        }
+        let store = self.undo_history_store();
+        let (Some(state), Some(event_log)) = (
+            self.buffers.get(&buffer_id),
+            self.event_logs.get_mut(&buffer_id),
+        ) else {
+            return;
+        };
+        if state.buffer.is_large_file() {
+            return;
+        }
+        let (Some(path), Some(content)) = (state.buffer.file_path(), state.buffer.get_all_text())
+        else {
+            return;
+        };
+        match store.load(path, &content, event_log) {
+            Ok(true) => tracing::debug!("Restored undo history for {}", path.display()),
+            Ok(false) => {}
+            Err(e) => tracing::warn!("Failed to load undo history for {}: {}", path.display(), e),
+        }
+    }
+
     /// Handle Undo action - revert the last edit operation.
     pub fn handle_undo(&mut self) {
         if self.is_editing_disabled() {
@@ -33,6 +107,12 @@ impl Editor {
 
         // Update modified status based on event log position
         self.update_modified_from_event_log();
+
+        // Undo stops short of the original content once old history was dropped
+        let event_log = self.active_event_log();
+        if !event_log.can_undo() && event_log.dropped_entries() > 0 {
+            self.set_status_message(t!("status.undo_history_truncated").to_string());
+        }
     }
 
     /// Handle Redo action - reapply an undone edit operation.
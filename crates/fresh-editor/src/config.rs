@@ -284,6 +284,35 @@ impl JsonSchema for LineEndingOption {
     }
 }
 
+/// Starting directory of the Save As dialog for buffers without a file
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SaveAsDirectory {
+    /// Directory of the file that was active before the new buffer, falling
+    /// back to the workspace root
+    #[default]
+    ActiveBuffer,
+    /// The workspace root
+    WorkspaceRoot,
+    /// The directory a file was last saved to or opened from
+    LastUsed,
+}
+
+impl JsonSchema for SaveAsDirectory {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("SaveAsDirectory")
+    }
+
+    fn json_schema(_gen: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "Starting directory of the Save As dialog for new buffers",
+            "type": "string",
+            "enum": ["active_buffer", "workspace_root", "last_used"],
+            "default": "active_buffer"
+        })
+    }
+}
+
 impl PartialEq<KeybindingMapName> for str {
     fn eq(&self, other: &KeybindingMapName) -> bool {
         self == other.0
@@ -367,6 +396,10 @@ pub struct Config {
     #[serde(default)]
     pub warnings: WarningsConfig,
 
+    /// Status bar segments and their order
+    #[serde(default)]
+    pub status_bar: StatusBarConfig,
+
     /// Plugin configurations by plugin name
     /// Plugins are auto-discovered from the plugins directory.
     /// Use this to enable/disable specific plugins.
@@ -396,10 +429,58 @@ pub struct EditorConfig {
     #[serde(default = "default_tab_size")]
     pub tab_size: usize,
 
+    /// Detect the indentation width (e.g. 2 or 4 spaces) of space-indented files
+    /// when they are opened, and use it as the tab size for that buffer
+    #[serde(default = "default_true")]
+    pub detect_indent_width: bool,
+
     /// Automatically indent new lines based on the previous line
     #[serde(default = "default_true")]
     pub auto_indent: bool,
 
+    /// Insert the matching closing bracket or quote when typing an opening one,
+    /// type over a closing character that is already next to the cursor, and
+    /// delete an empty pair with a single backspace.
+    /// Brackets and quotes typed inside strings and comments are not closed.
+    #[serde(default = "default_true")]
+    pub auto_close: bool,
+
+    /// Backspace in leading whitespace removes a whole indent level (back to the
+    /// previous tab stop) instead of a single space. Only applies when indenting with spaces.
+    #[serde(default = "default_false")]
+    pub smart_backspace: bool,
+
+    /// Reindent multi-line clipboard pastes to the indentation at the cursor,
+    /// keeping the relative indentation of the pasted lines.
+    #[serde(default = "default_false")]
+    pub reindent_on_paste: bool,
+
+    /// Also copy to the terminal's clipboard with OSC 52 escape sequences, which
+    /// works over SSH and inside tmux/screen. Skipped in terminals known not to
+    /// support it and for copies too large to send.
+    #[serde(default = "default_true")]
+    pub osc52_clipboard: bool,
+
+    /// Delete to Line End at the end of a line removes the line ending, joining
+    /// the next line onto it (like emacs kill-line). When off it does nothing there.
+    #[serde(default = "default_true")]
+    pub kill_line_joins_lines: bool,
+
+    /// Delete to Line End and Delete to Line Start put the deleted text on the
+    /// clipboard and kill ring, like a cut. Always on in the emacs and macos
+    /// keymaps, where these are the kill commands.
+    #[serde(default = "default_false")]
+    pub kill_line_to_clipboard: bool,
+
+    /// When saving, add a line ending at the end of the file if it doesn't
+    /// already end with one. Empty files are left empty.
+    #[serde(default = "default_false")]
+    pub insert_final_newline: bool,
+
+    /// When saving, remove trailing spaces and tabs from every line.
+    #[serde(default = "default_false")]
+    pub trim_trailing_whitespace: bool,
+
     /// Show line numbers in the gutter (default for new buffers)
     #[serde(default = "default_true")]
     pub line_numbers: bool,
@@ -420,6 +501,68 @@ pub struct EditorConfig {
     #[serde(default = "default_true")]
     pub line_wrap: bool,
 
+    /// Draw vertical guide lines at each indentation level
+    #[serde(default = "default_false")]
+    pub show_indent_guides: bool,
+
+    /// Show control characters in caret notation (`^M`) and invalid UTF-8
+    /// bytes as `�`. When off, both are shown as hex codes (`<0D>`)
+    #[serde(default = "default_true")]
+    pub caret_control_chars: bool,
+
+    /// Draw spaces as `·` and tabs as `→`. The buffer content is not changed
+    #[serde(default = "default_false")]
+    pub render_whitespace: bool,
+
+    /// Draw spaces and tabs at the end of lines as `·` and `→`, even when
+    /// `render_whitespace` is off
+    #[serde(default = "default_false")]
+    pub render_trailing_whitespace: bool,
+
+    /// Columns at which to draw vertical rulers, e.g. `[80, 120]`. A ruler at
+    /// column 80 is drawn just after the 80th character of a line
+    #[serde(default)]
+    pub rulers: Vec<usize>,
+
+    /// When aligning lines on a delimiter, collapse the whitespace on either
+    /// side of it to a single space
+    #[serde(default = "default_true")]
+    pub align_delimiter_single_space: bool,
+
+    /// Pin the lines that open the scopes around the top of the view
+    /// (such as the enclosing function signature) while scrolling
+    #[serde(default = "default_false")]
+    pub sticky_scroll: bool,
+
+    /// Animate large scroll jumps (page up/down, go to line) over a few frames
+    /// instead of moving the view instantly
+    #[serde(default = "default_false")]
+    pub smooth_scroll: bool,
+
+    /// How long a smooth scroll animation takes, in milliseconds.
+    /// Default: 120ms
+    #[serde(default = "default_smooth_scroll_duration")]
+    pub smooth_scroll_duration_ms: u64,
+
+    /// Highlight other occurrences of the word under the cursor
+    #[serde(default = "default_true")]
+    pub highlight_word_occurrences: bool,
+
+    /// Show the current search match and the total match count
+    /// ("Match 2 of 5") in the status bar while a search is active
+    #[serde(default = "default_true")]
+    pub show_search_match_count: bool,
+
+    /// Search case-sensitively whenever the query contains an uppercase
+    /// letter, even with the Case Sensitive search option off
+    #[serde(default = "default_true")]
+    pub search_smart_case: bool,
+
+    /// Center the cursor line in the viewport when jumping to a search match,
+    /// definition, or diagnostic, instead of scrolling just enough to show it
+    #[serde(default = "default_false")]
+    pub center_on_jump: bool,
+
     /// Maximum time in milliseconds for syntax highlighting per frame
     #[serde(default = "default_highlight_timeout")]
     pub highlight_timeout_ms: u64,
@@ -428,6 +571,27 @@ pub struct EditorConfig {
     #[serde(default = "default_snapshot_interval")]
     pub snapshot_interval: usize,
 
+    /// Maximum number of undo history entries kept per buffer.
+    /// When the limit is exceeded the oldest entries are dropped.
+    /// Set to 0 for no limit.
+    /// Default: 100000
+    #[serde(default = "default_undo_history_max_entries")]
+    pub undo_history_max_entries: usize,
+
+    /// Approximate memory bound in bytes for the undo history of each buffer.
+    /// When the bound is exceeded the oldest entries are dropped.
+    /// Set to 0 for no limit.
+    /// Default: 67108864 (64 MiB)
+    #[serde(default = "default_undo_history_max_bytes")]
+    pub undo_history_max_bytes: u64,
+
+    /// Keep each file's undo history across editor restarts.
+    /// The history is written when the file is saved or closed and is only
+    /// restored if the file still has the content it was saved with.
+    /// Default: false
+    #[serde(default = "default_false")]
+    pub persistent_undo: bool,
+
     /// File size threshold in bytes for "large file" behavior
     /// Files larger than this will:
     /// - Skip LSP features
@@ -453,6 +617,12 @@ pub struct EditorConfig {
     #[serde(default = "default_false")]
     pub enable_semantic_tokens_full: bool,
 
+    /// Show go-to-definition and find-references results in a peek popup
+    /// with the surrounding lines instead of jumping to them.
+    /// Enter jumps to the selected line, Escape stays where you are.
+    #[serde(default = "default_false")]
+    pub peek_lsp_locations: bool,
+
     /// Whether to enable file recovery (Emacs-style auto-save)
     /// When enabled, buffers are periodically saved to recovery files
     /// so they can be recovered if the editor crashes.
@@ -466,6 +636,13 @@ pub struct EditorConfig {
     #[serde(default = "default_auto_save_interval")]
     pub auto_save_interval_secs: u32,
 
+    /// Whether to save the session (open files, cursors, split layout) when quitting.
+    /// The session is restored the next time Fresh starts in the same directory.
+    /// The Save Session and Restore Session commands work regardless of this setting.
+    /// Default: true
+    #[serde(default = "default_true")]
+    pub save_session_on_quit: bool,
+
     /// Number of bytes to look back/forward from the viewport for syntax highlighting context.
     /// Larger values improve accuracy for multi-line constructs (strings, comments, nested blocks)
     /// but may slow down highlighting for very large files.
@@ -491,6 +668,19 @@ pub struct EditorConfig {
     #[serde(default = "default_double_click_time")]
     pub double_click_time_ms: u64,
 
+    /// Number of lines scrolled by each mouse wheel step, in the editor as well
+    /// as in the file explorer and popups. The cursor does not move.
+    /// Default: 3
+    #[serde(default = "default_mouse_scroll_lines")]
+    pub mouse_scroll_lines: usize,
+
+    /// Time in milliseconds to wait for the next key of a multi-key chord (e.g. Ctrl+X Ctrl+S).
+    /// While a chord is pending the status bar shows the keys pressed so far and the time left.
+    /// Set to 0 to wait indefinitely.
+    /// Default: 0
+    #[serde(default = "default_chord_timeout")]
+    pub chord_timeout_ms: u64,
+
     /// Poll interval in milliseconds for auto-reverting open buffers.
     /// When auto-revert is enabled, file modification times are checked at this interval.
     /// Lower values detect external changes faster but use more CPU.
@@ -498,6 +688,12 @@ pub struct EditorConfig {
     #[serde(default = "default_auto_revert_poll_interval")]
     pub auto_revert_poll_interval_ms: u64,
 
+    /// Flag open buffers whose file was deleted outside the editor.
+    /// The content is kept, and saving the buffer recreates the file.
+    /// Checked at the auto-revert poll interval.
+    #[serde(default = "default_true")]
+    pub detect_deleted_files: bool,
+
     /// Poll interval in milliseconds for refreshing expanded directories in the file explorer.
     /// Directory modification times are checked at this interval to detect new/deleted files.
     /// Lower values detect changes faster but use more CPU.
@@ -512,6 +708,13 @@ pub struct EditorConfig {
     #[serde(default)]
     pub default_line_ending: LineEndingOption,
 
+    /// Directory the Save As dialog starts in for buffers that have no file yet.
+    /// Options: "active_buffer" (directory of the file active before the new buffer),
+    /// "workspace_root", "last_used" (the directory last saved to or opened from)
+    /// Default: "active_buffer"
+    #[serde(default)]
+    pub save_as_default_dir: SaveAsDirectory,
+
     /// Cursor style for the terminal cursor.
     /// Options: blinking_block, steady_block, blinking_bar, steady_bar, blinking_underline, steady_underline
     /// Default: blinking_block
@@ -568,6 +771,13 @@ pub struct EditorConfig {
     #[serde(default = "default_true")]
     pub show_tab_bar: bool,
 
+    /// Whether the status bar shows the cursor's byte offset alongside the file size and line count.
+    /// In large files where the line count is unknown, the position is shown as a percentage.
+    /// Can be toggled at runtime via command palette.
+    /// Default: false
+    #[serde(default = "default_false")]
+    pub show_cursor_details: bool,
+
     /// Use the terminal's default background color instead of the theme's editor background.
     /// When enabled, the editor background inherits from the terminal emulator,
     /// allowing transparency or custom terminal backgrounds to show through.
@@ -609,6 +819,14 @@ fn default_snapshot_interval() -> usize {
     100
 }
 
+fn default_undo_history_max_entries() -> usize {
+    100_000
+}
+
+fn default_undo_history_max_bytes() -> u64 {
+    64 * 1024 * 1024 // 64 MiB per buffer
+}
+
 fn default_estimated_line_length() -> usize {
     80
 }
@@ -625,10 +843,22 @@ fn default_mouse_hover_delay() -> u64 {
     500 // 500ms delay before showing hover info
 }
 
+fn default_smooth_scroll_duration() -> u64 {
+    120 // 120ms animation for large scroll jumps
+}
+
 fn default_double_click_time() -> u64 {
     500 // 500ms window for detecting double-clicks
 }
 
+fn default_mouse_scroll_lines() -> usize {
+    3 // Lines per mouse wheel step
+}
+
+fn default_chord_timeout() -> u64 {
+    0 // Wait indefinitely for the rest of a chord
+}
+
 fn default_auto_revert_poll_interval() -> u64 {
     2000 // 2 seconds between file mtime checks
 }
@@ -641,27 +871,58 @@ impl Default for EditorConfig {
     fn default() -> Self {
         Self {
             tab_size: default_tab_size(),
+            detect_indent_width: true,
             auto_indent: true,
+            auto_close: true,
+            smart_backspace: false,
+            reindent_on_paste: false,
+            osc52_clipboard: true,
+            kill_line_joins_lines: true,
+            kill_line_to_clipboard: false,
+            insert_final_newline: false,
+            trim_trailing_whitespace: false,
             line_numbers: true,
             relative_line_numbers: false,
             scroll_offset: default_scroll_offset(),
             syntax_highlighting: true,
             line_wrap: true,
+            show_indent_guides: false,
+            caret_control_chars: true,
+            render_whitespace: false,
+            render_trailing_whitespace: false,
+            rulers: Vec::new(),
+            align_delimiter_single_space: true,
+            sticky_scroll: false,
+            smooth_scroll: false,
+            smooth_scroll_duration_ms: default_smooth_scroll_duration(),
+            highlight_word_occurrences: true,
+            show_search_match_count: true,
+            search_smart_case: true,
+            center_on_jump: false,
             highlight_timeout_ms: default_highlight_timeout(),
             snapshot_interval: default_snapshot_interval(),
+            undo_history_max_entries: default_undo_history_max_entries(),
+            undo_history_max_bytes: default_undo_history_max_bytes(),
+            persistent_undo: false,
             large_file_threshold_bytes: default_large_file_threshold(),
             estimated_line_length: default_estimated_line_length(),
             enable_inlay_hints: true,
             enable_semantic_tokens_full: false,
+            peek_lsp_locations: false,
             recovery_enabled: true,
             auto_save_interval_secs: default_auto_save_interval(),
+            save_session_on_quit: true,
             highlight_context_bytes: default_highlight_context_bytes(),
             mouse_hover_enabled: true,
             mouse_hover_delay_ms: default_mouse_hover_delay(),
             double_click_time_ms: default_double_click_time(),
+            mouse_scroll_lines: default_mouse_scroll_lines(),
+            chord_timeout_ms: default_chord_timeout(),
             auto_revert_poll_interval_ms: default_auto_revert_poll_interval(),
+            detect_deleted_files: true,
             file_tree_poll_interval_ms: default_file_tree_poll_interval(),
             default_line_ending: LineEndingOption::default(),
+            save_as_default_dir: SaveAsDirectory::default(),
             cursor_style: CursorStyle::default(),
             keyboard_disambiguate_escape_codes: true,
             keyboard_report_event_types: false,
@@ -670,6 +931,7 @@ impl Default for EditorConfig {
             quick_suggestions: true,
             show_menu_bar: true,
             show_tab_bar: true,
+            show_cursor_details: false,
             use_terminal_bg: false,
         }
     }
@@ -737,6 +999,98 @@ impl Default for WarningsConfig {
     }
 }
 
+/// Status bar layout
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct StatusBarConfig {
+    /// Segments shown on the left, in order, separated by `|`.
+    /// The left side is truncated when the status bar is too narrow.
+    #[serde(default = "default_status_bar_left")]
+    pub left: Vec<StatusBarSegment>,
+
+    /// Segments shown on the right, in order.
+    /// Remove a segment from both lists to hide it.
+    #[serde(default = "default_status_bar_right")]
+    pub right: Vec<StatusBarSegment>,
+}
+
+impl Default for StatusBarConfig {
+    fn default() -> Self {
+        Self {
+            left: default_status_bar_left(),
+            right: default_status_bar_right(),
+        }
+    }
+}
+
+fn default_status_bar_left() -> Vec<StatusBarSegment> {
+    vec![
+        StatusBarSegment::Filename,
+        StatusBarSegment::Cursor,
+        StatusBarSegment::CursorDetails,
+        StatusBarSegment::Diagnostics,
+        StatusBarSegment::CursorCount,
+        StatusBarSegment::SearchMatch,
+        StatusBarSegment::Overwrite,
+        StatusBarSegment::Plugins,
+        StatusBarSegment::Chord,
+        StatusBarSegment::Messages,
+    ]
+}
+
+fn default_status_bar_right() -> Vec<StatusBarSegment> {
+    vec![
+        StatusBarSegment::Plugins,
+        StatusBarSegment::Indentation,
+        StatusBarSegment::LineEnding,
+        StatusBarSegment::Lsp,
+        StatusBarSegment::Warnings,
+        StatusBarSegment::Update,
+        StatusBarSegment::Palette,
+    ]
+}
+
+/// A built-in status bar segment
+///
+/// Segments with nothing to show (e.g. `search_match` outside a search) take no space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StatusBarSegment {
+    /// File name, followed by `[+]` when the buffer is modified
+    Filename,
+    /// Cursor line and column
+    Cursor,
+    /// Cursor byte offset, file size and line count (when enabled)
+    CursorDetails,
+    /// LSP error, warning and info counts
+    Diagnostics,
+    /// Number of cursors, when there is more than one
+    CursorCount,
+    /// Current search match and match count
+    SearchMatch,
+    /// Overwrite mode indicator
+    Overwrite,
+    /// Items added by plugins for this side of the status bar
+    Plugins,
+    /// Keys of a pending multi-key chord
+    Chord,
+    /// Editor and plugin status messages
+    Messages,
+    /// Indentation width detected when the file was opened
+    Indentation,
+    /// Line ending format (click to change it)
+    LineEnding,
+    /// LSP server status (click for details)
+    Lsp,
+    /// General warning count (click to show warnings)
+    Warnings,
+    /// Available update notice
+    Update,
+    /// Command palette shortcut
+    Palette,
+    /// Line, word and character counts of the selection, or of the whole buffer
+    WordCount,
+}
+
 // Re-export PluginConfig from fresh-core for shared type usage
 pub use fresh_core::config::PluginConfig;
 
@@ -918,6 +1272,16 @@ pub struct LanguageConfig {
     #[serde(default)]
     pub tab_size: Option<usize>,
 
+    /// Whether to wrap long lines when opening files of this language.
+    /// If not specified, falls back to the global editor.line_wrap setting.
+    #[serde(default)]
+    pub line_wrap: Option<bool>,
+
+    /// Whether to show line numbers when opening files of this language.
+    /// If not specified, falls back to the global editor.line_numbers setting.
+    #[serde(default)]
+    pub line_numbers: Option<bool>,
+
     /// The formatter for this language (used by format_buffer command)
     #[serde(default)]
     pub formatter: Option<FormatterConfig>,
@@ -953,6 +1317,12 @@ pub struct BufferConfig {
     /// Whether to show whitespace tab indicators (→)
     pub show_whitespace_tabs: bool,
 
+    /// Whether long lines are wrapped when the buffer is opened
+    pub line_wrap: bool,
+
+    /// Whether line numbers are shown when the buffer is opened
+    pub line_numbers: bool,
+
     /// Formatter command for this buffer
     pub formatter: Option<FormatterConfig>,
 
@@ -987,6 +1357,8 @@ impl BufferConfig {
             use_tabs: false, // Global default is spaces
             auto_indent: editor.auto_indent,
             show_whitespace_tabs: true, // Global default
+            line_wrap: editor.line_wrap,
+            line_numbers: editor.line_numbers,
             formatter: None,
             format_on_save: false,
             on_save: Vec::new(),
@@ -1011,6 +1383,14 @@ impl BufferConfig {
                 // Show whitespace tabs: language override
                 config.show_whitespace_tabs = lang_config.show_whitespace_tabs;
 
+                // Line wrap and line numbers: use language setting if specified, else global
+                if let Some(wrap) = lang_config.line_wrap {
+                    config.line_wrap = wrap;
+                }
+                if let Some(numbers) = lang_config.line_numbers {
+                    config.line_numbers = numbers;
+                }
+
                 // Formatter: from language config
                 config.formatter = lang_config.formatter.clone();
 
@@ -1161,6 +1541,7 @@ impl Default for Config {
             languages: Self::default_languages(),
             lsp: Self::default_lsp_config(),
             warnings: WarningsConfig::default(),
+            status_bar: StatusBarConfig::default(),
             plugins: HashMap::new(), // Populated when scanning for plugins
         }
     }
@@ -1376,6 +1757,34 @@ impl MenuConfig {
                         when: None,
                         checkbox: Some(context_keys::LINE_WRAP.to_string()),
                     },
+                    MenuItem::Action {
+                        label: t!("menu.view.indent_guides").to_string(),
+                        action: "toggle_indent_guides".to_string(),
+                        args: HashMap::new(),
+                        when: None,
+                        checkbox: Some(context_keys::INDENT_GUIDES.to_string()),
+                    },
+                    MenuItem::Action {
+                        label: t!("menu.view.render_whitespace").to_string(),
+                        action: "toggle_render_whitespace".to_string(),
+                        args: HashMap::new(),
+                        when: None,
+                        checkbox: Some(context_keys::RENDER_WHITESPACE.to_string()),
+                    },
+                    MenuItem::Action {
+                        label: t!("menu.view.render_trailing_whitespace").to_string(),
+                        action: "toggle_render_trailing_whitespace".to_string(),
+                        args: HashMap::new(),
+                        when: None,
+                        checkbox: Some(context_keys::RENDER_TRAILING_WHITESPACE.to_string()),
+                    },
+                    MenuItem::Action {
+                        label: t!("menu.view.sticky_scroll").to_string(),
+                        action: "toggle_sticky_scroll".to_string(),
+                        args: HashMap::new(),
+                        when: None,
+                        checkbox: Some(context_keys::STICKY_SCROLL.to_string()),
+                    },
                     MenuItem::Action {
                         label: t!("menu.view.mouse_support").to_string(),
                         action: "toggle_mouse_capture".to_string(),
@@ -1716,6 +2125,20 @@ impl MenuConfig {
                         when: Some(context_keys::LSP_AVAILABLE.to_string()),
                         checkbox: None,
                     },
+                    MenuItem::Action {
+                        label: t!("menu.lsp.format").to_string(),
+                        action: "lsp_format".to_string(),
+                        args: HashMap::new(),
+                        when: Some(context_keys::LSP_AVAILABLE.to_string()),
+                        checkbox: None,
+                    },
+                    MenuItem::Action {
+                        label: t!("menu.lsp.document_symbols").to_string(),
+                        action: "lsp_document_symbols".to_string(),
+                        args: HashMap::new(),
+                        when: Some(context_keys::LSP_AVAILABLE.to_string()),
+                        checkbox: None,
+                    },
                     MenuItem::Separator { separator: true },
                     MenuItem::Action {
                         label: t!("menu.lsp.toggle_inlay_hints").to_string(),
@@ -1950,6 +2373,8 @@ impl Config {
                 show_whitespace_tabs: true,
                 use_tabs: false,
                 tab_size: None,
+                line_wrap: None,
+                line_numbers: None,
                 formatter: Some(FormatterConfig {
                     command: "rustfmt".to_string(),
                     args: vec!["--edition".to_string(), "2021".to_string()],
@@ -1974,6 +2399,8 @@ impl Config {
                 show_whitespace_tabs: true,
                 use_tabs: false,
                 tab_size: None,
+                line_wrap: None,
+                line_numbers: None,
                 formatter: Some(FormatterConfig {
                     command: "prettier".to_string(),
                     args: vec!["--stdin-filepath".to_string(), "$FILE".to_string()],
@@ -1998,6 +2425,8 @@ impl Config {
                 show_whitespace_tabs: true,
                 use_tabs: false,
                 tab_size: None,
+                line_wrap: None,
+                line_numbers: None,
                 formatter: Some(FormatterConfig {
                     command: "prettier".to_string(),
                     args: vec!["--stdin-filepath".to_string(), "$FILE".to_string()],
@@ -2022,6 +2451,8 @@ impl Config {
                 show_whitespace_tabs: true,
                 use_tabs: false,
                 tab_size: None,
+                line_wrap: None,
+                line_numbers: None,
                 formatter: Some(FormatterConfig {
                     command: "ruff".to_string(),
                     args: vec![
@@ -2050,6 +2481,8 @@ impl Config {
                 show_whitespace_tabs: true,
                 use_tabs: false,
                 tab_size: None,
+                line_wrap: None,
+                line_numbers: None,
                 formatter: Some(FormatterConfig {
                     command: "clang-format".to_string(),
                     args: vec![],
@@ -2081,6 +2514,8 @@ impl Config {
                 show_whitespace_tabs: true,
                 use_tabs: false,
                 tab_size: None,
+                line_wrap: None,
+                line_numbers: None,
                 formatter: Some(FormatterConfig {
                     command: "clang-format".to_string(),
                     args: vec![],
@@ -2105,6 +2540,8 @@ impl Config {
                 show_whitespace_tabs: true,
                 use_tabs: false,
                 tab_size: None,
+                line_wrap: None,
+                line_numbers: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
@@ -2139,6 +2576,8 @@ impl Config {
                 show_whitespace_tabs: true,
                 use_tabs: false,
                 tab_size: None,
+                line_wrap: None,
+                line_numbers: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
@@ -2162,6 +2601,8 @@ impl Config {
                 show_whitespace_tabs: true,
                 use_tabs: true,    // Makefiles require tabs for recipes
                 tab_size: Some(8), // Makefiles traditionally use 8-space tabs
+                line_wrap: None,
+                line_numbers: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
@@ -2181,6 +2622,8 @@ impl Config {
                 show_whitespace_tabs: true,
                 use_tabs: false,
                 tab_size: None,
+                line_wrap: None,
+                line_numbers: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
@@ -2200,6 +2643,8 @@ impl Config {
                 show_whitespace_tabs: true,
                 use_tabs: false,
                 tab_size: None,
+                line_wrap: None,
+                line_numbers: None,
                 formatter: Some(FormatterConfig {
                     command: "prettier".to_string(),
                     args: vec!["--stdin-filepath".to_string(), "$FILE".to_string()],
@@ -2224,6 +2669,8 @@ impl Config {
                 show_whitespace_tabs: true,
                 use_tabs: false,
                 tab_size: None,
+                line_wrap: None,
+                line_numbers: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
@@ -2243,6 +2690,8 @@ impl Config {
                 show_whitespace_tabs: true,
                 use_tabs: false,
                 tab_size: None,
+                line_wrap: None,
+                line_numbers: None,
                 formatter: Some(FormatterConfig {
                     command: "prettier".to_string(),
                     args: vec!["--stdin-filepath".to_string(), "$FILE".to_string()],
@@ -2267,6 +2716,8 @@ impl Config {
                 show_whitespace_tabs: true,
                 use_tabs: false,
                 tab_size: None,
+                line_wrap: None,
+                line_numbers: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
@@ -2287,6 +2738,8 @@ impl Config {
                 show_whitespace_tabs: false,
                 use_tabs: true,    // Go convention is to use tabs
                 tab_size: Some(8), // Go convention is 8-space tab width
+                line_wrap: None,
+                line_numbers: None,
                 formatter: Some(FormatterConfig {
                     command: "gofmt".to_string(),
                     args: vec![],
@@ -2311,6 +2764,8 @@ impl Config {
                 show_whitespace_tabs: false,
                 use_tabs: true,
                 tab_size: Some(8),
+                line_wrap: None,
+                line_numbers: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
@@ -2803,6 +3258,8 @@ mod tests {
                 show_whitespace_tabs: false, // Go hides tab indicators
                 use_tabs: true,              // Go uses tabs
                 tab_size: Some(8),           // Go uses 8-space tabs
+                line_wrap: None,
+                line_numbers: None,
                 formatter: Some(FormatterConfig {
                     command: "gofmt".to_string(),
                     args: vec![],
@@ -2834,6 +3291,25 @@ mod tests {
         assert!(!buffer_config.use_tabs);
     }
 
+    #[test]
+    fn test_buffer_config_line_wrap_and_numbers_overrides() {
+        let mut config = Config::default();
+        config.editor.line_wrap = false;
+        config.editor.line_numbers = true;
+        let markdown = config.languages.get_mut("markdown").unwrap();
+        markdown.line_wrap = Some(true);
+        markdown.line_numbers = Some(false);
+
+        let md_config = BufferConfig::resolve(&config, Some("markdown"));
+        assert!(md_config.line_wrap);
+        assert!(!md_config.line_numbers);
+
+        // Languages without overrides fall back to the editor settings
+        let rust_config = BufferConfig::resolve(&config, Some("rust"));
+        assert!(!rust_config.line_wrap);
+        assert!(rust_config.line_numbers);
+    }
+
     #[test]
     fn test_buffer_config_indent_string() {
         let config = Config::default();
@@ -2849,6 +3325,8 @@ mod tests {
             LanguageConfig {
                 use_tabs: true,
                 tab_size: Some(8),
+                line_wrap: None,
+                line_numbers: None,
                 ..Default::default()
             },
         );
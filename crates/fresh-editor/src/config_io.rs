@@ -182,7 +182,8 @@ pub enum ConfigLayer {
     System,
     /// User-global settings (~/.config/fresh/config.json)
     User,
-    /// Project-local settings ($PROJECT_ROOT/.fresh/config.json)
+    /// Project-local settings ($PROJECT_ROOT/.fresh/config.json, then the
+    /// nearest .fresh.toml)
     Project,
     /// Runtime/volatile session state (highest precedence)
     Session,
@@ -233,6 +234,10 @@ impl ConfigResolver {
             tracing::debug!("Loaded project config layer");
             merged.merge_from(&project_partial);
         }
+        if let Some(toml_partial) = self.project_toml_layer_or_skip() {
+            tracing::debug!("Loaded .fresh.toml project config");
+            merged.merge_from(&toml_partial);
+        }
 
         // Merge in User Platform layer (e.g., config_linux.json)
         if let Some(platform_partial) = self.load_user_platform_layer()? {
@@ -271,6 +276,31 @@ impl ConfigResolver {
         new_path
     }
 
+    /// Find the nearest `.fresh.toml`, searching the working directory and
+    /// then its ancestors up to the project root.
+    ///
+    /// The project root is the nearest directory under version control; when
+    /// there is none, only the working directory itself is searched.
+    pub fn project_toml_path(&self) -> Option<PathBuf> {
+        const VCS_DIRS: [&str; 4] = [".git", ".hg", ".jj", ".svn"];
+        let root = self
+            .working_dir
+            .ancestors()
+            .find(|dir| VCS_DIRS.iter().any(|vcs| dir.join(vcs).exists()))
+            .unwrap_or(&self.working_dir);
+
+        for dir in self.working_dir.ancestors() {
+            let path = dir.join(".fresh.toml");
+            if path.is_file() {
+                return Some(path);
+            }
+            if dir == root {
+                break;
+            }
+        }
+        None
+    }
+
     /// Get the preferred path for writing project config (new location).
     pub fn project_config_write_path(&self) -> PathBuf {
         self.working_dir.join(".fresh").join("config.json")
@@ -318,6 +348,32 @@ impl ConfigResolver {
         self.load_layer_from_path(&self.project_config_path())
     }
 
+    /// Load the `.fresh.toml` project file, if one is found.
+    ///
+    /// It sits just below `.fresh/config.json` (which the settings UI writes
+    /// to) within the Project layer.
+    pub fn load_project_toml_layer(&self) -> Result<Option<PartialConfig>, ConfigError> {
+        let Some(path) = self.project_toml_path() else {
+            return Ok(None);
+        };
+
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| ConfigError::IoError(format!("{}: {}", path.display(), e)))?;
+        let value: Value = toml::from_str(&content)
+            .map_err(|e| ConfigError::ParseError(format!("{}: {}", path.display(), e)))?;
+
+        Self::partial_from_value(value, &path).map(Some)
+    }
+
+    /// Load the `.fresh.toml` layer, skipping it (with a warning) if it can't
+    /// be read, so a broken project file doesn't discard the other layers.
+    fn project_toml_layer_or_skip(&self) -> Option<PartialConfig> {
+        self.load_project_toml_layer().unwrap_or_else(|e| {
+            tracing::warn!("Skipping invalid .fresh.toml: {}", e);
+            None
+        })
+    }
+
     /// Load the session layer from disk.
     pub fn load_session_layer(&self) -> Result<Option<PartialConfig>, ConfigError> {
         self.load_layer_from_path(&self.session_config_path())
@@ -336,14 +392,17 @@ impl ConfigResolver {
         let value: Value = serde_json::from_str(&content)
             .map_err(|e| ConfigError::ParseError(format!("{}: {}", path.display(), e)))?;
 
+        Self::partial_from_value(value, path).map(Some)
+    }
+
+    /// Migrate a raw layer value read from `path` and deserialize it.
+    fn partial_from_value(value: Value, path: &Path) -> Result<PartialConfig, ConfigError> {
         // Apply migrations
         let migrated = migrate_config(value)?;
 
         // Now deserialize to PartialConfig
-        let partial: PartialConfig = serde_json::from_value(migrated)
-            .map_err(|e| ConfigError::ParseError(format!("{}: {}", path.display(), e)))?;
-
-        Ok(Some(partial))
+        serde_json::from_value(migrated)
+            .map_err(|e| ConfigError::ParseError(format!("{}: {}", path.display(), e)))
     }
 
     /// Save a config to a specific layer, writing only the delta from parent layers.
@@ -521,15 +580,18 @@ impl ConfigResolver {
         let mut merged = PartialConfig::default();
 
         // Merge from highest precedence (just below target) to lowest
-        // Session layer: parent includes Project + UserPlatform + User
-        // Project layer: parent includes UserPlatform + User
+        // Session layer: parent includes Project + .fresh.toml + UserPlatform + User
+        // Project layer: parent includes .fresh.toml + UserPlatform + User
         // User layer: parent is empty (system defaults applied during resolve)
 
         if layer == ConfigLayer::Session {
-            // Session's parent is Project + UserPlatform + User
+            // Session's parent is Project + .fresh.toml + UserPlatform + User
             if let Some(project) = self.load_project_layer()? {
                 merged = project;
             }
+            if let Some(project_toml) = self.project_toml_layer_or_skip() {
+                merged.merge_from(&project_toml);
+            }
             if let Some(platform) = self.load_user_platform_layer()? {
                 merged.merge_from(&platform);
             }
@@ -537,9 +599,13 @@ impl ConfigResolver {
                 merged.merge_from(&user);
             }
         } else if layer == ConfigLayer::Project {
-            // Project's parent is UserPlatform + User
+            // .fresh/config.json's parent is .fresh.toml + UserPlatform + User,
+            // so saving from the settings UI doesn't copy .fresh.toml values
+            if let Some(project_toml) = self.project_toml_layer_or_skip() {
+                merged = project_toml;
+            }
             if let Some(platform) = self.load_user_platform_layer()? {
-                merged = platform;
+                merged.merge_from(&platform);
             }
             if let Some(user) = self.load_user_layer()? {
                 merged.merge_from(&user);
@@ -577,6 +643,13 @@ impl ConfigResolver {
             });
         }
 
+        if let Some(project_toml) = self.project_toml_layer_or_skip() {
+            let json = serde_json::to_value(&project_toml).unwrap_or_default();
+            collect_paths(&json, "", &mut |path| {
+                sources.entry(path).or_insert(ConfigLayer::Project);
+            });
+        }
+
         if let Some(user) = self.load_user_layer()? {
             let json = serde_json::to_value(&user).unwrap_or_default();
             collect_paths(&json, "", &mut |path| {
@@ -864,6 +937,16 @@ impl DirectoryContext {
         self.data_dir.join("sessions")
     }
 
+    /// Get the directory for undo histories kept across sessions
+    pub fn undo_history_dir(&self) -> std::path::PathBuf {
+        self.data_dir.join("undo")
+    }
+
+    /// Get the directory holding each plugin's persistent storage
+    pub fn plugin_storage_dir(&self) -> std::path::PathBuf {
+        self.data_dir.join("plugin-storage")
+    }
+
     /// Get the history file path for a specific prompt type
     /// This is the generic method used by prompt_histories HashMap.
     /// history_name can be: "search", "replace", "goto_line", "plugin:custom_name", etc.
@@ -1114,6 +1197,92 @@ mod tests {
         drop(temp);
     }
 
+    #[test]
+    fn project_toml_overrides_user() {
+        let (temp, resolver) = create_test_resolver();
+
+        // User config with tab_size=2
+        let user_config_path = resolver.user_config_path();
+        std::fs::create_dir_all(user_config_path.parent().unwrap()).unwrap();
+        std::fs::write(&user_config_path, r#"{"editor": {"tab_size": 2}}"#).unwrap();
+
+        // .fresh.toml at the repository root, a parent of the working directory
+        std::fs::create_dir(temp.path().join(".git")).unwrap();
+        std::fs::write(
+            temp.path().join(".fresh.toml"),
+            "[editor]\ntab_size = 8\nline_numbers = false\n",
+        )
+        .unwrap();
+
+        let config = resolver.resolve().unwrap();
+        assert_eq!(config.editor.tab_size, 8);
+        assert!(!config.editor.line_numbers);
+
+        // The settings UI shows these values as project overrides
+        let sources = resolver.get_layer_sources().unwrap();
+        assert_eq!(sources.get("/editor/tab_size"), Some(&ConfigLayer::Project));
+        drop(temp);
+    }
+
+    #[test]
+    fn project_json_overrides_project_toml() {
+        let (temp, resolver) = create_test_resolver();
+
+        std::fs::write(
+            temp.path().join("project").join(".fresh.toml"),
+            "[editor]\ntab_size = 8\n",
+        )
+        .unwrap();
+        let project_config_path = resolver.project_config_path();
+        std::fs::create_dir_all(project_config_path.parent().unwrap()).unwrap();
+        std::fs::write(&project_config_path, r#"{"editor": {"tab_size": 3}}"#).unwrap();
+
+        let config = resolver.resolve().unwrap();
+        assert_eq!(config.editor.tab_size, 3);
+        drop(temp);
+    }
+
+    #[test]
+    fn project_toml_search_stops_at_project_root() {
+        let (temp, resolver) = create_test_resolver();
+
+        // Outside a repository only the working directory is searched
+        std::fs::write(temp.path().join(".fresh.toml"), "[editor]\ntab_size = 8\n").unwrap();
+        assert_eq!(resolver.project_toml_path(), None);
+
+        // Inside one, the search goes up to the repository root but not past it
+        std::fs::create_dir(temp.path().join(".git")).unwrap();
+        assert_eq!(
+            resolver.project_toml_path(),
+            Some(temp.path().join(".fresh.toml"))
+        );
+        std::fs::create_dir(temp.path().join("project").join(".git")).unwrap();
+        assert_eq!(resolver.project_toml_path(), None);
+        drop(temp);
+    }
+
+    #[test]
+    fn invalid_project_toml_is_skipped() {
+        let (temp, resolver) = create_test_resolver();
+
+        let user_config_path = resolver.user_config_path();
+        std::fs::create_dir_all(user_config_path.parent().unwrap()).unwrap();
+        std::fs::write(&user_config_path, r#"{"editor": {"tab_size": 2}}"#).unwrap();
+        std::fs::write(
+            temp.path().join("project").join(".fresh.toml"),
+            "[editor\ntab_size = ",
+        )
+        .unwrap();
+
+        assert!(matches!(
+            resolver.load_project_toml_layer(),
+            Err(ConfigError::ParseError(_))
+        ));
+        // The other layers still apply
+        assert_eq!(resolver.resolve().unwrap().editor.tab_size, 2);
+        drop(temp);
+    }
+
     #[test]
     fn migration_adds_version() {
         let input = serde_json::json!({
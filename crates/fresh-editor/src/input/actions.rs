@@ -2,14 +2,16 @@
 
 use crate::input::keybindings::Action;
 use crate::model::buffer::{Buffer, LineEnding};
-use crate::model::cursor::{Position2D, SelectionMode};
+use crate::model::cursor::{Cursor, Position2D, SelectionMode};
 use crate::model::event::{CursorId, Event};
 use crate::primitives::display_width::{byte_offset_at_visual_column, str_width};
 use crate::primitives::word_navigation::{
-    find_word_end, find_word_start, find_word_start_left, find_word_start_right,
+    find_subword_start_left, find_subword_start_right, find_word_end, find_word_start,
+    find_word_start_left, find_word_start_right,
 };
 use crate::state::EditorState;
 use std::ops::Range;
+use std::path::Path;
 
 /// Direction for block selection movement
 #[derive(Debug, Clone, Copy)]
@@ -20,27 +22,72 @@ enum BlockDirection {
     Down,
 }
 
-/// Convert byte offset to 2D position (line, column)
+/// Convert byte offset to 2D position (line, visual column)
 fn byte_to_2d(buffer: &Buffer, byte_pos: usize) -> Position2D {
     let line = buffer.get_line_number(byte_pos);
     let line_start = buffer.line_start_offset(line).unwrap_or(0);
-    let column = byte_pos.saturating_sub(line_start);
+    let line_content = buffer.get_line(line).unwrap_or_default();
+    let byte_column = byte_pos.saturating_sub(line_start).min(line_content.len());
+    let column = str_width(&String::from_utf8_lossy(&line_content[..byte_column]));
     Position2D { line, column }
 }
 
-/// Convert 2D position to byte offset
+/// Convert 2D position (line, visual column) to byte offset, clamped to the
+/// end of the line's content
 fn pos_2d_to_byte(buffer: &Buffer, pos: Position2D) -> usize {
     let line_start = buffer.line_start_offset(pos.line).unwrap_or(0);
-    // Get line content to check bounds
     let line_content = buffer.get_line(pos.line).unwrap_or_default();
-    // Clamp column to line length (excluding newline)
-    let line_len = if line_content.last() == Some(&b'\n') {
-        line_content.len().saturating_sub(1)
+    position_at_visual_column(
+        line_start,
+        &String::from_utf8_lossy(&line_content),
+        pos.column,
+    )
+}
+
+/// Rectangle covered by a cursor's block selection, as
+/// (first line, first visual column, last line, last visual column)
+///
+/// The cursor's edge is its goal column, as in `goal_visual_column`, so the
+/// block keeps its width while the cursor passes through shorter lines.
+pub(crate) fn block_selection_rect(
+    buffer: &Buffer,
+    cursor: &Cursor,
+) -> Option<(usize, usize, usize, usize)> {
+    if cursor.selection_mode != SelectionMode::Block {
+        return None;
+    }
+    let anchor = cursor.block_anchor?;
+    let current = byte_to_2d(buffer, cursor.position);
+    let column = if cursor.sticky_column > 0 {
+        cursor.sticky_column
     } else {
-        line_content.len()
+        current.column
     };
-    let clamped_col = pos.column.min(line_len);
-    line_start + clamped_col
+    Some((
+        anchor.line.min(current.line),
+        anchor.column.min(column),
+        anchor.line.max(current.line),
+        anchor.column.max(column),
+    ))
+}
+
+/// Byte range of `line` from visual column `start` up to visual column
+/// `end`, each clamped to the end of the line's content
+///
+/// Returns None past the end of the buffer.
+pub(crate) fn line_range_at_visual_columns(
+    buffer: &Buffer,
+    line: usize,
+    start: usize,
+    end: usize,
+) -> Option<Range<usize>> {
+    let line_start = buffer.line_start_offset(line)?;
+    let line_content = buffer.get_line(line)?;
+    let line_content = String::from_utf8_lossy(&line_content);
+    Some(
+        position_at_visual_column(line_start, &line_content, start)
+            ..position_at_visual_column(line_start, &line_content, end),
+    )
 }
 
 /// Calculate the visual column (display width) at the cursor position.
@@ -65,6 +112,25 @@ fn calculate_visual_column(
     }
 }
 
+/// The visual column a vertical move should aim for: the cursor's sticky
+/// column if set, otherwise its current visual column
+///
+/// Every vertical motion stores this as the new sticky column, so clamping on
+/// a short line doesn't lose the column for the moves that follow.
+fn goal_visual_column(buffer: &mut Buffer, cursor: &Cursor, estimated_line_length: usize) -> usize {
+    if cursor.sticky_column > 0 {
+        cursor.sticky_column
+    } else {
+        calculate_visual_column(buffer, cursor.position, estimated_line_length).0
+    }
+}
+
+/// Position on a line at `visual_column`, clamped to the end of its content
+fn position_at_visual_column(line_start: usize, line_content: &str, visual_column: usize) -> usize {
+    let line_text = &line_content[..content_len_without_line_ending(line_content)];
+    line_start + byte_offset_at_visual_column(line_text, visual_column)
+}
+
 /// Pattern for matching line ending characters (\r and \n)
 const LINE_ENDING_CHARS: &[char] = &['\r', '\n'];
 
@@ -197,10 +263,14 @@ fn add_move_cursor_event(
 }
 
 /// Handle block selection movement
+///
+/// Block columns are visual columns: up and down keep the cursor's goal
+/// column, and left and right step one character on the cursor's line.
 fn block_select_action(
     state: &mut EditorState,
     events: &mut Vec<Event>,
     direction: BlockDirection,
+    estimated_line_length: usize,
 ) {
     // Get line count for bounds checking
     let total_lines = {
@@ -213,59 +283,67 @@ fn block_select_action(
     };
 
     for (cursor_id, cursor) in state.cursors.iter() {
+        let in_block =
+            cursor.selection_mode == SelectionMode::Block && cursor.block_anchor.is_some();
         let current_2d = byte_to_2d(&state.buffer, cursor.position);
+        let goal_column = if in_block {
+            goal_visual_column(&mut state.buffer, cursor, estimated_line_length)
+        } else {
+            current_2d.column
+        };
 
         // If not in block mode, start block selection
-        let block_anchor =
-            if cursor.selection_mode != SelectionMode::Block || cursor.block_anchor.is_none() {
-                current_2d
-            } else {
-                cursor.block_anchor.unwrap()
-            };
+        let block_anchor = match cursor.block_anchor {
+            Some(anchor) if in_block => anchor,
+            _ => current_2d,
+        };
 
-        // Calculate new 2D position based on direction
-        let new_2d = match direction {
-            BlockDirection::Left => Position2D {
-                line: current_2d.line,
-                column: current_2d.column.saturating_sub(1),
-            },
-            BlockDirection::Right => {
-                // Get current line length to bound the column
-                let line_content = state.buffer.get_line(current_2d.line).unwrap_or_default();
-                let line_len = if line_content.last() == Some(&b'\n') {
-                    line_content.len().saturating_sub(1)
+        // Calculate new position based on direction
+        let new_byte_pos = match direction {
+            BlockDirection::Left => {
+                let line_start = state.buffer.line_start_offset(current_2d.line).unwrap_or(0);
+                if cursor.position > line_start {
+                    state.buffer.prev_char_boundary(cursor.position)
                 } else {
-                    line_content.len()
-                };
-                Position2D {
-                    line: current_2d.line,
-                    column: (current_2d.column + 1).min(line_len),
+                    cursor.position
                 }
             }
-            BlockDirection::Up => {
-                if current_2d.line > 0 {
+            BlockDirection::Right => {
+                // Bounded by the end of the line's content
+                let line_end = pos_2d_to_byte(
+                    &state.buffer,
                     Position2D {
-                        line: current_2d.line - 1,
-                        column: current_2d.column,
-                    }
+                        line: current_2d.line,
+                        column: usize::MAX,
+                    },
+                );
+                if cursor.position < line_end {
+                    state.buffer.next_char_boundary(cursor.position)
                 } else {
-                    current_2d
+                    cursor.position
                 }
             }
-            BlockDirection::Down => {
-                if current_2d.line + 1 < total_lines {
+            BlockDirection::Up | BlockDirection::Down => {
+                let line = match direction {
+                    BlockDirection::Up => current_2d.line.saturating_sub(1),
+                    _ if current_2d.line + 1 < total_lines => current_2d.line + 1,
+                    _ => current_2d.line,
+                };
+                pos_2d_to_byte(
+                    &state.buffer,
                     Position2D {
-                        line: current_2d.line + 1,
-                        column: current_2d.column,
-                    }
-                } else {
-                    current_2d
-                }
+                        line,
+                        column: goal_column,
+                    },
+                )
             }
         };
 
-        // Convert new 2D position back to byte offset
-        let new_byte_pos = pos_2d_to_byte(&state.buffer, new_2d);
+        // Up and down remember the goal column; left and right move it
+        let new_sticky_column = match direction {
+            BlockDirection::Up | BlockDirection::Down => goal_column,
+            _ => byte_to_2d(&state.buffer, new_byte_pos).column,
+        };
 
         // Store the byte anchor for the event system (for undo/redo compatibility)
         let byte_anchor = pos_2d_to_byte(&state.buffer, block_anchor);
@@ -277,7 +355,7 @@ fn block_select_action(
             old_anchor: cursor.anchor,
             new_anchor: Some(byte_anchor),
             old_sticky_column: cursor.sticky_column,
-            new_sticky_column: new_2d.column,
+            new_sticky_column,
         });
 
         // Note: We need to set block selection mode after the event is processed
@@ -308,8 +386,8 @@ pub fn clear_block_selection_if_active(state: &mut EditorState) {
 }
 
 /// Get the matching close character for auto-pairing.
-pub fn get_auto_close_char(ch: char, auto_indent: bool, language: &str) -> Option<char> {
-    if !auto_indent {
+pub fn get_auto_close_char(ch: char, auto_close: bool, language: &str) -> Option<char> {
+    if !auto_close {
         return None;
     }
     // Disable auto-closing quotes in plain text files
@@ -327,6 +405,85 @@ pub fn get_auto_close_char(ch: char, auto_indent: bool, language: &str) -> Optio
     }
 }
 
+/// How far back to scan for the `<` of an opening tag when `>` is typed.
+const TAG_SCAN_LIMIT: usize = 1024;
+
+/// HTML elements that never take a closing tag.
+const HTML_VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+/// Get the closing tag to insert when `>` completes an opening tag.
+///
+/// `text_before` is the text preceding the cursor (without the `>` being typed).
+/// Scans back to the nearest `<` and returns the tag name if it starts an
+/// opening tag. Only applies to HTML and XML buffers and to `.jsx`/`.tsx`
+/// files; plain `.js`/`.ts` share a language id with those but use `<T>` for
+/// generics and type assertions.
+pub fn get_auto_close_tag(
+    text_before: &str,
+    auto_close: bool,
+    language: &str,
+    path: Option<&Path>,
+) -> Option<String> {
+    let jsx = path
+        .and_then(|p| p.extension())
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("jsx") || ext.eq_ignore_ascii_case("tsx"));
+    if !auto_close || !(jsx || matches!(language, "html" | "xml")) {
+        return None;
+    }
+
+    let open = text_before.rfind('<')?;
+    // `foo<T>` is a generic, not a tag
+    if text_before[..open]
+        .chars()
+        .next_back()
+        .is_some_and(|c| c.is_alphanumeric() || c == '_')
+    {
+        return None;
+    }
+
+    let tag = &text_before[open + 1..];
+    let name_len = tag
+        .find(|c: char| !(c.is_alphanumeric() || matches!(c, '-' | '_' | ':' | '.')))
+        .unwrap_or(tag.len());
+    let name = &tag[..name_len];
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    // The rest must be attributes: no stray `>` outside quotes/braces, no open
+    // quote or brace (the `>` would be part of the value), and not self-closing.
+    let attributes = &tag[name_len..];
+    if !attributes.is_empty() && !attributes.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let mut quote = None;
+    let mut brace_depth = 0usize;
+    for c in attributes.chars() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '{') => brace_depth += 1,
+            (None, '}') => brace_depth = brace_depth.saturating_sub(1),
+            (None, '>') if brace_depth == 0 => return None,
+            _ => {}
+        }
+    }
+    if quote.is_some() || brace_depth > 0 || attributes.trim_end().ends_with('/') {
+        return None;
+    }
+
+    if language == "html" && HTML_VOID_ELEMENTS.contains(&name.to_ascii_lowercase().as_str()) {
+        return None;
+    }
+
+    Some(name.to_string())
+}
+
 /// Calculate the correct indent for a closing delimiter using tree-sitter.
 fn calculate_closing_delimiter_indent(
     state: &mut EditorState,
@@ -466,6 +623,30 @@ fn should_auto_close(char_after: Option<u8>) -> bool {
     !is_alphanumeric_after
 }
 
+/// Check if the insert position is inside a string or comment, where brackets
+/// and quotes are typed as-is.
+///
+/// Uses the categories of the last highlight pass, so buffers without
+/// highlighting always auto-close. A line comment's span stops before the line
+/// ending, so the end of a comment line counts as inside it.
+fn in_string_or_comment(state: &EditorState, position: usize, char_after: Option<u8>) -> bool {
+    use crate::primitives::highlighter::HighlightCategory;
+
+    if position == 0 {
+        return false;
+    }
+    let buffer_len = state.buffer.len();
+    let category_at = |pos| state.highlighter.category_at(pos, buffer_len);
+    match category_at(position - 1) {
+        Some(HighlightCategory::String) => category_at(position) == Some(HighlightCategory::String),
+        Some(HighlightCategory::Comment) => {
+            matches!(char_after, None | Some(b'\n' | b'\r'))
+                || category_at(position) == Some(HighlightCategory::Comment)
+        }
+        _ => false,
+    }
+}
+
 /// Handle auto-close: insert both opening and closing bracket/quote.
 fn handle_auto_close(
     events: &mut Vec<Event>,
@@ -493,6 +674,32 @@ fn handle_auto_close(
     });
 }
 
+/// Handle auto-close tag: insert `>` followed by the matching closing tag.
+fn handle_auto_close_tag(
+    events: &mut Vec<Event>,
+    cursor_id: CursorId,
+    tag_name: &str,
+    insert_position: usize,
+) {
+    let text = format!("></{}>", tag_name);
+    let end = insert_position + text.len();
+    events.push(Event::Insert {
+        position: insert_position,
+        text,
+        cursor_id,
+    });
+    // Move cursor between the tags
+    events.push(Event::MoveCursor {
+        cursor_id,
+        old_position: end,
+        new_position: insert_position + 1,
+        old_anchor: None,
+        new_anchor: None,
+        old_sticky_column: 0,
+        new_sticky_column: 0,
+    });
+}
+
 /// Cursor context data collected before processing insertions.
 struct InsertCursorData {
     cursor_id: CursorId,
@@ -580,9 +787,10 @@ fn insert_char_events(
     ch: char,
     tab_size: usize,
     auto_indent: bool,
+    auto_close: bool,
 ) {
     let is_closing_delimiter = matches!(ch, '}' | ')' | ']');
-    let auto_close_char = get_auto_close_char(ch, auto_indent, &state.language);
+    let auto_close_char = get_auto_close_char(ch, auto_close, &state.language);
     let cursor_data = collect_insert_cursor_data(state);
 
     for data in cursor_data {
@@ -596,7 +804,7 @@ fn insert_char_events(
         }
 
         // Try skip-over logic for closing brackets/quotes
-        if auto_indent && matches!(ch, ')' | ']' | '}' | '"' | '\'' | '`') {
+        if auto_close && matches!(ch, ')' | ']' | '}' | '"' | '\'' | '`') {
             if let Some(next_byte) = data.char_after {
                 if next_byte == ch as u8 {
                     // Try skip-over with dedent for closing delimiters
@@ -642,12 +850,32 @@ fn insert_char_events(
 
         // Try auto-close
         if let Some(close_char) = auto_close_char {
-            if should_auto_close(data.char_after) {
+            if should_auto_close(data.char_after)
+                && !in_string_or_comment(state, data.insert_position, data.char_after)
+            {
                 handle_auto_close(events, data.cursor_id, ch, close_char, data.insert_position);
                 continue;
             }
         }
 
+        // Try auto-close tag when `>` completes an opening tag
+        if ch == '>' && !in_string_or_comment(state, data.insert_position, data.char_after) {
+            let scan_start = data.insert_position.saturating_sub(TAG_SCAN_LIMIT);
+            let text_before = String::from_utf8_lossy(
+                &state.buffer.slice_bytes(scan_start..data.insert_position),
+            )
+            .into_owned();
+            if let Some(tag_name) = get_auto_close_tag(
+                &text_before,
+                auto_close,
+                &state.language,
+                state.buffer.file_path(),
+            ) {
+                handle_auto_close_tag(events, data.cursor_id, &tag_name, data.insert_position);
+                continue;
+            }
+        }
+
         // Normal character insertion
         events.push(Event::Insert {
             position: data.insert_position,
@@ -657,6 +885,265 @@ fn insert_char_events(
     }
 }
 
+/// Generate events for typing a character in overwrite mode.
+///
+/// The character under each cursor is replaced instead of being pushed right.
+/// At the end of a line there is nothing to replace, so the character is inserted.
+/// Selections are replaced just like in insert mode.
+pub fn overwrite_char_events(state: &mut EditorState, ch: char) -> Vec<Event> {
+    let mut events = Vec::new();
+    let buffer_len = state.buffer.len();
+    let cursor_data = collect_insert_cursor_data(state);
+
+    for data in cursor_data {
+        if let (Some(range), Some(text)) = (data.selection, data.deleted_text) {
+            events.push(Event::Delete {
+                range,
+                deleted_text: text,
+                cursor_id: data.cursor_id,
+            });
+        } else if data.char_after.is_some_and(|b| b != b'\n' && b != b'\r') {
+            let end = state
+                .buffer
+                .next_grapheme_boundary(data.insert_position)
+                .min(buffer_len);
+            let deleted_text = state.get_text_range(data.insert_position, end);
+            events.push(Event::Delete {
+                range: data.insert_position..end,
+                deleted_text,
+                cursor_id: data.cursor_id,
+            });
+        }
+
+        events.push(Event::Insert {
+            position: data.insert_position,
+            text: ch.to_string(),
+            cursor_id: data.cursor_id,
+        });
+    }
+
+    events
+}
+
+/// Generate events for a backspace that removes a whole indent level.
+///
+/// Every cursor must sit without a selection in the space-only leading
+/// whitespace of its line; it then deletes back to the previous tab stop.
+/// Returns `None` if the buffer indents with tabs or any cursor doesn't
+/// qualify, in which case the regular single-character backspace applies.
+pub fn unindent_backspace_events(state: &mut EditorState, tab_size: usize) -> Option<Vec<Event>> {
+    if state.use_tabs || tab_size == 0 {
+        return None;
+    }
+
+    let mut cursor_vec: Vec<_> = state.cursors.iter().collect();
+    cursor_vec.sort_by_key(|(_, c)| std::cmp::Reverse(c.position));
+
+    let mut deletions = Vec::new();
+    for (cursor_id, cursor) in cursor_vec {
+        if cursor.selection_range().is_some() || cursor.position == 0 {
+            return None;
+        }
+
+        let mut line_start = cursor.position;
+        while line_start > 0 {
+            match state.buffer.slice_bytes(line_start - 1..line_start).first() {
+                Some(b' ') => line_start -= 1,
+                Some(b'\n') => break,
+                _ => return None,
+            }
+        }
+
+        let column = cursor.position - line_start;
+        if column == 0 {
+            return None;
+        }
+        let target = (column - 1) / tab_size * tab_size;
+        deletions.push((cursor_id, line_start + target..cursor.position));
+    }
+
+    let mut events = Vec::new();
+    apply_deletions(state, deletions, &mut events);
+    Some(events)
+}
+
+/// Generate events for kill-line style deletion to a line boundary.
+///
+/// Each cursor deletes to the end (or start) of the line it is on. When
+/// `view_rows` holds the byte ranges of wrapped display rows, the line is
+/// narrowed to the row containing the cursor; otherwise source lines are used.
+/// At the end of a line, `DeleteToLineEnd` removes the line ending (joining the
+/// next line) only when `join_at_eol` is set. At the start of a line,
+/// `DeleteToLineStart` does nothing.
+pub fn delete_to_line_boundary_events(
+    state: &mut EditorState,
+    to_end: bool,
+    join_at_eol: bool,
+    view_rows: &[Range<usize>],
+    estimated_line_length: usize,
+) -> Vec<Event> {
+    let deletions: Vec<_> = state
+        .cursors
+        .iter()
+        .filter_map(|(cursor_id, cursor)| {
+            let pos = cursor.position;
+            let mut iter = state.buffer.line_iterator(pos, estimated_line_length);
+            let line_start = iter.current_position();
+            let (content_end, full_end) = iter
+                .next_line()
+                .map(|(_start, content)| {
+                    (
+                        line_start + content_len_without_line_ending(&content),
+                        line_start + content.len(),
+                    )
+                })
+                .unwrap_or((line_start, line_start));
+
+            // A position on a wrap boundary is displayed at the start of the
+            // following row, so take the last row that starts at or before it
+            let (start, end) = view_rows
+                .iter()
+                .rfind(|row| {
+                    row.start <= pos && row.start >= line_start && row.start <= content_end
+                })
+                .map(|row| (row.start, row.end.min(content_end).max(pos)))
+                .unwrap_or((line_start, content_end));
+
+            let range = if to_end {
+                if pos < end {
+                    pos..end
+                } else if join_at_eol && end == content_end && pos < full_end {
+                    pos..full_end
+                } else {
+                    return None;
+                }
+            } else if pos > start {
+                start..pos
+            } else {
+                return None;
+            };
+            Some((cursor_id, range))
+        })
+        .collect();
+
+    let mut events = Vec::new();
+    apply_deletions(state, deletions, &mut events);
+    events
+}
+
+/// Row of the viewport targeted by the `CursorToViewport*` actions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViewportRow {
+    Top,
+    Middle,
+    Bottom,
+}
+
+/// Generate events moving the primary cursor to the first, middle or last
+/// visible row (vim's `H`, `M` and `L`) without scrolling.
+///
+/// Visible rows are the source lines starting at `top_byte`, at most
+/// `visible_line_count` of them, unless `view_rows` holds the byte ranges of
+/// wrapped display rows, in which case those are used. The cursor keeps its
+/// visual column (or sticky column) within the row, clamped to the row's
+/// content.
+pub fn cursor_to_viewport_row_events(
+    state: &mut EditorState,
+    target: ViewportRow,
+    top_byte: usize,
+    visible_line_count: usize,
+    view_rows: &[Range<usize>],
+    estimated_line_length: usize,
+) -> Vec<Event> {
+    // Each row runs from its first byte to the last position still shown on it
+    let rows: Vec<Range<usize>> = if view_rows.is_empty() {
+        let mut iter = state.buffer.line_iterator(top_byte, estimated_line_length);
+        (0..visible_line_count)
+            .map_while(|_| iter.next_line())
+            .map(|(start, content)| start..start + content_len_without_line_ending(&content))
+            .collect()
+    } else {
+        view_rows
+            .iter()
+            .map(|row| {
+                let mut iter = state.buffer.line_iterator(row.start, estimated_line_length);
+                let line_start = iter.current_position();
+                let content_end = iter
+                    .next_line()
+                    .map(|(_start, content)| line_start + content_len_without_line_ending(&content))
+                    .unwrap_or(line_start);
+                if row.end > content_end {
+                    row.start..content_end
+                } else {
+                    // A position on a wrap boundary is shown on the following row
+                    row.start..state.buffer.prev_char_boundary(row.end).max(row.start)
+                }
+            })
+            .collect()
+    };
+    let Some(last) = rows.len().checked_sub(1) else {
+        return Vec::new();
+    };
+    let row = &rows[match target {
+        ViewportRow::Top => 0,
+        ViewportRow::Middle => last / 2,
+        ViewportRow::Bottom => last,
+    }];
+
+    let cursor_id = state.cursors.primary_id();
+    let cursor = *state.cursors.primary();
+    // On a wrapped display row the column is measured from the row's start
+    let cursor_row = rows
+        .iter()
+        .rfind(|r| r.start <= cursor.position && cursor.position <= r.end);
+    let goal_column = match cursor_row {
+        Some(r) if cursor.sticky_column == 0 && !view_rows.is_empty() => str_width(
+            &String::from_utf8_lossy(&state.buffer.slice_bytes(r.start..cursor.position)),
+        ),
+        _ => goal_visual_column(&mut state.buffer, &cursor, estimated_line_length),
+    };
+    let row_text = String::from_utf8_lossy(&state.buffer.slice_bytes(row.clone())).into_owned();
+    let new_position = position_at_visual_column(row.start, &row_text, goal_column).min(row.end);
+
+    let new_anchor = if cursor.deselect_on_move {
+        None
+    } else {
+        cursor.anchor
+    };
+    vec![Event::MoveCursor {
+        cursor_id,
+        old_position: cursor.position,
+        new_position,
+        old_anchor: cursor.anchor,
+        new_anchor,
+        old_sticky_column: cursor.sticky_column,
+        new_sticky_column: goal_column,
+    }]
+}
+
+/// Indentation for a new line started by pressing Enter at `position`.
+fn newline_indent(state: &EditorState, position: usize, tab_size: usize) -> String {
+    let use_tabs = state.use_tabs;
+    if let Some(language) = state.highlighter.language() {
+        // Use tree-sitter-based indent when we have a highlighter
+        state
+            .indent_calculator
+            .borrow_mut()
+            .calculate_indent(&state.buffer, position, language, tab_size)
+            .map(|indent_width| indent_to_string(indent_width, use_tabs, tab_size))
+            .unwrap_or_default()
+    } else {
+        // Fallback for files without syntax highlighting (e.g., .txt)
+        let indent_width =
+            crate::primitives::indent::IndentCalculator::calculate_indent_no_language(
+                &state.buffer,
+                position,
+                tab_size,
+            );
+        indent_to_string(indent_width, use_tabs, tab_size)
+    }
+}
+
 /// Calculate the maximum valid cursor position in the buffer.
 /// This is the end of the last line (excluding trailing newline).
 /// For empty buffers, returns 0.
@@ -666,6 +1153,144 @@ fn max_cursor_position(buffer: &Buffer) -> usize {
     buffer.len()
 }
 
+/// Move every cursor `lines_to_move` lines up or down, keeping its goal column
+///
+/// With `select`, the selection is extended from the cursor's anchor (or its
+/// current position if it has none).
+fn move_vertical(
+    state: &mut EditorState,
+    events: &mut Vec<Event>,
+    lines_to_move: usize,
+    up: bool,
+    select: bool,
+    estimated_line_length: usize,
+) {
+    for (cursor_id, cursor) in state.cursors.iter() {
+        let goal_column = goal_visual_column(&mut state.buffer, cursor, estimated_line_length);
+        let mut iter = state
+            .buffer
+            .line_iterator(cursor.position, estimated_line_length);
+
+        let mut new_pos = cursor.position;
+        if up {
+            for _ in 0..lines_to_move {
+                if let Some((line_start, line_content)) = iter.prev() {
+                    new_pos = position_at_visual_column(line_start, &line_content, goal_column);
+                } else {
+                    new_pos = 0;
+                    break;
+                }
+            }
+        } else {
+            // Consume current line
+            iter.next_line();
+
+            for _ in 0..lines_to_move {
+                if let Some((line_start, line_content)) = iter.next_line() {
+                    new_pos = position_at_visual_column(line_start, &line_content, goal_column);
+                } else {
+                    // Reached end of buffer - clamp to last valid position
+                    new_pos = max_cursor_position(&state.buffer);
+                    break;
+                }
+            }
+        }
+
+        // Plain moves preserve the anchor only if deselect_on_move is false (Emacs mark mode)
+        let new_anchor = if select {
+            Some(cursor.anchor.unwrap_or(cursor.position))
+        } else if cursor.deselect_on_move {
+            None
+        } else {
+            cursor.anchor
+        };
+        events.push(Event::MoveCursor {
+            cursor_id,
+            old_position: cursor.position,
+            new_position: new_pos,
+            old_anchor: cursor.anchor,
+            new_anchor,
+            old_sticky_column: cursor.sticky_column,
+            new_sticky_column: goal_column, // Preserve the goal column
+        });
+    }
+}
+
+/// Find the start of the blank line that bounds the paragraph above or below
+/// `position`, or the start/end of the buffer if there is none
+///
+/// Blank lines next to the cursor are skipped first, so a run of blank lines
+/// counts as a single boundary. Whitespace-only lines are blank.
+fn paragraph_boundary(
+    buffer: &mut Buffer,
+    position: usize,
+    up: bool,
+    estimated_line_length: usize,
+) -> usize {
+    let is_blank = |line: &str| line.trim().is_empty();
+    let mut iter = buffer.line_iterator(position, estimated_line_length);
+    let mut in_paragraph = false;
+
+    if up {
+        while let Some((line_start, line_content)) = iter.prev() {
+            if !is_blank(&line_content) {
+                in_paragraph = true;
+            } else if in_paragraph {
+                return line_start;
+            }
+        }
+        0
+    } else {
+        // The current line counts towards the paragraph being left
+        if let Some((_, line_content)) = iter.next_line() {
+            in_paragraph = !is_blank(&line_content);
+        }
+        while let Some((line_start, line_content)) = iter.next_line() {
+            if !is_blank(&line_content) {
+                in_paragraph = true;
+            } else if in_paragraph {
+                return line_start;
+            }
+        }
+        max_cursor_position(buffer)
+    }
+}
+
+/// Move every cursor to the next paragraph boundary, optionally extending the selection
+fn move_paragraph(
+    state: &mut EditorState,
+    events: &mut Vec<Event>,
+    up: bool,
+    select: bool,
+    estimated_line_length: usize,
+) {
+    for (cursor_id, cursor) in state.cursors.iter() {
+        let new_pos = paragraph_boundary(
+            &mut state.buffer,
+            cursor.position,
+            up,
+            estimated_line_length,
+        );
+        // Plain moves preserve the anchor only if deselect_on_move is false (Emacs mark mode)
+        let new_anchor = if select {
+            Some(cursor.anchor.unwrap_or(cursor.position))
+        } else if cursor.deselect_on_move {
+            None
+        } else {
+            cursor.anchor
+        };
+        events.push(Event::MoveCursor {
+            cursor_id,
+            old_position: cursor.position,
+            new_position: new_pos,
+            old_anchor: cursor.anchor,
+            new_anchor,
+            old_sticky_column: cursor.sticky_column,
+            new_sticky_column: 0, // Reset sticky column
+        });
+    }
+}
+
 /// Convert an action into a sequence of events that can be applied to the editor state
 ///
 /// # Parameters
@@ -673,6 +1298,7 @@ fn max_cursor_position(buffer: &Buffer) -> usize {
 /// * `action` - The action to convert
 /// * `tab_size` - Number of spaces per tab
 /// * `auto_indent` - Whether auto-indent is enabled
+/// * `auto_close` - Whether brackets and quotes are auto-closed, typed over and deleted in pairs
 /// * `estimated_line_length` - Estimated bytes per line for large files
 /// * `viewport_height` - Height of the viewport in lines (for PageUp/PageDown)
 ///
@@ -684,6 +1310,7 @@ pub fn action_to_events(
     action: Action,
     tab_size: usize,
     auto_indent: bool,
+    auto_close: bool,
     estimated_line_length: usize,
     viewport_height: u16,
 ) -> Option<Vec<Event>> {
@@ -692,7 +1319,7 @@ pub fn action_to_events(
     match action {
         // Character input - insert at each cursor
         Action::InsertChar(ch) => {
-            insert_char_events(state, &mut events, ch, tab_size, auto_indent);
+            insert_char_events(state, &mut events, ch, tab_size, auto_indent, auto_close);
         }
 
         Action::InsertNewline => {
@@ -736,28 +1363,8 @@ pub fn action_to_events(
             for (cursor_id, indent_position) in indent_positions {
                 // Calculate indent for new line
                 let mut text = line_ending.to_string();
-
                 if auto_indent {
-                    let use_tabs = state.use_tabs;
-                    if let Some(language) = state.highlighter.language() {
-                        // Use tree-sitter-based indent when we have a highlighter
-                        if let Some(indent_width) = state
-                            .indent_calculator
-                            .borrow_mut()
-                            .calculate_indent(&state.buffer, indent_position, language, tab_size)
-                        {
-                            text.push_str(&indent_to_string(indent_width, use_tabs, tab_size));
-                        }
-                    } else {
-                        // Fallback for files without syntax highlighting (e.g., .txt)
-                        let indent_width =
-                            crate::primitives::indent::IndentCalculator::calculate_indent_no_language(
-                                &state.buffer,
-                                indent_position,
-                                tab_size,
-                            );
-                        text.push_str(&indent_to_string(indent_width, use_tabs, tab_size));
-                    }
+                    text.push_str(&newline_indent(state, indent_position, tab_size));
                 }
 
                 events.push(Event::Insert {
@@ -1047,19 +1654,9 @@ pub fn action_to_events(
 
         Action::MoveUp => {
             for (cursor_id, cursor) in state.cursors.iter() {
-                // Calculate visual column first (iterator is dropped after this call)
-                let (current_visual_column, _) = calculate_visual_column(
-                    &mut state.buffer,
-                    cursor.position,
-                    estimated_line_length,
-                );
-
-                // Use sticky_column if set (now stores visual column), otherwise use current visual column
-                let goal_visual_column = if cursor.sticky_column > 0 {
-                    cursor.sticky_column
-                } else {
-                    current_visual_column
-                };
+                // Calculate the goal column first (iterator is dropped after this call)
+                let goal_visual_column =
+                    goal_visual_column(&mut state.buffer, cursor, estimated_line_length);
 
                 // Now create iterator for navigation
                 let mut iter = state
@@ -1067,11 +1664,11 @@ pub fn action_to_events(
                     .line_iterator(cursor.position, estimated_line_length);
 
                 if let Some((prev_line_start, prev_line_content)) = iter.prev() {
-                    // Calculate byte offset from visual column, ensuring valid character boundary
-                    let prev_line_text = prev_line_content.trim_end_matches('\n');
-                    let byte_offset =
-                        byte_offset_at_visual_column(prev_line_text, goal_visual_column);
-                    let new_pos = prev_line_start + byte_offset;
+                    let new_pos = position_at_visual_column(
+                        prev_line_start,
+                        &prev_line_content,
+                        goal_visual_column,
+                    );
 
                     // Preserve anchor if deselect_on_move is false (Emacs mark mode)
                     let new_anchor = if cursor.deselect_on_move {
@@ -1094,19 +1691,9 @@ pub fn action_to_events(
 
         Action::MoveDown => {
             for (cursor_id, cursor) in state.cursors.iter() {
-                // Calculate visual column first (iterator is dropped after this call)
-                let (current_visual_column, _) = calculate_visual_column(
-                    &mut state.buffer,
-                    cursor.position,
-                    estimated_line_length,
-                );
-
-                // Use sticky_column if set (now stores visual column), otherwise use current visual column
-                let goal_visual_column = if cursor.sticky_column > 0 {
-                    cursor.sticky_column
-                } else {
-                    current_visual_column
-                };
+                // Calculate the goal column first (iterator is dropped after this call)
+                let goal_visual_column =
+                    goal_visual_column(&mut state.buffer, cursor, estimated_line_length);
 
                 // Now create iterator for navigation
                 let mut iter = state
@@ -1117,11 +1704,11 @@ pub fn action_to_events(
                 iter.next_line();
 
                 if let Some((next_line_start, next_line_content)) = iter.next_line() {
-                    // Calculate byte offset from visual column, ensuring valid character boundary
-                    let next_line_text = next_line_content.trim_end_matches('\n');
-                    let byte_offset =
-                        byte_offset_at_visual_column(next_line_text, goal_visual_column);
-                    let new_pos = next_line_start + byte_offset;
+                    let new_pos = position_at_visual_column(
+                        next_line_start,
+                        &next_line_content,
+                        goal_visual_column,
+                    );
 
                     // Preserve anchor if deselect_on_move is false (Emacs mark mode)
                     let new_anchor = if cursor.deselect_on_move {
@@ -1196,9 +1783,14 @@ pub fn action_to_events(
             }
         }
 
-        Action::MoveWordLeft => {
+        Action::MoveWordLeft | Action::MoveSubwordLeft => {
+            let find_start = if matches!(action, Action::MoveWordLeft) {
+                find_word_start_left
+            } else {
+                find_subword_start_left
+            };
             for (cursor_id, cursor) in state.cursors.iter() {
-                let new_pos = find_word_start_left(&state.buffer, cursor.position);
+                let new_pos = find_start(&state.buffer, cursor.position);
                 // Preserve anchor if deselect_on_move is false (Emacs mark mode)
                 let new_anchor = if cursor.deselect_on_move {
                     None
@@ -1217,9 +1809,14 @@ pub fn action_to_events(
             }
         }
 
-        Action::MoveWordRight => {
+        Action::MoveWordRight | Action::MoveSubwordRight => {
+            let find_start = if matches!(action, Action::MoveWordRight) {
+                find_word_start_right
+            } else {
+                find_subword_start_right
+            };
             for (cursor_id, cursor) in state.cursors.iter() {
-                let new_pos = find_word_start_right(&state.buffer, cursor.position);
+                let new_pos = find_start(&state.buffer, cursor.position);
                 // Preserve anchor if deselect_on_move is false (Emacs mark mode)
                 let new_anchor = if cursor.deselect_on_move {
                     None
@@ -1279,100 +1876,60 @@ pub fn action_to_events(
             }
         }
 
-        Action::MovePageUp => {
-            for (cursor_id, cursor) in state.cursors.iter() {
-                // Move up by viewport height
-                let lines_to_move = viewport_height.saturating_sub(1) as usize;
-                let mut iter = state
-                    .buffer
-                    .line_iterator(cursor.position, estimated_line_length);
-                let current_line_start = iter.current_position();
-                let current_column = cursor.position - current_line_start;
-
-                // Use sticky_column if set, otherwise use current column
-                let goal_column = if cursor.sticky_column > 0 {
-                    cursor.sticky_column
-                } else {
-                    current_column
-                };
+        Action::MoveParagraphUp => {
+            move_paragraph(state, &mut events, true, false, estimated_line_length);
+        }
 
-                let mut new_pos = cursor.position;
-                for _ in 0..lines_to_move {
-                    if let Some((line_start, line_content)) = iter.prev() {
-                        let line_len = line_content.trim_end_matches('\n').len();
-                        new_pos = line_start + goal_column.min(line_len);
-                    } else {
-                        new_pos = 0;
-                        break;
-                    }
-                }
+        Action::MoveParagraphDown => {
+            move_paragraph(state, &mut events, false, false, estimated_line_length);
+        }
 
-                // Preserve anchor if deselect_on_move is false (Emacs mark mode)
-                let new_anchor = if cursor.deselect_on_move {
-                    None
-                } else {
-                    cursor.anchor
-                };
-                events.push(Event::MoveCursor {
-                    cursor_id,
-                    old_position: cursor.position,
-                    new_position: new_pos,
-                    old_anchor: cursor.anchor,
-                    new_anchor,
-                    old_sticky_column: cursor.sticky_column,
-                    new_sticky_column: goal_column, // Preserve the goal column
-                });
-            }
+        Action::MovePageUp => {
+            let lines_to_move = viewport_height.saturating_sub(1) as usize;
+            move_vertical(
+                state,
+                &mut events,
+                lines_to_move,
+                true,
+                false,
+                estimated_line_length,
+            );
         }
 
         Action::MovePageDown => {
-            for (cursor_id, cursor) in state.cursors.iter() {
-                // Move down by viewport height
-                let lines_to_move = viewport_height.saturating_sub(1) as usize;
-                let mut iter = state
-                    .buffer
-                    .line_iterator(cursor.position, estimated_line_length);
-                let current_line_start = iter.current_position();
-                let current_column = cursor.position - current_line_start;
-
-                // Use sticky_column if set, otherwise use current column
-                let goal_column = if cursor.sticky_column > 0 {
-                    cursor.sticky_column
-                } else {
-                    current_column
-                };
-
-                // Consume current line
-                iter.next_line();
+            let lines_to_move = viewport_height.saturating_sub(1) as usize;
+            move_vertical(
+                state,
+                &mut events,
+                lines_to_move,
+                false,
+                false,
+                estimated_line_length,
+            );
+        }
 
-                let mut new_pos = cursor.position;
-                for _ in 0..lines_to_move {
-                    if let Some((line_start, line_content)) = iter.next_line() {
-                        let line_len = line_content.trim_end_matches('\n').len();
-                        new_pos = line_start + goal_column.min(line_len);
-                    } else {
-                        // Reached end of buffer - clamp to last valid position
-                        new_pos = max_cursor_position(&state.buffer);
-                        break;
-                    }
-                }
+        Action::MoveHalfPageUp => {
+            let lines_to_move = (viewport_height as usize / 2).max(1);
+            move_vertical(
+                state,
+                &mut events,
+                lines_to_move,
+                true,
+                false,
+                estimated_line_length,
+            );
+        }
 
-                // Preserve anchor if deselect_on_move is false (Emacs mark mode)
-                let new_anchor = if cursor.deselect_on_move {
-                    None
-                } else {
-                    cursor.anchor
-                };
-                events.push(Event::MoveCursor {
-                    cursor_id,
-                    old_position: cursor.position,
-                    new_position: new_pos,
-                    old_anchor: cursor.anchor,
-                    new_anchor,
-                    old_sticky_column: cursor.sticky_column,
-                    new_sticky_column: goal_column, // Preserve the goal column
-                });
-            }
+        Action::MoveHalfPageDown => {
+            let lines_to_move = (viewport_height as usize / 2).max(1);
+            move_vertical(
+                state,
+                &mut events,
+                lines_to_move,
+                false,
+                false,
+                estimated_line_length,
+            );
         }
 
         // Selection movement - same as regular movement but keeps anchor
@@ -1415,23 +1972,16 @@ pub fn action_to_events(
 
         Action::SelectUp => {
             for (cursor_id, cursor) in state.cursors.iter() {
+                let goal_column =
+                    goal_visual_column(&mut state.buffer, cursor, estimated_line_length);
                 let mut iter = state
                     .buffer
                     .line_iterator(cursor.position, estimated_line_length);
-                let current_line_start = iter.current_position();
-                let current_column = cursor.position - current_line_start;
                 let anchor = cursor.anchor.unwrap_or(cursor.position);
 
-                // Use sticky_column if set, otherwise use current column
-                let goal_column = if cursor.sticky_column > 0 {
-                    cursor.sticky_column
-                } else {
-                    current_column
-                };
-
                 if let Some((prev_line_start, prev_line_content)) = iter.prev() {
-                    let prev_line_len = prev_line_content.trim_end_matches('\n').len();
-                    let new_pos = prev_line_start + goal_column.min(prev_line_len);
+                    let new_pos =
+                        position_at_visual_column(prev_line_start, &prev_line_content, goal_column);
 
                     events.push(Event::MoveCursor {
                         cursor_id,
@@ -1448,25 +1998,18 @@ pub fn action_to_events(
 
         Action::SelectDown => {
             for (cursor_id, cursor) in state.cursors.iter() {
+                let goal_column =
+                    goal_visual_column(&mut state.buffer, cursor, estimated_line_length);
                 let mut iter = state
                     .buffer
                     .line_iterator(cursor.position, estimated_line_length);
-                let current_line_start = iter.current_position();
-                let current_column = cursor.position - current_line_start;
                 let anchor = cursor.anchor.unwrap_or(cursor.position);
 
-                // Use sticky_column if set, otherwise use current column
-                let goal_column = if cursor.sticky_column > 0 {
-                    cursor.sticky_column
-                } else {
-                    current_column
-                };
-
                 // Skip current line, then get next line
                 iter.next_line();
                 if let Some((next_line_start, next_line_content)) = iter.next_line() {
-                    let next_line_len = next_line_content.trim_end_matches('\n').len();
-                    let new_pos = next_line_start + goal_column.min(next_line_len);
+                    let new_pos =
+                        position_at_visual_column(next_line_start, &next_line_content, goal_column);
 
                     events.push(Event::MoveCursor {
                         cursor_id,
@@ -1527,9 +2070,14 @@ pub fn action_to_events(
             }
         }
 
-        Action::SelectWordLeft => {
+        Action::SelectWordLeft | Action::SelectSubwordLeft => {
+            let find_start = if matches!(action, Action::SelectWordLeft) {
+                find_word_start_left
+            } else {
+                find_subword_start_left
+            };
             for (cursor_id, cursor) in state.cursors.iter() {
-                let new_pos = find_word_start_left(&state.buffer, cursor.position);
+                let new_pos = find_start(&state.buffer, cursor.position);
                 let anchor = cursor.anchor.unwrap_or(cursor.position);
                 events.push(Event::MoveCursor {
                     cursor_id,
@@ -1543,9 +2091,14 @@ pub fn action_to_events(
             }
         }
 
-        Action::SelectWordRight => {
+        Action::SelectWordRight | Action::SelectSubwordRight => {
+            let find_start = if matches!(action, Action::SelectWordRight) {
+                find_word_start_right
+            } else {
+                find_subword_start_right
+            };
             for (cursor_id, cursor) in state.cursors.iter() {
-                let new_pos = find_word_start_right(&state.buffer, cursor.position);
+                let new_pos = find_start(&state.buffer, cursor.position);
                 let anchor = cursor.anchor.unwrap_or(cursor.position);
                 events.push(Event::MoveCursor {
                     cursor_id,
@@ -1585,95 +2138,43 @@ pub fn action_to_events(
                     old_anchor: cursor.anchor,
                     new_anchor: Some(anchor),
                     old_sticky_column: cursor.sticky_column,
-                    new_sticky_column: 0, // Reset sticky column
-                });
-            }
-        }
-
-        Action::SelectPageUp => {
-            for (cursor_id, cursor) in state.cursors.iter() {
-                let lines_to_move = viewport_height.saturating_sub(1) as usize;
-                let mut iter = state
-                    .buffer
-                    .line_iterator(cursor.position, estimated_line_length);
-                let current_line_start = iter.current_position();
-                let current_column = cursor.position - current_line_start;
-                let anchor = cursor.anchor.unwrap_or(cursor.position);
-
-                // Use sticky_column if set, otherwise use current column
-                let goal_column = if cursor.sticky_column > 0 {
-                    cursor.sticky_column
-                } else {
-                    current_column
-                };
-
-                let mut new_pos = cursor.position;
-                for _ in 0..lines_to_move {
-                    if let Some((line_start, line_content)) = iter.prev() {
-                        let line_len = line_content.trim_end_matches('\n').len();
-                        new_pos = line_start + goal_column.min(line_len);
-                    } else {
-                        new_pos = 0;
-                        break;
-                    }
-                }
-
-                events.push(Event::MoveCursor {
-                    cursor_id,
-                    old_position: cursor.position,
-                    new_position: new_pos,
-                    old_anchor: cursor.anchor,
-                    new_anchor: Some(anchor),
-                    old_sticky_column: cursor.sticky_column,
-                    new_sticky_column: goal_column, // Preserve the goal column
-                });
-            }
-        }
-
-        Action::SelectPageDown => {
-            for (cursor_id, cursor) in state.cursors.iter() {
-                let lines_to_move = viewport_height.saturating_sub(1) as usize;
-                let mut iter = state
-                    .buffer
-                    .line_iterator(cursor.position, estimated_line_length);
-                let current_line_start = iter.current_position();
-                let current_column = cursor.position - current_line_start;
-                let anchor = cursor.anchor.unwrap_or(cursor.position);
-
-                // Use sticky_column if set, otherwise use current column
-                let goal_column = if cursor.sticky_column > 0 {
-                    cursor.sticky_column
-                } else {
-                    current_column
-                };
-
-                // Consume current line
-                iter.next_line();
-
-                let mut new_pos = cursor.position;
-                for _ in 0..lines_to_move {
-                    if let Some((line_start, line_content)) = iter.next_line() {
-                        let line_len = line_content.trim_end_matches('\n').len();
-                        new_pos = line_start + goal_column.min(line_len);
-                    } else {
-                        // Reached end of buffer - clamp to last valid position
-                        new_pos = max_cursor_position(&state.buffer);
-                        break;
-                    }
-                }
-
-                events.push(Event::MoveCursor {
-                    cursor_id,
-                    old_position: cursor.position,
-                    new_position: new_pos,
-                    old_anchor: cursor.anchor,
-                    new_anchor: Some(anchor),
-                    old_sticky_column: cursor.sticky_column,
-                    new_sticky_column: goal_column, // Preserve the goal column
+                    new_sticky_column: 0, // Reset sticky column
                 });
             }
         }
 
+        Action::SelectParagraphUp => {
+            move_paragraph(state, &mut events, true, true, estimated_line_length);
+        }
+
+        Action::SelectParagraphDown => {
+            move_paragraph(state, &mut events, false, true, estimated_line_length);
+        }
+
+        Action::SelectPageUp => {
+            let lines_to_move = viewport_height.saturating_sub(1) as usize;
+            move_vertical(
+                state,
+                &mut events,
+                lines_to_move,
+                true,
+                true,
+                estimated_line_length,
+            );
+        }
+
+        Action::SelectPageDown => {
+            let lines_to_move = viewport_height.saturating_sub(1) as usize;
+            move_vertical(
+                state,
+                &mut events,
+                lines_to_move,
+                false,
+                true,
+                estimated_line_length,
+            );
+        }
+
         Action::SelectAll => {
             // Select entire buffer for primary cursor only
             let primary_id = state.cursors.primary_id();
@@ -1732,9 +2233,9 @@ pub fn action_to_events(
                         let delete_from = state.buffer.prev_char_boundary(cursor.position);
                         let delete_from = adjust_position_for_crlf_left(&state.buffer, delete_from);
 
-                        // Check for auto-pair deletion when auto_indent is enabled
+                        // Check for auto-pair deletion when auto_close is enabled
                         // Note: Auto-pairs are ASCII-only, so we can safely check single bytes
-                        if auto_indent && cursor.position < state.buffer.len() {
+                        if auto_close && cursor.position < state.buffer.len() {
                             let char_before = state
                                 .buffer
                                 .slice_bytes(delete_from..cursor.position)
@@ -1873,55 +2374,16 @@ pub fn action_to_events(
             apply_deletions(state, deletions, &mut events);
         }
 
-        Action::DeleteToLineEnd => {
-            // Delete from cursor to end of line (like Ctrl+K in emacs/bash)
-            let deletions: Vec<_> = state
-                .cursors
-                .iter()
-                .filter_map(|(cursor_id, cursor)| {
-                    let mut iter = state
-                        .buffer
-                        .line_iterator(cursor.position, estimated_line_length);
-                    let line_start = iter.current_position();
-                    iter.next_line().map(|(_start, content)| {
-                        let line_end = line_start + content_len_without_line_ending(&content);
-                        if cursor.position < line_end {
-                            Some((cursor_id, cursor.position..line_end))
-                        } else {
-                            // If cursor is at end of line content, delete the newline instead
-                            let full_line_end = line_start + content.len();
-                            if cursor.position < full_line_end {
-                                Some((cursor_id, cursor.position..full_line_end))
-                            } else {
-                                None
-                            }
-                        }
-                    })?
-                })
-                .collect();
-
-            apply_deletions(state, deletions, &mut events);
-        }
-
-        Action::DeleteToLineStart => {
-            // Delete from start of line to cursor (like Ctrl+U in bash)
-            let deletions: Vec<_> = state
-                .cursors
-                .iter()
-                .filter_map(|(cursor_id, cursor)| {
-                    let iter = state
-                        .buffer
-                        .line_iterator(cursor.position, estimated_line_length);
-                    let line_start = iter.current_position();
-                    if cursor.position > line_start {
-                        Some((cursor_id, line_start..cursor.position))
-                    } else {
-                        None
-                    }
-                })
-                .collect();
-
-            apply_deletions(state, deletions, &mut events);
+        Action::DeleteToLineEnd | Action::DeleteToLineStart => {
+            // Delete from cursor to end of line (like Ctrl+K in emacs/bash) or
+            // from start of line to cursor (like Ctrl+U in bash)
+            events.extend(delete_to_line_boundary_events(
+                state,
+                action == Action::DeleteToLineEnd,
+                true,
+                &[],
+                estimated_line_length,
+            ));
         }
 
         Action::TransposeChars => {
@@ -2038,6 +2500,83 @@ pub fn action_to_events(
             }
         }
 
+        Action::OpenLineBelow | Action::OpenLineAbove => {
+            // Start a new line below/above the current one and move onto it
+            // (vim's o/O). Each line is opened once even with several cursors on it.
+            let below = action == Action::OpenLineBelow;
+            let line_ending = state.buffer.line_ending().as_str();
+            let mut targets: Vec<_> = state
+                .cursors
+                .iter()
+                .map(|(cursor_id, cursor)| {
+                    let mut iter = state
+                        .buffer
+                        .line_iterator(cursor.position, estimated_line_length);
+                    let line_start = iter.current_position();
+                    let content_end = iter
+                        .next_line()
+                        .map(|(_start, content)| {
+                            line_start + content_len_without_line_ending(&content)
+                        })
+                        .unwrap_or(line_start);
+                    (cursor_id, line_start, content_end)
+                })
+                .collect();
+            targets.sort_by_key(|(_, line_start, _)| std::cmp::Reverse(*line_start));
+            targets.dedup_by_key(|(_, line_start, _)| *line_start);
+
+            for (cursor_id, line_start, content_end) in targets {
+                if below || line_start > 0 {
+                    // Like pressing Enter at the end of this (or the previous) line
+                    let position = if below {
+                        content_end
+                    } else {
+                        line_start - line_ending.len()
+                    };
+                    let mut text = line_ending.to_string();
+                    if auto_indent {
+                        text.push_str(&newline_indent(state, position, tab_size));
+                    }
+                    events.push(Event::Insert {
+                        position,
+                        text,
+                        cursor_id,
+                    });
+                } else {
+                    // Above the first line there is nothing to continue from, so
+                    // reuse the line's own indentation and place the cursor explicitly
+                    let indent: String = if auto_indent {
+                        state
+                            .get_text_range(0, content_end)
+                            .chars()
+                            .take_while(|c| *c == ' ' || *c == '\t')
+                            .collect()
+                    } else {
+                        String::new()
+                    };
+                    let (old_position, old_anchor, old_sticky_column) = state
+                        .cursors
+                        .get(cursor_id)
+                        .map(|c| (c.position, c.anchor, c.sticky_column))
+                        .unwrap_or_default();
+                    events.push(Event::Insert {
+                        position: 0,
+                        text: format!("{}{}", indent, line_ending),
+                        cursor_id,
+                    });
+                    events.push(Event::MoveCursor {
+                        cursor_id,
+                        old_position,
+                        new_position: indent.len(),
+                        old_anchor,
+                        new_anchor: None,
+                        old_sticky_column,
+                        new_sticky_column: 0,
+                    });
+                }
+            }
+        }
+
         Action::Recenter => {
             // Scroll so that the cursor is centered in the view
             // This is handled specially - we emit a Recenter event
@@ -2087,9 +2626,25 @@ pub fn action_to_events(
             events.push(Event::Scroll { line_offset: 1 });
         }
 
+        Action::ScrollHalfPageUp => {
+            let half_page = (viewport_height as isize / 2).max(1);
+            events.push(Event::Scroll {
+                line_offset: -half_page,
+            });
+        }
+
+        Action::ScrollHalfPageDown => {
+            let half_page = (viewport_height as isize / 2).max(1);
+            events.push(Event::Scroll {
+                line_offset: half_page,
+            });
+        }
+
         // Actions that don't generate events
         Action::Quit
         | Action::ForceQuit
+        | Action::SaveSession
+        | Action::RestoreSession
         | Action::Save
         | Action::SaveAs
         | Action::Open
@@ -2098,6 +2653,7 @@ pub fn action_to_events(
         | Action::Close
         | Action::CloseTab
         | Action::GotoLine
+        | Action::GotoPercent(_)
         | Action::NextBuffer
         | Action::PrevBuffer
         | Action::SwitchToPreviousTab
@@ -2111,8 +2667,19 @@ pub fn action_to_events(
         | Action::PrevSplit
         | Action::Copy
         | Action::CopyWithTheme(_)
+        | Action::SelectTextObject(_)
+        | Action::DeleteTextObject(_)
         | Action::Cut
+        | Action::ExtractSelectionToNewBuffer
+        | Action::MoveSelectionToNewBuffer
+        | Action::ExportHtml
+        | Action::ExportHtmlWithLineNumbers
         | Action::Paste
+        | Action::PasteAndIndent
+        | Action::YankPop
+        | Action::CopyToRegister(_)
+        | Action::PasteFromRegister(_)
+        | Action::PromptSelectRegister
         | Action::YankWordForward
         | Action::YankWordBackward
         | Action::YankToLineEnd
@@ -2123,22 +2690,41 @@ pub fn action_to_events(
         | Action::CommandPalette
         | Action::ShowHelp
         | Action::ToggleLineWrap
+        | Action::ToggleIndentGuides
+        | Action::ToggleRenderWhitespace
+        | Action::ToggleRenderTrailingWhitespace
+        | Action::ToggleStickyScroll
+        | Action::ToggleInsertMode
         | Action::ToggleComposeMode
         | Action::SetComposeWidth
         | Action::IncreaseSplitSize
         | Action::DecreaseSplitSize
+        | Action::EqualizeSplits
+        | Action::MoveBufferToNextSplit
+        | Action::MoveBufferToPrevSplit
+        | Action::SwapBufferWithNextSplit
         | Action::ToggleMaximizeSplit
         | Action::Undo
         | Action::Redo
+        | Action::RepeatLastEdit
         | Action::GoToMatchingBracket
         | Action::JumpToNextError
         | Action::JumpToPreviousError
+        | Action::QuickfixNext
+        | Action::QuickfixPrev
         | Action::ShowKeyboardShortcuts
+        | Action::ShowKeybindings
         | Action::ShowWarnings
         | Action::ShowLspStatus
         | Action::ClearWarnings
         | Action::SmartHome
         | Action::ToggleComment
+        | Action::AlignOnDelimiter
+        | Action::SortLines
+        | Action::SortLinesDescending
+        | Action::SortLinesCaseInsensitive
+        | Action::UniqueLines
+        | Action::UniqueAdjacentLines
         | Action::SetBookmark(_)
         | Action::JumpToBookmark(_)
         | Action::ClearBookmark(_)
@@ -2156,6 +2742,7 @@ pub fn action_to_events(
         | Action::PromptRecordMacro
         | Action::PromptPlayMacro
         | Action::PlayLastMacro
+        | Action::PromptPlayLastMacroTimes
         | Action::PromptSetBookmark
         | Action::PromptJumpToBookmark
         | Action::PromptConfirm
@@ -2197,6 +2784,8 @@ pub fn action_to_events(
         | Action::ToggleFileExplorer
         | Action::ToggleMenuBar
         | Action::ToggleTabBar
+        | Action::ToggleCursorDetails
+        | Action::ShowWordCount
         | Action::FocusFileExplorer
         | Action::FocusEditor
         | Action::SetBackground
@@ -2213,6 +2802,7 @@ pub fn action_to_events(
         | Action::FileExplorerNewDirectory
         | Action::FileExplorerDelete
         | Action::FileExplorerRename
+        | Action::FileExplorerFilter
         | Action::FileExplorerToggleHidden
         | Action::FileExplorerToggleGitignored
         | Action::LspCompletion
@@ -2222,6 +2812,8 @@ pub fn action_to_events(
         | Action::LspHover
         | Action::LspSignatureHelp
         | Action::LspCodeActions
+        | Action::LspFormat
+        | Action::LspDocumentSymbols
         | Action::LspRestart
         | Action::LspStop
         | Action::ToggleInlayHints
@@ -2229,6 +2821,13 @@ pub fn action_to_events(
         | Action::ToggleLineNumbers
         | Action::ToggleMouseCapture
         | Action::DumpConfig
+        | Action::CursorToViewportTop
+        | Action::CursorToViewportMiddle
+        | Action::CursorToViewportBottom
+        | Action::ScrollLeft
+        | Action::ScrollRight
+        | Action::ScrollCursorToTop
+        | Action::ScrollCursorToBottom
         | Action::Search
         | Action::FindInSelection
         | Action::FindNext
@@ -2236,7 +2835,10 @@ pub fn action_to_events(
         | Action::FindSelectionNext
         | Action::FindSelectionPrevious
         | Action::Replace
+        | Action::ReplaceAll
         | Action::QueryReplace
+        | Action::ClearSearchHighlight
+        | Action::ProjectSearch
         | Action::MenuActivate
         | Action::MenuClose
         | Action::MenuLeft
@@ -2255,6 +2857,7 @@ pub fn action_to_events(
         | Action::SelectCursorStyle
         | Action::SelectLocale
         | Action::Revert
+        | Action::ForceRevert
         | Action::ToggleAutoRevert
         | Action::FormatBuffer
         | Action::OpenTerminal
@@ -2276,6 +2879,8 @@ pub fn action_to_events(
         | Action::SetTabSize
         | Action::SetLineEnding
         | Action::ToggleIndentationStyle
+        | Action::ConvertIndentationToSpaces
+        | Action::ConvertIndentationToTabs
         | Action::ToggleTabIndicators
         | Action::ToggleDebugHighlights
         | Action::ResetBufferSettings
@@ -2285,24 +2890,60 @@ pub fn action_to_events(
 
         // Block/rectangular selection actions
         Action::BlockSelectLeft => {
-            block_select_action(state, &mut events, BlockDirection::Left);
+            block_select_action(
+                state,
+                &mut events,
+                BlockDirection::Left,
+                estimated_line_length,
+            );
         }
 
         Action::BlockSelectRight => {
-            block_select_action(state, &mut events, BlockDirection::Right);
+            block_select_action(
+                state,
+                &mut events,
+                BlockDirection::Right,
+                estimated_line_length,
+            );
         }
 
         Action::BlockSelectUp => {
-            block_select_action(state, &mut events, BlockDirection::Up);
+            block_select_action(
+                state,
+                &mut events,
+                BlockDirection::Up,
+                estimated_line_length,
+            );
         }
 
         Action::BlockSelectDown => {
-            block_select_action(state, &mut events, BlockDirection::Down);
+            block_select_action(
+                state,
+                &mut events,
+                BlockDirection::Down,
+                estimated_line_length,
+            );
         }
 
         Action::SelectLine => {
             // Select the entire line for each cursor
+            let buffer_len = state.buffer.len();
             for (cursor_id, cursor) in state.cursors.iter() {
+                // If the selection already covers whole lines (e.g. from a previous
+                // SelectLine), extend it by the next line instead of starting over
+                let is_line_start = |pos: usize| {
+                    pos == 0 || state.buffer.slice_bytes(pos - 1..pos).first() == Some(&b'\n')
+                };
+                let linewise_anchor = cursor.anchor.filter(|&anchor| {
+                    anchor < cursor.position
+                        && is_line_start(anchor)
+                        && (is_line_start(cursor.position) || cursor.position == buffer_len)
+                });
+                if linewise_anchor.is_some() && cursor.position >= buffer_len {
+                    // Already extended to the end of the buffer
+                    continue;
+                }
+
                 // Use iterator to get line bounds
                 let mut iter = state
                     .buffer
@@ -2316,7 +2957,7 @@ pub fn action_to_events(
                         old_position: cursor.position,
                         new_position: line_end,
                         old_anchor: cursor.anchor,
-                        new_anchor: Some(line_start),
+                        new_anchor: Some(linewise_anchor.unwrap_or(line_start)),
                         old_sticky_column: cursor.sticky_column,
                         new_sticky_column: 0, // Reset sticky column
                     });
@@ -2413,7 +3054,7 @@ mod tests {
 
         // Press Backspace - should delete the newline at position 5
         let events =
-            action_to_events(&mut state, Action::DeleteBackward, 4, false, 80, 24).unwrap();
+            action_to_events(&mut state, Action::DeleteBackward, 4, false, false, 80, 24).unwrap();
         println!("Generated events: {:?}", events);
 
         for event in events {
@@ -2450,7 +3091,8 @@ mod tests {
         assert_eq!(state.cursors.primary().position, 0);
 
         // Move down - should go to position 6 (start of Line2)
-        let events = action_to_events(&mut state, Action::MoveDown, 4, false, 80, 24).unwrap();
+        let events =
+            action_to_events(&mut state, Action::MoveDown, 4, false, false, 80, 24).unwrap();
         assert_eq!(events.len(), 1);
 
         if let Event::MoveCursor { new_position, .. } = &events[0] {
@@ -2463,7 +3105,8 @@ mod tests {
         assert_eq!(state.cursors.primary().position, 6);
 
         // Move down again - should go to position 12 (start of Line3)
-        let events = action_to_events(&mut state, Action::MoveDown, 4, false, 80, 24).unwrap();
+        let events =
+            action_to_events(&mut state, Action::MoveDown, 4, false, false, 80, 24).unwrap();
         assert_eq!(events.len(), 1);
 
         if let Event::MoveCursor { new_position, .. } = &events[0] {
@@ -2495,7 +3138,7 @@ mod tests {
         // Should go to end of Line2 (position 11, which is the newline, BUT we want column 5 which is position 11)
         // Wait, Line2 has content "Line2" (5 chars), so column 5 is position 6+5=11 (the newline)
         // This is technically correct but weird - we're on the newline
-        let events = action_to_events(&mut state, Action::MoveUp, 4, false, 80, 24).unwrap();
+        let events = action_to_events(&mut state, Action::MoveUp, 4, false, false, 80, 24).unwrap();
         assert_eq!(events.len(), 1);
 
         if let Event::MoveCursor { new_position, .. } = &events[0] {
@@ -2516,7 +3159,7 @@ mod tests {
         // Current line is Line2 (starts at 6), column is 11-6=5
         // Previous line is Line1 (starts at 0), content "Line1" has length 5
         // So we go to position 0 + min(5, 5) = 5 (the newline after Line1)
-        let events = action_to_events(&mut state, Action::MoveUp, 4, false, 80, 24).unwrap();
+        let events = action_to_events(&mut state, Action::MoveUp, 4, false, false, 80, 24).unwrap();
         assert_eq!(events.len(), 1);
 
         if let Event::MoveCursor { new_position, .. } = &events[0] {
@@ -2555,7 +3198,8 @@ mod tests {
         assert_eq!(state.cursors.primary().position, 3);
 
         // Move down - should go to position 9 (column 3 of second line, which is end of "123")
-        let events = action_to_events(&mut state, Action::MoveDown, 4, false, 80, 24).unwrap();
+        let events =
+            action_to_events(&mut state, Action::MoveDown, 4, false, false, 80, 24).unwrap();
         assert_eq!(events.len(), 1);
 
         if let Event::MoveCursor {
@@ -2579,7 +3223,8 @@ mod tests {
         state.apply(&events[0]);
 
         // Move down again - should go to position 13 (column 3 of third line)
-        let events = action_to_events(&mut state, Action::MoveDown, 4, false, 80, 24).unwrap();
+        let events =
+            action_to_events(&mut state, Action::MoveDown, 4, false, false, 80, 24).unwrap();
         assert_eq!(events.len(), 1);
 
         if let Event::MoveCursor {
@@ -2621,7 +3266,7 @@ mod tests {
         assert_eq!(state.cursors.primary().position, 13);
 
         // Move up - should go to position 9 (column 3 of second line, which is end of "123")
-        let events = action_to_events(&mut state, Action::MoveUp, 4, false, 80, 24).unwrap();
+        let events = action_to_events(&mut state, Action::MoveUp, 4, false, false, 80, 24).unwrap();
         assert_eq!(events.len(), 1);
 
         if let Event::MoveCursor {
@@ -2645,7 +3290,7 @@ mod tests {
         state.apply(&events[0]);
 
         // Move up again - should go to position 3 (column 3 of first line)
-        let events = action_to_events(&mut state, Action::MoveUp, 4, false, 80, 24).unwrap();
+        let events = action_to_events(&mut state, Action::MoveUp, 4, false, false, 80, 24).unwrap();
         assert_eq!(events.len(), 1);
 
         if let Event::MoveCursor {
@@ -2685,7 +3330,8 @@ mod tests {
         });
 
         // Move down - should go to position 6 (start of second line)
-        let events = action_to_events(&mut state, Action::MoveDown, 4, false, 80, 24).unwrap();
+        let events =
+            action_to_events(&mut state, Action::MoveDown, 4, false, false, 80, 24).unwrap();
         assert_eq!(events.len(), 1);
 
         if let Event::MoveCursor { new_position, .. } = &events[0] {
@@ -2719,7 +3365,7 @@ mod tests {
         });
 
         // Move up - should go to position 0 (start of first line)
-        let events = action_to_events(&mut state, Action::MoveUp, 4, false, 80, 24).unwrap();
+        let events = action_to_events(&mut state, Action::MoveUp, 4, false, false, 80, 24).unwrap();
         assert_eq!(events.len(), 1);
 
         if let Event::MoveCursor { new_position, .. } = &events[0] {
@@ -2756,7 +3402,8 @@ mod tests {
         });
 
         // Move down - should go to position 6 (empty line)
-        let events = action_to_events(&mut state, Action::MoveDown, 4, false, 80, 24).unwrap();
+        let events =
+            action_to_events(&mut state, Action::MoveDown, 4, false, false, 80, 24).unwrap();
         if let Event::MoveCursor { new_position, .. } = &events[0] {
             assert_eq!(*new_position, 6, "Cursor should move to empty line");
         }
@@ -2764,7 +3411,8 @@ mod tests {
         state.apply(&events[0]);
 
         // Move down again - should go to position 7 (start of Line3)
-        let events = action_to_events(&mut state, Action::MoveDown, 4, false, 80, 24).unwrap();
+        let events =
+            action_to_events(&mut state, Action::MoveDown, 4, false, false, 80, 24).unwrap();
         if let Event::MoveCursor { new_position, .. } = &events[0] {
             assert_eq!(*new_position, 7, "Cursor should move to Line3");
         }
@@ -2794,7 +3442,7 @@ mod tests {
         });
 
         // Try to move up (no previous line exists)
-        let events = action_to_events(&mut state, Action::MoveUp, 4, false, 80, 24).unwrap();
+        let events = action_to_events(&mut state, Action::MoveUp, 4, false, false, 80, 24).unwrap();
         assert_eq!(
             events.len(),
             0,
@@ -2802,7 +3450,8 @@ mod tests {
         );
 
         // Try to move down (no next line exists)
-        let events = action_to_events(&mut state, Action::MoveDown, 4, false, 80, 24).unwrap();
+        let events =
+            action_to_events(&mut state, Action::MoveDown, 4, false, false, 80, 24).unwrap();
         assert_eq!(
             events.len(),
             0,
@@ -2905,7 +3554,8 @@ mod tests {
         });
 
         // Move to line end
-        let events = action_to_events(&mut state, Action::MoveLineEnd, 4, false, 80, 24).unwrap();
+        let events =
+            action_to_events(&mut state, Action::MoveLineEnd, 4, false, false, 80, 24).unwrap();
         for event in events {
             println!("MoveLineEnd event: {:?}", event);
             state.apply(&event);
@@ -2948,7 +3598,8 @@ mod tests {
         );
 
         // Move to line start
-        let events = action_to_events(&mut state, Action::MoveLineStart, 4, false, 80, 24).unwrap();
+        let events =
+            action_to_events(&mut state, Action::MoveLineStart, 4, false, false, 80, 24).unwrap();
         for event in events {
             println!("MoveLineStart event from EOF: {:?}", event);
             state.apply(&event);
@@ -3011,7 +3662,7 @@ mod tests {
         );
 
         // Try to move up - this should work even if chunks aren't loaded
-        let events = action_to_events(&mut state, Action::MoveUp, 4, false, 80, 24).unwrap();
+        let events = action_to_events(&mut state, Action::MoveUp, 4, false, false, 80, 24).unwrap();
         println!("MoveUp events: {:?}", events);
 
         assert!(
@@ -3075,7 +3726,8 @@ mod tests {
         );
 
         // Move down to second line
-        let events = action_to_events(&mut state, Action::MoveDown, 4, false, 80, 24).unwrap();
+        let events =
+            action_to_events(&mut state, Action::MoveDown, 4, false, false, 80, 24).unwrap();
         println!("MoveDown events: {:?}", events);
 
         if events.is_empty() {
@@ -3117,7 +3769,7 @@ mod tests {
         assert_eq!(state.cursors.primary().position, 20); // End of text
 
         // Move up to first line
-        let events = action_to_events(&mut state, Action::MoveUp, 4, false, 80, 24).unwrap();
+        let events = action_to_events(&mut state, Action::MoveUp, 4, false, false, 80, 24).unwrap();
         for event in events {
             state.apply(&event);
         }
@@ -3127,7 +3779,8 @@ mod tests {
         );
 
         // Move to end of first line
-        let events = action_to_events(&mut state, Action::MoveLineEnd, 4, false, 80, 24).unwrap();
+        let events =
+            action_to_events(&mut state, Action::MoveLineEnd, 4, false, false, 80, 24).unwrap();
         for event in events {
             state.apply(&event);
         }
@@ -3138,7 +3791,8 @@ mod tests {
         );
 
         // Move down to second line
-        let events = action_to_events(&mut state, Action::MoveDown, 4, false, 80, 24).unwrap();
+        let events =
+            action_to_events(&mut state, Action::MoveDown, 4, false, false, 80, 24).unwrap();
         for event in events {
             state.apply(&event);
         }
@@ -3148,7 +3802,8 @@ mod tests {
         );
 
         // Move to start of line (Home)
-        let events = action_to_events(&mut state, Action::MoveLineStart, 4, false, 80, 24).unwrap();
+        let events =
+            action_to_events(&mut state, Action::MoveLineStart, 4, false, false, 80, 24).unwrap();
         for event in events {
             state.apply(&event);
         }
@@ -3161,7 +3816,7 @@ mod tests {
 
         // Delete backward (should delete the newline)
         let events =
-            action_to_events(&mut state, Action::DeleteBackward, 4, false, 80, 24).unwrap();
+            action_to_events(&mut state, Action::DeleteBackward, 4, false, false, 80, 24).unwrap();
         for event in events.iter() {
             println!("Event: {:?}", event);
             state.apply(event);
@@ -3197,7 +3852,7 @@ mod tests {
 
         // Insert opening parenthesis with auto_indent=true
         let events =
-            action_to_events(&mut state, Action::InsertChar('('), 4, true, 80, 24).unwrap();
+            action_to_events(&mut state, Action::InsertChar('('), 4, true, true, 80, 24).unwrap();
         println!("Events: {:?}", events);
 
         // Should have Insert event for "()" and MoveCursor to position between them
@@ -3223,7 +3878,7 @@ mod tests {
 
         // Insert opening curly brace with auto_indent=true
         let events =
-            action_to_events(&mut state, Action::InsertChar('{'), 4, true, 80, 24).unwrap();
+            action_to_events(&mut state, Action::InsertChar('{'), 4, true, true, 80, 24).unwrap();
 
         for event in events {
             state.apply(&event);
@@ -3244,7 +3899,7 @@ mod tests {
 
         // Insert opening square bracket
         let events =
-            action_to_events(&mut state, Action::InsertChar('['), 4, true, 80, 24).unwrap();
+            action_to_events(&mut state, Action::InsertChar('['), 4, true, true, 80, 24).unwrap();
 
         for event in events {
             state.apply(&event);
@@ -3262,7 +3917,7 @@ mod tests {
 
         // Insert double quote
         let events =
-            action_to_events(&mut state, Action::InsertChar('"'), 4, true, 80, 24).unwrap();
+            action_to_events(&mut state, Action::InsertChar('"'), 4, true, true, 80, 24).unwrap();
 
         for event in events {
             state.apply(&event);
@@ -3273,13 +3928,13 @@ mod tests {
     }
 
     #[test]
-    fn test_bracket_auto_close_disabled_when_auto_indent_false() {
+    fn test_bracket_auto_close_disabled_when_auto_close_false() {
         let mut state =
             EditorState::new(80, 24, crate::config::LARGE_FILE_THRESHOLD_BYTES as usize);
 
-        // Insert opening parenthesis with auto_indent=false
+        // Insert opening parenthesis with auto_close=false (auto-indent stays on)
         let events =
-            action_to_events(&mut state, Action::InsertChar('('), 4, false, 80, 24).unwrap();
+            action_to_events(&mut state, Action::InsertChar('('), 4, true, false, 80, 24).unwrap();
 
         for event in events {
             state.apply(&event);
@@ -3315,7 +3970,7 @@ mod tests {
 
         // Insert opening parenthesis before 'abc'
         let events =
-            action_to_events(&mut state, Action::InsertChar('('), 4, true, 80, 24).unwrap();
+            action_to_events(&mut state, Action::InsertChar('('), 4, true, true, 80, 24).unwrap();
 
         for event in events {
             state.apply(&event);
@@ -3368,7 +4023,7 @@ mod tests {
 
         // Insert opening parenthesis at both cursors
         let events =
-            action_to_events(&mut state, Action::InsertChar('('), 4, true, 80, 24).unwrap();
+            action_to_events(&mut state, Action::InsertChar('('), 4, true, true, 80, 24).unwrap();
 
         for event in events {
             state.apply(&event);
@@ -3411,21 +4066,21 @@ mod tests {
 
         // Type 'f'
         let events =
-            action_to_events(&mut state, Action::InsertChar('f'), 4, true, 80, 24).unwrap();
+            action_to_events(&mut state, Action::InsertChar('f'), 4, true, true, 80, 24).unwrap();
         for event in events {
             state.apply(&event);
         }
 
         // Type 'o'
         let events =
-            action_to_events(&mut state, Action::InsertChar('o'), 4, true, 80, 24).unwrap();
+            action_to_events(&mut state, Action::InsertChar('o'), 4, true, true, 80, 24).unwrap();
         for event in events {
             state.apply(&event);
         }
 
         // Type 'o'
         let events =
-            action_to_events(&mut state, Action::InsertChar('o'), 4, true, 80, 24).unwrap();
+            action_to_events(&mut state, Action::InsertChar('o'), 4, true, true, 80, 24).unwrap();
         for event in events {
             state.apply(&event);
         }
@@ -3439,7 +4094,7 @@ mod tests {
 
         // Type '(' - should auto-close to '()'
         let events =
-            action_to_events(&mut state, Action::InsertChar('('), 4, true, 80, 24).unwrap();
+            action_to_events(&mut state, Action::InsertChar('('), 4, true, true, 80, 24).unwrap();
         for event in events {
             state.apply(&event);
         }
@@ -3465,7 +4120,7 @@ mod tests {
 
         // Type ')' - should skip over the existing ')', not add another
         let events =
-            action_to_events(&mut state, Action::InsertChar(')'), 4, true, 80, 24).unwrap();
+            action_to_events(&mut state, Action::InsertChar(')'), 4, true, true, 80, 24).unwrap();
         for event in events {
             state.apply(&event);
         }
@@ -3520,7 +4175,8 @@ mod tests {
         // Type 'foo'
         for ch in ['f', 'o', 'o'] {
             let events =
-                action_to_events(&mut state, Action::InsertChar(ch), 4, true, 80, 24).unwrap();
+                action_to_events(&mut state, Action::InsertChar(ch), 4, true, true, 80, 24)
+                    .unwrap();
             for event in events {
                 state.apply(&event);
             }
@@ -3535,7 +4191,7 @@ mod tests {
 
         // Type '(' - should auto-close to '()'
         let events =
-            action_to_events(&mut state, Action::InsertChar('('), 4, true, 80, 24).unwrap();
+            action_to_events(&mut state, Action::InsertChar('('), 4, true, true, 80, 24).unwrap();
         for event in events {
             state.apply(&event);
         }
@@ -3562,7 +4218,7 @@ mod tests {
 
         // Type ')' - should skip over the existing ')', not add another
         let events =
-            action_to_events(&mut state, Action::InsertChar(')'), 4, true, 80, 24).unwrap();
+            action_to_events(&mut state, Action::InsertChar(')'), 4, true, true, 80, 24).unwrap();
         for event in events {
             state.apply(&event);
         }
@@ -3601,8 +4257,9 @@ mod tests {
         assert_eq!(state.buffer.to_string().unwrap(), "()");
         assert_eq!(state.cursors.primary().position, 1);
 
-        // Delete backward with auto_indent=true - should delete both characters
-        let events = action_to_events(&mut state, Action::DeleteBackward, 4, true, 80, 24).unwrap();
+        // Delete backward with auto_close=true - should delete both characters
+        let events =
+            action_to_events(&mut state, Action::DeleteBackward, 4, true, true, 80, 24).unwrap();
 
         for event in events {
             state.apply(&event);
@@ -3636,7 +4293,8 @@ mod tests {
         });
 
         // Delete backward - should delete both
-        let events = action_to_events(&mut state, Action::DeleteBackward, 4, true, 80, 24).unwrap();
+        let events =
+            action_to_events(&mut state, Action::DeleteBackward, 4, true, true, 80, 24).unwrap();
 
         for event in events {
             state.apply(&event);
@@ -3669,7 +4327,8 @@ mod tests {
         });
 
         // Delete backward - should delete both quotes
-        let events = action_to_events(&mut state, Action::DeleteBackward, 4, true, 80, 24).unwrap();
+        let events =
+            action_to_events(&mut state, Action::DeleteBackward, 4, true, true, 80, 24).unwrap();
 
         for event in events {
             state.apply(&event);
@@ -3679,7 +4338,7 @@ mod tests {
     }
 
     #[test]
-    fn test_auto_pair_deletion_disabled_when_auto_indent_false() {
+    fn test_auto_pair_deletion_disabled_when_auto_close_false() {
         let mut state =
             EditorState::new(80, 24, crate::config::LARGE_FILE_THRESHOLD_BYTES as usize);
 
@@ -3701,9 +4360,9 @@ mod tests {
             new_sticky_column: 0,
         });
 
-        // Delete backward with auto_indent=false - should only delete opening bracket
+        // Delete backward with auto_close=false - should only delete opening bracket
         let events =
-            action_to_events(&mut state, Action::DeleteBackward, 4, false, 80, 24).unwrap();
+            action_to_events(&mut state, Action::DeleteBackward, 4, true, false, 80, 24).unwrap();
 
         for event in events {
             state.apply(&event);
@@ -3737,7 +4396,8 @@ mod tests {
         });
 
         // Delete backward - should only delete opening bracket since they don't match
-        let events = action_to_events(&mut state, Action::DeleteBackward, 4, true, 80, 24).unwrap();
+        let events =
+            action_to_events(&mut state, Action::DeleteBackward, 4, true, true, 80, 24).unwrap();
 
         for event in events {
             state.apply(&event);
@@ -3771,7 +4431,8 @@ mod tests {
         });
 
         // Delete backward - should only delete 'a', not both brackets
-        let events = action_to_events(&mut state, Action::DeleteBackward, 4, true, 80, 24).unwrap();
+        let events =
+            action_to_events(&mut state, Action::DeleteBackward, 4, true, true, 80, 24).unwrap();
 
         for event in events {
             state.apply(&event);
@@ -3779,6 +4440,275 @@ mod tests {
 
         assert_eq!(state.buffer.to_string().unwrap(), "(bc)");
     }
+
+    /// Apply the MoveCursor event for `action` and return the new position
+    fn move_by(state: &mut EditorState, action: Action, viewport_height: u16) -> usize {
+        let events = action_to_events(state, action, 4, false, false, 80, viewport_height).unwrap();
+        assert_eq!(events.len(), 1);
+        state.apply(&events[0]);
+        state.cursors.primary().position
+    }
+
+    #[test]
+    fn test_half_page_moves_keep_goal_column() {
+        let mut state =
+            EditorState::new(80, 24, crate::config::LARGE_FILE_THRESHOLD_BYTES as usize);
+        // Every line is "lineNN" (6 bytes + newline), except an empty line 10
+        let text: String = (0..40)
+            .map(|i| {
+                if i == 10 {
+                    "\n".to_string()
+                } else {
+                    format!("line{i:02}\n")
+                }
+            })
+            .collect();
+        state.apply(&Event::Insert {
+            position: 0,
+            text: text.clone(),
+            cursor_id: CursorId(0),
+        });
+        let line_start =
+            |line: usize| -> usize { text.split_inclusive('\n').take(line).map(str::len).sum() };
+
+        state.apply(&Event::MoveCursor {
+            cursor_id: CursorId(0),
+            old_position: text.len(),
+            new_position: 3,
+            old_anchor: None,
+            new_anchor: None,
+            old_sticky_column: 0,
+            new_sticky_column: 0,
+        });
+
+        // Half of a 20-line viewport lands on the short line, clamped to its end
+        assert_eq!(
+            move_by(&mut state, Action::MoveHalfPageDown, 20),
+            line_start(10)
+        );
+        // The goal column is restored on the next full-length line
+        assert_eq!(
+            move_by(&mut state, Action::MoveHalfPageDown, 20),
+            line_start(20) + 3
+        );
+        assert_eq!(
+            move_by(&mut state, Action::MoveHalfPageUp, 20),
+            line_start(10)
+        );
+        assert_eq!(move_by(&mut state, Action::MoveHalfPageUp, 20), 3);
+
+        // Moving past either end of the buffer clamps like a page move
+        assert_eq!(move_by(&mut state, Action::MoveHalfPageUp, 20), 0);
+        assert_eq!(
+            move_by(&mut state, Action::MoveHalfPageDown, 100),
+            text.len()
+        );
+    }
+
+    #[test]
+    fn test_goal_column_survives_mixed_vertical_moves() {
+        let mut state =
+            EditorState::new(80, 24, crate::config::LARGE_FILE_THRESHOLD_BYTES as usize);
+        // "é" is two bytes but one column wide
+        let text = "xxxxxxxxxx\nab\néééééé\nab\nxxxxxxxxxx\n";
+        state.apply(&Event::Insert {
+            position: 0,
+            text: text.to_string(),
+            cursor_id: CursorId(0),
+        });
+        let line_start =
+            |line: usize| -> usize { text.split_inclusive('\n').take(line).map(str::len).sum() };
+        state.apply(&Event::MoveCursor {
+            cursor_id: CursorId(0),
+            old_position: text.len(),
+            new_position: 5,
+            old_anchor: None,
+            new_anchor: None,
+            old_sticky_column: 0,
+            new_sticky_column: 0,
+        });
+
+        // Clamped on the short line, then restored in columns rather than bytes
+        assert_eq!(move_by(&mut state, Action::MoveDown, 24), line_start(1) + 2);
+        assert_eq!(
+            move_by(&mut state, Action::MoveHalfPageDown, 2),
+            line_start(2) + "ééééé".len()
+        );
+        assert_eq!(
+            move_by(&mut state, Action::SelectDown, 24),
+            line_start(3) + 2
+        );
+        assert_eq!(
+            move_by(&mut state, Action::SelectPageDown, 2),
+            line_start(4) + 5
+        );
+        assert_eq!(
+            move_by(&mut state, Action::SelectPageUp, 3),
+            line_start(2) + "ééééé".len()
+        );
+        assert_eq!(move_by(&mut state, Action::MoveUp, 24), line_start(1) + 2);
+        assert_eq!(move_by(&mut state, Action::MoveUp, 24), 5);
+    }
+
+    #[test]
+    fn test_goal_column_restored_after_clamping_down_and_back_up() {
+        let mut state =
+            EditorState::new(80, 24, crate::config::LARGE_FILE_THRESHOLD_BYTES as usize);
+        // "日本語" is three bytes and two columns per character, "é" is two
+        // bytes and one column
+        let text = "日本語abcd\né\n\nab\n日本語abcd\n";
+        state.apply(&Event::Insert {
+            position: 0,
+            text: text.to_string(),
+            cursor_id: CursorId(0),
+        });
+        let line_start =
+            |line: usize| -> usize { text.split_inclusive('\n').take(line).map(str::len).sum() };
+        // After "日本語ab", visual column 8
+        let start = "日本語ab".len();
+        state.apply(&Event::MoveCursor {
+            cursor_id: CursorId(0),
+            old_position: text.len(),
+            new_position: start,
+            old_anchor: None,
+            new_anchor: None,
+            old_sticky_column: 0,
+            new_sticky_column: 0,
+        });
+
+        // Each shorter line clamps to its end while the goal column stays at 8
+        let down = [
+            line_start(1) + "é".len(),
+            line_start(2),
+            line_start(3) + 2,
+            line_start(4) + start,
+        ];
+        for expected in down {
+            assert_eq!(move_by(&mut state, Action::MoveDown, 24), expected);
+            assert_eq!(state.cursors.primary().sticky_column, 8);
+        }
+
+        // Coming back up restores the original column, not a clamped one
+        for expected in [line_start(3) + 2, line_start(2), line_start(1) + "é".len()] {
+            assert_eq!(move_by(&mut state, Action::MoveUp, 24), expected);
+        }
+        assert_eq!(move_by(&mut state, Action::MoveUp, 24), start);
+        assert_eq!(state.cursors.primary().sticky_column, 8);
+    }
+
+    #[test]
+    fn test_scroll_half_page() {
+        let mut state =
+            EditorState::new(80, 24, crate::config::LARGE_FILE_THRESHOLD_BYTES as usize);
+
+        let events = action_to_events(
+            &mut state,
+            Action::ScrollHalfPageDown,
+            4,
+            false,
+            false,
+            80,
+            21,
+        )
+        .unwrap();
+        assert!(matches!(events[..], [Event::Scroll { line_offset: 10 }]));
+
+        let events = action_to_events(
+            &mut state,
+            Action::ScrollHalfPageUp,
+            4,
+            false,
+            false,
+            80,
+            21,
+        )
+        .unwrap();
+        assert!(matches!(events[..], [Event::Scroll { line_offset: -10 }]));
+
+        // A one-line viewport still scrolls
+        let events =
+            action_to_events(&mut state, Action::ScrollHalfPageUp, 4, false, false, 80, 1).unwrap();
+        assert!(matches!(events[..], [Event::Scroll { line_offset: -1 }]));
+    }
+
+    #[test]
+    fn test_subword_moves_and_selects() {
+        let mut state =
+            EditorState::new(80, 24, crate::config::LARGE_FILE_THRESHOLD_BYTES as usize);
+        state.apply(&Event::Insert {
+            position: 0,
+            text: "getHTTPResponseCode".to_string(),
+            cursor_id: CursorId(0),
+        });
+
+        assert_eq!(move_by(&mut state, Action::MoveSubwordLeft, 24), 15);
+        assert_eq!(move_by(&mut state, Action::MoveSubwordLeft, 24), 7);
+        assert_eq!(state.cursors.primary().anchor, None);
+
+        assert_eq!(move_by(&mut state, Action::SelectSubwordLeft, 24), 3);
+        assert_eq!(move_by(&mut state, Action::SelectSubwordRight, 24), 7);
+        assert_eq!(move_by(&mut state, Action::SelectSubwordRight, 24), 15);
+        assert_eq!(state.cursors.primary().anchor, Some(7));
+    }
+
+    #[test]
+    fn test_paragraph_moves_stop_at_blank_lines() {
+        let mut state =
+            EditorState::new(80, 24, crate::config::LARGE_FILE_THRESHOLD_BYTES as usize);
+        // Blank lines at 2 and 5-6 (one whitespace-only), text ends without a blank line
+        let text = "one\ntwo\n\nthree\nfour\n  \n\nfive\nsix";
+        state.apply(&Event::Insert {
+            position: 0,
+            text: text.to_string(),
+            cursor_id: CursorId(0),
+        });
+        let line_start =
+            |line: usize| -> usize { text.split_inclusive('\n').take(line).map(str::len).sum() };
+        state.apply(&Event::MoveCursor {
+            cursor_id: CursorId(0),
+            old_position: text.len(),
+            new_position: 1,
+            old_anchor: None,
+            new_anchor: None,
+            old_sticky_column: 0,
+            new_sticky_column: 0,
+        });
+
+        assert_eq!(
+            move_by(&mut state, Action::MoveParagraphDown, 24),
+            line_start(2)
+        );
+        // The run of blank lines is a single boundary
+        assert_eq!(
+            move_by(&mut state, Action::MoveParagraphDown, 24),
+            line_start(5)
+        );
+        assert_eq!(
+            move_by(&mut state, Action::MoveParagraphDown, 24),
+            text.len()
+        );
+
+        assert_eq!(
+            move_by(&mut state, Action::MoveParagraphUp, 24),
+            line_start(6)
+        );
+        assert_eq!(
+            move_by(&mut state, Action::MoveParagraphUp, 24),
+            line_start(2)
+        );
+        assert_eq!(move_by(&mut state, Action::MoveParagraphUp, 24), 0);
+        assert_eq!(state.cursors.primary().anchor, None);
+
+        assert_eq!(
+            move_by(&mut state, Action::SelectParagraphDown, 24),
+            line_start(2)
+        );
+        assert_eq!(
+            move_by(&mut state, Action::SelectParagraphDown, 24),
+            line_start(5)
+        );
+        assert_eq!(state.cursors.primary().anchor, Some(0));
+    }
 }
 
 #[cfg(test)]
@@ -245,6 +245,22 @@ pub fn get_all_commands() -> Vec<Command> {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
         },
+        Command {
+            name: t!("cmd.save_session").to_string(),
+            description: t!("cmd.save_session_desc").to_string(),
+            action: Action::SaveSession,
+            contexts: vec![],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.restore_session").to_string(),
+            description: t!("cmd.restore_session_desc").to_string(),
+            action: Action::RestoreSession,
+            contexts: vec![],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
         // Edit operations
         Command {
             name: t!("cmd.undo").to_string(),
@@ -262,6 +278,14 @@ pub fn get_all_commands() -> Vec<Command> {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
         },
+        Command {
+            name: t!("cmd.repeat_last_edit").to_string(),
+            description: t!("cmd.repeat_last_edit_desc").to_string(),
+            action: Action::RepeatLastEdit,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
         Command {
             name: t!("cmd.copy").to_string(),
             description: t!("cmd.copy_desc").to_string(),
@@ -286,6 +310,38 @@ pub fn get_all_commands() -> Vec<Command> {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
         },
+        Command {
+            name: t!("cmd.extract_selection_to_new_buffer").to_string(),
+            description: t!("cmd.extract_selection_to_new_buffer_desc").to_string(),
+            action: Action::ExtractSelectionToNewBuffer,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.move_selection_to_new_buffer").to_string(),
+            description: t!("cmd.move_selection_to_new_buffer_desc").to_string(),
+            action: Action::MoveSelectionToNewBuffer,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.export_html").to_string(),
+            description: t!("cmd.export_html_desc").to_string(),
+            action: Action::ExportHtml,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.export_html_with_line_numbers").to_string(),
+            description: t!("cmd.export_html_with_line_numbers_desc").to_string(),
+            action: Action::ExportHtmlWithLineNumbers,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
         Command {
             name: t!("cmd.paste").to_string(),
             description: t!("cmd.paste_desc").to_string(),
@@ -294,6 +350,30 @@ pub fn get_all_commands() -> Vec<Command> {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
         },
+        Command {
+            name: t!("cmd.paste_and_indent").to_string(),
+            description: t!("cmd.paste_and_indent_desc").to_string(),
+            action: Action::PasteAndIndent,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.yank_pop").to_string(),
+            description: t!("cmd.yank_pop_desc").to_string(),
+            action: Action::YankPop,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.select_register").to_string(),
+            description: t!("cmd.select_register_desc").to_string(),
+            action: Action::PromptSelectRegister,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
         Command {
             name: t!("cmd.delete_line").to_string(),
             description: t!("cmd.delete_line_desc").to_string(),
@@ -326,6 +406,14 @@ pub fn get_all_commands() -> Vec<Command> {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
         },
+        Command {
+            name: t!("cmd.delete_to_start_of_line").to_string(),
+            description: t!("cmd.delete_to_start_of_line_desc").to_string(),
+            action: Action::DeleteToLineStart,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
         Command {
             name: t!("cmd.transpose_characters").to_string(),
             description: t!("cmd.transpose_characters_desc").to_string(),
@@ -358,6 +446,30 @@ pub fn get_all_commands() -> Vec<Command> {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
         },
+        Command {
+            name: t!("cmd.open_line_below").to_string(),
+            description: t!("cmd.open_line_below_desc").to_string(),
+            action: Action::OpenLineBelow,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.open_line_above").to_string(),
+            description: t!("cmd.open_line_above_desc").to_string(),
+            action: Action::OpenLineAbove,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.toggle_insert_mode").to_string(),
+            description: t!("cmd.toggle_insert_mode_desc").to_string(),
+            action: Action::ToggleInsertMode,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
         Command {
             name: t!("cmd.recenter").to_string(),
             description: t!("cmd.recenter_desc").to_string(),
@@ -366,6 +478,22 @@ pub fn get_all_commands() -> Vec<Command> {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
         },
+        Command {
+            name: t!("cmd.scroll_cursor_to_top").to_string(),
+            description: t!("cmd.scroll_cursor_to_top_desc").to_string(),
+            action: Action::ScrollCursorToTop,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.scroll_cursor_to_bottom").to_string(),
+            description: t!("cmd.scroll_cursor_to_bottom_desc").to_string(),
+            action: Action::ScrollCursorToBottom,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
         Command {
             name: t!("cmd.set_mark").to_string(),
             description: t!("cmd.set_mark_desc").to_string(),
@@ -530,6 +658,38 @@ pub fn get_all_commands() -> Vec<Command> {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
         },
+        Command {
+            name: t!("cmd.equalize_splits").to_string(),
+            description: t!("cmd.equalize_splits_desc").to_string(),
+            action: Action::EqualizeSplits,
+            contexts: vec![KeyContext::Normal, KeyContext::Terminal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.move_buffer_to_next_split").to_string(),
+            description: t!("cmd.move_buffer_to_next_split_desc").to_string(),
+            action: Action::MoveBufferToNextSplit,
+            contexts: vec![KeyContext::Normal, KeyContext::Terminal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.move_buffer_to_prev_split").to_string(),
+            description: t!("cmd.move_buffer_to_prev_split_desc").to_string(),
+            action: Action::MoveBufferToPrevSplit,
+            contexts: vec![KeyContext::Normal, KeyContext::Terminal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.swap_buffer_with_next_split").to_string(),
+            description: t!("cmd.swap_buffer_with_next_split_desc").to_string(),
+            action: Action::SwapBufferWithNextSplit,
+            contexts: vec![KeyContext::Normal, KeyContext::Terminal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
         Command {
             name: t!("cmd.toggle_maximize_split").to_string(),
             description: t!("cmd.toggle_maximize_split_desc").to_string(),
@@ -580,6 +740,22 @@ pub fn get_all_commands() -> Vec<Command> {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
         },
+        Command {
+            name: t!("cmd.convert_indentation_to_spaces").to_string(),
+            description: t!("cmd.convert_indentation_to_spaces_desc").to_string(),
+            action: Action::ConvertIndentationToSpaces,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.convert_indentation_to_tabs").to_string(),
+            description: t!("cmd.convert_indentation_to_tabs_desc").to_string(),
+            action: Action::ConvertIndentationToTabs,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
         Command {
             name: t!("cmd.toggle_tab_indicators").to_string(),
             description: t!("cmd.toggle_tab_indicators_desc").to_string(),
@@ -612,6 +788,118 @@ pub fn get_all_commands() -> Vec<Command> {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
         },
+        Command {
+            name: t!("cmd.scroll_half_page_up").to_string(),
+            description: t!("cmd.scroll_half_page_up_desc").to_string(),
+            action: Action::ScrollHalfPageUp,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.scroll_half_page_down").to_string(),
+            description: t!("cmd.scroll_half_page_down_desc").to_string(),
+            action: Action::ScrollHalfPageDown,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.scroll_left").to_string(),
+            description: t!("cmd.scroll_left_desc").to_string(),
+            action: Action::ScrollLeft,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.scroll_right").to_string(),
+            description: t!("cmd.scroll_right_desc").to_string(),
+            action: Action::ScrollRight,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.move_half_page_up").to_string(),
+            description: t!("cmd.move_half_page_up_desc").to_string(),
+            action: Action::MoveHalfPageUp,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.move_half_page_down").to_string(),
+            description: t!("cmd.move_half_page_down_desc").to_string(),
+            action: Action::MoveHalfPageDown,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.move_subword_left").to_string(),
+            description: t!("cmd.move_subword_left_desc").to_string(),
+            action: Action::MoveSubwordLeft,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.move_subword_right").to_string(),
+            description: t!("cmd.move_subword_right_desc").to_string(),
+            action: Action::MoveSubwordRight,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.select_subword_left").to_string(),
+            description: t!("cmd.select_subword_left_desc").to_string(),
+            action: Action::SelectSubwordLeft,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.select_subword_right").to_string(),
+            description: t!("cmd.select_subword_right_desc").to_string(),
+            action: Action::SelectSubwordRight,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.move_paragraph_up").to_string(),
+            description: t!("cmd.move_paragraph_up_desc").to_string(),
+            action: Action::MoveParagraphUp,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.move_paragraph_down").to_string(),
+            description: t!("cmd.move_paragraph_down_desc").to_string(),
+            action: Action::MoveParagraphDown,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.select_paragraph_up").to_string(),
+            description: t!("cmd.select_paragraph_up_desc").to_string(),
+            action: Action::SelectParagraphUp,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.select_paragraph_down").to_string(),
+            description: t!("cmd.select_paragraph_down_desc").to_string(),
+            action: Action::SelectParagraphDown,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
         Command {
             name: t!("cmd.scroll_tabs_left").to_string(),
             description: t!("cmd.scroll_tabs_left_desc").to_string(),
@@ -673,6 +961,22 @@ pub fn get_all_commands() -> Vec<Command> {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
         },
+        Command {
+            name: t!("cmd.toggle_cursor_details").to_string(),
+            description: t!("cmd.toggle_cursor_details_desc").to_string(),
+            action: Action::ToggleCursorDetails,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.show_word_count").to_string(),
+            description: t!("cmd.show_word_count_desc").to_string(),
+            action: Action::ShowWordCount,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
         Command {
             name: t!("cmd.focus_file_explorer").to_string(),
             description: t!("cmd.focus_file_explorer_desc").to_string(),
@@ -729,6 +1033,14 @@ pub fn get_all_commands() -> Vec<Command> {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
         },
+        Command {
+            name: t!("cmd.explorer_filter").to_string(),
+            description: t!("cmd.explorer_filter_desc").to_string(),
+            action: Action::FileExplorerFilter,
+            contexts: vec![KeyContext::FileExplorer],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
         Command {
             name: t!("cmd.toggle_hidden_files").to_string(),
             description: t!("cmd.toggle_hidden_files_desc").to_string(),
@@ -754,6 +1066,38 @@ pub fn get_all_commands() -> Vec<Command> {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
         },
+        Command {
+            name: t!("cmd.toggle_indent_guides").to_string(),
+            description: t!("cmd.toggle_indent_guides_desc").to_string(),
+            action: Action::ToggleIndentGuides,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.toggle_render_whitespace").to_string(),
+            description: t!("cmd.toggle_render_whitespace_desc").to_string(),
+            action: Action::ToggleRenderWhitespace,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.toggle_render_trailing_whitespace").to_string(),
+            description: t!("cmd.toggle_render_trailing_whitespace_desc").to_string(),
+            action: Action::ToggleRenderTrailingWhitespace,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.toggle_sticky_scroll").to_string(),
+            description: t!("cmd.toggle_sticky_scroll_desc").to_string(),
+            action: Action::ToggleStickyScroll,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
         // Note: Compose mode commands removed - markdown_compose plugin provides these
         Command {
             name: t!("cmd.set_background").to_string(),
@@ -806,6 +1150,14 @@ pub fn get_all_commands() -> Vec<Command> {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
         },
+        Command {
+            name: t!("cmd.clear_search_highlight").to_string(),
+            description: t!("cmd.clear_search_highlight_desc").to_string(),
+            action: Action::ClearSearchHighlight,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
         Command {
             name: t!("cmd.find_selection_next").to_string(),
             description: t!("cmd.find_selection_next_desc").to_string(),
@@ -830,6 +1182,14 @@ pub fn get_all_commands() -> Vec<Command> {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
         },
+        Command {
+            name: t!("cmd.replace_all").to_string(),
+            description: t!("cmd.replace_all_desc").to_string(),
+            action: Action::ReplaceAll,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
         Command {
             name: t!("cmd.query_replace").to_string(),
             description: t!("cmd.query_replace_desc").to_string(),
@@ -838,6 +1198,14 @@ pub fn get_all_commands() -> Vec<Command> {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
         },
+        Command {
+            name: t!("cmd.project_search").to_string(),
+            description: t!("cmd.project_search_desc").to_string(),
+            action: Action::ProjectSearch,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
         // Navigation
         Command {
             name: t!("cmd.goto_line").to_string(),
@@ -903,6 +1271,22 @@ pub fn get_all_commands() -> Vec<Command> {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
         },
+        Command {
+            name: t!("cmd.lsp_format").to_string(),
+            description: t!("cmd.lsp_format_desc").to_string(),
+            action: Action::LspFormat,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.lsp_document_symbols").to_string(),
+            description: t!("cmd.lsp_document_symbols_desc").to_string(),
+            action: Action::LspDocumentSymbols,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
         Command {
             name: t!("cmd.start_restart_lsp").to_string(),
             description: t!("cmd.start_restart_lsp_desc").to_string(),
@@ -952,6 +1336,54 @@ pub fn get_all_commands() -> Vec<Command> {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
         },
+        Command {
+            name: t!("cmd.align_on_delimiter").to_string(),
+            description: t!("cmd.align_on_delimiter_desc").to_string(),
+            action: Action::AlignOnDelimiter,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.sort_lines").to_string(),
+            description: t!("cmd.sort_lines_desc").to_string(),
+            action: Action::SortLines,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.sort_lines_descending").to_string(),
+            description: t!("cmd.sort_lines_descending_desc").to_string(),
+            action: Action::SortLinesDescending,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.sort_lines_case_insensitive").to_string(),
+            description: t!("cmd.sort_lines_case_insensitive_desc").to_string(),
+            action: Action::SortLinesCaseInsensitive,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.unique_lines").to_string(),
+            description: t!("cmd.unique_lines_desc").to_string(),
+            action: Action::UniqueLines,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.unique_adjacent_lines").to_string(),
+            description: t!("cmd.unique_adjacent_lines_desc").to_string(),
+            action: Action::UniqueAdjacentLines,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
         Command {
             name: t!("cmd.dedent_selection").to_string(),
             description: t!("cmd.dedent_selection_desc").to_string(),
@@ -985,6 +1417,23 @@ pub fn get_all_commands() -> Vec<Command> {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
         },
+        // Quickfix navigation
+        Command {
+            name: t!("cmd.quickfix_next").to_string(),
+            description: t!("cmd.quickfix_next_desc").to_string(),
+            action: Action::QuickfixNext,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
+        Command {
+            name: t!("cmd.quickfix_prev").to_string(),
+            description: t!("cmd.quickfix_prev_desc").to_string(),
+            action: Action::QuickfixPrev,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
         // LSP
         Command {
             name: t!("cmd.rename_symbol").to_string(),
@@ -1043,6 +1492,14 @@ pub fn get_all_commands() -> Vec<Command> {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
         },
+        Command {
+            name: t!("cmd.play_last_macro_times").to_string(),
+            description: t!("cmd.play_last_macro_times_desc").to_string(),
+            action: Action::PromptPlayLastMacroTimes,
+            contexts: vec![KeyContext::Normal],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
         Command {
             name: t!("cmd.set_bookmark").to_string(),
             description: t!("cmd.set_bookmark_desc").to_string(),
@@ -1076,6 +1533,14 @@ pub fn get_all_commands() -> Vec<Command> {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
         },
+        Command {
+            name: t!("cmd.show_keybindings").to_string(),
+            description: t!("cmd.show_keybindings_desc").to_string(),
+            action: Action::ShowKeybindings,
+            contexts: vec![],
+            custom_contexts: vec![],
+            source: CommandSource::Builtin,
+        },
         Command {
             name: t!("cmd.show_warnings").to_string(),
             description: t!("cmd.show_warnings_desc").to_string(),
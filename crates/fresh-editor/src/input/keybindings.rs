@@ -60,6 +60,7 @@ pub fn format_keybinding(keycode: &KeyCode, modifiers: &KeyModifiers) -> String
         KeyCode::Enter => result.push_str("Enter"),
         KeyCode::Backspace => result.push_str("Backspace"),
         KeyCode::Delete => result.push_str("Del"),
+        KeyCode::Insert => result.push_str("Ins"),
         KeyCode::Tab => result.push_str("Tab"),
         KeyCode::Esc => result.push_str("Esc"),
         KeyCode::Left => result.push('←'),
@@ -227,12 +228,21 @@ pub enum Action {
     MoveDown,
     MoveWordLeft,
     MoveWordRight,
+    MoveSubwordLeft,
+    MoveSubwordRight,
     MoveLineStart,
     MoveLineEnd,
     MovePageUp,
     MovePageDown,
+    MoveHalfPageUp,
+    MoveHalfPageDown,
+    MoveParagraphUp,
+    MoveParagraphDown,
     MoveDocumentStart,
     MoveDocumentEnd,
+    CursorToViewportTop,
+    CursorToViewportMiddle,
+    CursorToViewportBottom,
 
     // Selection movement (extends selection while moving)
     SelectLeft,
@@ -241,16 +251,22 @@ pub enum Action {
     SelectDown,
     SelectWordLeft,
     SelectWordRight,
+    SelectSubwordLeft,
+    SelectSubwordRight,
     SelectLineStart,
     SelectLineEnd,
     SelectDocumentStart,
     SelectDocumentEnd,
     SelectPageUp,
     SelectPageDown,
+    SelectParagraphUp,
+    SelectParagraphDown,
     SelectAll,
     SelectWord,
     SelectLine,
     ExpandSelection,
+    SelectTextObject(String), // Select a plugin-defined text object by name
+    DeleteTextObject(String), // Delete a plugin-defined text object by name
 
     // Block/rectangular selection (column-wise)
     BlockSelectLeft,
@@ -268,9 +284,15 @@ pub enum Action {
     DeleteToLineStart,
     TransposeChars,
     OpenLine,
+    OpenLineBelow,
+    OpenLineAbove,
+    /// Switch between inserting and overwriting typed characters
+    ToggleInsertMode,
 
     // View
     Recenter,
+    ScrollCursorToTop,
+    ScrollCursorToBottom,
 
     // Selection
     SetMark,
@@ -279,7 +301,16 @@ pub enum Action {
     Copy,
     CopyWithTheme(String),
     Cut,
+    ExtractSelectionToNewBuffer,
+    MoveSelectionToNewBuffer,
+    ExportHtml,
+    ExportHtmlWithLineNumbers,
     Paste,
+    PasteAndIndent,
+    YankPop,
+    CopyToRegister(char),
+    PasteFromRegister(char),
+    PromptSelectRegister,
 
     // Vi-style yank (copy without selection, then restore cursor)
     YankWordForward,
@@ -303,20 +334,32 @@ pub enum Action {
     CloseTab,
     Quit,
     ForceQuit,
+    SaveSession,
+    RestoreSession,
     Revert,
+    ForceRevert,
     ToggleAutoRevert,
     FormatBuffer,
 
     // Navigation
     GotoLine,
+    GotoPercent(usize), // Jump to a percentage through the buffer
     GoToMatchingBracket,
     JumpToNextError,
     JumpToPreviousError,
+    QuickfixNext,
+    QuickfixPrev,
 
     // Smart editing
     SmartHome,
     DedentSelection,
     ToggleComment,
+    AlignOnDelimiter,
+    SortLines,
+    SortLinesDescending,
+    SortLinesCaseInsensitive,
+    UniqueLines,
+    UniqueAdjacentLines,
 
     // Bookmarks
     SetBookmark(char),
@@ -340,6 +383,7 @@ pub enum Action {
     PromptRecordMacro,
     PromptPlayMacro,
     PlayLastMacro,
+    PromptPlayLastMacroTimes,
 
     // Bookmarks (prompt-based)
     PromptSetBookmark,
@@ -348,17 +392,27 @@ pub enum Action {
     // Undo/redo
     Undo,
     Redo,
+    RepeatLastEdit,
 
     // View
     ScrollUp,
     ScrollDown,
+    ScrollHalfPageUp,
+    ScrollHalfPageDown,
+    ScrollLeft,
+    ScrollRight,
     ShowHelp,
     ShowKeyboardShortcuts,
+    ShowKeybindings,
     ShowWarnings,
     ShowLspStatus,
     ClearWarnings,
     CommandPalette,
     ToggleLineWrap,
+    ToggleIndentGuides,
+    ToggleRenderWhitespace,
+    ToggleRenderTrailingWhitespace,
+    ToggleStickyScroll,
     ToggleComposeMode,
     SetComposeWidth,
     SelectTheme,
@@ -388,6 +442,10 @@ pub enum Action {
     PrevSplit,
     IncreaseSplitSize,
     DecreaseSplitSize,
+    EqualizeSplits,
+    MoveBufferToNextSplit,
+    MoveBufferToPrevSplit,
+    SwapBufferWithNextSplit,
     ToggleMaximizeSplit,
 
     // Prompt mode actions
@@ -441,6 +499,10 @@ pub enum Action {
     ToggleMenuBar,
     // Tab bar visibility
     ToggleTabBar,
+    // Cursor byte offset / file size segment in the status bar
+    ToggleCursorDetails,
+    // Line, word and character counts of the buffer or selection
+    ShowWordCount,
     FocusFileExplorer,
     FocusEditor,
     FileExplorerUp,
@@ -455,6 +517,7 @@ pub enum Action {
     FileExplorerNewDirectory,
     FileExplorerDelete,
     FileExplorerRename,
+    FileExplorerFilter,
     FileExplorerToggleHidden,
     FileExplorerToggleGitignored,
 
@@ -466,6 +529,8 @@ pub enum Action {
     LspHover,
     LspSignatureHelp,
     LspCodeActions,
+    LspFormat,
+    LspDocumentSymbols,
     LspRestart,
     LspStop,
     ToggleInlayHints,
@@ -482,6 +547,8 @@ pub enum Action {
     SetTabSize,
     SetLineEnding,
     ToggleIndentationStyle,
+    ConvertIndentationToSpaces,
+    ConvertIndentationToTabs,
     ToggleTabIndicators,
     ResetBufferSettings,
 
@@ -496,7 +563,10 @@ pub enum Action {
     FindSelectionNext,     // Quick find next occurrence of selection (Ctrl+F3)
     FindSelectionPrevious, // Quick find previous occurrence of selection (Ctrl+Shift+F3)
     Replace,
+    ReplaceAll,
     QueryReplace, // Interactive replace (y/n/!/q for each match)
+    ClearSearchHighlight,
+    ProjectSearch, // Search all files under the working directory
 
     // Menu navigation
     MenuActivate,     // Open menu bar (Alt or F10)
@@ -549,6 +619,55 @@ pub enum Action {
     None,
 }
 
+/// Category an action is grouped under when listing keybindings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ActionCategory {
+    Editing,
+    Movement,
+    Selection,
+    Clipboard,
+    Search,
+    File,
+    Navigation,
+    /// Buffers, tabs and splits
+    Windows,
+    View,
+    Lsp,
+    Macros,
+    Terminal,
+    FileExplorer,
+    Prompt,
+    Popup,
+    Menu,
+    General,
+}
+
+impl ActionCategory {
+    /// Localized name of the category
+    pub fn label(self) -> String {
+        match self {
+            Self::Editing => t!("action_category.editing"),
+            Self::Movement => t!("action_category.movement"),
+            Self::Selection => t!("action_category.selection"),
+            Self::Clipboard => t!("action_category.clipboard"),
+            Self::Search => t!("action_category.search"),
+            Self::File => t!("action_category.file"),
+            Self::Navigation => t!("action_category.navigation"),
+            Self::Windows => t!("action_category.windows"),
+            Self::View => t!("action_category.view"),
+            Self::Lsp => t!("action_category.lsp"),
+            Self::Macros => t!("action_category.macros"),
+            Self::Terminal => t!("action_category.terminal"),
+            Self::FileExplorer => t!("action_category.file_explorer"),
+            Self::Prompt => t!("action_category.prompt"),
+            Self::Popup => t!("action_category.popup"),
+            Self::Menu => t!("action_category.menu"),
+            Self::General => t!("action_category.general"),
+        }
+        .to_string()
+    }
+}
+
 impl Action {
     fn with_char(
         args: &HashMap<String, serde_json::Value>,
@@ -561,6 +680,317 @@ impl Action {
         }
     }
 
+    /// Category the action is grouped under in the keybindings list
+    pub fn category(&self) -> ActionCategory {
+        use ActionCategory as C;
+        match self {
+            Self::PromptSelectRegister => C::Clipboard,
+            Self::PromptSetBookmark | Self::PromptJumpToBookmark => C::Navigation,
+            Self::PromptRecordMacro | Self::PromptPlayMacro | Self::PromptPlayLastMacroTimes => {
+                C::Macros
+            }
+            Self::FileBrowserToggleHidden => C::Prompt,
+
+            Self::InsertChar(_)
+            | Self::InsertNewline
+            | Self::InsertTab
+            | Self::DeleteBackward
+            | Self::DeleteForward
+            | Self::DeleteWordBackward
+            | Self::DeleteWordForward
+            | Self::DeleteLine
+            | Self::DeleteToLineEnd
+            | Self::DeleteToLineStart
+            | Self::DeleteTextObject(_)
+            | Self::TransposeChars
+            | Self::OpenLine
+            | Self::OpenLineBelow
+            | Self::OpenLineAbove
+            | Self::ToggleInsertMode
+            | Self::SmartHome
+            | Self::DedentSelection
+            | Self::ToggleComment
+            | Self::AlignOnDelimiter
+            | Self::SortLines
+            | Self::SortLinesDescending
+            | Self::SortLinesCaseInsensitive
+            | Self::UniqueLines
+            | Self::UniqueAdjacentLines
+            | Self::Undo
+            | Self::Redo
+            | Self::RepeatLastEdit
+            | Self::ToUpperCase
+            | Self::ToLowerCase
+            | Self::ShellCommand
+            | Self::ShellCommandReplace
+            | Self::SetTabSize
+            | Self::SetLineEnding
+            | Self::ToggleIndentationStyle
+            | Self::ConvertIndentationToSpaces
+            | Self::ConvertIndentationToTabs
+            | Self::ResetBufferSettings => C::Editing,
+
+            Self::MoveLeft
+            | Self::MoveRight
+            | Self::MoveUp
+            | Self::MoveDown
+            | Self::MoveWordLeft
+            | Self::MoveWordRight
+            | Self::MoveSubwordLeft
+            | Self::MoveSubwordRight
+            | Self::MoveLineStart
+            | Self::MoveLineEnd
+            | Self::MovePageUp
+            | Self::MovePageDown
+            | Self::MoveHalfPageUp
+            | Self::MoveHalfPageDown
+            | Self::MoveParagraphUp
+            | Self::MoveParagraphDown
+            | Self::MoveDocumentStart
+            | Self::MoveDocumentEnd
+            | Self::CursorToViewportTop
+            | Self::CursorToViewportMiddle
+            | Self::CursorToViewportBottom => C::Movement,
+
+            Self::SelectLeft
+            | Self::SelectRight
+            | Self::SelectUp
+            | Self::SelectDown
+            | Self::SelectWordLeft
+            | Self::SelectWordRight
+            | Self::SelectSubwordLeft
+            | Self::SelectSubwordRight
+            | Self::SelectLineStart
+            | Self::SelectLineEnd
+            | Self::SelectDocumentStart
+            | Self::SelectDocumentEnd
+            | Self::SelectPageUp
+            | Self::SelectPageDown
+            | Self::SelectParagraphUp
+            | Self::SelectParagraphDown
+            | Self::SelectAll
+            | Self::SelectWord
+            | Self::SelectLine
+            | Self::ExpandSelection
+            | Self::SelectTextObject(_)
+            | Self::BlockSelectLeft
+            | Self::BlockSelectRight
+            | Self::BlockSelectUp
+            | Self::BlockSelectDown
+            | Self::SetMark
+            | Self::AddCursorAbove
+            | Self::AddCursorBelow
+            | Self::AddCursorNextMatch
+            | Self::RemoveSecondaryCursors => C::Selection,
+
+            Self::Copy
+            | Self::CopyWithTheme(_)
+            | Self::Cut
+            | Self::ExtractSelectionToNewBuffer
+            | Self::MoveSelectionToNewBuffer
+            | Self::ExportHtml
+            | Self::ExportHtmlWithLineNumbers
+            | Self::Paste
+            | Self::PasteAndIndent
+            | Self::YankPop
+            | Self::CopyToRegister(_)
+            | Self::PasteFromRegister(_)
+            | Self::YankWordForward
+            | Self::YankWordBackward
+            | Self::YankToLineEnd
+            | Self::YankToLineStart => C::Clipboard,
+
+            Self::Search
+            | Self::FindInSelection
+            | Self::FindNext
+            | Self::FindPrevious
+            | Self::FindSelectionNext
+            | Self::FindSelectionPrevious
+            | Self::Replace
+            | Self::ReplaceAll
+            | Self::QueryReplace
+            | Self::ClearSearchHighlight
+            | Self::ProjectSearch
+            | Self::ToggleSearchCaseSensitive
+            | Self::ToggleSearchWholeWord
+            | Self::ToggleSearchRegex
+            | Self::ToggleSearchConfirmEach => C::Search,
+
+            Self::Save
+            | Self::SaveAs
+            | Self::Open
+            | Self::SwitchProject
+            | Self::New
+            | Self::Close
+            | Self::Quit
+            | Self::ForceQuit
+            | Self::SaveSession
+            | Self::RestoreSession
+            | Self::Revert
+            | Self::ForceRevert
+            | Self::ToggleAutoRevert
+            | Self::FormatBuffer => C::File,
+
+            Self::GotoLine
+            | Self::GotoPercent(_)
+            | Self::GoToMatchingBracket
+            | Self::JumpToNextError
+            | Self::JumpToPreviousError
+            | Self::QuickfixNext
+            | Self::QuickfixPrev
+            | Self::SetBookmark(_)
+            | Self::JumpToBookmark(_)
+            | Self::ClearBookmark(_)
+            | Self::ListBookmarks
+            | Self::NavigateBack
+            | Self::NavigateForward => C::Navigation,
+
+            Self::CloseTab
+            | Self::NextBuffer
+            | Self::PrevBuffer
+            | Self::SwitchToPreviousTab
+            | Self::SwitchToTabByName
+            | Self::ScrollTabsLeft
+            | Self::ScrollTabsRight
+            | Self::SplitHorizontal
+            | Self::SplitVertical
+            | Self::CloseSplit
+            | Self::NextSplit
+            | Self::PrevSplit
+            | Self::IncreaseSplitSize
+            | Self::DecreaseSplitSize
+            | Self::EqualizeSplits
+            | Self::MoveBufferToNextSplit
+            | Self::MoveBufferToPrevSplit
+            | Self::SwapBufferWithNextSplit
+            | Self::ToggleMaximizeSplit => C::Windows,
+
+            Self::Recenter
+            | Self::ScrollCursorToTop
+            | Self::ScrollCursorToBottom
+            | Self::ScrollUp
+            | Self::ScrollDown
+            | Self::ScrollHalfPageUp
+            | Self::ScrollHalfPageDown
+            | Self::ScrollLeft
+            | Self::ScrollRight
+            | Self::ToggleLineWrap
+            | Self::ToggleIndentGuides
+            | Self::ToggleRenderWhitespace
+            | Self::ToggleRenderTrailingWhitespace
+            | Self::ToggleStickyScroll
+            | Self::ToggleComposeMode
+            | Self::SetComposeWidth
+            | Self::SelectTheme
+            | Self::SelectCursorStyle
+            | Self::ToggleMenuBar
+            | Self::ToggleTabBar
+            | Self::ToggleCursorDetails
+            | Self::ToggleLineNumbers
+            | Self::ToggleDebugHighlights
+            | Self::ToggleTabIndicators
+            | Self::SetBackground
+            | Self::SetBackgroundBlend => C::View,
+
+            Self::LspCompletion
+            | Self::LspGotoDefinition
+            | Self::LspReferences
+            | Self::LspRename
+            | Self::LspHover
+            | Self::LspSignatureHelp
+            | Self::LspCodeActions
+            | Self::LspFormat
+            | Self::LspDocumentSymbols
+            | Self::LspRestart
+            | Self::LspStop
+            | Self::ShowLspStatus
+            | Self::ToggleInlayHints
+            | Self::ToggleMouseHover => C::Lsp,
+
+            Self::StartMacroRecording
+            | Self::StopMacroRecording
+            | Self::PlayMacro(_)
+            | Self::ToggleMacroRecording(_)
+            | Self::ShowMacro(_)
+            | Self::ListMacros
+            | Self::PlayLastMacro => C::Macros,
+
+            Self::OpenTerminal
+            | Self::CloseTerminal
+            | Self::FocusTerminal
+            | Self::TerminalEscape
+            | Self::ToggleKeyboardCapture
+            | Self::TerminalPaste => C::Terminal,
+
+            Self::ToggleFileExplorer
+            | Self::FocusFileExplorer
+            | Self::FocusEditor
+            | Self::FileExplorerUp
+            | Self::FileExplorerDown
+            | Self::FileExplorerPageUp
+            | Self::FileExplorerPageDown
+            | Self::FileExplorerExpand
+            | Self::FileExplorerCollapse
+            | Self::FileExplorerOpen
+            | Self::FileExplorerRefresh
+            | Self::FileExplorerNewFile
+            | Self::FileExplorerNewDirectory
+            | Self::FileExplorerDelete
+            | Self::FileExplorerRename
+            | Self::FileExplorerFilter
+            | Self::FileExplorerToggleHidden
+            | Self::FileExplorerToggleGitignored => C::FileExplorer,
+
+            Self::PromptConfirm
+            | Self::PromptConfirmWithText(_)
+            | Self::PromptCancel
+            | Self::PromptBackspace
+            | Self::PromptDelete
+            | Self::PromptMoveLeft
+            | Self::PromptMoveRight
+            | Self::PromptMoveStart
+            | Self::PromptMoveEnd
+            | Self::PromptSelectPrev
+            | Self::PromptSelectNext
+            | Self::PromptPageUp
+            | Self::PromptPageDown
+            | Self::PromptAcceptSuggestion
+            | Self::PromptMoveWordLeft
+            | Self::PromptMoveWordRight
+            | Self::PromptDeleteWordForward
+            | Self::PromptDeleteWordBackward
+            | Self::PromptDeleteToLineEnd
+            | Self::PromptCopy
+            | Self::PromptCut
+            | Self::PromptPaste
+            | Self::PromptMoveLeftSelecting
+            | Self::PromptMoveRightSelecting
+            | Self::PromptMoveHomeSelecting
+            | Self::PromptMoveEndSelecting
+            | Self::PromptSelectWordLeft
+            | Self::PromptSelectWordRight
+            | Self::PromptSelectAll => C::Prompt,
+
+            Self::PopupSelectNext
+            | Self::PopupSelectPrev
+            | Self::PopupPageUp
+            | Self::PopupPageDown
+            | Self::PopupConfirm
+            | Self::PopupCancel => C::Popup,
+
+            Self::MenuActivate
+            | Self::MenuClose
+            | Self::MenuLeft
+            | Self::MenuRight
+            | Self::MenuUp
+            | Self::MenuDown
+            | Self::MenuExecute
+            | Self::MenuOpen(_) => C::Menu,
+
+            _ => C::General,
+        }
+    }
+
     /// Parse action from string (used when loading from config)
     pub fn from_str(s: &str, args: &HashMap<String, serde_json::Value>) -> Option<Self> {
         Some(match s {
@@ -574,12 +1004,21 @@ impl Action {
             "move_down" => Self::MoveDown,
             "move_word_left" => Self::MoveWordLeft,
             "move_word_right" => Self::MoveWordRight,
+            "move_subword_left" => Self::MoveSubwordLeft,
+            "move_subword_right" => Self::MoveSubwordRight,
             "move_line_start" => Self::MoveLineStart,
             "move_line_end" => Self::MoveLineEnd,
             "move_page_up" => Self::MovePageUp,
             "move_page_down" => Self::MovePageDown,
+            "move_half_page_up" => Self::MoveHalfPageUp,
+            "move_half_page_down" => Self::MoveHalfPageDown,
+            "move_paragraph_up" => Self::MoveParagraphUp,
+            "move_paragraph_down" => Self::MoveParagraphDown,
             "move_document_start" => Self::MoveDocumentStart,
             "move_document_end" => Self::MoveDocumentEnd,
+            "cursor_to_viewport_top" => Self::CursorToViewportTop,
+            "cursor_to_viewport_middle" => Self::CursorToViewportMiddle,
+            "cursor_to_viewport_bottom" => Self::CursorToViewportBottom,
 
             "select_left" => Self::SelectLeft,
             "select_right" => Self::SelectRight,
@@ -587,16 +1026,28 @@ impl Action {
             "select_down" => Self::SelectDown,
             "select_word_left" => Self::SelectWordLeft,
             "select_word_right" => Self::SelectWordRight,
+            "select_subword_left" => Self::SelectSubwordLeft,
+            "select_subword_right" => Self::SelectSubwordRight,
             "select_line_start" => Self::SelectLineStart,
             "select_line_end" => Self::SelectLineEnd,
             "select_document_start" => Self::SelectDocumentStart,
             "select_document_end" => Self::SelectDocumentEnd,
             "select_page_up" => Self::SelectPageUp,
             "select_page_down" => Self::SelectPageDown,
+            "select_paragraph_up" => Self::SelectParagraphUp,
+            "select_paragraph_down" => Self::SelectParagraphDown,
             "select_all" => Self::SelectAll,
             "select_word" => Self::SelectWord,
             "select_line" => Self::SelectLine,
             "expand_selection" => Self::ExpandSelection,
+            "select_text_object" => {
+                let name = args.get("name")?.as_str()?;
+                Self::SelectTextObject(name.to_string())
+            }
+            "delete_text_object" => {
+                let name = args.get("name")?.as_str()?;
+                Self::DeleteTextObject(name.to_string())
+            }
 
             // Block/rectangular selection
             "block_select_left" => Self::BlockSelectLeft,
@@ -613,7 +1064,12 @@ impl Action {
             "delete_to_line_start" => Self::DeleteToLineStart,
             "transpose_chars" => Self::TransposeChars,
             "open_line" => Self::OpenLine,
+            "open_line_below" => Self::OpenLineBelow,
+            "open_line_above" => Self::OpenLineAbove,
+            "toggle_insert_mode" => Self::ToggleInsertMode,
             "recenter" => Self::Recenter,
+            "scroll_cursor_to_top" => Self::ScrollCursorToTop,
+            "scroll_cursor_to_bottom" => Self::ScrollCursorToBottom,
             "set_mark" => Self::SetMark,
 
             "copy" => Self::Copy,
@@ -623,7 +1079,16 @@ impl Action {
                 Self::CopyWithTheme(theme.to_string())
             }
             "cut" => Self::Cut,
+            "extract_selection_to_new_buffer" => Self::ExtractSelectionToNewBuffer,
+            "move_selection_to_new_buffer" => Self::MoveSelectionToNewBuffer,
+            "export_html" => Self::ExportHtml,
+            "export_html_with_line_numbers" => Self::ExportHtmlWithLineNumbers,
             "paste" => Self::Paste,
+            "paste_and_indent" => Self::PasteAndIndent,
+            "yank_pop" => Self::YankPop,
+            "copy_to_register" => return Self::with_char(args, Self::CopyToRegister),
+            "paste_from_register" => return Self::with_char(args, Self::PasteFromRegister),
+            "prompt_select_register" => Self::PromptSelectRegister,
 
             // Vi-style yank actions
             "yank_word_forward" => Self::YankWordForward,
@@ -645,17 +1110,32 @@ impl Action {
             "close_tab" => Self::CloseTab,
             "quit" => Self::Quit,
             "force_quit" => Self::ForceQuit,
+            "save_session" => Self::SaveSession,
+            "restore_session" => Self::RestoreSession,
             "revert" => Self::Revert,
+            "force_revert" => Self::ForceRevert,
             "toggle_auto_revert" => Self::ToggleAutoRevert,
             "format_buffer" => Self::FormatBuffer,
             "goto_line" => Self::GotoLine,
+            "goto_percent" => {
+                let percent = args.get("percent")?.as_u64()?;
+                Self::GotoPercent(percent as usize)
+            }
             "goto_matching_bracket" => Self::GoToMatchingBracket,
             "jump_to_next_error" => Self::JumpToNextError,
             "jump_to_previous_error" => Self::JumpToPreviousError,
+            "quickfix_next" => Self::QuickfixNext,
+            "quickfix_prev" => Self::QuickfixPrev,
 
             "smart_home" => Self::SmartHome,
             "dedent_selection" => Self::DedentSelection,
             "toggle_comment" => Self::ToggleComment,
+            "align_on_delimiter" => Self::AlignOnDelimiter,
+            "sort_lines" => Self::SortLines,
+            "sort_lines_descending" => Self::SortLinesDescending,
+            "sort_lines_case_insensitive" => Self::SortLinesCaseInsensitive,
+            "unique_lines" => Self::UniqueLines,
+            "unique_adjacent_lines" => Self::UniqueAdjacentLines,
 
             "set_bookmark" => return Self::with_char(args, Self::SetBookmark),
             "jump_to_bookmark" => return Self::with_char(args, Self::JumpToBookmark),
@@ -679,26 +1159,38 @@ impl Action {
             "prompt_record_macro" => Self::PromptRecordMacro,
             "prompt_play_macro" => Self::PromptPlayMacro,
             "play_last_macro" => Self::PlayLastMacro,
+            "prompt_play_last_macro_times" => Self::PromptPlayLastMacroTimes,
             "prompt_set_bookmark" => Self::PromptSetBookmark,
             "prompt_jump_to_bookmark" => Self::PromptJumpToBookmark,
 
             "undo" => Self::Undo,
             "redo" => Self::Redo,
+            "repeat_last_edit" => Self::RepeatLastEdit,
 
             "scroll_up" => Self::ScrollUp,
             "scroll_down" => Self::ScrollDown,
+            "scroll_half_page_up" => Self::ScrollHalfPageUp,
+            "scroll_half_page_down" => Self::ScrollHalfPageDown,
+            "scroll_left" => Self::ScrollLeft,
+            "scroll_right" => Self::ScrollRight,
             "show_help" => Self::ShowHelp,
             "keyboard_shortcuts" => Self::ShowKeyboardShortcuts,
+            "show_keybindings" => Self::ShowKeybindings,
             "show_warnings" => Self::ShowWarnings,
             "show_lsp_status" => Self::ShowLspStatus,
             "clear_warnings" => Self::ClearWarnings,
             "command_palette" => Self::CommandPalette,
             "toggle_line_wrap" => Self::ToggleLineWrap,
+            "toggle_indent_guides" => Self::ToggleIndentGuides,
+            "toggle_render_whitespace" => Self::ToggleRenderWhitespace,
+            "toggle_render_trailing_whitespace" => Self::ToggleRenderTrailingWhitespace,
+            "toggle_sticky_scroll" => Self::ToggleStickyScroll,
             "toggle_compose_mode" => Self::ToggleComposeMode,
             "set_compose_width" => Self::SetComposeWidth,
 
             "next_buffer" => Self::NextBuffer,
             "prev_buffer" => Self::PrevBuffer,
+            "switch_to_previous_tab" => Self::SwitchToPreviousTab,
 
             "navigate_back" => Self::NavigateBack,
             "navigate_forward" => Self::NavigateForward,
@@ -710,6 +1202,10 @@ impl Action {
             "prev_split" => Self::PrevSplit,
             "increase_split_size" => Self::IncreaseSplitSize,
             "decrease_split_size" => Self::DecreaseSplitSize,
+            "equalize_splits" => Self::EqualizeSplits,
+            "move_buffer_to_next_split" => Self::MoveBufferToNextSplit,
+            "move_buffer_to_prev_split" => Self::MoveBufferToPrevSplit,
+            "swap_buffer_with_next_split" => Self::SwapBufferWithNextSplit,
             "toggle_maximize_split" => Self::ToggleMaximizeSplit,
 
             "prompt_confirm" => Self::PromptConfirm,
@@ -752,6 +1248,8 @@ impl Action {
             "toggle_file_explorer" => Self::ToggleFileExplorer,
             "toggle_menu_bar" => Self::ToggleMenuBar,
             "toggle_tab_bar" => Self::ToggleTabBar,
+            "toggle_cursor_details" => Self::ToggleCursorDetails,
+            "show_word_count" => Self::ShowWordCount,
             "focus_file_explorer" => Self::FocusFileExplorer,
             "focus_editor" => Self::FocusEditor,
             "file_explorer_up" => Self::FileExplorerUp,
@@ -766,6 +1264,7 @@ impl Action {
             "file_explorer_new_directory" => Self::FileExplorerNewDirectory,
             "file_explorer_delete" => Self::FileExplorerDelete,
             "file_explorer_rename" => Self::FileExplorerRename,
+            "file_explorer_filter" => Self::FileExplorerFilter,
             "file_explorer_toggle_hidden" => Self::FileExplorerToggleHidden,
             "file_explorer_toggle_gitignored" => Self::FileExplorerToggleGitignored,
 
@@ -776,6 +1275,8 @@ impl Action {
             "lsp_hover" => Self::LspHover,
             "lsp_signature_help" => Self::LspSignatureHelp,
             "lsp_code_actions" => Self::LspCodeActions,
+            "lsp_format" => Self::LspFormat,
+            "lsp_document_symbols" => Self::LspDocumentSymbols,
             "lsp_restart" => Self::LspRestart,
             "lsp_stop" => Self::LspStop,
             "toggle_inlay_hints" => Self::ToggleInlayHints,
@@ -794,6 +1295,8 @@ impl Action {
             "set_tab_size" => Self::SetTabSize,
             "set_line_ending" => Self::SetLineEnding,
             "toggle_indentation_style" => Self::ToggleIndentationStyle,
+            "convert_indentation_to_spaces" => Self::ConvertIndentationToSpaces,
+            "convert_indentation_to_tabs" => Self::ConvertIndentationToTabs,
             "toggle_tab_indicators" => Self::ToggleTabIndicators,
             "reset_buffer_settings" => Self::ResetBufferSettings,
 
@@ -806,7 +1309,10 @@ impl Action {
             "find_selection_next" => Self::FindSelectionNext,
             "find_selection_previous" => Self::FindSelectionPrevious,
             "replace" => Self::Replace,
+            "replace_all" => Self::ReplaceAll,
             "query_replace" => Self::QueryReplace,
+            "clear_search_highlight" => Self::ClearSearchHighlight,
+            "project_search" => Self::ProjectSearch,
 
             "menu_activate" => Self::MenuActivate,
             "menu_close" => Self::MenuClose,
@@ -888,6 +1394,10 @@ pub struct KeybindingResolver {
 
     /// Default chord bindings for each context
     default_chord_bindings: HashMap<KeyContext, HashMap<Vec<(KeyCode, KeyModifiers)>, Action>>,
+
+    /// Names the bound actions were configured with (e.g. "move_line_end"),
+    /// so bindings can be listed back in the form they are set
+    action_names: Vec<(Action, String)>,
 }
 
 impl KeybindingResolver {
@@ -898,6 +1408,7 @@ impl KeybindingResolver {
             default_bindings: HashMap::new(),
             chord_bindings: HashMap::new(),
             default_chord_bindings: HashMap::new(),
+            action_names: Vec::new(),
         };
 
         // Load bindings from the active keymap (with inheritance resolution) into default_bindings
@@ -921,6 +1432,8 @@ impl KeybindingResolver {
             };
 
             if let Some(action) = Action::from_str(&binding.action, &binding.args) {
+                self.record_action_name(&action, &binding.action);
+
                 // Check if this is a chord binding (has keys field)
                 if !binding.keys.is_empty() {
                     // Parse the chord sequence
@@ -1013,6 +1526,8 @@ impl KeybindingResolver {
             };
 
             if let Some(action) = Action::from_str(&binding.action, &binding.args) {
+                self.record_action_name(&action, &binding.action);
+
                 // Check if this is a chord binding (has keys field)
                 if !binding.keys.is_empty() {
                     // Parse the chord sequence
@@ -1056,6 +1571,7 @@ impl KeybindingResolver {
                 | Action::SaveAs
                 | Action::ShowHelp
                 | Action::ShowKeyboardShortcuts
+                | Action::ShowKeybindings
                 | Action::PromptCancel  // Esc should always cancel
                 | Action::PopupCancel // Esc should always cancel
         )
@@ -1074,6 +1590,7 @@ impl KeybindingResolver {
                 | Action::MenuOpen(_)
                 | Action::ShowHelp
                 | Action::ShowKeyboardShortcuts
+                | Action::ShowKeybindings
                 | Action::Quit
                 | Action::ForceQuit
                 // Split navigation
@@ -1374,6 +1891,7 @@ impl KeybindingResolver {
             KeyCode::Backspace => (2, 2),
             KeyCode::Delete => (2, 3),
             KeyCode::Esc => (2, 4),
+            KeyCode::Insert => (2, 5),
             KeyCode::Left => (3, 0),
             KeyCode::Right => (3, 1),
             KeyCode::Up => (3, 2),
@@ -1421,6 +1939,7 @@ impl KeybindingResolver {
             "enter" => Some(KeyCode::Enter),
             "backspace" => Some(KeyCode::Backspace),
             "delete" | "del" => Some(KeyCode::Delete),
+            "insert" | "ins" => Some(KeyCode::Insert),
             "tab" => Some(KeyCode::Tab),
             "backtab" => Some(KeyCode::BackTab),
             "esc" | "escape" => Some(KeyCode::Esc),
@@ -1506,6 +2025,114 @@ impl KeybindingResolver {
         bindings
     }
 
+    /// Get the bindings that are active in a single context (for the keybindings popup)
+    ///
+    /// Global bindings are listed alongside the context's own bindings, and outside
+    /// of Normal mode the application-wide actions that fall through from Normal are
+    /// included too. Custom bindings override defaults, and chords are formatted as
+    /// space-separated key sequences.
+    /// Returns a Vec of (category, key_description, action_description), grouped by
+    /// the action's category and sorted by action description within each group.
+    pub fn get_bindings_for_context(&self, context: KeyContext) -> Vec<(String, String, String)> {
+        self.collect_context_bindings(context, true)
+    }
+
+    /// Get the bindings a context defines itself, without the Global ones
+    ///
+    /// Same shape and ordering as [`Self::get_bindings_for_context`].
+    pub fn get_own_bindings_for_context(
+        &self,
+        context: KeyContext,
+    ) -> Vec<(String, String, String)> {
+        self.collect_context_bindings(context, false)
+    }
+
+    fn collect_context_bindings(
+        &self,
+        context: KeyContext,
+        include_global: bool,
+    ) -> Vec<(String, String, String)> {
+        let mut groups = vec![KeyContext::Global, context];
+        if context == KeyContext::Global || !include_global {
+            groups.remove(0);
+        }
+
+        let mut bindings = Vec::new();
+        for group in groups {
+            let mut keys: HashMap<(KeyCode, KeyModifiers), Action> = HashMap::new();
+            for source in [&self.default_bindings, &self.bindings] {
+                if let Some(group_bindings) = source.get(&group) {
+                    for (key, action) in group_bindings {
+                        keys.insert(*key, action.clone());
+                    }
+                }
+            }
+
+            // Application-wide actions resolve from Normal in every other context
+            if group == context && context != KeyContext::Normal {
+                for source in [&self.default_bindings, &self.bindings] {
+                    if let Some(normal_bindings) = source.get(&KeyContext::Normal) {
+                        for (key, action) in normal_bindings {
+                            if Self::is_application_wide_action(action) {
+                                keys.entry(*key).or_insert_with(|| action.clone());
+                            }
+                        }
+                    }
+                }
+            }
+
+            let mut chords: HashMap<Vec<(KeyCode, KeyModifiers)>, Action> = HashMap::new();
+            for source in [&self.default_chord_bindings, &self.chord_bindings] {
+                if let Some(group_chords) = source.get(&group) {
+                    for (sequence, action) in group_chords {
+                        chords.insert(sequence.clone(), action.clone());
+                    }
+                }
+            }
+
+            let group_bindings = keys
+                .into_iter()
+                .filter(|(_, action)| *action != Action::None)
+                .map(|((key_code, modifiers), action)| {
+                    (
+                        action.category(),
+                        Self::format_key(key_code, modifiers),
+                        Self::format_action(&action),
+                    )
+                })
+                .chain(
+                    chords
+                        .into_iter()
+                        .filter(|(_, action)| *action != Action::None)
+                        .map(|(sequence, action)| {
+                            let keys: Vec<String> = sequence
+                                .iter()
+                                .map(|(key_code, modifiers)| {
+                                    Self::format_key(*key_code, *modifiers)
+                                })
+                                .collect();
+                            (
+                                action.category(),
+                                keys.join(" "),
+                                Self::format_action(&action),
+                            )
+                        }),
+                )
+                .filter(|(_, key, _)| !key.is_empty());
+            bindings.extend(group_bindings);
+        }
+
+        bindings.sort_by(|a, b| {
+            a.0.cmp(&b.0)
+                .then_with(|| a.2.cmp(&b.2))
+                .then_with(|| a.1.cmp(&b.1))
+        });
+        bindings
+            .into_iter()
+            .map(|(category, key, action)| (category.label(), key, action))
+            .collect()
+    }
+
     /// Format a key combination as a readable string
     fn format_key(key_code: KeyCode, modifiers: KeyModifiers) -> String {
         format_keybinding(&key_code, &modifiers)
@@ -1523,28 +2150,43 @@ impl KeybindingResolver {
             Action::MoveDown => t!("action.move_down"),
             Action::MoveWordLeft => t!("action.move_word_left"),
             Action::MoveWordRight => t!("action.move_word_right"),
+            Action::MoveSubwordLeft => t!("action.move_subword_left"),
+            Action::MoveSubwordRight => t!("action.move_subword_right"),
             Action::MoveLineStart => t!("action.move_line_start"),
             Action::MoveLineEnd => t!("action.move_line_end"),
             Action::MovePageUp => t!("action.move_page_up"),
             Action::MovePageDown => t!("action.move_page_down"),
+            Action::MoveHalfPageUp => t!("action.move_half_page_up"),
+            Action::MoveHalfPageDown => t!("action.move_half_page_down"),
+            Action::MoveParagraphUp => t!("action.move_paragraph_up"),
+            Action::MoveParagraphDown => t!("action.move_paragraph_down"),
             Action::MoveDocumentStart => t!("action.move_document_start"),
             Action::MoveDocumentEnd => t!("action.move_document_end"),
+            Action::CursorToViewportTop => t!("action.cursor_to_viewport_top"),
+            Action::CursorToViewportMiddle => t!("action.cursor_to_viewport_middle"),
+            Action::CursorToViewportBottom => t!("action.cursor_to_viewport_bottom"),
             Action::SelectLeft => t!("action.select_left"),
             Action::SelectRight => t!("action.select_right"),
             Action::SelectUp => t!("action.select_up"),
             Action::SelectDown => t!("action.select_down"),
             Action::SelectWordLeft => t!("action.select_word_left"),
             Action::SelectWordRight => t!("action.select_word_right"),
+            Action::SelectSubwordLeft => t!("action.select_subword_left"),
+            Action::SelectSubwordRight => t!("action.select_subword_right"),
             Action::SelectLineStart => t!("action.select_line_start"),
             Action::SelectLineEnd => t!("action.select_line_end"),
             Action::SelectDocumentStart => t!("action.select_document_start"),
             Action::SelectDocumentEnd => t!("action.select_document_end"),
             Action::SelectPageUp => t!("action.select_page_up"),
             Action::SelectPageDown => t!("action.select_page_down"),
+            Action::SelectParagraphUp => t!("action.select_paragraph_up"),
+            Action::SelectParagraphDown => t!("action.select_paragraph_down"),
             Action::SelectAll => t!("action.select_all"),
             Action::SelectWord => t!("action.select_word"),
             Action::SelectLine => t!("action.select_line"),
             Action::ExpandSelection => t!("action.expand_selection"),
+            Action::SelectTextObject(name) => t!("action.select_text_object", name = name),
+            Action::DeleteTextObject(name) => t!("action.delete_text_object", name = name),
             Action::BlockSelectLeft => t!("action.block_select_left"),
             Action::BlockSelectRight => t!("action.block_select_right"),
             Action::BlockSelectUp => t!("action.block_select_up"),
@@ -1558,13 +2200,27 @@ impl KeybindingResolver {
             Action::DeleteToLineStart => t!("action.delete_to_line_start"),
             Action::TransposeChars => t!("action.transpose_chars"),
             Action::OpenLine => t!("action.open_line"),
+            Action::OpenLineBelow => t!("action.open_line_below"),
+            Action::OpenLineAbove => t!("action.open_line_above"),
+            Action::ToggleInsertMode => t!("action.toggle_insert_mode"),
             Action::Recenter => t!("action.recenter"),
+            Action::ScrollCursorToTop => t!("action.scroll_cursor_to_top"),
+            Action::ScrollCursorToBottom => t!("action.scroll_cursor_to_bottom"),
             Action::SetMark => t!("action.set_mark"),
             Action::Copy => t!("action.copy"),
             Action::CopyWithTheme(theme) if theme.is_empty() => t!("action.copy_with_formatting"),
             Action::CopyWithTheme(theme) => t!("action.copy_with_theme", theme = theme),
             Action::Cut => t!("action.cut"),
+            Action::ExtractSelectionToNewBuffer => t!("action.extract_selection_to_new_buffer"),
+            Action::MoveSelectionToNewBuffer => t!("action.move_selection_to_new_buffer"),
+            Action::ExportHtml => t!("action.export_html"),
+            Action::ExportHtmlWithLineNumbers => t!("action.export_html_with_line_numbers"),
             Action::Paste => t!("action.paste"),
+            Action::PasteAndIndent => t!("action.paste_and_indent"),
+            Action::YankPop => t!("action.yank_pop"),
+            Action::CopyToRegister(c) => t!("action.copy_to_register", key = c),
+            Action::PasteFromRegister(c) => t!("action.paste_from_register", key = c),
+            Action::PromptSelectRegister => t!("action.prompt_select_register"),
             Action::YankWordForward => t!("action.yank_word_forward"),
             Action::YankWordBackward => t!("action.yank_word_backward"),
             Action::YankToLineEnd => t!("action.yank_to_line_end"),
@@ -1582,16 +2238,28 @@ impl KeybindingResolver {
             Action::CloseTab => t!("action.close_tab"),
             Action::Quit => t!("action.quit"),
             Action::ForceQuit => t!("action.force_quit"),
+            Action::SaveSession => t!("action.save_session"),
+            Action::RestoreSession => t!("action.restore_session"),
             Action::Revert => t!("action.revert"),
+            Action::ForceRevert => t!("action.force_revert"),
             Action::ToggleAutoRevert => t!("action.toggle_auto_revert"),
             Action::FormatBuffer => t!("action.format_buffer"),
             Action::GotoLine => t!("action.goto_line"),
+            Action::GotoPercent(percent) => t!("action.goto_percent", percent = percent),
             Action::GoToMatchingBracket => t!("action.goto_matching_bracket"),
             Action::JumpToNextError => t!("action.jump_to_next_error"),
             Action::JumpToPreviousError => t!("action.jump_to_previous_error"),
+            Action::QuickfixNext => t!("action.quickfix_next"),
+            Action::QuickfixPrev => t!("action.quickfix_prev"),
             Action::SmartHome => t!("action.smart_home"),
             Action::DedentSelection => t!("action.dedent_selection"),
             Action::ToggleComment => t!("action.toggle_comment"),
+            Action::AlignOnDelimiter => t!("action.align_on_delimiter"),
+            Action::SortLines => t!("action.sort_lines"),
+            Action::SortLinesDescending => t!("action.sort_lines_descending"),
+            Action::SortLinesCaseInsensitive => t!("action.sort_lines_case_insensitive"),
+            Action::UniqueLines => t!("action.unique_lines"),
+            Action::UniqueAdjacentLines => t!("action.unique_adjacent_lines"),
             Action::SetBookmark(c) => t!("action.set_bookmark", key = c),
             Action::JumpToBookmark(c) => t!("action.jump_to_bookmark", key = c),
             Action::ClearBookmark(c) => t!("action.clear_bookmark", key = c),
@@ -1609,19 +2277,32 @@ impl KeybindingResolver {
             Action::PromptRecordMacro => t!("action.prompt_record_macro"),
             Action::PromptPlayMacro => t!("action.prompt_play_macro"),
             Action::PlayLastMacro => t!("action.play_last_macro"),
+            Action::PromptPlayLastMacroTimes => t!("action.prompt_play_last_macro_times"),
             Action::PromptSetBookmark => t!("action.prompt_set_bookmark"),
             Action::PromptJumpToBookmark => t!("action.prompt_jump_to_bookmark"),
             Action::Undo => t!("action.undo"),
             Action::Redo => t!("action.redo"),
+            Action::RepeatLastEdit => t!("action.repeat_last_edit"),
             Action::ScrollUp => t!("action.scroll_up"),
             Action::ScrollDown => t!("action.scroll_down"),
+            Action::ScrollHalfPageUp => t!("action.scroll_half_page_up"),
+            Action::ScrollHalfPageDown => t!("action.scroll_half_page_down"),
+            Action::ScrollLeft => t!("action.scroll_left"),
+            Action::ScrollRight => t!("action.scroll_right"),
             Action::ShowHelp => t!("action.show_help"),
             Action::ShowKeyboardShortcuts => t!("action.show_keyboard_shortcuts"),
+            Action::ShowKeybindings => t!("action.show_keybindings"),
             Action::ShowWarnings => t!("action.show_warnings"),
             Action::ShowLspStatus => t!("action.show_lsp_status"),
             Action::ClearWarnings => t!("action.clear_warnings"),
             Action::CommandPalette => t!("action.command_palette"),
             Action::ToggleLineWrap => t!("action.toggle_line_wrap"),
+            Action::ToggleIndentGuides => t!("action.toggle_indent_guides"),
+            Action::ToggleRenderWhitespace => t!("action.toggle_render_whitespace"),
+            Action::ToggleRenderTrailingWhitespace => {
+                t!("action.toggle_render_trailing_whitespace")
+            }
+            Action::ToggleStickyScroll => t!("action.toggle_sticky_scroll"),
             Action::ToggleComposeMode => t!("action.toggle_compose_mode"),
             Action::SetComposeWidth => t!("action.set_compose_width"),
             Action::NextBuffer => t!("action.next_buffer"),
@@ -1635,6 +2316,10 @@ impl KeybindingResolver {
             Action::PrevSplit => t!("action.prev_split"),
             Action::IncreaseSplitSize => t!("action.increase_split_size"),
             Action::DecreaseSplitSize => t!("action.decrease_split_size"),
+            Action::EqualizeSplits => t!("action.equalize_splits"),
+            Action::MoveBufferToNextSplit => t!("action.move_buffer_to_next_split"),
+            Action::MoveBufferToPrevSplit => t!("action.move_buffer_to_prev_split"),
+            Action::SwapBufferWithNextSplit => t!("action.swap_buffer_with_next_split"),
             Action::ToggleMaximizeSplit => t!("action.toggle_maximize_split"),
             Action::PromptConfirm => t!("action.prompt_confirm"),
             Action::PromptConfirmWithText(ref text) => {
@@ -1677,6 +2362,8 @@ impl KeybindingResolver {
             Action::ToggleFileExplorer => t!("action.toggle_file_explorer"),
             Action::ToggleMenuBar => t!("action.toggle_menu_bar"),
             Action::ToggleTabBar => t!("action.toggle_tab_bar"),
+            Action::ToggleCursorDetails => t!("action.toggle_cursor_details"),
+            Action::ShowWordCount => t!("action.show_word_count"),
             Action::FocusFileExplorer => t!("action.focus_file_explorer"),
             Action::FocusEditor => t!("action.focus_editor"),
             Action::FileExplorerUp => t!("action.file_explorer_up"),
@@ -1691,6 +2378,7 @@ impl KeybindingResolver {
             Action::FileExplorerNewDirectory => t!("action.file_explorer_new_directory"),
             Action::FileExplorerDelete => t!("action.file_explorer_delete"),
             Action::FileExplorerRename => t!("action.file_explorer_rename"),
+            Action::FileExplorerFilter => t!("action.file_explorer_filter"),
             Action::FileExplorerToggleHidden => t!("action.file_explorer_toggle_hidden"),
             Action::FileExplorerToggleGitignored => t!("action.file_explorer_toggle_gitignored"),
             Action::LspCompletion => t!("action.lsp_completion"),
@@ -1700,6 +2388,8 @@ impl KeybindingResolver {
             Action::LspHover => t!("action.lsp_hover"),
             Action::LspSignatureHelp => t!("action.lsp_signature_help"),
             Action::LspCodeActions => t!("action.lsp_code_actions"),
+            Action::LspFormat => t!("action.lsp_format"),
+            Action::LspDocumentSymbols => t!("action.lsp_document_symbols"),
             Action::LspRestart => t!("action.lsp_restart"),
             Action::LspStop => t!("action.lsp_stop"),
             Action::ToggleInlayHints => t!("action.toggle_inlay_hints"),
@@ -1712,6 +2402,8 @@ impl KeybindingResolver {
             Action::SetTabSize => t!("action.set_tab_size"),
             Action::SetLineEnding => t!("action.set_line_ending"),
             Action::ToggleIndentationStyle => t!("action.toggle_indentation_style"),
+            Action::ConvertIndentationToSpaces => t!("action.convert_indentation_to_spaces"),
+            Action::ConvertIndentationToTabs => t!("action.convert_indentation_to_tabs"),
             Action::ToggleTabIndicators => t!("action.toggle_tab_indicators"),
             Action::ResetBufferSettings => t!("action.reset_buffer_settings"),
             Action::DumpConfig => t!("action.dump_config"),
@@ -1722,7 +2414,10 @@ impl KeybindingResolver {
             Action::FindSelectionNext => t!("action.find_selection_next"),
             Action::FindSelectionPrevious => t!("action.find_selection_previous"),
             Action::Replace => t!("action.replace"),
+            Action::ReplaceAll => t!("action.replace_all"),
             Action::QueryReplace => t!("action.query_replace"),
+            Action::ClearSearchHighlight => t!("action.clear_search_highlight"),
+            Action::ProjectSearch => t!("action.project_search"),
             Action::MenuActivate => t!("action.menu_activate"),
             Action::MenuClose => t!("action.menu_close"),
             Action::MenuLeft => t!("action.menu_left"),
@@ -1847,6 +2542,113 @@ impl KeybindingResolver {
         None
     }
 
+    /// Remember the configured name of an action for listing bindings
+    fn record_action_name(&mut self, action: &Action, name: &str) {
+        if !self.action_names.iter().any(|(known, _)| known == action) {
+            self.action_names.push((action.clone(), name.to_string()));
+        }
+    }
+
+    /// Name of a bound action in the form accepted by `Action::from_str`
+    ///
+    /// Plugin actions are named by their command; anything else that was never
+    /// configured by name falls back to its description.
+    fn action_name(&self, action: &Action) -> String {
+        if let Action::PluginAction(name) = action {
+            return name.clone();
+        }
+        self.action_names
+            .iter()
+            .find(|(known, _)| known == action)
+            .map(|(_, name)| name.clone())
+            .unwrap_or_else(|| Self::format_action(action))
+    }
+
+    /// Bind a single key in a context, overriding any existing binding
+    ///
+    /// `name` is an action name (see `Action::from_str`) or, failing that, the
+    /// name of a plugin command. Returns the action the key resolved to before,
+    /// if it was bound to something else.
+    pub fn set_binding(
+        &mut self,
+        context: KeyContext,
+        key_code: KeyCode,
+        modifiers: KeyModifiers,
+        name: &str,
+    ) -> Option<Action> {
+        let action = Action::from_str(name, &HashMap::new())
+            .unwrap_or_else(|| Action::PluginAction(name.to_string()));
+        self.record_action_name(&action, name);
+
+        let previous = self.binding_in_context(context, key_code, modifiers);
+        self.bindings
+            .entry(context)
+            .or_default()
+            .insert((key_code, modifiers), action.clone());
+        previous.filter(|previous| *previous != action)
+    }
+
+    /// Unbind a single key in a context
+    ///
+    /// A default binding for the key is shadowed with `Action::None` so the key
+    /// stays unbound. Returns the action the key was bound to, if any.
+    pub fn remove_binding(
+        &mut self,
+        context: KeyContext,
+        key_code: KeyCode,
+        modifiers: KeyModifiers,
+    ) -> Option<Action> {
+        let previous = self.binding_in_context(context, key_code, modifiers);
+        let has_default = self
+            .default_bindings
+            .get(&context)
+            .is_some_and(|defaults| defaults.contains_key(&(key_code, modifiers)));
+        let context_bindings = self.bindings.entry(context).or_default();
+        if has_default {
+            context_bindings.insert((key_code, modifiers), Action::None);
+        } else {
+            context_bindings.remove(&(key_code, modifiers));
+        }
+        previous
+    }
+
+    /// The action a key is bound to in exactly this context (custom over default)
+    fn binding_in_context(
+        &self,
+        context: KeyContext,
+        key_code: KeyCode,
+        modifiers: KeyModifiers,
+    ) -> Option<Action> {
+        [&self.bindings, &self.default_bindings]
+            .into_iter()
+            .find_map(|source| source.get(&context)?.get(&(key_code, modifiers)))
+            .filter(|action| **action != Action::None)
+            .cloned()
+    }
+
+    /// List the single-key bindings of a context with their action names
+    ///
+    /// Custom bindings override defaults and unbound keys are left out. Returns
+    /// (key code, modifiers, action name), sorted by key.
+    pub fn list_bindings(&self, context: KeyContext) -> Vec<(KeyCode, KeyModifiers, String)> {
+        let mut keys: HashMap<(KeyCode, KeyModifiers), &Action> = HashMap::new();
+        for source in [&self.default_bindings, &self.bindings] {
+            if let Some(context_bindings) = source.get(&context) {
+                keys.extend(context_bindings.iter().map(|(key, action)| (*key, action)));
+            }
+        }
+
+        let mut bindings: Vec<_> = keys
+            .into_iter()
+            .filter(|(_, action)| **action != Action::None)
+            .map(|((key_code, modifiers), action)| (key_code, modifiers, self.action_name(action)))
+            .collect();
+        bindings.sort_by_key(|(key_code, modifiers, _)| {
+            (Self::key_code_sort_key(key_code), modifiers.bits())
+        });
+        bindings
+    }
+
     /// Reload bindings from config (for hot reload)
     pub fn reload(&mut self, config: &Config) {
         self.bindings.clear();
@@ -1854,6 +2656,8 @@ impl KeybindingResolver {
             if let Some(key_code) = Self::parse_key(&binding.key) {
                 let modifiers = Self::parse_modifiers(&binding.modifiers);
                 if let Some(action) = Action::from_str(&binding.action, &binding.args) {
+                    self.record_action_name(&action, &binding.action);
+
                     // Determine context from "when" clause
                     let context = if let Some(ref when) = binding.when {
                         KeyContext::from_when_clause(when).unwrap_or(KeyContext::Normal)
@@ -1909,6 +2713,64 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_set_and_remove_binding() {
+        let config = Config::default();
+        let mut resolver = KeybindingResolver::new(&config);
+        let f7 = KeyEvent::new(KeyCode::F(7), KeyModifiers::empty());
+        let end = KeyEvent::new(KeyCode::End, KeyModifiers::empty());
+
+        // Binding a free key reports no conflict and is listed by name
+        let previous = resolver.set_binding(
+            KeyContext::Normal,
+            KeyCode::F(7),
+            KeyModifiers::empty(),
+            "move_line_end",
+        );
+        assert_eq!(previous, None);
+        assert_eq!(
+            resolver.resolve(&f7, KeyContext::Normal),
+            Action::MoveLineEnd
+        );
+        let listed = resolver.list_bindings(KeyContext::Normal);
+        assert!(listed.contains(&(
+            KeyCode::F(7),
+            KeyModifiers::empty(),
+            "move_line_end".to_string()
+        )));
+
+        // Rebinding reports the action it replaced; unknown names become plugin actions
+        let previous = resolver.set_binding(
+            KeyContext::Normal,
+            KeyCode::F(7),
+            KeyModifiers::empty(),
+            "my_command",
+        );
+        assert_eq!(previous, Some(Action::MoveLineEnd));
+        assert_eq!(
+            resolver.resolve(&f7, KeyContext::Normal),
+            Action::PluginAction("my_command".to_string())
+        );
+
+        // Removing a custom binding frees the key
+        resolver.remove_binding(KeyContext::Normal, KeyCode::F(7), KeyModifiers::empty());
+        assert_eq!(resolver.resolve(&f7, KeyContext::Normal), Action::None);
+
+        // Removing a default binding shadows it and drops it from the listing
+        assert_eq!(
+            resolver.resolve(&end, KeyContext::Normal),
+            Action::MoveLineEnd
+        );
+        let removed =
+            resolver.remove_binding(KeyContext::Normal, KeyCode::End, KeyModifiers::empty());
+        assert_eq!(removed, Some(Action::MoveLineEnd));
+        assert_eq!(resolver.resolve(&end, KeyContext::Normal), Action::None);
+        assert!(!resolver
+            .list_bindings(KeyContext::Normal)
+            .iter()
+            .any(|(code, modifiers, _)| *code == KeyCode::End && modifiers.is_empty()));
+    }
+
     #[test]
     fn test_resolve_basic() {
         let config = Config::default();
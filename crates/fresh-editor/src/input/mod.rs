@@ -14,6 +14,7 @@ pub mod key_translator;
 pub mod keybindings;
 pub mod multi_cursor;
 pub mod position_history;
+pub mod quickfix;
 
 #[cfg(test)]
 pub mod tests_language_features;
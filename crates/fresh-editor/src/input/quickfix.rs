@@ -0,0 +1,156 @@
+/// Quickfix list: a reusable list of locations the user can step through
+///
+/// The list is filled by features that produce a set of locations, such as
+/// LSP find references or project-wide grep, and replaced wholesale each time
+/// a new result set arrives. Next/previous-item actions walk the list and
+/// wrap around at either end.
+use std::path::PathBuf;
+
+/// A single location in the quickfix list
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuickfixItem {
+    /// File containing the location
+    pub path: PathBuf,
+
+    /// Line number (1-indexed)
+    pub line: usize,
+
+    /// Column number (1-indexed)
+    pub column: usize,
+
+    /// Short description shown when the item is visited (e.g. the matched line)
+    pub text: String,
+}
+
+impl QuickfixItem {
+    /// Create a new quickfix item
+    pub fn new(path: PathBuf, line: usize, column: usize, text: impl Into<String>) -> Self {
+        Self {
+            path,
+            line,
+            column,
+            text: text.into(),
+        }
+    }
+}
+
+/// The quickfix list and the position of the item last visited
+#[derive(Debug, Default)]
+pub struct QuickfixList {
+    /// Items in the order they were produced
+    items: Vec<QuickfixItem>,
+
+    /// Index of the item last visited, None until the user steps into the list
+    current: Option<usize>,
+}
+
+impl QuickfixList {
+    /// Create an empty quickfix list
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the list contents, resetting the current position
+    pub fn set(&mut self, items: Vec<QuickfixItem>) {
+        self.items = items;
+        self.current = None;
+    }
+
+    /// Append items that arrived after the list was set, keeping the current position
+    pub fn extend(&mut self, items: impl IntoIterator<Item = QuickfixItem>) {
+        self.items.extend(items);
+    }
+
+    /// All items in the list
+    pub fn items(&self) -> &[QuickfixItem] {
+        &self.items
+    }
+
+    /// Number of items in the list
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Check if the list is empty
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Index of the item last visited
+    pub fn current_index(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// Advance to the next item, wrapping to the first after the last
+    pub fn next_item(&mut self) -> Option<&QuickfixItem> {
+        if self.items.is_empty() {
+            return None;
+        }
+        let len = self.items.len();
+        let index = self.current.map_or(0, |i| (i + 1) % len);
+        self.current = Some(index);
+        self.items.get(index)
+    }
+
+    /// Go back to the previous item, wrapping to the last before the first
+    pub fn prev_item(&mut self) -> Option<&QuickfixItem> {
+        if self.items.is_empty() {
+            return None;
+        }
+        let len = self.items.len();
+        let index = self.current.map_or(len - 1, |i| (i + len - 1) % len);
+        self.current = Some(index);
+        self.items.get(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_list(lines: &[usize]) -> QuickfixList {
+        let mut list = QuickfixList::new();
+        list.set(
+            lines
+                .iter()
+                .map(|&line| QuickfixItem::new(PathBuf::from("a.rs"), line, 1, ""))
+                .collect(),
+        );
+        list
+    }
+
+    #[test]
+    fn test_empty_list_has_no_items() {
+        let mut list = QuickfixList::new();
+        assert!(list.is_empty());
+        assert_eq!(list.next_item(), None);
+        assert_eq!(list.prev_item(), None);
+        assert_eq!(list.current_index(), None);
+    }
+
+    #[test]
+    fn test_next_wraps_around() {
+        let mut list = make_list(&[1, 5, 9]);
+        let visited: Vec<usize> = (0..4).map(|_| list.next_item().unwrap().line).collect();
+        assert_eq!(visited, vec![1, 5, 9, 1]);
+        assert_eq!(list.current_index(), Some(0));
+    }
+
+    #[test]
+    fn test_prev_starts_at_last_and_wraps() {
+        let mut list = make_list(&[1, 5, 9]);
+        let visited: Vec<usize> = (0..4).map(|_| list.prev_item().unwrap().line).collect();
+        assert_eq!(visited, vec![9, 5, 1, 9]);
+    }
+
+    #[test]
+    fn test_set_resets_position() {
+        let mut list = make_list(&[1, 5]);
+        list.next_item();
+        list.next_item();
+        list.set(vec![QuickfixItem::new(PathBuf::from("b.rs"), 3, 2, "x")]);
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.current_index(), None);
+        assert_eq!(list.next_item().unwrap().path, PathBuf::from("b.rs"));
+    }
+}
@@ -1,10 +1,11 @@
 #[cfg(test)]
 mod tests {
-    use crate::input::actions::get_auto_close_char;
+    use crate::input::actions::{get_auto_close_char, get_auto_close_tag};
     use crate::input::multi_cursor::{add_cursor_at_next_match, AddCursorResult};
     use crate::model::buffer::Buffer;
     use crate::primitives::word_navigation::{find_word_start_left, find_word_start_right};
     use crate::state::EditorState;
+    use std::path::Path;
 
     // --- Auto-Pairs Logic Tests ---
 
@@ -27,6 +28,44 @@ mod tests {
         assert_eq!(get_auto_close_char('{', true, "text"), Some('}'));
     }
 
+    #[test]
+    fn test_auto_close_tag_detection() {
+        let tag = |text: &str, lang: &str| get_auto_close_tag(text, true, lang, None);
+        let jsx = |text: &str, file: &str| {
+            get_auto_close_tag(text, true, "typescript", Some(Path::new(file)))
+        };
+
+        assert_eq!(tag("<div", "html"), Some("div".to_string()));
+        assert_eq!(
+            tag("<p><span class=\"a\"", "html"),
+            Some("span".to_string())
+        );
+        assert_eq!(tag("<svg:rect x='1'", "xml"), Some("svg:rect".to_string()));
+        assert_eq!(
+            jsx("return <Foo.Bar on={() => x}", "App.tsx"),
+            Some("Foo.Bar".to_string())
+        );
+
+        // Not opening tags
+        assert_eq!(tag("</div", "html"), None);
+        assert_eq!(tag("<!DOCTYPE html", "html"), None);
+        assert_eq!(tag("<br/", "html"), None);
+        assert_eq!(tag("<img src=\"a.png\"", "html"), None);
+        assert_eq!(tag("<a title=\"x", "html"), None);
+        assert_eq!(tag("<div>text", "html"), None);
+        assert_eq!(jsx("let v: Vec<u8", "App.tsx"), None);
+        assert_eq!(jsx("if (a < b", "App.jsx"), None);
+
+        // Plain .ts/.js files use `<T>` for generics and type assertions
+        assert_eq!(jsx("const f = <T", "util.ts"), None);
+        assert_eq!(jsx("const x = <Foo", "util.ts"), None);
+        assert_eq!(tag("return <div", "javascript"), None);
+
+        // Language-gated and disabled without auto_close
+        assert_eq!(tag("<div", "rust"), None);
+        assert_eq!(get_auto_close_tag("<div", false, "html", None), None);
+    }
+
     // --- Word Movement Tests ---
 
     #[test]
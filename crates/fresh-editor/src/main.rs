@@ -544,11 +544,6 @@ fn initialize_app(args: &Args) -> AnyhowResult<SetupState> {
         tracing::info!("Using GPM for mouse capture");
     }
 
-    // Set cursor style from config
-    use crossterm::ExecutableCommand;
-    let _ = stdout().execute(config.editor.cursor_style.to_crossterm_style());
-    tracing::info!("Set cursor style to {:?}", config.editor.cursor_style);
-
     let backend = ratatui::backend::CrosstermBackend::new(stdout());
     let mut terminal = Terminal::new(backend)?;
     terminal.clear()?;
@@ -937,11 +932,21 @@ where
             needs_render = true;
         }
 
+        // Abandon a pending chord once chord_timeout_ms has elapsed
+        if editor.check_chord_timeout() {
+            needs_render = true;
+        }
+
         // Check semantic highlight debounce timer
         if editor.check_semantic_highlight_timer() {
             needs_render = true;
         }
 
+        // Keep drawing frames while a smooth scroll is in progress
+        if editor.is_scroll_animating() {
+            needs_render = true;
+        }
+
         // Check for warnings and open warning log if any occurred
         if editor.check_warning_log() {
             needs_render = true;
@@ -963,7 +968,7 @@ where
         }
 
         if editor.should_quit() {
-            if session_enabled {
+            if session_enabled && editor.config().editor.save_session_on_quit {
                 if let Err(e) = editor.save_session() {
                     tracing::warn!("Failed to save session: {}", e);
                 } else {
@@ -975,6 +980,7 @@ where
 
         if needs_render && last_render.elapsed() >= FRAME_DURATION {
             terminal.draw(|frame| editor.render(frame))?;
+            editor.write_cursor_style(terminal.backend_mut())?;
             last_render = Instant::now();
             needs_render = false;
         }
@@ -1017,7 +1023,7 @@ where
             }
             CrosstermEvent::Paste(text) => {
                 // External paste from terminal (bracketed paste mode)
-                editor.paste_text(text);
+                editor.handle_paste(text);
                 needs_render = true;
             }
             _ => {}
@@ -86,6 +86,16 @@ impl LineEnding {
             Self::CR => "CR",
         }
     }
+
+    /// Rewrite every line ending in `text` (LF, CRLF or CR) to this format
+    pub fn apply_to(&self, text: &str) -> String {
+        let unified = text.replace("\r\n", "\n").replace('\r', "\n");
+        match self {
+            Self::LF => unified,
+            Self::CRLF => unified.replace('\n', "\r\n"),
+            Self::CR => unified.replace('\n', "\r"),
+        }
+    }
 }
 
 /// Represents a line number (simplified for new implementation)
@@ -171,6 +181,13 @@ pub struct TextBuffer {
     /// changed the line ending format and needs conversion on save.
     original_line_ending: LineEnding,
 
+    /// Whether the file had more than one kind of line ending when loaded.
+    /// The bytes are kept as-is; choosing a line ending normalizes them on save.
+    mixed_line_endings: bool,
+
+    /// Set when mixed line endings should be converted to `line_ending` on the next save
+    normalize_line_endings: bool,
+
     /// The file size on disk after the last save.
     /// Used for chunked recovery to know the original file size for reconstruction.
     /// Updated when loading from file or after saving.
@@ -178,6 +195,17 @@ pub struct TextBuffer {
 
     /// Monotonic version counter for change tracking.
     version: u64,
+
+    /// Identifies this buffer among all buffers created in the process, so
+    /// state derived from one buffer is not mistaken for another's
+    instance_id: u64,
+}
+
+/// Source of `TextBuffer::instance_id`
+static NEXT_INSTANCE_ID: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+fn next_instance_id() -> u64 {
+    NEXT_INSTANCE_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
 }
 
 impl TextBuffer {
@@ -198,8 +226,11 @@ impl TextBuffer {
             is_binary: false,
             line_ending,
             original_line_ending: line_ending,
+            mixed_line_endings: false,
+            normalize_line_endings: false,
             saved_file_size: None,
             version: 0,
+            instance_id: next_instance_id(),
         }
     }
 
@@ -208,6 +239,14 @@ impl TextBuffer {
         self.version
     }
 
+    /// Process-wide unique identifier of this buffer instance
+    ///
+    /// Versions and piece tree snapshots only compare meaningfully between
+    /// states of the same instance.
+    pub fn instance_id(&self) -> u64 {
+        self.instance_id
+    }
+
     #[inline]
     fn bump_version(&mut self) {
         self.version = self.version.wrapping_add(1);
@@ -226,6 +265,7 @@ impl TextBuffer {
 
         // Auto-detect line ending format from content
         let line_ending = Self::detect_line_ending(&content);
+        let mixed_line_endings = Self::detect_mixed_line_endings(&content);
 
         // Create initial StringBuffer with ID 0
         let buffer = StringBuffer::new(0, content);
@@ -242,6 +282,8 @@ impl TextBuffer {
         TextBuffer {
             line_ending,
             original_line_ending: line_ending,
+            mixed_line_endings,
+            normalize_line_endings: false,
             piece_tree,
             saved_root,
             buffers: vec![buffer],
@@ -253,6 +295,7 @@ impl TextBuffer {
             is_binary: false,
             saved_file_size: Some(bytes), // Treat initial content as "saved" state
             version: 0,
+            instance_id: next_instance_id(),
         }
     }
 
@@ -278,8 +321,11 @@ impl TextBuffer {
             is_binary: false,
             line_ending,
             original_line_ending: line_ending,
+            mixed_line_endings: false,
+            normalize_line_endings: false,
             saved_file_size: None,
             version: 0,
+            instance_id: next_instance_id(),
         }
     }
 
@@ -341,14 +387,15 @@ impl TextBuffer {
 
         // Read a sample of the file to detect if it's binary and line ending format
         // We read the first 8KB for both binary and line ending detection
-        let (is_binary, line_ending) = {
+        let (is_binary, line_ending, mixed_line_endings) = {
             let mut file = std::fs::File::open(path)?;
             let sample_size = file_size.min(8 * 1024);
             let mut sample = vec![0u8; sample_size];
             file.read_exact(&mut sample)?;
             let is_binary = Self::detect_binary(&sample);
             let line_ending = Self::detect_line_ending(&sample);
-            (is_binary, line_ending)
+            let mixed_line_endings = Self::detect_mixed_line_endings(&sample);
+            (is_binary, line_ending, mixed_line_endings)
         };
 
         // Create an unloaded buffer that references the entire file
@@ -388,8 +435,11 @@ impl TextBuffer {
             is_binary,
             line_ending,
             original_line_ending: line_ending,
+            mixed_line_endings,
+            normalize_line_endings: false,
             saved_file_size: Some(file_size),
             version: 0,
+            instance_id: next_instance_id(),
         })
     }
 
@@ -481,7 +531,8 @@ impl TextBuffer {
         let original_metadata = std::fs::metadata(dest_path).ok();
 
         // Check if we need to convert line endings
-        let needs_conversion = self.line_ending != self.original_line_ending;
+        let needs_conversion =
+            self.line_ending != self.original_line_ending || self.normalize_line_endings;
         let target_ending = self.line_ending;
 
         // Determine whether to use in-place writing to preserve file ownership.
@@ -647,6 +698,7 @@ impl TextBuffer {
         // Update original_line_ending to match what we just saved
         // This prevents repeated conversions on subsequent saves
         self.original_line_ending = self.line_ending;
+        self.normalize_line_endings = false;
 
         Ok(())
     }
@@ -746,11 +798,7 @@ impl TextBuffer {
         // First, quick check: if tree roots are identical (Arc pointer equality),
         // the content is definitely the same.
         if Arc::ptr_eq(&self.saved_root, &self.piece_tree.root()) {
-            return PieceTreeDiff {
-                equal: true,
-                byte_ranges: Vec::new(),
-                line_ranges: Some(Vec::new()),
-            };
+            return PieceTreeDiff::identical();
         }
 
         // Phase 1: Fast structure-based diff to find which byte ranges differ
@@ -782,11 +830,7 @@ impl TextBuffer {
                 return structure_diff;
             } else {
                 // Content is the same despite structure differences (rare case: undo/redo)
-                return PieceTreeDiff {
-                    equal: true,
-                    byte_ranges: Vec::new(),
-                    line_ranges: Some(Vec::new()),
-                };
+                return PieceTreeDiff::identical();
             }
         }
 
@@ -922,22 +966,57 @@ impl TextBuffer {
 
     /// Structure-based diff comparing piece tree leaves
     fn diff_trees_by_structure(&self) -> PieceTreeDiff {
-        crate::model::piece_tree_diff::diff_piece_trees(
-            &self.saved_root,
-            &self.piece_tree.root(),
-            &|leaf, start, len| {
-                if len == 0 {
-                    return Some(0);
-                }
-                let buf = self.buffers.get(leaf.location.buffer_id())?;
-                let data = buf.get_data()?;
-                let start = leaf.offset + start;
-                let end = start + len;
-                let slice = data.get(start..end)?;
-                let line_feeds = slice.iter().filter(|&&b| b == b'\n').count();
-                Some(line_feeds)
-            },
-        )
+        self.diff_tree_roots(&self.saved_root, &self.piece_tree.root())
+    }
+
+    /// Structure-based diff from the current piece tree to a snapshot of it
+    /// (see `snapshot_piece_tree`), e.g. the tree an undo is about to restore
+    pub fn diff_to_snapshot(&self, target: &PieceTree) -> PieceTreeDiff {
+        self.diff_tree_roots(&self.piece_tree.root(), &target.root())
+    }
+
+    /// Structure-based diff from a snapshot of this buffer's piece tree to the
+    /// current tree, e.g. the tree some derived state was built from
+    pub fn diff_from_snapshot(&self, source: &PieceTree) -> PieceTreeDiff {
+        self.diff_tree_roots(&source.root(), &self.piece_tree.root())
+    }
+
+    /// Narrow the spans of `diff` (from `diff_to_snapshot(target)`) to the bytes
+    /// whose content differs. Returns None if either span's text is unloaded.
+    pub fn narrow_snapshot_diff(
+        &self,
+        target: &PieceTree,
+        diff: &PieceTreeDiff,
+    ) -> Option<(std::ops::Range<usize>, std::ops::Range<usize>)> {
+        let before = &diff.before_span;
+        let after = &diff.after_span;
+        let before_text = self.get_text_range(before.start, before.len())?;
+        let after_text = self.get_tree_text_range(target, after.start, after.len())?;
+        Some(crate::model::piece_tree_diff::narrow_changed_span(
+            before.clone(),
+            after.clone(),
+            &before_text,
+            &after_text,
+        ))
+    }
+
+    fn diff_tree_roots(
+        &self,
+        before: &Arc<crate::model::piece_tree::PieceTreeNode>,
+        after: &Arc<crate::model::piece_tree::PieceTreeNode>,
+    ) -> PieceTreeDiff {
+        crate::model::piece_tree_diff::diff_piece_trees(before, after, &|leaf, start, len| {
+            if len == 0 {
+                return Some(0);
+            }
+            let buf = self.buffers.get(leaf.location.buffer_id())?;
+            let data = buf.get_data()?;
+            let start = leaf.offset + start;
+            let end = start + len;
+            let slice = data.get(start..end)?;
+            let line_feeds = slice.iter().filter(|&&b| b == b'\n').count();
+            Some(line_feeds)
+        })
     }
 
     /// Convert a byte offset to a line/column position
@@ -1194,6 +1273,26 @@ impl TextBuffer {
     /// Returns None if any buffer in the range is unloaded
     /// PRIVATE: External code should use get_text_range_mut() which handles lazy loading
     fn get_text_range(&self, offset: usize, bytes: usize) -> Option<Vec<u8>> {
+        self.get_tree_text_range(&self.piece_tree, offset, bytes)
+    }
+
+    /// Get text from a byte offset range of a snapshot of this buffer's piece tree
+    /// (see `snapshot_piece_tree`). Returns None if any buffer in the range is unloaded.
+    pub fn get_snapshot_text_range(
+        &self,
+        tree: &PieceTree,
+        offset: usize,
+        bytes: usize,
+    ) -> Option<Vec<u8>> {
+        self.get_tree_text_range(tree, offset, bytes)
+    }
+
+    fn get_tree_text_range(
+        &self,
+        tree: &PieceTree,
+        offset: usize,
+        bytes: usize,
+    ) -> Option<Vec<u8>> {
         if bytes == 0 {
             return Some(Vec::new());
         }
@@ -1203,7 +1302,7 @@ impl TextBuffer {
         let mut collected = 0;
 
         // Use the efficient piece iterator (single O(log n) traversal + O(N) iteration)
-        for piece_view in self.piece_tree.iter_pieces_in_range(offset, end_offset) {
+        for piece_view in tree.iter_pieces_in_range(offset, end_offset) {
             let buffer_id = piece_view.location.buffer_id();
             if let Some(buffer) = self.buffers.get(buffer_id) {
                 // Calculate the range to read from this piece
@@ -1630,12 +1729,22 @@ impl TextBuffer {
         self.line_ending
     }
 
+    /// Check if the file had a mix of line ending formats when loaded
+    pub fn has_mixed_line_endings(&self) -> bool {
+        self.mixed_line_endings
+    }
+
     /// Set the line ending format for this buffer
     ///
     /// This marks the buffer as modified since the line ending format has changed.
-    /// On save, the buffer content will be converted to the new format.
+    /// On save, the buffer content will be converted to the new format. If the
+    /// file had mixed line endings, all of them are normalized to this format.
     pub fn set_line_ending(&mut self, line_ending: LineEnding) {
         self.line_ending = line_ending;
+        if self.mixed_line_endings {
+            self.mixed_line_endings = false;
+            self.normalize_line_endings = true;
+        }
         self.mark_content_modified();
     }
 
@@ -1722,8 +1831,32 @@ impl TextBuffer {
     pub fn detect_line_ending(bytes: &[u8]) -> LineEnding {
         // Only check the first 8KB for line ending detection (same as binary detection)
         let check_len = bytes.len().min(8 * 1024);
-        let sample = &bytes[..check_len];
+        let (crlf_count, lf_only_count, cr_only_count) =
+            Self::count_line_endings(&bytes[..check_len]);
 
+        // Use majority voting to determine line ending
+        if crlf_count > lf_only_count && crlf_count > cr_only_count {
+            LineEnding::CRLF
+        } else if cr_only_count > lf_only_count && cr_only_count > crlf_count {
+            LineEnding::CR
+        } else {
+            // Default to LF if no clear winner or if LF wins
+            LineEnding::LF
+        }
+    }
+
+    /// Detect if the given bytes use more than one line ending format
+    pub fn detect_mixed_line_endings(bytes: &[u8]) -> bool {
+        let (crlf_count, lf_only_count, cr_only_count) = Self::count_line_endings(bytes);
+        [crlf_count, lf_only_count, cr_only_count]
+            .iter()
+            .filter(|&&count| count > 0)
+            .count()
+            > 1
+    }
+
+    /// Count CRLF, LF-only and CR-only line endings in the given bytes
+    fn count_line_endings(sample: &[u8]) -> (usize, usize, usize) {
         let mut crlf_count = 0;
         let mut lf_only_count = 0;
         let mut cr_only_count = 0;
@@ -1747,15 +1880,7 @@ impl TextBuffer {
             i += 1;
         }
 
-        // Use majority voting to determine line ending
-        if crlf_count > lf_only_count && crlf_count > cr_only_count {
-            LineEnding::CRLF
-        } else if cr_only_count > lf_only_count && cr_only_count > crlf_count {
-            LineEnding::CR
-        } else {
-            // Default to LF if no clear winner or if LF wins
-            LineEnding::LF
-        }
+        (crlf_count, lf_only_count, cr_only_count)
     }
 
     /// Normalize line endings in the given bytes to LF only
@@ -2007,6 +2132,17 @@ impl TextBuffer {
 
     /// Find regex pattern in a byte range using overlapping chunks
     fn find_regex(&self, start: usize, end: usize, regex: &Regex) -> Option<usize> {
+        self.find_regex_match(start, end, regex)
+            .map(|range| range.start)
+    }
+
+    /// Find the first regex match in a byte range, returning the matched range
+    pub fn find_regex_match(
+        &self,
+        start: usize,
+        end: usize,
+        regex: &Regex,
+    ) -> Option<Range<usize>> {
         if start >= end {
             return None;
         }
@@ -2029,7 +2165,7 @@ impl TextBuffer {
                     // Verify the match doesn't extend beyond our search range
                     let match_len = mat.end() - mat.start();
                     if absolute_pos + match_len <= end {
-                        return Some(absolute_pos);
+                        return Some(absolute_pos..absolute_pos + match_len);
                     }
                 }
             }
@@ -2038,6 +2174,52 @@ impl TextBuffer {
         None
     }
 
+    /// Find all non-empty, non-overlapping regex matches in a byte range
+    ///
+    /// Streams the range through overlapping chunks like `find_regex_match`,
+    /// so the buffer is never materialized as a whole.
+    pub fn find_all_regex_matches(
+        &self,
+        start: usize,
+        end: usize,
+        regex: &Regex,
+    ) -> Vec<Range<usize>> {
+        let mut matches = Vec::new();
+        if start >= end {
+            return matches;
+        }
+
+        const CHUNK_SIZE: usize = 1048576; // 1MB chunks
+        const OVERLAP: usize = 4096; // 4KB overlap for regex
+
+        let mut last_end = start;
+        for chunk in OverlappingChunks::new(self, start, end, CHUNK_SIZE, OVERLAP) {
+            // Resume after the last reported match so overlap bytes are not matched twice
+            let mut pos = last_end.saturating_sub(chunk.absolute_pos);
+            while pos <= chunk.buffer.len() {
+                let Some(mat) = regex.find_at(&chunk.buffer, pos) else {
+                    break;
+                };
+                if mat.start() == mat.end() {
+                    pos = mat.end() + 1;
+                    continue;
+                }
+                pos = mat.end();
+                if mat.end() <= chunk.valid_start {
+                    continue;
+                }
+                let range = chunk.absolute_pos + mat.start()..chunk.absolute_pos + mat.end();
+                if range.end > end {
+                    break;
+                }
+                last_end = range.end;
+                matches.push(range);
+            }
+        }
+
+        matches
+    }
+
     /// Replace a range with replacement text
     pub fn replace_range(&mut self, range: Range<usize>, replacement: &str) -> bool {
         if range.start >= self.len() {
@@ -2303,6 +2485,11 @@ impl TextBuffer {
         self.prev_char_boundary(pos)
     }
 
+    /// Check whether `pos` is within the buffer and on a UTF-8 character boundary
+    pub fn is_char_boundary(&self, pos: usize) -> bool {
+        pos <= self.len() && self.snap_to_char_boundary(pos) == pos
+    }
+
     /// Find the previous grapheme cluster boundary (for proper cursor movement with combining characters)
     ///
     /// This handles complex scripts like Thai where multiple Unicode code points
@@ -4091,6 +4278,14 @@ mod tests {
             assert_eq!(result, b"No line endings here".to_vec());
         }
 
+        #[test]
+        fn test_line_ending_apply_to_text() {
+            let text = "a\r\nb\nc\rd";
+            assert_eq!(LineEnding::LF.apply_to(text), "a\nb\nc\nd");
+            assert_eq!(LineEnding::CRLF.apply_to(text), "a\r\nb\r\nc\r\nd");
+            assert_eq!(LineEnding::CR.apply_to(text), "a\rb\rc\rd");
+        }
+
         #[test]
         fn test_set_line_ending_marks_modified() {
             let mut buffer = TextBuffer::from_bytes(b"Hello\nWorld\n".to_vec());
@@ -4110,6 +4305,59 @@ mod tests {
             assert_eq!(buffer.line_ending(), LineEnding::CRLF);
         }
 
+        #[test]
+        fn test_detect_mixed_line_endings() {
+            assert!(TextBuffer::detect_mixed_line_endings(b"a\r\nb\nc\r\n"));
+            assert!(TextBuffer::detect_mixed_line_endings(b"a\rb\n"));
+            assert!(!TextBuffer::detect_mixed_line_endings(b"a\r\nb\r\n"));
+            assert!(!TextBuffer::detect_mixed_line_endings(b"a\nb\n"));
+            assert!(!TextBuffer::detect_mixed_line_endings(b"no newline"));
+        }
+
+        #[test]
+        fn test_save_preserves_mixed_line_endings() {
+            use tempfile::TempDir;
+
+            let temp_dir = TempDir::new().unwrap();
+            let file_path = temp_dir.path().join("mixed.txt");
+            let original_content = b"Line 1\r\nLine 2\nLine 3\r\n";
+            std::fs::write(&file_path, original_content).unwrap();
+
+            let mut buffer =
+                TextBuffer::load_from_file(&file_path, DEFAULT_LARGE_FILE_THRESHOLD).unwrap();
+            assert!(buffer.has_mixed_line_endings());
+            // Each line ending counts as one line, whatever its format
+            assert_eq!(buffer.line_count(), Some(4));
+
+            buffer.insert_bytes(0, b"X".to_vec());
+            buffer.save_to_file(&file_path).unwrap();
+
+            let saved_bytes = std::fs::read(&file_path).unwrap();
+            assert_eq!(&saved_bytes, b"XLine 1\r\nLine 2\nLine 3\r\n");
+            assert!(buffer.has_mixed_line_endings());
+        }
+
+        #[test]
+        fn test_set_line_ending_normalizes_mixed_line_endings() {
+            use tempfile::TempDir;
+
+            let temp_dir = TempDir::new().unwrap();
+            let file_path = temp_dir.path().join("mixed.txt");
+            std::fs::write(&file_path, b"Line 1\r\nLine 2\nLine 3\r\n").unwrap();
+
+            let mut buffer =
+                TextBuffer::load_from_file(&file_path, DEFAULT_LARGE_FILE_THRESHOLD).unwrap();
+            assert_eq!(buffer.line_ending(), LineEnding::CRLF);
+
+            // Choosing the detected format still normalizes the stray LF
+            buffer.set_line_ending(LineEnding::CRLF);
+            assert!(!buffer.has_mixed_line_endings());
+            buffer.save_to_file(&file_path).unwrap();
+
+            let saved_bytes = std::fs::read(&file_path).unwrap();
+            assert_eq!(&saved_bytes, b"Line 1\r\nLine 2\r\nLine 3\r\n");
+        }
+
         #[test]
         fn test_save_to_file_converts_lf_to_crlf() {
             use tempfile::TempDir;
@@ -4,6 +4,7 @@ pub use fresh_core::{BufferId, CursorId, SplitDirection, SplitId};
 use serde::{Deserialize, Serialize};
 use std::ops::Range;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 /// Core event types representing all possible state changes
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -210,6 +211,10 @@ pub enum Event {
         old_cursors: Vec<(CursorId, usize, Option<usize>)>,
         /// Cursor states after the edit
         new_cursors: Vec<(CursorId, usize, Option<usize>)>,
+        /// The edits as (position, old_len, new_len) in "before" tree offsets,
+        /// sorted by position descending so each stays valid when applied in order
+        #[serde(default)]
+        edits: Vec<(usize, usize, usize)>,
         /// Human-readable description
         description: String,
     },
@@ -418,6 +423,7 @@ impl Event {
                 new_tree,
                 old_cursors,
                 new_cursors,
+                edits,
                 description,
             } => {
                 // Inverse swaps both trees and cursor states
@@ -427,6 +433,7 @@ impl Event {
                     new_tree: old_tree.clone(),
                     old_cursors: new_cursors.clone(),
                     new_cursors: old_cursors.clone(),
+                    edits: invert_bulk_edits(edits),
                     description: format!("Undo: {}", description),
                 })
             }
@@ -472,6 +479,34 @@ impl Event {
         }
     }
 
+    /// Approximate memory used by this event, for bounding undo history
+    ///
+    /// Piece trees held by BulkEdit are shared with the buffer and not counted.
+    pub fn approx_size(&self) -> usize {
+        let heap = match self {
+            Self::Insert { text, .. } => text.len(),
+            Self::Delete { deleted_text, .. } => deleted_text.len(),
+            Self::Batch {
+                events,
+                description,
+            } => description.len() + events.iter().map(Self::approx_size).sum::<usize>(),
+            Self::BulkEdit {
+                old_cursors,
+                new_cursors,
+                edits,
+                description,
+                ..
+            } => {
+                description.len()
+                    + (old_cursors.len() + new_cursors.len())
+                        * std::mem::size_of::<(CursorId, usize, Option<usize>)>()
+                    + edits.len() * std::mem::size_of::<(usize, usize, usize)>()
+            }
+            _ => 0,
+        };
+        std::mem::size_of::<Self>() + heap
+    }
+
     /// Returns the cursor ID associated with this event, if any
     pub fn cursor_id(&self) -> Option<CursorId> {
         match self {
@@ -496,6 +531,10 @@ pub struct LogEntry {
 
     /// Optional description for debugging
     pub description: Option<String>,
+
+    /// Whether this entry is undone and redone together with the one before it
+    #[serde(default)]
+    pub continues_group: bool,
 }
 
 impl LogEntry {
@@ -507,6 +546,7 @@ impl LogEntry {
                 .unwrap()
                 .as_millis() as u64,
             description: None,
+            continues_group: false,
         }
     }
 
@@ -530,6 +570,91 @@ pub struct Snapshot {
     pub cursor_positions: Vec<(CursorId, usize, Option<usize>)>,
 }
 
+/// The edits that undo `edits` (see `Event::BulkEdit`), in "after" tree offsets
+///
+/// Each edit moves by the length change of the edits before it; edits at the
+/// same position share one shift. The result keeps the descending order.
+fn invert_bulk_edits(edits: &[(usize, usize, usize)]) -> Vec<(usize, usize, usize)> {
+    let mut inverse = vec![(0, 0, 0); edits.len()];
+    let mut shift: isize = 0;
+    let mut pending: isize = 0;
+    let mut last_pos = None;
+    for (i, &(pos, old_len, new_len)) in edits.iter().enumerate().rev() {
+        if last_pos != Some(pos) {
+            shift += pending;
+            pending = 0;
+            last_pos = Some(pos);
+        }
+        inverse[i] = ((pos as isize + shift) as usize, new_len, old_len);
+        pending += new_len as isize - old_len as isize;
+    }
+    inverse
+}
+
+/// Typed characters further apart than this start a new undo group
+const TYPING_GROUP_WINDOW: Duration = Duration::from_secs(1);
+
+/// The typed text of a single-character insert or backspace, with the range it
+/// covered
+fn typed_edit(event: &Event) -> Option<(bool, Range<usize>, &str, CursorId)> {
+    let (inserted, range, text, cursor_id) = match event {
+        Event::Insert {
+            position,
+            text,
+            cursor_id,
+        } => (true, *position..*position + text.len(), text, *cursor_id),
+        Event::Delete {
+            range,
+            deleted_text,
+            cursor_id,
+        } => (false, range.clone(), deleted_text, *cursor_id),
+        _ => return None,
+    };
+    let mut chars = text.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c != '\n' && c != '\r' => Some((inserted, range, text, cursor_id)),
+        _ => None,
+    }
+}
+
+/// Whether `event` extends the run of typing that ends with `previous`
+fn continues_typing(previous: &Event, event: &Event) -> bool {
+    let (
+        Some((prev_insert, prev_range, prev_text, prev_cursor)),
+        Some((insert, range, text, cursor)),
+    ) = (typed_edit(previous), typed_edit(event))
+    else {
+        return false;
+    };
+    let adjacent = if insert {
+        range.start == prev_range.end
+    } else {
+        range.end == prev_range.start
+    };
+    let starts_word = insert
+        && prev_text.chars().all(char::is_whitespace)
+        && !text.chars().all(char::is_whitespace);
+    insert == prev_insert && cursor == prev_cursor && adjacent && !starts_word
+}
+
+/// Whether an event can be replayed after a round trip through serialization
+fn is_persistable(event: &Event) -> bool {
+    match event {
+        Event::BulkEdit { .. } => false,
+        Event::Batch { events, .. } => events.iter().all(|event| {
+            matches!(
+                event,
+                Event::Insert { .. } | Event::Delete { .. } | Event::MoveCursor { .. }
+            )
+        }),
+        _ => true,
+    }
+}
+
+/// When trimming history, drop an extra 1/N of the limit so that editing at
+/// the limit doesn't shift the whole log on every append
+const HISTORY_TRIM_SLACK_DIVISOR: usize = 16;
+
 /// The event log - append-only log of all events
 pub struct EventLog {
     /// All logged events
@@ -550,6 +675,21 @@ pub struct EventLog {
     /// Index at which the buffer was last saved (for tracking modified status)
     /// When current_index equals saved_at_index, the buffer is not modified
     saved_at_index: Option<usize>,
+
+    /// Maximum number of entries to keep (0 = unlimited)
+    max_entries: usize,
+
+    /// Approximate memory bound for the entries in bytes (0 = unlimited)
+    max_bytes: usize,
+
+    /// Approximate memory used by the entries (see `Event::approx_size`)
+    entries_bytes: usize,
+
+    /// Number of oldest entries dropped to stay within the limits
+    dropped_entries: usize,
+
+    /// When the last entry was appended by `append_typed`, if it was
+    last_typed_at: Option<Instant>,
 }
 
 impl EventLog {
@@ -562,9 +702,86 @@ impl EventLog {
             snapshot_interval: 100,
             stream_file: None,
             saved_at_index: Some(0), // New buffer starts at "saved" state (index 0)
+            max_entries: 0,
+            max_bytes: 0,
+            entries_bytes: 0,
+            dropped_entries: 0,
+            last_typed_at: None,
         }
     }
 
+    /// Create a new empty event log that keeps at most `max_entries` entries
+    /// and roughly `max_bytes` of history (0 = unlimited)
+    pub fn with_limits(max_entries: usize, max_bytes: usize) -> Self {
+        let mut log = Self::new();
+        log.set_limits(max_entries, max_bytes);
+        log
+    }
+
+    /// Change the history limits, dropping the oldest entries if now over them
+    pub fn set_limits(&mut self, max_entries: usize, max_bytes: usize) {
+        self.max_entries = max_entries;
+        self.max_bytes = max_bytes;
+        self.enforce_limits();
+    }
+
+    /// Number of oldest entries dropped so far to stay within the history limits
+    pub fn dropped_entries(&self) -> usize {
+        self.dropped_entries
+    }
+
+    /// Drop the oldest entries until the log is within its limits
+    ///
+    /// Entries that can still be redone, and the most recent undoable entry,
+    /// are never dropped.
+    fn enforce_limits(&mut self) {
+        let over_entries = self.max_entries > 0 && self.entries.len() > self.max_entries;
+        let over_bytes = self.max_bytes > 0 && self.entries_bytes > self.max_bytes;
+        if !over_entries && !over_bytes {
+            return;
+        }
+
+        let target_entries = match self.max_entries {
+            0 => usize::MAX,
+            max => max - max / HISTORY_TRIM_SLACK_DIVISOR,
+        };
+        let target_bytes = match self.max_bytes {
+            0 => usize::MAX,
+            max => max - max / HISTORY_TRIM_SLACK_DIVISOR,
+        };
+        let droppable = self.current_index.saturating_sub(1);
+
+        let mut count = 0;
+        let mut bytes = self.entries_bytes;
+        while count < droppable
+            && (self.entries.len() - count > target_entries || bytes > target_bytes)
+        {
+            bytes -= self.entries[count].event.approx_size();
+            count += 1;
+        }
+        if count == 0 {
+            return;
+        }
+
+        self.entries.drain(..count);
+        self.entries_bytes = bytes;
+        self.current_index -= count;
+        // The saved point can no longer be reached if it was dropped
+        self.saved_at_index = self.saved_at_index.and_then(|idx| idx.checked_sub(count));
+        self.snapshots
+            .retain(|snapshot| snapshot.log_index >= count);
+        for snapshot in &mut self.snapshots {
+            snapshot.log_index -= count;
+        }
+        self.dropped_entries += count;
+        tracing::debug!(
+            "Dropped {} oldest undo history entries ({} kept, ~{} bytes)",
+            count,
+            self.entries.len(),
+            self.entries_bytes
+        );
+    }
+
     /// Mark the current position as the saved point
     /// Call this when the buffer is saved to disk
     pub fn mark_saved(&mut self) {
@@ -673,6 +890,10 @@ impl EventLog {
     pub fn append(&mut self, event: Event) -> usize {
         // If we're not at the end, truncate future events
         if self.current_index < self.entries.len() {
+            self.entries_bytes -= self.entries[self.current_index..]
+                .iter()
+                .map(|entry| entry.event.approx_size())
+                .sum::<usize>();
             self.entries.truncate(self.current_index);
 
             // Invalidate saved_at_index if it pointed to a truncated entry
@@ -702,6 +923,7 @@ impl EventLog {
             }
         }
 
+        self.entries_bytes += event.approx_size();
         let entry = LogEntry::new(event);
         self.entries.push(entry);
         self.current_index = self.entries.len();
@@ -712,9 +934,34 @@ impl EventLog {
             // For now, just track that we'd create one here
         }
 
+        self.enforce_limits();
+
         self.current_index - 1
     }
 
+    /// Append an event produced by typing or backspacing a single character
+    ///
+    /// A run of such edits at adjacent positions, without pauses, cursor
+    /// jumps or other events in between, is undone as one group. A word
+    /// typed after whitespace starts a new group, as does any edit after a save.
+    pub fn append_typed(&mut self, event: Event, now: Instant) -> usize {
+        let continues_group = !self.can_redo()
+            && self.saved_at_index != Some(self.current_index)
+            && self
+                .last_typed_at
+                .is_some_and(|at| now.saturating_duration_since(at) <= TYPING_GROUP_WINDOW)
+            && self
+                .entries
+                .last()
+                .is_some_and(|previous| continues_typing(&previous.event, &event));
+        let index = self.append(event);
+        if let Some(entry) = self.entries.last_mut() {
+            entry.continues_group = continues_group;
+        }
+        self.last_typed_at = Some(now);
+        index
+    }
+
     /// Get the current event index
     pub fn current_index(&self) -> usize {
         self.current_index
@@ -752,8 +999,9 @@ impl EventLog {
             self.current_index -= 1;
             let event = &self.entries[self.current_index].event;
 
-            // Check if this is a write action - we'll stop after processing it
-            if event.is_write_action() {
+            // Check if this is a write action - we'll stop after processing it,
+            // unless it continues a group started by an earlier write
+            if event.is_write_action() && !self.entries[self.current_index].continues_group {
                 found_write_action = true;
             }
 
@@ -776,10 +1024,12 @@ impl EventLog {
 
         // Keep moving forward to collect write action and subsequent readonly events
         while self.can_redo() {
-            let event = self.entries[self.current_index].event.clone();
+            let entry = &self.entries[self.current_index];
+            let event = entry.event.clone();
 
-            // If we've already found a write action and this is another write action, stop
-            if found_write_action && event.is_write_action() {
+            // If we've already found a write action and this is another write action
+            // outside its group, stop
+            if found_write_action && event.is_write_action() && !entry.continues_group {
                 // Don't include this event, it's the next write action
                 break;
             }
@@ -816,11 +1066,63 @@ impl EventLog {
         }
     }
 
+    /// The history to keep across sessions, with the position of the saved point in it
+    ///
+    /// Only edits that can be replayed from their serialized form are kept:
+    /// readonly events are dropped, and the history is cut at any bulk edit
+    /// since its tree snapshots aren't serialized. Returns None if the saved
+    /// point is no longer in the log or the history around it is empty.
+    pub fn persistable_history(&self) -> Option<(Vec<LogEntry>, usize)> {
+        let saved_at = self.saved_at_index?;
+        let start = self.entries[..saved_at]
+            .iter()
+            .rposition(|entry| !is_persistable(&entry.event))
+            .map_or(0, |i| i + 1);
+        let end = self.entries[saved_at..]
+            .iter()
+            .position(|entry| !is_persistable(&entry.event))
+            .map_or(self.entries.len(), |i| saved_at + i);
+
+        let keep = |entry: &&LogEntry| entry.event.modifies_buffer();
+        let before: Vec<LogEntry> = self.entries[start..saved_at]
+            .iter()
+            .filter(keep)
+            .cloned()
+            .collect();
+        let position = before.len();
+        let mut entries = before;
+        entries.extend(self.entries[saved_at..end].iter().filter(keep).cloned());
+        if entries.is_empty() {
+            return None;
+        }
+        if let Some(first) = entries.first_mut() {
+            // A group cut off at the start begins here instead
+            first.continues_group = false;
+        }
+        Some((entries, position))
+    }
+
+    /// Replace the log with history from an earlier session
+    ///
+    /// `position` is where the current buffer content sits in `entries`; it
+    /// becomes both the current and the saved position.
+    pub fn restore_history(&mut self, entries: Vec<LogEntry>, position: usize) {
+        self.clear();
+        self.entries_bytes = entries.iter().map(|entry| entry.event.approx_size()).sum();
+        self.entries = entries;
+        self.current_index = position.min(self.entries.len());
+        self.saved_at_index = Some(self.current_index);
+        self.last_typed_at = None;
+        self.enforce_limits();
+    }
+
     /// Clear all events (for testing or reset)
     pub fn clear(&mut self) {
         self.entries.clear();
         self.current_index = 0;
         self.snapshots.clear();
+        self.entries_bytes = 0;
+        self.dropped_entries = 0;
     }
 
     /// Save event log to JSON Lines format
@@ -851,6 +1153,7 @@ impl EventLog {
                 continue;
             }
             let entry: LogEntry = serde_json::from_str(&line)?;
+            log.entries_bytes += entry.event.approx_size();
             log.entries.push(entry);
         }
 
@@ -998,6 +1301,75 @@ mod tests {
         assert_eq!(log.entries().len(), 1);
     }
 
+    fn typed_at(log: &mut EventLog, position: usize, text: &str, now: Instant) {
+        log.append_typed(
+            Event::Insert {
+                position,
+                text: text.to_string(),
+                cursor_id: CursorId(0),
+            },
+            now,
+        );
+    }
+
+    fn typed(log: &mut EventLog, position: usize, text: &str) {
+        typed_at(log, position, text, Instant::now());
+    }
+
+    #[test]
+    fn test_typed_characters_undo_as_one_group() {
+        let mut log = EventLog::new();
+        for (i, c) in "hello".chars().enumerate() {
+            typed(&mut log, i, &c.to_string());
+        }
+
+        assert_eq!(log.undo().len(), 5);
+        assert!(!log.can_undo());
+        assert_eq!(log.redo().len(), 5);
+        assert!(!log.can_redo());
+    }
+
+    #[test]
+    fn test_typing_group_breaks() {
+        let mut log = EventLog::new();
+        for (i, c) in "hi there".chars().enumerate() {
+            typed(&mut log, i, &c.to_string());
+        }
+        // Typing somewhere else after a cursor jump
+        typed(&mut log, 0, "X");
+        // A newline is never grouped
+        typed(&mut log, 1, "\n");
+
+        assert_eq!(log.undo().len(), 1); // "\n"
+        assert_eq!(log.undo().len(), 1); // "X"
+        assert_eq!(log.undo().len(), 5); // "there"
+        assert_eq!(log.undo().len(), 3); // "hi "
+        assert!(!log.can_undo());
+    }
+
+    #[test]
+    fn test_typing_group_breaks_after_pause() {
+        let mut log = EventLog::new();
+        let start = Instant::now();
+        typed_at(&mut log, 0, "a", start);
+        typed_at(&mut log, 1, "b", start + Duration::from_millis(500));
+        typed_at(&mut log, 2, "c", start + Duration::from_secs(3));
+
+        assert_eq!(log.undo().len(), 1);
+        assert_eq!(log.undo().len(), 2);
+    }
+
+    #[test]
+    fn test_typing_group_breaks_at_save_point() {
+        let mut log = EventLog::new();
+        typed(&mut log, 0, "a");
+        log.mark_saved();
+        typed(&mut log, 1, "b");
+
+        assert_eq!(log.undo().len(), 1);
+        assert!(log.is_at_saved_position());
+    }
+
     #[test]
     fn test_undo_redo() {
         let mut log = EventLog::new();
@@ -1054,6 +1426,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_bulk_edit_inverse_maps_edits_to_after_offsets() {
+        // "aaa bb c" -> "a XYZc"
+        let bulk_edit = Event::BulkEdit {
+            old_tree: None,
+            new_tree: None,
+            old_cursors: Vec::new(),
+            new_cursors: Vec::new(),
+            edits: vec![(6, 1, 3), (4, 2, 0), (0, 3, 1)],
+            description: "Replace".to_string(),
+        };
+
+        match bulk_edit.inverse().unwrap() {
+            Event::BulkEdit { edits, .. } => {
+                assert_eq!(edits, vec![(2, 3, 1), (2, 0, 2), (0, 1, 3)]);
+            }
+            _ => panic!("Expected BulkEdit event"),
+        }
+    }
+
     #[test]
     fn test_truncate_on_new_event_after_undo() {
         let mut log = EventLog::new();
@@ -1140,4 +1532,91 @@ mod tests {
             "Should not be at saved position after undo + new edit"
         );
     }
+
+    fn insert(position: usize, text: &str) -> Event {
+        Event::Insert {
+            position,
+            text: text.to_string(),
+            cursor_id: CursorId(0),
+        }
+    }
+
+    #[test]
+    fn test_history_limit_drops_oldest_entries() {
+        let mut log = EventLog::with_limits(10, 0);
+
+        for i in 0..50 {
+            log.append(insert(i, "x"));
+        }
+
+        // Only the most recent edits are kept
+        assert_eq!(log.entries().len(), 10);
+        assert_eq!(log.current_index(), 10);
+        assert_eq!(log.dropped_entries(), 40);
+        assert!(matches!(
+            log.entries()[0].event,
+            Event::Insert { position: 40, .. }
+        ));
+
+        // Recent undo still works, back to the oldest kept edit
+        for i in (40..50).rev() {
+            let events = log.undo();
+            assert!(matches!(events[..], [Event::Delete { ref range, .. }] if range.start == i));
+        }
+        assert!(!log.can_undo());
+
+        // ...and so does redo
+        assert_eq!(log.redo().len(), 1);
+        assert_eq!(log.current_index(), 1);
+    }
+
+    #[test]
+    fn test_history_limit_keeps_redo_entries() {
+        let mut log = EventLog::new();
+        for i in 0..6 {
+            log.append(insert(i, "x"));
+        }
+        log.mark_saved();
+        for _ in 0..3 {
+            log.undo();
+        }
+
+        // Lowering the limit only drops undoable entries, keeping the latest one
+        log.set_limits(2, 0);
+        assert_eq!(log.dropped_entries(), 2);
+        assert_eq!(log.entries().len(), 4);
+        assert_eq!(log.current_index(), 1);
+
+        for _ in 0..3 {
+            assert_eq!(log.redo().len(), 1);
+        }
+        assert!(!log.can_redo());
+        assert!(log.is_at_saved_position());
+
+        // A new edit discards the redo branch and is trimmed back to the limit
+        log.undo();
+        log.append(insert(0, "y"));
+        assert_eq!(log.entries().len(), 2);
+        assert_eq!(log.dropped_entries(), 4);
+        assert!(!log.is_at_saved_position());
+    }
+
+    #[test]
+    fn test_history_memory_bound() {
+        let big = "x".repeat(1000);
+        let entry_size = insert(0, &big).approx_size();
+        let mut log = EventLog::with_limits(0, entry_size * 4);
+
+        for i in 0..20 {
+            log.append(insert(i, &big));
+        }
+
+        assert!(log.entries().len() <= 4);
+        assert_eq!(log.dropped_entries(), 20 - log.entries().len());
+
+        // An edit larger than the whole bound is still undoable
+        log.append(insert(0, &"y".repeat(entry_size * 8)));
+        assert_eq!(log.entries().len(), 1);
+        assert_eq!(log.undo().len(), 1);
+    }
 }
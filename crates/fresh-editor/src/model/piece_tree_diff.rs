@@ -1,7 +1,11 @@
 use std::ops::Range;
 use std::sync::Arc;
 
-use crate::model::piece_tree::{LeafData, PieceTreeNode};
+use crate::model::piece_tree::{BufferLocation, LeafData, PieceTreeNode};
+
+/// Largest LCS table (before pieces × after pieces) worth building. Bigger
+/// diffs fall back to a linear walk that may merge nearby changes.
+const MAX_LCS_CELLS: usize = 1 << 20;
 
 /// Summary of differences between two piece tree roots.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -12,13 +16,20 @@ pub struct PieceTreeDiff {
     pub byte_ranges: Vec<Range<usize>>,
     /// Changed line ranges in the "after" tree (exclusive end). `None` when line counts are unknown.
     pub line_ranges: Option<Vec<Range<usize>>>,
+    /// Single contiguous span in the "before" tree covering every difference.
+    /// Replacing it with `after_span` turns "before" into "after". Empty when `equal` is true.
+    pub before_span: Range<usize>,
+    /// The "after" tree bytes that replace `before_span`.
+    pub after_span: Range<usize>,
 }
 
 /// Compute a diff between two piece tree roots.
 ///
 /// Comparison happens at the byte-span level (not whole leaves) so split leaves
-/// still align. The result identifies the minimal contiguous range in the
-/// "after" tree that differs from "before".
+/// still align. After trimming the common prefix and suffix, the remaining
+/// spans are aligned with a longest common subsequence, so separate edits are
+/// reported as separate disjoint ranges in the "after" tree. Deletions show up
+/// as empty ranges at the point where text was removed.
 ///
 /// `line_counter` should return the number of line feeds in a slice of a leaf.
 /// If it returns None for any consulted slice, the diff will have line_range=None.
@@ -37,24 +48,28 @@ pub fn diff_piece_trees(
 
     // Fast-path: identical leaf sequences.
     if leaf_slices_equal(&before_leaves, &after_leaves) {
-        return PieceTreeDiff {
-            equal: true,
-            byte_ranges: Vec::new(),
-            line_ranges: Some(Vec::new()),
-        };
+        return PieceTreeDiff::identical();
     }
 
     let before_spans = with_doc_offsets(&before_leaves);
     let after_spans = with_doc_offsets(&after_leaves);
 
-    let _total_after = sum_bytes(&after_leaves);
+    let total_before = sum_bytes(&before_leaves);
+    let total_after = sum_bytes(&after_leaves);
 
     // Longest common prefix at byte granularity.
     let prefix = common_prefix_bytes(&before_spans, &after_spans);
     // Longest common suffix without overlapping prefix.
-    let suffix = common_suffix_bytes(&before_spans, &after_spans, prefix);
+    let suffix = common_suffix_bytes(&before_spans, &after_spans, prefix)
+        .min(total_before.saturating_sub(prefix));
 
-    let ranges = collect_diff_ranges(&before_spans, &after_spans, prefix, suffix);
+    let ranges = lcs_diff_ranges(
+        &before_spans,
+        &after_spans,
+        prefix..total_before - suffix,
+        prefix..total_after - suffix,
+    )
+    .unwrap_or_else(|| collect_diff_ranges(&before_spans, &after_spans, prefix, suffix));
 
     // Map byte ranges to line ranges (best effort).
     let line_ranges = line_ranges(&after_spans, &ranges, line_counter);
@@ -63,7 +78,67 @@ pub fn diff_piece_trees(
         equal: false,
         byte_ranges: ranges,
         line_ranges,
+        before_span: prefix..total_before - suffix,
+        after_span: prefix..total_after - suffix,
+    }
+}
+
+impl PieceTreeDiff {
+    /// Diff of two trees with identical content.
+    pub fn identical() -> Self {
+        Self {
+            equal: true,
+            byte_ranges: Vec::new(),
+            line_ranges: Some(Vec::new()),
+            before_span: 0..0,
+            after_span: 0..0,
+        }
+    }
+}
+
+/// Narrow a changed span down to the bytes whose content actually differs.
+///
+/// The piece tree diff compares where bytes live, not what they are, so
+/// retyping a character can mark a whole leaf as changed. Given the text of
+/// `before_span` and `after_span`, this trims their common prefix and suffix,
+/// keeping UTF-8 characters whole, and returns the narrowed
+/// `(before, after)` ranges in document offsets.
+pub fn narrow_changed_span(
+    before_span: Range<usize>,
+    after_span: Range<usize>,
+    before_text: &[u8],
+    after_text: &[u8],
+) -> (Range<usize>, Range<usize>) {
+    let is_continuation = |text: &[u8], i: usize| text.get(i).is_some_and(|b| b & 0xC0 == 0x80);
+    let splits_char = |i_before: usize, i_after: usize| {
+        is_continuation(before_text, i_before) || is_continuation(after_text, i_after)
+    };
+
+    let mut prefix = before_text
+        .iter()
+        .zip(after_text)
+        .take_while(|(a, b)| a == b)
+        .count();
+    while prefix > 0 && splits_char(prefix, prefix) {
+        prefix -= 1;
+    }
+
+    let max_suffix = before_text.len().min(after_text.len()) - prefix;
+    let mut suffix = before_text
+        .iter()
+        .rev()
+        .zip(after_text.iter().rev())
+        .take(max_suffix)
+        .take_while(|(a, b)| a == b)
+        .count();
+    while suffix > 0 && splits_char(before_text.len() - suffix, after_text.len() - suffix) {
+        suffix -= 1;
     }
+
+    (
+        before_span.start + prefix..before_span.start + before_text.len() - suffix,
+        after_span.start + prefix..after_span.start + after_text.len() - suffix,
+    )
 }
 
 fn collect_leaves(node: &Arc<PieceTreeNode>, out: &mut Vec<LeafData>) {
@@ -341,6 +416,159 @@ fn collect_diff_ranges(
     ranges
 }
 
+/// A piece of the document: `bytes` bytes of `location` starting at `offset`,
+/// placed at `doc_offset` in its tree.
+#[derive(Clone, Copy)]
+struct Piece {
+    location: BufferLocation,
+    offset: usize,
+    bytes: usize,
+    doc_offset: usize,
+}
+
+impl Piece {
+    fn same_text(&self, other: &Piece) -> bool {
+        self.location == other.location && self.offset == other.offset && self.bytes == other.bytes
+    }
+}
+
+/// Pieces of `spans` clipped to the document byte range `range`.
+fn pieces_in(spans: &[Span], range: &Range<usize>) -> Vec<Piece> {
+    spans
+        .iter()
+        .filter_map(|span| {
+            let start = span.doc_offset.max(range.start);
+            let end = (span.doc_offset + span.leaf.bytes).min(range.end);
+            (start < end).then(|| Piece {
+                location: span.leaf.location,
+                offset: span.leaf.offset + (start - span.doc_offset),
+                bytes: end - start,
+                doc_offset: start,
+            })
+        })
+        .collect()
+}
+
+/// Split pieces at every buffer offset where a piece of either side starts or
+/// ends, so pieces that share bytes compare equal piece by piece.
+fn refine_pieces(before: &[Piece], after: &[Piece]) -> (Vec<Piece>, Vec<Piece>) {
+    let mut cuts: Vec<(BufferLocation, Vec<usize>)> = Vec::new();
+    for piece in before.iter().chain(after) {
+        let index = match cuts.iter().position(|(loc, _)| *loc == piece.location) {
+            Some(index) => index,
+            None => {
+                cuts.push((piece.location, Vec::new()));
+                cuts.len() - 1
+            }
+        };
+        cuts[index].1.push(piece.offset);
+        cuts[index].1.push(piece.offset + piece.bytes);
+    }
+    for (_, offsets) in &mut cuts {
+        offsets.sort_unstable();
+        offsets.dedup();
+    }
+
+    let split = |pieces: &[Piece]| {
+        let mut out = Vec::with_capacity(pieces.len());
+        for piece in pieces {
+            let Some((_, offsets)) = cuts.iter().find(|(loc, _)| *loc == piece.location) else {
+                continue;
+            };
+            let end = piece.offset + piece.bytes;
+            let first = offsets.partition_point(|&o| o <= piece.offset);
+            let mut start = piece.offset;
+            for &cut in offsets[first..].iter().take_while(|&&o| o < end) {
+                out.push(Piece {
+                    offset: start,
+                    bytes: cut - start,
+                    doc_offset: piece.doc_offset + (start - piece.offset),
+                    ..*piece
+                });
+                start = cut;
+            }
+            out.push(Piece {
+                offset: start,
+                bytes: end - start,
+                doc_offset: piece.doc_offset + (start - piece.offset),
+                ..*piece
+            });
+        }
+        out
+    };
+
+    (split(before), split(after))
+}
+
+/// Changed ranges in the "after" tree between the common prefix and suffix,
+/// found with a longest common subsequence of pieces (weighted by bytes).
+///
+/// Returns None when the middle sections are too large to align.
+fn lcs_diff_ranges(
+    before_spans: &[Span],
+    after_spans: &[Span],
+    before_middle: Range<usize>,
+    after_middle: Range<usize>,
+) -> Option<Vec<Range<usize>>> {
+    let (before, after) = refine_pieces(
+        &pieces_in(before_spans, &before_middle),
+        &pieces_in(after_spans, &after_middle),
+    );
+    let (n, m) = (before.len(), after.len());
+    if n.saturating_mul(m) > MAX_LCS_CELLS {
+        return None;
+    }
+
+    // matched[i][j]: most bytes shared by before[i..] and after[j..]
+    let width = m + 1;
+    let mut matched = vec![0usize; (n + 1) * width];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            let skip = matched[(i + 1) * width + j].max(matched[i * width + j + 1]);
+            matched[i * width + j] = if before[i].same_text(&after[j]) {
+                skip.max(after[j].bytes + matched[(i + 1) * width + j + 1])
+            } else {
+                skip
+            };
+        }
+    }
+
+    let mut ranges = Vec::new();
+    let mut current: Option<Range<usize>> = None;
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        let here = after
+            .get(j)
+            .map_or(after_middle.end, |piece| piece.doc_offset);
+        let score = matched[i * width + j];
+        if i < n
+            && j < m
+            && before[i].same_text(&after[j])
+            && score == after[j].bytes + matched[(i + 1) * width + j + 1]
+        {
+            // Unchanged piece: close any open range
+            ranges.extend(current.take());
+            i += 1;
+            j += 1;
+        } else if j < m && (i == n || score == matched[i * width + j + 1]) {
+            // Inserted piece
+            let range = current.get_or_insert(here..here);
+            range.end = here + after[j].bytes;
+            j += 1;
+        } else {
+            // Deleted piece: marks an empty range where the text was
+            current.get_or_insert(here..here);
+            i += 1;
+        }
+    }
+    ranges.extend(current);
+
+    if ranges.is_empty() {
+        ranges.push(after_middle);
+    }
+    Some(ranges)
+}
+
 fn count_lines_in_range(
     spans: &[Span],
     start: usize,
@@ -522,4 +750,118 @@ mod tests {
         // Only the inserted span should be marked.
         assert_eq!(diff.byte_ranges, vec![50..60]);
     }
+
+    #[test]
+    fn spans_cover_single_replacement() {
+        let before = build(&[leaf(BufferLocation::Stored(0), 0, 20, Some(2))]);
+        let after = build(&[
+            leaf(BufferLocation::Stored(0), 0, 8, Some(1)),
+            leaf(BufferLocation::Added(1), 0, 5, Some(0)),
+            leaf(BufferLocation::Stored(0), 11, 9, Some(1)),
+        ]);
+
+        let diff = diff_piece_trees(&before, &after, &count_line_feeds);
+        assert_eq!(diff.before_span, 8..11);
+        assert_eq!(diff.after_span, 8..13);
+    }
+
+    #[test]
+    fn spans_cover_separate_insert_and_delete() {
+        // Insert 1 byte at 2, delete bytes 5..7
+        let before = build(&[leaf(BufferLocation::Stored(0), 0, 10, Some(0))]);
+        let after = build(&[
+            leaf(BufferLocation::Stored(0), 0, 2, Some(0)),
+            leaf(BufferLocation::Added(1), 0, 1, Some(0)),
+            leaf(BufferLocation::Stored(0), 2, 3, Some(0)),
+            leaf(BufferLocation::Stored(0), 7, 3, Some(0)),
+        ]);
+
+        let diff = diff_piece_trees(&before, &after, &count_line_feeds);
+        // The spans reach from the insertion through the deletion
+        assert_eq!(diff.before_span, 2..7);
+        assert_eq!(diff.after_span, 2..6);
+    }
+
+    #[test]
+    fn identical_trees_have_empty_spans() {
+        let leaves = vec![leaf(BufferLocation::Stored(0), 0, 10, Some(0))];
+        let diff = diff_piece_trees(&build(&leaves), &build(&leaves), &count_line_feeds);
+        assert_eq!(diff.before_span, 0..0);
+        assert_eq!(diff.after_span, 0..0);
+    }
+
+    #[test]
+    fn separate_replacements_produce_separate_ranges() {
+        // Replace byte 2 and byte 5 of the original text
+        let before = build(&[leaf(BufferLocation::Stored(0), 0, 10, Some(0))]);
+        let after = build(&[
+            leaf(BufferLocation::Stored(0), 0, 2, Some(0)),
+            leaf(BufferLocation::Added(1), 0, 1, Some(0)),
+            leaf(BufferLocation::Stored(0), 3, 2, Some(0)),
+            leaf(BufferLocation::Added(1), 1, 1, Some(0)),
+            leaf(BufferLocation::Stored(0), 6, 4, Some(0)),
+        ]);
+
+        let diff = diff_piece_trees(&before, &after, &count_line_feeds);
+        assert_eq!(diff.byte_ranges, vec![2..3, 5..6]);
+        assert_eq!(diff.after_span, 2..6);
+    }
+
+    #[test]
+    fn single_replacement_produces_one_range() {
+        let before = build(&[leaf(BufferLocation::Stored(0), 0, 10, Some(0))]);
+        let after = build(&[
+            leaf(BufferLocation::Stored(0), 0, 4, Some(0)),
+            leaf(BufferLocation::Added(1), 0, 3, Some(0)),
+            leaf(BufferLocation::Stored(0), 6, 4, Some(0)),
+        ]);
+
+        let diff = diff_piece_trees(&before, &after, &count_line_feeds);
+        assert_eq!(diff.byte_ranges, vec![4..7]);
+    }
+
+    #[test]
+    fn separate_insert_and_delete_produce_separate_ranges() {
+        // Insert 1 byte at 2, delete bytes 5..7
+        let before = build(&[leaf(BufferLocation::Stored(0), 0, 10, Some(0))]);
+        let after = build(&[
+            leaf(BufferLocation::Stored(0), 0, 2, Some(0)),
+            leaf(BufferLocation::Added(1), 0, 1, Some(0)),
+            leaf(BufferLocation::Stored(0), 2, 3, Some(0)),
+            leaf(BufferLocation::Stored(0), 7, 3, Some(0)),
+        ]);
+
+        let diff = diff_piece_trees(&before, &after, &count_line_feeds);
+        assert_eq!(diff.byte_ranges, vec![2..3, 6..6]);
+    }
+
+    #[test]
+    fn narrows_replaced_leaf_to_changed_character() {
+        // "hallo" retyped as "hello": the whole span moved, one character differs
+        let (before, after) = narrow_changed_span(4..9, 4..9, b"hallo", b"hello");
+        assert_eq!(before, 5..6);
+        assert_eq!(after, 5..6);
+    }
+
+    #[test]
+    fn narrows_insertion_inside_replaced_leaf() {
+        let (before, after) = narrow_changed_span(10..14, 10..15, b"abcd", b"abXcd");
+        assert_eq!(before, 12..12);
+        assert_eq!(after, 12..13);
+    }
+
+    #[test]
+    fn narrowing_keeps_multibyte_characters_whole() {
+        // "é" (C3 A9) and "è" (C3 A8) share their first byte
+        let (before, after) = narrow_changed_span(0..3, 0..3, "aé".as_bytes(), "aè".as_bytes());
+        assert_eq!(before, 1..3);
+        assert_eq!(after, 1..3);
+    }
+
+    #[test]
+    fn narrowing_identical_text_is_empty() {
+        let (before, after) = narrow_changed_span(3..6, 3..6, b"abc", b"abc");
+        assert_eq!(before, 6..6);
+        assert_eq!(after, 6..6);
+    }
 }
@@ -6,7 +6,8 @@
 use crate::config::{
     CursorStyle, FileBrowserConfig, FileExplorerConfig, FormatterConfig, HighlighterPreference,
     Keybinding, KeybindingMapName, KeymapConfig, LanguageConfig, LineEndingOption, OnSaveAction,
-    PluginConfig, TerminalConfig, ThemeName, WarningsConfig,
+    PluginConfig, SaveAsDirectory, StatusBarConfig, StatusBarSegment, TerminalConfig, ThemeName,
+    WarningsConfig,
 };
 use crate::types::LspServerConfig;
 use serde::{Deserialize, Serialize};
@@ -87,6 +88,7 @@ pub struct PartialConfig {
     pub languages: Option<HashMap<String, PartialLanguageConfig>>,
     pub lsp: Option<HashMap<String, LspServerConfig>>,
     pub warnings: Option<PartialWarningsConfig>,
+    pub status_bar: Option<PartialStatusBarConfig>,
     pub plugins: Option<HashMap<String, PartialPluginConfig>>,
 }
 
@@ -103,6 +105,7 @@ impl Merge for PartialConfig {
         merge_partial(&mut self.file_browser, &other.file_browser);
         merge_partial(&mut self.terminal, &other.terminal);
         merge_partial(&mut self.warnings, &other.warnings);
+        merge_partial(&mut self.status_bar, &other.status_bar);
 
         // Lists: higher precedence replaces (per design doc)
         self.keybindings.merge_from(&other.keybindings);
@@ -132,27 +135,58 @@ fn merge_partial<T: Merge + Clone>(target: &mut Option<T>, other: &Option<T>) {
 #[serde(default)]
 pub struct PartialEditorConfig {
     pub tab_size: Option<usize>,
+    pub detect_indent_width: Option<bool>,
     pub auto_indent: Option<bool>,
+    pub auto_close: Option<bool>,
+    pub smart_backspace: Option<bool>,
+    pub reindent_on_paste: Option<bool>,
+    pub osc52_clipboard: Option<bool>,
+    pub kill_line_joins_lines: Option<bool>,
+    pub kill_line_to_clipboard: Option<bool>,
+    pub insert_final_newline: Option<bool>,
+    pub trim_trailing_whitespace: Option<bool>,
     pub line_numbers: Option<bool>,
     pub relative_line_numbers: Option<bool>,
     pub scroll_offset: Option<usize>,
     pub syntax_highlighting: Option<bool>,
     pub line_wrap: Option<bool>,
+    pub show_indent_guides: Option<bool>,
+    pub caret_control_chars: Option<bool>,
+    pub render_whitespace: Option<bool>,
+    pub render_trailing_whitespace: Option<bool>,
+    pub rulers: Option<Vec<usize>>,
+    pub align_delimiter_single_space: Option<bool>,
+    pub sticky_scroll: Option<bool>,
+    pub smooth_scroll: Option<bool>,
+    pub smooth_scroll_duration_ms: Option<u64>,
+    pub highlight_word_occurrences: Option<bool>,
+    pub show_search_match_count: Option<bool>,
+    pub search_smart_case: Option<bool>,
+    pub center_on_jump: Option<bool>,
     pub highlight_timeout_ms: Option<u64>,
     pub snapshot_interval: Option<usize>,
+    pub undo_history_max_entries: Option<usize>,
+    pub undo_history_max_bytes: Option<u64>,
+    pub persistent_undo: Option<bool>,
     pub large_file_threshold_bytes: Option<u64>,
     pub estimated_line_length: Option<usize>,
     pub enable_inlay_hints: Option<bool>,
     pub enable_semantic_tokens_full: Option<bool>,
+    pub peek_lsp_locations: Option<bool>,
     pub recovery_enabled: Option<bool>,
     pub auto_save_interval_secs: Option<u32>,
+    pub save_session_on_quit: Option<bool>,
     pub highlight_context_bytes: Option<usize>,
     pub mouse_hover_enabled: Option<bool>,
     pub mouse_hover_delay_ms: Option<u64>,
     pub double_click_time_ms: Option<u64>,
+    pub mouse_scroll_lines: Option<usize>,
+    pub chord_timeout_ms: Option<u64>,
     pub auto_revert_poll_interval_ms: Option<u64>,
+    pub detect_deleted_files: Option<bool>,
     pub file_tree_poll_interval_ms: Option<u64>,
     pub default_line_ending: Option<LineEndingOption>,
+    pub save_as_default_dir: Option<SaveAsDirectory>,
     pub cursor_style: Option<CursorStyle>,
     pub keyboard_disambiguate_escape_codes: Option<bool>,
     pub keyboard_report_event_types: Option<bool>,
@@ -161,13 +195,28 @@ pub struct PartialEditorConfig {
     pub quick_suggestions: Option<bool>,
     pub show_menu_bar: Option<bool>,
     pub show_tab_bar: Option<bool>,
+    pub show_cursor_details: Option<bool>,
     pub use_terminal_bg: Option<bool>,
 }
 
 impl Merge for PartialEditorConfig {
     fn merge_from(&mut self, other: &Self) {
         self.tab_size.merge_from(&other.tab_size);
+        self.detect_indent_width
+            .merge_from(&other.detect_indent_width);
         self.auto_indent.merge_from(&other.auto_indent);
+        self.auto_close.merge_from(&other.auto_close);
+        self.smart_backspace.merge_from(&other.smart_backspace);
+        self.reindent_on_paste.merge_from(&other.reindent_on_paste);
+        self.osc52_clipboard.merge_from(&other.osc52_clipboard);
+        self.kill_line_joins_lines
+            .merge_from(&other.kill_line_joins_lines);
+        self.kill_line_to_clipboard
+            .merge_from(&other.kill_line_to_clipboard);
+        self.insert_final_newline
+            .merge_from(&other.insert_final_newline);
+        self.trim_trailing_whitespace
+            .merge_from(&other.trim_trailing_whitespace);
         self.line_numbers.merge_from(&other.line_numbers);
         self.relative_line_numbers
             .merge_from(&other.relative_line_numbers);
@@ -175,9 +224,34 @@ impl Merge for PartialEditorConfig {
         self.syntax_highlighting
             .merge_from(&other.syntax_highlighting);
         self.line_wrap.merge_from(&other.line_wrap);
+        self.show_indent_guides
+            .merge_from(&other.show_indent_guides);
+        self.caret_control_chars
+            .merge_from(&other.caret_control_chars);
+        self.render_whitespace.merge_from(&other.render_whitespace);
+        self.render_trailing_whitespace
+            .merge_from(&other.render_trailing_whitespace);
+        self.rulers.merge_from(&other.rulers);
+        self.align_delimiter_single_space
+            .merge_from(&other.align_delimiter_single_space);
+        self.sticky_scroll.merge_from(&other.sticky_scroll);
+        self.smooth_scroll.merge_from(&other.smooth_scroll);
+        self.smooth_scroll_duration_ms
+            .merge_from(&other.smooth_scroll_duration_ms);
+        self.highlight_word_occurrences
+            .merge_from(&other.highlight_word_occurrences);
+        self.show_search_match_count
+            .merge_from(&other.show_search_match_count);
+        self.search_smart_case.merge_from(&other.search_smart_case);
+        self.center_on_jump.merge_from(&other.center_on_jump);
         self.highlight_timeout_ms
             .merge_from(&other.highlight_timeout_ms);
         self.snapshot_interval.merge_from(&other.snapshot_interval);
+        self.undo_history_max_entries
+            .merge_from(&other.undo_history_max_entries);
+        self.undo_history_max_bytes
+            .merge_from(&other.undo_history_max_bytes);
+        self.persistent_undo.merge_from(&other.persistent_undo);
         self.large_file_threshold_bytes
             .merge_from(&other.large_file_threshold_bytes);
         self.estimated_line_length
@@ -186,9 +260,13 @@ impl Merge for PartialEditorConfig {
             .merge_from(&other.enable_inlay_hints);
         self.enable_semantic_tokens_full
             .merge_from(&other.enable_semantic_tokens_full);
+        self.peek_lsp_locations
+            .merge_from(&other.peek_lsp_locations);
         self.recovery_enabled.merge_from(&other.recovery_enabled);
         self.auto_save_interval_secs
             .merge_from(&other.auto_save_interval_secs);
+        self.save_session_on_quit
+            .merge_from(&other.save_session_on_quit);
         self.highlight_context_bytes
             .merge_from(&other.highlight_context_bytes);
         self.mouse_hover_enabled
@@ -197,12 +275,19 @@ impl Merge for PartialEditorConfig {
             .merge_from(&other.mouse_hover_delay_ms);
         self.double_click_time_ms
             .merge_from(&other.double_click_time_ms);
+        self.mouse_scroll_lines
+            .merge_from(&other.mouse_scroll_lines);
+        self.chord_timeout_ms.merge_from(&other.chord_timeout_ms);
         self.auto_revert_poll_interval_ms
             .merge_from(&other.auto_revert_poll_interval_ms);
+        self.detect_deleted_files
+            .merge_from(&other.detect_deleted_files);
         self.file_tree_poll_interval_ms
             .merge_from(&other.file_tree_poll_interval_ms);
         self.default_line_ending
             .merge_from(&other.default_line_ending);
+        self.save_as_default_dir
+            .merge_from(&other.save_as_default_dir);
         self.cursor_style.merge_from(&other.cursor_style);
         self.keyboard_disambiguate_escape_codes
             .merge_from(&other.keyboard_disambiguate_escape_codes);
@@ -215,6 +300,8 @@ impl Merge for PartialEditorConfig {
         self.quick_suggestions.merge_from(&other.quick_suggestions);
         self.show_menu_bar.merge_from(&other.show_menu_bar);
         self.show_tab_bar.merge_from(&other.show_tab_bar);
+        self.show_cursor_details
+            .merge_from(&other.show_cursor_details);
         self.use_terminal_bg.merge_from(&other.use_terminal_bg);
     }
 }
@@ -282,6 +369,21 @@ impl Merge for PartialWarningsConfig {
     }
 }
 
+/// Partial status bar configuration.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct PartialStatusBarConfig {
+    pub left: Option<Vec<StatusBarSegment>>,
+    pub right: Option<Vec<StatusBarSegment>>,
+}
+
+impl Merge for PartialStatusBarConfig {
+    fn merge_from(&mut self, other: &Self) {
+        self.left.merge_from(&other.left);
+        self.right.merge_from(&other.right);
+    }
+}
+
 /// Partial plugin configuration.
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
 #[serde(default)]
@@ -313,6 +415,8 @@ pub struct PartialLanguageConfig {
     pub show_whitespace_tabs: Option<bool>,
     pub use_tabs: Option<bool>,
     pub tab_size: Option<usize>,
+    pub line_wrap: Option<bool>,
+    pub line_numbers: Option<bool>,
     pub formatter: Option<FormatterConfig>,
     pub format_on_save: Option<bool>,
     pub on_save: Option<Vec<OnSaveAction>>,
@@ -331,6 +435,8 @@ impl Merge for PartialLanguageConfig {
             .merge_from(&other.show_whitespace_tabs);
         self.use_tabs.merge_from(&other.use_tabs);
         self.tab_size.merge_from(&other.tab_size);
+        self.line_wrap.merge_from(&other.line_wrap);
+        self.line_numbers.merge_from(&other.line_numbers);
         self.formatter.merge_from(&other.formatter);
         self.format_on_save.merge_from(&other.format_on_save);
         self.on_save.merge_from(&other.on_save);
@@ -362,27 +468,58 @@ impl From<&crate::config::EditorConfig> for PartialEditorConfig {
     fn from(cfg: &crate::config::EditorConfig) -> Self {
         Self {
             tab_size: Some(cfg.tab_size),
+            detect_indent_width: Some(cfg.detect_indent_width),
             auto_indent: Some(cfg.auto_indent),
+            auto_close: Some(cfg.auto_close),
+            smart_backspace: Some(cfg.smart_backspace),
+            reindent_on_paste: Some(cfg.reindent_on_paste),
+            osc52_clipboard: Some(cfg.osc52_clipboard),
+            kill_line_joins_lines: Some(cfg.kill_line_joins_lines),
+            kill_line_to_clipboard: Some(cfg.kill_line_to_clipboard),
+            insert_final_newline: Some(cfg.insert_final_newline),
+            trim_trailing_whitespace: Some(cfg.trim_trailing_whitespace),
             line_numbers: Some(cfg.line_numbers),
             relative_line_numbers: Some(cfg.relative_line_numbers),
             scroll_offset: Some(cfg.scroll_offset),
             syntax_highlighting: Some(cfg.syntax_highlighting),
             line_wrap: Some(cfg.line_wrap),
+            show_indent_guides: Some(cfg.show_indent_guides),
+            caret_control_chars: Some(cfg.caret_control_chars),
+            render_whitespace: Some(cfg.render_whitespace),
+            render_trailing_whitespace: Some(cfg.render_trailing_whitespace),
+            rulers: Some(cfg.rulers.clone()),
+            align_delimiter_single_space: Some(cfg.align_delimiter_single_space),
+            sticky_scroll: Some(cfg.sticky_scroll),
+            smooth_scroll: Some(cfg.smooth_scroll),
+            smooth_scroll_duration_ms: Some(cfg.smooth_scroll_duration_ms),
+            highlight_word_occurrences: Some(cfg.highlight_word_occurrences),
+            show_search_match_count: Some(cfg.show_search_match_count),
+            search_smart_case: Some(cfg.search_smart_case),
+            center_on_jump: Some(cfg.center_on_jump),
             highlight_timeout_ms: Some(cfg.highlight_timeout_ms),
             snapshot_interval: Some(cfg.snapshot_interval),
+            undo_history_max_entries: Some(cfg.undo_history_max_entries),
+            undo_history_max_bytes: Some(cfg.undo_history_max_bytes),
+            persistent_undo: Some(cfg.persistent_undo),
             large_file_threshold_bytes: Some(cfg.large_file_threshold_bytes),
             estimated_line_length: Some(cfg.estimated_line_length),
             enable_inlay_hints: Some(cfg.enable_inlay_hints),
             enable_semantic_tokens_full: Some(cfg.enable_semantic_tokens_full),
+            peek_lsp_locations: Some(cfg.peek_lsp_locations),
             recovery_enabled: Some(cfg.recovery_enabled),
             auto_save_interval_secs: Some(cfg.auto_save_interval_secs),
+            save_session_on_quit: Some(cfg.save_session_on_quit),
             highlight_context_bytes: Some(cfg.highlight_context_bytes),
             mouse_hover_enabled: Some(cfg.mouse_hover_enabled),
             mouse_hover_delay_ms: Some(cfg.mouse_hover_delay_ms),
             double_click_time_ms: Some(cfg.double_click_time_ms),
+            mouse_scroll_lines: Some(cfg.mouse_scroll_lines),
+            chord_timeout_ms: Some(cfg.chord_timeout_ms),
             auto_revert_poll_interval_ms: Some(cfg.auto_revert_poll_interval_ms),
+            detect_deleted_files: Some(cfg.detect_deleted_files),
             file_tree_poll_interval_ms: Some(cfg.file_tree_poll_interval_ms),
             default_line_ending: Some(cfg.default_line_ending.clone()),
+            save_as_default_dir: Some(cfg.save_as_default_dir),
             cursor_style: Some(cfg.cursor_style),
             keyboard_disambiguate_escape_codes: Some(cfg.keyboard_disambiguate_escape_codes),
             keyboard_report_event_types: Some(cfg.keyboard_report_event_types),
@@ -393,6 +530,7 @@ impl From<&crate::config::EditorConfig> for PartialEditorConfig {
             quick_suggestions: Some(cfg.quick_suggestions),
             show_menu_bar: Some(cfg.show_menu_bar),
             show_tab_bar: Some(cfg.show_tab_bar),
+            show_cursor_details: Some(cfg.show_cursor_details),
             use_terminal_bg: Some(cfg.use_terminal_bg),
         }
     }
@@ -403,7 +541,26 @@ impl PartialEditorConfig {
     pub fn resolve(self, defaults: &crate::config::EditorConfig) -> crate::config::EditorConfig {
         crate::config::EditorConfig {
             tab_size: self.tab_size.unwrap_or(defaults.tab_size),
+            detect_indent_width: self
+                .detect_indent_width
+                .unwrap_or(defaults.detect_indent_width),
             auto_indent: self.auto_indent.unwrap_or(defaults.auto_indent),
+            auto_close: self.auto_close.unwrap_or(defaults.auto_close),
+            smart_backspace: self.smart_backspace.unwrap_or(defaults.smart_backspace),
+            reindent_on_paste: self.reindent_on_paste.unwrap_or(defaults.reindent_on_paste),
+            osc52_clipboard: self.osc52_clipboard.unwrap_or(defaults.osc52_clipboard),
+            kill_line_joins_lines: self
+                .kill_line_joins_lines
+                .unwrap_or(defaults.kill_line_joins_lines),
+            kill_line_to_clipboard: self
+                .kill_line_to_clipboard
+                .unwrap_or(defaults.kill_line_to_clipboard),
+            insert_final_newline: self
+                .insert_final_newline
+                .unwrap_or(defaults.insert_final_newline),
+            trim_trailing_whitespace: self
+                .trim_trailing_whitespace
+                .unwrap_or(defaults.trim_trailing_whitespace),
             line_numbers: self.line_numbers.unwrap_or(defaults.line_numbers),
             relative_line_numbers: self
                 .relative_line_numbers
@@ -413,10 +570,44 @@ impl PartialEditorConfig {
                 .syntax_highlighting
                 .unwrap_or(defaults.syntax_highlighting),
             line_wrap: self.line_wrap.unwrap_or(defaults.line_wrap),
+            show_indent_guides: self
+                .show_indent_guides
+                .unwrap_or(defaults.show_indent_guides),
+            caret_control_chars: self
+                .caret_control_chars
+                .unwrap_or(defaults.caret_control_chars),
+            render_whitespace: self.render_whitespace.unwrap_or(defaults.render_whitespace),
+            render_trailing_whitespace: self
+                .render_trailing_whitespace
+                .unwrap_or(defaults.render_trailing_whitespace),
+            rulers: self.rulers.unwrap_or_else(|| defaults.rulers.clone()),
+            align_delimiter_single_space: self
+                .align_delimiter_single_space
+                .unwrap_or(defaults.align_delimiter_single_space),
+            sticky_scroll: self.sticky_scroll.unwrap_or(defaults.sticky_scroll),
+            smooth_scroll: self.smooth_scroll.unwrap_or(defaults.smooth_scroll),
+            smooth_scroll_duration_ms: self
+                .smooth_scroll_duration_ms
+                .unwrap_or(defaults.smooth_scroll_duration_ms),
+            highlight_word_occurrences: self
+                .highlight_word_occurrences
+                .unwrap_or(defaults.highlight_word_occurrences),
+            show_search_match_count: self
+                .show_search_match_count
+                .unwrap_or(defaults.show_search_match_count),
+            search_smart_case: self.search_smart_case.unwrap_or(defaults.search_smart_case),
+            center_on_jump: self.center_on_jump.unwrap_or(defaults.center_on_jump),
             highlight_timeout_ms: self
                 .highlight_timeout_ms
                 .unwrap_or(defaults.highlight_timeout_ms),
             snapshot_interval: self.snapshot_interval.unwrap_or(defaults.snapshot_interval),
+            undo_history_max_entries: self
+                .undo_history_max_entries
+                .unwrap_or(defaults.undo_history_max_entries),
+            undo_history_max_bytes: self
+                .undo_history_max_bytes
+                .unwrap_or(defaults.undo_history_max_bytes),
+            persistent_undo: self.persistent_undo.unwrap_or(defaults.persistent_undo),
             large_file_threshold_bytes: self
                 .large_file_threshold_bytes
                 .unwrap_or(defaults.large_file_threshold_bytes),
@@ -429,10 +620,16 @@ impl PartialEditorConfig {
             enable_semantic_tokens_full: self
                 .enable_semantic_tokens_full
                 .unwrap_or(defaults.enable_semantic_tokens_full),
+            peek_lsp_locations: self
+                .peek_lsp_locations
+                .unwrap_or(defaults.peek_lsp_locations),
             recovery_enabled: self.recovery_enabled.unwrap_or(defaults.recovery_enabled),
             auto_save_interval_secs: self
                 .auto_save_interval_secs
                 .unwrap_or(defaults.auto_save_interval_secs),
+            save_session_on_quit: self
+                .save_session_on_quit
+                .unwrap_or(defaults.save_session_on_quit),
             highlight_context_bytes: self
                 .highlight_context_bytes
                 .unwrap_or(defaults.highlight_context_bytes),
@@ -445,15 +642,25 @@ impl PartialEditorConfig {
             double_click_time_ms: self
                 .double_click_time_ms
                 .unwrap_or(defaults.double_click_time_ms),
+            mouse_scroll_lines: self
+                .mouse_scroll_lines
+                .unwrap_or(defaults.mouse_scroll_lines),
+            chord_timeout_ms: self.chord_timeout_ms.unwrap_or(defaults.chord_timeout_ms),
             auto_revert_poll_interval_ms: self
                 .auto_revert_poll_interval_ms
                 .unwrap_or(defaults.auto_revert_poll_interval_ms),
+            detect_deleted_files: self
+                .detect_deleted_files
+                .unwrap_or(defaults.detect_deleted_files),
             file_tree_poll_interval_ms: self
                 .file_tree_poll_interval_ms
                 .unwrap_or(defaults.file_tree_poll_interval_ms),
             default_line_ending: self
                 .default_line_ending
                 .unwrap_or(defaults.default_line_ending.clone()),
+            save_as_default_dir: self
+                .save_as_default_dir
+                .unwrap_or(defaults.save_as_default_dir),
             cursor_style: self.cursor_style.unwrap_or(defaults.cursor_style),
             keyboard_disambiguate_escape_codes: self
                 .keyboard_disambiguate_escape_codes
@@ -470,6 +677,9 @@ impl PartialEditorConfig {
             quick_suggestions: self.quick_suggestions.unwrap_or(defaults.quick_suggestions),
             show_menu_bar: self.show_menu_bar.unwrap_or(defaults.show_menu_bar),
             show_tab_bar: self.show_tab_bar.unwrap_or(defaults.show_tab_bar),
+            show_cursor_details: self
+                .show_cursor_details
+                .unwrap_or(defaults.show_cursor_details),
             use_terminal_bg: self.use_terminal_bg.unwrap_or(defaults.use_terminal_bg),
         }
     }
@@ -553,6 +763,24 @@ impl PartialWarningsConfig {
     }
 }
 
+impl From<&StatusBarConfig> for PartialStatusBarConfig {
+    fn from(cfg: &StatusBarConfig) -> Self {
+        Self {
+            left: Some(cfg.left.clone()),
+            right: Some(cfg.right.clone()),
+        }
+    }
+}
+
+impl PartialStatusBarConfig {
+    pub fn resolve(self, defaults: &StatusBarConfig) -> StatusBarConfig {
+        StatusBarConfig {
+            left: self.left.unwrap_or_else(|| defaults.left.clone()),
+            right: self.right.unwrap_or_else(|| defaults.right.clone()),
+        }
+    }
+}
+
 impl From<&PluginConfig> for PartialPluginConfig {
     fn from(cfg: &PluginConfig) -> Self {
         Self {
@@ -584,6 +812,8 @@ impl From<&LanguageConfig> for PartialLanguageConfig {
             show_whitespace_tabs: Some(cfg.show_whitespace_tabs),
             use_tabs: Some(cfg.use_tabs),
             tab_size: cfg.tab_size,
+            line_wrap: cfg.line_wrap,
+            line_numbers: cfg.line_numbers,
             formatter: cfg.formatter.clone(),
             format_on_save: Some(cfg.format_on_save),
             on_save: Some(cfg.on_save.clone()),
@@ -612,6 +842,8 @@ impl PartialLanguageConfig {
                 .unwrap_or(defaults.show_whitespace_tabs),
             use_tabs: self.use_tabs.unwrap_or(defaults.use_tabs),
             tab_size: self.tab_size.or(defaults.tab_size),
+            line_wrap: self.line_wrap.or(defaults.line_wrap),
+            line_numbers: self.line_numbers.or(defaults.line_numbers),
             formatter: self.formatter.or_else(|| defaults.formatter.clone()),
             format_on_save: self.format_on_save.unwrap_or(defaults.format_on_save),
             on_save: self.on_save.unwrap_or_else(|| defaults.on_save.clone()),
@@ -641,6 +873,7 @@ impl From<&crate::config::Config> for PartialConfig {
             ),
             lsp: Some(cfg.lsp.clone()),
             warnings: Some(PartialWarningsConfig::from(&cfg.warnings)),
+            status_bar: Some(PartialStatusBarConfig::from(&cfg.status_bar)),
             // Only include plugins that differ from defaults
             // Path is auto-discovered at runtime and should never be saved
             plugins: {
@@ -765,6 +998,10 @@ impl PartialConfig {
                 .warnings
                 .map(|e| e.resolve(&defaults.warnings))
                 .unwrap_or_else(|| defaults.warnings.clone()),
+            status_bar: self
+                .status_bar
+                .map(|e| e.resolve(&defaults.status_bar))
+                .unwrap_or_else(|| defaults.status_bar.clone()),
             plugins,
         }
     }
@@ -784,6 +1021,8 @@ impl Default for LanguageConfig {
             show_whitespace_tabs: true,
             use_tabs: false,
             tab_size: None,
+            line_wrap: None,
+            line_numbers: None,
             formatter: None,
             format_on_save: false,
             on_save: Vec::new(),
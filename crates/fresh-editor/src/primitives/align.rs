@@ -0,0 +1,100 @@
+//! Aligning lines on a shared delimiter
+
+use crate::primitives::display_width::str_width;
+
+/// Pad `lines` so the first `delimiter` on each line starts at the same column
+///
+/// Spaces are inserted before the delimiter. With `single_space`, the
+/// whitespace on either side of it is first collapsed to one space. Lines
+/// that don't contain the delimiter are returned unchanged.
+pub fn align_on_delimiter(lines: &[&str], delimiter: &str, single_space: bool) -> Vec<String> {
+    if delimiter.is_empty() {
+        return lines.iter().map(|line| line.to_string()).collect();
+    }
+
+    // (text before the delimiter, text after it) for lines that contain it
+    let parts: Vec<Option<(String, String)>> = lines
+        .iter()
+        .map(|line| {
+            let idx = line.find(delimiter)?;
+            let (left, right) = (&line[..idx], &line[idx + delimiter.len()..]);
+            if !single_space {
+                return Some((left.to_string(), right.to_string()));
+            }
+            let left = left.trim_end();
+            let right = right.trim_start();
+            Some((
+                if left.is_empty() {
+                    String::new()
+                } else {
+                    format!("{left} ")
+                },
+                if right.is_empty() {
+                    String::new()
+                } else {
+                    format!(" {right}")
+                },
+            ))
+        })
+        .collect();
+
+    let column = parts
+        .iter()
+        .flatten()
+        .map(|(left, _)| str_width(left))
+        .max()
+        .unwrap_or(0);
+
+    lines
+        .iter()
+        .zip(parts)
+        .map(|(line, part)| match part {
+            Some((left, right)) => {
+                let padding = " ".repeat(column - str_width(&left));
+                format!("{left}{padding}{delimiter}{right}")
+            }
+            None => line.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_align_single_space() {
+        let lines = ["a = 1", "long_name   =2", "mid= 3"];
+        assert_eq!(
+            align_on_delimiter(&lines, "=", true),
+            vec!["a         = 1", "long_name = 2", "mid       = 3"]
+        );
+    }
+
+    #[test]
+    fn test_align_keeps_existing_spacing() {
+        let lines = ["a: 1", "bbb:2"];
+        assert_eq!(
+            align_on_delimiter(&lines, ":", false),
+            vec!["a  : 1", "bbb:2"]
+        );
+    }
+
+    #[test]
+    fn test_align_skips_lines_without_delimiter() {
+        let lines = ["  x = 1", "// note", "  yy = 2"];
+        assert_eq!(
+            align_on_delimiter(&lines, "=", true),
+            vec!["  x  = 1", "// note", "  yy = 2"]
+        );
+    }
+
+    #[test]
+    fn test_align_uses_first_delimiter_and_display_width() {
+        let lines = ["é => a => b", "abc => c"];
+        assert_eq!(
+            align_on_delimiter(&lines, "=>", true),
+            vec!["é   => a => b", "abc => c"]
+        );
+    }
+}
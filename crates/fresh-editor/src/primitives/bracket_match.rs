@@ -0,0 +1,213 @@
+//! Finding the bracket that matches the one at a position
+
+/// How a language writes comments and string literals, so brackets inside
+/// them can be skipped while matching
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BracketSyntax {
+    /// Line comment prefix, e.g. `//` or `#`
+    pub line_comment: Option<String>,
+    /// Block comment delimiters, e.g. `/*` and `*/`
+    pub block_comment: Option<(&'static str, &'static str)>,
+    /// Quote characters that delimit strings, which may span several lines
+    pub string_quotes: &'static [u8],
+    /// Whether `'` delimits character literals like `'('` rather than strings,
+    /// leaving it alone when it starts a Rust lifetime
+    pub char_literals: bool,
+}
+
+impl BracketSyntax {
+    /// Syntax for a language, given its configured comment prefix
+    ///
+    /// Returns None for languages without a comment prefix, whose brackets are
+    /// then matched without skipping anything.
+    pub fn for_language(language: &str, comment_prefix: Option<&str>) -> Option<Self> {
+        let line_comment = comment_prefix
+            .map(str::trim)
+            .filter(|prefix| !prefix.is_empty())?;
+        let c_like = line_comment == "//";
+        let (string_quotes, char_literals): (&'static [u8], bool) = match language {
+            "javascript" | "typescript" | "go" => (b"\"'`", false),
+            _ if !c_like => (b"\"'", false),
+            _ => (b"\"", true),
+        };
+        Some(Self {
+            line_comment: Some(line_comment.to_string()),
+            block_comment: c_like.then_some(("/*", "*/")),
+            string_quotes,
+            char_literals,
+        })
+    }
+
+    /// Mark which bytes of `text` are code rather than comment or string content
+    fn code_mask(&self, text: &[u8]) -> Vec<bool> {
+        let mut mask = vec![true; text.len()];
+        let line_comment = self.line_comment.as_deref().map(str::as_bytes);
+        let mut i = 0;
+        while i < text.len() {
+            let rest = &text[i..];
+            let skip_to = if line_comment.is_some_and(|prefix| rest.starts_with(prefix)) {
+                rest.iter()
+                    .position(|&b| b == b'\n')
+                    .map_or(text.len(), |n| i + n)
+            } else if let Some((open, close)) = self
+                .block_comment
+                .filter(|(open, _)| rest.starts_with(open.as_bytes()))
+            {
+                find_subslice(&text[i + open.len()..], close.as_bytes())
+                    .map_or(text.len(), |n| i + open.len() + n + close.len())
+            } else if self.string_quotes.contains(&text[i]) {
+                closing_quote(text, i, text[i], usize::MAX).map_or(text.len(), |end| end + 1)
+            } else if self.char_literals && text[i] == b'\'' {
+                // Only a short quoted run is a character literal; 'a in `&'a str` is not
+                closing_quote(text, i, b'\'', 4).map_or(i + 1, |end| end + 1)
+            } else {
+                i += 1;
+                continue;
+            };
+            mask[i..skip_to].iter_mut().for_each(|code| *code = false);
+            i = skip_to.max(i + 1);
+        }
+        mask
+    }
+}
+
+/// Position of the quote closing the one at `open`, honoring backslash escapes
+/// and looking at most `max_len` bytes ahead
+fn closing_quote(text: &[u8], open: usize, quote: u8, max_len: usize) -> Option<usize> {
+    let mut i = open + 1;
+    while i < text.len() && i - open <= max_len {
+        match text[i] {
+            b'\\' => i += 2,
+            b if b == quote => return Some(i),
+            b'\n' if max_len != usize::MAX => return None,
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+fn find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// The opening and closing bracket for `byte` and whether it opens
+fn bracket_pair(byte: u8) -> Option<(u8, u8, bool)> {
+    match byte {
+        b'(' => Some((b'(', b')', true)),
+        b')' => Some((b'(', b')', false)),
+        b'[' => Some((b'[', b']', true)),
+        b']' => Some((b'[', b']', false)),
+        b'{' => Some((b'{', b'}', true)),
+        b'}' => Some((b'{', b'}', false)),
+        b'<' => Some((b'<', b'>', true)),
+        b'>' => Some((b'<', b'>', false)),
+        _ => None,
+    }
+}
+
+/// Whether the byte at `pos` is a bracket that `find_matching_bracket` can match
+pub fn is_bracket(text: &[u8], pos: usize) -> bool {
+    text.get(pos).copied().and_then(bracket_pair).is_some()
+}
+
+/// Find the bracket matching the one at `pos`, respecting nesting
+///
+/// Scans forward from an opening bracket or backward from a closing one. With
+/// `syntax`, brackets inside comments and strings are skipped, unless the
+/// bracket at `pos` is itself inside one, in which case everything counts.
+/// Returns None if `pos` is not on a bracket or it has no match.
+pub fn find_matching_bracket(
+    text: &[u8],
+    pos: usize,
+    syntax: Option<&BracketSyntax>,
+) -> Option<usize> {
+    let (opening, closing, forward) = bracket_pair(*text.get(pos)?)?;
+    let mask = syntax
+        .map(|syntax| syntax.code_mask(text))
+        .filter(|mask| mask[pos]);
+    let counts = |i: usize| mask.as_ref().is_none_or(|mask| mask[i]);
+
+    let mut depth = 0usize;
+    let mut visit = |i: usize| {
+        if !counts(i) {
+            return false;
+        }
+        let b = text[i];
+        if b == opening {
+            depth = if forward { depth + 1 } else { depth - 1 };
+        } else if b == closing {
+            depth = if forward { depth - 1 } else { depth + 1 };
+        }
+        depth == 0
+    };
+
+    if forward {
+        (pos..text.len()).find(|&i| visit(i)).filter(|&i| i != pos)
+    } else {
+        (0..=pos).rev().find(|&i| visit(i)).filter(|&i| i != pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rust() -> BracketSyntax {
+        BracketSyntax::for_language("rust", Some("//")).unwrap()
+    }
+
+    #[test]
+    fn test_nested_parentheses_both_directions() {
+        let text = b"f((a, (b)), c)";
+        assert_eq!(find_matching_bracket(text, 1, None), Some(13));
+        assert_eq!(find_matching_bracket(text, 13, None), Some(1));
+        assert_eq!(find_matching_bracket(text, 2, None), Some(9));
+        assert_eq!(find_matching_bracket(text, 9, None), Some(2));
+        assert_eq!(find_matching_bracket(text, 6, None), Some(8));
+    }
+
+    #[test]
+    fn test_unmatched_and_non_bracket() {
+        assert_eq!(find_matching_bracket(b"((a)", 0, None), None);
+        assert_eq!(find_matching_bracket(b"a])", 2, None), None);
+        assert_eq!(find_matching_bracket(b"(a)", 1, None), None);
+        assert_eq!(find_matching_bracket(b"(a)", 3, None), None);
+        // Mismatched kinds don't pair up
+        assert_eq!(find_matching_bracket(b"(]", 0, None), None);
+    }
+
+    #[test]
+    fn test_skips_brackets_in_strings_and_comments() {
+        let text = b"f(\")\", // )\n  /* ) */ ')', x)";
+        let close = text.len() - 1;
+        assert_eq!(find_matching_bracket(text, 1, Some(&rust())), Some(close));
+        assert_eq!(find_matching_bracket(text, close, Some(&rust())), Some(1));
+        // Without syntax the first `)` wins
+        assert_eq!(find_matching_bracket(text, 1, None), Some(3));
+    }
+
+    #[test]
+    fn test_lifetimes_are_not_char_literals() {
+        let text = b"fn f<'a>(x: &'a str) {}";
+        assert_eq!(find_matching_bracket(text, 8, Some(&rust())), Some(19));
+        assert_eq!(find_matching_bracket(text, 4, Some(&rust())), Some(7));
+    }
+
+    #[test]
+    fn test_bracket_inside_string_matches_within_it() {
+        let text = b"s = \"(a)\" + (b)";
+        let python = BracketSyntax::for_language("python", Some("#")).unwrap();
+        assert_eq!(find_matching_bracket(text, 5, Some(&python)), Some(7));
+        assert_eq!(find_matching_bracket(text, 12, Some(&python)), Some(14));
+    }
+
+    #[test]
+    fn test_unknown_language_has_no_syntax() {
+        assert_eq!(BracketSyntax::for_language("text", None), None);
+        let python = BracketSyntax::for_language("python", Some("# ")).unwrap();
+        assert_eq!(python.line_comment.as_deref(), Some("#"));
+        assert_eq!(python.block_comment, None);
+    }
+}
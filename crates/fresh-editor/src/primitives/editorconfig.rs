@@ -0,0 +1,388 @@
+//! `.editorconfig` support
+//!
+//! Collects the properties that apply to a file from the `.editorconfig`
+//! files in its directory and every parent directory, stopping at a file
+//! marked `root = true`. Closer files override files further up, and later
+//! sections in a file override earlier ones.
+
+use crate::model::buffer::LineEnding;
+use std::path::Path;
+
+/// Name of the files read by [`properties_for_file`]
+pub const EDITORCONFIG_FILE_NAME: &str = ".editorconfig";
+
+/// Value of `indent_size`, which may defer to `tab_width`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndentSize {
+    Columns(usize),
+    /// `indent_size = tab`: use the tab width
+    Tab,
+}
+
+/// The supported properties that apply to one file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EditorConfigProperties {
+    /// `indent_style`: `Some(true)` for tabs, `Some(false)` for spaces
+    pub use_tabs: Option<bool>,
+    pub indent_size: Option<IndentSize>,
+    pub tab_width: Option<usize>,
+    pub end_of_line: Option<LineEnding>,
+    pub insert_final_newline: Option<bool>,
+    pub trim_trailing_whitespace: Option<bool>,
+}
+
+impl EditorConfigProperties {
+    /// Whether no supported property was set
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Width of one indentation level, combining `indent_size` and `tab_width`
+    ///
+    /// Tab-indented files prefer `tab_width`, since that is how wide their
+    /// tabs are drawn.
+    pub fn indent_width(&self) -> Option<usize> {
+        let columns = match self.indent_size {
+            Some(IndentSize::Columns(columns)) => Some(columns),
+            _ => None,
+        };
+        if self.use_tabs == Some(true) {
+            self.tab_width.or(columns)
+        } else {
+            columns.or(self.tab_width)
+        }
+    }
+
+    fn set(&mut self, key: &str, value: &str) {
+        // `unset` removes a value inherited from an earlier section or file
+        let unset = value == "unset";
+        match key {
+            "indent_style" => {
+                self.use_tabs = match value {
+                    "tab" => Some(true),
+                    "space" => Some(false),
+                    _ if unset => None,
+                    _ => self.use_tabs,
+                }
+            }
+            "indent_size" => {
+                self.indent_size = match value {
+                    "tab" => Some(IndentSize::Tab),
+                    _ if unset => None,
+                    _ => parse_width(value)
+                        .map(IndentSize::Columns)
+                        .or(self.indent_size),
+                }
+            }
+            "tab_width" => {
+                self.tab_width = if unset {
+                    None
+                } else {
+                    parse_width(value).or(self.tab_width)
+                }
+            }
+            "end_of_line" => {
+                self.end_of_line = match value {
+                    "lf" => Some(LineEnding::LF),
+                    "crlf" => Some(LineEnding::CRLF),
+                    "cr" => Some(LineEnding::CR),
+                    _ if unset => None,
+                    _ => self.end_of_line,
+                }
+            }
+            "insert_final_newline" => {
+                self.insert_final_newline = parse_bool(value, unset, self.insert_final_newline)
+            }
+            "trim_trailing_whitespace" => {
+                self.trim_trailing_whitespace =
+                    parse_bool(value, unset, self.trim_trailing_whitespace)
+            }
+            _ => {}
+        }
+    }
+}
+
+fn parse_width(value: &str) -> Option<usize> {
+    value.parse().ok().filter(|width| *width > 0)
+}
+
+fn parse_bool(value: &str, unset: bool, current: Option<bool>) -> Option<bool> {
+    match value {
+        "true" => Some(true),
+        "false" => Some(false),
+        _ if unset => None,
+        _ => current,
+    }
+}
+
+/// One parsed `.editorconfig` file
+#[derive(Debug, Default)]
+struct EditorConfigFile {
+    root: bool,
+    /// Section globs with their `key = value` pairs, in file order
+    sections: Vec<(String, Vec<(String, String)>)>,
+}
+
+impl EditorConfigFile {
+    fn parse(content: &str) -> Self {
+        let mut file = Self::default();
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+            if let Some(glob) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                file.sections.push((glob.to_string(), Vec::new()));
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let key = key.trim().to_lowercase();
+            let value = value.trim().to_lowercase();
+            match file.sections.last_mut() {
+                Some((_, pairs)) => pairs.push((key, value)),
+                None if key == "root" => file.root = value == "true",
+                None => {}
+            }
+        }
+        file
+    }
+
+    /// Apply the sections matching `relative_path` (relative to this file's
+    /// directory, with `/` separators) on top of `properties`
+    fn apply(&self, relative_path: &str, properties: &mut EditorConfigProperties) {
+        for (glob, pairs) in &self.sections {
+            if glob_matches(glob, relative_path) {
+                for (key, value) in pairs {
+                    properties.set(key, value);
+                }
+            }
+        }
+    }
+}
+
+/// Collect the `.editorconfig` properties for the file at `path`
+///
+/// Missing or unreadable `.editorconfig` files are skipped.
+pub fn properties_for_file(path: &Path) -> EditorConfigProperties {
+    let mut files = Vec::new();
+    for dir in path.ancestors().skip(1) {
+        let Ok(content) = std::fs::read_to_string(dir.join(EDITORCONFIG_FILE_NAME)) else {
+            continue;
+        };
+        let file = EditorConfigFile::parse(&content);
+        let root = file.root;
+        files.push((dir, file));
+        if root {
+            break;
+        }
+    }
+
+    let mut properties = EditorConfigProperties::default();
+    for (dir, file) in files.iter().rev() {
+        let Ok(relative) = path.strip_prefix(dir) else {
+            continue;
+        };
+        let relative: Vec<_> = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect();
+        file.apply(&relative.join("/"), &mut properties);
+    }
+    properties
+}
+
+/// Whether a section glob matches `relative_path`
+///
+/// Globs without a `/` match the file name in any directory; globs with one
+/// are anchored to the directory of the `.editorconfig` file.
+fn glob_matches(glob: &str, relative_path: &str) -> bool {
+    let pattern = match glob.strip_prefix('/') {
+        Some(anchored) => format!("^{}$", glob_to_regex(anchored)),
+        None if glob.contains('/') => format!("^{}$", glob_to_regex(glob)),
+        None => format!("^(?:.*/)?{}$", glob_to_regex(glob)),
+    };
+    regex::Regex::new(&pattern)
+        .map(|re| re.is_match(relative_path))
+        .unwrap_or(false)
+}
+
+/// Translate EditorConfig glob syntax (`*`, `**`, `?`, `[...]`, `{a,b}`) into
+/// a regular expression
+fn glob_to_regex(glob: &str) -> String {
+    let chars: Vec<char> = glob.chars().collect();
+    let mut re = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '*' if chars.get(i + 1) == Some(&'*') => {
+                re.push_str(".*");
+                i += 1;
+            }
+            '*' => re.push_str("[^/]*"),
+            '?' => re.push_str("[^/]"),
+            '[' => match chars[i + 1..].iter().position(|&c| c == ']') {
+                Some(len) => {
+                    let class: String = chars[i + 1..i + 1 + len].iter().collect();
+                    let (negated, class) = match class.strip_prefix('!') {
+                        Some(rest) => (true, rest.to_string()),
+                        None => (false, class),
+                    };
+                    re.push('[');
+                    if negated {
+                        re.push('^');
+                    }
+                    re.push_str(&class.replace('\\', "\\\\").replace('[', "\\["));
+                    re.push(']');
+                    i += len + 1;
+                }
+                None => re.push_str("\\["),
+            },
+            '{' => match matching_brace(&chars, i) {
+                Some(end) => {
+                    let inner: String = chars[i + 1..end].iter().collect();
+                    let alternatives = split_alternatives(&inner);
+                    if alternatives.len() > 1 {
+                        let alternatives: Vec<_> =
+                            alternatives.iter().map(|a| glob_to_regex(a)).collect();
+                        re.push_str(&format!("(?:{})", alternatives.join("|")));
+                    } else {
+                        re.push_str(&regex::escape(&format!("{{{inner}}}")));
+                    }
+                    i = end;
+                }
+                None => re.push_str("\\{"),
+            },
+            '\\' if i + 1 < chars.len() => {
+                re.push_str(&regex::escape(&chars[i + 1].to_string()));
+                i += 1;
+            }
+            c => re.push_str(&regex::escape(&c.to_string())),
+        }
+        i += 1;
+    }
+    re
+}
+
+/// Index of the `}` closing the `{` at `open`, allowing nesting
+fn matching_brace(chars: &[char], open: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (i, &c) in chars.iter().enumerate().skip(open) {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Split the body of a `{...}` group on its top-level commas
+fn split_alternatives(inner: &str) -> Vec<String> {
+    let mut alternatives = vec![String::new()];
+    let mut depth = 0;
+    for c in inner.chars() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            ',' if depth == 0 => {
+                alternatives.push(String::new());
+                continue;
+            }
+            _ => {}
+        }
+        if let Some(last) = alternatives.last_mut() {
+            last.push(c);
+        }
+    }
+    alternatives
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_matching() {
+        assert!(glob_matches("*", "main.go"));
+        assert!(glob_matches("*.go", "cmd/tool/main.go"));
+        assert!(!glob_matches("*.go", "main.py"));
+        assert!(glob_matches("*.{js,ts}", "src/app.ts"));
+        assert!(!glob_matches("*.{js,ts}", "src/app.rs"));
+        assert!(glob_matches("Makefile", "sub/Makefile"));
+        assert!(glob_matches("/docs/*.md", "docs/guide.md"));
+        assert!(!glob_matches("/docs/*.md", "docs/deep/guide.md"));
+        assert!(glob_matches("docs/**.md", "docs/deep/guide.md"));
+        assert!(glob_matches("file?.txt", "file1.txt"));
+        assert!(glob_matches("[!a]*.txt", "b.txt"));
+        assert!(!glob_matches("[!a]*.txt", "a.txt"));
+    }
+
+    #[test]
+    fn test_later_sections_override_earlier_ones() {
+        let file = EditorConfigFile::parse(
+            "root = true\n\
+             # defaults\n\
+             [*]\n\
+             indent_style = space\n\
+             indent_size = 4\n\
+             end_of_line = lf\n\
+             \n\
+             [*.go]\n\
+             indent_style = tab\n\
+             tab_width = 8\n\
+             indent_size = unset\n",
+        );
+        assert!(file.root);
+
+        let mut go = EditorConfigProperties::default();
+        file.apply("main.go", &mut go);
+        assert_eq!(go.use_tabs, Some(true));
+        assert_eq!(go.indent_size, None);
+        assert_eq!(go.indent_width(), Some(8));
+        assert_eq!(go.end_of_line, Some(LineEnding::LF));
+
+        let mut py = EditorConfigProperties::default();
+        file.apply("app.py", &mut py);
+        assert_eq!(py.use_tabs, Some(false));
+        assert_eq!(py.indent_width(), Some(4));
+    }
+
+    #[test]
+    fn test_closer_files_override_and_root_stops_search() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let outer = temp_dir.path();
+        let project = outer.join("project");
+        let src = project.join("src");
+        std::fs::create_dir_all(&src).unwrap();
+        std::fs::write(
+            outer.join(EDITORCONFIG_FILE_NAME),
+            "[*]\ntrim_trailing_whitespace = true\n",
+        )
+        .unwrap();
+        std::fs::write(
+            project.join(EDITORCONFIG_FILE_NAME),
+            "root = true\n[*.rs]\nindent_size = 4\ninsert_final_newline = true\n",
+        )
+        .unwrap();
+        std::fs::write(
+            src.join(EDITORCONFIG_FILE_NAME),
+            "[*.rs]\nindent_size = 2\n",
+        )
+        .unwrap();
+
+        let properties = properties_for_file(&src.join("lib.rs"));
+        assert_eq!(properties.indent_size, Some(IndentSize::Columns(2)));
+        assert_eq!(properties.insert_final_newline, Some(true));
+        // The outer file is above the root and is never read
+        assert_eq!(properties.trim_trailing_whitespace, None);
+
+        assert!(properties_for_file(&src.join("notes.txt")).is_empty());
+    }
+}
@@ -367,6 +367,8 @@ mod tests {
                 show_whitespace_tabs: true,
                 use_tabs: false,
                 tab_size: None,
+                line_wrap: None,
+                line_numbers: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
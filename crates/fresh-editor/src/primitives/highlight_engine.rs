@@ -474,6 +474,22 @@ impl TextMateEngine {
         }
     }
 
+    /// Highlight category of the byte at `position`, from the last highlight pass
+    ///
+    /// Returns `None` for unhighlighted text and for positions not highlighted
+    /// since the last edit.
+    pub fn category_at(&self, position: usize, buffer_len: usize) -> Option<HighlightCategory> {
+        let cache = self.cache.as_ref()?;
+        if self.last_buffer_len != buffer_len {
+            return None;
+        }
+        cache
+            .spans
+            .iter()
+            .find(|span| span.range.contains(&position))
+            .map(|span| span.category)
+    }
+
     /// Invalidate all cache
     pub fn invalidate_all(&mut self) {
         self.cache = None;
@@ -681,6 +697,18 @@ impl HighlightEngine {
         }
     }
 
+    /// Highlight category of the byte at `position`
+    ///
+    /// Based on the spans of the last highlight pass, so positions outside the
+    /// highlighted viewport report `None`.
+    pub fn category_at(&self, position: usize, buffer_len: usize) -> Option<HighlightCategory> {
+        match self {
+            Self::TreeSitter(h) => h.category_at(position, buffer_len),
+            Self::TextMate(h) => h.category_at(position, buffer_len),
+            Self::None => None,
+        }
+    }
+
     /// Check if this engine has highlighting available
     pub fn has_highlighting(&self) -> bool {
         !matches!(self, Self::None)
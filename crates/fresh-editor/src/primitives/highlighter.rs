@@ -225,6 +225,22 @@ impl Highlighter {
         }
     }
 
+    /// Highlight category of the byte at `position`, from the last highlight pass
+    ///
+    /// Returns `None` for unhighlighted text and for positions not highlighted
+    /// since the last edit.
+    pub fn category_at(&self, position: usize, buffer_len: usize) -> Option<HighlightCategory> {
+        let cache = self.cache.as_ref()?;
+        if self.last_buffer_len != buffer_len {
+            return None;
+        }
+        cache
+            .spans
+            .iter()
+            .find(|span| span.range.contains(&position))
+            .map(|span| span.category)
+    }
+
     /// Invalidate entire cache
     pub fn invalidate_all(&mut self) {
         self.cache = None;
@@ -970,11 +970,180 @@ impl Default for IndentCalculator {
     }
 }
 
+/// Reindent multi-line text so its base indentation becomes `target_indent`
+///
+/// The base is the smallest indentation among non-blank lines after the first,
+/// plus the first line when it starts with whitespace (a partial first line copied
+/// from the middle of code carries no indentation of its own). That many columns
+/// are removed from every following line and replaced by `target_indent`, so
+/// nesting inside the text is preserved. Blank lines become empty.
+///
+/// The first line lands at the cursor, which already has the target indentation
+/// in front of it when `at_indent` is true; its own leading whitespace is then
+/// dropped.
+pub fn reindent_text(text: &str, target_indent: &str, at_indent: bool, tab_size: usize) -> String {
+    let lines: Vec<&str> = text.split('\n').collect();
+    if lines.len() < 2 {
+        return text.to_string();
+    }
+
+    let tab_size = tab_size.max(1);
+    let width = |line: &str| {
+        line.chars()
+            .take_while(|c| *c == ' ' || *c == '\t')
+            .fold(0, |col, c| {
+                if c == '\t' {
+                    col + tab_size - col % tab_size
+                } else {
+                    col + 1
+                }
+            })
+    };
+    let is_blank = |line: &str| line.trim().is_empty();
+
+    let first_indented = lines[0].starts_with([' ', '\t']);
+    let base = lines
+        .iter()
+        .enumerate()
+        .filter(|(i, line)| !is_blank(line) && (*i > 0 || first_indented))
+        .map(|(_, line)| width(line))
+        .min()
+        .unwrap_or(0);
+
+    let mut result = String::with_capacity(text.len());
+    result.push_str(if at_indent {
+        lines[0].trim_start_matches([' ', '\t'])
+    } else {
+        lines[0]
+    });
+
+    for line in &lines[1..] {
+        result.push('\n');
+        if is_blank(line) {
+            continue;
+        }
+        // Strip `base` columns of leading whitespace, keeping any remainder
+        let mut col = 0;
+        let mut rest = *line;
+        while col < base {
+            match rest.chars().next() {
+                Some(' ') => col += 1,
+                Some('\t') => col += tab_size - col % tab_size,
+                _ => break,
+            }
+            rest = &rest[1..];
+        }
+        result.push_str(target_indent);
+        if col > base {
+            // A tab overshot the base; make up the difference with spaces
+            result.push_str(&" ".repeat(col - base));
+        }
+        result.push_str(rest);
+    }
+
+    result
+}
+
+/// Largest indentation step [`detect_indent_width`] will report
+const MAX_DETECTED_INDENT_WIDTH: usize = 8;
+
+/// Guess the indentation step of space-indented text
+///
+/// Counts how far the leading spaces grow between consecutive non-blank
+/// lines and returns the most common step between 2 and 8 (the smaller one
+/// on a tie). Returns `None` when there are no such steps, or when more
+/// lines are indented with tabs than with spaces.
+pub fn detect_indent_width(text: &str) -> Option<usize> {
+    let mut step_counts = [0usize; MAX_DETECTED_INDENT_WIDTH + 1];
+    let mut space_lines = 0;
+    let mut tab_lines = 0;
+    let mut prev_indent = 0;
+
+    for line in text.lines() {
+        let content = line.trim_start_matches(' ');
+        if content.trim().is_empty() {
+            // Blank lines don't change the indentation level
+            continue;
+        }
+        if content.starts_with('\t') {
+            tab_lines += 1;
+            continue;
+        }
+        let indent = line.len() - content.len();
+        if indent > 0 {
+            space_lines += 1;
+        }
+        if indent > prev_indent {
+            let step = indent - prev_indent;
+            if (2..=MAX_DETECTED_INDENT_WIDTH).contains(&step) {
+                step_counts[step] += 1;
+            }
+        }
+        prev_indent = indent;
+    }
+
+    if tab_lines > space_lines {
+        return None;
+    }
+    let (width, count) = step_counts
+        .iter()
+        .enumerate()
+        .fold(
+            (0, 0),
+            |best, (width, &count)| {
+                if count > best.1 {
+                    (width, count)
+                } else {
+                    best
+                }
+            },
+        );
+    (count > 0).then_some(width)
+}
+
+/// Rewrite a run of leading whitespace in the given indentation style
+///
+/// The visual width of `indent` (tabs advance to the next multiple of
+/// `tab_size`) is kept. With tabs, any width that does not fill a whole tab
+/// stop is made up with trailing spaces.
+pub fn convert_indentation(indent: &str, use_tabs: bool, tab_size: usize) -> String {
+    let tab_size = tab_size.max(1);
+    let width = indent.chars().fold(0, |col, c| {
+        if c == '\t' {
+            col + tab_size - col % tab_size
+        } else {
+            col + 1
+        }
+    });
+    if use_tabs {
+        let mut result = "\t".repeat(width / tab_size);
+        result.push_str(&" ".repeat(width % tab_size));
+        result
+    } else {
+        " ".repeat(width)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::model::buffer::Buffer;
 
+    #[test]
+    fn test_detect_indent_width() {
+        let two = "fn main() {\n  if x {\n    y();\n  }\n\n  z();\n}\n";
+        assert_eq!(detect_indent_width(two), Some(2));
+
+        // Continuation lines with a deeper step don't outweigh the common one
+        let four = "def f():\n    a = [\n            1,\n    ]\n    if a:\n        pass\n";
+        assert_eq!(detect_indent_width(four), Some(4));
+
+        // Block comment alignment (1 space) is not an indentation step
+        assert_eq!(detect_indent_width("/**\n * doc\n */\n"), None);
+        assert_eq!(detect_indent_width("no indentation\nat all\n"), None);
+        assert_eq!(detect_indent_width("a\n\tb\n\t\tc\n  d\n"), None);
+    }
+
     #[test]
     fn test_current_and_previous_line_indent() {
         let buffer = Buffer::from_str_test("fn main() {\n    let x = 1;");
@@ -1274,4 +1443,40 @@ mod tests {
             "After empty line in function body (incomplete syntax), should indent to 4 spaces using reference line"
         );
     }
+
+    #[test]
+    fn test_reindent_text_shifts_base_and_keeps_nesting() {
+        let text = "    if x {\n        y();\n\n    }";
+        assert_eq!(
+            reindent_text(text, "        ", true, 4),
+            "if x {\n            y();\n\n        }"
+        );
+
+        // A partial first line does not count toward the base indentation
+        let text = "if x {\n        y();\n    }";
+        assert_eq!(
+            reindent_text(text, "\t", false, 4),
+            "if x {\n\t    y();\n\t}"
+        );
+
+        // Single-line text is left alone
+        assert_eq!(reindent_text("  foo", "    ", true, 4), "  foo");
+
+        // A zero tab size is treated as one column per tab
+        assert_eq!(
+            reindent_text("\tif x {\n\t\ty();\n\t}", "", true, 0),
+            "if x {\n\ty();\n}"
+        );
+    }
+
+    #[test]
+    fn test_convert_indentation_round_trips() {
+        assert_eq!(convert_indentation("\t\t", false, 4), "        ");
+        assert_eq!(convert_indentation("        ", true, 4), "\t\t");
+        // Partial tab stops stay as spaces
+        assert_eq!(convert_indentation("      ", true, 4), "\t  ");
+        // Mixed input is measured by tab stops, not character count
+        assert_eq!(convert_indentation("  \t ", false, 4), "     ");
+        assert_eq!(convert_indentation("", true, 4), "");
+    }
 }
@@ -133,10 +133,17 @@ impl<'a> LineIterator<'a> {
     /// Get the next line (moving forward)
     /// Uses lazy loading to handle unloaded buffers transparently
     pub fn next_line(&mut self) -> Option<(usize, String)> {
+        self.next_line_bytes()
+            .map(|(line_start, bytes)| (line_start, String::from_utf8_lossy(&bytes).into_owned()))
+    }
+
+    /// Get the next line as raw bytes (moving forward)
+    /// Unlike `next_line`, invalid UTF-8 is preserved so byte offsets stay exact
+    pub fn next_line_bytes(&mut self) -> Option<(usize, Vec<u8>)> {
         if self.pending_trailing_empty_line {
             self.pending_trailing_empty_line = false;
             let line_start = self.buffer_len;
-            return Some((line_start, String::new()));
+            return Some((line_start, Vec::new()));
         }
 
         if self.current_pos >= self.buffer_len {
@@ -223,19 +230,18 @@ impl<'a> LineIterator<'a> {
             line_len = line_len.min(MAX_LINE_BYTES).min(extended_chunk.len());
 
             // Use the extended chunk
-            let line_bytes = &extended_chunk[..line_len];
+            extended_chunk.truncate(line_len);
             self.current_pos += line_len;
-            self.schedule_trailing_empty_line(line_bytes);
-            let line_string = String::from_utf8_lossy(line_bytes).into_owned();
-            return Some((line_start, line_string));
+            self.schedule_trailing_empty_line(&extended_chunk);
+            return Some((line_start, extended_chunk));
         }
 
         // Normal case: found newline or reached EOF within initial chunk
-        let line_bytes = &chunk[..line_len];
+        let mut line_bytes = chunk;
+        line_bytes.truncate(line_len);
         self.current_pos += line_len;
-        self.schedule_trailing_empty_line(line_bytes);
-        let line_string = String::from_utf8_lossy(line_bytes).into_owned();
-        Some((line_start, line_string))
+        self.schedule_trailing_empty_line(&line_bytes);
+        Some((line_start, line_bytes))
     }
 
     /// Get the previous line (moving backward)
@@ -3,9 +3,12 @@
 //! This module contains syntax highlighting, ANSI handling,
 //! and text manipulation utilities.
 
+pub mod align;
 pub mod ansi;
 pub mod ansi_background;
+pub mod bracket_match;
 pub mod display_width;
+pub mod editorconfig;
 pub mod grammar;
 pub mod grapheme;
 
@@ -19,6 +22,8 @@ pub mod line_wrapping;
 pub mod path_utils;
 pub mod reference_highlighter;
 pub mod snippet;
+pub mod sort_lines;
 pub mod text_property;
+pub mod text_stats;
 pub mod visual_layout;
 pub mod word_navigation;
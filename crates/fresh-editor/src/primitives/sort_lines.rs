@@ -0,0 +1,87 @@
+//! Sorting and de-duplicating lines
+
+use std::collections::HashSet;
+
+/// Order used by [`sort_lines`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineOrder {
+    Ascending,
+    Descending,
+    /// Ascending, comparing lines by their lowercase form
+    CaseInsensitive,
+}
+
+/// Return `lines` sorted in `order`
+///
+/// The sort is stable, so lines that compare equal (including lines that
+/// differ only in case under [`LineOrder::CaseInsensitive`]) keep their order.
+pub fn sort_lines(lines: &[&str], order: LineOrder) -> Vec<String> {
+    let mut sorted: Vec<&str> = lines.to_vec();
+    match order {
+        LineOrder::Ascending => sorted.sort(),
+        LineOrder::Descending => sorted.sort_by(|a, b| b.cmp(a)),
+        LineOrder::CaseInsensitive => sorted.sort_by_cached_key(|line| line.to_lowercase()),
+    }
+    sorted.into_iter().map(str::to_string).collect()
+}
+
+/// Return `lines` without duplicates, keeping the first of each
+///
+/// With `adjacent_only`, only runs of identical neighbouring lines are
+/// collapsed; otherwise every repeat of an earlier line is dropped.
+pub fn unique_lines(lines: &[&str], adjacent_only: bool) -> Vec<String> {
+    let mut seen = HashSet::new();
+    let mut previous = None;
+    lines
+        .iter()
+        .filter(|line| {
+            let keep = if adjacent_only {
+                previous != Some(**line)
+            } else {
+                seen.insert(**line)
+            };
+            previous = Some(**line);
+            keep
+        })
+        .map(|line| line.to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sort_ascending_and_descending() {
+        let lines = ["pear", "apple", "Banana", "fig"];
+        assert_eq!(
+            sort_lines(&lines, LineOrder::Ascending),
+            vec!["Banana", "apple", "fig", "pear"]
+        );
+        assert_eq!(
+            sort_lines(&lines, LineOrder::Descending),
+            vec!["pear", "fig", "apple", "Banana"]
+        );
+    }
+
+    #[test]
+    fn test_sort_case_insensitive_is_stable() {
+        let lines = ["b", "B", "a", "A"];
+        assert_eq!(
+            sort_lines(&lines, LineOrder::CaseInsensitive),
+            vec!["a", "A", "b", "B"]
+        );
+    }
+
+    #[test]
+    fn test_unique_all_keeps_first_occurrence() {
+        let lines = ["x", "y", "x", "z", "y"];
+        assert_eq!(unique_lines(&lines, false), vec!["x", "y", "z"]);
+    }
+
+    #[test]
+    fn test_unique_adjacent_only_collapses_runs() {
+        let lines = ["x", "x", "y", "x", "x"];
+        assert_eq!(unique_lines(&lines, true), vec!["x", "y", "x"]);
+    }
+}
@@ -0,0 +1,75 @@
+//! Line, word and character counts of buffer text
+//!
+//! Words follow the Unicode word boundary rules (UAX #29), so punctuation is
+//! not counted as a word and CJK text is split per ideograph. Characters are
+//! counted as grapheme clusters, so an emoji with modifiers or a letter with
+//! combining marks counts once.
+
+use std::ops::Range;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Line, word and character counts of a piece of text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TextStats {
+    /// Number of lines, not counting the empty line after a trailing newline
+    pub lines: usize,
+    /// Number of words
+    pub words: usize,
+    /// Number of grapheme clusters, including line breaks
+    pub chars: usize,
+}
+
+impl TextStats {
+    /// Count the lines, words and characters of `text`
+    pub fn count(text: &str) -> Self {
+        let lines = if text.is_empty() {
+            0
+        } else {
+            text.lines().count()
+        };
+        Self {
+            lines,
+            words: text.unicode_words().count(),
+            chars: text.graphemes(true).count(),
+        }
+    }
+}
+
+/// Counts of a byte range, valid until the buffer changes
+#[derive(Debug, Clone)]
+pub struct TextStatsCache {
+    /// Buffer version the counts were taken at
+    pub version: u64,
+    /// Byte range that was counted
+    pub range: Range<usize>,
+    pub stats: TextStats,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_plain_text() {
+        let stats = TextStats::count("Hello, world!\nSecond line here.\n");
+        assert_eq!(
+            stats,
+            TextStats {
+                lines: 2,
+                words: 5,
+                chars: 32,
+            }
+        );
+        assert_eq!(TextStats::count(""), TextStats::default());
+        assert_eq!(TextStats::count("no newline").lines, 1);
+    }
+
+    #[test]
+    fn test_count_is_unicode_aware() {
+        // "é" written as e + combining acute, a family emoji and CRLF each count once
+        let stats = TextStats::count("cafe\u{301} 👨‍👩‍👧\r\nnaïve-ish");
+        assert_eq!(stats.lines, 2);
+        assert_eq!(stats.words, 3);
+        assert_eq!(stats.chars, 4 + 1 + 1 + 1 + 9);
+    }
+}
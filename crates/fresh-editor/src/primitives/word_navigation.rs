@@ -340,6 +340,120 @@ pub fn find_word_start_right(buffer: &Buffer, pos: usize) -> usize {
     start + current_idx
 }
 
+/// Leading char of the grapheme starting at `idx`
+fn grapheme_char_at(text: &str, idx: usize) -> Option<char> {
+    text.get(idx..).and_then(|rest| rest.chars().next())
+}
+
+/// Check if a subword starts at the grapheme at `idx`
+///
+/// Subwords split identifiers at camelCase humps (`get|HTTP|Response`),
+/// after underscores (`some_|snake`) and where letters meet digits (`utf|8`).
+/// Underscores themselves never start a subword.
+fn is_subword_start(text: &str, idx: usize) -> bool {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let prev = (idx > 0)
+        .then(|| grapheme_char_at(text, prev_grapheme_boundary(text, idx)))
+        .flatten();
+    let Some(prev) = prev.filter(|&c| is_word(c)) else {
+        return true;
+    };
+    let Some(cur) = grapheme_char_at(text, idx) else {
+        return true;
+    };
+    if cur == '_' {
+        return false;
+    }
+    let next = grapheme_char_at(text, next_grapheme_boundary(text, idx));
+
+    prev == '_'
+        || (prev.is_lowercase() && cur.is_uppercase())
+        || (prev.is_uppercase() && cur.is_uppercase() && next.is_some_and(char::is_lowercase))
+        || prev.is_numeric() != cur.is_numeric()
+}
+
+/// Find the start of the subword to the left of the given position
+///
+/// Like `find_word_start_left`, but stops inside identifiers at camelCase
+/// humps, underscores and digit boundaries.
+pub fn find_subword_start_left(buffer: &Buffer, pos: usize) -> usize {
+    if pos == 0 {
+        return 0;
+    }
+
+    let actual_pos = pos.min(buffer.len());
+    let start = actual_pos.saturating_sub(1000);
+    let bytes = buffer.slice_bytes(start..actual_pos);
+    let text = String::from_utf8_lossy(&bytes);
+
+    // Whitespace and punctuation move exactly as they do for whole words
+    let mut current_idx = text.len();
+    while current_idx > 0 {
+        let prev = prev_grapheme_boundary(&text, current_idx);
+        match get_grapheme_class(&text[prev..current_idx]) {
+            CharClass::Whitespace => current_idx = prev,
+            CharClass::Punctuation => return find_word_start_left(buffer, pos),
+            CharClass::Word => break,
+        }
+    }
+
+    // Step back at least one grapheme, then on to the start of the subword
+    if current_idx > 0 {
+        current_idx = prev_grapheme_boundary(&text, current_idx);
+        while current_idx > 0 && !is_subword_start(&text, current_idx) {
+            current_idx = prev_grapheme_boundary(&text, current_idx);
+        }
+    }
+
+    let delta = text.len() - current_idx;
+    actual_pos.saturating_sub(delta)
+}
+
+/// Find the start of the subword to the right of the given position
+///
+/// Like `find_word_start_right`, but stops inside identifiers at camelCase
+/// humps, underscores and digit boundaries.
+pub fn find_subword_start_right(buffer: &Buffer, pos: usize) -> usize {
+    let buf_len = buffer.len();
+    if pos >= buf_len {
+        return buf_len;
+    }
+
+    let start = pos;
+    let end = (pos + 1000).min(buf_len);
+    let bytes = buffer.slice_bytes(start..end);
+    let text = String::from_utf8_lossy(&bytes);
+
+    let is_word_at = |idx: usize| {
+        idx < text.len()
+            && get_grapheme_class(&text[idx..next_grapheme_boundary(&text, idx)]) == CharClass::Word
+    };
+    if !is_word_at(0) {
+        return find_word_start_right(buffer, pos);
+    }
+
+    // 1. Move through the current subword
+    let mut current_idx = next_grapheme_boundary(&text, 0);
+    while is_word_at(current_idx) && !is_subword_start(&text, current_idx) {
+        current_idx = next_grapheme_boundary(&text, current_idx);
+    }
+    if is_word_at(current_idx) {
+        return start + current_idx;
+    }
+
+    // 2. At the end of the word, consume whitespace to land at the next token
+    while current_idx < text.len() {
+        let next = next_grapheme_boundary(&text, current_idx);
+        if get_grapheme_class(&text[current_idx..next]) == CharClass::Whitespace {
+            current_idx = next;
+        } else {
+            break;
+        }
+    }
+
+    start + current_idx
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -387,6 +501,48 @@ mod tests {
         assert_eq!(find_word_start_right(&buffer, 6), 12); // From "world" to "test"
     }
 
+    #[test]
+    fn test_subword_stops_in_camel_case() {
+        let buffer = Buffer::from_str_test("getHTTPResponseCode");
+        let right: Vec<usize> = std::iter::successors(Some(0), |&pos| {
+            (pos < buffer.len()).then(|| find_subword_start_right(&buffer, pos))
+        })
+        .collect();
+        assert_eq!(right, vec![0, 3, 7, 15, 19]);
+
+        let left: Vec<usize> = std::iter::successors(Some(19), |&pos| {
+            (pos > 0).then(|| find_subword_start_left(&buffer, pos))
+        })
+        .collect();
+        assert_eq!(left, vec![19, 15, 7, 3, 0]);
+    }
+
+    #[test]
+    fn test_subword_stops_in_snake_case() {
+        let buffer = Buffer::from_str_test("some_snake_case");
+        assert_eq!(find_subword_start_right(&buffer, 0), 5);
+        assert_eq!(find_subword_start_right(&buffer, 5), 11);
+        assert_eq!(find_subword_start_right(&buffer, 11), 15);
+        assert_eq!(find_subword_start_left(&buffer, 15), 11);
+        assert_eq!(find_subword_start_left(&buffer, 11), 5);
+        assert_eq!(find_subword_start_left(&buffer, 5), 0);
+        // From inside an underscore run
+        assert_eq!(find_subword_start_right(&buffer, 4), 5);
+        assert_eq!(find_subword_start_left(&buffer, 4), 0);
+    }
+
+    #[test]
+    fn test_subword_digits_and_separators() {
+        let buffer = Buffer::from_str_test("utf8Decode(x) __init");
+        assert_eq!(find_subword_start_right(&buffer, 0), 3); // "utf" | "8"
+        assert_eq!(find_subword_start_right(&buffer, 3), 4); // "8" | "Decode"
+        assert_eq!(find_subword_start_right(&buffer, 4), 10); // Word end, no whitespace
+        assert_eq!(find_subword_start_right(&buffer, 10), 11); // Punctuation as words
+        assert_eq!(find_subword_start_left(&buffer, 20), 16); // Leading underscores skipped
+        assert_eq!(find_subword_start_left(&buffer, 16), 14);
+        assert_eq!(find_subword_start_left(&buffer, 14), 12); // Punctuation as words
+    }
+
     // ========================================================================
     // Tests for byte-level word navigation (shared by Buffer and String)
     // ========================================================================
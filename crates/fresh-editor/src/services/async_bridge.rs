@@ -11,7 +11,7 @@
 //! - Main loop remains responsive and simple
 
 use crate::services::terminal::TerminalId;
-use crate::view::file_tree::{FileTreeView, NodeId};
+use crate::view::file_tree::{FileTreeView, GitStatusMap, NodeId};
 use lsp_types::{
     CodeActionOrCommand, CompletionItem, Diagnostic, InlayHint, Location,
     SemanticTokensFullDeltaResult, SemanticTokensLegend, SemanticTokensRangeResult,
@@ -108,6 +108,24 @@ pub enum AsyncMessage {
         actions: Vec<CodeActionOrCommand>,
     },
 
+    /// LSP formatting response (textDocument/formatting or rangeFormatting)
+    LspFormatting {
+        request_id: u64,
+        result: Result<Vec<lsp_types::TextEdit>, String>,
+    },
+
+    /// LSP document symbols response (None if the server had no symbols or failed)
+    LspDocumentSymbols {
+        request_id: u64,
+        symbols: Option<lsp_types::DocumentSymbolResponse>,
+    },
+
+    /// Server asked the editor to apply a workspace edit (workspace/applyEdit)
+    LspApplyEdit {
+        edit: lsp_types::WorkspaceEdit,
+        label: Option<String>,
+    },
+
     /// LSP pulled diagnostics response (textDocument/diagnostic)
     LspPulledDiagnostics {
         request_id: u64,
@@ -158,12 +176,24 @@ pub enum AsyncMessage {
     /// Contains the updated FileTreeView with the path expanded and selected
     FileExplorerExpandedToPath(FileTreeView),
 
+    /// File explorer git status reloaded after a refresh
+    FileExplorerGitStatusLoaded(GitStatusMap),
+
     /// Plugin-related async messages
     Plugin(fresh_core::api::PluginAsyncMessage),
 
     /// File open dialog: directory listing completed
     FileOpenDirectoryLoaded(std::io::Result<Vec<crate::services::fs::FsEntry>>),
 
+    /// Project search: matches found in one file
+    ProjectSearchResults {
+        search_id: u64,
+        matches: Vec<crate::input::quickfix::QuickfixItem>,
+    },
+
+    /// Project search: the walk finished with this many matches
+    ProjectSearchFinished { search_id: u64, total: usize },
+
     /// Terminal output received (triggers redraw)
     TerminalOutput { terminal_id: TerminalId },
 
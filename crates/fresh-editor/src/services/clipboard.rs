@@ -2,27 +2,143 @@
 //!
 //! This module provides a unified clipboard interface that:
 //! - Maintains an internal clipboard for in-editor copy/paste
-//! - Uses crossterm's OSC 52 escape sequences for copying to system clipboard
+//! - Sends OSC 52 escape sequences so copies reach the terminal's clipboard,
+//!   including over SSH and through tmux/screen
 //! - Uses arboard crate for reading from system clipboard
 //! - Supports copying HTML-formatted text for rich text editors
 //! - Gracefully falls back to internal clipboard if system clipboard is unavailable
+//! - Keeps a bounded kill ring of recent clipboard contents for yank-pop
+//! - Holds named registers `a`-`z`, with `0`-`9` reading back the kill ring
 
-use crossterm::clipboard::CopyToClipboard;
-use crossterm::execute;
+use std::collections::{HashMap, VecDeque};
 use std::io::{stdout, Write};
 use std::sync::Mutex;
 
+/// Maximum number of entries kept in the kill ring
+const KILL_RING_CAPACITY: usize = 30;
+
+/// Largest base64 payload sent in a single OSC 52 sequence.
+/// Many terminals silently drop longer sequences (hterm stops at 100 000 bytes),
+/// so bigger copies only go to the local system clipboard.
+const OSC52_MAX_PAYLOAD: usize = 100_000;
+
+/// Length of each DCS chunk when passing OSC 52 through GNU screen,
+/// which truncates longer DCS strings
+const SCREEN_CHUNK_LEN: usize = 76;
+
 /// Global clipboard holder to maintain X11 clipboard ownership for the application lifetime.
 /// On X11, the clipboard owner must stay alive to respond to paste requests from other apps.
 static SYSTEM_CLIPBOARD: Mutex<Option<arboard::Clipboard>> = Mutex::new(None);
 
+/// Shape of the content held in the clipboard
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ClipboardKind {
+    /// Ordinary text, pasted as a single run at the cursor
+    #[default]
+    Linear,
+    /// Rows copied from a block (column) selection, pasted as a rectangle
+    Block,
+}
+
+/// How an OSC 52 sequence has to be wrapped to reach the outer terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Osc52Wrap {
+    /// Written as-is
+    Plain,
+    /// Wrapped in a tmux DCS passthrough
+    Tmux,
+    /// Split into DCS chunks that GNU screen passes through
+    Screen,
+}
+
+impl Osc52Wrap {
+    /// Detect OSC 52 support from the environment
+    ///
+    /// Returns `None` for terminals known not to handle OSC 52, such as the
+    /// Linux console, where the sequence would only show up as garbage.
+    pub fn detect() -> Option<Self> {
+        let term = std::env::var("TERM").ok();
+        Self::for_terminal(
+            term.as_deref(),
+            std::env::var_os("TMUX").is_some(),
+            std::env::var_os("STY").is_some(),
+        )
+    }
+
+    fn for_terminal(term: Option<&str>, in_tmux: bool, in_screen: bool) -> Option<Self> {
+        if in_tmux {
+            return Some(Self::Tmux);
+        }
+        match term {
+            Some("dumb") | Some("linux") => None,
+            Some(term) if term.starts_with("tmux") => Some(Self::Tmux),
+            Some(term) if in_screen || term.starts_with("screen") => Some(Self::Screen),
+            _ => Some(Self::Plain),
+        }
+    }
+}
+
+/// Build the OSC 52 sequence that sets the terminal clipboard to `text`
+///
+/// Returns `None` when the base64 payload would exceed [`OSC52_MAX_PAYLOAD`].
+pub fn osc52_sequence(text: &str, wrap: Osc52Wrap) -> Option<String> {
+    let payload = base64_encode(text.as_bytes());
+    if payload.len() > OSC52_MAX_PAYLOAD {
+        return None;
+    }
+    let osc = format!("\x1b]52;c;{}\x07", payload);
+    Some(match wrap {
+        Osc52Wrap::Plain => osc,
+        // tmux needs every ESC inside the passthrough doubled
+        Osc52Wrap::Tmux => format!("\x1bPtmux;{}\x1b\\", osc.replace('\x1b', "\x1b\x1b")),
+        Osc52Wrap::Screen => osc
+            .as_bytes()
+            .chunks(SCREEN_CHUNK_LEN)
+            .map(|chunk| format!("\x1bP{}\x1b\\", String::from_utf8_lossy(chunk)))
+            .collect(),
+    })
+}
+
+/// Standard base64 encoding with padding (RFC 4648)
+fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = (chunk[0] as u32) << 16
+            | (chunk.get(1).copied().unwrap_or(0) as u32) << 8
+            | chunk.get(2).copied().unwrap_or(0) as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
 /// Clipboard manager that handles both internal and system clipboard
 #[derive(Debug, Clone, Default)]
 pub struct Clipboard {
     /// Internal clipboard content (always available)
     internal: String,
+    /// Shape of the internal clipboard content
+    kind: ClipboardKind,
+    /// Recent clipboard contents, most recent first
+    kill_ring: VecDeque<String>,
+    /// Named registers `a`-`z`, kept apart from the system clipboard
+    registers: HashMap<char, String>,
     /// When true, paste() uses internal clipboard only (for testing)
     internal_only: bool,
+    /// How to send copies to the terminal clipboard via OSC 52, or `None` when
+    /// disabled by config or unsupported by the terminal
+    osc52: Option<Osc52Wrap>,
+}
+
+/// Whether `name` is a register that can be selected with the register prefix
+pub fn is_register_name(name: char) -> bool {
+    name.is_ascii_lowercase() || name.is_ascii_digit()
 }
 
 impl Clipboard {
@@ -30,10 +146,21 @@ impl Clipboard {
     pub fn new() -> Self {
         Self {
             internal: String::new(),
+            kind: ClipboardKind::Linear,
+            kill_ring: VecDeque::new(),
+            registers: HashMap::new(),
             internal_only: false,
+            osc52: Osc52Wrap::detect(),
         }
     }
 
+    /// Enable or disable copying to the terminal clipboard via OSC 52
+    ///
+    /// Enabling has no effect in terminals detected as not supporting it.
+    pub fn set_osc52_enabled(&mut self, enabled: bool) {
+        self.osc52 = if enabled { Osc52Wrap::detect() } else { None };
+    }
+
     /// Enable internal-only mode (for testing)
     /// When enabled, paste() uses internal clipboard only, ignoring system clipboard
     pub fn set_internal_only(&mut self, enabled: bool) {
@@ -47,6 +174,8 @@ impl Clipboard {
     /// Returns true if successful, false otherwise.
     pub fn copy_html(&mut self, html: &str, plain_text: &str) -> bool {
         self.internal = plain_text.to_string();
+        self.kind = ClipboardKind::Linear;
+        self.push_kill_ring(plain_text);
 
         if let Ok(mut guard) = SYSTEM_CLIPBOARD.lock() {
             // Create clipboard if it doesn't exist yet
@@ -84,16 +213,25 @@ impl Clipboard {
     /// 2. arboard crate (works via X11/Wayland APIs in Gnome Console, XFCE Terminal, etc.)
     pub fn copy(&mut self, text: String) {
         self.internal = text.clone();
+        self.kind = ClipboardKind::Linear;
+        self.push_kill_ring(&text);
 
-        // Try OSC 52 first (works in modern terminals)
+        // Try OSC 52 first (works in modern terminals, also over SSH)
         // Note: This doesn't "fail" in a detectable way - it just sends escape sequences
         // that the terminal may or may not handle
-        let osc52_result = execute!(stdout(), CopyToClipboard::to_clipboard_from(&text));
-        if let Err(e) = &osc52_result {
-            tracing::debug!("Crossterm OSC 52 clipboard copy failed: {}", e);
+        if let Some(wrap) = self.osc52 {
+            match osc52_sequence(&text, wrap) {
+                Some(sequence) => {
+                    let mut out = stdout();
+                    if let Err(e) = out.write_all(sequence.as_bytes()).and_then(|_| out.flush()) {
+                        tracing::debug!("OSC 52 clipboard copy failed: {}", e);
+                    }
+                }
+                None => {
+                    tracing::debug!("Copy too large for OSC 52 ({} bytes)", text.len());
+                }
+            }
         }
-        // Ensure the escape sequence is flushed to the terminal
-        let _ = stdout().flush();
 
         // Also try arboard (works via X11/Wayland in terminals without OSC 52 support)
         // This provides coverage for Gnome Console, XFCE Terminal, and similar
@@ -130,6 +268,48 @@ impl Clipboard {
         }
     }
 
+    /// Append text to the most recent kill ring entry and copy the result
+    ///
+    /// Used for consecutive kills, which build up a single entry instead of
+    /// pushing one per kill.
+    pub fn append_kill(&mut self, text: String) {
+        let combined = match self.kill_ring.pop_front() {
+            Some(mut front) => {
+                front.push_str(&text);
+                front
+            }
+            None => text,
+        };
+        self.copy(combined);
+    }
+
+    /// Prepend text to the most recent kill ring entry and copy the result
+    ///
+    /// The counterpart of `append_kill` for kills that move backward, so the
+    /// combined entry keeps the killed text in buffer order.
+    pub fn prepend_kill(&mut self, text: String) {
+        let combined = match self.kill_ring.pop_front() {
+            Some(front) => text + &front,
+            None => text,
+        };
+        self.copy(combined);
+    }
+
+    /// Copy the rows of a block selection, one per line
+    ///
+    /// The text goes to the system clipboard like any other copy; the block
+    /// shape is only remembered internally and is dropped as soon as the
+    /// clipboard holds something else.
+    pub fn copy_block(&mut self, text: String) {
+        self.copy(text);
+        self.kind = ClipboardKind::Block;
+    }
+
+    /// Shape of the current clipboard content
+    pub fn kind(&self) -> ClipboardKind {
+        self.kind
+    }
+
     /// Get text from clipboard, preferring system clipboard
     ///
     /// Tries system clipboard first, falls back to internal clipboard.
@@ -152,6 +332,12 @@ impl Clipboard {
             if let Some(clipboard) = guard.as_mut() {
                 if let Ok(text) = clipboard.get_text() {
                     if !text.is_empty() {
+                        // Text copied in another application has no block shape,
+                        // but joins the kill ring like an in-editor copy
+                        if text != self.internal {
+                            self.kind = ClipboardKind::Linear;
+                            self.push_kill_ring(&text);
+                        }
                         self.internal = text.clone();
                         return Some(text);
                     }
@@ -173,8 +359,51 @@ impl Clipboard {
     }
 
     /// Set the internal clipboard content without updating system clipboard
+    ///
+    /// The kill ring is left alone; only copies and kills are recorded there.
     pub fn set_internal(&mut self, text: String) {
         self.internal = text;
+        self.kind = ClipboardKind::Linear;
+    }
+
+    /// Number of entries in the kill ring
+    pub fn kill_ring_len(&self) -> usize {
+        self.kill_ring.len()
+    }
+
+    /// Get a kill ring entry, where 0 is the most recent
+    pub fn kill_ring_entry(&self, index: usize) -> Option<&str> {
+        self.kill_ring.get(index).map(String::as_str)
+    }
+
+    /// Store text in a named register
+    ///
+    /// Only letter registers can be written; numbered registers always
+    /// reflect the kill ring.
+    pub fn set_register(&mut self, name: char, text: String) {
+        if name.is_ascii_lowercase() {
+            self.registers.insert(name, text);
+        }
+    }
+
+    /// Get the content of a register
+    ///
+    /// `0`-`9` are the kill ring, where `0` is the most recent copy.
+    pub fn register(&self, name: char) -> Option<&str> {
+        match name {
+            'a'..='z' => self.registers.get(&name).map(String::as_str),
+            '0'..='9' => self.kill_ring_entry(name as usize - '0' as usize),
+            _ => None,
+        }
+    }
+
+    /// Record text at the front of the kill ring, dropping the oldest entry when full
+    fn push_kill_ring(&mut self, text: &str) {
+        if text.is_empty() || self.kill_ring.front().is_some_and(|front| front == text) {
+            return;
+        }
+        self.kill_ring.push_front(text.to_string());
+        self.kill_ring.truncate(KILL_RING_CAPACITY);
     }
 
     /// Get text from internal clipboard only (ignores system clipboard)
@@ -225,10 +454,150 @@ mod tests {
         assert_eq!(clipboard.get_internal(), "test");
     }
 
+    #[test]
+    fn test_base64_encode_padding() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+        assert_eq!(base64_encode("héllo".as_bytes()), "aMOpbGxv");
+    }
+
+    #[test]
+    fn test_osc52_sequence_encodes_text() {
+        assert_eq!(
+            osc52_sequence("Hello, world!", Osc52Wrap::Plain).as_deref(),
+            Some("\x1b]52;c;SGVsbG8sIHdvcmxkIQ==\x07")
+        );
+        assert_eq!(
+            osc52_sequence("Hello, world!", Osc52Wrap::Tmux).as_deref(),
+            Some("\x1bPtmux;\x1b\x1b]52;c;SGVsbG8sIHdvcmxkIQ==\x07\x1b\\")
+        );
+    }
+
+    #[test]
+    fn test_osc52_sequence_chunks_for_screen() {
+        let text = "x".repeat(100);
+        let sequence = osc52_sequence(&text, Osc52Wrap::Screen).unwrap();
+        let chunks: Vec<&str> = sequence
+            .split("\x1b\\")
+            .filter(|chunk| !chunk.is_empty())
+            .collect();
+        assert!(chunks.len() > 1);
+        for chunk in &chunks {
+            let body = chunk.strip_prefix("\x1bP").unwrap();
+            assert!(body.len() <= SCREEN_CHUNK_LEN);
+        }
+        let joined: String = chunks.iter().map(|c| &c[2..]).collect();
+        assert_eq!(joined, osc52_sequence(&text, Osc52Wrap::Plain).unwrap());
+    }
+
+    #[test]
+    fn test_osc52_sequence_respects_size_limit() {
+        let fits = "a".repeat(OSC52_MAX_PAYLOAD / 4 * 3);
+        assert!(osc52_sequence(&fits, Osc52Wrap::Plain).is_some());
+        let too_big = "a".repeat(OSC52_MAX_PAYLOAD / 4 * 3 + 1);
+        assert_eq!(osc52_sequence(&too_big, Osc52Wrap::Plain), None);
+    }
+
+    #[test]
+    fn test_osc52_detection() {
+        assert_eq!(
+            Osc52Wrap::for_terminal(Some("xterm-256color"), false, false),
+            Some(Osc52Wrap::Plain)
+        );
+        assert_eq!(Osc52Wrap::for_terminal(Some("linux"), false, false), None);
+        assert_eq!(Osc52Wrap::for_terminal(Some("dumb"), false, false), None);
+        assert_eq!(
+            Osc52Wrap::for_terminal(Some("screen-256color"), true, false),
+            Some(Osc52Wrap::Tmux)
+        );
+        assert_eq!(
+            Osc52Wrap::for_terminal(Some("screen"), false, true),
+            Some(Osc52Wrap::Screen)
+        );
+    }
+
     #[test]
     fn test_clipboard_copy_updates_internal() {
         let mut clipboard = Clipboard::new();
         clipboard.copy("hello".to_string());
         assert_eq!(clipboard.get_internal(), "hello");
     }
+
+    #[test]
+    fn test_clipboard_block_kind_reset_by_linear_copy() {
+        let mut clipboard = Clipboard::new();
+        clipboard.copy_block("ab\ncd".to_string());
+        assert_eq!(clipboard.kind(), ClipboardKind::Block);
+        assert_eq!(clipboard.get_internal(), "ab\ncd");
+
+        clipboard.copy("plain".to_string());
+        assert_eq!(clipboard.kind(), ClipboardKind::Linear);
+    }
+
+    #[test]
+    fn test_kill_ring_is_bounded_and_most_recent_first() {
+        let mut clipboard = Clipboard::new();
+        for i in 0..KILL_RING_CAPACITY + 5 {
+            clipboard.copy(format!("entry {}", i));
+        }
+        // Repeating the latest entry does not add a duplicate
+        clipboard.copy(format!("entry {}", KILL_RING_CAPACITY + 4));
+
+        assert_eq!(clipboard.kill_ring_len(), KILL_RING_CAPACITY);
+        assert_eq!(
+            clipboard.kill_ring_entry(0),
+            Some(format!("entry {}", KILL_RING_CAPACITY + 4).as_str())
+        );
+        assert_eq!(
+            clipboard.kill_ring_entry(1),
+            Some(format!("entry {}", KILL_RING_CAPACITY + 3).as_str())
+        );
+        assert_eq!(clipboard.kill_ring_entry(KILL_RING_CAPACITY), None);
+    }
+
+    #[test]
+    fn test_registers_are_independent() {
+        let mut clipboard = Clipboard::new();
+        clipboard.set_register('a', "first".to_string());
+        clipboard.set_register('b', "second".to_string());
+        clipboard.copy("clip".to_string());
+
+        assert_eq!(clipboard.register('a'), Some("first"));
+        assert_eq!(clipboard.register('b'), Some("second"));
+        assert_eq!(clipboard.register('c'), None);
+        assert_eq!(clipboard.get_internal(), "clip");
+        assert_eq!(clipboard.kill_ring_len(), 1);
+
+        // Setting the content directly doesn't record it in the kill ring
+        clipboard.set_internal("direct".to_string());
+        assert_eq!(clipboard.kill_ring_len(), 1);
+        assert_eq!(clipboard.register('0'), Some("clip"));
+    }
+
+    #[test]
+    fn test_append_and_prepend_kill_extend_the_latest_entry() {
+        let mut clipboard = Clipboard::new();
+        clipboard.copy("older".to_string());
+        clipboard.copy("two".to_string());
+        clipboard.append_kill("\nthree".to_string());
+        clipboard.prepend_kill("one\n".to_string());
+
+        assert_eq!(clipboard.get_internal(), "one\ntwo\nthree");
+        assert_eq!(clipboard.kill_ring_len(), 2);
+        assert_eq!(clipboard.kill_ring_entry(1), Some("older"));
+    }
+
+    #[test]
+    fn test_numbered_registers_read_kill_ring() {
+        let mut clipboard = Clipboard::new();
+        clipboard.copy("older".to_string());
+        clipboard.copy("newer".to_string());
+        clipboard.set_register('1', "ignored".to_string());
+
+        assert_eq!(clipboard.register('0'), Some("newer"));
+        assert_eq!(clipboard.register('1'), Some("older"));
+        assert_eq!(clipboard.register('2'), None);
+    }
 }
@@ -200,8 +200,10 @@ impl LspClientState {
 /// Create common LSP client capabilities with workDoneProgress support
 fn create_client_capabilities() -> ClientCapabilities {
     use lsp_types::{
-        GeneralClientCapabilities, RenameClientCapabilities, TextDocumentClientCapabilities,
-        WorkspaceClientCapabilities, WorkspaceEditClientCapabilities,
+        CodeActionClientCapabilities, CodeActionKind, CodeActionKindLiteralSupport,
+        CodeActionLiteralSupport, DocumentSymbolClientCapabilities, GeneralClientCapabilities,
+        RenameClientCapabilities, TextDocumentClientCapabilities, WorkspaceClientCapabilities,
+        WorkspaceEditClientCapabilities,
     };
 
     ClientCapabilities {
@@ -218,6 +220,29 @@ fn create_client_capabilities() -> ClientCapabilities {
             ..Default::default()
         }),
         text_document: Some(TextDocumentClientCapabilities {
+            code_action: Some(CodeActionClientCapabilities {
+                code_action_literal_support: Some(CodeActionLiteralSupport {
+                    code_action_kind: CodeActionKindLiteralSupport {
+                        value_set: [
+                            CodeActionKind::QUICKFIX,
+                            CodeActionKind::REFACTOR,
+                            CodeActionKind::REFACTOR_EXTRACT,
+                            CodeActionKind::REFACTOR_INLINE,
+                            CodeActionKind::REFACTOR_REWRITE,
+                            CodeActionKind::SOURCE,
+                            CodeActionKind::SOURCE_ORGANIZE_IMPORTS,
+                        ]
+                        .iter()
+                        .map(|kind| kind.as_str().to_string())
+                        .collect(),
+                    },
+                }),
+                ..Default::default()
+            }),
+            document_symbol: Some(DocumentSymbolClientCapabilities {
+                hierarchical_document_symbol_support: Some(true),
+                ..Default::default()
+            }),
             rename: Some(RenameClientCapabilities {
                 dynamic_registration: Some(true),
                 prepare_support: Some(true),
@@ -412,6 +437,20 @@ enum LspCommand {
         diagnostics: Vec<lsp_types::Diagnostic>,
     },
 
+    /// Run a server command (workspace/executeCommand)
+    ExecuteCommand { command: lsp_types::Command },
+
+    /// Request document formatting, or range formatting when `range` is set
+    Formatting {
+        request_id: u64,
+        uri: Uri,
+        range: Option<lsp_types::Range>,
+        options: lsp_types::FormattingOptions,
+    },
+
+    /// Request the symbols defined in a document
+    DocumentSymbols { request_id: u64, uri: Uri },
+
     /// Request document diagnostics (pull model)
     DocumentDiagnostic {
         request_id: u64,
@@ -1440,6 +1479,155 @@ impl LspState {
         }
     }
 
+    /// Handle document or range formatting request
+    #[allow(clippy::type_complexity)]
+    async fn handle_formatting(
+        &mut self,
+        request_id: u64,
+        uri: Uri,
+        range: Option<lsp_types::Range>,
+        options: lsp_types::FormattingOptions,
+        pending: &Arc<Mutex<HashMap<i64, oneshot::Sender<Result<Value, String>>>>>,
+    ) -> Result<(), String> {
+        use lsp_types::{
+            DocumentFormattingParams, DocumentRangeFormattingParams, TextDocumentIdentifier,
+            WorkDoneProgressParams,
+        };
+
+        tracing::trace!("LSP: formatting request for {} ({:?})", uri.as_str(), range);
+
+        let text_document = TextDocumentIdentifier { uri };
+        let response = match range {
+            Some(range) => {
+                let params = DocumentRangeFormattingParams {
+                    text_document,
+                    range,
+                    options,
+                    work_done_progress_params: WorkDoneProgressParams::default(),
+                };
+                self.send_request_sequential::<_, Value>(
+                    "textDocument/rangeFormatting",
+                    Some(params),
+                    pending,
+                )
+                .await
+            }
+            None => {
+                let params = DocumentFormattingParams {
+                    text_document,
+                    options,
+                    work_done_progress_params: WorkDoneProgressParams::default(),
+                };
+                self.send_request_sequential::<_, Value>(
+                    "textDocument/formatting",
+                    Some(params),
+                    pending,
+                )
+                .await
+            }
+        };
+
+        // The response is TextEdit[] or null (no changes)
+        let result = response.and_then(|result| {
+            if result.is_null() {
+                Ok(Vec::new())
+            } else {
+                serde_json::from_value::<Vec<lsp_types::TextEdit>>(result)
+                    .map_err(|e| format!("Failed to parse formatting response: {}", e))
+            }
+        });
+        if let Err(e) = &result {
+            tracing::error!("Formatting request failed: {}", e);
+        }
+
+        let outcome = result.as_ref().map(|_| ()).map_err(Clone::clone);
+        let _ = self
+            .async_tx
+            .send(AsyncMessage::LspFormatting { request_id, result });
+        outcome
+    }
+
+    /// Handle document symbols request
+    #[allow(clippy::type_complexity)]
+    async fn handle_document_symbols(
+        &mut self,
+        request_id: u64,
+        uri: Uri,
+        pending: &Arc<Mutex<HashMap<i64, oneshot::Sender<Result<Value, String>>>>>,
+    ) -> Result<(), String> {
+        use lsp_types::{
+            DocumentSymbolParams, PartialResultParams, TextDocumentIdentifier,
+            WorkDoneProgressParams,
+        };
+
+        tracing::trace!("LSP: document symbols request for {}", uri.as_str());
+
+        let params = DocumentSymbolParams {
+            text_document: TextDocumentIdentifier { uri },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+        };
+
+        match self
+            .send_request_sequential::<_, Value>(
+                "textDocument/documentSymbol",
+                Some(params),
+                pending,
+            )
+            .await
+        {
+            Ok(result) => {
+                // The response is DocumentSymbol[], SymbolInformation[] or null
+                let symbols = if result.is_null() {
+                    None
+                } else {
+                    serde_json::from_value::<lsp_types::DocumentSymbolResponse>(result)
+                        .map_err(|e| {
+                            tracing::error!("Failed to parse document symbols response: {}", e)
+                        })
+                        .ok()
+                };
+                let _ = self.async_tx.send(AsyncMessage::LspDocumentSymbols {
+                    request_id,
+                    symbols,
+                });
+                Ok(())
+            }
+            Err(e) => {
+                tracing::error!("Document symbols request failed: {}", e);
+                let _ = self.async_tx.send(AsyncMessage::LspDocumentSymbols {
+                    request_id,
+                    symbols: None,
+                });
+                Err(e)
+            }
+        }
+    }
+
+    /// Handle execute command request
+    ///
+    /// The result is ignored: servers report the effect of a command by sending
+    /// `workspace/applyEdit` back while the request is in flight.
+    #[allow(clippy::type_complexity)]
+    async fn handle_execute_command(
+        &mut self,
+        command: lsp_types::Command,
+        pending: &Arc<Mutex<HashMap<i64, oneshot::Sender<Result<Value, String>>>>>,
+    ) -> Result<(), String> {
+        use lsp_types::{ExecuteCommandParams, WorkDoneProgressParams};
+
+        let params = ExecuteCommandParams {
+            command: command.command,
+            arguments: command.arguments.unwrap_or_default(),
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        };
+
+        self.send_request_sequential::<_, Value>("workspace/executeCommand", Some(params), pending)
+            .await
+            .map(|_| ())
+            .inspect_err(|e| tracing::error!("Execute command request failed: {}", e))
+    }
+
     /// Handle document diagnostic request (pull diagnostics)
     #[allow(clippy::type_complexity)]
     async fn handle_document_diagnostic(
@@ -2400,6 +2588,50 @@ impl LspTask {
                                 });
                             }
                         }
+                        LspCommand::Formatting {
+                            request_id,
+                            uri,
+                            range,
+                            options,
+                        } => {
+                            if state.initialized {
+                                tracing::info!("Processing Formatting request for {}", uri.as_str());
+                                let _ = state
+                                    .handle_formatting(request_id, uri, range, options, &pending)
+                                    .await;
+                            } else {
+                                tracing::trace!("LSP not initialized, cannot format");
+                                let _ = state.async_tx.send(AsyncMessage::LspFormatting {
+                                    request_id,
+                                    result: Err("LSP not initialized".to_string()),
+                                });
+                            }
+                        }
+                        LspCommand::DocumentSymbols { request_id, uri } => {
+                            if state.initialized {
+                                tracing::info!(
+                                    "Processing DocumentSymbols request for {}",
+                                    uri.as_str()
+                                );
+                                let _ = state
+                                    .handle_document_symbols(request_id, uri, &pending)
+                                    .await;
+                            } else {
+                                tracing::trace!("LSP not initialized, cannot get document symbols");
+                                let _ = state.async_tx.send(AsyncMessage::LspDocumentSymbols {
+                                    request_id,
+                                    symbols: None,
+                                });
+                            }
+                        }
+                        LspCommand::ExecuteCommand { command } => {
+                            if state.initialized {
+                                tracing::info!("Processing ExecuteCommand {}", command.command);
+                                let _ = state.handle_execute_command(command, &pending).await;
+                            } else {
+                                tracing::trace!("LSP not initialized, cannot execute command");
+                            }
+                        }
                         LspCommand::DocumentDiagnostic {
                             request_id,
                             uri,
@@ -2754,6 +2986,26 @@ async fn handle_message_dispatch(
                         error: None,
                     }
                 }
+                "workspace/applyEdit" => {
+                    // Server wants an edit applied (usually while executing a command).
+                    // The editor applies it on the main loop, so report success up front.
+                    let params = request.params.clone().and_then(|p| {
+                        serde_json::from_value::<lsp_types::ApplyWorkspaceEditParams>(p).ok()
+                    });
+                    let applied = params.is_some();
+                    if let Some(params) = params {
+                        let _ = async_tx.send(AsyncMessage::LspApplyEdit {
+                            edit: params.edit,
+                            label: params.label,
+                        });
+                    }
+                    JsonRpcResponse {
+                        jsonrpc: "2.0".to_string(),
+                        id: request.id,
+                        result: Some(serde_json::json!({ "applied": applied })),
+                        error: None,
+                    }
+                }
                 "client/registerCapability" => {
                     // Server wants to register a capability dynamically - acknowledge
                     tracing::trace!(
@@ -3365,6 +3617,38 @@ impl LspHandle {
             .map_err(|_| "Failed to send code_actions command".to_string())
     }
 
+    /// Request document formatting, or range formatting when `range` is set
+    pub fn formatting(
+        &self,
+        request_id: u64,
+        uri: Uri,
+        range: Option<lsp_types::Range>,
+        options: lsp_types::FormattingOptions,
+    ) -> Result<(), String> {
+        self.command_tx
+            .try_send(LspCommand::Formatting {
+                request_id,
+                uri,
+                range,
+                options,
+            })
+            .map_err(|_| "Failed to send formatting command".to_string())
+    }
+
+    /// Request the symbols defined in a document
+    pub fn document_symbols(&self, request_id: u64, uri: Uri) -> Result<(), String> {
+        self.command_tx
+            .try_send(LspCommand::DocumentSymbols { request_id, uri })
+            .map_err(|_| "Failed to send document_symbols command".to_string())
+    }
+
+    /// Run a server command (workspace/executeCommand)
+    pub fn execute_command(&self, command: lsp_types::Command) -> Result<(), String> {
+        self.command_tx
+            .try_send(LspCommand::ExecuteCommand { command })
+            .map_err(|_| "Failed to send execute_command command".to_string())
+    }
+
     /// Request document diagnostics (pull model)
     ///
     /// This sends a textDocument/diagnostic request to fetch diagnostics on demand.
@@ -2,10 +2,12 @@
 //!
 //! This module handles converting LSP diagnostics to visual overlays in the editor.
 //! Diagnostics are displayed as colored underlines (red for errors, yellow for warnings, etc.)
+//! and a severity glyph in the gutter.
 use crate::model::buffer::Buffer;
 use crate::state::EditorState;
-use crate::view::overlay::{Overlay, OverlayFace, OverlayNamespace};
+use crate::view::overlay::{Overlay, OverlayFace, OverlayNamespace, Priority};
 use lsp_types::{Diagnostic, DiagnosticSeverity};
+use ratatui::style::{Color, Modifier, Style};
 use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
@@ -17,6 +19,34 @@ pub fn lsp_diagnostic_namespace() -> OverlayNamespace {
     OverlayNamespace::from_string("lsp-diagnostic".to_string())
 }
 
+/// Overlay priority of error diagnostics
+pub const ERROR_PRIORITY: Priority = 100;
+/// Overlay priority of warning diagnostics
+pub const WARNING_PRIORITY: Priority = 50;
+/// Overlay priority of information diagnostics
+pub const INFO_PRIORITY: Priority = 30;
+/// Overlay priority of hint diagnostics
+pub const HINT_PRIORITY: Priority = 10;
+
+/// Gutter glyph and color for a diagnostic overlay of the given priority
+///
+/// Diagnostic overlays are prioritized by severity, so when a line has several
+/// diagnostics the caller passes the highest priority among them.
+pub fn diagnostic_gutter_glyph(
+    priority: Priority,
+    theme: &crate::view::theme::Theme,
+) -> (&'static str, Color) {
+    if priority >= ERROR_PRIORITY {
+        ("●", theme.diagnostic_error_fg)
+    } else if priority >= WARNING_PRIORITY {
+        ("▲", theme.diagnostic_warning_fg)
+    } else if priority >= INFO_PRIORITY {
+        ("◆", theme.diagnostic_info_fg)
+    } else {
+        ("·", theme.diagnostic_hint_fg)
+    }
+}
+
 /// Cache for diagnostic hash to avoid redundant updates, keyed by file path.
 /// This prevents diagnostics from one buffer from invalidating another buffer's cache.
 static DIAGNOSTIC_CACHE: LazyLock<Mutex<HashMap<String, u64>>> =
@@ -117,34 +147,37 @@ pub fn diagnostic_to_overlay(
     let start_byte = buffer.lsp_position_to_byte(start_line, start_char);
     let end_byte = buffer.lsp_position_to_byte(end_line, end_char);
 
-    // Determine overlay face based on diagnostic severity using theme colors
-    let (face, priority) = match diagnostic.severity {
+    // Determine overlay face based on diagnostic severity using theme colors:
+    // a tinted background with an underline in the severity's foreground color
+    let (fg, bg, priority) = match diagnostic.severity {
         Some(DiagnosticSeverity::ERROR) => (
-            OverlayFace::Background {
-                color: theme.diagnostic_error_bg,
-            },
-            100, // Highest priority
+            theme.diagnostic_error_fg,
+            theme.diagnostic_error_bg,
+            ERROR_PRIORITY,
         ),
         Some(DiagnosticSeverity::WARNING) => (
-            OverlayFace::Background {
-                color: theme.diagnostic_warning_bg,
-            },
-            50, // Medium priority
+            theme.diagnostic_warning_fg,
+            theme.diagnostic_warning_bg,
+            WARNING_PRIORITY,
         ),
         Some(DiagnosticSeverity::INFORMATION) => (
-            OverlayFace::Background {
-                color: theme.diagnostic_info_bg,
-            },
-            30, // Lower priority
+            theme.diagnostic_info_fg,
+            theme.diagnostic_info_bg,
+            INFO_PRIORITY,
         ),
         Some(DiagnosticSeverity::HINT) | None => (
-            OverlayFace::Background {
-                color: theme.diagnostic_hint_bg,
-            },
-            10, // Lowest priority
+            theme.diagnostic_hint_fg,
+            theme.diagnostic_hint_bg,
+            HINT_PRIORITY,
         ),
         _ => return None, // Unknown severity
     };
+    let face = OverlayFace::Style {
+        style: Style::default()
+            .bg(bg)
+            .add_modifier(Modifier::UNDERLINED)
+            .underline_color(fg),
+    };
 
     Some((start_byte..end_byte, face, priority))
 }
@@ -250,10 +283,12 @@ mod tests {
         assert_eq!(priority, 100); // Error has highest priority
 
         match face {
-            OverlayFace::Background { color } => {
-                assert_eq!(color, theme.diagnostic_error_bg);
+            OverlayFace::Style { style } => {
+                assert_eq!(style.bg, Some(theme.diagnostic_error_bg));
+                assert_eq!(style.underline_color, Some(theme.diagnostic_error_fg));
+                assert!(style.add_modifier.contains(Modifier::UNDERLINED));
             }
-            _ => panic!("Expected Background face"),
+            _ => panic!("Expected Style face"),
         }
     }
 
@@ -291,10 +326,12 @@ mod tests {
         assert_eq!(priority, 50); // Warning has medium priority
 
         match face {
-            OverlayFace::Background { color } => {
-                assert_eq!(color, theme.diagnostic_warning_bg);
+            OverlayFace::Style { style } => {
+                assert_eq!(style.bg, Some(theme.diagnostic_warning_bg));
+                assert_eq!(style.underline_color, Some(theme.diagnostic_warning_fg));
+                assert!(style.add_modifier.contains(Modifier::UNDERLINED));
             }
-            _ => panic!("Expected Background face"),
+            _ => panic!("Expected Style face"),
         }
     }
 
@@ -334,4 +371,26 @@ mod tests {
         assert_eq!(range.start, 3);
         assert_eq!(range.end, 8);
     }
+
+    #[test]
+    fn test_diagnostic_gutter_glyph_by_severity() {
+        let theme = crate::view::theme::Theme::load_builtin(theme::THEME_DARK).unwrap();
+
+        assert_eq!(
+            diagnostic_gutter_glyph(ERROR_PRIORITY, &theme),
+            ("●", theme.diagnostic_error_fg)
+        );
+        assert_eq!(
+            diagnostic_gutter_glyph(WARNING_PRIORITY, &theme),
+            ("▲", theme.diagnostic_warning_fg)
+        );
+        assert_eq!(
+            diagnostic_gutter_glyph(INFO_PRIORITY, &theme),
+            ("◆", theme.diagnostic_info_fg)
+        );
+        assert_eq!(
+            diagnostic_gutter_glyph(HINT_PRIORITY, &theme),
+            ("·", theme.diagnostic_hint_fg)
+        );
+    }
 }
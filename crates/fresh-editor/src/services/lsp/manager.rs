@@ -765,6 +765,8 @@ mod tests {
                 show_whitespace_tabs: false,
                 use_tabs: false,
                 tab_size: None,
+                line_wrap: None,
+                line_numbers: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
@@ -783,6 +785,8 @@ mod tests {
                 show_whitespace_tabs: false,
                 use_tabs: false,
                 tab_size: None,
+                line_wrap: None,
+                line_numbers: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
@@ -801,6 +805,8 @@ mod tests {
                 show_whitespace_tabs: false,
                 use_tabs: false,
                 tab_size: None,
+                line_wrap: None,
+                line_numbers: None,
                 formatter: None,
                 format_on_save: false,
                 on_save: vec![],
@@ -12,6 +12,7 @@ pub mod log_dirs;
 pub mod lsp;
 pub mod plugins;
 pub mod process_limits;
+pub mod project_search;
 pub mod recovery;
 pub mod release_checker;
 pub mod signal_handler;
@@ -21,4 +22,5 @@ pub mod terminal;
 pub mod terminal_modes;
 pub mod time_source;
 pub mod tracing_setup;
+pub mod undo_history;
 pub mod warning_log;
@@ -200,6 +200,34 @@ impl PluginManager {
         }
     }
 
+    /// Run a plugin text object matcher (fire-and-forget).
+    pub fn match_text_object(&self, name: &str, query: super::api::TextObjectQuery) {
+        #[cfg(feature = "plugins")]
+        {
+            if let Some(ref manager) = self.inner {
+                manager.match_text_object(name, query);
+            }
+        }
+        #[cfg(not(feature = "plugins"))]
+        {
+            let _ = (name, query);
+        }
+    }
+
+    /// Ask a plugin open handler for the content of a buffer (fire-and-forget).
+    pub fn open_with_handler(&self, pattern: &str, path: &str, buffer_id: fresh_core::BufferId) {
+        #[cfg(feature = "plugins")]
+        {
+            if let Some(ref manager) = self.inner {
+                manager.open_with_handler(pattern, path, buffer_id);
+            }
+        }
+        #[cfg(not(feature = "plugins"))]
+        {
+            let _ = (pattern, path, buffer_id);
+        }
+    }
+
     /// Deliver a response to a pending async plugin operation.
     pub fn deliver_response(&self, response: super::api::PluginResponse) {
         #[cfg(feature = "plugins")]
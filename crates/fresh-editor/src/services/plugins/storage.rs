@@ -0,0 +1,149 @@
+//! Persistent storage for plugins
+//!
+//! Each plugin gets its own JSON object, saved as `<plugin name>.json` in the
+//! plugin storage directory, so plugins cannot overwrite each other's keys.
+//! Plugins read through the state snapshot (`editor.getStorage`) and write
+//! through `PluginCommand::SetPluginStorage` (`editor.setStorage`).
+
+use fresh_core::api::{plugin_storage_fits, MAX_PLUGIN_STORAGE_BYTES};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Stored values for every plugin, keyed by plugin name
+#[derive(Debug, Default)]
+pub struct PluginStorage {
+    dir: PathBuf,
+    plugins: HashMap<String, Map<String, Value>>,
+}
+
+impl PluginStorage {
+    /// Load every plugin's storage file from `dir`
+    ///
+    /// Files that cannot be read or parsed are skipped with a warning.
+    pub fn load(dir: &Path) -> Self {
+        let mut plugins = HashMap::new();
+        if let Ok(entries) = std::fs::read_dir(dir) {
+            for path in entries.filter_map(|e| e.ok()).map(|e| e.path()) {
+                if path.extension().is_none_or(|ext| ext != "json") {
+                    continue;
+                }
+                let Some(name) = path.file_stem().map(|s| s.to_string_lossy().to_string()) else {
+                    continue;
+                };
+                let parsed = std::fs::read_to_string(&path)
+                    .map_err(|e| e.to_string())
+                    .and_then(|content| {
+                        serde_json::from_str::<Map<String, Value>>(&content)
+                            .map_err(|e| e.to_string())
+                    });
+                match parsed {
+                    Ok(storage) => {
+                        plugins.insert(name, storage);
+                    }
+                    Err(e) => {
+                        tracing::warn!("Ignoring plugin storage {}: {}", path.display(), e)
+                    }
+                }
+            }
+        }
+        Self {
+            dir: dir.to_path_buf(),
+            plugins,
+        }
+    }
+
+    /// Stored values for every plugin
+    pub fn all(&self) -> &HashMap<String, Map<String, Value>> {
+        &self.plugins
+    }
+
+    /// Set `key` in `plugin_name`'s storage and save it to disk
+    ///
+    /// A null `value` removes the key. The change is refused if the plugin's
+    /// storage would grow past [`MAX_PLUGIN_STORAGE_BYTES`].
+    pub fn set(&mut self, plugin_name: &str, key: String, value: Value) -> Result<(), String> {
+        let mut storage = self.plugins.get(plugin_name).cloned().unwrap_or_default();
+        if value.is_null() {
+            storage.remove(&key);
+        } else {
+            storage.insert(key, value);
+        }
+        if !plugin_storage_fits(&storage) {
+            return Err(format!(
+                "storage for plugin '{}' would exceed {} bytes",
+                plugin_name, MAX_PLUGIN_STORAGE_BYTES
+            ));
+        }
+
+        self.save(plugin_name, &storage)?;
+        if storage.is_empty() {
+            self.plugins.remove(plugin_name);
+        } else {
+            self.plugins.insert(plugin_name.to_string(), storage);
+        }
+        Ok(())
+    }
+
+    fn save(&self, plugin_name: &str, storage: &Map<String, Value>) -> Result<(), String> {
+        let path = self.dir.join(format!("{plugin_name}.json"));
+        if storage.is_empty() {
+            return match std::fs::remove_file(&path) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.to_string()),
+                _ => Ok(()),
+            };
+        }
+        let content = serde_json::to_string_pretty(storage).map_err(|e| e.to_string())?;
+        std::fs::create_dir_all(&self.dir).map_err(|e| e.to_string())?;
+        std::fs::write(&path, content).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_values_survive_reload_per_plugin() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut storage = PluginStorage::load(temp_dir.path());
+        storage.set("alpha", "count".to_string(), json!(3)).unwrap();
+        storage
+            .set("beta", "count".to_string(), json!({"nested": [1, 2]}))
+            .unwrap();
+
+        let reloaded = PluginStorage::load(temp_dir.path());
+        assert_eq!(reloaded.all()["alpha"]["count"], json!(3));
+        assert_eq!(reloaded.all()["beta"]["count"], json!({"nested": [1, 2]}));
+    }
+
+    #[test]
+    fn test_null_removes_key_and_empty_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut storage = PluginStorage::load(temp_dir.path());
+        storage.set("alpha", "key".to_string(), json!("v")).unwrap();
+        storage
+            .set("alpha", "key".to_string(), Value::Null)
+            .unwrap();
+
+        assert!(storage.all().is_empty());
+        assert!(!temp_dir.path().join("alpha.json").exists());
+    }
+
+    #[test]
+    fn test_oversized_value_is_refused() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut storage = PluginStorage::load(temp_dir.path());
+        storage.set("alpha", "small".to_string(), json!(1)).unwrap();
+
+        let big = "x".repeat(MAX_PLUGIN_STORAGE_BYTES);
+        assert!(storage.set("alpha", "big".to_string(), json!(big)).is_err());
+        // The earlier value is kept
+        assert_eq!(storage.all()["alpha"].len(), 1);
+        assert_eq!(
+            PluginStorage::load(temp_dir.path()).all()["alpha"]["small"],
+            json!(1)
+        );
+    }
+}
@@ -0,0 +1,190 @@
+//! Project-wide search: find every line matching a pattern under a directory
+//!
+//! The walk honours `.gitignore` and the hidden-file setting the same way the
+//! file explorer does, skips binary files, and reports matches one file at a
+//! time so the caller can stream results to the UI while the walk continues.
+
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use ignore::WalkBuilder;
+use regex::Regex;
+
+use crate::input::quickfix::QuickfixItem;
+
+/// Maximum number of matches reported for a single search
+pub const MAX_PROJECT_SEARCH_RESULTS: usize = 10_000;
+
+/// Files larger than this are skipped
+const MAX_FILE_SIZE: u64 = 10 * 1024 * 1024;
+
+/// Number of leading bytes checked for NUL when detecting binary files
+const BINARY_CHECK_LEN: usize = 8 * 1024;
+
+/// Which files a project search visits
+#[derive(Debug, Clone, Copy)]
+pub struct ProjectSearchOptions {
+    /// Search hidden files and directories (names starting with `.`)
+    pub include_hidden: bool,
+    /// Skip files excluded by `.gitignore`
+    pub respect_gitignore: bool,
+}
+
+/// Search every file under `root` for lines matching `regex`.
+///
+/// `on_file` is called with the matches of each file that has any, in walk
+/// order. The walk stops early once `cancelled` is set or the result limit is
+/// reached. Returns the total number of matches reported.
+pub fn search_project(
+    root: &Path,
+    regex: &Regex,
+    options: ProjectSearchOptions,
+    cancelled: &AtomicBool,
+    mut on_file: impl FnMut(Vec<QuickfixItem>),
+) -> usize {
+    let walker = WalkBuilder::new(root)
+        .hidden(!options.include_hidden)
+        .git_ignore(options.respect_gitignore)
+        .git_global(options.respect_gitignore)
+        .git_exclude(options.respect_gitignore)
+        .require_git(false)
+        .sort_by_file_name(|a, b| a.cmp(b))
+        .build();
+
+    let mut total = 0;
+    for entry in walker {
+        if cancelled.load(Ordering::Relaxed) || total >= MAX_PROJECT_SEARCH_RESULTS {
+            break;
+        }
+        let Ok(entry) = entry else {
+            continue;
+        };
+        if !entry.file_type().is_some_and(|t| t.is_file()) {
+            continue;
+        }
+        if entry
+            .metadata()
+            .is_ok_and(|metadata| metadata.len() > MAX_FILE_SIZE)
+        {
+            continue;
+        }
+
+        let matches = search_file(entry.path(), regex, MAX_PROJECT_SEARCH_RESULTS - total);
+        if !matches.is_empty() {
+            total += matches.len();
+            on_file(matches);
+        }
+    }
+    total
+}
+
+/// Find up to `limit` matching lines in a single file
+fn search_file(path: &Path, regex: &Regex, limit: usize) -> Vec<QuickfixItem> {
+    let Ok(bytes) = std::fs::read(path) else {
+        return Vec::new();
+    };
+    if bytes[..bytes.len().min(BINARY_CHECK_LEN)].contains(&0) {
+        return Vec::new();
+    }
+
+    let text = String::from_utf8_lossy(&bytes);
+    text.lines()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let m = regex.find_iter(line).find(|m| !m.is_empty())?;
+            let column = line[..m.start()].chars().count() + 1;
+            Some(QuickfixItem::new(
+                path.to_path_buf(),
+                index + 1,
+                column,
+                line.trim(),
+            ))
+        })
+        .take(limit)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect(root: &Path, pattern: &str, options: ProjectSearchOptions) -> Vec<QuickfixItem> {
+        let regex = Regex::new(pattern).unwrap();
+        let mut results = Vec::new();
+        search_project(root, &regex, options, &AtomicBool::new(false), |matches| {
+            results.extend(matches)
+        });
+        results
+    }
+
+    #[test]
+    fn test_search_project_lists_matches_in_walk_order() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("a.txt"),
+            "one\nneedle here\n  needle again\n",
+        )
+        .unwrap();
+        std::fs::create_dir(dir.path().join("sub")).unwrap();
+        std::fs::write(dir.path().join("sub/b.txt"), "x needle\n").unwrap();
+        std::fs::write(dir.path().join("bin.dat"), b"needle\0\x01").unwrap();
+
+        let options = ProjectSearchOptions {
+            include_hidden: false,
+            respect_gitignore: true,
+        };
+        let results = collect(dir.path(), "needle", options);
+        let found: Vec<_> = results
+            .iter()
+            .map(|item| {
+                (
+                    item.path.strip_prefix(dir.path()).unwrap().to_path_buf(),
+                    item.line,
+                    item.column,
+                    item.text.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("a.txt".into(), 2, 1, "needle here"),
+                ("a.txt".into(), 3, 3, "needle again"),
+                ("sub/b.txt".into(), 1, 3, "x needle"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_search_project_respects_gitignore_and_hidden() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join(".gitignore"), "ignored.txt\n").unwrap();
+        std::fs::write(dir.path().join("ignored.txt"), "needle\n").unwrap();
+        std::fs::write(dir.path().join(".hidden.txt"), "needle\n").unwrap();
+        std::fs::write(dir.path().join("kept.txt"), "needle\n").unwrap();
+
+        let names = |options| {
+            let mut names: Vec<_> = collect(dir.path(), "needle", options)
+                .into_iter()
+                .map(|item| item.path.file_name().unwrap().to_string_lossy().to_string())
+                .collect();
+            names.sort();
+            names
+        };
+
+        assert_eq!(
+            names(ProjectSearchOptions {
+                include_hidden: false,
+                respect_gitignore: true,
+            }),
+            vec!["kept.txt"]
+        );
+        assert_eq!(
+            names(ProjectSearchOptions {
+                include_hidden: true,
+                respect_gitignore: false,
+            }),
+            vec![".hidden.txt", "ignored.txt", "kept.txt"]
+        );
+    }
+}
@@ -1,7 +1,8 @@
 //! Styled text rendering for clipboard copy feature
 //!
 //! This module renders styled text with syntax highlighting as HTML
-//! for pasting into rich text editors (Google Docs, Word, etc.)
+//! for pasting into rich text editors (Google Docs, Word, etc.) and for
+//! exporting buffers as HTML fragments, optionally with line numbers
 
 use crate::primitives::highlighter::HighlightSpan;
 use crate::view::theme::Theme;
@@ -45,6 +46,29 @@ fn color_to_css(color: Color, default: &str) -> String {
 /// # Returns
 /// HTML string with inline styles
 pub fn render_styled_html(text: &str, highlight_spans: &[HighlightSpan], theme: &Theme) -> String {
+    render_html(text, highlight_spans, theme, None)
+}
+
+/// Render styled text to HTML like [`render_styled_html`], prefixing each
+/// line with its line number
+///
+/// `first_line` is the 1-based number of the first line of `text`. Numbers
+/// are right-aligned and colored with the theme's line number color.
+pub fn render_styled_html_with_line_numbers(
+    text: &str,
+    highlight_spans: &[HighlightSpan],
+    theme: &Theme,
+    first_line: usize,
+) -> String {
+    render_html(text, highlight_spans, theme, Some(first_line))
+}
+
+fn render_html(
+    text: &str,
+    highlight_spans: &[HighlightSpan],
+    theme: &Theme,
+    first_line: Option<usize>,
+) -> String {
     let bg_color = color_to_css(theme.editor_bg, "#1e1e1e");
     let fg_color = color_to_css(theme.editor_fg, "#d4d4d4");
 
@@ -65,6 +89,24 @@ pub fn render_styled_html(text: &str, highlight_spans: &[HighlightSpan], theme:
         bg_color, fg_color
     ));
 
+    // Line number gutter: every number is padded to the width of the last one
+    let line_number_color = color_to_css(theme.line_number_fg, "#858585");
+    let line_number_width = first_line
+        .map(|first| (first + text.matches('\n').count()).to_string().len())
+        .unwrap_or(0);
+    let push_line_number = |html: &mut String, line: usize| {
+        html.push_str(&format!(
+            "<span style=\"color:{};user-select:none;\">{:>width$} </span>",
+            line_number_color,
+            line,
+            width = line_number_width
+        ));
+    };
+    let mut line_number = first_line;
+    if let Some(line) = line_number {
+        push_line_number(&mut html, line);
+    }
+
     let mut current_color: Option<Color> = None;
     let mut span_open = false;
     let mut byte_offset = 0;
@@ -72,6 +114,23 @@ pub fn render_styled_html(text: &str, highlight_spans: &[HighlightSpan], theme:
     for ch in text.chars() {
         let char_byte_len = ch.len_utf8();
 
+        // Start each new line with its number, outside any color span
+        if let (Some(line), true) = (line_number, ch == '\n') {
+            if span_open {
+                html.push_str("</span>");
+                span_open = false;
+            }
+            current_color = None;
+            html.push('\n');
+            byte_offset += char_byte_len;
+            // A trailing newline ends the last line rather than starting one
+            if byte_offset < text.len() {
+                push_line_number(&mut html, line + 1);
+            }
+            line_number = Some(line + 1);
+            continue;
+        }
+
         // Get color for this character
         let char_color = if byte_offset < color_map.len() {
             color_map[byte_offset]
@@ -168,6 +227,51 @@ mod tests {
         assert!(html.contains("main()"));
     }
 
+    #[test]
+    fn test_render_html_with_line_numbers() {
+        let text = "let a = 1;\nlet b = 2;\n";
+        let spans = vec![
+            HighlightSpan {
+                range: 0..3,
+                color: Color::Rgb(0x56, 0x9c, 0xd6),
+            },
+            HighlightSpan {
+                range: 11..14,
+                color: Color::Rgb(0x56, 0x9c, 0xd6),
+            },
+        ];
+        let theme = Theme::load_builtin(theme::THEME_DARK).unwrap();
+        let number_color = color_to_css(theme.line_number_fg, "");
+
+        let html = render_styled_html_with_line_numbers(text, &spans, &theme, 9);
+
+        assert!(html.contains(&format!(
+            "<span style=\"color:{};user-select:none;\"> 9 </span><span style=\"color:#569cd6;\">let</span> a = 1;\n",
+            number_color
+        )));
+        assert!(html.contains(&format!(
+            "<span style=\"color:{};user-select:none;\">10 </span><span style=\"color:#569cd6;\">let</span> b = 2;\n</pre>",
+            number_color
+        )));
+        assert_eq!(html.matches("user-select:none").count(), 2);
+    }
+
+    #[test]
+    fn test_render_html_line_numbers_close_spans_at_line_end() {
+        // A span crossing a line break is closed before the next line number
+        let text = "/* a\nb */";
+        let spans = vec![HighlightSpan {
+            range: 0..text.len(),
+            color: Color::Green,
+        }];
+        let theme = Theme::load_builtin(theme::THEME_DARK).unwrap();
+
+        let html = render_styled_html_with_line_numbers(text, &spans, &theme, 1);
+
+        assert!(html.contains("<span style=\"color:#0dbc79;\">/* a</span>\n"));
+        assert!(html.contains("2 </span><span style=\"color:#0dbc79;\">b */</span></pre>"));
+    }
+
     #[test]
     fn test_color_to_css() {
         assert_eq!(color_to_css(Color::Black, "#fff"), "#000000");
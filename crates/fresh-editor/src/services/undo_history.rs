@@ -0,0 +1,184 @@
+//! Persistent undo history
+//!
+//! Keeps each file's undo log on disk so undo keeps working after the editor
+//! restarts. Histories are keyed by the file's canonical path and record a
+//! hash of the content they were saved with, so a file changed outside the
+//! editor never has stale edits replayed onto it.
+
+use crate::model::event::{EventLog, LogEntry};
+use crate::services::recovery::types::path_hash;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Bumped whenever the on-disk format changes; other versions are ignored
+const FORMAT_VERSION: u32 = 1;
+
+/// A file's undo history as written to disk
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredHistory {
+    version: u32,
+    /// Canonical path of the file, to rule out hash collisions
+    path: PathBuf,
+    /// SHA-256 of the file content at `position`
+    content_hash: String,
+    /// Where the saved content sits in `entries`
+    position: usize,
+    entries: Vec<LogEntry>,
+}
+
+/// Reads and writes undo histories in a directory
+#[derive(Debug, Clone)]
+pub struct UndoHistoryStore {
+    dir: PathBuf,
+}
+
+impl UndoHistoryStore {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    fn history_path(&self, canonical: &Path) -> PathBuf {
+        self.dir.join(format!("{}.json", path_hash(canonical)))
+    }
+
+    /// Write the history of `file`, whose saved content is `content`
+    ///
+    /// Removes any stored history if `log` has nothing worth keeping.
+    pub fn save(&self, file: &Path, content: &[u8], log: &EventLog) -> io::Result<()> {
+        let canonical = canonical_path(file);
+        let target = self.history_path(&canonical);
+        let Some((entries, position)) = log.persistable_history() else {
+            return match fs::remove_file(&target) {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+                _ => Ok(()),
+            };
+        };
+
+        let stored = StoredHistory {
+            version: FORMAT_VERSION,
+            path: canonical,
+            content_hash: content_hash(content),
+            position,
+            entries,
+        };
+        let json = serde_json::to_vec(&stored).map_err(io::Error::other)?;
+
+        fs::create_dir_all(&self.dir)?;
+        let temp_path = target.with_extension("tmp");
+        fs::write(&temp_path, json)?;
+        fs::rename(&temp_path, &target)
+    }
+
+    /// Restore the stored history of `file` into `log` if it was saved with `content`
+    ///
+    /// Returns whether a history was restored.
+    pub fn load(&self, file: &Path, content: &[u8], log: &mut EventLog) -> io::Result<bool> {
+        let canonical = canonical_path(file);
+        let json = match fs::read(self.history_path(&canonical)) {
+            Ok(json) => json,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(false),
+            Err(e) => return Err(e),
+        };
+        let stored: StoredHistory = serde_json::from_slice(&json)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        if stored.version != FORMAT_VERSION
+            || stored.path != canonical
+            || stored.content_hash != content_hash(content)
+            || stored.position > stored.entries.len()
+        {
+            return Ok(false);
+        }
+        log.restore_history(stored.entries, stored.position);
+        Ok(true)
+    }
+}
+
+fn canonical_path(file: &Path) -> PathBuf {
+    fs::canonicalize(file).unwrap_or_else(|_| file.to_path_buf())
+}
+
+fn content_hash(content: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+    format!("{:x}", Sha256::digest(content))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::event::{CursorId, Event};
+    use tempfile::TempDir;
+
+    fn insert(position: usize, text: &str) -> Event {
+        Event::Insert {
+            position,
+            text: text.to_string(),
+            cursor_id: CursorId(0),
+        }
+    }
+
+    fn setup() -> (TempDir, UndoHistoryStore, PathBuf) {
+        let temp_dir = TempDir::new().unwrap();
+        let store = UndoHistoryStore::new(temp_dir.path().join("undo"));
+        let file = temp_dir.path().join("file.txt");
+        fs::write(&file, "ab").unwrap();
+        (temp_dir, store, file)
+    }
+
+    #[test]
+    fn test_history_round_trip() {
+        let (_temp_dir, store, file) = setup();
+        let mut log = EventLog::new();
+        log.append(insert(0, "a"));
+        log.append(insert(1, "b"));
+        log.mark_saved();
+        store.save(&file, b"ab", &log).unwrap();
+
+        let mut restored = EventLog::new();
+        assert!(store.load(&file, b"ab", &mut restored).unwrap());
+        assert!(restored.is_at_saved_position());
+        assert_eq!(restored.current_index(), 2);
+        assert!(matches!(
+            restored.undo().as_slice(),
+            [Event::Delete { range, .. }] if *range == (1..2)
+        ));
+    }
+
+    #[test]
+    fn test_stale_history_is_ignored() {
+        let (_temp_dir, store, file) = setup();
+        let mut log = EventLog::new();
+        log.append(insert(0, "ab"));
+        log.mark_saved();
+        store.save(&file, b"ab", &log).unwrap();
+
+        let mut restored = EventLog::new();
+        assert!(!store.load(&file, b"changed", &mut restored).unwrap());
+        assert!(!restored.can_undo());
+    }
+
+    #[test]
+    fn test_history_is_cut_at_bulk_edits() {
+        let (_temp_dir, store, file) = setup();
+        let mut log = EventLog::new();
+        log.append(insert(0, "a"));
+        log.append(Event::BulkEdit {
+            old_tree: None,
+            new_tree: None,
+            old_cursors: Vec::new(),
+            new_cursors: Vec::new(),
+            edits: Vec::new(),
+            description: "Toggle comment".to_string(),
+        });
+        log.append(insert(1, "b"));
+        log.mark_saved();
+        store.save(&file, b"ab", &log).unwrap();
+
+        let mut restored = EventLog::new();
+        assert!(store.load(&file, b"ab", &mut restored).unwrap());
+        assert_eq!(restored.undo().len(), 1);
+        assert!(!restored.can_undo());
+    }
+}
@@ -6,6 +6,7 @@
 //! - File explorer state
 //! - Search/replace history and options
 //! - Bookmarks
+//! - The last recorded macro
 //!
 //! ## Storage
 //!
@@ -30,6 +31,7 @@ use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::input::input_history::get_data_dir;
+use crate::input::keybindings::Action;
 
 /// Current session file format version
 pub const SESSION_VERSION: u32 = 1;
@@ -83,6 +85,10 @@ pub struct Session {
     #[serde(default)]
     pub external_files: Vec<PathBuf>,
 
+    /// Last recorded macro, so it can be replayed after a restart
+    #[serde(default)]
+    pub last_macro: Option<SerializedMacro>,
+
     /// Timestamp when session was saved (Unix epoch seconds)
     pub saved_at: u64,
 }
@@ -284,6 +290,15 @@ pub struct SerializedBookmark {
     pub position: usize,
 }
 
+/// Serialized macro (register + recorded actions)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SerializedMacro {
+    /// Register the macro was recorded into
+    pub register: char,
+    /// Recorded actions, in playback order
+    pub actions: Vec<Action>,
+}
+
 /// Reference to an open tab (file path or terminal index)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum SerializedTabRef {
@@ -727,6 +742,7 @@ impl Session {
             bookmarks: HashMap::new(),
             terminals: Vec::new(),
             external_files: Vec::new(),
+            last_macro: None,
             saved_at: SystemTime::now()
                 .duration_since(UNIX_EPOCH)
                 .unwrap_or_default()
@@ -988,6 +1004,12 @@ mod tests {
             },
         );
 
+        // Add the last recorded macro
+        session.last_macro = Some(SerializedMacro {
+            register: '3',
+            actions: vec![Action::InsertChar('x'), Action::MoveDown],
+        });
+
         // Set search options
         session.search_options.case_sensitive = true;
         session.search_options.use_regex = true;
@@ -1003,6 +1025,12 @@ mod tests {
         assert!(restored.bookmarks.contains_key(&'m'));
         assert!(restored.search_options.case_sensitive);
         assert!(restored.search_options.use_regex);
+        let last_macro = restored.last_macro.unwrap();
+        assert_eq!(last_macro.register, '3');
+        assert_eq!(
+            last_macro.actions,
+            vec![Action::InsertChar('x'), Action::MoveDown]
+        );
 
         // Verify split state
         let split_state = restored.split_states.get(&1).unwrap();
@@ -14,6 +14,7 @@ use crate::primitives::highlighter::Language;
 use crate::primitives::indent::IndentCalculator;
 use crate::primitives::reference_highlighter::ReferenceHighlighter;
 use crate::primitives::text_property::TextPropertyManager;
+use crate::primitives::text_stats::{TextStats, TextStatsCache};
 use crate::view::margin::{MarginAnnotation, MarginContent, MarginManager, MarginPosition};
 use crate::view::overlay::{Overlay, OverlayFace, OverlayManager, UnderlineStyle};
 use crate::view::popup::{Popup, PopupContent, PopupListItem, PopupManager, PopupPosition};
@@ -33,6 +34,15 @@ pub enum ViewMode {
     Compose,
 }
 
+/// Buffer settings set for one buffer (from `.editorconfig`, the file's
+/// detected indentation or by hand) rather than taken from the config
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BufferSettingOverrides {
+    pub tab_size: bool,
+    pub use_tabs: bool,
+    pub show_whitespace_tabs: bool,
+}
+
 /// The complete editor state - everything needed to represent the current editing session
 ///
 /// NOTE: Viewport is NOT stored here - it lives in SplitViewState.
@@ -70,6 +80,9 @@ pub struct EditorState {
     /// Maintained incrementally to avoid O(n) scanning on every render
     pub primary_cursor_line_number: LineNumber,
 
+    /// Cached word count of the buffer or selection, see `text_stats`
+    pub text_stats_cache: Option<TextStatsCache>,
+
     /// Current mode (for modal editing, if implemented)
     pub mode: String,
 
@@ -95,6 +108,10 @@ pub struct EditorState {
     /// Set based on language config; defaults to true
     pub show_whitespace_tabs: bool,
 
+    /// This buffer's own line wrap setting, from a per-language default or a
+    /// toggle; None follows the global `editor.line_wrap`
+    pub line_wrap: Option<bool>,
+
     /// Whether pressing Tab should insert a tab character instead of spaces.
     /// Set based on language config; defaults to false (insert spaces).
     pub use_tabs: bool,
@@ -103,6 +120,26 @@ pub struct EditorState {
     /// Used for visual display of tab characters and indent calculations.
     pub tab_size: usize,
 
+    /// Buffer settings that a config reload leaves alone
+    pub setting_overrides: BufferSettingOverrides,
+
+    /// Indentation width detected from the file content when it was opened.
+    /// Cleared when the tab size is changed by hand.
+    pub detected_indent_width: Option<usize>,
+
+    /// Whether the indentation (tab size or tabs vs spaces) was set from
+    /// `.editorconfig` when the file was opened.
+    /// Cleared when the tab size is changed by hand.
+    pub editorconfig_indent: bool,
+
+    /// Per-buffer override of `editor.insert_final_newline`, set from
+    /// `.editorconfig` when the file is opened
+    pub insert_final_newline: Option<bool>,
+
+    /// Per-buffer override of `editor.trim_trailing_whitespace`, set from
+    /// `.editorconfig` when the file is opened
+    pub trim_trailing_whitespace: Option<bool>,
+
     /// Semantic highlighter for word occurrence highlighting
     pub reference_highlighter: ReferenceHighlighter,
 
@@ -152,14 +189,21 @@ impl EditorState {
             popups: PopupManager::new(),
             margins: MarginManager::new(),
             primary_cursor_line_number: LineNumber::Absolute(0), // Start at line 0
+            text_stats_cache: None,
             mode: "insert".to_string(),
             text_properties: TextPropertyManager::new(),
             show_cursors: true,
             editing_disabled: false,
             is_composite_buffer: false,
             show_whitespace_tabs: true,
+            line_wrap: None,
             use_tabs: false,
             tab_size: 4, // Default tab size
+            setting_overrides: BufferSettingOverrides::default(),
+            detected_indent_width: None,
+            editorconfig_indent: false,
+            insert_final_newline: None,
+            trim_trailing_whitespace: None,
             reference_highlighter: ReferenceHighlighter::new(),
             view_mode: ViewMode::Source,
             debug_highlight_mode: false,
@@ -256,14 +300,21 @@ impl EditorState {
             popups: PopupManager::new(),
             margins: MarginManager::new(),
             primary_cursor_line_number: LineNumber::Absolute(0), // Start at line 0
+            text_stats_cache: None,
             mode: "insert".to_string(),
             text_properties: TextPropertyManager::new(),
             show_cursors: true,
             editing_disabled: false,
             is_composite_buffer: false,
             show_whitespace_tabs: true,
+            line_wrap: None,
             use_tabs: false,
             tab_size: 4, // Default tab size
+            setting_overrides: BufferSettingOverrides::default(),
+            detected_indent_width: None,
+            editorconfig_indent: false,
+            insert_final_newline: None,
+            trim_trailing_whitespace: None,
             reference_highlighter,
             view_mode: ViewMode::Source,
             debug_highlight_mode: false,
@@ -336,14 +387,21 @@ impl EditorState {
             popups: PopupManager::new(),
             margins: MarginManager::new(),
             primary_cursor_line_number: LineNumber::Absolute(0), // Start at line 0
+            text_stats_cache: None,
             mode: "insert".to_string(),
             text_properties: TextPropertyManager::new(),
             show_cursors: true,
             editing_disabled: false,
             is_composite_buffer: false,
             show_whitespace_tabs: true,
+            line_wrap: None,
             use_tabs: false,
             tab_size: 4, // Default tab size
+            setting_overrides: BufferSettingOverrides::default(),
+            detected_indent_width: None,
+            editorconfig_indent: false,
+            insert_final_newline: None,
+            trim_trailing_whitespace: None,
             reference_highlighter,
             view_mode: ViewMode::Source,
             debug_highlight_mode: false,
@@ -931,6 +989,32 @@ impl EditorState {
         }
     }
 
+    /// Line, word and character counts of the primary selection, or of the
+    /// whole buffer when nothing is selected
+    ///
+    /// The counts are cached until the buffer or the counted range changes, so
+    /// calling this on every render only counts once per edit.
+    pub fn text_stats(&mut self) -> TextStats {
+        let range = self
+            .cursors
+            .primary()
+            .selection_range()
+            .unwrap_or(0..self.buffer.len());
+        let version = self.buffer.version();
+        if let Some(cache) = &self.text_stats_cache {
+            if cache.version == version && cache.range == range {
+                return cache.stats;
+            }
+        }
+        let stats = TextStats::count(&self.get_text_range(range.start, range.end));
+        self.text_stats_cache = Some(TextStatsCache {
+            version,
+            range,
+            stats,
+        });
+        stats
+    }
+
     /// Get the content of a line by its byte offset
     ///
     /// Returns the line containing the given offset, along with its start position.
@@ -11,6 +11,10 @@ use serde::{Deserialize, Serialize};
 pub mod context_keys {
     pub const LINE_NUMBERS: &str = "line_numbers";
     pub const LINE_WRAP: &str = "line_wrap";
+    pub const INDENT_GUIDES: &str = "indent_guides";
+    pub const RENDER_WHITESPACE: &str = "render_whitespace";
+    pub const RENDER_TRAILING_WHITESPACE: &str = "render_trailing_whitespace";
+    pub const STICKY_SCROLL: &str = "sticky_scroll";
     pub const COMPOSE_MODE: &str = "compose_mode";
     pub const FILE_EXPLORER: &str = "file_explorer";
     pub const MENU_BAR: &str = "menu_bar";
@@ -0,0 +1,212 @@
+//! Git working-tree status for file explorer entries
+//!
+//! The status is read once per explorer load or refresh from
+//! `git status --porcelain -z --ignored` and kept as a map keyed by absolute
+//! path. Outside a git repository (or without git installed) the map is empty
+//! and the explorer shows no annotations.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Status of a single path, in increasing order of precedence
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum GitFileStatus {
+    /// Excluded by `.gitignore`
+    Ignored,
+    /// Not tracked by git
+    Untracked,
+    /// Newly staged, or the target of a rename or copy
+    Added,
+    /// Changed (or deleted) relative to the index or HEAD
+    Modified,
+    /// Unmerged
+    Conflicted,
+}
+
+impl GitFileStatus {
+    /// Badge shown next to the entry, `None` for statuses shown by color only
+    pub fn symbol(self) -> Option<&'static str> {
+        match self {
+            Self::Ignored => None,
+            Self::Untracked => Some("U"),
+            Self::Added => Some("A"),
+            Self::Modified => Some("M"),
+            Self::Conflicted => Some("!"),
+        }
+    }
+
+    /// Parse the two-letter `XY` code of a porcelain entry
+    fn from_code(x: char, y: char) -> Option<Self> {
+        match (x, y) {
+            ('!', '!') => Some(Self::Ignored),
+            ('?', '?') => Some(Self::Untracked),
+            ('U', _) | (_, 'U') | ('A', 'A') | ('D', 'D') => Some(Self::Conflicted),
+            ('A' | 'R' | 'C', _) => Some(Self::Added),
+            (' ', ' ') => None,
+            _ => Some(Self::Modified),
+        }
+    }
+}
+
+/// Git status of the paths under the explorer root
+#[derive(Debug, Clone, Default)]
+pub struct GitStatusMap {
+    /// Status of each path reported by git
+    entries: HashMap<PathBuf, GitFileStatus>,
+    /// Highest status among the changed descendants of each directory
+    directories: HashMap<PathBuf, GitFileStatus>,
+}
+
+impl GitStatusMap {
+    /// Read the status of the repository containing `root`
+    ///
+    /// Returns an empty map when `root` is not inside a git work tree or git
+    /// cannot be run.
+    pub fn load(root: &Path) -> Self {
+        let git = |args: &[&str]| {
+            Command::new("git")
+                .arg("-C")
+                .arg(root)
+                .args(args)
+                .output()
+                .ok()
+                .filter(|output| output.status.success())
+                .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+        };
+
+        // Porcelain paths are relative to the repository top level; the prefix
+        // locates `root` within it
+        let Some(prefix) = git(&["rev-parse", "--show-prefix"]) else {
+            return Self::default();
+        };
+        let Some(output) = git(&["status", "--porcelain", "-z", "--ignored"]) else {
+            return Self::default();
+        };
+        Self::parse(&output, prefix.trim_end_matches(['\r', '\n']), root)
+    }
+
+    /// Build the map from `git status --porcelain -z` output
+    ///
+    /// `prefix` is the path of `root` relative to the repository top level
+    /// (empty, or ending in `/`); entries outside it are dropped.
+    pub fn parse(output: &str, prefix: &str, root: &Path) -> Self {
+        let mut map = Self::default();
+        let mut records = output.split('\0').filter(|record| !record.is_empty());
+        while let Some(record) = records.next() {
+            let mut chars = record.chars();
+            let (Some(x), Some(y)) = (chars.next(), chars.next()) else {
+                continue;
+            };
+            // A rename or copy is followed by a record holding the original path
+            if matches!(x, 'R' | 'C') || matches!(y, 'R' | 'C') {
+                records.next();
+            }
+            let Some(status) = GitFileStatus::from_code(x, y) else {
+                continue;
+            };
+            let Some(relative) = record.get(3..).and_then(|path| path.strip_prefix(prefix)) else {
+                continue;
+            };
+            let relative = relative.trim_end_matches('/');
+            if relative.is_empty() {
+                continue;
+            }
+            map.insert(root.join(relative), status, root);
+        }
+        map
+    }
+
+    fn insert(&mut self, path: PathBuf, status: GitFileStatus, root: &Path) {
+        if status != GitFileStatus::Ignored {
+            for ancestor in path.ancestors().skip(1) {
+                if !ancestor.starts_with(root) {
+                    break;
+                }
+                let entry = self
+                    .directories
+                    .entry(ancestor.to_path_buf())
+                    .or_insert(status);
+                *entry = (*entry).max(status);
+            }
+        }
+        let entry = self.entries.entry(path).or_insert(status);
+        *entry = (*entry).max(status);
+    }
+
+    /// Whether the map holds no status at all
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Status of a file or directory
+    ///
+    /// Git reports untracked and ignored directories as a single entry, so
+    /// their descendants inherit that status. A directory otherwise takes the
+    /// highest status among its changed descendants.
+    pub fn status(&self, path: &Path) -> Option<GitFileStatus> {
+        if let Some(status) = self.entries.get(path) {
+            return Some(*status);
+        }
+        if let Some(status) = self.directories.get(path) {
+            return Some(*status);
+        }
+        path.ancestors().skip(1).find_map(|ancestor| {
+            self.entries.get(ancestor).copied().filter(|status| {
+                matches!(status, GitFileStatus::Ignored | GitFileStatus::Untracked)
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_porcelain_statuses() {
+        let root = Path::new("/repo/sub");
+        let output = concat!(
+            " M sub/changed.rs\0",
+            "A  sub/nested/new.rs\0",
+            "R  sub/renamed.rs\0sub/old.rs\0",
+            "?? sub/scratch/\0",
+            "!! sub/target/\0",
+            "UU sub/conflict.rs\0",
+            " M outside.rs\0",
+        );
+        let map = GitStatusMap::parse(output, "sub/", root);
+        let status = |path: &str| map.status(&root.join(path));
+
+        assert_eq!(status("changed.rs"), Some(GitFileStatus::Modified));
+        assert_eq!(status("nested/new.rs"), Some(GitFileStatus::Added));
+        assert_eq!(status("renamed.rs"), Some(GitFileStatus::Added));
+        assert_eq!(status("old.rs"), None);
+        assert_eq!(status("conflict.rs"), Some(GitFileStatus::Conflicted));
+        assert_eq!(status("clean.rs"), None);
+        // Collapsed untracked and ignored directories cover their contents
+        assert_eq!(status("scratch/notes.txt"), Some(GitFileStatus::Untracked));
+        assert_eq!(status("target/debug/app"), Some(GitFileStatus::Ignored));
+        // Directories take the highest status of their changed descendants
+        assert_eq!(status("nested"), Some(GitFileStatus::Added));
+        assert_eq!(map.status(root), Some(GitFileStatus::Conflicted));
+        // Entries outside the explorer root are dropped
+        assert!(map.entries.keys().all(|path| path.starts_with(root)));
+        assert_eq!(map.entries.len(), 6);
+    }
+
+    #[test]
+    fn test_load_outside_repository_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        // Guard against the temp dir itself living inside a work tree
+        let inside_repo = Command::new("git")
+            .arg("-C")
+            .arg(dir.path())
+            .args(["rev-parse", "--is-inside-work-tree"])
+            .output()
+            .is_ok_and(|output| output.status.success());
+        if !inside_repo {
+            assert!(GitStatusMap::load(dir.path()).is_empty());
+        }
+    }
+}
@@ -5,12 +5,14 @@
 // navigation.
 
 pub mod decorations;
+pub mod git_status;
 pub mod ignore;
 pub mod node;
 pub mod tree;
 pub mod view;
 
 pub use decorations::{FileExplorerDecoration, FileExplorerDecorationCache};
+pub use git_status::{GitFileStatus, GitStatusMap};
 pub use ignore::{IgnorePatterns, IgnoreStatus};
 pub use node::{NodeId, NodeState, TreeNode};
 pub use tree::FileTree;
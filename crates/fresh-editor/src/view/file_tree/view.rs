@@ -1,7 +1,10 @@
+use super::git_status::GitStatusMap;
 use super::ignore::IgnorePatterns;
 use super::node::NodeId;
 use super::tree::FileTree;
+use crate::input::fuzzy::fuzzy_match;
 use crate::services::fs::FsEntry;
+use std::collections::HashSet;
 
 /// View state for file tree navigation and filtering
 #[derive(Debug)]
@@ -16,6 +19,10 @@ pub struct FileTreeView {
     sort_mode: SortMode,
     /// Ignore patterns for filtering
     ignore_patterns: IgnorePatterns,
+    /// Git status of the entries, reloaded on each refresh
+    git_status: GitStatusMap,
+    /// Fuzzy filter on entry names; non-matching entries are hidden
+    filter: Option<String>,
     /// Last known viewport height (for scrolling calculations)
     pub(crate) viewport_height: usize,
 }
@@ -41,6 +48,8 @@ impl FileTreeView {
             scroll_offset: 0,
             sort_mode: SortMode::Type,
             ignore_patterns: IgnorePatterns::new(),
+            git_status: GitStatusMap::default(),
+            filter: None,
             viewport_height: 10, // Default, will be updated during rendering
         }
     }
@@ -60,11 +69,86 @@ impl FileTreeView {
         &mut self.tree
     }
 
+    /// Get the git status of the entries
+    pub fn git_status(&self) -> &GitStatusMap {
+        &self.git_status
+    }
+
+    /// Replace the git status of the entries
+    pub fn set_git_status(&mut self, git_status: GitStatusMap) {
+        self.git_status = git_status;
+    }
+
+    /// Get the active name filter
+    pub fn filter(&self) -> Option<&str> {
+        self.filter.as_deref()
+    }
+
+    /// Filter the expanded entries by fuzzy-matching their names
+    ///
+    /// Matching entries stay visible together with their ancestor
+    /// directories. An empty filter restores the full tree. If the selection
+    /// is hidden by the filter, the first matching entry is selected.
+    pub fn set_filter(&mut self, filter: &str) {
+        self.filter = (!filter.is_empty()).then(|| filter.to_string());
+
+        let visible = self.visible_nodes();
+        if !self
+            .selected_node
+            .is_some_and(|selected| visible.contains(&selected))
+        {
+            self.selected_node = visible
+                .iter()
+                .copied()
+                .find(|&id| self.name_matches_filter(id))
+                .or_else(|| visible.first().copied());
+        }
+        self.scroll_offset = 0;
+        self.update_scroll_for_selection();
+    }
+
+    /// Remove the name filter, restoring the full tree
+    pub fn clear_filter(&mut self) {
+        self.set_filter("");
+    }
+
+    /// Whether a node's name matches the active filter
+    fn name_matches_filter(&self, node_id: NodeId) -> bool {
+        match (&self.filter, self.tree.get_node(node_id)) {
+            (Some(filter), Some(node)) => fuzzy_match(filter, &node.entry.name).matched,
+            _ => false,
+        }
+    }
+
+    /// Visible nodes in tree order, after applying the name filter
+    pub fn visible_nodes(&self) -> Vec<NodeId> {
+        let visible = self.tree.get_visible_nodes();
+        if self.filter.is_none() {
+            return visible;
+        }
+
+        // Keep every match along with the directories leading to it
+        let mut kept = HashSet::from([self.tree.root_id()]);
+        for &id in &visible {
+            if !self.name_matches_filter(id) {
+                continue;
+            }
+            let mut current = Some(id);
+            while let Some(node_id) = current {
+                if !kept.insert(node_id) {
+                    break;
+                }
+                current = self.tree.get_node(node_id).and_then(|node| node.parent);
+            }
+        }
+        visible.into_iter().filter(|id| kept.contains(id)).collect()
+    }
+
     /// Get currently visible nodes with their indent levels
     ///
     /// Returns a list of (NodeId, indent_level) tuples for rendering.
     pub fn get_display_nodes(&self) -> Vec<(NodeId, usize)> {
-        let visible = self.tree.get_visible_nodes();
+        let visible = self.visible_nodes();
         visible
             .into_iter()
             .map(|id| {
@@ -86,7 +170,7 @@ impl FileTreeView {
 
     /// Select the next visible node
     pub fn select_next(&mut self) {
-        let visible = self.tree.get_visible_nodes();
+        let visible = self.visible_nodes();
         if visible.is_empty() {
             return;
         }
@@ -104,7 +188,7 @@ impl FileTreeView {
 
     /// Select the previous visible node
     pub fn select_prev(&mut self) {
-        let visible = self.tree.get_visible_nodes();
+        let visible = self.visible_nodes();
         if visible.is_empty() {
             return;
         }
@@ -126,7 +210,7 @@ impl FileTreeView {
             return;
         }
 
-        let visible = self.tree.get_visible_nodes();
+        let visible = self.visible_nodes();
         if visible.is_empty() {
             return;
         }
@@ -147,7 +231,7 @@ impl FileTreeView {
             return;
         }
 
-        let visible = self.tree.get_visible_nodes();
+        let visible = self.visible_nodes();
         if visible.is_empty() {
             return;
         }
@@ -175,7 +259,7 @@ impl FileTreeView {
         }
 
         if let Some(selected) = self.selected_node {
-            let visible = self.tree.get_visible_nodes();
+            let visible = self.visible_nodes();
             if let Some(pos) = visible.iter().position(|&id| id == selected) {
                 // Only scroll if cursor goes PAST the viewport edges
                 // This implements symmetric scrolling behavior
@@ -195,7 +279,7 @@ impl FileTreeView {
 
     /// Select the first visible node
     pub fn select_first(&mut self) {
-        let visible = self.tree.get_visible_nodes();
+        let visible = self.visible_nodes();
         if !visible.is_empty() {
             self.selected_node = Some(visible[0]);
         }
@@ -203,7 +287,7 @@ impl FileTreeView {
 
     /// Select the last visible node
     pub fn select_last(&mut self) {
-        let visible = self.tree.get_visible_nodes();
+        let visible = self.visible_nodes();
         if !visible.is_empty() {
             self.selected_node = Some(*visible.last().unwrap());
         }
@@ -243,7 +327,7 @@ impl FileTreeView {
         }
 
         if let Some(selected) = self.selected_node {
-            let visible = self.tree.get_visible_nodes();
+            let visible = self.visible_nodes();
             if let Some(pos) = visible.iter().position(|&id| id == selected) {
                 // If selection is above viewport, scroll up
                 if pos < self.scroll_offset {
@@ -285,7 +369,7 @@ impl FileTreeView {
     /// Get the index of the selected node in the visible list
     pub fn get_selected_index(&self) -> Option<usize> {
         if let Some(selected) = self.selected_node {
-            let visible = self.tree.get_visible_nodes();
+            let visible = self.visible_nodes();
             visible.iter().position(|&id| id == selected)
         } else {
             None
@@ -294,13 +378,13 @@ impl FileTreeView {
 
     /// Get visible node at index (accounting for scroll offset)
     pub fn get_node_at_index(&self, index: usize) -> Option<NodeId> {
-        let visible = self.tree.get_visible_nodes();
+        let visible = self.visible_nodes();
         visible.get(index).copied()
     }
 
     /// Get the number of visible nodes
     pub fn visible_count(&self) -> usize {
-        self.tree.get_visible_nodes().len()
+        self.visible_nodes().len()
     }
 
     /// Get reference to ignore patterns
@@ -474,6 +558,43 @@ mod tests {
         assert_eq!(view.get_selected(), Some(root_id));
     }
 
+    #[tokio::test]
+    async fn test_filter_keeps_ancestors_of_matches() {
+        let (_temp_dir, mut view) = create_test_view().await;
+
+        let root_id = view.tree().root_id();
+        view.tree_mut().expand_node(root_id).await.unwrap();
+        let dir1_id = view.tree().get_node(root_id).unwrap().children[0];
+        view.tree_mut().expand_node(dir1_id).await.unwrap();
+        let names = |view: &FileTreeView| {
+            view.visible_nodes()
+                .into_iter()
+                .skip(1)
+                .map(|id| view.tree().get_node(id).unwrap().entry.name.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            names(&view),
+            vec!["dir1", "file1.txt", "file2.txt", "dir2", "file3.txt"]
+        );
+
+        view.set_filter("fle1");
+        assert_eq!(names(&view), vec!["dir1", "file1.txt"]);
+        // The selection moves to the first match once the old one is hidden
+        view.set_selected(Some(root_id));
+        view.select_next();
+        view.set_filter("file3");
+        assert_eq!(names(&view), vec!["file3.txt"]);
+        assert_eq!(
+            view.get_selected_entry().map(|entry| entry.name.as_str()),
+            Some("file3.txt")
+        );
+
+        view.clear_filter();
+        assert_eq!(view.filter(), None);
+        assert_eq!(names(&view).len(), 5);
+    }
+
     #[tokio::test]
     async fn test_ensure_visible() {
         let (_temp_dir, mut view) = create_test_view().await;
@@ -27,6 +27,8 @@ pub enum PromptType {
     QueryReplace { search: String },
     /// Query replace confirmation prompt (y/n/!/q for each match)
     QueryReplaceConfirm,
+    /// Search all files under the working directory
+    ProjectSearch,
     /// Execute a command by name (M-x)
     Command,
     /// Go to a specific line number
@@ -50,16 +52,22 @@ pub enum PromptType {
     RecordMacro,
     /// Play a macro - prompts for register (0-9)
     PlayMacro,
+    /// Play the last recorded macro - prompts for a repeat count
+    PlayLastMacroTimes,
     /// Set a bookmark - prompts for register (0-9)
     SetBookmark,
     /// Jump to a bookmark - prompts for register (0-9)
     JumpToBookmark,
+    /// Select the register for the next copy, cut or paste (a-z, 0-9)
+    SelectRegister,
     /// Set compose width (empty clears to viewport)
     SetComposeWidth,
     /// Set tab size for current buffer
     SetTabSize,
     /// Set line ending format for current buffer
     SetLineEnding,
+    /// Align the selected lines on a delimiter
+    AlignOnDelimiter,
     /// Stop a running LSP server (select from list)
     StopLspServer,
     /// Select a theme (select from list)
@@ -99,6 +107,8 @@ pub enum PromptType {
         /// False if renaming an existing file (should keep focus in file explorer)
         is_new_file: bool,
     },
+    /// Filter the file explorer by entry name, updated as the user types
+    FileExplorerFilter,
     /// Confirm deleting a file or directory in the file explorer
     ConfirmDeleteFile {
         path: std::path::PathBuf,
@@ -106,6 +116,8 @@ pub enum PromptType {
     },
     /// Switch to a tab by name (from the current split's open buffers)
     SwitchToTab,
+    /// Browse the keybindings active in the context the prompt was opened from
+    ShowKeybindings,
     /// Run shell command on buffer/selection
     /// If replace is true, replace the input with the output
     /// If replace is false, output goes to a new buffer
@@ -178,6 +178,19 @@ impl ReferenceHighlightOverlay {
         })
     }
 
+    /// Enable or disable highlighting, clearing any highlights when disabled
+    pub fn set_enabled(
+        &mut self,
+        enabled: bool,
+        overlays: &mut OverlayManager,
+        marker_list: &mut MarkerList,
+    ) {
+        if self.enabled && !enabled {
+            self.clear(overlays, marker_list);
+        }
+        self.enabled = enabled;
+    }
+
     /// Force clear all highlights (e.g., when switching buffers)
     pub fn clear(&mut self, overlays: &mut OverlayManager, marker_list: &mut MarkerList) {
         let ns = reference_highlight_namespace();
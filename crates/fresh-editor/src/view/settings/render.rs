@@ -618,6 +618,13 @@ fn render_settings_panel(
     }
 }
 
+/// Leading `max_chars` characters of `text`, cut on a char boundary
+fn truncate_chars(text: &str, max_chars: usize) -> &str {
+    text.char_indices()
+        .nth(max_chars)
+        .map_or(text, |(idx, _)| &text[..idx])
+}
+
 /// Wrap text to fit within a given width
 fn wrap_text(text: &str, width: usize) -> Vec<String> {
     if width == 0 || text.is_empty() {
@@ -781,7 +788,8 @@ fn render_setting_item_pure(
             let desc_style = Style::default().fg(theme.line_number_fg);
             let max_width = desc_width.saturating_sub(2) as usize;
 
-            if is_focused_or_hovered && description.len() > max_width {
+            let description_len = description.chars().count();
+            if is_focused_or_hovered && description_len > max_width {
                 // Wrap description to multiple lines when focused/hovered
                 let wrapped_lines = wrap_text(description, max_width);
                 let available_rows = area.height.saturating_sub(desc_start_row) as usize;
@@ -794,10 +802,10 @@ fn render_setting_item_pure(
                 }
             } else {
                 // Single line with optional layer indicator
-                let mut display_desc = if description.len() > max_width.saturating_sub(12) {
+                let mut display_desc = if description_len > max_width.saturating_sub(12) {
                     format!(
                         "{}...",
-                        &description[..max_width.saturating_sub(15).max(10)]
+                        truncate_chars(description, max_width.saturating_sub(15).max(10))
                     )
                 } else {
                     description.clone()
@@ -2238,8 +2246,8 @@ fn render_search_result_item(
     // Third line: Description (if any)
     if let Some(ref desc) = result.item.description {
         let desc_style = Style::default().fg(theme.line_number_fg);
-        let truncated_desc = if desc.len() > area.width as usize - 2 {
-            format!("  {}...", &desc[..area.width as usize - 5])
+        let truncated_desc = if desc.chars().count() > area.width as usize - 2 {
+            format!("  {}...", truncate_chars(desc, area.width as usize - 5))
         } else {
             format!("  {}", desc)
         };
@@ -2838,4 +2846,10 @@ mod tests {
         };
         assert!(matches!(number, ControlLayoutInfo::Number { .. }));
     }
+
+    #[test]
+    fn test_truncate_chars_keeps_char_boundaries() {
+        assert_eq!(truncate_chars("as `·` and", 5), "as `·");
+        assert_eq!(truncate_chars("short", 10), "short");
+    }
 }
@@ -25,12 +25,18 @@
 /// ```
 use crate::model::cursor::Cursors;
 use crate::model::event::{BufferId, SplitDirection, SplitId};
-use crate::view::ui::view_pipeline::Layout;
+use crate::view::ui::view_pipeline::{Layout, ViewLineCache};
 use crate::view::viewport::Viewport;
 use crate::{services::plugins::api::ViewTransformPayload, state::ViewMode};
 use ratatui::layout::Rect;
 use serde::{Deserialize, Serialize};
 
+/// Smallest width, in columns, a split can be resized to
+pub const MIN_SPLIT_WIDTH: u16 = 10;
+
+/// Smallest height, in rows, a split can be resized to
+pub const MIN_SPLIT_HEIGHT: u16 = 3;
+
 /// A node in the split tree
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum SplitNode {
@@ -103,6 +109,9 @@ pub struct SplitViewState {
     /// Whether the layout needs to be rebuilt (buffer changed, transform changed, etc.)
     pub layout_dirty: bool,
 
+    /// Source lines last rendered in this split, reused after small edits
+    pub view_line_cache: Option<ViewLineCache>,
+
     /// Previously active buffer in this split (for "Switch to Previous Tab" command)
     pub previous_buffer: Option<BufferId>,
 
@@ -132,6 +141,7 @@ impl SplitViewState {
             view_transform: None,
             layout: None,
             layout_dirty: true, // Start dirty so first operation builds layout
+            view_line_cache: None,
             previous_buffer: None,
             sync_group: None,
             composite_view: None,
@@ -152,6 +162,7 @@ impl SplitViewState {
             view_transform: None,
             layout: None,
             layout_dirty: true, // Start dirty so first operation builds layout
+            view_line_cache: None,
             previous_buffer: None,
             sync_group: None,
             composite_view: None,
@@ -396,6 +407,52 @@ impl SplitNode {
             Self::Split { first, second, .. } => first.count_leaves() + second.count_leaves(),
         }
     }
+
+    /// Ratios of all split containers in the tree
+    pub fn container_ratios(&self) -> Vec<(SplitId, f32)> {
+        match self {
+            Self::Leaf { .. } => vec![],
+            Self::Split {
+                first,
+                second,
+                ratio,
+                split_id,
+                ..
+            } => {
+                let mut ratios = vec![(*split_id, *ratio)];
+                ratios.extend(first.container_ratios());
+                ratios.extend(second.container_ratios());
+                ratios
+            }
+        }
+    }
+
+    /// Find the container holding `target_id`, laid out in `rect`
+    /// Returns (container id, direction, container rect, whether the target is the first child)
+    pub fn find_parent_with_rect(
+        &self,
+        target_id: SplitId,
+        rect: Rect,
+    ) -> Option<(SplitId, SplitDirection, Rect, bool)> {
+        match self {
+            Self::Leaf { .. } => None,
+            Self::Split {
+                direction,
+                first,
+                second,
+                ratio,
+                split_id,
+            } => {
+                if first.id() == target_id || second.id() == target_id {
+                    return Some((*split_id, *direction, rect, first.id() == target_id));
+                }
+                let (first_rect, second_rect) = split_rect(rect, *direction, *ratio);
+                first
+                    .find_parent_with_rect(target_id, first_rect)
+                    .or_else(|| second.find_parent_with_rect(target_id, second_rect))
+            }
+        }
+    }
 }
 
 /// Split a rectangle into two parts based on direction and ratio
@@ -463,6 +520,9 @@ pub struct SplitManager {
 
     /// Currently maximized split (if any). When set, only this split is visible.
     maximized_split: Option<SplitId>,
+
+    /// Container ratios saved when the split was maximized, put back on restore
+    saved_ratios: Vec<(SplitId, f32)>,
 }
 
 impl SplitManager {
@@ -474,6 +534,7 @@ impl SplitManager {
             active_split: split_id,
             next_split_id: 1,
             maximized_split: None,
+            saved_ratios: Vec::new(),
         }
     }
 
@@ -491,6 +552,10 @@ impl SplitManager {
     pub fn set_active_split(&mut self, split_id: SplitId) -> bool {
         // Verify the split exists
         if self.root.find(split_id).is_some() {
+            // Focusing a hidden split brings the other splits back
+            if self.maximized_split.is_some_and(|id| id != split_id) {
+                self.restore_layout();
+            }
             self.active_split = split_id;
             true
         } else {
@@ -551,6 +616,7 @@ impl SplitManager {
         new_buffer_id: BufferId,
         ratio: f32,
     ) -> Result<SplitId, String> {
+        self.restore_layout();
         let active_id = self.active_split;
 
         // Find the parent of the active split
@@ -626,7 +692,7 @@ impl SplitManager {
 
         // If the split being closed is maximized, unmaximize first
         if self.maximized_split == Some(split_id) {
-            self.maximized_split = None;
+            self.restore_layout();
         }
 
         // Find the parent of the split to close
@@ -697,6 +763,43 @@ impl SplitManager {
         }
     }
 
+    /// Grow (positive delta) or shrink a split along its container's direction
+    ///
+    /// The container's ratio moves by `delta` in favour of `split_id`, limited
+    /// so neither side drops below `MIN_SPLIT_WIDTH` columns or
+    /// `MIN_SPLIT_HEIGHT` rows when laid out in `viewport_rect`.
+    /// Returns the container's new ratio.
+    pub fn resize_split(
+        &mut self,
+        split_id: SplitId,
+        delta: f32,
+        viewport_rect: Rect,
+    ) -> Result<f32, String> {
+        self.restore_layout();
+        let (container_id, direction, rect, is_first) = self
+            .root
+            .find_parent_with_rect(split_id, viewport_rect)
+            .ok_or_else(|| "Split has no container to resize in".to_string())?;
+
+        // Space shared by the two sides, excluding the separator
+        let (total, min_size) = match direction {
+            SplitDirection::Horizontal => (rect.height.saturating_sub(1), MIN_SPLIT_HEIGHT),
+            SplitDirection::Vertical => (rect.width.saturating_sub(1), MIN_SPLIT_WIDTH),
+        };
+        if total < min_size * 2 {
+            return Err("Not enough space to resize split".to_string());
+        }
+        let min_ratio = (min_size as f32 / total as f32).max(0.1);
+        let max_ratio = (1.0 - min_size as f32 / total as f32).min(0.9);
+
+        let delta = if is_first { delta } else { -delta };
+        let Some(SplitNode::Split { ratio, .. }) = self.root.find_mut(container_id) else {
+            return Err("Target is not a split container".to_string());
+        };
+        *ratio = (*ratio + delta).clamp(min_ratio, max_ratio);
+        Ok(*ratio)
+    }
+
     /// Get all visible buffer views with their rectangles
     pub fn get_visible_buffers(&self, viewport_rect: Rect) -> Vec<(SplitId, BufferId, Rect)> {
         // If a split is maximized, only show that split taking up the full viewport
@@ -760,6 +863,7 @@ impl SplitManager {
     /// Distribute all visible splits evenly
     /// This sets the ratios of all container splits so that leaf splits get equal space
     pub fn distribute_splits_evenly(&mut self) {
+        self.restore_layout();
         Self::distribute_node_evenly(&mut self.root);
     }
 
@@ -789,6 +893,7 @@ impl SplitManager {
 
     /// Navigate to the next split (circular)
     pub fn next_split(&mut self) {
+        self.restore_layout();
         let leaf_ids = self.root.leaf_split_ids();
         if let Some(pos) = leaf_ids.iter().position(|id| *id == self.active_split) {
             let next_pos = (pos + 1) % leaf_ids.len();
@@ -798,6 +903,7 @@ impl SplitManager {
 
     /// Navigate to the previous split (circular)
     pub fn prev_split(&mut self) {
+        self.restore_layout();
         let leaf_ids = self.root.leaf_split_ids();
         if let Some(pos) = leaf_ids.iter().position(|id| *id == self.active_split) {
             let prev_pos = if pos == 0 { leaf_ids.len() } else { pos } - 1;
@@ -847,8 +953,9 @@ impl SplitManager {
             return Err("A split is already maximized".to_string());
         }
 
-        // Maximize the active split
+        // Maximize the active split, remembering the layout to restore
         self.maximized_split = Some(self.active_split);
+        self.saved_ratios = self.root.container_ratios();
         Ok(())
     }
 
@@ -859,10 +966,23 @@ impl SplitManager {
             return Err("No split is maximized".to_string());
         }
 
-        self.maximized_split = None;
+        self.restore_layout();
         Ok(())
     }
 
+    /// Leave the maximized state, putting back the ratios saved when the split
+    /// was maximized. Does nothing if no split is maximized.
+    fn restore_layout(&mut self) {
+        if self.maximized_split.take().is_none() {
+            return;
+        }
+        for (split_id, saved) in std::mem::take(&mut self.saved_ratios) {
+            if let Some(SplitNode::Split { ratio, .. }) = self.root.find_mut(split_id) {
+                *ratio = saved;
+            }
+        }
+    }
+
     /// Check if a split is currently maximized
     pub fn is_maximized(&self) -> bool {
         self.maximized_split.is_some()
@@ -990,6 +1110,97 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_resize_split_grows_active_side() {
+        let mut manager = SplitManager::new(BufferId(0));
+        let left = manager.active_split();
+        let right = manager
+            .split_active(SplitDirection::Vertical, BufferId(1), 0.5)
+            .unwrap();
+        let container = manager.root().id();
+        let rect = Rect::new(0, 0, 101, 40);
+
+        // Growing the second (right) split shrinks the first side's ratio
+        let ratio = manager.resize_split(right, 0.1, rect).unwrap();
+        assert!((ratio - 0.4).abs() < 1e-6);
+
+        manager.set_ratio(container, 0.5).unwrap();
+        let ratio = manager.resize_split(left, 0.1, rect).unwrap();
+        assert!((ratio - 0.6).abs() < 1e-6);
+        assert_eq!(manager.get_ratio(container), Some(ratio));
+    }
+
+    #[test]
+    fn test_resize_split_keeps_minimum_size() {
+        let mut manager = SplitManager::new(BufferId(0));
+        let top = manager.active_split();
+        manager
+            .split_active(SplitDirection::Horizontal, BufferId(1), 0.5)
+            .unwrap();
+        let rect = Rect::new(0, 0, 80, 21);
+
+        for _ in 0..20 {
+            manager.resize_split(top, 0.1, rect).unwrap();
+        }
+        let leaves = manager.root().get_leaves_with_rects(rect);
+        assert_eq!(leaves[1].2.height, MIN_SPLIT_HEIGHT);
+
+        for _ in 0..20 {
+            manager.resize_split(top, -0.1, rect).unwrap();
+        }
+        let leaves = manager.root().get_leaves_with_rects(rect);
+        assert_eq!(leaves[0].2.height, MIN_SPLIT_HEIGHT);
+
+        // A lone split has nothing to resize against
+        let mut single = SplitManager::new(BufferId(0));
+        let only = single.active_split();
+        assert!(single.resize_split(only, 0.1, rect).is_err());
+    }
+
+    #[test]
+    fn test_maximize_restores_saved_layout() {
+        let mut manager = SplitManager::new(BufferId(0));
+        manager
+            .split_active(SplitDirection::Vertical, BufferId(1), 0.3)
+            .unwrap();
+        let middle = manager.active_split();
+        manager
+            .split_active(SplitDirection::Horizontal, BufferId(2), 0.7)
+            .unwrap();
+        manager.set_active_split(middle);
+        let rect = Rect::new(0, 0, 120, 40);
+        let before = manager.get_visible_buffers(rect);
+        let ratios = manager.root().container_ratios();
+
+        manager.maximize_split().unwrap();
+        assert_eq!(
+            manager.get_visible_buffers(rect),
+            vec![(middle, BufferId(1), rect)]
+        );
+
+        // Ratios changed behind the maximized split are put back on restore
+        for (split_id, _) in &ratios {
+            manager.set_ratio(*split_id, 0.5).unwrap();
+        }
+        manager.unmaximize_split().unwrap();
+        assert_eq!(manager.root().container_ratios(), ratios);
+        assert_eq!(manager.get_visible_buffers(rect), before);
+        assert_eq!(manager.active_split(), middle);
+    }
+
+    #[test]
+    fn test_navigating_restores_maximized_layout() {
+        let mut manager = SplitManager::new(BufferId(0));
+        manager
+            .split_active(SplitDirection::Vertical, BufferId(1), 0.5)
+            .unwrap();
+        manager.maximize_split().unwrap();
+
+        manager.next_split();
+        assert!(!manager.is_maximized());
+        assert_eq!(manager.root().count_leaves(), 2);
+    }
+
     #[test]
     fn test_split_rect_horizontal() {
         let rect = Rect {
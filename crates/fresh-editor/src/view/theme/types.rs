@@ -217,6 +217,21 @@ pub struct EditorColors {
     /// Diff modified line background
     #[serde(default = "default_diff_modify_bg")]
     pub diff_modify_bg: ColorDef,
+    /// Indent guide line color
+    #[serde(default = "default_indent_guide_fg")]
+    pub indent_guide_fg: ColorDef,
+    /// Indent guide color for the indentation level at the cursor
+    #[serde(default = "default_indent_guide_active_fg")]
+    pub indent_guide_active_fg: ColorDef,
+    /// Color of control characters and invalid bytes shown as `^X` or `<XX>`
+    #[serde(default = "default_control_char_fg")]
+    pub control_char_fg: ColorDef,
+    /// Color of the glyphs drawn for spaces and tabs when whitespace is rendered
+    #[serde(default = "default_whitespace_indicator_fg")]
+    pub whitespace_indicator_fg: ColorDef,
+    /// Color of the vertical column rulers
+    #[serde(default = "default_ruler_fg")]
+    pub ruler_fg: ColorDef,
 }
 
 // Default editor colors (for minimal themes)
@@ -253,6 +268,21 @@ fn default_diff_remove_bg() -> ColorDef {
 fn default_diff_modify_bg() -> ColorDef {
     ColorDef::Rgb(40, 38, 30) // Very subtle yellow tint, close to dark bg
 }
+fn default_indent_guide_fg() -> ColorDef {
+    ColorDef::Rgb(60, 60, 60)
+}
+fn default_indent_guide_active_fg() -> ColorDef {
+    ColorDef::Rgb(110, 110, 110)
+}
+fn default_control_char_fg() -> ColorDef {
+    ColorDef::Rgb(86, 156, 214)
+}
+fn default_whitespace_indicator_fg() -> ColorDef {
+    ColorDef::Rgb(70, 70, 70)
+}
+fn default_ruler_fg() -> ColorDef {
+    ColorDef::Rgb(55, 55, 55)
+}
 
 /// UI element colors (tabs, menus, status bar, etc.)
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -645,6 +675,12 @@ pub struct SearchColors {
     /// Search match text color
     #[serde(default = "default_search_match_fg")]
     pub match_fg: ColorDef,
+    /// Background color of the match the cursor is on
+    #[serde(default = "default_search_current_match_bg")]
+    pub current_match_bg: ColorDef,
+    /// Text color of the match the cursor is on
+    #[serde(default = "default_search_current_match_fg")]
+    pub current_match_fg: ColorDef,
 }
 
 // Default search colors
@@ -654,6 +690,12 @@ fn default_search_match_bg() -> ColorDef {
 fn default_search_match_fg() -> ColorDef {
     ColorDef::Rgb(255, 255, 255)
 }
+fn default_search_current_match_bg() -> ColorDef {
+    ColorDef::Rgb(180, 100, 0)
+}
+fn default_search_current_match_fg() -> ColorDef {
+    ColorDef::Rgb(255, 255, 255)
+}
 
 /// LSP diagnostic colors (errors, warnings, etc.)
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -790,6 +832,19 @@ pub struct Theme {
     /// Brighter background for inline diff highlighting on removed content
     pub diff_remove_highlight_bg: Color,
 
+    // Indent guide colors
+    pub indent_guide_fg: Color,
+    pub indent_guide_active_fg: Color,
+
+    // Control character and invalid byte color
+    pub control_char_fg: Color,
+
+    // Rendered whitespace glyph color
+    pub whitespace_indicator_fg: Color,
+
+    // Column ruler color
+    pub ruler_fg: Color,
+
     // UI element colors
     pub tab_active_fg: Color,
     pub tab_active_bg: Color,
@@ -877,6 +932,8 @@ pub struct Theme {
     // Search colors
     pub search_match_bg: Color,
     pub search_match_fg: Color,
+    pub search_current_match_bg: Color,
+    pub search_current_match_fg: Color,
 
     // Diagnostic colors
     pub diagnostic_error_fg: Color,
@@ -917,6 +974,11 @@ impl From<ThemeFile> for Theme {
             // Compute brighter highlight colors from base diff colors
             diff_add_highlight_bg: brighten_color(file.editor.diff_add_bg.into(), 40),
             diff_remove_highlight_bg: brighten_color(file.editor.diff_remove_bg.into(), 40),
+            indent_guide_fg: file.editor.indent_guide_fg.into(),
+            indent_guide_active_fg: file.editor.indent_guide_active_fg.into(),
+            control_char_fg: file.editor.control_char_fg.into(),
+            whitespace_indicator_fg: file.editor.whitespace_indicator_fg.into(),
+            ruler_fg: file.editor.ruler_fg.into(),
             tab_active_fg: file.ui.tab_active_fg.into(),
             tab_active_bg: file.ui.tab_active_bg.into(),
             tab_inactive_fg: file.ui.tab_inactive_fg.into(),
@@ -979,6 +1041,8 @@ impl From<ThemeFile> for Theme {
             tab_drop_zone_border: file.ui.tab_drop_zone_border.into(),
             search_match_bg: file.search.match_bg.into(),
             search_match_fg: file.search.match_fg.into(),
+            search_current_match_bg: file.search.current_match_bg.into(),
+            search_current_match_fg: file.search.current_match_fg.into(),
             diagnostic_error_fg: file.diagnostic.error_fg.into(),
             diagnostic_error_bg: file.diagnostic.error_bg.into(),
             diagnostic_warning_fg: file.diagnostic.warning_fg.into(),
@@ -1015,6 +1079,11 @@ impl From<Theme> for ThemeFile {
                 diff_add_bg: theme.diff_add_bg.into(),
                 diff_remove_bg: theme.diff_remove_bg.into(),
                 diff_modify_bg: theme.diff_modify_bg.into(),
+                indent_guide_fg: theme.indent_guide_fg.into(),
+                indent_guide_active_fg: theme.indent_guide_active_fg.into(),
+                control_char_fg: theme.control_char_fg.into(),
+                whitespace_indicator_fg: theme.whitespace_indicator_fg.into(),
+                ruler_fg: theme.ruler_fg.into(),
             },
             ui: UiColors {
                 tab_active_fg: theme.tab_active_fg.into(),
@@ -1081,6 +1150,8 @@ impl From<Theme> for ThemeFile {
             search: SearchColors {
                 match_bg: theme.search_match_bg.into(),
                 match_fg: theme.search_match_fg.into(),
+                current_match_bg: theme.search_current_match_bg.into(),
+                current_match_fg: theme.search_current_match_fg.into(),
             },
             diagnostic: DiagnosticColors {
                 error_fg: theme.diagnostic_error_fg.into(),
@@ -1122,6 +1193,17 @@ impl Theme {
             serde_json::from_str(json).map_err(|e| format!("Failed to parse theme JSON: {}", e))?;
         Ok(theme_file.into())
     }
+
+    /// Look up a color by its theme file key, such as `"diagnostic.error_bg"`
+    /// or `"syntax.keyword"`
+    pub fn color_by_key(&self, key: &str) -> Option<Color> {
+        let (section, field) = key.split_once('.')?;
+        let file = serde_json::to_value(ThemeFile::from(self.clone())).ok()?;
+        let def = file.get(section)?.get(field)?.clone();
+        serde_json::from_value::<ColorDef>(def)
+            .ok()
+            .map(Color::from)
+    }
 }
 
 // =============================================================================
@@ -1174,6 +1256,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_color_by_key() {
+        let dark = Theme::load_builtin(THEME_DARK).unwrap();
+        assert_eq!(
+            dark.color_by_key("diagnostic.error_bg"),
+            Some(dark.diagnostic_error_bg)
+        );
+        assert_eq!(
+            dark.color_by_key("syntax.keyword"),
+            Some(dark.syntax_keyword)
+        );
+        assert_eq!(dark.color_by_key("syntax.missing"), None);
+        assert_eq!(dark.color_by_key("keyword"), None);
+    }
+
     #[test]
     fn test_from_json() {
         let json = r#"{"name":"test","editor":{},"ui":{},"search":{},"diagnostic":{},"syntax":{}}"#;
@@ -1,5 +1,5 @@
 use crate::primitives::display_width::str_width;
-use crate::view::file_tree::{FileExplorerDecorationCache, FileTreeView, NodeId};
+use crate::view::file_tree::{FileExplorerDecorationCache, FileTreeView, GitFileStatus, NodeId};
 use crate::view::theme::Theme;
 use ratatui::{
     layout::Rect,
@@ -95,6 +95,10 @@ impl FileExplorerRenderer {
         } else {
             " File Explorer ".to_string()
         };
+        let title = match view.filter() {
+            Some(filter) => format!("{}[{}] ", title, filter),
+            None => title,
+        };
 
         // Title style: inverted colors (dark on light) when focused using theme colors
         let (title_style, border_style) = if is_focused {
@@ -224,9 +228,13 @@ impl FileExplorerRenderer {
             spans.push(Span::raw("  "));
         }
 
+        let git_status = view.git_status().status(&node.entry.path);
+
         // Name styling using theme colors
         let name_style = if is_selected && is_focused {
             Style::default().fg(theme.editor_fg)
+        } else if let Some(status) = git_status {
+            Style::default().fg(Self::git_status_color(status, theme))
         } else if node
             .entry
             .metadata
@@ -263,14 +271,21 @@ impl FileExplorerRenderer {
             None
         };
 
+        // Plugin decorations take precedence over the built-in git status
         let right_indicator: Option<(String, Color)> = if has_unsaved {
             Some(("●".to_string(), theme.diagnostic_warning_fg))
         } else if let Some(decoration) = direct_decoration {
             let symbol = Self::decoration_symbol(&decoration.symbol);
             Some((symbol, Self::decoration_color(decoration)))
+        } else if let Some(decoration) = bubbled_decoration {
+            Some(("●".to_string(), Self::decoration_color(decoration)))
         } else {
-            bubbled_decoration
-                .map(|decoration| ("●".to_string(), Self::decoration_color(decoration)))
+            git_status.and_then(|status| {
+                let symbol = status.symbol()?;
+                // Directories only signal that something below them changed
+                let symbol = if node.is_dir() { "●" } else { symbol };
+                Some((symbol.to_string(), Self::git_status_color(status, theme)))
+            })
         };
 
         // Calculate right-side content width
@@ -319,6 +334,16 @@ impl FileExplorerRenderer {
             .unwrap_or_else(|| " ".to_string())
     }
 
+    fn git_status_color(status: GitFileStatus, theme: &Theme) -> Color {
+        match status {
+            GitFileStatus::Ignored => theme.line_number_fg,
+            GitFileStatus::Untracked => theme.diagnostic_hint_fg,
+            GitFileStatus::Added => theme.diagnostic_info_fg,
+            GitFileStatus::Modified => theme.diagnostic_warning_fg,
+            GitFileStatus::Conflicted => theme.diagnostic_error_fg,
+        }
+    }
+
     fn decoration_color(decoration: &crate::view::file_tree::FileExplorerDecoration) -> Color {
         let [r, g, b] = decoration.color;
         Color::Rgb(r, g, b)
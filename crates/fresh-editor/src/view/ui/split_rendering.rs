@@ -4,9 +4,10 @@ use std::collections::BTreeMap;
 
 use crate::app::types::ViewLineMapping;
 use crate::app::BufferMetadata;
+use crate::input::actions::{block_selection_rect, line_range_at_visual_columns};
 use crate::model::buffer::Buffer;
-use crate::model::cursor::SelectionMode;
 use crate::model::event::{BufferId, EventLog, SplitDirection};
+use crate::model::piece_tree_diff::PieceTreeDiff;
 use crate::primitives::ansi::AnsiParser;
 use crate::primitives::ansi_background::AnsiBackground;
 use crate::primitives::display_width::char_width;
@@ -14,7 +15,8 @@ use crate::state::{EditorState, ViewMode};
 use crate::view::split::SplitManager;
 use crate::view::ui::tabs::TabsRenderer;
 use crate::view::ui::view_pipeline::{
-    should_show_line_number, LineStart, ViewLine, ViewLineIterator,
+    shift_source_bytes, should_show_line_number, source_line_starts, LineStart, ViewLine,
+    ViewLineCache, ViewLineCacheKey, ViewLineIterator, WhitespaceRender,
 };
 use crate::view::virtual_text::VirtualTextPosition;
 use fresh_core::api::ViewTransformPayload;
@@ -25,6 +27,7 @@ use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 use ratatui::Frame;
 use std::collections::{HashMap, HashSet};
 use std::ops::Range;
+use std::sync::Arc;
 
 /// Maximum line width before forced wrapping is applied, even when line wrapping is disabled.
 /// This prevents memory exhaustion when opening files with extremely long lines (e.g., 10MB
@@ -297,7 +300,7 @@ impl DebugSpanTracker {
 /// Processed view data containing display lines from the view pipeline
 struct ViewData {
     /// Display lines with all token information preserved
-    lines: Vec<ViewLine>,
+    lines: Arc<Vec<ViewLine>>,
 }
 
 struct ViewAnchor {
@@ -313,7 +316,7 @@ struct ComposeLayout {
 
 struct SelectionContext {
     ranges: Vec<Range<usize>>,
-    block_rects: Vec<(usize, usize, usize, usize)>,
+    block_ranges: Vec<Range<usize>>,
     cursor_positions: Vec<usize>,
     primary_cursor_position: usize,
 }
@@ -323,7 +326,8 @@ struct DecorationContext {
     semantic_token_spans: Vec<crate::primitives::highlighter::HighlightSpan>,
     viewport_overlays: Vec<(crate::view::overlay::Overlay, Range<usize>)>,
     virtual_text_lookup: HashMap<usize, Vec<crate::view::virtual_text::VirtualText>>,
-    diagnostic_lines: HashSet<usize>,
+    /// Highest diagnostic overlay priority per source line
+    diagnostic_lines: HashMap<usize, crate::view::overlay::Priority>,
     /// Line indicators indexed by line number (highest priority indicator per line)
     line_indicators: BTreeMap<usize, crate::view::margin::LineIndicator>,
 }
@@ -375,6 +379,81 @@ struct LineRenderInput<'a> {
     left_column: usize,
     /// Whether to show relative line numbers (distance from cursor)
     relative_line_numbers: bool,
+    /// Whether to draw indent guides in leading whitespace
+    show_indent_guides: bool,
+}
+
+/// Indent guide layout for the view lines being rendered
+struct IndentGuides {
+    /// Columns per indentation level
+    step: usize,
+    /// Leading whitespace width of each view line (`None` for wrapped continuations)
+    widths: Vec<Option<usize>>,
+    /// Guide column of the indentation level at the cursor, and the view lines it spans
+    active: Option<(usize, Range<usize>)>,
+}
+
+impl IndentGuides {
+    fn compute(view_lines: &[ViewLine], tab_size: usize, cursor: usize) -> Self {
+        let step = tab_size.max(1);
+        // Tabs are already expanded to spaces in the view text
+        let widths: Vec<Option<usize>> = view_lines
+            .iter()
+            .map(|line| {
+                (!line.line_start.is_continuation())
+                    .then(|| line.text.chars().take_while(|&c| c == ' ').count())
+            })
+            .collect();
+
+        // Indentation of lines that delimit blocks; blank lines and wrapped
+        // continuations don't end the block around them
+        let level = |idx: usize| -> Option<usize> {
+            let width = widths[idx]?;
+            (view_lines[idx].text.trim_end().len() > width).then_some(width)
+        };
+
+        let active = view_lines
+            .iter()
+            .position(|line| line.char_source_bytes.contains(&Some(cursor)))
+            .and_then(|mut cursor_idx| {
+                while widths[cursor_idx].is_none() && cursor_idx > 0 {
+                    cursor_idx -= 1;
+                }
+                let indent = level(cursor_idx).filter(|&w| w > 0)?;
+                let col = (indent - 1) / step * step;
+                let inside = |idx: usize| level(idx).is_none_or(|w| w > col);
+
+                let mut start = cursor_idx;
+                while start > 0 && inside(start - 1) {
+                    start -= 1;
+                }
+                let mut end = cursor_idx + 1;
+                while end < view_lines.len() && inside(end) {
+                    end += 1;
+                }
+                Some((col, start..end))
+            });
+
+        Self {
+            step,
+            widths,
+            active,
+        }
+    }
+
+    /// Whether a guide belongs at `col` of view line `line_idx`, and if so
+    /// whether it is the active one
+    fn guide_at(&self, line_idx: usize, col: usize) -> Option<bool> {
+        let width = self.widths.get(line_idx).copied().flatten()?;
+        if col >= width || !col.is_multiple_of(self.step) {
+            return None;
+        }
+        Some(
+            self.active
+                .as_ref()
+                .is_some_and(|(active_col, lines)| *active_col == col && lines.contains(&line_idx)),
+        )
+    }
 }
 
 /// Context for computing the style of a single character
@@ -405,7 +484,7 @@ struct LeftMarginContext<'a> {
     is_continuation: bool,
     current_source_line_num: usize,
     estimated_lines: usize,
-    diagnostic_lines: &'a HashSet<usize>,
+    diagnostic_lines: &'a HashMap<usize, crate::view::overlay::Priority>,
     /// Pre-computed line indicators (line_num -> indicator)
     line_indicators: &'a BTreeMap<usize, crate::view::margin::LineIndicator>,
     /// Line number where the primary cursor is located (for relative line numbers)
@@ -433,13 +512,15 @@ fn render_left_margin(
             Style::default(),
             None,
         );
-    } else if ctx.diagnostic_lines.contains(&ctx.current_source_line_num) {
+    } else if let Some(&priority) = ctx.diagnostic_lines.get(&ctx.current_source_line_num) {
         // Diagnostic indicators have highest priority
+        let (glyph, color) =
+            crate::services::lsp::diagnostics::diagnostic_gutter_glyph(priority, ctx.theme);
         push_span_with_map(
             line_spans,
             line_view_map,
-            "●".to_string(),
-            Style::default().fg(ratatui::style::Color::Red),
+            glyph.to_string(),
+            Style::default().fg(color),
             None,
         );
     } else if let Some(indicator) = ctx.line_indicators.get(&ctx.current_source_line_num) {
@@ -522,6 +603,61 @@ fn render_left_margin(
     }
 }
 
+/// How far back from the first visible line to scan for enclosing braces
+const STICKY_SCROLL_SCAN_BYTES: usize = 64 * 1024;
+
+/// Most scope headers shown at once by sticky scroll
+const MAX_STICKY_SCROLL_LINES: usize = 2;
+
+/// Longest scope header line read for display
+const STICKY_SCROLL_MAX_LINE_BYTES: usize = 1024;
+
+/// Find the lines that open the scopes enclosing the end of `text`
+///
+/// Scans backwards for `{` without a matching `}` and returns the byte offsets
+/// of the lines holding them, outermost first, keeping at most `max` innermost
+/// scopes. A brace alone on its line belongs to the line above it.
+fn sticky_scope_line_starts(text: &str, max: usize) -> Vec<usize> {
+    let bytes = text.as_bytes();
+    let line_start_of = |pos: usize| {
+        bytes[..pos]
+            .iter()
+            .rposition(|b| *b == b'\n')
+            .map_or(0, |i| i + 1)
+    };
+
+    let mut starts: Vec<usize> = Vec::new();
+    let mut depth = 0usize;
+    let mut pos = bytes.len();
+    while pos > 0 && starts.len() < max {
+        pos -= 1;
+        match bytes[pos] {
+            b'}' => depth += 1,
+            b'{' if depth > 0 => depth -= 1,
+            b'{' => {
+                let mut start = line_start_of(pos);
+                if text[start..pos].trim().is_empty() && start > 0 {
+                    // Allman style: use the nearest non-blank line above
+                    let mut prev = line_start_of(start - 1);
+                    while prev > 0 && text[prev..start].trim().is_empty() {
+                        prev = line_start_of(prev - 1);
+                    }
+                    if !text[prev..start].trim().is_empty() {
+                        start = prev;
+                    }
+                }
+                if starts.last() != Some(&start) {
+                    starts.push(start);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    starts.reverse();
+    starts
+}
+
 /// Compute the style for a character by layering: token -> ANSI -> syntax -> semantic -> overlays -> selection -> cursor
 fn compute_char_style(ctx: &CharStyleContext) -> CharStyleOutput {
     use crate::view::overlay::OverlayFace;
@@ -718,6 +854,14 @@ impl SplitRenderer {
         hovered_maximize_split: Option<crate::model::event::SplitId>,
         is_maximized: bool,
         relative_line_numbers: bool,
+        show_indent_guides: bool,
+        caret_control_chars: bool,
+        render_whitespace: WhitespaceRender,
+        rulers: &[usize],
+        sticky_scroll: bool,
+        smooth_scroll: Option<std::time::Duration>,
+        now: std::time::Instant,
+        highlight_word_occurrences: bool,
         tab_bar_visible: bool,
         use_terminal_bg: bool,
     ) -> (
@@ -883,6 +1027,7 @@ impl SplitRenderer {
                             theme,
                             is_active,
                             view_state,
+                            caret_control_chars,
                             use_terminal_bg,
                         );
 
@@ -928,6 +1073,7 @@ impl SplitRenderer {
                             )
                         });
                 let mut viewport = viewport_clone;
+                viewport.restore_scroll_target();
 
                 let saved_cursors = Self::temporary_split_state(
                     state,
@@ -944,10 +1090,16 @@ impl SplitRenderer {
                 let view_prefs =
                     Self::resolve_view_preferences(state, split_view_states.as_deref(), split_id);
 
+                let mut view_line_cache = split_view_states
+                    .as_deref_mut()
+                    .and_then(|vs| vs.get_mut(&split_id))
+                    .and_then(|vs| vs.view_line_cache.take());
+
                 let split_view_mappings = Self::render_buffer_in_split(
                     frame,
                     state,
                     &mut viewport,
+                    &mut view_line_cache,
                     event_log_opt,
                     layout.content_rect,
                     is_active,
@@ -964,6 +1116,14 @@ impl SplitRenderer {
                     buffer_id,
                     hide_cursor,
                     relative_line_numbers,
+                    show_indent_guides,
+                    caret_control_chars,
+                    render_whitespace,
+                    rulers,
+                    sticky_scroll,
+                    smooth_scroll,
+                    now,
+                    highlight_word_occurrences,
                     use_terminal_bg,
                 );
 
@@ -1008,6 +1168,7 @@ impl SplitRenderer {
                             viewport.should_skip_ensure_visible()
                         );
                         view_state.viewport = viewport.clone();
+                        view_state.view_line_cache = view_line_cache;
                     }
                 }
 
@@ -1078,6 +1239,7 @@ impl SplitRenderer {
         theme: &crate::view::theme::Theme,
         _is_active: bool,
         view_state: &mut crate::view::composite_view::CompositeViewState,
+        caret_control_chars: bool,
         use_terminal_bg: bool,
     ) {
         use crate::model::composite_buffer::{CompositeLayout, RowType};
@@ -1168,7 +1330,7 @@ impl SplitRenderer {
         // Build ViewData and get syntax highlighting for each pane
         // Store: (ViewLines, line->ViewLine mapping, highlight spans)
         struct PaneRenderData {
-            lines: Vec<ViewLine>,
+            lines: Arc<Vec<ViewLine>>,
             line_to_view_line: HashMap<usize, usize>,
             highlight_spans: Vec<crate::primitives::highlighter::HighlightSpan>,
         }
@@ -1226,6 +1388,7 @@ impl SplitRenderer {
                     let lines_needed = last_line - first_line + 10;
                     let view_data = Self::build_view_data(
                         source_state,
+                        &mut None,
                         &viewport,
                         None,         // No view transform
                         80,           // estimated_line_length
@@ -1233,6 +1396,8 @@ impl SplitRenderer {
                         false,        // line_wrap_enabled
                         content_width,
                         gutter_width,
+                        caret_control_chars,
+                        WhitespaceRender::default(),
                     );
 
                     // Build source_line -> ViewLine index mapping
@@ -1972,6 +2137,7 @@ impl SplitRenderer {
     #[allow(clippy::too_many_arguments)]
     fn build_view_data(
         state: &mut EditorState,
+        view_line_cache: &mut Option<ViewLineCache>,
         viewport: &crate::view::viewport::Viewport,
         view_transform: Option<ViewTransformPayload>,
         estimated_line_length: usize,
@@ -1979,23 +2145,11 @@ impl SplitRenderer {
         line_wrap_enabled: bool,
         content_width: usize,
         gutter_width: usize,
+        caret_control_chars: bool,
+        render_whitespace: WhitespaceRender,
     ) -> ViewData {
         // Check if buffer is binary before building tokens
         let is_binary = state.buffer.is_binary();
-        let line_ending = state.buffer.line_ending();
-
-        // Build base token stream from source
-        let base_tokens = Self::build_base_tokens(
-            &mut state.buffer,
-            viewport.top_byte,
-            estimated_line_length,
-            visible_count,
-            is_binary,
-            line_ending,
-        );
-
-        // Use plugin transform if available, otherwise use base tokens
-        let mut tokens = view_transform.map(|vt| vt.tokens).unwrap_or(base_tokens);
 
         // Apply wrapping transform - always enabled for safety, but with different thresholds.
         // When line_wrap is on: wrap at viewport width for normal text flow.
@@ -2006,16 +2160,27 @@ impl SplitRenderer {
         } else {
             MAX_SAFE_LINE_WIDTH
         };
-        tokens = Self::apply_wrapping_transform(tokens, effective_width, gutter_width);
+        let key = ViewLineCacheKey {
+            top_byte: viewport.top_byte,
+            visible_count,
+            estimated_line_length,
+            wrap_width: effective_width,
+            gutter_width,
+            tab_size: state.tab_size,
+            caret_control_chars,
+            render_whitespace,
+            line_ending: state.buffer.line_ending(),
+        };
 
-        // Convert tokens to display lines using the view pipeline
-        // Each ViewLine preserves LineStart info for correct line number rendering
-        // Use binary mode if the buffer contains binary content
-        // Enable ANSI awareness for non-binary content to handle escape sequences correctly
-        let is_binary = state.buffer.is_binary();
-        let ansi_aware = !is_binary; // ANSI parsing for normal text files
-        let source_lines: Vec<ViewLine> =
-            ViewLineIterator::new(&tokens, is_binary, ansi_aware, state.tab_size).collect();
+        // Use plugin transform if available, otherwise lay out the source lines.
+        // A transform can reshape any line, so its lines are always built in full.
+        let source_lines = match view_transform {
+            Some(vt) => {
+                *view_line_cache = None;
+                Arc::new(Self::layout_tokens(vt.tokens, &key, is_binary))
+            }
+            None => Self::build_source_lines(state, view_line_cache, key, is_binary),
+        };
 
         // Inject virtual lines (LineAbove/LineBelow) from VirtualTextManager
         let lines = Self::inject_virtual_lines(source_lines, state);
@@ -2023,6 +2188,252 @@ impl SplitRenderer {
         ViewData { lines }
     }
 
+    /// Wrap `tokens` and convert them to display lines using the view pipeline
+    ///
+    /// Each ViewLine preserves LineStart info for correct line number rendering.
+    /// Binary content is shown in binary mode; other content is ANSI aware so
+    /// escape sequences are handled correctly.
+    fn layout_tokens(
+        tokens: Vec<fresh_core::api::ViewTokenWire>,
+        key: &ViewLineCacheKey,
+        is_binary: bool,
+    ) -> Vec<ViewLine> {
+        let tokens = Self::apply_wrapping_transform(tokens, key.wrap_width, key.gutter_width);
+        ViewLineIterator::new(&tokens, is_binary, !is_binary, key.tab_size)
+            .with_caret_notation(key.caret_control_chars)
+            .with_whitespace(key.render_whitespace)
+            .collect()
+    }
+
+    /// Lay out the source lines of the viewport
+    ///
+    /// The lines of the split's previous build are reused when nothing that
+    /// affects them changed. After an edit, `diff_piece_trees` locates the
+    /// edited bytes and only the source lines they touch are laid out again.
+    fn build_source_lines(
+        state: &mut EditorState,
+        view_line_cache: &mut Option<ViewLineCache>,
+        key: ViewLineCacheKey,
+        is_binary: bool,
+    ) -> Arc<Vec<ViewLine>> {
+        if let Some(cache) = view_line_cache.take() {
+            if !is_binary && cache.key == key && cache.buffer_instance == state.buffer.instance_id()
+            {
+                if let Some(cache) = Self::update_view_line_cache(&mut state.buffer, cache) {
+                    let lines = Arc::clone(&cache.lines);
+                    *view_line_cache = Some(cache);
+                    return lines;
+                }
+            }
+        }
+
+        let tokens = Self::build_base_tokens(
+            &mut state.buffer,
+            key.top_byte,
+            key.estimated_line_length,
+            key.visible_count,
+            is_binary,
+            key.line_ending,
+        );
+        // Lines split at MAX_SAFE_LINE_WIDTH count more than once toward the
+        // lines to show, so the line-by-line update does not apply to them
+        let splits_long_lines = tokens
+            .iter()
+            .any(|t| matches!(t.kind, fresh_core::api::ViewTokenWireKind::Break));
+        let lines = Arc::new(Self::layout_tokens(tokens, &key, is_binary));
+        if !is_binary && !splits_long_lines {
+            *view_line_cache =
+                Self::new_view_line_cache(&mut state.buffer, key, Arc::clone(&lines));
+        }
+        lines
+    }
+
+    /// Cache freshly built source lines, if each of them maps to the buffer
+    fn new_view_line_cache(
+        buffer: &mut Buffer,
+        key: ViewLineCacheKey,
+        lines: Arc<Vec<ViewLine>>,
+    ) -> Option<ViewLineCache> {
+        let &(_, last_start) = source_line_starts(&lines)?.last()?;
+        Some(ViewLineCache {
+            key,
+            buffer_instance: buffer.instance_id(),
+            version: buffer.version(),
+            tree: buffer.snapshot_piece_tree(),
+            buffer_len: buffer.len(),
+            end_byte: Self::source_line_end(buffer, last_start, key.estimated_line_length),
+            #[cfg(test)]
+            rebuilt: 0..lines.len(),
+            lines,
+        })
+    }
+
+    /// Byte after the source line starting at `line_start`, including its line ending
+    fn source_line_end(
+        buffer: &mut Buffer,
+        line_start: usize,
+        estimated_line_length: usize,
+    ) -> usize {
+        buffer
+            .line_iterator(line_start, estimated_line_length)
+            .next_line_bytes()
+            .map_or(line_start, |(start, bytes)| start + bytes.len())
+    }
+
+    /// Bring cached source lines up to date with the buffer
+    ///
+    /// Returns None when the lines must be built in full instead, see
+    /// `splice_view_line_edit`.
+    fn update_view_line_cache(
+        buffer: &mut Buffer,
+        mut cache: ViewLineCache,
+    ) -> Option<ViewLineCache> {
+        #[cfg(test)]
+        {
+            cache.rebuilt = 0..0;
+        }
+        if cache.version == buffer.version() {
+            return Some(cache);
+        }
+
+        // Edits below the last line shown leave every line as it is
+        let diff = buffer.diff_from_snapshot(&cache.tree);
+        if !diff.equal
+            && (diff.before_span.start < cache.end_byte || cache.end_byte == cache.buffer_len)
+        {
+            Self::splice_view_line_edit(buffer, &mut cache, &diff)?;
+        }
+
+        cache.version = buffer.version();
+        cache.tree = buffer.snapshot_piece_tree();
+        cache.buffer_len = buffer.len();
+        Some(cache)
+    }
+
+    /// Lay out again the cached source lines that `diff` touches
+    ///
+    /// Returns None when line counts are unknown (large files), the edit starts
+    /// above the viewport or runs past its end, or it leaves a line that is
+    /// split at MAX_SAFE_LINE_WIDTH.
+    fn splice_view_line_edit(
+        buffer: &mut Buffer,
+        cache: &mut ViewLineCache,
+        diff: &PieceTreeDiff,
+    ) -> Option<()> {
+        let key = cache.key;
+        diff.line_ranges.as_ref()?;
+        let edit = diff.before_span.clone();
+        if edit.start < key.top_byte || edit.end > cache.end_byte {
+            return None;
+        }
+        let delta = diff.after_span.len() as isize - edit.len() as isize;
+        let lines = Arc::make_mut(&mut cache.lines);
+
+        // The source lines the edit touches, including the line after a
+        // removed line ending
+        let starts = source_line_starts(lines)?;
+        let first = starts.iter().rposition(|&(_, byte)| byte <= edit.start)?;
+        let last = starts.iter().rposition(|&(_, byte)| byte <= edit.end)?;
+        let old_lines = starts[first].0..starts.get(last + 1).map_or(lines.len(), |s| s.0);
+        let region_start = starts[first].1;
+        let region_end = starts
+            .get(last + 1)
+            .map_or(cache.end_byte, |s| s.1)
+            .checked_add_signed(delta)?;
+
+        // The edited lines must still end where the reused lines begin
+        let region = buffer
+            .get_text_range_mut(region_start, region_end.checked_sub(region_start)?)
+            .ok()?;
+        if region_end < buffer.len() && !(region.is_empty() || region.ends_with(b"\n")) {
+            return None;
+        }
+        let line_count = region.iter().filter(|&&b| b == b'\n').count()
+            + usize::from(!region.is_empty() && !region.ends_with(b"\n"));
+
+        let mut new_lines = if line_count > 0 {
+            Self::build_source_lines_at(buffer, &key, region_start, line_count)?
+        } else {
+            Vec::new()
+        };
+        if let (Some(line), 0) = (new_lines.first_mut(), old_lines.start) {
+            line.line_start = LineStart::Beginning;
+        }
+
+        let mut tail = lines.split_off(old_lines.end);
+        shift_source_bytes(&mut tail, delta);
+        lines.truncate(old_lines.start);
+        #[cfg(test)]
+        let mut rebuilt = lines.len()..lines.len() + new_lines.len();
+        lines.append(&mut new_lines);
+        lines.append(&mut tail);
+
+        // Keep as many source lines as a full build would show: drop the lines
+        // pushed out by inserted ones, or fill in below after removed ones
+        let max_lines = key.visible_count.saturating_add(4);
+        let mut starts = source_line_starts(lines)?;
+        if let Some(&(idx, _)) = starts.get(max_lines) {
+            lines.truncate(idx);
+            starts.truncate(max_lines);
+        }
+        let mut end_byte =
+            Self::source_line_end(buffer, starts.last()?.1, key.estimated_line_length);
+        if starts.len() < max_lines && end_byte < buffer.len() {
+            let mut more =
+                Self::build_source_lines_at(buffer, &key, end_byte, max_lines - starts.len())?;
+            let &(_, last_start) = source_line_starts(&more)?.last()?;
+            end_byte = Self::source_line_end(buffer, last_start, key.estimated_line_length);
+            #[cfg(test)]
+            {
+                rebuilt.end = lines.len() + more.len();
+            }
+            lines.append(&mut more);
+        }
+        #[cfg(test)]
+        {
+            rebuilt.end = rebuilt.end.min(lines.len());
+            rebuilt.start = rebuilt.start.min(rebuilt.end);
+            cache.rebuilt = rebuilt;
+        }
+
+        cache.end_byte = end_byte;
+        Some(())
+    }
+
+    /// Lay out `count` source lines starting at the line start `start`
+    ///
+    /// The first line is laid out as following a source line ending. Returns
+    /// None if a line is split at MAX_SAFE_LINE_WIDTH.
+    fn build_source_lines_at(
+        buffer: &mut Buffer,
+        key: &ViewLineCacheKey,
+        start: usize,
+        count: usize,
+    ) -> Option<Vec<ViewLine>> {
+        let tokens = Self::build_base_tokens(
+            buffer,
+            start,
+            key.estimated_line_length,
+            count,
+            false,
+            key.line_ending,
+        );
+        if tokens
+            .iter()
+            .any(|t| matches!(t.kind, fresh_core::api::ViewTokenWireKind::Break))
+        {
+            return None;
+        }
+        let mut lines = Self::layout_tokens(tokens, key, false);
+        if let Some(&(idx, _)) = source_line_starts(&lines)?.get(count) {
+            lines.truncate(idx);
+        }
+        if let Some(line) = lines.first_mut() {
+            line.line_start = LineStart::AfterSourceNewline;
+        }
+        Some(lines)
+    }
+
     /// Create a ViewLine from virtual text content (for LineAbove/LineBelow)
     fn create_virtual_line(text: &str, style: ratatui::style::Style) -> ViewLine {
         use fresh_core::api::ViewTokenStyle;
@@ -2057,6 +2468,8 @@ impl SplitRenderer {
             // Per-visual-column: each column maps to its corresponding character
             visual_to_char: (0..len).collect(),
             tab_starts: HashSet::new(),
+            control_chars: HashSet::new(),
+            whitespace_glyphs: HashMap::new(),
             // AfterInjectedNewline means no line number will be shown
             line_start: LineStart::AfterInjectedNewline,
             ends_with_newline: true,
@@ -2064,7 +2477,10 @@ impl SplitRenderer {
     }
 
     /// Inject virtual lines (LineAbove/LineBelow) into the ViewLine stream
-    fn inject_virtual_lines(source_lines: Vec<ViewLine>, state: &EditorState) -> Vec<ViewLine> {
+    fn inject_virtual_lines(
+        source_lines: Arc<Vec<ViewLine>>,
+        state: &EditorState,
+    ) -> Arc<Vec<ViewLine>> {
         use crate::view::virtual_text::VirtualTextPosition;
 
         // Get viewport byte range from source lines
@@ -2093,7 +2509,7 @@ impl SplitRenderer {
         // Build result with virtual lines injected
         let mut result = Vec::with_capacity(source_lines.len() + virtual_lines.len());
 
-        for source_line in source_lines {
+        for source_line in source_lines.iter() {
             // Get this line's byte range
             let line_start_byte = source_line.char_source_bytes.iter().find_map(|m| *m);
             let line_end_byte = source_line
@@ -2131,7 +2547,7 @@ impl SplitRenderer {
             }
         }
 
-        result
+        Arc::new(result)
     }
 
     fn build_base_tokens(
@@ -2163,12 +2579,19 @@ impl SplitRenderer {
         let max_lines = visible_count.saturating_add(4);
 
         while lines_seen < max_lines {
-            if let Some((line_start, line_content)) = iter.next_line() {
+            if let Some((line_start, content_bytes)) = iter.next_line_bytes() {
                 let mut byte_offset = 0usize;
-                let content_bytes = line_content.as_bytes();
                 let mut skip_next_lf = false; // Track if we should skip \n after \r in CRLF
                 let mut chars_this_line = 0usize; // Track chars to enforce MAX_SAFE_LINE_WIDTH
-                for ch in line_content.chars() {
+                                                  // Invalid UTF-8 bytes come through as Err so they keep their own source offset
+                let decoded = content_bytes.utf8_chunks().flat_map(|chunk| {
+                    chunk
+                        .valid()
+                        .chars()
+                        .map(Ok)
+                        .chain(chunk.invalid().iter().map(|&b| Err(b)))
+                });
+                for decoded_ch in decoded {
                     // Limit characters per line to prevent memory exhaustion from huge lines.
                     // Insert a Break token to force wrapping at safe intervals.
                     if chars_this_line >= MAX_SAFE_LINE_WIDTH {
@@ -2186,8 +2609,21 @@ impl SplitRenderer {
                     }
                     chars_this_line += 1;
 
-                    let ch_len = ch.len_utf8();
                     let source_offset = Some(line_start + byte_offset);
+                    let ch = match decoded_ch {
+                        Ok(ch) => ch,
+                        Err(b) => {
+                            // Invalid UTF-8 byte - emit as BinaryByte, one per byte
+                            tokens.push(ViewTokenWire {
+                                source_offset,
+                                kind: ViewTokenWireKind::BinaryByte(b),
+                                style: None,
+                            });
+                            byte_offset += 1;
+                            continue;
+                        }
+                    };
+                    let ch_len = ch.len_utf8();
 
                     match ch {
                         '\r' => {
@@ -2244,7 +2680,7 @@ impl SplitRenderer {
                             });
                         }
                         _ if Self::is_control_char(ch) => {
-                            // Control character - emit as BinaryByte to render as <XX>
+                            // Control character - emit as BinaryByte to render as ^X or <XX>
                             tokens.push(ViewTokenWire {
                                 source_offset,
                                 kind: ViewTokenWireKind::BinaryByte(ch as u8),
@@ -2789,30 +3225,16 @@ impl SplitRenderer {
             .filter_map(|(_, cursor)| cursor.selection_range())
             .collect();
 
-        let block_rects: Vec<(usize, usize, usize, usize)> = state
+        // Block selections cover the same visual columns on every line,
+        // including the column the cursor is on
+        let block_ranges: Vec<Range<usize>> = state
             .cursors
             .iter()
-            .filter_map(|(_, cursor)| {
-                if cursor.selection_mode == SelectionMode::Block {
-                    if let Some(anchor) = cursor.block_anchor {
-                        // Convert cursor position to 2D coords
-                        let cur_line = state.buffer.get_line_number(cursor.position);
-                        let cur_line_start = state.buffer.line_start_offset(cur_line).unwrap_or(0);
-                        let cur_col = cursor.position.saturating_sub(cur_line_start);
-
-                        // Return normalized rectangle (min values first)
-                        Some((
-                            anchor.line.min(cur_line),
-                            anchor.column.min(cur_col),
-                            anchor.line.max(cur_line),
-                            anchor.column.max(cur_col),
-                        ))
-                    } else {
-                        None
-                    }
-                } else {
-                    None
-                }
+            .filter_map(|(_, cursor)| block_selection_rect(&state.buffer, cursor))
+            .flat_map(|(first_line, first_col, last_line, last_col)| {
+                (first_line..=last_line).filter_map(move |line| {
+                    line_range_at_visual_columns(&state.buffer, line, first_col, last_col + 1)
+                })
             })
             .collect();
 
@@ -2828,7 +3250,7 @@ impl SplitRenderer {
 
         SelectionContext {
             ranges,
-            block_rects,
+            block_ranges,
             cursor_positions,
             primary_cursor_position: state.cursors.primary().position,
         }
@@ -2841,6 +3263,7 @@ impl SplitRenderer {
         primary_cursor_position: usize,
         theme: &crate::view::theme::Theme,
         highlight_context_bytes: usize,
+        highlight_word_occurrences: bool,
     ) -> DecorationContext {
         // Extend highlighting range by ~1 viewport size before/after for better context.
         // This helps tree-sitter parse multi-line constructs that span viewport boundaries.
@@ -2859,6 +3282,11 @@ impl SplitRenderer {
         );
 
         // Update reference highlight overlays (debounced, creates overlays that auto-adjust)
+        state.reference_highlight_overlay.set_enabled(
+            highlight_word_occurrences,
+            &mut state.overlays,
+            &mut state.marker_list,
+        );
         state.reference_highlight_overlay.update(
             &state.buffer,
             &mut state.overlays,
@@ -2895,15 +3323,15 @@ impl SplitRenderer {
 
         // Use the lsp-diagnostic namespace to identify diagnostic overlays
         let diagnostic_ns = crate::services::lsp::diagnostics::lsp_diagnostic_namespace();
-        let diagnostic_lines: HashSet<usize> = viewport_overlays
-            .iter()
-            .filter_map(|(overlay, range)| {
-                if overlay.namespace.as_ref() == Some(&diagnostic_ns) {
-                    return Some(state.buffer.get_line_number(range.start));
-                }
-                None
-            })
-            .collect();
+        let mut diagnostic_lines: HashMap<usize, crate::view::overlay::Priority> = HashMap::new();
+        for (overlay, range) in &viewport_overlays {
+            if overlay.namespace.as_ref() == Some(&diagnostic_ns) {
+                // The most severe diagnostic on a line picks its glyph
+                let line = state.buffer.get_line_number(range.start);
+                let priority = diagnostic_lines.entry(line).or_insert(overlay.priority);
+                *priority = (*priority).max(overlay.priority);
+            }
+        }
 
         let virtual_text_lookup: HashMap<usize, Vec<crate::view::virtual_text::VirtualText>> =
             state
@@ -2970,16 +3398,29 @@ impl SplitRenderer {
             estimated_lines,
             left_column,
             relative_line_numbers,
+            show_indent_guides,
         } = input;
 
         let selection_ranges = &selection.ranges;
-        let block_selections = &selection.block_rects;
+        let block_selections = &selection.block_ranges;
         let cursor_positions = &selection.cursor_positions;
         let primary_cursor_position = selection.primary_cursor_position;
 
         // Compute cursor line number for relative line numbers display
         let cursor_line = state.buffer.get_line_number(primary_cursor_position);
 
+        let indent_guides = show_indent_guides.then(|| {
+            let start = view_anchor.start_line_idx.min(view_lines.len());
+            let end = start
+                .saturating_add(visible_line_count)
+                .min(view_lines.len());
+            IndentGuides::compute(
+                &view_lines[start..end],
+                state.tab_size,
+                primary_cursor_position,
+            )
+        });
+
         let highlight_spans = &decorations.highlight_spans;
         let semantic_token_spans = &decorations.semantic_token_spans;
         let viewport_overlays = &decorations.viewport_overlays;
@@ -3022,6 +3463,8 @@ impl SplitRenderer {
                     char_visual_cols: Vec::new(),
                     visual_to_char: Vec::new(),
                     tab_starts: HashSet::new(),
+                    control_chars: HashSet::new(),
+                    whitespace_glyphs: HashMap::new(),
                     line_start: LineStart::Beginning,
                     ends_with_newline: false,
                 })
@@ -3036,6 +3479,8 @@ impl SplitRenderer {
             let line_char_styles = &current_view_line.char_styles;
             let line_visual_to_char = &current_view_line.visual_to_char;
             let line_tab_starts = &current_view_line.tab_starts;
+            let line_control_chars = &current_view_line.control_chars;
+            let line_whitespace_glyphs = &current_view_line.whitespace_glyphs;
             let _line_start_type = current_view_line.line_start; // Available for future use
 
             // Helper to get source byte at a visual column using the new O(1) lookup
@@ -3044,6 +3489,7 @@ impl SplitRenderer {
                 line_char_source_bytes.get(char_idx).copied().flatten()
             };
 
+            let guide_line_idx = view_iter_idx.wrapping_sub(view_anchor.start_line_idx);
             view_iter_idx += 1;
 
             if lines_rendered >= visible_line_count {
@@ -3103,7 +3549,6 @@ impl SplitRenderer {
             );
 
             // Check if this line has any selected text
-            let mut byte_index = 0; // Byte offset in line_content string
             let mut display_char_idx = 0usize; // Character index in text (for char_source_bytes)
             let mut col_offset = 0usize; // Visual column position
 
@@ -3181,7 +3626,6 @@ impl SplitRenderer {
                                     have_cursor = true;
                                 }
                             }
-                            byte_index += ch.len_utf8();
                             display_char_idx += 1;
                             // Note: col_offset not incremented - ANSI chars have 0 visual width
                             continue;
@@ -3193,7 +3637,7 @@ impl SplitRenderer {
                 };
 
                 // Performance: skip expensive style calculations for characters beyond visible range
-                // Use visible_char_count (not byte_index) since ANSI codes don't take up visible space
+                // Use visible_char_count (not the byte offset) since ANSI codes don't take up visible space
                 if visible_char_count > max_chars_to_process {
                     // Fast path: skip remaining characters without processing
                     // This is critical for performance with very long lines (e.g., 100KB single line)
@@ -3225,14 +3669,8 @@ impl SplitRenderer {
 
                     // Check if this character is in any selection range (but not at cursor position)
                     // Also check for block/rectangular selections
-                    let is_in_block_selection = block_selections.iter().any(
-                        |(start_line, start_col, end_line, end_col)| {
-                            current_source_line_num >= *start_line
-                                && current_source_line_num <= *end_line
-                                && byte_index >= *start_col
-                                && byte_index <= *end_col
-                        },
-                    );
+                    let is_in_block_selection = byte_pos
+                        .is_some_and(|bp| block_selections.iter().any(|range| range.contains(&bp)));
 
                     // For primary cursor in active split, terminal hardware cursor provides
                     // visual indication, so we can still show selection background.
@@ -3253,7 +3691,7 @@ impl SplitRenderer {
                         .get(display_char_idx)
                         .and_then(|s| s.as_ref());
                     let CharStyleOutput {
-                        style,
+                        mut style,
                         is_secondary_cursor,
                     } = compute_char_style(&CharStyleContext {
                         byte_pos,
@@ -3268,6 +3706,15 @@ impl SplitRenderer {
                         primary_cursor_position,
                         is_active,
                     });
+                    if token_style.is_none() && line_control_chars.contains(&display_char_idx) {
+                        style = style.fg(theme.control_char_fg);
+                    }
+
+                    // Glyph for rendered whitespace (not drawn under a cursor)
+                    let whitespace_glyph = line_whitespace_glyphs
+                        .get(&display_char_idx)
+                        .copied()
+                        .filter(|_| !is_cursor);
 
                     // Determine display character (tabs already expanded in ViewLineIterator)
                     // Show tab indicator (→) at the start of tab expansions (if enabled for this language)
@@ -3284,8 +3731,26 @@ impl SplitRenderer {
                         ""
                     } else if is_tab_start && state.show_whitespace_tabs {
                         // Visual indicator for tab: show → at the first position
+                        if whitespace_glyph.is_some() {
+                            style = style.fg(theme.whitespace_indicator_fg);
+                        }
                         tab_indicator = "→".to_string();
                         &tab_indicator
+                    } else if let Some(is_active_guide) = indent_guides
+                        .as_ref()
+                        .filter(|_| !is_cursor)
+                        .and_then(|guides| guides.guide_at(guide_line_idx, col_offset))
+                    {
+                        style = style.fg(if is_active_guide {
+                            theme.indent_guide_active_fg
+                        } else {
+                            theme.indent_guide_fg
+                        });
+                        "│"
+                    } else if let Some(glyph) = whitespace_glyph {
+                        style = style.fg(theme.whitespace_indicator_fg);
+                        tab_indicator = glyph.to_string();
+                        &tab_indicator
                     } else {
                         tab_indicator = ch.to_string();
                         &tab_indicator
@@ -3419,7 +3884,6 @@ impl SplitRenderer {
                     }
                 }
 
-                byte_index += ch.len_utf8();
                 display_char_idx += 1; // Increment character index for next lookup
                                        // col_offset tracks visual column position (for indexing into visual_to_char)
                                        // visual_to_char has one entry per visual column, not per character
@@ -3806,6 +4270,7 @@ impl SplitRenderer {
         frame: &mut Frame,
         state: &mut EditorState,
         viewport: &mut crate::view::viewport::Viewport,
+        view_line_cache: &mut Option<ViewLineCache>,
         event_log: Option<&mut EventLog>,
         area: Rect,
         is_active: bool,
@@ -3822,6 +4287,14 @@ impl SplitRenderer {
         _buffer_id: BufferId,
         hide_cursor: bool,
         relative_line_numbers: bool,
+        show_indent_guides: bool,
+        caret_control_chars: bool,
+        render_whitespace: WhitespaceRender,
+        rulers: &[usize],
+        sticky_scroll: bool,
+        smooth_scroll: Option<std::time::Duration>,
+        now: std::time::Instant,
+        highlight_word_occurrences: bool,
         use_terminal_bg: bool,
     ) -> Vec<ViewLineMapping> {
         let _span = tracing::trace_span!("render_buffer_in_split").entered();
@@ -3855,6 +4328,7 @@ impl SplitRenderer {
 
         let view_data = Self::build_view_data(
             state,
+            view_line_cache,
             viewport,
             view_transform,
             estimated_line_length,
@@ -3862,6 +4336,8 @@ impl SplitRenderer {
             line_wrap,
             render_area.width as usize,
             gutter_width,
+            caret_control_chars,
+            render_whitespace,
         );
 
         // Ensure cursor is visible using Layout-aware check (handles virtual lines)
@@ -3869,11 +4345,15 @@ impl SplitRenderer {
         let primary = *state.cursors.primary();
         let scrolled = viewport.ensure_visible_in_layout(&view_data.lines, &primary, gutter_width);
 
+        // With smooth scrolling, show an intermediate position on the way to the target
+        let animating = viewport.animate_scroll(&state.buffer, smooth_scroll, now);
+
         // If we scrolled, rebuild view_data from new position WITH the view_transform
         // This ensures virtual lines are included in the rebuilt view
-        let view_data = if scrolled {
+        let view_data = if scrolled || animating {
             Self::build_view_data(
                 state,
+                view_line_cache,
                 viewport,
                 view_transform_for_rebuild,
                 estimated_line_length,
@@ -3881,6 +4361,8 @@ impl SplitRenderer {
                 line_wrap,
                 render_area.width as usize,
                 gutter_width,
+                caret_control_chars,
+                render_whitespace,
             )
         } else {
             view_data
@@ -3938,6 +4420,7 @@ impl SplitRenderer {
             selection.primary_cursor_position,
             theme,
             highlight_context_bytes,
+            highlight_word_occurrences,
         );
 
         // Use top_view_line_offset to handle scrolling through virtual lines.
@@ -3994,6 +4477,7 @@ impl SplitRenderer {
             estimated_lines,
             left_column: viewport.left_column,
             relative_line_numbers,
+            show_indent_guides,
         });
 
         let mut lines = render_output.lines;
@@ -4018,6 +4502,18 @@ impl SplitRenderer {
             .style(Style::default().bg(effective_editor_bg));
         frame.render_widget(Paragraph::new(lines).block(editor_block), render_area);
 
+        Self::render_rulers(
+            frame,
+            render_area,
+            gutter_width,
+            viewport.left_column,
+            render_output
+                .content_lines_rendered
+                .min(render_area.height as usize),
+            rulers,
+            theme,
+        );
+
         // Render column guides if present (for tables, etc.)
         if let Some(guides) = compose_column_guides {
             let guide_style = Style::default()
@@ -4059,7 +4555,22 @@ impl SplitRenderer {
             gutter_width,
         );
 
-        if is_active && state.show_cursors && !hide_cursor {
+        if sticky_scroll && view_mode == ViewMode::Source {
+            // Keep the header out of the way when the cursor is on a row it would cover
+            let cursor_row = cursor.map(|(_, y)| y as usize);
+            Self::render_sticky_scroll(
+                frame,
+                state,
+                viewport.top_byte,
+                render_area,
+                gutter_width,
+                cursor_row,
+                theme,
+            );
+        }
+
+        // The cursor may be off screen until the animation lands
+        if is_active && state.show_cursors && !hide_cursor && !animating {
             if let Some((cursor_screen_x, cursor_screen_y)) = cursor {
                 // cursor_screen_x already includes gutter width from line_view_map
                 let screen_x = render_area.x.saturating_add(cursor_screen_x);
@@ -4087,6 +4598,128 @@ impl SplitRenderer {
         render_output.view_line_mappings
     }
 
+    /// Draw the headers of the scopes enclosing the first visible line over the
+    /// top rows of the content area
+    /// Draw vertical rulers at the configured text columns
+    ///
+    /// Rulers are drawn only over blank cells, so they pass behind text that
+    /// runs past them. Columns scrolled out of view are skipped.
+    fn render_rulers(
+        frame: &mut Frame,
+        render_area: Rect,
+        gutter_width: usize,
+        left_column: usize,
+        rows: usize,
+        rulers: &[usize],
+        theme: &crate::view::theme::Theme,
+    ) {
+        let content_x = render_area.x as usize + gutter_width;
+        let area_end = render_area.x as usize + render_area.width as usize;
+        let buf = frame.buffer_mut();
+        for &column in rulers {
+            let Some(x) = column
+                .checked_sub(left_column)
+                .map(|col| content_x + col)
+                .filter(|&x| x < area_end)
+            else {
+                continue;
+            };
+            for row in 0..rows {
+                let y = render_area.y + row as u16;
+                if let Some(cell) = buf.cell_mut((x as u16, y)) {
+                    if cell.symbol() == " " {
+                        cell.set_symbol("│");
+                        cell.set_fg(theme.ruler_fg);
+                    }
+                }
+            }
+        }
+    }
+
+    fn render_sticky_scroll(
+        frame: &mut Frame,
+        state: &mut EditorState,
+        top_byte: usize,
+        render_area: Rect,
+        gutter_width: usize,
+        cursor_row: Option<usize>,
+        theme: &crate::view::theme::Theme,
+    ) {
+        if top_byte == 0 || render_area.height < 2 {
+            return;
+        }
+
+        let scan_start = top_byte.saturating_sub(STICKY_SCROLL_SCAN_BYTES);
+        let preceding = state.get_text_range(scan_start, top_byte);
+        let mut headers = sticky_scope_line_starts(&preceding, MAX_STICKY_SCROLL_LINES);
+        // Leave at least one row of real content below the header
+        headers.truncate(render_area.height as usize - 1);
+        if headers.is_empty() || cursor_row.is_some_and(|row| row < headers.len()) {
+            return;
+        }
+
+        let style = Style::default()
+            .fg(theme.editor_fg)
+            .bg(theme.current_line_bg);
+        let gutter_style = Style::default()
+            .fg(theme.line_number_fg)
+            .bg(theme.current_line_bg);
+        let text_width = (render_area.width as usize).saturating_sub(gutter_width);
+        let tab_size = state.tab_size.max(1);
+
+        for (row, offset) in headers.into_iter().enumerate() {
+            let line_start = scan_start + offset;
+            let line_end = top_byte.min(line_start + STICKY_SCROLL_MAX_LINE_BYTES);
+            let line = state.get_text_range(line_start, line_end);
+            let line = line.lines().next().unwrap_or("");
+
+            let mut gutter = String::new();
+            if state.margins.left_config.enabled {
+                let line_num = state.buffer.get_line_number(line_start) + 1;
+                gutter = format!(
+                    " {:>width$}{}",
+                    line_num,
+                    state.margins.left_config.separator,
+                    width = state.margins.left_config.width
+                );
+            }
+            let gutter: String = format!("{:<gutter_width$}", gutter)
+                .chars()
+                .take(gutter_width)
+                .collect();
+
+            // Expand tabs and clip to the visible width
+            let mut text = String::new();
+            let mut col = 0;
+            for ch in line.chars() {
+                let (piece, w) = if ch == '\t' {
+                    let w = tab_size - col % tab_size;
+                    (" ".repeat(w), w)
+                } else {
+                    (ch.to_string(), char_width(ch))
+                };
+                if col + w > text_width {
+                    break;
+                }
+                text.push_str(&piece);
+                col += w;
+            }
+            text.push_str(&" ".repeat(text_width - col));
+
+            let row_area = Rect::new(
+                render_area.x,
+                render_area.y + row as u16,
+                render_area.width,
+                1,
+            );
+            let header = Line::from(vec![
+                Span::styled(gutter, gutter_style),
+                Span::styled(text, style),
+            ]);
+            frame.render_widget(Paragraph::new(header), row_area);
+        }
+    }
+
     /// Apply styles from original line_spans to a wrapped segment
     ///
     /// Maps each character in the segment text back to its original span to preserve
@@ -4210,6 +4843,7 @@ mod tests {
 
         let view_data = SplitRenderer::build_view_data(
             &mut state,
+            &mut None,
             &viewport,
             None,
             content.len().max(1),
@@ -4217,6 +4851,8 @@ mod tests {
             false, // line wrap disabled for tests
             render_area.width as usize,
             gutter_width,
+            true,
+            WhitespaceRender::default(),
         );
         let view_anchor = SplitRenderer::calculate_view_anchor(&view_data.lines, 0);
 
@@ -4243,6 +4879,7 @@ mod tests {
             selection.primary_cursor_position,
             &theme,
             100_000, // default highlight context bytes
+            true,
         );
 
         let output = SplitRenderer::render_view_lines(LineRenderInput {
@@ -4262,6 +4899,7 @@ mod tests {
             estimated_lines,
             left_column: viewport.left_column,
             relative_line_numbers: false,
+            show_indent_guides: false,
         });
 
         (
@@ -5445,4 +6083,185 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_sticky_scope_line_starts() {
+        let text =
+            "impl Foo {\n    fn bar() {\n        if x {\n            y();\n        }\n        ";
+        let starts = sticky_scope_line_starts(text, 2);
+        let lines: Vec<&str> = starts
+            .iter()
+            .map(|&s| text[s..].lines().next().unwrap())
+            .collect();
+        // The closed `if` block is skipped and only the two innermost scopes are kept
+        assert_eq!(lines, vec!["impl Foo {", "    fn bar() {"]);
+
+        // `} else {` closes one block and opens another on the same line
+        let text = "fn f() {\n    if a {\n    } else {\n        ";
+        let starts = sticky_scope_line_starts(text, 2);
+        assert_eq!(starts, vec![0, text.find("    } else").unwrap()]);
+
+        // A brace on its own line belongs to the line above
+        let text = "void f()\n{\n    ";
+        assert_eq!(sticky_scope_line_starts(text, 2), vec![0]);
+
+        assert!(sticky_scope_line_starts("a();\nb();\n", 2).is_empty());
+    }
+
+    /// Build the view of a 20-line viewport at `top_byte`, 20 columns wide when wrapping
+    fn build_source_view(
+        state: &mut EditorState,
+        cache: &mut Option<ViewLineCache>,
+        top_byte: usize,
+        line_wrap: bool,
+    ) -> Arc<Vec<ViewLine>> {
+        let mut viewport = Viewport::new(80, 20);
+        viewport.top_byte = top_byte;
+        SplitRenderer::build_view_data(
+            state,
+            cache,
+            &viewport,
+            None,
+            80,
+            20,
+            line_wrap,
+            20,
+            0,
+            true,
+            WhitespaceRender::default(),
+        )
+        .lines
+    }
+
+    fn assert_same_layout(
+        state: &mut EditorState,
+        lines: &[ViewLine],
+        top_byte: usize,
+        line_wrap: bool,
+    ) {
+        let full = build_source_view(state, &mut None, top_byte, line_wrap);
+        assert_eq!(lines.len(), full.len());
+        for (i, (line, expected)) in lines.iter().zip(full.iter()).enumerate() {
+            assert_eq!(line.text, expected.text, "line {i}");
+            assert_eq!(
+                line.char_source_bytes, expected.char_source_bytes,
+                "line {i}"
+            );
+            assert_eq!(
+                format!("{:?}", line.char_styles),
+                format!("{:?}", expected.char_styles),
+                "line {i}"
+            );
+            assert_eq!(line.char_visual_cols, expected.char_visual_cols, "line {i}");
+            assert_eq!(line.visual_to_char, expected.visual_to_char, "line {i}");
+            assert_eq!(line.tab_starts, expected.tab_starts, "line {i}");
+            assert_eq!(line.control_chars, expected.control_chars, "line {i}");
+            assert_eq!(
+                line.whitespace_glyphs, expected.whitespace_glyphs,
+                "line {i}"
+            );
+            assert_eq!(line.line_start, expected.line_start, "line {i}");
+            assert_eq!(
+                line.ends_with_newline, expected.ends_with_newline,
+                "line {i}"
+            );
+        }
+    }
+
+    fn rebuilt(cache: &Option<ViewLineCache>) -> Range<usize> {
+        cache.as_ref().unwrap().rebuilt.clone()
+    }
+
+    #[test]
+    fn test_build_view_data_lays_out_only_edited_lines() {
+        let content: String = (0..100).map(|i| format!("line {i}\n")).collect();
+        let mut state = EditorState::new(80, 20, 1024);
+        state.buffer = Buffer::from_str(&content, 1024);
+        let mut cache = None;
+
+        let lines = build_source_view(&mut state, &mut cache, 0, false);
+        assert_eq!(lines.len(), 24);
+        assert_eq!(rebuilt(&cache), 0..24);
+
+        // Typing on line 5 lays out line 5 alone
+        let line5 = content.find("line 5\n").unwrap();
+        state.buffer.insert_bytes(line5 + 4, b"!\t".to_vec());
+        let lines = build_source_view(&mut state, &mut cache, 0, false);
+        assert_eq!(rebuilt(&cache), 5..6);
+        assert_eq!(lines[5].text, "line!    5\n");
+        assert_same_layout(&mut state, &lines, 0, false);
+
+        // Splitting line 5 lays out its two halves; the last line is pushed out
+        state.buffer.insert_bytes(line5 + 5, b"\n".to_vec());
+        let lines = build_source_view(&mut state, &mut cache, 0, false);
+        assert_eq!(rebuilt(&cache), 5..7);
+        assert_same_layout(&mut state, &lines, 0, false);
+
+        // Joining them again fills in a line at the bottom
+        state.buffer.delete_bytes(line5 + 5, 1);
+        let lines = build_source_view(&mut state, &mut cache, 0, false);
+        assert_eq!(rebuilt(&cache).start, 5);
+        assert_same_layout(&mut state, &lines, 0, false);
+
+        // Edits below the viewport leave every line as it was
+        let line90 = state.buffer.len() - "line 99\n".len() * 10;
+        state.buffer.insert_bytes(line90, b"x".to_vec());
+        let lines = build_source_view(&mut state, &mut cache, 0, false);
+        assert_eq!(rebuilt(&cache), 0..0);
+        assert_same_layout(&mut state, &lines, 0, false);
+    }
+
+    #[test]
+    fn test_build_view_data_lays_out_only_edited_wrapped_rows() {
+        let content: String = (0..100)
+            .map(|i| format!("row {i:02} aaaaaaaaaa bbbbbbbbbb\n"))
+            .collect();
+        let mut state = EditorState::new(80, 20, 1024);
+        state.buffer = Buffer::from_str(&content, 1024);
+        let mut cache = None;
+
+        let lines = build_source_view(&mut state, &mut cache, 0, true);
+        assert_same_layout(&mut state, &lines, 0, true);
+        let row_count = |lines: &[ViewLine], first: usize| {
+            1 + lines[first + 1..]
+                .iter()
+                .take_while(|l| l.line_start.is_continuation())
+                .count()
+        };
+        assert_eq!(row_count(&lines, 0), 2);
+
+        // Lengthening line 5 adds a row to it and lays out only its rows
+        let line5 = content.find("row 05 ").unwrap();
+        let line5_end = line5 + content[line5..].find('\n').unwrap();
+        state
+            .buffer
+            .insert_bytes(line5_end, b" cccccccccc".to_vec());
+        let lines = build_source_view(&mut state, &mut cache, 0, true);
+        let rows = row_count(&lines, 10);
+        assert_eq!(rows, 3);
+        assert_eq!(rebuilt(&cache), 10..10 + rows);
+        assert_same_layout(&mut state, &lines, 0, true);
+    }
+
+    #[test]
+    fn test_build_view_data_cache_is_per_view() {
+        let content: String = (0..100).map(|i| format!("line {i}\n")).collect();
+        let mut state = EditorState::new(80, 20, 1024);
+        state.buffer = Buffer::from_str(&content, 1024);
+        let line3 = content.find("line 3\n").unwrap();
+        let mut top_cache = None;
+        let mut scrolled_cache = None;
+        build_source_view(&mut state, &mut top_cache, 0, false);
+        build_source_view(&mut state, &mut scrolled_cache, line3, false);
+
+        // Two views of the same buffer each keep their lines across an edit
+        let line5 = content.find("line 5\n").unwrap();
+        state.buffer.insert_bytes(line5, b"x".to_vec());
+        let lines = build_source_view(&mut state, &mut top_cache, 0, false);
+        assert_eq!(rebuilt(&top_cache), 5..6);
+        assert_same_layout(&mut state, &lines, 0, false);
+        let lines = build_source_view(&mut state, &mut scrolled_cache, line3, false);
+        assert_eq!(rebuilt(&scrolled_cache), 2..3);
+        assert_same_layout(&mut state, &lines, line3, false);
+    }
 }
@@ -3,9 +3,11 @@
 use std::path::Path;
 
 use crate::app::WarningLevel;
+use crate::config::{StatusBarConfig, StatusBarSegment};
 use crate::primitives::display_width::{char_width, str_width};
 use crate::state::EditorState;
 use crate::view::prompt::Prompt;
+use fresh_core::api::{StatusBarAlignment, StatusBarItemOptions};
 use ratatui::layout::Rect;
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
@@ -24,6 +26,56 @@ pub struct StatusBarLayout {
     pub line_ending_indicator: Option<(u16, u16, u16)>,
 }
 
+/// Maximum number of status bar items plugins can add
+pub const MAX_PLUGIN_STATUS_ITEMS: usize = 16;
+
+/// Maximum display width of a plugin status bar item (longer text is truncated)
+pub const MAX_PLUGIN_STATUS_ITEM_WIDTH: usize = 30;
+
+/// A status bar segment contributed by a plugin
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginStatusItem {
+    /// Identifier the plugin uses to update or remove the item
+    pub id: String,
+    /// Text to display, truncated to `MAX_PLUGIN_STATUS_ITEM_WIDTH`
+    pub text: String,
+    /// Side of the status bar the item is shown on
+    pub alignment: StatusBarAlignment,
+    /// Items with higher priority are shown first on their side
+    pub priority: i32,
+}
+
+impl PluginStatusItem {
+    /// Create an item, collapsing newlines and truncating the text to the width limit
+    pub fn new(id: String, text: &str, options: &StatusBarItemOptions) -> Self {
+        let text = text.replace(['\n', '\r'], " ");
+        let text = if str_width(&text) > MAX_PLUGIN_STATUS_ITEM_WIDTH {
+            let mut width = 0;
+            let truncated: String = text
+                .chars()
+                .take_while(|ch| {
+                    let w = char_width(*ch);
+                    if width + w < MAX_PLUGIN_STATUS_ITEM_WIDTH {
+                        width += w;
+                        true
+                    } else {
+                        false
+                    }
+                })
+                .collect();
+            format!("{}…", truncated)
+        } else {
+            text
+        };
+        Self {
+            id,
+            text,
+            alignment: options.alignment.unwrap_or_default(),
+            priority: options.priority.unwrap_or(0),
+        }
+    }
+}
+
 /// Status bar hover state for styling clickable indicators
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum StatusBarHover {
@@ -230,6 +282,418 @@ pub fn truncate_path(path: &Path, max_len: usize) -> TruncatedPath {
     }
 }
 
+/// Text of a rendered status bar segment
+struct SegmentText {
+    text: String,
+    style: Style,
+    /// Clickable indicator the text belongs to, for mouse hit testing
+    target: StatusBarHover,
+}
+
+impl SegmentText {
+    fn plain(text: String, style: Style) -> Self {
+        Self {
+            text,
+            style,
+            target: StatusBarHover::None,
+        }
+    }
+}
+
+/// Truncate segments to `max_width` columns, ending them with "..." when cut
+fn truncate_segments(parts: Vec<SegmentText>, max_width: usize, style: Style) -> Vec<SegmentText> {
+    let total_width: usize = parts.iter().map(|part| str_width(&part.text)).sum();
+    if total_width <= max_width {
+        return parts;
+    }
+    // Leave room for the ellipsis unless the space is too small for it
+    let truncate_at = if max_width > 3 {
+        max_width - 3
+    } else {
+        max_width
+    };
+    let mut width = 0;
+    let mut truncated = Vec::new();
+    for mut part in parts {
+        let text: String = part
+            .text
+            .chars()
+            .take_while(|ch| {
+                let w = char_width(*ch);
+                if width + w <= truncate_at {
+                    width += w;
+                    true
+                } else {
+                    false
+                }
+            })
+            .collect();
+        let complete = text.len() == part.text.len();
+        if !text.is_empty() {
+            part.text = text;
+            truncated.push(part);
+        }
+        if !complete {
+            break;
+        }
+    }
+    if max_width > 3 {
+        truncated.push(SegmentText::plain("...".to_string(), style));
+    }
+    truncated
+}
+
+/// Editor state the status bar segments are rendered from
+struct SegmentContext<'a> {
+    state: &'a mut EditorState,
+    status_message: &'a Option<String>,
+    plugin_status_message: &'a Option<String>,
+    lsp_status: &'a str,
+    theme: &'a crate::view::theme::Theme,
+    display_name: &'a str,
+    keybindings: &'a crate::input::keybindings::KeybindingResolver,
+    chord_state: &'a [(crossterm::event::KeyCode, crossterm::event::KeyModifiers)],
+    overwrite_mode: bool,
+    update_available: Option<&'a str>,
+    warning_level: WarningLevel,
+    general_warning_count: usize,
+    hover: StatusBarHover,
+    plugin_items: &'a [PluginStatusItem],
+    search_match: Option<(usize, usize)>,
+    cursor_details: bool,
+    chord_remaining: Option<std::time::Duration>,
+}
+
+impl SegmentContext<'_> {
+    /// Render a segment for one side of the status bar, empty when it has nothing to show
+    fn render(&mut self, segment: StatusBarSegment, side: StatusBarAlignment) -> Vec<SegmentText> {
+        match segment {
+            StatusBarSegment::Filename => vec![self.filename()],
+            StatusBarSegment::Cursor => vec![self.cursor()],
+            StatusBarSegment::CursorDetails => self.cursor_details().into_iter().collect(),
+            StatusBarSegment::Diagnostics => self.diagnostics().into_iter().collect(),
+            StatusBarSegment::CursorCount => self.cursor_count().into_iter().collect(),
+            StatusBarSegment::SearchMatch => self.search_match().into_iter().collect(),
+            StatusBarSegment::Overwrite => self.overwrite().into_iter().collect(),
+            StatusBarSegment::Plugins => self.plugins(side),
+            StatusBarSegment::Chord => self.chord().into_iter().collect(),
+            StatusBarSegment::Messages => self.messages(),
+            StatusBarSegment::Indentation => self.indentation().into_iter().collect(),
+            StatusBarSegment::LineEnding => vec![self.line_ending()],
+            StatusBarSegment::Lsp => self.lsp().into_iter().collect(),
+            StatusBarSegment::Warnings => self.warnings().into_iter().collect(),
+            StatusBarSegment::Update => self.update().into_iter().collect(),
+            StatusBarSegment::Palette => vec![self.palette()],
+            StatusBarSegment::WordCount => self.word_count().into_iter().collect(),
+        }
+    }
+
+    fn plain(&self, text: String) -> SegmentText {
+        SegmentText::plain(
+            text,
+            Style::default()
+                .fg(self.theme.status_bar_fg)
+                .bg(self.theme.status_bar_bg),
+        )
+    }
+
+    fn filename(&self) -> SegmentText {
+        let modified = if self.state.buffer.is_modified() {
+            " [+]"
+        } else {
+            ""
+        };
+        self.plain(format!("{}{modified}", self.display_name))
+    }
+
+    fn cursor(&mut self) -> SegmentText {
+        let cursor = *self.state.primary_cursor();
+        // Find the start of the line containing the cursor
+        let line_start = self
+            .state
+            .buffer
+            .line_iterator(cursor.position, 80)
+            .current_position();
+        let col = cursor.position.saturating_sub(line_start);
+        // Use cached line number from state
+        let line = self.state.primary_cursor_line_number.value();
+        // Line and column are 0-indexed internally, but displayed as 1-indexed (standard editor convention)
+        self.plain(format!("Ln {}, Col {}", line + 1, col + 1))
+    }
+
+    /// Byte offset, file size and line count.
+    /// Large files have no line count yet, so show how far through the file we are instead.
+    fn cursor_details(&self) -> Option<SegmentText> {
+        if !self.cursor_details {
+            return None;
+        }
+        let position = self.state.primary_cursor().position;
+        let total_bytes = self.state.buffer.total_bytes();
+        let details = match self.state.buffer.line_count() {
+            Some(lines) => t!(
+                "status.cursor_details",
+                byte = position,
+                total = total_bytes,
+                lines = lines
+            ),
+            None => t!(
+                "status.cursor_details_percent",
+                byte = position,
+                total = total_bytes,
+                percent = (position * 100).checked_div(total_bytes).unwrap_or(0)
+            ),
+        };
+        Some(self.plain(details.to_string()))
+    }
+
+    fn diagnostics(&self) -> Option<SegmentText> {
+        let mut error_count = 0;
+        let mut warning_count = 0;
+        let mut info_count = 0;
+
+        // Use the lsp-diagnostic namespace to identify diagnostic overlays
+        let diagnostic_ns = crate::services::lsp::diagnostics::lsp_diagnostic_namespace();
+        for overlay in self.state.overlays.all() {
+            if overlay.namespace.as_ref() == Some(&diagnostic_ns) {
+                // Check priority to determine severity
+                use crate::services::lsp::diagnostics::{ERROR_PRIORITY, WARNING_PRIORITY};
+                match overlay.priority {
+                    ERROR_PRIORITY => error_count += 1,
+                    WARNING_PRIORITY => warning_count += 1,
+                    _ => info_count += 1,
+                }
+            }
+        }
+
+        let mut parts = Vec::new();
+        if error_count > 0 {
+            parts.push(format!("E:{}", error_count));
+        }
+        if warning_count > 0 {
+            parts.push(format!("W:{}", warning_count));
+        }
+        if info_count > 0 {
+            parts.push(format!("I:{}", info_count));
+        }
+        (!parts.is_empty()).then(|| self.plain(parts.join(" ")))
+    }
+
+    fn cursor_count(&self) -> Option<SegmentText> {
+        let count = self.state.cursors.count();
+        (count > 1).then(|| self.plain(t!("status.cursors", count = count).to_string()))
+    }
+
+    fn search_match(&self) -> Option<SegmentText> {
+        self.search_match.map(|(current, total)| {
+            self.plain(t!("search.match_of", current = current, total = total).to_string())
+        })
+    }
+
+    /// Insert mode is the default, so only overwrite mode is shown
+    fn overwrite(&self) -> Option<SegmentText> {
+        self.overwrite_mode
+            .then(|| self.plain(t!("status.overwrite_indicator").to_string()))
+    }
+
+    fn plugins(&self, side: StatusBarAlignment) -> Vec<SegmentText> {
+        self.plugin_items
+            .iter()
+            .filter(|item| item.alignment == side)
+            .map(|item| self.plain(item.text.clone()))
+            .collect()
+    }
+
+    fn chord(&self) -> Option<SegmentText> {
+        if self.chord_state.is_empty() {
+            return None;
+        }
+        let chord_str = self
+            .chord_state
+            .iter()
+            .map(|(code, modifiers)| crate::input::keybindings::format_keybinding(code, modifiers))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let text = match self.chord_remaining {
+            Some(remaining) => {
+                let seconds = remaining.as_millis().div_ceil(1000);
+                format!(
+                    "[{} {}]",
+                    chord_str,
+                    t!("status.chord_remaining", seconds = seconds)
+                )
+            }
+            None => format!("[{}]", chord_str),
+        };
+        Some(self.plain(text))
+    }
+
+    fn messages(&self) -> Vec<SegmentText> {
+        [self.status_message, self.plugin_status_message]
+            .into_iter()
+            .flatten()
+            .filter(|msg| !msg.is_empty())
+            .map(|msg| self.plain(msg.clone()))
+            .collect()
+    }
+
+    /// Indentation detected or read from `.editorconfig` when the file was opened
+    fn indentation(&self) -> Option<SegmentText> {
+        if self.state.detected_indent_width.is_none() && !self.state.editorconfig_indent {
+            return None;
+        }
+        let width = self.state.tab_size;
+        let text = if self.state.use_tabs {
+            t!("status.indent_tabs", width = width)
+        } else {
+            t!("status.indent_spaces", width = width)
+        };
+        Some(self.plain(text.to_string()))
+    }
+
+    /// Line ending indicator (clickable to change format)
+    fn line_ending(&self) -> SegmentText {
+        let line_ending_name = self.state.buffer.line_ending().display_name();
+        let text = if self.state.buffer.has_mixed_line_endings() {
+            t!("status.line_ending_mixed", ending = line_ending_name).to_string()
+        } else {
+            line_ending_name.to_string()
+        };
+        let is_hovering = self.hover == StatusBarHover::LineEndingIndicator;
+        let (fg, bg) = if is_hovering {
+            (self.theme.menu_hover_fg, self.theme.menu_hover_bg)
+        } else {
+            (self.theme.status_bar_fg, self.theme.status_bar_bg)
+        };
+        let mut style = Style::default().fg(fg).bg(bg);
+        if is_hovering {
+            style = style.add_modifier(Modifier::UNDERLINED);
+        }
+        SegmentText {
+            text,
+            style,
+            target: StatusBarHover::LineEndingIndicator,
+        }
+    }
+
+    /// LSP indicator, with colored background if warning/error
+    fn lsp(&self) -> Option<SegmentText> {
+        if self.lsp_status.is_empty() {
+            return None;
+        }
+        let theme = self.theme;
+        let is_hovering = self.hover == StatusBarHover::LspIndicator;
+        let (fg, bg) = match (self.warning_level, is_hovering) {
+            (WarningLevel::Error, true) => (
+                theme.status_error_indicator_hover_fg,
+                theme.status_error_indicator_hover_bg,
+            ),
+            (WarningLevel::Error, false) => (
+                theme.status_error_indicator_fg,
+                theme.status_error_indicator_bg,
+            ),
+            (WarningLevel::Warning, true) => (
+                theme.status_warning_indicator_hover_fg,
+                theme.status_warning_indicator_hover_bg,
+            ),
+            (WarningLevel::Warning, false) => (
+                theme.status_warning_indicator_fg,
+                theme.status_warning_indicator_bg,
+            ),
+            (WarningLevel::None, _) => (theme.status_bar_fg, theme.status_bar_bg),
+        };
+        let mut style = Style::default().fg(fg).bg(bg);
+        if is_hovering && self.warning_level != WarningLevel::None {
+            style = style.add_modifier(Modifier::UNDERLINED);
+        }
+        Some(SegmentText {
+            text: self.lsp_status.to_string(),
+            style,
+            target: StatusBarHover::LspIndicator,
+        })
+    }
+
+    /// General warning badge
+    fn warnings(&self) -> Option<SegmentText> {
+        if self.general_warning_count == 0 {
+            return None;
+        }
+        let is_hovering = self.hover == StatusBarHover::WarningBadge;
+        let (fg, bg) = if is_hovering {
+            (
+                self.theme.status_warning_indicator_hover_fg,
+                self.theme.status_warning_indicator_hover_bg,
+            )
+        } else {
+            (
+                self.theme.status_warning_indicator_fg,
+                self.theme.status_warning_indicator_bg,
+            )
+        };
+        let mut style = Style::default().fg(fg).bg(bg);
+        if is_hovering {
+            style = style.add_modifier(Modifier::UNDERLINED);
+        }
+        Some(SegmentText {
+            text: format!("[⚠ {}]", self.general_warning_count),
+            style,
+            target: StatusBarHover::WarningBadge,
+        })
+    }
+
+    fn update(&self) -> Option<SegmentText> {
+        self.update_available.map(|version| {
+            SegmentText::plain(
+                t!("status.update_available", version = version).to_string(),
+                Style::default()
+                    .fg(self.theme.menu_highlight_fg)
+                    .bg(self.theme.menu_dropdown_bg),
+            )
+        })
+    }
+
+    /// Counts for the selection, or the whole buffer when nothing is selected.
+    /// Large files are only counted for a selection, as counting would load the whole file.
+    fn word_count(&mut self) -> Option<SegmentText> {
+        let selected = self.state.cursors.primary().selection_range().is_some();
+        if !selected && self.state.buffer.is_large_file() {
+            return None;
+        }
+        let stats = self.state.text_stats();
+        let text = if selected {
+            t!(
+                "status.word_count_selection",
+                lines = stats.lines,
+                words = stats.words,
+                chars = stats.chars
+            )
+        } else {
+            t!(
+                "status.word_count",
+                lines = stats.lines,
+                words = stats.words,
+                chars = stats.chars
+            )
+        };
+        Some(self.plain(text.to_string()))
+    }
+
+    fn palette(&self) -> SegmentText {
+        let shortcut = self
+            .keybindings
+            .get_keybinding_for_action(
+                &crate::input::keybindings::Action::CommandPalette,
+                crate::input::keybindings::KeyContext::Global,
+            )
+            .unwrap_or_else(|| "?".to_string());
+        SegmentText::plain(
+            t!("status.palette", shortcut = shortcut).to_string(),
+            Style::default()
+                .fg(self.theme.help_indicator_fg)
+                .bg(self.theme.help_indicator_bg),
+        )
+    }
+}
+
 /// Renders the status bar and prompt/minibuffer
 pub struct StatusBarRenderer;
 
@@ -248,6 +712,11 @@ impl StatusBarRenderer {
     /// * `update_available` - Optional new version string if an update is available
     /// * `warning_level` - LSP warning level (for coloring LSP indicator)
     /// * `general_warning_count` - Number of general warnings (for badge display)
+    /// * `plugin_items` - Plugin status bar items, in display order
+    /// * `search_match` - Current search match (1-based) and total match count, if searching
+    /// * `cursor_details` - Whether to show the cursor byte offset and file size
+    /// * `chord_remaining` - Time left before the pending chord times out, if configured
+    /// * `segments` - Segments to show on each side, in order
     ///
     /// # Returns
     /// Layout information with positions of clickable indicators
@@ -263,10 +732,16 @@ impl StatusBarRenderer {
         display_name: &str,
         keybindings: &crate::input::keybindings::KeybindingResolver,
         chord_state: &[(crossterm::event::KeyCode, crossterm::event::KeyModifiers)],
+        overwrite_mode: bool,
         update_available: Option<&str>,
         warning_level: WarningLevel,
         general_warning_count: usize,
         hover: StatusBarHover,
+        plugin_items: &[PluginStatusItem],
+        search_match: Option<(usize, usize)>,
+        cursor_details: bool,
+        chord_remaining: Option<std::time::Duration>,
+        segments: &StatusBarConfig,
     ) -> StatusBarLayout {
         Self::render_status(
             frame,
@@ -279,10 +754,16 @@ impl StatusBarRenderer {
             display_name,
             keybindings,
             chord_state,
+            overwrite_mode,
             update_available,
             warning_level,
             general_warning_count,
             hover,
+            plugin_items,
+            search_match,
+            cursor_details,
+            chord_remaining,
+            segments,
         )
     }
 
@@ -461,415 +942,117 @@ impl StatusBarRenderer {
         display_name: &str,
         keybindings: &crate::input::keybindings::KeybindingResolver,
         chord_state: &[(crossterm::event::KeyCode, crossterm::event::KeyModifiers)],
+        overwrite_mode: bool,
         update_available: Option<&str>,
         warning_level: WarningLevel,
         general_warning_count: usize,
         hover: StatusBarHover,
+        plugin_items: &[PluginStatusItem],
+        search_match: Option<(usize, usize)>,
+        cursor_details: bool,
+        chord_remaining: Option<std::time::Duration>,
+        segments: &StatusBarConfig,
     ) -> StatusBarLayout {
-        // Initialize layout tracking
-        let mut layout = StatusBarLayout::default();
-        // Use the pre-computed display name from buffer metadata
-        let filename = display_name;
-
-        let modified = if state.buffer.is_modified() {
-            " [+]"
-        } else {
-            ""
-        };
-
-        // Format chord state if present
-        let chord_display = if !chord_state.is_empty() {
-            let chord_str = chord_state
-                .iter()
-                .map(|(code, modifiers)| {
-                    crate::input::keybindings::format_keybinding(code, modifiers)
-                })
-                .collect::<Vec<_>>()
-                .join(" ");
-            format!(" [{}]", chord_str)
-        } else {
-            String::new()
-        };
-
-        // View mode indicator
-        let _mode_label = match state.view_mode {
-            crate::state::ViewMode::Compose => " | Compose",
-            _ => "",
-        };
-
-        let cursor = *state.primary_cursor();
-
-        // Get line number and column efficiently using cached values
-        let (line, col) = {
-            // Find the start of the line containing the cursor
-            let cursor_iter = state.buffer.line_iterator(cursor.position, 80);
-            let line_start = cursor_iter.current_position();
-            let col = cursor.position.saturating_sub(line_start);
-
-            // Use cached line number from state
-            let line_num = state.primary_cursor_line_number.value();
-            (line_num, col)
+        let mut context = SegmentContext {
+            state,
+            status_message,
+            plugin_status_message,
+            lsp_status,
+            theme,
+            display_name,
+            keybindings,
+            chord_state,
+            overwrite_mode,
+            update_available,
+            warning_level,
+            general_warning_count,
+            hover,
+            plugin_items,
+            search_match,
+            cursor_details,
+            chord_remaining,
         };
+        let left: Vec<SegmentText> = segments
+            .left
+            .iter()
+            .flat_map(|segment| context.render(*segment, StatusBarAlignment::Left))
+            .collect();
+        let right: Vec<SegmentText> = segments
+            .right
+            .iter()
+            .flat_map(|segment| context.render(*segment, StatusBarAlignment::Right))
+            .collect();
 
-        // Count diagnostics by severity
-        let diagnostics = state.overlays.all();
-        let mut error_count = 0;
-        let mut warning_count = 0;
-        let mut info_count = 0;
+        let mut layout = StatusBarLayout::default();
+        let base_style = Style::default()
+            .fg(theme.status_bar_fg)
+            .bg(theme.status_bar_bg);
+        let available_width = area.width as usize;
 
-        // Use the lsp-diagnostic namespace to identify diagnostic overlays
-        let diagnostic_ns = crate::services::lsp::diagnostics::lsp_diagnostic_namespace();
-        for overlay in diagnostics {
-            if overlay.namespace.as_ref() == Some(&diagnostic_ns) {
-                // Check priority to determine severity
-                // Based on lsp_diagnostics.rs: Error=100, Warning=50, Info=30, Hint=10
-                match overlay.priority {
-                    100 => error_count += 1,
-                    50 => warning_count += 1,
-                    _ => info_count += 1,
-                }
+        // Left segments are separated by " | ", right segments are padded with a space
+        let mut left_parts = Vec::new();
+        for (i, part) in left.into_iter().enumerate() {
+            if i > 0 {
+                left_parts.push(SegmentText::plain(" | ".to_string(), base_style));
             }
+            left_parts.push(part);
         }
-
-        // Build diagnostics summary if there are any
-        let diagnostics_summary = if error_count + warning_count + info_count > 0 {
-            let mut parts = Vec::new();
-            if error_count > 0 {
-                parts.push(format!("E:{}", error_count));
-            }
-            if warning_count > 0 {
-                parts.push(format!("W:{}", warning_count));
-            }
-            if info_count > 0 {
-                parts.push(format!("I:{}", info_count));
-            }
-            format!(" | {}", parts.join(" "))
+        // The right side is only shown if there's enough space (at least 15 chars for minimal display)
+        let right_parts: Vec<SegmentText> = if available_width >= 15 {
+            right
+                .into_iter()
+                .map(|part| SegmentText {
+                    text: format!(" {} ", part.text),
+                    ..part
+                })
+                .collect()
         } else {
-            String::new()
+            Vec::new()
         };
+        let right_side_width: usize = right_parts.iter().map(|part| str_width(&part.text)).sum();
 
-        // Build cursor count indicator (only show if multiple cursors)
-        let cursor_count_indicator = if state.cursors.count() > 1 {
-            format!(" | {}", t!("status.cursors", count = state.cursors.count()))
+        // Reserve space for the right side, keeping at least one space before it
+        let left_max_width = if right_parts.is_empty() {
+            available_width
+        } else if available_width > right_side_width + 1 {
+            available_width - right_side_width - 1
         } else {
-            String::new()
+            1 // Minimal space
         };
+        let left_parts = truncate_segments(left_parts, left_max_width, base_style);
+        let left_width: usize = left_parts.iter().map(|part| str_width(&part.text)).sum();
 
-        // Build status message parts
-        let mut message_parts: Vec<&str> = Vec::new();
-        if let Some(msg) = status_message {
-            if !msg.is_empty() {
-                message_parts.push(msg);
-            }
-        }
-        if let Some(msg) = plugin_status_message {
-            if !msg.is_empty() {
-                message_parts.push(msg);
+        let mut spans = Vec::new();
+        let mut current_col = area.x;
+        let mut push_part = |part: SegmentText, spans: &mut Vec<Span<'static>>| {
+            let width = str_width(&part.text) as u16;
+            let position = Some((area.y, current_col, current_col + width));
+            match part.target {
+                StatusBarHover::LspIndicator => layout.lsp_indicator = position,
+                StatusBarHover::WarningBadge => layout.warning_badge = position,
+                StatusBarHover::LineEndingIndicator => layout.line_ending_indicator = position,
+                StatusBarHover::None => {}
             }
-        }
-
-        let message_suffix = if message_parts.is_empty() {
-            String::new()
-        } else {
-            format!(" | {}", message_parts.join(" | "))
+            current_col += width;
+            spans.push(Span::styled(part.text, part.style));
         };
+        for part in left_parts {
+            push_part(part, &mut spans);
+        }
 
-        // Build left status (file info, position, diagnostics, messages)
-        // Line and column are 0-indexed internally, but displayed as 1-indexed (standard editor convention)
-        let base_status = format!(
-            "{filename}{modified} | Ln {}, Col {}{diagnostics_summary}{cursor_count_indicator}",
-            line + 1,
-            col + 1
-        );
-
-        let left_status = format!("{base_status}{chord_display}{message_suffix}");
-
-        // Build right-side indicators (these stay fixed on the right)
-        // Order: [Line ending] [LSP indicator] [warning badge] [update] [Palette]
-
-        // Line ending indicator (clickable to change format)
-        let line_ending_text = format!(" {} ", state.buffer.line_ending().display_name());
-        let line_ending_width = str_width(&line_ending_text);
-
-        // LSP indicator (right-aligned, with colored background if warning/error)
-        let lsp_indicator = if !lsp_status.is_empty() {
-            format!(" {} ", lsp_status)
-        } else {
-            String::new()
-        };
-        let lsp_indicator_width = str_width(&lsp_indicator);
-
-        // General warning badge (right-aligned)
-        let warning_badge = if general_warning_count > 0 {
-            format!(" [⚠ {}] ", general_warning_count)
-        } else {
-            String::new()
-        };
-        let warning_badge_width = str_width(&warning_badge);
-
-        // Build update indicator for right side (if update available)
-        let update_indicator = update_available
-            .map(|version| format!(" {} ", t!("status.update_available", version = version)));
-        let update_width = update_indicator.as_ref().map(|s| s.len()).unwrap_or(0);
-
-        // Build Command Palette indicator for right side
-        // Always show Command Palette indicator on the right side
-        let cmd_palette_shortcut = keybindings
-            .get_keybinding_for_action(
-                &crate::input::keybindings::Action::CommandPalette,
-                crate::input::keybindings::KeyContext::Global,
-            )
-            .unwrap_or_else(|| "?".to_string());
-        let cmd_palette_indicator = t!("status.palette", shortcut = cmd_palette_shortcut);
-        let padded_cmd_palette = format!(" {} ", cmd_palette_indicator);
-
-        // Calculate available width and right side width
-        // Right side: [Line ending] [LSP indicator] [warning badge] [update] [Palette]
-        let available_width = area.width as usize;
-        let cmd_palette_width = str_width(&padded_cmd_palette);
-        let right_side_width = line_ending_width
-            + lsp_indicator_width
-            + warning_badge_width
-            + update_width
-            + cmd_palette_width;
-
-        // Only show command palette indicator if there's enough space (at least 15 chars for minimal display)
-        let spans = if available_width >= 15 {
-            // Reserve space for right side indicators
-            let left_max_width = if available_width > right_side_width + 1 {
-                available_width - right_side_width - 1 // -1 for at least one space separator
-            } else {
-                1 // Minimal space
-            };
-
-            let mut spans = vec![];
-
-            // Truncate left status if it's too long (use visual width, not char count)
-            let left_visual_width = str_width(&left_status);
-            let displayed_left = if left_visual_width > left_max_width {
-                let truncate_at = left_max_width.saturating_sub(3); // -3 for "..."
-                if truncate_at > 0 {
-                    // Take characters up to visual width limit
-                    let mut width = 0;
-                    let truncated: String = left_status
-                        .chars()
-                        .take_while(|ch| {
-                            let w = char_width(*ch);
-                            if width + w <= truncate_at {
-                                width += w;
-                                true
-                            } else {
-                                false
-                            }
-                        })
-                        .collect();
-                    format!("{}...", truncated)
-                } else {
-                    String::from("...")
-                }
-            } else {
-                left_status.clone()
-            };
-
-            let displayed_left_len = str_width(&displayed_left);
-
-            spans.push(Span::styled(
-                displayed_left.clone(),
-                Style::default()
-                    .fg(theme.status_bar_fg)
-                    .bg(theme.status_bar_bg),
-            ));
-
-            // Add spacing to push right side indicators to the right
-            if displayed_left_len + right_side_width < available_width {
-                let padding_len = available_width - displayed_left_len - right_side_width;
-                spans.push(Span::styled(
-                    " ".repeat(padding_len),
-                    Style::default()
-                        .fg(theme.status_bar_fg)
-                        .bg(theme.status_bar_bg),
-                ));
-            } else if displayed_left_len < available_width {
-                // Add minimal space
-                spans.push(Span::styled(
-                    " ",
-                    Style::default()
-                        .fg(theme.status_bar_fg)
-                        .bg(theme.status_bar_bg),
-                ));
-            }
-
-            // Track current column for layout positions
-            let mut current_col = area.x + displayed_left_len as u16;
-            if displayed_left_len + right_side_width < available_width {
-                current_col = area.x + (available_width - right_side_width) as u16;
-            }
-
-            // Add line ending indicator (clickable to change format)
-            {
-                let is_hovering = hover == StatusBarHover::LineEndingIndicator;
-                // Record position for click detection
-                layout.line_ending_indicator =
-                    Some((area.y, current_col, current_col + line_ending_width as u16));
-                let (fg, bg) = if is_hovering {
-                    (theme.menu_hover_fg, theme.menu_hover_bg)
-                } else {
-                    (theme.status_bar_fg, theme.status_bar_bg)
-                };
-                let mut style = Style::default().fg(fg).bg(bg);
-                if is_hovering {
-                    style = style.add_modifier(Modifier::UNDERLINED);
-                }
-                spans.push(Span::styled(line_ending_text.clone(), style));
-                current_col += line_ending_width as u16;
-            }
-
-            // Add LSP indicator with colored background if warning/error
-            if !lsp_indicator.is_empty() {
-                let is_hovering = hover == StatusBarHover::LspIndicator;
-                let (lsp_fg, lsp_bg) = match (warning_level, is_hovering) {
-                    (WarningLevel::Error, true) => (
-                        theme.status_error_indicator_hover_fg,
-                        theme.status_error_indicator_hover_bg,
-                    ),
-                    (WarningLevel::Error, false) => (
-                        theme.status_error_indicator_fg,
-                        theme.status_error_indicator_bg,
-                    ),
-                    (WarningLevel::Warning, true) => (
-                        theme.status_warning_indicator_hover_fg,
-                        theme.status_warning_indicator_hover_bg,
-                    ),
-                    (WarningLevel::Warning, false) => (
-                        theme.status_warning_indicator_fg,
-                        theme.status_warning_indicator_bg,
-                    ),
-                    (WarningLevel::None, _) => (theme.status_bar_fg, theme.status_bar_bg),
-                };
-                // Record LSP indicator position for click detection
-                layout.lsp_indicator = Some((
-                    area.y,
-                    current_col,
-                    current_col + lsp_indicator_width as u16,
-                ));
-                current_col += lsp_indicator_width as u16;
-                let mut style = Style::default().fg(lsp_fg).bg(lsp_bg);
-                if is_hovering && warning_level != WarningLevel::None {
-                    style = style.add_modifier(Modifier::UNDERLINED);
-                }
-                spans.push(Span::styled(lsp_indicator.clone(), style));
-            }
-
-            // Add general warning badge if there are warnings
-            if !warning_badge.is_empty() {
-                let is_hovering = hover == StatusBarHover::WarningBadge;
-                // Record warning badge position for click detection
-                layout.warning_badge = Some((
-                    area.y,
-                    current_col,
-                    current_col + warning_badge_width as u16,
-                ));
-                current_col += warning_badge_width as u16;
-                let (fg, bg) = if is_hovering {
-                    (
-                        theme.status_warning_indicator_hover_fg,
-                        theme.status_warning_indicator_hover_bg,
-                    )
-                } else {
-                    (
-                        theme.status_warning_indicator_fg,
-                        theme.status_warning_indicator_bg,
-                    )
-                };
-                let mut style = Style::default().fg(fg).bg(bg);
-                if is_hovering {
-                    style = style.add_modifier(Modifier::UNDERLINED);
-                }
-                spans.push(Span::styled(warning_badge.clone(), style));
-            }
-            // Keep current_col in scope to avoid unused warning
-            let _ = current_col;
-
-            // Add update indicator if available (with highlighted styling)
-            if let Some(ref update_text) = update_indicator {
-                spans.push(Span::styled(
-                    update_text.clone(),
-                    Style::default()
-                        .fg(theme.menu_highlight_fg)
-                        .bg(theme.menu_dropdown_bg),
-                ));
-            }
-
-            // Add command palette indicator with distinct styling and padding
-            spans.push(Span::styled(
-                padded_cmd_palette.clone(),
-                Style::default()
-                    .fg(theme.help_indicator_fg)
-                    .bg(theme.help_indicator_bg),
-            ));
-
-            spans
-        } else {
-            // Terminal too narrow or no command palette indicator - fill entire width with left status
-            let mut spans = vec![];
-            let left_visual_width = str_width(&left_status);
-            let displayed_left = if left_visual_width > available_width {
-                let truncate_at = available_width.saturating_sub(3);
-                if truncate_at > 0 {
-                    // Take characters up to visual width limit
-                    let mut width = 0;
-                    let truncated: String = left_status
-                        .chars()
-                        .take_while(|ch| {
-                            let w = char_width(*ch);
-                            if width + w <= truncate_at {
-                                width += w;
-                                true
-                            } else {
-                                false
-                            }
-                        })
-                        .collect();
-                    format!("{}...", truncated)
-                } else {
-                    // Take characters up to available width
-                    let mut width = 0;
-                    left_status
-                        .chars()
-                        .take_while(|ch| {
-                            let w = char_width(*ch);
-                            if width + w <= available_width {
-                                width += w;
-                                true
-                            } else {
-                                false
-                            }
-                        })
-                        .collect()
-                }
-            } else {
-                left_status.clone()
-            };
-
-            spans.push(Span::styled(
-                displayed_left.clone(),
-                Style::default()
-                    .fg(theme.status_bar_fg)
-                    .bg(theme.status_bar_bg),
-            ));
-
-            // Fill remaining width
-            if displayed_left.len() < available_width {
-                spans.push(Span::styled(
-                    " ".repeat(available_width - displayed_left.len()),
-                    Style::default()
-                        .fg(theme.status_bar_fg)
-                        .bg(theme.status_bar_bg),
-                ));
-            }
-
-            spans
-        };
+        // Add spacing to push right side indicators to the right
+        let padding_len = available_width.saturating_sub(left_width + right_side_width);
+        if padding_len > 0 {
+            push_part(
+                SegmentText::plain(" ".repeat(padding_len), base_style),
+                &mut spans,
+            );
+        } else if left_width < available_width {
+            push_part(SegmentText::plain(" ".to_string(), base_style), &mut spans);
+        }
+        for part in right_parts {
+            push_part(part, &mut spans);
+        }
 
         let status_line = Paragraph::new(Line::from(spans));
 
@@ -881,7 +1064,7 @@ impl StatusBarRenderer {
     /// Render the search options bar (shown when search prompt is active)
     ///
     /// Displays checkboxes for search options with their keyboard shortcuts:
-    /// - Case Sensitive (Alt+C)
+    /// - Case Sensitive (Alt+C), marked "(Smart)" when `smart_case` applies
     /// - Whole Word (Alt+W)
     /// - Regex (Alt+R)
     /// - Confirm Each (Alt+I) - only shown in replace mode
@@ -893,6 +1076,7 @@ impl StatusBarRenderer {
         frame: &mut Frame,
         area: Rect,
         case_sensitive: bool,
+        smart_case: bool,
         whole_word: bool,
         use_regex: bool,
         confirm_each: Option<bool>, // None = don't show, Some(value) = show with this state
@@ -976,7 +1160,16 @@ impl StatusBarRenderer {
         // Case Sensitive option
         let case_hovered = hover == SearchOptionsHover::CaseSensitive;
         let case_start = current_col;
-        let case_label = format!("{} {}", case_checkbox, t!("search.case_sensitive"));
+        let case_label = if smart_case {
+            format!(
+                "{} {} ({})",
+                case_checkbox,
+                t!("search.case_sensitive"),
+                t!("search.smart_case")
+            )
+        } else {
+            format!("{} {}", case_checkbox, t!("search.case_sensitive"))
+        };
         let case_shortcut_text = case_shortcut
             .as_ref()
             .map(|s| format!(" ({})", s))
@@ -1205,4 +1398,28 @@ mod tests {
 
         assert_eq!(truncated.to_string_plain(), "/home/user/project");
     }
+
+    #[test]
+    fn test_plugin_status_item_truncates_long_text() {
+        let text = "x".repeat(MAX_PLUGIN_STATUS_ITEM_WIDTH + 10);
+        let item = PluginStatusItem::new("id".into(), &text, &StatusBarItemOptions::default());
+
+        assert_eq!(str_width(&item.text), MAX_PLUGIN_STATUS_ITEM_WIDTH);
+        assert!(item.text.ends_with('…'));
+        assert_eq!(item.alignment, StatusBarAlignment::Left);
+        assert_eq!(item.priority, 0);
+    }
+
+    #[test]
+    fn test_plugin_status_item_collapses_newlines() {
+        let options = StatusBarItemOptions {
+            alignment: Some(StatusBarAlignment::Right),
+            priority: Some(5),
+        };
+        let item = PluginStatusItem::new("id".into(), "a\nb", &options);
+
+        assert_eq!(item.text, "a b");
+        assert_eq!(item.alignment, StatusBarAlignment::Right);
+        assert_eq!(item.priority, 5);
+    }
 }
@@ -23,7 +23,16 @@
 use crate::primitives::ansi::AnsiParser;
 use crate::primitives::display_width::char_width;
 use fresh_core::api::{ViewTokenStyle, ViewTokenWire, ViewTokenWireKind};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+
+/// Which spaces and tabs from the source are drawn as visible glyphs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WhitespaceRender {
+    /// Every space and tab
+    pub all: bool,
+    /// Spaces and tabs at the end of a source line
+    pub trailing: bool,
+}
 
 /// A display line built from tokens, preserving token-level information
 #[derive(Debug, Clone)]
@@ -48,6 +57,11 @@ pub struct ViewLine {
 
     /// Positions that are the start of a tab expansion
     pub tab_starts: HashSet<usize>,
+    /// Positions of characters that depict a control character or invalid byte
+    pub control_chars: HashSet<usize>,
+    /// Glyphs drawn in place of rendered whitespace, by character position
+    /// (`·` for a space, `→` at the start of a tab)
+    pub whitespace_glyphs: HashMap<usize, char>,
     /// How this line started (what kind of token/boundary preceded it)
     pub line_start: LineStart,
     /// Whether this line ends with a newline character
@@ -123,6 +137,11 @@ pub struct ViewLineIterator<'a> {
     next_line_start: LineStart,
     /// Whether to render in binary mode (unprintable chars shown as code points)
     binary_mode: bool,
+    /// Whether to show control chars as `^X` and invalid bytes as U+FFFD
+    /// instead of `<XX>` (ignored in binary mode)
+    caret_notation: bool,
+    /// Which whitespace to mark for drawing as glyphs (ignored in binary mode)
+    whitespace: WhitespaceRender,
     /// Whether to parse ANSI escape sequences (giving them zero visual width)
     ansi_aware: bool,
     /// Tab width for rendering (number of spaces per tab)
@@ -153,11 +172,83 @@ impl<'a> ViewLineIterator<'a> {
             token_idx: 0,
             next_line_start: LineStart::Beginning,
             binary_mode,
+            caret_notation: false,
+            whitespace: WhitespaceRender::default(),
             ansi_aware,
             tab_size,
         }
     }
 
+    /// Show control chars in caret notation (`^M`) and invalid UTF-8 bytes as
+    /// U+FFFD rather than as `<XX>` code points
+    pub fn with_caret_notation(mut self, caret_notation: bool) -> Self {
+        self.caret_notation = caret_notation;
+        self
+    }
+
+    /// Mark spaces and tabs from the source to be drawn as `·` and `→`
+    pub fn with_whitespace(mut self, whitespace: WhitespaceRender) -> Self {
+        self.whitespace = whitespace;
+        self
+    }
+
+    /// Glyphs for the source whitespace of a line that should be visible
+    ///
+    /// Only the first column of an expanded tab gets a glyph. Trailing
+    /// whitespace is the run before a source newline (or the end of the
+    /// text); a line that is wrapped or ended by injected content has none.
+    fn whitespace_glyphs(
+        &self,
+        text: &str,
+        char_source_bytes: &[Option<usize>],
+        tab_starts: &HashSet<usize>,
+        ends_source_line: bool,
+    ) -> HashMap<usize, char> {
+        let trailing = self.whitespace.trailing && ends_source_line;
+        if self.binary_mode || !(self.whitespace.all || trailing) {
+            return HashMap::new();
+        }
+
+        let chars: Vec<char> = text.chars().collect();
+        let is_whitespace = |idx: usize| chars[idx] == ' ' && char_source_bytes[idx].is_some();
+        let end = chars.len() - usize::from(chars.last() == Some(&'\n'));
+        let start = if self.whitespace.all {
+            0
+        } else {
+            (0..end)
+                .rev()
+                .take_while(|&idx| is_whitespace(idx))
+                .last()
+                .unwrap_or(end)
+        };
+
+        (start..end)
+            .filter(|&idx| is_whitespace(idx))
+            .filter_map(|idx| {
+                if tab_starts.contains(&idx) {
+                    Some((idx, '→'))
+                } else if idx > 0 && char_source_bytes[idx - 1] == char_source_bytes[idx] {
+                    // Remaining columns of an expanded tab
+                    None
+                } else {
+                    Some((idx, '·'))
+                }
+            })
+            .collect()
+    }
+
+    /// Display form of a `BinaryByte` token
+    fn format_binary_byte(&self, b: u8) -> String {
+        if !self.caret_notation || self.binary_mode {
+            return format_unprintable_byte(b);
+        }
+        match b {
+            0x7F => "^?".to_string(),
+            0x00..=0x1F => format!("^{}", (b ^ 0x40) as char),
+            _ => '\u{FFFD}'.to_string(),
+        }
+    }
+
     /// Expand a tab to spaces based on current column and configured tab_size
     #[inline]
     fn tab_expansion_width(&self, col: usize) -> usize {
@@ -209,6 +300,7 @@ impl<'a> Iterator for ViewLineIterator<'a> {
         let mut visual_to_char: Vec<usize> = Vec::new();
 
         let mut tab_starts = HashSet::new();
+        let mut control_chars = HashSet::new();
         let mut col = 0usize; // Current visual column
         let mut ends_with_newline = false;
 
@@ -388,9 +480,10 @@ impl<'a> Iterator for ViewLineIterator<'a> {
                     break;
                 }
                 ViewTokenWireKind::BinaryByte(b) => {
-                    // Binary byte rendered as <XX> - all 4 chars map to same source byte
-                    let formatted = format_unprintable_byte(*b);
+                    // Binary byte rendered as <XX>, ^X or U+FFFD - all chars map to same source byte
+                    let formatted = self.format_binary_byte(*b);
                     for display_ch in formatted.chars() {
+                        control_chars.insert(char_source_bytes.len());
                         add_char!(display_ch, token.source_offset, token_style.clone(), 1);
                     }
                     self.token_idx += 1;
@@ -406,6 +499,11 @@ impl<'a> Iterator for ViewLineIterator<'a> {
             return None;
         }
 
+        let ends_source_line =
+            !ends_with_newline || self.next_line_start == LineStart::AfterSourceNewline;
+        let whitespace_glyphs =
+            self.whitespace_glyphs(&text, &char_source_bytes, &tab_starts, ends_source_line);
+
         Some(ViewLine {
             text,
             char_source_bytes,
@@ -413,6 +511,8 @@ impl<'a> Iterator for ViewLineIterator<'a> {
             char_visual_cols,
             visual_to_char,
             tab_starts,
+            control_chars,
+            whitespace_glyphs,
             line_start,
             ends_with_newline,
         })
@@ -458,6 +558,69 @@ pub fn should_show_line_number(line: &ViewLine) -> bool {
     true
 }
 
+/// The first source byte of every source line in `lines`, with the index of
+/// the view line it starts on
+///
+/// Wrapped continuations belong to the source line before them. Returns None
+/// if a source line has no source bytes (injected content or an empty buffer).
+pub fn source_line_starts(lines: &[ViewLine]) -> Option<Vec<(usize, usize)>> {
+    lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !line.line_start.is_continuation())
+        .map(|(idx, line)| Some((idx, line.char_source_bytes.iter().find_map(|m| *m)?)))
+        .collect()
+}
+
+/// Move the source bytes of `lines` by `delta`, for lines after an edit
+pub fn shift_source_bytes(lines: &mut [ViewLine], delta: isize) {
+    for line in lines {
+        for byte in line.char_source_bytes.iter_mut().flatten() {
+            *byte = byte.saturating_add_signed(delta);
+        }
+    }
+}
+
+/// Inputs that source lines are laid out with, besides the buffer content
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ViewLineCacheKey {
+    pub top_byte: usize,
+    pub visible_count: usize,
+    pub estimated_line_length: usize,
+    pub wrap_width: usize,
+    pub gutter_width: usize,
+    pub tab_size: usize,
+    pub caret_control_chars: bool,
+    pub render_whitespace: WhitespaceRender,
+    pub line_ending: crate::model::buffer::LineEnding,
+}
+
+/// The source lines of the last viewport a split built for its buffer
+///
+/// Kept so that after an edit only the source lines the edit touched are laid
+/// out again; the lines before them are reused as they are and the lines after
+/// them with their source bytes shifted.
+#[derive(Debug, Clone)]
+pub struct ViewLineCache {
+    pub key: ViewLineCacheKey,
+    /// `Buffer::instance_id` of the buffer the lines were built from
+    pub buffer_instance: u64,
+    /// Buffer version the lines are up to date with
+    pub version: u64,
+    /// Piece tree at `version`, diffed against the current tree to find the edit
+    pub tree: std::sync::Arc<crate::model::piece_tree::PieceTree>,
+    /// Buffer length at `version`
+    pub buffer_len: usize,
+    /// Byte after the last source line in `lines`
+    pub end_byte: usize,
+    /// View lines, without injected virtual lines; shared with the view data
+    /// built from them
+    pub lines: std::sync::Arc<Vec<ViewLine>>,
+    /// Indices of `lines` laid out by the last build; the other lines were reused
+    #[cfg(test)]
+    pub rebuilt: Range<usize>,
+}
+
 // ============================================================================
 // Layout: The computed display state for a view
 // ============================================================================
@@ -795,6 +958,122 @@ mod tests {
         assert_eq!(format_unprintable_byte(0xFF), "<FF>");
     }
 
+    #[test]
+    fn test_caret_notation_for_binary_bytes() {
+        let tokens = vec![
+            ViewTokenWire {
+                kind: ViewTokenWireKind::Text("a".to_string()),
+                source_offset: Some(0),
+                style: None,
+            },
+            ViewTokenWire {
+                kind: ViewTokenWireKind::BinaryByte(0x0D),
+                source_offset: Some(1),
+                style: None,
+            },
+            ViewTokenWire {
+                kind: ViewTokenWireKind::BinaryByte(0x7F),
+                source_offset: Some(2),
+                style: None,
+            },
+            ViewTokenWire {
+                kind: ViewTokenWireKind::BinaryByte(0xFF),
+                source_offset: Some(3),
+                style: None,
+            },
+            make_newline_token(Some(4)),
+        ];
+
+        let lines: Vec<_> = ViewLineIterator::new(&tokens, false, false, 4)
+            .with_caret_notation(true)
+            .collect();
+        assert_eq!(lines[0].text, "a^M^?\u{FFFD}\n");
+        assert_eq!(
+            lines[0].char_source_bytes,
+            vec![
+                Some(0),
+                Some(1),
+                Some(1),
+                Some(2),
+                Some(2),
+                Some(3),
+                Some(4)
+            ]
+        );
+        assert_eq!(lines[0].control_chars, (1..6).collect::<HashSet<_>>());
+
+        // Binary mode keeps hex code points
+        let lines: Vec<_> = ViewLineIterator::new(&tokens, true, false, 4)
+            .with_caret_notation(true)
+            .collect();
+        assert_eq!(lines[0].text, "a<0D><7F><FF>\n");
+    }
+
+    #[test]
+    fn test_whitespace_glyphs() {
+        let space = |offset| ViewTokenWire {
+            kind: ViewTokenWireKind::Space,
+            source_offset: Some(offset),
+            style: None,
+        };
+        let tokens = vec![
+            // "\tx y  \n"
+            make_text_token("\tx", Some(0)),
+            space(2),
+            make_text_token("y", Some(3)),
+            space(4),
+            space(5),
+            make_newline_token(Some(6)),
+            // "ab cd\n", wrapped after the space
+            make_text_token("ab", Some(7)),
+            space(9),
+            make_break_token(),
+            make_text_token("cd", Some(10)),
+            make_newline_token(Some(12)),
+            // "  " without a final newline
+            space(13),
+            space(14),
+        ];
+        let glyphs = |whitespace| -> Vec<HashMap<usize, char>> {
+            ViewLineIterator::new(&tokens, false, false, 4)
+                .with_whitespace(whitespace)
+                .map(|line| line.whitespace_glyphs)
+                .collect()
+        };
+
+        // The tab gets one arrow at its first column; the text is unchanged
+        let all = glyphs(WhitespaceRender {
+            all: true,
+            trailing: false,
+        });
+        assert_eq!(
+            all[0],
+            HashMap::from([(0, '→'), (5, '·'), (7, '·'), (8, '·')])
+        );
+        assert_eq!(all[1], HashMap::from([(2, '·')]));
+        assert!(all[2].is_empty());
+        assert_eq!(all[3], HashMap::from([(0, '·'), (1, '·')]));
+
+        // Whitespace before a wrap is not trailing
+        let trailing = glyphs(WhitespaceRender {
+            all: false,
+            trailing: true,
+        });
+        assert_eq!(trailing[0], HashMap::from([(7, '·'), (8, '·')]));
+        assert!(trailing[1].is_empty());
+        assert_eq!(trailing[3], HashMap::from([(0, '·'), (1, '·')]));
+
+        let none = glyphs(WhitespaceRender::default());
+        assert!(none.iter().all(HashMap::is_empty));
+        let lines: Vec<_> = ViewLineIterator::new(&tokens, false, false, 4)
+            .with_whitespace(WhitespaceRender {
+                all: true,
+                trailing: true,
+            })
+            .collect();
+        assert_eq!(lines[0].text, "    x y  \n");
+    }
+
     #[test]
     fn test_binary_mode_renders_control_chars() {
         // Text with null byte and control character
@@ -3,6 +3,26 @@ use crate::model::cursor::Cursor;
 use crate::primitives::display_width::{char_width, str_width};
 use crate::primitives::line_wrapping::{char_position_to_segment, wrap_line, WrapConfig};
 use crate::view::ui::view_pipeline::ViewLine;
+use std::time::{Duration, Instant};
+
+/// Jumps shorter than this many lines scroll instantly even with smooth scrolling
+const SMOOTH_SCROLL_MIN_LINES: usize = 4;
+
+/// An in-progress smooth scroll of the top line toward a target
+#[derive(Debug, Clone, Copy)]
+struct ScrollAnimation {
+    /// Top line the animation started from
+    from_line: usize,
+    /// Top line the animation ends on
+    to_line: usize,
+    /// Authoritative top_byte once the animation finishes
+    target_byte: usize,
+    /// top_byte shown by the most recent frame
+    shown_byte: usize,
+    start: Instant,
+    duration: Duration,
+}
+
 /// The viewport - what portion of the buffer is visible
 #[derive(Debug, Clone)]
 pub struct Viewport {
@@ -49,6 +69,12 @@ pub struct Viewport {
     /// This is set after scroll actions (Ctrl+Up/Down) to prevent the scroll
     /// from being immediately undone by ensure_visible
     skip_ensure_visible: bool,
+
+    /// Smooth scroll in progress, if any
+    scroll_animation: Option<ScrollAnimation>,
+
+    /// top_byte shown by the previous frame, used to detect large jumps
+    last_shown_top_byte: Option<usize>,
 }
 
 impl Viewport {
@@ -66,6 +92,8 @@ impl Viewport {
             needs_sync: false,
             skip_resize_sync: false,
             skip_ensure_visible: false,
+            scroll_animation: None,
+            last_shown_top_byte: None,
         }
     }
 
@@ -101,6 +129,94 @@ impl Viewport {
         self.skip_ensure_visible = false;
     }
 
+    /// Whether a smooth scroll animation is still running
+    pub fn is_scroll_animating(&self) -> bool {
+        self.scroll_animation.is_some()
+    }
+
+    /// Put back the animation's target as the authoritative top_byte before a
+    /// render, so cursor visibility checks work against where the view is going.
+    /// If something else moved the view since the last frame, the animation is
+    /// dropped and that position wins.
+    pub fn restore_scroll_target(&mut self) {
+        if let Some(animation) = self.scroll_animation {
+            if self.top_byte == animation.shown_byte {
+                self.top_byte = animation.target_byte;
+            } else {
+                self.scroll_animation = None;
+            }
+        }
+    }
+
+    /// Advance smooth scrolling for this frame
+    ///
+    /// Call after top_byte has been settled for the frame. A jump of at least
+    /// `SMOOTH_SCROLL_MIN_LINES` from the previously shown position starts an
+    /// animation; while one is running top_byte is replaced by the interpolated
+    /// line for this frame. Pass `None` as `duration` to scroll instantly.
+    /// Returns true if top_byte now differs from the target.
+    pub fn animate_scroll(
+        &mut self,
+        buffer: &Buffer,
+        duration: Option<Duration>,
+        now: Instant,
+    ) -> bool {
+        let target_byte = self.top_byte;
+        let previous = self.last_shown_top_byte.replace(target_byte);
+
+        let Some(duration) = duration.filter(|d| !d.is_zero()) else {
+            self.scroll_animation = None;
+            return false;
+        };
+
+        let mut animation = match self.scroll_animation.take() {
+            Some(animation) if animation.target_byte == target_byte => animation,
+            _ => {
+                // A new jump (or a new target mid-animation) starts from what is on screen
+                let Some(previous) = previous.filter(|&p| p != target_byte) else {
+                    return false;
+                };
+                let from_line = buffer.get_line_number(previous);
+                let to_line = buffer.get_line_number(target_byte);
+                if from_line.abs_diff(to_line) < SMOOTH_SCROLL_MIN_LINES {
+                    return false;
+                }
+                ScrollAnimation {
+                    from_line,
+                    to_line,
+                    target_byte,
+                    shown_byte: previous,
+                    start: now,
+                    duration,
+                }
+            }
+        };
+
+        let progress = now.saturating_duration_since(animation.start).as_secs_f64()
+            / animation.duration.as_secs_f64();
+        if progress >= 1.0 {
+            return false;
+        }
+
+        // Ease out so the view decelerates into place
+        let eased = 1.0 - (1.0 - progress).powi(2);
+        let distance = animation.to_line as f64 - animation.from_line as f64;
+        let line = (animation.from_line as f64 + distance * eased).round() as usize;
+        let Some(shown_byte) = buffer.line_start_offset(line) else {
+            return false;
+        };
+
+        animation.shown_byte = shown_byte;
+        self.scroll_animation = Some(animation);
+        self.last_shown_top_byte = Some(shown_byte);
+        if shown_byte == target_byte {
+            return false;
+        }
+        self.top_byte = shown_byte;
+        self.top_view_line_offset = 0;
+        true
+    }
+
     /// Set the scroll offset
     pub fn set_scroll_offset(&mut self, offset: usize) {
         self.scroll_offset = offset;
@@ -585,6 +701,53 @@ impl Viewport {
         self.set_top_byte_with_limit(buffer, target_position);
     }
 
+    /// Scroll so the line containing `position` sits in the middle of the viewport
+    ///
+    /// Marks the viewport to skip the next `ensure_visible` so the scroll isn't undone.
+    pub fn center_on(&mut self, buffer: &mut Buffer, position: usize) {
+        let rows_from_top = self.visible_line_count() / 2;
+        self.scroll_line_to_row(buffer, position, rows_from_top);
+    }
+
+    /// Scroll so the line containing `position` is the first row past the scroll margin
+    pub fn scroll_line_to_top(&mut self, buffer: &mut Buffer, position: usize) {
+        let effective_offset = self.scroll_offset.min(self.visible_line_count() / 2);
+        self.scroll_line_to_row(buffer, position, effective_offset);
+    }
+
+    /// Scroll so the line containing `position` is the last row before the scroll margin
+    pub fn scroll_line_to_bottom(&mut self, buffer: &mut Buffer, position: usize) {
+        let visible_count = self.visible_line_count();
+        let effective_offset = self.scroll_offset.min(visible_count / 2);
+        let rows_from_top = visible_count.saturating_sub(effective_offset + 1);
+        self.scroll_line_to_row(buffer, position, rows_from_top);
+    }
+
+    /// Scroll so the line containing `position` sits `rows_from_top` rows below
+    /// the top of the viewport
+    ///
+    /// The result is clamped like other scrolls, so near the start or end of the
+    /// buffer the line lands as close to the requested row as the content allows.
+    /// Marks the viewport to skip the next `ensure_visible` so the scroll isn't undone.
+    pub fn scroll_line_to_row(
+        &mut self,
+        buffer: &mut Buffer,
+        position: usize,
+        rows_from_top: usize,
+    ) {
+        // Move backwards from the position rows_from_top lines
+        let mut iter = buffer.line_iterator(position, 80);
+        for _ in 0..rows_from_top {
+            if iter.prev().is_none() {
+                break;
+            }
+        }
+        let new_top_byte = iter.current_position();
+        self.set_top_byte_with_limit(buffer, new_top_byte);
+        self.top_view_line_offset = 0;
+        self.set_skip_ensure_visible();
+    }
+
     /// Mark viewport as needing synchronization with cursor positions
     /// This defers the actual viewport update until sync_with_cursor is called
     pub fn mark_needs_sync(&mut self) {
@@ -970,6 +1133,40 @@ impl Viewport {
         }
     }
 
+    /// Scroll horizontally by `columns` (negative scrolls left) without moving the cursor
+    ///
+    /// Does nothing when lines wrap. Scrolling right stops once the end of the
+    /// longest visible line reaches the last column. Marks the viewport to skip
+    /// the next `ensure_visible` so the scroll isn't undone.
+    pub fn scroll_horizontal(&mut self, buffer: &mut Buffer, columns: isize) {
+        if self.line_wrap_enabled {
+            return;
+        }
+
+        if columns < 0 {
+            self.left_column = self.left_column.saturating_sub(columns.unsigned_abs());
+        } else {
+            let gutter_width = self.gutter_width(buffer);
+            let scrollbar_width = 1;
+            let visible_width = (self.width as usize)
+                .saturating_sub(gutter_width)
+                .saturating_sub(scrollbar_width);
+
+            let mut iter = buffer.line_iterator(self.top_byte, 80);
+            let longest_line = (0..self.visible_line_count())
+                .map_while(|_| iter.next_line())
+                .map(|(_, content)| str_width(content.trim_end_matches(['\n', '\r'])))
+                .max()
+                .unwrap_or(0);
+            let max_left_column = longest_line.saturating_sub(visible_width.saturating_sub(1));
+
+            if self.left_column < max_left_column {
+                self.left_column = (self.left_column + columns as usize).min(max_left_column);
+            }
+        }
+        self.set_skip_ensure_visible();
+    }
+
     /// Ensure multiple cursors are visible (smart scroll for multi-cursor)
     /// Prioritizes keeping the primary cursor visible
     pub fn ensure_cursors_visible(
@@ -1330,4 +1527,99 @@ mod tests {
             vp.left_column
         );
     }
+
+    #[test]
+    fn test_animate_scroll_interpolates_toward_target() {
+        let content: String = (0..100).map(|i| format!("line {i}\n")).collect();
+        let buffer = Buffer::from_str_test(&content);
+        let mut vp = Viewport::new(80, 24);
+        let duration = Some(Duration::from_millis(100));
+        let start = Instant::now();
+
+        // First frame only records the shown position
+        assert!(!vp.animate_scroll(&buffer, duration, start));
+
+        let target = buffer.line_start_offset(50).unwrap();
+        vp.top_byte = target;
+        assert!(vp.animate_scroll(&buffer, duration, start));
+        assert!(vp.is_scroll_animating());
+        assert_eq!(vp.top_byte, 0, "animation starts from the shown position");
+
+        vp.restore_scroll_target();
+        assert_eq!(vp.top_byte, target);
+        assert!(vp.animate_scroll(&buffer, duration, start + Duration::from_millis(50)));
+        let line = buffer.get_line_number(vp.top_byte);
+        assert!(line > 0 && line < 50, "halfway frame at line {line}");
+
+        vp.restore_scroll_target();
+        assert!(!vp.animate_scroll(&buffer, duration, start + Duration::from_millis(100)));
+        assert_eq!(vp.top_byte, target);
+        assert!(!vp.is_scroll_animating());
+
+        // Small jumps and disabled animation scroll instantly
+        vp.top_byte = buffer.line_start_offset(52).unwrap();
+        assert!(!vp.animate_scroll(&buffer, duration, start));
+        assert!(!vp.is_scroll_animating());
+        vp.top_byte = 0;
+        assert!(!vp.animate_scroll(&buffer, None, start));
+        assert!(!vp.is_scroll_animating());
+    }
+
+    #[test]
+    fn test_scroll_line_to_top_middle_and_bottom() {
+        let content: Vec<String> = (0..100).map(|i| format!("line{i}")).collect();
+        let mut buffer = Buffer::from_str_test(&content.join("\n"));
+        let mut vp = Viewport::new(80, 21);
+        assert_eq!(vp.scroll_offset, 3);
+        let line_start = |buffer: &Buffer, line: usize| buffer.line_start_offset(line).unwrap();
+
+        // A cursor in the middle of the document
+        let cursor = line_start(&buffer, 50) + 2;
+        vp.center_on(&mut buffer, cursor);
+        assert_eq!(buffer.get_line_number(vp.top_byte), 40);
+        vp.scroll_line_to_top(&mut buffer, cursor);
+        assert_eq!(buffer.get_line_number(vp.top_byte), 47);
+        vp.scroll_line_to_bottom(&mut buffer, cursor);
+        assert_eq!(buffer.get_line_number(vp.top_byte), 33);
+        assert!(vp.should_skip_ensure_visible());
+
+        // Near the start the view can't scroll above the first line
+        let cursor = line_start(&buffer, 2);
+        vp.center_on(&mut buffer, cursor);
+        assert_eq!(vp.top_byte, 0);
+        vp.scroll_line_to_bottom(&mut buffer, cursor);
+        assert_eq!(vp.top_byte, 0);
+
+        // Near the end the view stops with the last line at the bottom
+        let cursor = line_start(&buffer, 98);
+        vp.center_on(&mut buffer, cursor);
+        assert_eq!(buffer.get_line_number(vp.top_byte), 79);
+        vp.scroll_line_to_top(&mut buffer, cursor);
+        assert_eq!(buffer.get_line_number(vp.top_byte), 79);
+        vp.scroll_line_to_bottom(&mut buffer, cursor);
+        assert_eq!(buffer.get_line_number(vp.top_byte), 79);
+    }
+
+    #[test]
+    fn test_scroll_horizontal_clamps_to_longest_visible_line() {
+        let content = format!("short\n{}\nmid", "x".repeat(150));
+        let mut buffer = Buffer::from_str_test(&content);
+        let mut vp = Viewport::new(80, 10);
+        vp.line_wrap_enabled = false;
+        let visible_width = 80 - vp.gutter_width(&buffer) - 1;
+
+        vp.scroll_horizontal(&mut buffer, 10);
+        assert_eq!(vp.left_column, 10);
+        assert!(vp.should_skip_ensure_visible());
+
+        vp.scroll_horizontal(&mut buffer, 1000);
+        assert_eq!(vp.left_column, 150 - (visible_width - 1));
+
+        vp.scroll_horizontal(&mut buffer, -1000);
+        assert_eq!(vp.left_column, 0);
+
+        vp.line_wrap_enabled = true;
+        vp.scroll_horizontal(&mut buffer, 10);
+        assert_eq!(vp.left_column, 0);
+    }
 }
@@ -72,7 +72,56 @@ while true; do
 case "$method" in
     "initialize")
         # Send initialize response
-        send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":{"capabilities":{"completionProvider":{"triggerCharacters":[".",":",":"]},"definitionProvider":true,"hoverProvider":true,"textDocumentSync":1,"semanticTokensProvider":{"legend":{"tokenTypes":["keyword","function","variable"],"tokenModifiers":["declaration","deprecated"]},"full":{"delta":true},"range":true}}}}'
+        send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":{"capabilities":{"completionProvider":{"triggerCharacters":[".",":",":"]},"definitionProvider":true,"referencesProvider":true,"hoverProvider":true,"codeActionProvider":true,"executeCommandProvider":{"commands":["fake.appendFooter"]},"documentFormattingProvider":true,"documentRangeFormattingProvider":true,"documentSymbolProvider":true,"textDocumentSync":1,"semanticTokensProvider":{"legend":{"tokenTypes":["keyword","function","variable"],"tokenModifiers":["declaration","deprecated"]},"full":{"delta":true},"range":true}}}}'
+        ;;
+    "textDocument/codeAction")
+        # Offer an edit-style and a command-style action on the first line, nothing elsewhere
+        uri=$(echo "$msg" | grep -o '"uri":"[^"]*"' | head -1 | cut -d'"' -f4)
+        line=$(echo "$msg" | grep -o '"line":[0-9]*' | head -1 | cut -d':' -f2)
+        if [ "$line" = "0" ]; then
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":[{"title":"Insert header comment","kind":"quickfix","edit":{"changes":{"'$uri'":[{"range":{"start":{"line":0,"character":0},"end":{"line":0,"character":0}},"newText":"// header "}]}}},{"title":"Append footer","command":"fake.appendFooter","arguments":["'$uri'"]}]}'
+        else
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":[]}'
+        fi
+        ;;
+    "workspace/executeCommand")
+        # Ask the editor to apply the command's edit, then finish the command
+        uri=$(echo "$msg" | grep -o '"arguments":\["[^"]*"' | cut -d'"' -f4)
+        send_message '{"jsonrpc":"2.0","id":9001,"method":"workspace/applyEdit","params":{"label":"Append footer","edit":{"changes":{"'$uri'":[{"range":{"start":{"line":1,"character":0},"end":{"line":1,"character":0}},"newText":"// footer "}]}}}}'
+        send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":null}'
+        ;;
+    "textDocument/documentSymbol")
+        # A struct with a field and a function, or nothing for files named empty.*
+        uri=$(echo "$msg" | grep -o '"uri":"[^"]*"' | head -1 | cut -d'"' -f4)
+        case "$uri" in
+        */empty.*)
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":null}'
+            ;;
+        *)
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":[{"name":"Point","kind":23,"range":{"start":{"line":0,"character":0},"end":{"line":2,"character":1}},"selectionRange":{"start":{"line":0,"character":7},"end":{"line":0,"character":12}},"children":[{"name":"x","kind":8,"detail":"i32","range":{"start":{"line":1,"character":4},"end":{"line":1,"character":10}},"selectionRange":{"start":{"line":1,"character":4},"end":{"line":1,"character":5}}}]},{"name":"main","kind":12,"range":{"start":{"line":4,"character":0},"end":{"line":6,"character":1}},"selectionRange":{"start":{"line":4,"character":3},"end":{"line":4,"character":7}}}]}'
+            ;;
+        esac
+        ;;
+    "textDocument/formatting"|"textDocument/rangeFormatting")
+        # Files named slow.* take a while to format
+        if echo "$msg" | grep -q '"uri":"[^"]*/slow\.'; then
+            sleep 0.5
+        fi
+        # Indent with the requested options: a tab, or tabSize spaces
+        tab_size=$(echo "$msg" | grep -o '"tabSize":[0-9]*' | cut -d':' -f2)
+        if echo "$msg" | grep -q '"insertSpaces":false'; then
+            indent='\\t'
+        else
+            indent=$(printf '%*s' "$tab_size" '')
+        fi
+        if [ "$method" = "textDocument/rangeFormatting" ]; then
+            # Only indent the first line of the range
+            line=$(echo "$msg" | grep -o '"line":[0-9]*' | head -1 | cut -d':' -f2)
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":[{"range":{"start":{"line":'$line',"character":0},"end":{"line":'$line',"character":0}},"newText":"'"$indent"'"}]}'
+        else
+            # Edits for "fn main(){\nlet x=1;\n}\n", listed in document order
+            send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":[{"range":{"start":{"line":0,"character":9},"end":{"line":0,"character":9}},"newText":" "},{"range":{"start":{"line":1,"character":0},"end":{"line":1,"character":0}},"newText":"'"$indent"'"},{"range":{"start":{"line":1,"character":5},"end":{"line":1,"character":6}},"newText":" = "}]}'
+        fi
         ;;
     "textDocument/hover")
         # Send hover response with range
@@ -92,6 +141,11 @@ case "$method" in
         uri=$(echo "$msg" | grep -o '"uri":"[^"]*"' | head -1 | cut -d'"' -f4)
         send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":{"uri":"'$uri'","range":{"start":{"line":0,"character":0},"end":{"line":0,"character":10}}}}'
         ;;
+    "textDocument/references")
+        # Send three references in the requesting document
+        uri=$(echo "$msg" | grep -o '"uri":"[^"]*"' | head -1 | cut -d'"' -f4)
+        send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":[{"uri":"'$uri'","range":{"start":{"line":0,"character":3},"end":{"line":0,"character":12}}},{"uri":"'$uri'","range":{"start":{"line":2,"character":4},"end":{"line":2,"character":13}}},{"uri":"'$uri'","range":{"start":{"line":4,"character":4},"end":{"line":4,"character":13}}}]}'
+        ;;
     "textDocument/semanticTokens/full")
         send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":{"resultId":"1","data":[0,0,2,0,0,0,3,4,1,0]}}'
         ;;
@@ -291,6 +291,9 @@ pub struct EditorTestHarness {
     /// Terminal dimensions for vt100
     term_width: u16,
     term_height: u16,
+
+    /// Escape sequences the editor writes outside ratatui's buffer (e.g. cursor style)
+    escape_output: Vec<u8>,
 }
 
 impl EditorTestHarness {
@@ -422,6 +425,7 @@ impl EditorTestHarness {
             vt100_parser: vt100::Parser::new(height, width, 0),
             term_width: width,
             term_height: height,
+            escape_output: Vec::new(),
         })
     }
 
@@ -692,6 +696,14 @@ impl EditorTestHarness {
         Ok(())
     }
 
+    /// Simulate a bracketed paste from the terminal
+    pub fn send_paste(&mut self, text: &str) -> anyhow::Result<()> {
+        // Delegate to the editor's handle_paste method (just like main.rs does)
+        self.editor.handle_paste(text.to_string());
+        self.render()?;
+        Ok(())
+    }
+
     /// Simulate a mouse event
     pub fn send_mouse(&mut self, mouse_event: MouseEvent) -> anyhow::Result<()> {
         // Delegate to the editor's handle_mouse method (just like main.rs does)
@@ -818,9 +830,15 @@ impl EditorTestHarness {
         self.terminal.draw(|frame| {
             self.editor.render(frame);
         })?;
+        self.editor.write_cursor_style(&mut self.escape_output)?;
         Ok(())
     }
 
+    /// Take the escape sequences written outside the screen buffer since the last call
+    pub fn take_escape_output(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.escape_output)
+    }
+
     /// Render through the real CrosstermBackend and parse with vt100
     /// This tests the actual ANSI escape sequences, not just the buffer contents
     /// Returns the screen content as parsed by a real terminal emulator
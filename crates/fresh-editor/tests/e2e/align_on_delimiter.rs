@@ -0,0 +1,97 @@
+//! Tests for Align on Delimiter
+//!
+//! Tests that:
+//! - Selected lines are lined up on the delimiter as one undo step
+//! - Lines without the delimiter and lines outside the selection are untouched
+//! - Spacing around the delimiter follows `align_delimiter_single_space`
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use tempfile::TempDir;
+
+/// Helper to run a command from the command palette
+fn run_command(harness: &mut EditorTestHarness, command_name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.type_text(command_name).unwrap();
+    harness.render().unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Open `content`, select its first `lines` lines and align them on `delimiter`
+fn align_lines(config: Config, content: &str, lines: usize, delimiter: &str) -> EditorTestHarness {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("settings.conf");
+    std::fs::write(&file_path, content).unwrap();
+
+    let mut harness = EditorTestHarness::with_config(160, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    for _ in 0..lines {
+        harness
+            .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+            .unwrap();
+    }
+    run_command(&mut harness, "Align on Delimiter");
+    harness.assert_screen_contains("Align on delimiter:");
+    harness.type_text(delimiter).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness
+}
+
+/// Test aligning a block of `key = value` lines on `=`
+#[test]
+fn test_align_on_equals() {
+    let original = "name = fresh\nversion=1\n# comment\nlicense   =  GPL\nafter = x\n";
+    let mut harness = align_lines(Config::default(), original, 4, "=");
+
+    let aligned = "name    = fresh\nversion = 1\n# comment\nlicense = GPL\nafter = x\n";
+    assert_eq!(harness.get_buffer_content().unwrap(), aligned);
+    harness.assert_screen_contains("Aligned 3 line(s) on '='");
+
+    // The selection still covers the aligned block
+    let selection = harness
+        .editor()
+        .active_state()
+        .cursors
+        .primary()
+        .selection_range();
+    assert_eq!(selection, Some(0..aligned.find("after").unwrap()));
+
+    // A single undo restores the original text
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), original);
+}
+
+/// Test the existing spacing is kept when single spacing is turned off
+#[test]
+fn test_align_keeps_spacing_when_configured() {
+    let mut config = Config::default();
+    config.editor.align_delimiter_single_space = false;
+    let harness = align_lines(config, "a: 1\nbbb:2\n", 2, ":");
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "a  : 1\nbbb:2\n");
+}
+
+/// Test the command asks for a selection instead of prompting
+#[test]
+fn test_align_requires_selection() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("a = 1").unwrap();
+    run_command(&mut harness, "Align on Delimiter");
+
+    harness.assert_screen_contains("Select the lines to align");
+    assert_eq!(harness.get_buffer_content().unwrap(), "a = 1");
+}
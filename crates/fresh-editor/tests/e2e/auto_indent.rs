@@ -7,18 +7,23 @@ use tempfile::TempDir;
 /// Uses `.without_empty_plugins_dir()` so that embedded plugins are loaded,
 /// which is required for tree-sitter based auto-indent to work.
 fn harness_with_auto_indent() -> EditorTestHarness {
+    let mut harness = harness_with_auto_indent_untracked();
+    harness.enable_shadow_validation();
+    harness
+}
+
+/// Same as `harness_with_auto_indent`, for actions the shadow string doesn't model
+fn harness_with_auto_indent_untracked() -> EditorTestHarness {
     let mut config = Config::default();
     config.editor.auto_indent = true;
-    let mut harness = EditorTestHarness::create(
+    EditorTestHarness::create(
         80,
         24,
         HarnessOptions::new()
             .with_config(config)
             .without_empty_plugins_dir(),
     )
-    .unwrap();
-    harness.enable_shadow_validation();
-    harness
+    .unwrap()
 }
 
 /// Test basic auto-indent in Rust after opening brace
@@ -224,9 +229,10 @@ fn test_auto_indent_disabled_by_config() {
     let file_path = temp_dir.path().join("test.rs");
     std::fs::write(&file_path, "").unwrap();
 
-    // Create harness with auto_indent disabled
+    // Create harness with auto_indent (and bracket auto-close) disabled
     let mut config = Config::default();
     config.editor.auto_indent = false;
+    config.editor.auto_close = false;
     let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
     harness.open_file(&file_path).unwrap();
 
@@ -846,3 +852,73 @@ fn test_indent_after_empty_line_in_function_body() {
         line3_indent, content
     );
 }
+
+/// Open a Rust file with a two-line function body and put the cursor mid-way
+/// through the first body line
+fn open_rust_function(harness: &mut EditorTestHarness, temp_dir: &TempDir) {
+    let file_path = temp_dir.path().join("open_line.rs");
+    std::fs::write(&file_path, "fn main() {\n    let x = 1;\n}").unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    for _ in 0..7 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.render().unwrap();
+}
+
+/// Test Insert Line Below (Ctrl+Enter) moves to a new, indented line below
+#[test]
+fn test_open_line_below_is_indented() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut harness = harness_with_auto_indent_untracked();
+    open_rust_function(&mut harness, &temp_dir);
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("fn main() {\n    let x = 1;\n    \n}");
+    assert_eq!(
+        harness.cursor_position(),
+        "fn main() {\n    let x = 1;\n    ".len()
+    );
+
+    // A single undo removes the new line and restores the cursor line
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("fn main() {\n    let x = 1;\n}");
+}
+
+/// Test Insert Line Above (Ctrl+Shift+Enter) moves to a new, indented line above
+#[test]
+fn test_open_line_above_is_indented() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut harness = harness_with_auto_indent_untracked();
+    open_rust_function(&mut harness, &temp_dir);
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::CONTROL | KeyModifiers::SHIFT)
+        .unwrap();
+    harness.type_text("y").unwrap();
+    harness.assert_buffer_content("fn main() {\n    y\n    let x = 1;\n}");
+}
+
+/// Test Insert Line Above on the first line keeps that line's indentation
+#[test]
+fn test_open_line_above_first_line() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("notes.txt");
+    std::fs::write(&file_path, "  top\nnext").unwrap();
+
+    let mut harness = harness_with_auto_indent_untracked();
+    harness.open_file(&file_path).unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::CONTROL | KeyModifiers::SHIFT)
+        .unwrap();
+    harness.assert_buffer_content("  \n  top\nnext");
+    assert_eq!(harness.cursor_position(), 2);
+}
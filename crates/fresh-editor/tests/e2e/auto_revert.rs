@@ -466,3 +466,38 @@ fn test_auto_revert_with_temp_rename_save() {
         harness.assert_buffer_content(&new_content);
     }
 }
+
+/// Test that deleting an open file externally flags the buffer instead of
+/// discarding it, and that saving recreates the file
+#[test]
+fn test_deleted_file_is_flagged_and_save_recreates_it() {
+    let mut harness = EditorTestHarness::with_temp_project(80, 24).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    let file_path = project_dir.join("deleted.txt");
+
+    write_and_sync(&file_path, "Keep this content");
+    harness.open_file(&file_path).unwrap();
+
+    fs::remove_file(&file_path).unwrap();
+
+    harness
+        .wait_until(|h| h.editor().is_file_deleted_on_disk(&file_path))
+        .expect("Deleted file should be flagged");
+    harness.render().unwrap();
+    harness.assert_screen_contains("[deleted on disk]");
+    // The buffer keeps its content
+    harness.assert_buffer_content("Keep this content");
+
+    // Saving recreates the file and clears the flag
+    harness
+        .send_key(
+            crossterm::event::KeyCode::Char('s'),
+            crossterm::event::KeyModifiers::CONTROL,
+        )
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(fs::read_to_string(&file_path).unwrap(), "Keep this content");
+    assert!(!harness.editor().is_file_deleted_on_disk(&file_path));
+    harness.assert_screen_not_contains("[deleted on disk]");
+}
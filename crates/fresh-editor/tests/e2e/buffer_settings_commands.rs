@@ -1,8 +1,10 @@
 //! Tests for buffer settings commands:
 //! - Set Tab Size
 //! - Toggle Indentation: Spaces ↔ Tabs
+//! - Convert Indentation to Spaces / Tabs
 //! - Toggle Tab Indicators
 //! - Reset Buffer Settings
+//! - Indentation width detection on load
 
 use crate::common::harness::EditorTestHarness;
 use crossterm::event::{KeyCode, KeyModifiers};
@@ -276,3 +278,117 @@ fn test_reset_buffer_settings_command() {
         content_reset
     );
 }
+
+/// Test that "Convert Indentation" rewrites leading whitespace in one undo step,
+/// leaves alignment inside lines alone, and round-trips back to the original
+#[test]
+fn test_convert_indentation_commands() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    let original = "func f() {\n\tif x {\n\t\ty := 1\t// note\n\t}\n}\n";
+    std::fs::write(&file_path, original).unwrap();
+
+    let mut harness = EditorTestHarness::with_config(80, 24, Config::default()).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    // Put the cursor at the end of the third line
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    let line3_end = original.find("note").unwrap() + "note".len();
+    assert_eq!(harness.cursor_position(), line3_end);
+
+    run_command(&mut harness, "Convert Indentation to Spaces");
+
+    let spaces = "func f() {\n    if x {\n        y := 1\t// note\n    }\n}\n";
+    assert_eq!(harness.get_buffer_content().unwrap(), spaces);
+    // The cursor stays at the end of the same line
+    assert_eq!(harness.cursor_position(), spaces.find("note").unwrap() + 4);
+
+    // A single undo restores the original text
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), original);
+    harness
+        .send_key(KeyCode::Char('y'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), spaces);
+
+    run_command(&mut harness, "Convert Indentation to Tabs");
+
+    assert_eq!(harness.get_buffer_content().unwrap(), original);
+    assert_eq!(harness.cursor_position(), line3_end);
+}
+
+/// Test that the indentation width of a space-indented file is detected on load
+/// and used for new indentation
+#[test]
+fn test_detect_indent_width_on_load() {
+    let temp_dir = TempDir::new().unwrap();
+    let two = temp_dir.path().join("two.txt");
+    let four = temp_dir.path().join("four.txt");
+    std::fs::write(&two, "a {\n  b {\n    c\n  }\n}\n").unwrap();
+    std::fs::write(&four, "a {\n    b {\n        c\n    }\n}\n").unwrap();
+
+    let mut config = Config::default();
+    config.editor.tab_size = 8;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+
+    harness.open_file(&two).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.editor().active_state().tab_size, 2);
+    assert!(harness.get_screen_row(22).contains("Spaces: 2"));
+
+    // Tab at the start of the last line inserts two spaces
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    let content = harness.get_buffer_content().unwrap();
+    assert!(content.ends_with("}\n  "), "{:?}", content);
+
+    harness.open_file(&four).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.editor().active_state().tab_size, 4);
+    assert!(harness.get_screen_row(22).contains("Spaces: 4"));
+
+    // Setting the tab size explicitly replaces the detected width
+    run_command(&mut harness, "Set Tab Size");
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("3").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.editor().active_state().tab_size, 3);
+    assert!(!harness.get_screen_row(22).contains("Spaces:"));
+
+    // New indentation follows the buffer's tab size, not the global one
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    let content = harness.get_buffer_content().unwrap();
+    assert!(content.ends_with("}\n   "), "{:?}", content);
+}
+
+/// Test that indentation detection can be turned off
+#[test]
+fn test_detect_indent_width_disabled() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("two.txt");
+    std::fs::write(&file_path, "a {\n  b {\n    c\n  }\n}\n").unwrap();
+
+    let mut config = Config::default();
+    config.editor.detect_indent_width = false;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(harness.editor().active_state().tab_size, 4);
+    assert!(!harness.get_screen_row(22).contains("Spaces:"));
+}
@@ -703,8 +703,18 @@ fn test_command_palette_shows_shortcuts() {
     // Add Cursor Below should show Ctrl+Alt+↓
     harness.assert_screen_contains("Add Cursor Below");
 
-    // Copy should show Ctrl+C (or ⌘+C on macOS)
+    // Add Cursor at Next Match should show Ctrl+D (or ⌘+D on macOS)
+    harness.assert_screen_contains("Add Cursor at Next Match");
+    assert!(
+        screen.contains("Ctrl+D") || screen.contains("⌘+D"),
+        "Should show shortcut for Add Cursor at Next Match"
+    );
+
+    // Copy should show Ctrl+C (or ⌘+C on macOS); filter to bring it into view
+    harness.type_text("copy").unwrap();
+    harness.render().unwrap();
     harness.assert_screen_contains("Copy");
+    let screen = harness.screen_to_string();
     assert!(
         screen.contains("Ctrl+C") || screen.contains("⌘+C"),
         "Should show shortcut for Copy"
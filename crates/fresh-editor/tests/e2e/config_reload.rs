@@ -0,0 +1,158 @@
+//! Tests for reloading the config when its file changes on disk
+//!
+//! Tests that:
+//! - A changed config file is picked up by the running editor
+//! - Open buffers follow a changed tab size unless they set their own
+//! - A config file that fails to parse keeps the running config
+//! - A project `.fresh.toml` overrides the user config
+//! - A project `.fresh.toml` that fails to parse is skipped on its own
+
+use crate::common::harness::EditorTestHarness;
+use fresh::config::Config;
+use fresh::config_io::DirectoryContext;
+use std::fs;
+use std::time::Duration;
+use tempfile::TempDir;
+
+/// Advance past two config polls: one to notice the change, one to apply it
+fn poll_config_twice(harness: &mut EditorTestHarness) {
+    let interval = harness
+        .editor()
+        .config()
+        .editor
+        .auto_revert_poll_interval_ms;
+    for _ in 0..2 {
+        harness.advance_time(Duration::from_millis(interval + 1));
+        harness.process_async_and_render().unwrap();
+    }
+}
+
+/// Test writing a new tab size to the user config updates the running editor
+#[test]
+fn test_config_file_change_is_applied_live() {
+    let project = TempDir::new().unwrap();
+    let context_temp = TempDir::new().unwrap();
+    let dir_context = DirectoryContext::for_testing(context_temp.path());
+    fs::create_dir_all(&dir_context.config_dir).unwrap();
+
+    let mut harness = EditorTestHarness::with_shared_dir_context(
+        160,
+        24,
+        Config::default(),
+        project.path().to_path_buf(),
+        dir_context.clone(),
+    )
+    .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.editor().config().editor.tab_size, 4);
+
+    fs::write(dir_context.config_path(), r#"{"editor": {"tab_size": 8}}"#).unwrap();
+    poll_config_twice(&mut harness);
+
+    assert_eq!(harness.editor().config().editor.tab_size, 8);
+    harness.assert_screen_contains("Config reloaded");
+
+    // A broken config keeps the one that is running and reports the error
+    fs::write(dir_context.config_path(), r#"{"editor": {"tab_size": "#).unwrap();
+    let file = fs::File::options()
+        .write(true)
+        .open(dir_context.config_path())
+        .unwrap();
+    file.set_modified(std::time::SystemTime::now() + Duration::from_secs(5))
+        .unwrap();
+    poll_config_twice(&mut harness);
+
+    assert_eq!(harness.editor().config().editor.tab_size, 8);
+    harness.assert_screen_contains("Config change ignored");
+}
+
+/// Test a reloaded tab size reaches buffers that are already open, but not a
+/// buffer whose indentation width was detected from its content
+#[test]
+fn test_config_reload_updates_open_buffers() {
+    let project = TempDir::new().unwrap();
+    let context_temp = TempDir::new().unwrap();
+    let dir_context = DirectoryContext::for_testing(context_temp.path());
+    fs::create_dir_all(&dir_context.config_dir).unwrap();
+    let plain = project.path().join("plain.txt");
+    let two = project.path().join("two.txt");
+    fs::write(&plain, "hello\n").unwrap();
+    fs::write(&two, "a {\n  b {\n    c\n  }\n}\n").unwrap();
+
+    let mut harness = EditorTestHarness::with_shared_dir_context(
+        160,
+        24,
+        Config::default(),
+        project.path().to_path_buf(),
+        dir_context.clone(),
+    )
+    .unwrap();
+    harness.open_file(&two).unwrap();
+    harness.open_file(&plain).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.editor().active_state().tab_size, 4);
+
+    fs::write(dir_context.config_path(), r#"{"editor": {"tab_size": 8}}"#).unwrap();
+    poll_config_twice(&mut harness);
+
+    assert_eq!(harness.editor().active_state().tab_size, 8);
+
+    harness.open_file(&two).unwrap();
+    assert_eq!(harness.editor().active_state().tab_size, 2);
+}
+
+/// Test a `.fresh.toml` created in the project overrides the user tab size
+#[test]
+fn test_project_toml_overrides_user_config() {
+    let project = TempDir::new().unwrap();
+    let context_temp = TempDir::new().unwrap();
+    let dir_context = DirectoryContext::for_testing(context_temp.path());
+    fs::create_dir_all(&dir_context.config_dir).unwrap();
+    fs::write(dir_context.config_path(), r#"{"editor": {"tab_size": 2}}"#).unwrap();
+
+    let mut harness = EditorTestHarness::with_shared_dir_context(
+        160,
+        24,
+        Config::default(),
+        project.path().to_path_buf(),
+        dir_context,
+    )
+    .unwrap();
+    harness.render().unwrap();
+
+    fs::write(
+        project.path().join(".fresh.toml"),
+        "[editor]\ntab_size = 6\n",
+    )
+    .unwrap();
+    poll_config_twice(&mut harness);
+
+    assert_eq!(harness.editor().config().editor.tab_size, 6);
+}
+
+/// Test a `.fresh.toml` that fails to parse is skipped and reported, while
+/// the user config still applies
+#[test]
+fn test_invalid_project_toml_is_skipped() {
+    let project = TempDir::new().unwrap();
+    let context_temp = TempDir::new().unwrap();
+    let dir_context = DirectoryContext::for_testing(context_temp.path());
+    fs::create_dir_all(&dir_context.config_dir).unwrap();
+
+    let mut harness = EditorTestHarness::with_shared_dir_context(
+        160,
+        24,
+        Config::default(),
+        project.path().to_path_buf(),
+        dir_context.clone(),
+    )
+    .unwrap();
+    harness.render().unwrap();
+
+    fs::write(dir_context.config_path(), r#"{"editor": {"tab_size": 2}}"#).unwrap();
+    fs::write(project.path().join(".fresh.toml"), "[editor\ntab_size = ").unwrap();
+    poll_config_twice(&mut harness);
+
+    assert_eq!(harness.editor().config().editor.tab_size, 2);
+    harness.assert_screen_contains("Ignoring invalid .fresh.toml");
+}
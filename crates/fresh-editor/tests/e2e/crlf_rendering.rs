@@ -435,7 +435,7 @@ fn test_crlf_cut_paste() {
     );
 }
 
-/// Test that CR characters in LF files are shown as <0D>
+/// Test that CR characters in LF files are shown as ^M
 /// In Unix/LF files, \r is unusual and should be visible - even in \r\n sequences
 #[test]
 fn test_cr_shown_in_lf_file() {
@@ -444,7 +444,7 @@ fn test_cr_shown_in_lf_file() {
 
     // Create a test file with LF line endings but containing CR characters
     // The file has more LF than CRLF, so it should be detected as LF
-    // Even the \r\n sequence should show \r as <0D> because this is a Unix file
+    // Even the \r\n sequence should show \r as ^M because this is a Unix file
     let content = "Line1\nHello\rWorld\nLine3\r\nLine4\n";
     std::fs::write(&file_path, content).unwrap();
 
@@ -454,11 +454,11 @@ fn test_cr_shown_in_lf_file() {
 
     let screen = harness.screen_to_string();
 
-    // Both standalone \r and \r in \r\n should be shown as <0D> in LF files
+    // Both standalone \r and \r in \r\n should be shown as ^M in LF files
     // because any \r is unusual in a Unix file
     assert!(
-        screen.contains("<0D>"),
-        "CR characters in LF file should be shown as <0D>, screen: {}",
+        screen.contains("^M"),
+        "CR characters in LF file should be shown as ^M, screen: {}",
         screen
     );
 
@@ -776,3 +776,89 @@ fn test_set_line_ending_converts_on_save_crlf_to_lf() {
         "All line endings should be converted to LF"
     );
 }
+
+/// Test that mixed line endings are flagged on load and can be normalized to LF
+#[test]
+fn test_mixed_line_endings_flagged_and_normalized() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("mixed_endings.txt");
+    std::fs::write(&file_path, "CRLF line 1\r\nLF line 2\nCRLF line 3\r\n").unwrap();
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    // Majority format is CRLF, and the status bar flags the mix
+    assert!(harness
+        .editor()
+        .active_state()
+        .buffer
+        .has_mixed_line_endings());
+    harness.assert_screen_contains("CRLF (mixed)");
+
+    // Pick LF from the line ending prompt
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Set Line Ending").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_screen_contains("Line ending:").unwrap();
+    harness.send_key(KeyCode::Up, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert!(!harness
+        .editor()
+        .active_state()
+        .buffer
+        .has_mixed_line_endings());
+    harness.assert_screen_not_contains("(mixed)");
+
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    let saved = std::fs::read(&file_path).unwrap();
+    assert_eq!(saved, b"CRLF line 1\nLF line 2\nCRLF line 3\n");
+}
+
+/// Test that typing, Enter and pasting LF text into a CRLF file saves pure CRLF
+#[test]
+fn test_crlf_file_edited_and_saved_stays_crlf() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("windows.txt");
+    std::fs::write(&file_path, "First\r\nSecond\r\n").unwrap();
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    // End of "First": type, split the line, then paste text copied from an LF source
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.type_text(" line").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .editor_mut()
+        .set_clipboard_for_test("pasted A\npasted B\n".to_string());
+    harness.editor_mut().paste_for_test();
+    harness.type_text("Middle").unwrap();
+
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    let saved = std::fs::read(&file_path).unwrap();
+    assert_eq!(
+        saved,
+        b"First line\r\npasted A\r\npasted B\r\nMiddle\r\nSecond\r\n"
+    );
+    assert!(!harness
+        .editor()
+        .active_state()
+        .buffer
+        .has_mixed_line_endings());
+}
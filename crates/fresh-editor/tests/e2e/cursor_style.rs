@@ -0,0 +1,31 @@
+//! E2E tests for the configured terminal cursor style
+
+use crate::common::harness::EditorTestHarness;
+use fresh::config::{Config, CursorStyle};
+
+/// The configured cursor style escape is written on render, and only once
+#[test]
+fn test_configured_cursor_style_emitted_on_render() {
+    let mut config = Config::default();
+    config.editor.cursor_style = CursorStyle::SteadyBar;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+
+    harness.render().unwrap();
+    let output = String::from_utf8(harness.take_escape_output()).unwrap();
+    // DECSCUSR 6 = steady bar
+    assert_eq!(output, "\x1b[6 q");
+
+    // Unchanged style is not written again
+    harness.render().unwrap();
+    assert!(harness.take_escape_output().is_empty());
+}
+
+/// The default style restores the terminal's own cursor shape
+#[test]
+fn test_default_cursor_style_emits_user_shape() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    harness.render().unwrap();
+    let output = String::from_utf8(harness.take_escape_output()).unwrap();
+    assert_eq!(output, "\x1b[0 q");
+}
@@ -0,0 +1,126 @@
+//! E2E tests for deleting from the cursor to the end or start of the line
+//!
+//! Ctrl+K (Delete to Line End) works on display rows when lines wrap, and only
+//! joins the next line at the end of a line when `kill_line_joins_lines` is set.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+
+/// Test Delete to Line End from the middle of a line deletes to the end of it
+#[test]
+fn test_delete_to_line_end_from_middle() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("hello world\nnext").unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    for _ in 0..5 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('k'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("hello\nnext");
+    assert_eq!(harness.cursor_position(), 5);
+
+    // A single undo restores the deleted text
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("hello world\nnext");
+}
+
+/// Test Delete to Line End at the end of a line joins the next line by default
+#[test]
+fn test_delete_to_line_end_at_eol_joins_next_line() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("hello\nnext").unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('k'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("hellonext");
+}
+
+/// Test Delete to Line End at the end of a line does nothing when joining is disabled
+#[test]
+fn test_delete_to_line_end_at_eol_without_join() {
+    let mut config = Config::default();
+    config.editor.kill_line_joins_lines = false;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.type_text("hello\nnext").unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('k'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("hello\nnext");
+}
+
+/// Test Delete to Line End on a wrapped line stops at the end of the display row
+#[test]
+fn test_delete_to_line_end_stops_at_wrapped_row_end() {
+    let mut harness = EditorTestHarness::new(40, 24).unwrap();
+    let text = "0123456789".repeat(10);
+    harness.type_text(&text).unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('k'), KeyModifiers::CONTROL)
+        .unwrap();
+
+    // Only the first display row is gone; the rest of the source line remains
+    let remaining = harness.get_buffer_content().unwrap();
+    assert!(!remaining.is_empty(), "Later rows should be kept");
+    assert!(
+        remaining.len() < text.len(),
+        "The first row should be deleted"
+    );
+    assert!(text.ends_with(&remaining));
+}
+
+/// Test Delete to Start of Line deletes back to the line start, and does
+/// nothing at the start of a line
+#[test]
+fn test_delete_to_line_start() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("first\nhello world").unwrap();
+    for _ in 0..6 {
+        harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+    }
+    harness.render().unwrap();
+
+    let run_delete_to_line_start = |harness: &mut EditorTestHarness| {
+        harness
+            .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.type_text("Delete to Start of Line").unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+        harness.render().unwrap();
+    };
+
+    run_delete_to_line_start(&mut harness);
+    harness.assert_buffer_content("first\n world");
+
+    run_delete_to_line_start(&mut harness);
+    harness.assert_buffer_content("first\n world");
+}
@@ -0,0 +1,205 @@
+//! Tests for applying `.editorconfig` settings to opened buffers
+//!
+//! Tests that:
+//! - Section globs pick per-extension indentation (tabs for Go, spaces for Python)
+//! - Explicit project config wins over `.editorconfig`
+//! - trim_trailing_whitespace and insert_final_newline apply on save
+//! - The status bar shows the indentation style from `.editorconfig`
+//! - Reverting a file applies `.editorconfig` again
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use std::fs;
+use tempfile::TempDir;
+
+const EDITORCONFIG: &str = "root = true
+
+[*]
+end_of_line = lf
+
+[*.go]
+indent_style = tab
+tab_width = 8
+
+[*.py]
+indent_style = space
+indent_size = 2
+";
+
+/// Create a project directory containing `.editorconfig` with `content`
+fn project_with_editorconfig(content: &str) -> TempDir {
+    let project = TempDir::new().unwrap();
+    fs::write(project.path().join(".editorconfig"), content).unwrap();
+    project
+}
+
+#[test]
+fn test_editorconfig_indentation_per_extension() {
+    let project = project_with_editorconfig(EDITORCONFIG);
+    let go_file = project.path().join("main.go");
+    let py_file = project.path().join("app.py");
+    fs::write(&go_file, "package main\n").unwrap();
+    fs::write(&py_file, "import os\n").unwrap();
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        80,
+        24,
+        Config::default(),
+        project.path().to_path_buf(),
+    )
+    .unwrap();
+
+    harness.open_file(&go_file).unwrap();
+    let state = harness.editor().active_state();
+    assert!(state.use_tabs);
+    assert_eq!(state.tab_size, 8);
+
+    harness.open_file(&py_file).unwrap();
+    let state = harness.editor().active_state();
+    assert!(!state.use_tabs);
+    assert_eq!(state.tab_size, 2);
+
+    // Tab in the Python buffer inserts the editorconfig indent
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.assert_buffer_content("import os\n  \n");
+}
+
+#[test]
+fn test_project_config_wins_over_editorconfig() {
+    let project = project_with_editorconfig(EDITORCONFIG);
+    fs::write(
+        project.path().join(".fresh.toml"),
+        "[editor]\ntab_size = 6\n",
+    )
+    .unwrap();
+    let py_file = project.path().join("app.py");
+    fs::write(&py_file, "import os\n").unwrap();
+
+    let mut config = Config::default();
+    config.editor.tab_size = 6;
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        80,
+        24,
+        config,
+        project.path().to_path_buf(),
+    )
+    .unwrap();
+
+    harness.open_file(&py_file).unwrap();
+    let state = harness.editor().active_state();
+    // indent_style still applies, but the explicit tab size is kept
+    assert!(!state.use_tabs);
+    assert_eq!(state.tab_size, 6);
+}
+
+#[test]
+fn test_editorconfig_whitespace_on_save() {
+    let project = project_with_editorconfig(
+        "root = true\n\n[*.txt]\ntrim_trailing_whitespace = true\ninsert_final_newline = true\n",
+    );
+    let file_path = project.path().join("notes.txt");
+    fs::write(&file_path, "first  \nsecond\t\nthird ").unwrap();
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        80,
+        24,
+        Config::default(),
+        project.path().to_path_buf(),
+    )
+    .unwrap();
+    harness.open_file(&file_path).unwrap();
+
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+
+    assert_eq!(
+        fs::read_to_string(&file_path).unwrap(),
+        "first\nsecond\nthird\n"
+    );
+
+    // The trim is one undo step
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("first  \nsecond\t\nthird ");
+}
+
+#[test]
+fn test_project_config_trim_wins_over_editorconfig() {
+    let project =
+        project_with_editorconfig("root = true\n\n[*.txt]\ntrim_trailing_whitespace = true\n");
+    fs::write(
+        project.path().join(".fresh.toml"),
+        "[editor]\ntrim_trailing_whitespace = false\n",
+    )
+    .unwrap();
+    let file_path = project.path().join("notes.txt");
+    fs::write(&file_path, "first  \n").unwrap();
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        80,
+        24,
+        Config::default(),
+        project.path().to_path_buf(),
+    )
+    .unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+
+    assert_eq!(fs::read_to_string(&file_path).unwrap(), "first  \n");
+}
+
+#[test]
+fn test_editorconfig_tabs_shown_in_status_bar() {
+    let project = project_with_editorconfig(EDITORCONFIG);
+    let go_file = project.path().join("main.go");
+    fs::write(&go_file, "package main\n").unwrap();
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        100,
+        24,
+        Config::default(),
+        project.path().to_path_buf(),
+    )
+    .unwrap();
+    harness.open_file(&go_file).unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("Tabs: 8");
+    harness.assert_screen_not_contains("Spaces:");
+}
+
+#[test]
+fn test_revert_reapplies_editorconfig() {
+    let project = project_with_editorconfig(EDITORCONFIG);
+    let py_file = project.path().join("app.py");
+    fs::write(&py_file, "import os\n").unwrap();
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        80,
+        24,
+        Config::default(),
+        project.path().to_path_buf(),
+    )
+    .unwrap();
+    harness.open_file(&py_file).unwrap();
+
+    fs::write(&py_file, "import sys\n").unwrap();
+    assert!(harness.editor_mut().revert_file().unwrap());
+
+    harness.assert_buffer_content("import sys\n");
+    let state = harness.editor().active_state();
+    assert!(!state.use_tabs);
+    assert_eq!(state.tab_size, 2);
+}
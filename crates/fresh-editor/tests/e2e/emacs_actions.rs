@@ -399,3 +399,70 @@ fn test_ctrl_g_cancels_mark_mode() {
         "deselect_on_move should be true (mark mode cancelled)"
     );
 }
+
+// =============================================================================
+// Pending Chord (C-x ...) Indicator Tests
+// =============================================================================
+
+/// Create an Emacs harness with a chord timeout configured
+fn emacs_harness_with_chord_timeout(chord_timeout_ms: u64) -> EditorTestHarness {
+    let mut config = Config {
+        active_keybinding_map: "emacs".into(),
+        ..Default::default()
+    };
+    config.editor.chord_timeout_ms = chord_timeout_ms;
+    EditorTestHarness::create(
+        80,
+        24,
+        HarnessOptions::new()
+            .with_config(config)
+            .with_preserved_keybinding_map(),
+    )
+    .unwrap()
+}
+
+/// Test that the pending chord keys and countdown show until the chord completes
+#[test]
+fn test_pending_chord_indicator_clears_on_completion() {
+    let mut harness = emacs_harness_with_chord_timeout(2000);
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('x'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_screen_contains("[Ctrl+X 2s]");
+
+    // C-x 3 completes the chord (split vertically)
+    harness
+        .send_key(KeyCode::Char('3'), KeyModifiers::NONE)
+        .unwrap();
+    harness.assert_screen_not_contains("[Ctrl+X");
+    harness.assert_buffer_content("");
+}
+
+/// Test that a pending chord counts down and is abandoned after the timeout
+#[test]
+fn test_pending_chord_times_out() {
+    let mut harness = emacs_harness_with_chord_timeout(2000);
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('x'), KeyModifiers::CONTROL)
+        .unwrap();
+
+    harness.advance_time(std::time::Duration::from_millis(1200));
+    assert!(harness.editor_mut().check_chord_timeout());
+    harness.render().unwrap();
+    harness.assert_screen_contains("[Ctrl+X 1s]");
+
+    harness.advance_time(std::time::Duration::from_millis(1000));
+    assert!(harness.editor_mut().check_chord_timeout());
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("[Ctrl+X");
+
+    // The chord was abandoned, so "3" is typed instead of splitting
+    harness
+        .send_key(KeyCode::Char('3'), KeyModifiers::NONE)
+        .unwrap();
+    harness.assert_buffer_content("3");
+}
@@ -0,0 +1,89 @@
+//! E2E tests for exporting the buffer or selection as an HTML fragment
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use ratatui::style::Color;
+use tempfile::TempDir;
+
+/// Helper to run a command from the command palette
+fn run_command(harness: &mut EditorTestHarness, command_name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(command_name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+fn css(color: Color) -> String {
+    match color {
+        Color::Rgb(r, g, b) => format!("#{:02x}{:02x}{:02x}", r, g, b),
+        other => panic!("expected an RGB theme color, got {:?}", other),
+    }
+}
+
+/// Open a small Rust file so it gets syntax highlighting
+fn open_rust_file(temp_dir: &TempDir) -> EditorTestHarness {
+    let file_path = temp_dir.path().join("main.rs");
+    std::fs::write(&file_path, "fn main() {\n    let s = \"<a&b>\";\n}\n").unwrap();
+
+    // The dark theme uses RGB colors throughout
+    let config = Config {
+        theme: "dark".into(),
+        ..Default::default()
+    };
+    let mut harness = EditorTestHarness::with_config(100, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+    harness
+}
+
+#[test]
+fn test_export_buffer_to_html() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut harness = open_rust_file(&temp_dir);
+    let source = harness.editor().active_buffer();
+    let keyword = css(harness.editor().theme().syntax_keyword);
+    let background = css(harness.editor().theme().editor_bg);
+
+    run_command(&mut harness, "Export to HTML");
+
+    assert_ne!(harness.editor().active_buffer(), source);
+    let html = harness.get_buffer_content().unwrap();
+    assert!(html.starts_with(&format!("<pre style=\"background-color:{};", background)));
+    assert!(html.ends_with("</pre>"));
+    assert!(html.contains(&format!("<span style=\"color:{};\">fn</span>", keyword)));
+    assert!(html.contains(&format!("<span style=\"color:{};\">let</span>", keyword)));
+    // Special characters are escaped, including inside highlighted strings
+    assert!(html.contains("&lt;a&amp;b&gt;"));
+    assert!(!html.contains("<a&b>"));
+    assert!(!html.contains("user-select:none"));
+}
+
+#[test]
+fn test_export_selection_to_html_with_line_numbers() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut harness = open_rust_file(&temp_dir);
+    let keyword = css(harness.editor().theme().syntax_keyword);
+    let line_number = css(harness.editor().theme().line_number_fg);
+
+    // Select the second line
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+        .unwrap();
+
+    run_command(&mut harness, "Export to HTML with Line Numbers");
+
+    let html = harness.get_buffer_content().unwrap();
+    assert!(html.contains(&format!(
+        "<span style=\"color:{};user-select:none;\">2 </span>    <span style=\"color:{};\">let</span>",
+        line_number, keyword
+    )));
+    assert!(!html.contains("main"));
+    assert_eq!(html.matches("user-select:none").count(), 1);
+}
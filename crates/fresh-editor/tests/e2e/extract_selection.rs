@@ -0,0 +1,93 @@
+//! E2E tests for extracting the selection into a new buffer:
+//! - Extract Selection to New Buffer (copy)
+//! - Move Selection to New Buffer (remove from source in one undo step)
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use tempfile::TempDir;
+
+/// Helper to run a command from the command palette
+fn run_command(harness: &mut EditorTestHarness, command_name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(command_name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Open a three-line file and select the whole middle line
+fn open_with_middle_line_selected(temp_dir: &TempDir) -> EditorTestHarness {
+    let file_path = temp_dir.path().join("source.txt");
+    std::fs::write(&file_path, "alpha\nbeta\ngamma\n").unwrap();
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+        .unwrap();
+    harness.render().unwrap();
+    harness
+}
+
+#[test]
+fn test_extract_selection_to_new_buffer() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut harness = open_with_middle_line_selected(&temp_dir);
+    let source = harness.editor().active_buffer();
+
+    run_command(&mut harness, "Extract Selection to New Buffer");
+
+    // The new buffer holds the selection and has no file behind it
+    assert_ne!(harness.editor().active_buffer(), source);
+    harness.assert_buffer_content("beta\n");
+    let state = harness.editor().active_state();
+    assert!(state.buffer.file_path().is_none());
+    assert!(state.buffer.is_modified());
+
+    // The source is left untouched
+    harness.editor_mut().switch_buffer(source);
+    harness.assert_buffer_content("alpha\nbeta\ngamma\n");
+}
+
+#[test]
+fn test_move_selection_to_new_buffer() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut harness = open_with_middle_line_selected(&temp_dir);
+    let source = harness.editor().active_buffer();
+
+    run_command(&mut harness, "Move Selection to New Buffer");
+
+    assert_ne!(harness.editor().active_buffer(), source);
+    harness.assert_buffer_content("beta\n");
+
+    // The selection was removed from the source, and one undo restores it
+    harness.editor_mut().switch_buffer(source);
+    harness.render().unwrap();
+    harness.assert_buffer_content("alpha\ngamma\n");
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("alpha\nbeta\ngamma\n");
+}
+
+/// Without a selection nothing is extracted and no buffer is created
+#[test]
+fn test_extract_selection_without_selection() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("source.txt");
+    std::fs::write(&file_path, "alpha\n").unwrap();
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    let source = harness.editor().active_buffer();
+
+    run_command(&mut harness, "Move Selection to New Buffer");
+
+    assert_eq!(harness.editor().active_buffer(), source);
+    harness.assert_buffer_content("alpha\n");
+    harness.assert_screen_contains("No selection");
+}
@@ -591,6 +591,56 @@ fn test_file_explorer_git_change_indicator() {
     );
 }
 
+/// Test the built-in git status markers, which work without the git plugin
+#[test]
+#[cfg_attr(windows, ignore)]
+fn test_file_explorer_builtin_git_status_markers() {
+    let repo = GitTestRepo::new();
+    repo.create_file("changed.txt", "one");
+    repo.create_file("clean.txt", "same");
+    repo.create_file("subdir/child.txt", "alpha");
+    repo.git_add_all();
+    repo.git_commit("Initial commit");
+
+    fs::write(repo.path.join("changed.txt"), "two").unwrap();
+    fs::write(repo.path.join("subdir/child.txt"), "beta").unwrap();
+    fs::write(repo.path.join("new.txt"), "fresh").unwrap();
+
+    let mut harness = EditorTestHarness::with_working_dir(120, 40, repo.path.clone()).unwrap();
+    harness.editor_mut().focus_file_explorer();
+    harness.wait_for_file_explorer().unwrap();
+    harness.wait_for_file_explorer_item("changed.txt").unwrap();
+
+    let marker = |harness: &EditorTestHarness, name: &str| {
+        let screen = harness.screen_to_string();
+        let line = screen
+            .lines()
+            .find(|line| line.contains(&format!(" {name} ")))
+            .unwrap_or_else(|| panic!("{name} not listed. Screen:\n{screen}"))
+            .to_string();
+        let explorer_part = line.split('│').nth(1).unwrap_or(&line).trim().to_string();
+        explorer_part
+            .rsplit(' ')
+            .next()
+            .filter(|last| *last != name)
+            .map(str::to_string)
+    };
+
+    assert_eq!(marker(&harness, "changed.txt").as_deref(), Some("M"));
+    assert_eq!(marker(&harness, "new.txt").as_deref(), Some("U"));
+    assert_eq!(marker(&harness, "subdir").as_deref(), Some("●"));
+    assert_eq!(marker(&harness, "clean.txt"), None);
+
+    // The status is read again when the explorer is refreshed
+    fs::write(repo.path.join("clean.txt"), "different").unwrap();
+    harness
+        .send_key(KeyCode::Char('r'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .wait_until(|h| marker(h, "clean.txt").as_deref() == Some("M"))
+        .unwrap();
+}
+
 /// Test that file_explorer_new_file can be called (smoke test)
 #[test]
 fn test_file_explorer_new_file_smoke() {
@@ -2369,3 +2419,247 @@ fn test_file_explorer_directory_status_indicator_hover_tooltip() {
 
     println!("Screen after hover:\n{}", harness.screen_to_string());
 }
+
+/// Path of the node currently selected in the file explorer
+fn selected_explorer_path(harness: &EditorTestHarness) -> Option<std::path::PathBuf> {
+    let explorer = harness.editor().file_explorer()?;
+    let selected = explorer.get_selected()?;
+    explorer
+        .tree()
+        .get_node(selected)
+        .map(|node| node.entry.path.clone())
+}
+
+/// Test creating a directory and a file under the selected directory, with the
+/// new entry selected after each operation
+#[test]
+fn test_file_explorer_create_under_selected_directory() {
+    let mut harness = EditorTestHarness::with_temp_project(120, 40).unwrap();
+    let project_root = harness.project_dir().unwrap();
+    fs::create_dir(project_root.join("src")).unwrap();
+    fs::write(project_root.join("src/lib.rs"), "").unwrap();
+
+    harness.editor_mut().focus_file_explorer();
+    harness.wait_for_file_explorer().unwrap();
+    harness.wait_for_file_explorer_item("src").unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    assert_eq!(
+        selected_explorer_path(&harness),
+        Some(project_root.join("src"))
+    );
+
+    // New directory inside the selected directory
+    harness
+        .send_key(
+            KeyCode::Char('n'),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+        )
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("nested").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert!(project_root.join("src/nested").is_dir());
+    assert_eq!(
+        selected_explorer_path(&harness),
+        Some(project_root.join("src/nested"))
+    );
+    harness.assert_screen_contains("nested");
+
+    // New file inside the freshly created directory
+    harness
+        .send_key(KeyCode::Char('n'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("main.rs").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert!(project_root.join("src/nested/main.rs").is_file());
+    assert_eq!(
+        selected_explorer_path(&harness),
+        Some(project_root.join("src/nested/main.rs"))
+    );
+    harness.assert_screen_contains("main.rs");
+}
+
+/// Test that renaming refuses to overwrite an existing entry and reports it
+#[test]
+fn test_file_explorer_rename_reports_existing_target() {
+    let mut harness = EditorTestHarness::with_temp_project(120, 40).unwrap();
+    let project_root = harness.project_dir().unwrap();
+    fs::write(project_root.join("a.txt"), "alpha").unwrap();
+    fs::write(project_root.join("b.txt"), "beta").unwrap();
+
+    harness.editor_mut().focus_file_explorer();
+    harness.wait_for_file_explorer().unwrap();
+    harness.wait_for_file_explorer_item("a.txt").unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+
+    let rename_to = |harness: &mut EditorTestHarness, name: &str| {
+        harness.send_key(KeyCode::F(2), KeyModifiers::NONE).unwrap();
+        harness
+            .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.type_text(name).unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+        harness.render().unwrap();
+    };
+
+    rename_to(&mut harness, "b.txt");
+    assert!(harness.get_status_bar().contains("b.txt already exists"));
+    assert_eq!(
+        fs::read_to_string(project_root.join("a.txt")).unwrap(),
+        "alpha"
+    );
+    assert_eq!(
+        fs::read_to_string(project_root.join("b.txt")).unwrap(),
+        "beta"
+    );
+
+    rename_to(&mut harness, "sub/c.txt");
+    assert!(harness.get_status_bar().contains("Invalid name"));
+    assert!(project_root.join("a.txt").exists());
+
+    rename_to(&mut harness, "c.txt");
+    assert!(!project_root.join("a.txt").exists());
+    assert_eq!(
+        fs::read_to_string(project_root.join("c.txt")).unwrap(),
+        "alpha"
+    );
+    assert_eq!(
+        selected_explorer_path(&harness),
+        Some(project_root.join("c.txt"))
+    );
+}
+
+/// Test that delete asks for confirmation and then selects the next entry
+#[test]
+fn test_file_explorer_delete_with_confirmation() {
+    let mut harness = EditorTestHarness::with_temp_project(120, 40).unwrap();
+    let project_root = harness.project_dir().unwrap();
+    fs::write(project_root.join("a.txt"), "alpha").unwrap();
+    fs::write(project_root.join("b.txt"), "beta").unwrap();
+
+    harness.editor_mut().focus_file_explorer();
+    harness.wait_for_file_explorer().unwrap();
+    harness.wait_for_file_explorer_item("a.txt").unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+
+    // Declining keeps the file
+    harness
+        .send_key(KeyCode::Delete, KeyModifiers::NONE)
+        .unwrap();
+    harness.assert_screen_contains("Delete file 'a.txt'?");
+    harness.type_text("n").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert!(harness.get_status_bar().contains("Delete cancelled"));
+    assert!(project_root.join("a.txt").exists());
+
+    // Confirming removes it and moves the selection to the next entry
+    harness
+        .send_key(KeyCode::Delete, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("y").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert!(!project_root.join("a.txt").exists());
+    assert!(project_root.join("b.txt").exists());
+    assert_eq!(
+        selected_explorer_path(&harness),
+        Some(project_root.join("b.txt"))
+    );
+}
+
+/// Test filtering the file explorer by name as the user types
+#[test]
+fn test_file_explorer_filter_hides_non_matching_entries() {
+    let mut harness = EditorTestHarness::with_temp_project(120, 40).unwrap();
+    let project_root = harness.project_dir().unwrap();
+    fs::create_dir(project_root.join("docs")).unwrap();
+    fs::write(project_root.join("docs/guide.md"), "").unwrap();
+    fs::create_dir(project_root.join("src")).unwrap();
+    fs::write(project_root.join("src/main.rs"), "").unwrap();
+    fs::write(project_root.join("src/lib.rs"), "").unwrap();
+    fs::write(project_root.join("Cargo.toml"), "").unwrap();
+
+    harness.editor_mut().focus_file_explorer();
+    harness.wait_for_file_explorer().unwrap();
+    harness.wait_for_file_explorer_item("src").unwrap();
+
+    // Expand src (root, docs, src)
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_file_explorer_item("main.rs").unwrap();
+
+    let explorer_entries = |harness: &EditorTestHarness| {
+        let explorer = harness.editor().file_explorer().unwrap();
+        explorer
+            .get_display_nodes()
+            .into_iter()
+            .skip(1)
+            .map(|(id, _)| explorer.tree().get_node(id).unwrap().entry.name.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        explorer_entries(&harness),
+        vec!["docs", "src", "lib.rs", "main.rs", "Cargo.toml"]
+    );
+
+    // Typing narrows the tree incrementally, keeping parent directories
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+        .unwrap();
+    harness.assert_screen_contains("Filter:");
+    harness.type_text("mai").unwrap();
+    assert_eq!(explorer_entries(&harness), vec!["src", "main.rs"]);
+    harness.type_text("n.r").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(explorer_entries(&harness), vec!["src", "main.rs"]);
+    harness.assert_screen_contains("[main.r]");
+    harness.assert_screen_not_contains("guide.md");
+    harness.assert_screen_not_contains("Cargo.toml");
+
+    // Reopening the prompt and clearing it restores the full tree
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(explorer_entries(&harness).len(), 5);
+    assert!(harness.get_status_bar().contains("Filter cleared"));
+
+    // Escape cancels a filter while typing
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("toml").unwrap();
+    assert_eq!(explorer_entries(&harness), vec!["Cargo.toml"]);
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    assert_eq!(explorer_entries(&harness).len(), 5);
+}
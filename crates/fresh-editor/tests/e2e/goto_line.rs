@@ -0,0 +1,143 @@
+//! Tests for Go to Line
+//!
+//! Tests that:
+//! - A plain line number moves to the start of that line
+//! - `line:col` moves to a character column, clamped to the end of the line
+//! - `:col` moves within the current line
+//! - `line:col` works in large file mode, where line offsets are estimated
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use tempfile::TempDir;
+
+/// Numbered lines of the form `line N: text`
+fn numbered_lines(count: usize) -> String {
+    (1..=count).map(|i| format!("line {i}: text\n")).collect()
+}
+
+/// Byte offset of the start of 1-indexed `line` in `content`
+fn line_start(content: &str, line: usize) -> usize {
+    content
+        .split_inclusive('\n')
+        .take(line - 1)
+        .map(str::len)
+        .sum()
+}
+
+/// Open `content` in a harness built from `config`
+fn open_content(config: Config, content: &str) -> (TempDir, EditorTestHarness) {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("lines.txt");
+    std::fs::write(&file_path, content).unwrap();
+
+    let mut harness = EditorTestHarness::with_config(120, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+    (temp_dir, harness)
+}
+
+/// Open the Go to Line prompt and confirm `input`
+fn goto(harness: &mut EditorTestHarness, input: &str) {
+    harness
+        .send_key(KeyCode::Char('g'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Go to line:");
+    harness.type_text(input).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Test that a plain line number still jumps to the start of the line
+#[test]
+fn test_goto_line_number() {
+    let content = numbered_lines(20);
+    let (_temp_dir, mut harness) = open_content(Config::default(), &content);
+
+    goto(&mut harness, "12");
+
+    assert_eq!(harness.cursor_position(), line_start(&content, 12));
+    harness.assert_screen_contains("Jumped to line 12");
+}
+
+/// Test that `line:col` jumps to a 1-indexed column on the line
+#[test]
+fn test_goto_line_and_column() {
+    let content = numbered_lines(20);
+    let (_temp_dir, mut harness) = open_content(Config::default(), &content);
+
+    goto(&mut harness, "12:5");
+
+    assert_eq!(harness.cursor_position(), line_start(&content, 12) + 4);
+    harness.assert_screen_contains("Jumped to line 12, column 5");
+}
+
+/// Test that a column past the end of the line stops before the line ending
+#[test]
+fn test_goto_column_clamped_to_line_length() {
+    let content = "short\r\nmuch longer line\r\n";
+    let (_temp_dir, mut harness) = open_content(Config::default(), content);
+
+    goto(&mut harness, "1:99");
+
+    assert_eq!(harness.cursor_position(), "short".len());
+}
+
+/// Test that the column counts characters rather than bytes
+#[test]
+fn test_goto_column_counts_characters() {
+    let content = "héllo wörld\n";
+    let (_temp_dir, mut harness) = open_content(Config::default(), content);
+
+    goto(&mut harness, "1:8");
+
+    assert_eq!(harness.cursor_position(), "héllo w".len());
+}
+
+/// Test that `:col` moves within the current line
+#[test]
+fn test_goto_column_on_current_line() {
+    let content = numbered_lines(20);
+    let (_temp_dir, mut harness) = open_content(Config::default(), &content);
+
+    for _ in 0..3 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+    goto(&mut harness, ":3");
+
+    assert_eq!(harness.cursor_position(), line_start(&content, 4) + 2);
+    harness.assert_screen_contains("Jumped to column 3");
+}
+
+/// Test that zero and malformed input leave the cursor where it was
+#[test]
+fn test_goto_line_rejects_invalid_input() {
+    let content = numbered_lines(20);
+    let (_temp_dir, mut harness) = open_content(Config::default(), &content);
+
+    goto(&mut harness, "3:0");
+    assert_eq!(harness.cursor_position(), 0);
+
+    goto(&mut harness, "3:x");
+    assert_eq!(harness.cursor_position(), 0);
+}
+
+/// Test that `line:col` lands on a line start in large file mode
+#[test]
+fn test_goto_line_and_column_large_file() {
+    let content = numbered_lines(100);
+    let mut config = Config::default();
+    config.editor.large_file_threshold_bytes = 500;
+    let (_temp_dir, mut harness) = open_content(config, &content);
+
+    goto(&mut harness, "5:3");
+
+    // Large file mode estimates the line offset, so only check that the cursor
+    // is two characters into whichever line it landed on
+    let position = harness.cursor_position();
+    let landed_start = content[..position].rfind('\n').map_or(0, |i| i + 1);
+    assert_eq!(position - landed_start, 2);
+}
@@ -0,0 +1,85 @@
+//! E2E tests for in-editor help: the per-context keybindings list
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+/// Helper to run a command from the command palette
+fn run_command(harness: &mut EditorTestHarness, command_name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(command_name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Show Keybindings opened from the editor lists Normal-context bindings
+#[test]
+fn test_show_keybindings_lists_normal_bindings() {
+    let mut harness = EditorTestHarness::new(100, 30).unwrap();
+
+    run_command(&mut harness, "Show Keybindings");
+
+    harness.assert_screen_contains("Keybindings (normal):");
+
+    // Filter down to a known binding: Ctrl+O opens a file
+    harness.type_text("open file").unwrap();
+    harness.render().unwrap();
+
+    let screen = harness.screen_to_string();
+    let row = screen
+        .lines()
+        .find(|line| line.contains("Open file"))
+        .expect("Open file should be listed");
+    assert!(row.contains("Ctrl+O"), "row: {row}");
+    // Entries are grouped by action category
+    assert!(row.contains("File"), "row: {row}");
+}
+
+/// Fuzzy filtering narrows the list, and Escape closes it without side effects
+#[test]
+fn test_show_keybindings_filter_and_cancel() {
+    let mut harness = EditorTestHarness::new(100, 30).unwrap();
+
+    run_command(&mut harness, "Show Keybindings");
+    harness.type_text("svfl").unwrap();
+    harness.render().unwrap();
+
+    // "svfl" fuzzy-matches "Save file" but not "Open file"
+    harness.assert_screen_contains("Save file");
+    harness.assert_screen_not_contains("Open file");
+
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Keybindings (normal):");
+    harness.assert_buffer_content("");
+}
+
+/// F1 inside a prompt shows the prompt's own keys without taking focus from it
+#[test]
+fn test_context_help_in_prompt_shows_prompt_bindings() {
+    let mut harness = EditorTestHarness::new(100, 30).unwrap();
+
+    // Open the Go To Line prompt
+    harness
+        .send_key(KeyCode::Char('g'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.send_key(KeyCode::F(1), KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("Help: prompt");
+    harness.assert_screen_contains("Confirm prompt");
+    harness.assert_screen_contains("Enter");
+    // Editing bindings from the normal context are not listed
+    harness.assert_screen_not_contains("Open file");
+
+    // Typing keeps going to the prompt and dismisses the help
+    harness.type_text("12").unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Help: prompt");
+    assert!(harness.get_prompt_line().contains("12"));
+}
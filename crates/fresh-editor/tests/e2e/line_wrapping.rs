@@ -1681,3 +1681,65 @@ fn test_mouse_click_wrapped_thai_grapheme_clusters() {
         pos
     );
 }
+
+/// Test that per-language line_wrap/line_numbers overrides decide the initial view state
+#[test]
+fn test_per_language_wrap_and_line_number_defaults() {
+    let mut config = Config {
+        editor: fresh::config::EditorConfig {
+            line_wrap: false,
+            line_numbers: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let markdown = config.languages.get_mut("markdown").unwrap();
+    markdown.line_wrap = Some(true);
+    markdown.line_numbers = Some(false);
+
+    let mut harness = EditorTestHarness::with_temp_project_and_config(80, 24, config).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    let md_path = project_dir.join("notes.md");
+    let rs_path = project_dir.join("main.rs");
+    std::fs::write(&md_path, "# Notes\n").unwrap();
+    std::fs::write(&rs_path, "fn main() {}\n").unwrap();
+
+    // Markdown picks up its language overrides
+    harness.open_file(&md_path).unwrap();
+    assert!(harness.editor().active_viewport().line_wrap_enabled);
+    assert!(!harness.editor().active_state().margins.show_line_numbers);
+
+    // Rust has no overrides, so it falls back to the editor settings even in
+    // the split that just showed the markdown file
+    harness.open_file(&rs_path).unwrap();
+    assert!(!harness.editor().active_viewport().line_wrap_enabled);
+    assert!(harness.editor().active_state().margins.show_line_numbers);
+
+    // Toggling in the markdown buffer flips only that buffer's setting
+    harness.open_file(&md_path).unwrap();
+    assert!(harness.editor().active_viewport().line_wrap_enabled);
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Toggle Line Wrap").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    assert!(!harness.editor().active_viewport().line_wrap_enabled);
+    assert!(!harness.editor().config().editor.line_wrap);
+
+    // Toggling in the Rust buffer flips the global setting
+    harness.open_file(&rs_path).unwrap();
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Toggle Line Wrap").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    assert!(harness.editor().active_viewport().line_wrap_enabled);
+    assert!(harness.editor().config().editor.line_wrap);
+
+    harness.open_file(&md_path).unwrap();
+    assert!(!harness.editor().active_viewport().line_wrap_enabled);
+}
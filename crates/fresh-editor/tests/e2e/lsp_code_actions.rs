@@ -0,0 +1,127 @@
+//! E2E tests for the LSP code actions popup
+
+use crate::common::fake_lsp::FakeLspServer;
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+const SOURCE: &str = "fn main() {\n    let x = 1;\n}\n";
+
+/// Open SOURCE with the fake LSP server
+fn harness_with_lsp(temp_dir: &tempfile::TempDir) -> anyhow::Result<EditorTestHarness> {
+    let test_file = temp_dir.path().join("test.rs");
+    std::fs::write(&test_file, SOURCE)?;
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::services::lsp::LspServerConfig {
+            command: FakeLspServer::script_path().to_string_lossy().to_string(),
+            args: vec![],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+        },
+    );
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        100,
+        30,
+        config,
+        temp_dir.path().to_path_buf(),
+    )?;
+    harness.open_file(&test_file)?;
+    harness.render()?;
+
+    Ok(harness)
+}
+
+/// Confirming an edit-style code action applies its workspace edit
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_code_action_applies_edit() -> anyhow::Result<()> {
+    let _fake_server = FakeLspServer::spawn()?;
+    let temp_dir = tempfile::tempdir()?;
+    let mut harness = harness_with_lsp(&temp_dir)?;
+
+    harness.send_key(KeyCode::Char('.'), KeyModifiers::CONTROL)?;
+    harness.wait_for_screen_contains("Code Actions")?;
+    harness.assert_screen_contains("Insert header comment");
+    harness.assert_screen_contains("Append footer");
+
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+    harness.wait_for_buffer_content(&format!("// header {SOURCE}"))?;
+    harness.assert_screen_not_contains("Insert header comment");
+    assert_eq!(
+        harness.editor().get_status_message().map(String::as_str),
+        Some("Applied code action: Insert header comment")
+    );
+
+    Ok(())
+}
+
+/// Confirming a command-style code action runs it on the server, which sends the edit back
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_code_action_runs_command() -> anyhow::Result<()> {
+    let _fake_server = FakeLspServer::spawn()?;
+    let temp_dir = tempfile::tempdir()?;
+    let mut harness = harness_with_lsp(&temp_dir)?;
+
+    harness.send_key(KeyCode::Char('.'), KeyModifiers::CONTROL)?;
+    harness.wait_for_screen_contains("Append footer")?;
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+    harness.wait_for_buffer_content("fn main() {\n// footer     let x = 1;\n}\n")?;
+
+    Ok(())
+}
+
+/// Escape closes the popup without applying anything
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_code_actions_cancel() -> anyhow::Result<()> {
+    let _fake_server = FakeLspServer::spawn()?;
+    let temp_dir = tempfile::tempdir()?;
+    let mut harness = harness_with_lsp(&temp_dir)?;
+
+    harness.send_key(KeyCode::Char('.'), KeyModifiers::CONTROL)?;
+    harness.wait_for_screen_contains("Insert header comment")?;
+
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE)?;
+    harness.render()?;
+    harness.assert_screen_not_contains("Insert header comment");
+    assert_eq!(harness.get_buffer_content().unwrap(), SOURCE);
+
+    Ok(())
+}
+
+/// A server answering with no actions is reported in the status bar
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_no_code_actions_available() -> anyhow::Result<()> {
+    let _fake_server = FakeLspServer::spawn()?;
+    let temp_dir = tempfile::tempdir()?;
+    let mut harness = harness_with_lsp(&temp_dir)?;
+
+    // The fake server only offers actions on the first line
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::Char('.'), KeyModifiers::CONTROL)?;
+    harness.wait_for_screen_contains("No code actions available")?;
+    assert_eq!(harness.get_buffer_content().unwrap(), SOURCE);
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+//! E2E tests for the LSP document symbols outline popup
+
+use crate::common::fake_lsp::FakeLspServer;
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+const SOURCE: &str = "struct Point {\n    x: i32,\n}\n\nfn main() {\n    let p = 1;\n}\n";
+
+/// Open `content` as `file_name` with the fake LSP server
+fn harness_with_lsp(
+    temp_dir: &tempfile::TempDir,
+    file_name: &str,
+    content: &str,
+) -> anyhow::Result<EditorTestHarness> {
+    let test_file = temp_dir.path().join(file_name);
+    std::fs::write(&test_file, content)?;
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::services::lsp::LspServerConfig {
+            command: FakeLspServer::script_path().to_string_lossy().to_string(),
+            args: vec![],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+        },
+    );
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        100,
+        30,
+        config,
+        temp_dir.path().to_path_buf(),
+    )?;
+    harness.open_file(&test_file)?;
+    harness.render()?;
+
+    Ok(harness)
+}
+
+/// Run "Document Symbols" from the command palette
+fn show_document_symbols(harness: &mut EditorTestHarness) -> anyhow::Result<()> {
+    harness.send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)?;
+    harness.type_text("Document Symbols")?;
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+    harness.render()?;
+    Ok(())
+}
+
+/// The outline lists nested symbols with kind icons, and confirming jumps to the selected one
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_document_symbols_jumps_to_selected_symbol() -> anyhow::Result<()> {
+    let _fake_server = FakeLspServer::spawn()?;
+    let temp_dir = tempfile::tempdir()?;
+    let mut harness = harness_with_lsp(&temp_dir, "test.rs", SOURCE)?;
+
+    show_document_symbols(&mut harness)?;
+    harness.wait_for_screen_contains("λ main")?;
+    harness.assert_screen_contains("S Point");
+    harness.assert_screen_contains("v x i32");
+
+    // Point, x, main
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+    harness.render()?;
+
+    harness.assert_screen_not_contains("λ main");
+    let main_pos = SOURCE.find("main").unwrap();
+    assert_eq!(harness.cursor_position(), main_pos);
+
+    // The jump is recorded in the position history
+    harness.send_key(KeyCode::Left, KeyModifiers::ALT)?;
+    harness.render()?;
+    assert_eq!(harness.cursor_position(), 0);
+
+    Ok(())
+}
+
+/// A server with no symbols for the document is reported in the status bar
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_document_symbols_none_found() -> anyhow::Result<()> {
+    let _fake_server = FakeLspServer::spawn()?;
+    let temp_dir = tempfile::tempdir()?;
+    let mut harness = harness_with_lsp(&temp_dir, "empty.rs", "\n")?;
+
+    show_document_symbols(&mut harness)?;
+    harness.wait_for_screen_contains("No symbols found")?;
+    harness.assert_screen_not_contains("Enter: jump to symbol");
+
+    Ok(())
+}
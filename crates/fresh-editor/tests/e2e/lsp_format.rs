@@ -0,0 +1,148 @@
+//! E2E tests for LSP document and range formatting
+
+use crate::common::fake_lsp::FakeLspServer;
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+const SOURCE: &str = "fn main(){\nlet x=1;\n}\n";
+
+/// Open SOURCE with the fake LSP server, using `tab_size` for indentation
+fn harness_with_lsp(
+    temp_dir: &tempfile::TempDir,
+    tab_size: usize,
+) -> anyhow::Result<EditorTestHarness> {
+    harness_with_lsp_file(temp_dir, tab_size, "test.rs")
+}
+
+/// Open SOURCE as `file_name` with the fake LSP server
+fn harness_with_lsp_file(
+    temp_dir: &tempfile::TempDir,
+    tab_size: usize,
+    file_name: &str,
+) -> anyhow::Result<EditorTestHarness> {
+    let test_file = temp_dir.path().join(file_name);
+    std::fs::write(&test_file, SOURCE)?;
+
+    let mut config = fresh::config::Config::default();
+    config.editor.tab_size = tab_size;
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::services::lsp::LspServerConfig {
+            command: FakeLspServer::script_path().to_string_lossy().to_string(),
+            args: vec![],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+        },
+    );
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        100,
+        30,
+        config,
+        temp_dir.path().to_path_buf(),
+    )?;
+    harness.open_file(&test_file)?;
+    harness.render()?;
+
+    Ok(harness)
+}
+
+/// Run "Format Document" from the command palette
+fn format_document(harness: &mut EditorTestHarness) -> anyhow::Result<()> {
+    harness.send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)?;
+    harness.type_text("Format Document")?;
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+    harness.render()?;
+    Ok(())
+}
+
+/// Formatting applies all of the server's edits, and a single undo reverts them
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_format_document_single_undo() -> anyhow::Result<()> {
+    let _fake_server = FakeLspServer::spawn()?;
+    let temp_dir = tempfile::tempdir()?;
+    let mut harness = harness_with_lsp(&temp_dir, 4)?;
+
+    format_document(&mut harness)?;
+    harness.wait_for_buffer_content("fn main() {\n    let x = 1;\n}\n")?;
+    assert_eq!(
+        harness.editor().get_status_message().map(String::as_str),
+        Some("Formatted (3 edit(s))")
+    );
+
+    harness.send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)?;
+    harness.render()?;
+    assert_eq!(harness.get_buffer_content().unwrap(), SOURCE);
+
+    Ok(())
+}
+
+/// The configured tab size is sent to the server in the formatting options
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_format_document_uses_tab_size() -> anyhow::Result<()> {
+    let _fake_server = FakeLspServer::spawn()?;
+    let temp_dir = tempfile::tempdir()?;
+    let mut harness = harness_with_lsp(&temp_dir, 2)?;
+
+    format_document(&mut harness)?;
+    harness.wait_for_buffer_content("fn main() {\n  let x = 1;\n}\n")?;
+
+    Ok(())
+}
+
+/// With a selection active, only the selected range is formatted
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_format_selection() -> anyhow::Result<()> {
+    let _fake_server = FakeLspServer::spawn()?;
+    let temp_dir = tempfile::tempdir()?;
+    let mut harness = harness_with_lsp(&temp_dir, 4)?;
+
+    // Select the second line
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::End, KeyModifiers::SHIFT)?;
+    format_document(&mut harness)?;
+    harness.wait_for_buffer_content("fn main(){\n    let x=1;\n}\n")?;
+
+    Ok(())
+}
+
+/// Edits for a version of the buffer that was changed while the server was
+/// formatting it are discarded
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_format_response_for_changed_buffer_is_discarded() -> anyhow::Result<()> {
+    let _fake_server = FakeLspServer::spawn()?;
+    let temp_dir = tempfile::tempdir()?;
+    // The fake server takes a while to format files named slow.*
+    let mut harness = harness_with_lsp_file(&temp_dir, 4, "slow.rs")?;
+
+    format_document(&mut harness)?;
+    harness.type_text("x")?;
+    harness.wait_until(|h| {
+        h.editor().get_status_message().map(String::as_str)
+            == Some("Buffer changed while formatting; formatting discarded")
+    })?;
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        format!("x{}", SOURCE)
+    );
+
+    Ok(())
+}
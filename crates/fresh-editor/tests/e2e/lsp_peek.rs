@@ -0,0 +1,128 @@
+//! E2E tests for the LSP peek popup (definitions/references shown inline)
+
+use crate::common::fake_lsp::FakeLspServer;
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+const SOURCE: &str = "fn get_value() -> i32 { 42 }\nfn main() {\n    get_value();\n    let x = 1;\n    get_value();\n}\n";
+
+/// Open SOURCE with the fake LSP server and peeking enabled, cursor on the last call
+fn harness_with_peek(temp_dir: &tempfile::TempDir) -> anyhow::Result<EditorTestHarness> {
+    let test_file = temp_dir.path().join("test.rs");
+    std::fs::write(&test_file, SOURCE)?;
+
+    let mut config = fresh::config::Config::default();
+    config.editor.peek_lsp_locations = true;
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::services::lsp::LspServerConfig {
+            command: FakeLspServer::script_path().to_string_lossy().to_string(),
+            args: vec![],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+        },
+    );
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        100,
+        30,
+        config,
+        temp_dir.path().to_path_buf(),
+    )?;
+    harness.open_file(&test_file)?;
+
+    // Inside `get_value` on line 5: "    get_value();"
+    for _ in 0..4 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    }
+    harness.send_key(KeyCode::End, KeyModifiers::NONE)?;
+    for _ in 0..4 {
+        harness.send_key(KeyCode::Left, KeyModifiers::NONE)?;
+    }
+    harness.render()?;
+
+    Ok(harness)
+}
+
+/// Peek definition shows the target's surrounding lines; Enter jumps there
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_peek_definition_shows_context_and_jumps() -> anyhow::Result<()> {
+    let _fake_server = FakeLspServer::spawn()?;
+    let temp_dir = tempfile::tempdir()?;
+    let mut harness = harness_with_peek(&temp_dir)?;
+    let start = harness.cursor_position();
+
+    // The fake server puts the definition at the start of line 1
+    harness.send_key(KeyCode::F(12), KeyModifiers::NONE)?;
+    harness.wait_for_screen_contains("Peek Definition")?;
+
+    harness.assert_screen_contains("test.rs:1");
+    harness.assert_screen_contains("›    1 fn get_value() -> i32 { 42 }");
+    harness.assert_screen_contains("     3     get_value();");
+    // Peeking does not move the cursor
+    assert_eq!(harness.cursor_position(), start);
+
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+    harness.render()?;
+    harness.assert_screen_not_contains("Peek Definition");
+    assert_eq!(harness.cursor_position(), 0);
+
+    Ok(())
+}
+
+/// Escape closes the peek popup and leaves the cursor where it was
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_peek_definition_dismiss_stays() -> anyhow::Result<()> {
+    let _fake_server = FakeLspServer::spawn()?;
+    let temp_dir = tempfile::tempdir()?;
+    let mut harness = harness_with_peek(&temp_dir)?;
+    let start = harness.cursor_position();
+
+    harness.send_key(KeyCode::F(12), KeyModifiers::NONE)?;
+    harness.wait_for_screen_contains("Peek Definition")?;
+
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE)?;
+    harness.render()?;
+    harness.assert_screen_not_contains("Peek Definition");
+    assert_eq!(harness.cursor_position(), start);
+
+    Ok(())
+}
+
+/// Peek references lists each reference with context; confirming jumps to the selected line
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_peek_references_jumps_to_selected_line() -> anyhow::Result<()> {
+    let _fake_server = FakeLspServer::spawn()?;
+    let temp_dir = tempfile::tempdir()?;
+    let mut harness = harness_with_peek(&temp_dir)?;
+
+    harness.send_key(KeyCode::F(12), KeyModifiers::SHIFT)?;
+    harness.wait_for_screen_contains("Peek References: 'get_value' (3)")?;
+    harness.assert_screen_contains("test.rs:3");
+    harness.assert_screen_contains("›    3     get_value();");
+
+    // The first reference is preselected; move down one line to its neighbour
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::Enter, KeyModifiers::NONE)?;
+    harness.render()?;
+
+    // Line 2 ("fn main() {") starts at byte 29
+    assert_eq!(harness.cursor_position(), 29);
+    harness.assert_screen_not_contains("Peek References");
+
+    Ok(())
+}
@@ -326,3 +326,52 @@ fn test_macro_playback_is_undoable() {
         screen_after_undo
     );
 }
+
+/// Test replaying a recorded insert+move sequence twice with Play Last Macro N Times
+#[test]
+fn test_play_last_macro_n_times() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("list.txt");
+    std::fs::write(&file_path, "a\nb\nc\n").unwrap();
+
+    let mut harness = EditorTestHarness::new(120, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    let run_command = |harness: &mut EditorTestHarness, name: &str| {
+        harness
+            .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.wait_for_prompt().unwrap();
+        harness.type_text(name).unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+        harness.render().unwrap();
+    };
+
+    // Record "prefix the line, then move to the start of the next one"
+    run_command(&mut harness, "Record Macro");
+    harness.type_text("0").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("- ").unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    run_command(&mut harness, "Stop Recording");
+    assert_eq!(harness.get_buffer_content().unwrap(), "- a\nb\nc\n");
+
+    // Replay it twice
+    run_command(&mut harness, "Play Last Macro N Times");
+    harness.assert_screen_contains("Play last macro how many times:");
+    harness.type_text("2").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "- a\n- b\n- c\n");
+    harness.assert_screen_contains("Played macro '0' 2 times");
+}
@@ -549,3 +549,84 @@ fn test_line_numbers_update_with_navigation_keys() {
     harness.render().unwrap();
     harness.assert_screen_contains("   1 │");
 }
+
+/// Build a diagnostic with the given severity on a single line
+fn diagnostic(
+    line: u32,
+    start: u32,
+    end: u32,
+    severity: lsp_types::DiagnosticSeverity,
+) -> lsp_types::Diagnostic {
+    lsp_types::Diagnostic {
+        range: lsp_types::Range {
+            start: lsp_types::Position {
+                line,
+                character: start,
+            },
+            end: lsp_types::Position {
+                line,
+                character: end,
+            },
+        },
+        severity: Some(severity),
+        message: "test diagnostic".to_string(),
+        ..Default::default()
+    }
+}
+
+/// Test that diagnostics draw a severity glyph in the gutter and style their range
+#[test]
+fn test_margin_diagnostic_glyphs_and_ranges() {
+    use lsp_types::DiagnosticSeverity;
+    use ratatui::style::Modifier;
+
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "let x = oops;\nlet y = 2;\nlet z = 3;\n").unwrap();
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+
+    // Line 1 has a warning and an error, line 2 only a warning
+    let diagnostics = vec![
+        diagnostic(0, 4, 5, DiagnosticSeverity::WARNING),
+        diagnostic(0, 8, 12, DiagnosticSeverity::ERROR),
+        diagnostic(1, 4, 5, DiagnosticSeverity::WARNING),
+    ];
+    let theme = harness.editor().theme().clone();
+    let state = harness.editor_mut().active_state_mut();
+    fresh::services::lsp::diagnostics::apply_diagnostics_to_state(state, &diagnostics, &theme);
+    harness.render().unwrap();
+
+    let screen = harness.screen_to_string();
+    let rows: Vec<&str> = screen.lines().collect();
+    let find_row = |text: &str| rows.iter().position(|row| row.contains(text)).unwrap();
+
+    // The most severe diagnostic on a line picks the glyph
+    let error_row = find_row("let x = oops;");
+    assert!(
+        rows[error_row].starts_with('●'),
+        "Expected error glyph, got: {}",
+        rows[error_row]
+    );
+    let warning_row = find_row("let y = 2;");
+    assert!(
+        rows[warning_row].starts_with('▲'),
+        "Expected warning glyph, got: {}",
+        rows[warning_row]
+    );
+    let clean_row = find_row("let z = 3;");
+    assert!(rows[clean_row].starts_with(' '));
+
+    // The error range gets the error background and an underline in the error color
+    let col = rows[error_row].chars().position(|c| c == 'o').unwrap() as u16;
+    let style = harness.get_cell_style(col, error_row as u16).unwrap();
+    assert_eq!(style.bg, Some(theme.diagnostic_error_bg));
+    assert_eq!(style.underline_color, Some(theme.diagnostic_error_fg));
+    assert!(style.add_modifier.contains(Modifier::UNDERLINED));
+
+    // Text outside any diagnostic is left alone
+    let col = rows[error_row].chars().position(|c| c == 'l').unwrap() as u16;
+    let style = harness.get_cell_style(col, error_row as u16).unwrap();
+    assert!(!style.add_modifier.contains(Modifier::UNDERLINED));
+}
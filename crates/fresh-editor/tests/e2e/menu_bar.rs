@@ -311,8 +311,8 @@ fn test_mouse_click_undo_menu_item() {
     // Undo should have reversed the last text insertion
     // Menu should be closed
     harness.assert_screen_not_contains("Undo");
-    // The last character should be undone (type_text inserts char by char)
-    harness.assert_buffer_content("Hello Worl");
+    // The last typed word should be undone
+    harness.assert_buffer_content("Hello ");
 }
 
 /// Test that View menu File Explorer checkbox syncs with actual file explorer state
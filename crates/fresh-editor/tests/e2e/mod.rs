@@ -1,3 +1,4 @@
+pub mod align_on_delimiter;
 pub mod ansi_cursor;
 pub mod auto_indent;
 pub mod auto_revert;
@@ -7,14 +8,22 @@ pub mod buffer_lifecycle;
 pub mod buffer_settings_commands;
 pub mod case_conversion;
 pub mod command_palette;
+pub mod config_reload;
 pub mod crash_repro;
 pub mod crlf_rendering;
+pub mod cursor_style;
+pub mod delete_to_line;
 pub mod document_model;
+pub mod editorconfig;
 pub mod emacs_actions;
 pub mod explorer_menu;
+pub mod export_html;
+pub mod extract_selection;
 pub mod file_browser;
 pub mod file_explorer;
 pub mod file_permissions;
+pub mod goto_line;
+pub mod help;
 pub mod indent_dedent;
 pub mod language_features_e2e;
 pub mod large_file_mode;
@@ -23,7 +32,11 @@ pub mod line_wrapping;
 pub mod live_grep;
 pub mod locale;
 pub mod lsp;
+pub mod lsp_code_actions;
+pub mod lsp_document_symbols;
+pub mod lsp_format;
 pub mod lsp_order;
+pub mod lsp_peek;
 pub mod macros;
 pub mod margin;
 pub mod markdown_compose;
@@ -36,7 +49,9 @@ pub mod multibyte_characters;
 pub mod multicursor;
 pub mod on_save_actions;
 pub mod open_folder;
+pub mod overwrite_mode;
 pub mod paste;
+pub mod persistent_undo;
 #[cfg(feature = "plugins")]
 pub mod plugins;
 pub mod popup_selection;
@@ -46,8 +61,11 @@ pub mod position_history_debug;
 pub mod position_history_truncate_debug;
 pub mod prompt;
 pub mod prompt_editing;
+pub mod quickfix;
 pub mod recovery;
 pub mod rendering;
+pub mod repeat_last_edit;
+pub mod revert;
 pub mod save_as_language_detection;
 pub mod scroll_clearing;
 pub mod scrolling;
@@ -60,9 +78,11 @@ pub mod settings_paste;
 pub mod shell_command;
 pub mod slow_filesystem;
 pub mod smart_editing;
+pub mod sort_lines;
 pub mod split_tabs;
 pub mod split_view;
 pub mod split_view_expectations;
+pub mod status_bar;
 pub mod stdin_input;
 pub mod sudo_save_prompt;
 #[cfg(unix)]
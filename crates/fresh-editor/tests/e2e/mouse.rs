@@ -1387,6 +1387,59 @@ fn test_tab_hover_with_real_files() {
     }
 }
 
+/// Test that the tab bar lists open buffers, marks the active and modified ones,
+/// and switches buffers when a tab is clicked
+#[test]
+fn test_tab_bar_lists_buffers_and_click_switches() {
+    use crate::common::harness::layout;
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let one = TestFixture::new("one.txt", "first\n").unwrap();
+    let two = TestFixture::new("two.txt", "second\n").unwrap();
+    let three = TestFixture::new("three.txt", "third\n").unwrap();
+    harness.open_file(&one.path).unwrap();
+    harness.open_file(&two.path).unwrap();
+    harness.open_file(&three.path).unwrap();
+    harness.type_text("x").unwrap();
+    harness.render().unwrap();
+
+    // All three buffers are listed in open order; the edited one is marked modified
+    let tab_row = harness.get_screen_row(layout::TAB_BAR_ROW);
+    // Screen column of a tab name (the close buttons are multi-byte, so count chars)
+    fn column_of(row: &str, name: &str) -> usize {
+        let byte = row
+            .find(name)
+            .unwrap_or_else(|| panic!("{name} not in {row:?}"));
+        row[..byte].chars().count()
+    }
+    let col_of = |name: &str| column_of(&tab_row, name);
+    assert!(col_of("one.txt") < col_of("two.txt"));
+    assert!(col_of("two.txt") < col_of("three.txt"));
+    assert!(tab_row.contains("three.txt*"), "Tab row: {tab_row:?}");
+    assert!(!tab_row.contains("one.txt*"), "Tab row: {tab_row:?}");
+
+    // The active tab uses the active tab background
+    let active_bg = harness.editor().theme().tab_active_bg;
+    let bg_at = |harness: &EditorTestHarness, name: &str| {
+        let col = column_of(&harness.get_screen_row(layout::TAB_BAR_ROW), name);
+        harness
+            .get_cell_style(col as u16, layout::TAB_BAR_ROW as u16)
+            .and_then(|style| style.bg)
+    };
+    assert_eq!(bg_at(&harness, "three.txt"), Some(active_bg));
+    assert_ne!(bg_at(&harness, "one.txt"), Some(active_bg));
+
+    // Clicking the first tab switches to its buffer and marks it active
+    harness
+        .mouse_click(col_of("one.txt") as u16, layout::TAB_BAR_ROW as u16)
+        .unwrap();
+    harness.mouse_move(0, 10).unwrap();
+    harness.render().unwrap();
+    harness.assert_buffer_content("first\n");
+    assert_eq!(bg_at(&harness, "one.txt"), Some(active_bg));
+    assert_ne!(bg_at(&harness, "three.txt"), Some(active_bg));
+}
+
 /// Test that mouse hover over editor text tracks the position
 #[test]
 fn test_mouse_hover_tracks_position() {
@@ -1873,3 +1926,154 @@ fn test_blinking_bar_selection_first_char_color() {
         second_char_cell.bg
     );
 }
+
+/// Column of the first character of `needle` on a screen row
+fn screen_col_of(harness: &EditorTestHarness, row: u16, needle: &str) -> u16 {
+    let text = harness.get_row_text(row);
+    let byte = text.find(needle).expect("text should be on screen");
+    text[..byte].chars().count() as u16
+}
+
+/// Dragging from one line to the next selects exactly the text between the
+/// press and release positions
+#[test]
+fn test_drag_across_two_lines_selects_range() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness
+        .load_buffer_from_text("alpha beta\ngamma delta\n")
+        .unwrap();
+    harness.render().unwrap();
+
+    let (content_first_row, _) = harness.content_area_rows();
+    let first_row = content_first_row as u16;
+    let start_col = screen_col_of(&harness, first_row, "beta");
+    let end_col = screen_col_of(&harness, first_row + 1, "delta");
+
+    harness
+        .mouse_drag(start_col, first_row, end_col, first_row + 1)
+        .unwrap();
+
+    assert_eq!(harness.get_selection_range(), Some(6..17));
+    assert_eq!(harness.get_selected_text(), "beta\ngamma ");
+}
+
+/// Triple-click selects the whole line under the mouse
+#[test]
+fn test_triple_click_selects_line() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness
+        .load_buffer_from_text("first line\nsecond line here\nthird\n")
+        .unwrap();
+    harness.render().unwrap();
+
+    let (content_first_row, _) = harness.content_area_rows();
+    let row = content_first_row as u16 + 1;
+    let col = screen_col_of(&harness, row, "line here");
+
+    harness.mouse_click(col, row).unwrap();
+    harness.mouse_click(col, row).unwrap();
+    assert_eq!(harness.get_selected_text(), "line");
+
+    harness.mouse_click(col, row).unwrap();
+    assert_eq!(harness.get_selected_text(), "second line here\n");
+}
+
+/// Dragging past the bottom edge of the viewport scrolls and keeps extending
+/// the selection
+#[test]
+fn test_drag_past_bottom_edge_scrolls() {
+    use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+
+    let mut harness = EditorTestHarness::new(80, 12).unwrap();
+    let content: String = (1..=50).map(|i| format!("line {}\n", i)).collect();
+    let _fixture = harness.load_buffer_from_text(&content).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.top_line_number(), 0);
+
+    let (content_first_row, content_last_row) = harness.content_area_rows();
+    let below_content = content_last_row as u16 + 1;
+    let text_col = screen_col_of(&harness, content_first_row as u16, "line 1");
+    harness
+        .send_mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: text_col,
+            row: content_first_row as u16,
+            modifiers: KeyModifiers::empty(),
+        })
+        .unwrap();
+    for _ in 0..15 {
+        harness
+            .send_mouse(MouseEvent {
+                kind: MouseEventKind::Drag(MouseButton::Left),
+                column: text_col,
+                row: below_content,
+                modifiers: KeyModifiers::empty(),
+            })
+            .unwrap();
+        harness.render().unwrap();
+    }
+
+    assert!(
+        harness.top_line_number() > 0,
+        "Viewport should scroll while dragging below it"
+    );
+    let range = harness.get_selection_range().unwrap();
+    assert_eq!(range.start, 0);
+    assert!(harness.get_selected_text().contains("line 15\n"));
+}
+
+/// The mouse wheel scrolls by `mouse_scroll_lines` without moving the cursor,
+/// and stops once the last line is at the bottom of the view
+#[test]
+fn test_mouse_wheel_scrolls_configured_lines() {
+    use crossterm::event::{MouseEvent, MouseEventKind};
+
+    let mut config = fresh::config::Config::default();
+    config.editor.mouse_scroll_lines = 5;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    let content: String = (1..=40).map(|i| format!("line {}\n", i)).collect();
+    let _fixture = harness.load_buffer_from_text(&content).unwrap();
+    harness.render().unwrap();
+
+    let (content_first_row, _) = harness.content_area_rows();
+    let wheel = |kind| MouseEvent {
+        kind,
+        column: 20,
+        row: content_first_row as u16 + 2,
+        modifiers: KeyModifiers::empty(),
+    };
+
+    harness
+        .send_mouse(wheel(MouseEventKind::ScrollDown))
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.top_line_number(), 5);
+    assert_eq!(harness.cursor_position(), 0);
+
+    harness
+        .send_mouse(wheel(MouseEventKind::ScrollDown))
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.top_line_number(), 10);
+
+    harness.send_mouse(wheel(MouseEventKind::ScrollUp)).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.top_line_number(), 5);
+    assert_eq!(harness.cursor_position(), 0);
+
+    // Scrolling far past the end is clamped
+    for _ in 0..20 {
+        harness
+            .send_mouse(wheel(MouseEventKind::ScrollDown))
+            .unwrap();
+    }
+    harness.render().unwrap();
+    let clamped_top = harness.top_line_number();
+    assert!(clamped_top < 40, "top line {} past the end", clamped_top);
+    harness.assert_screen_contains("line 40");
+    harness
+        .send_mouse(wheel(MouseEventKind::ScrollDown))
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.top_line_number(), clamped_top);
+}
@@ -653,3 +653,242 @@ fn test_movement_through_multiple_empty_lines() {
         "Should be somewhere on Line 6, got position {final_pos}"
     );
 }
+
+/// Test that typing `N%` in the goto-line prompt jumps to the start of the line
+/// at that percentage of the buffer, clamping out-of-range values
+#[test]
+fn test_goto_percent_prompt() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("lines.txt");
+    // Ten lines of seven bytes each: 70 bytes total
+    let content: String = (0..10).map(|i| format!("line {i}\n")).collect();
+    std::fs::write(&file_path, &content).unwrap();
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+
+    let goto = |harness: &mut EditorTestHarness, input: &str| {
+        harness
+            .send_key(KeyCode::Char('g'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.type_text(input).unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+        harness.render().unwrap();
+    };
+
+    // 50% is byte 35, the start of "line 5"
+    goto(&mut harness, "50%");
+    assert_eq!(harness.cursor_position(), 35);
+    harness.assert_screen_contains("Jumped to 50%");
+
+    // A target inside a line lands on that line's start (byte 23 is in "line 3")
+    goto(&mut harness, "33%");
+    assert_eq!(harness.cursor_position(), 21);
+
+    goto(&mut harness, "0%");
+    assert_eq!(harness.cursor_position(), 0);
+
+    // 100% lands on the last line of the buffer
+    goto(&mut harness, "100%");
+    assert_eq!(harness.cursor_position(), content.len());
+
+    // Percentages above 100 are clamped to the end
+    goto(&mut harness, "0%");
+    goto(&mut harness, "250%");
+    assert_eq!(harness.cursor_position(), content.len());
+
+    goto(&mut harness, "abc%");
+    assert_eq!(harness.cursor_position(), content.len());
+    harness.assert_screen_contains("Invalid perc");
+}
+
+/// Test that the goto_percent action can be bound to a key with a percent argument
+#[test]
+fn test_goto_percent_keybinding() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("lines.txt");
+    let content: String = (0..10).map(|i| format!("line {i}\n")).collect();
+    std::fs::write(&file_path, &content).unwrap();
+
+    let mut config = fresh::config::Config::default();
+    config.keybindings.push(fresh::config::Keybinding {
+        key: "F6".to_string(),
+        modifiers: vec![],
+        keys: vec![],
+        action: "goto_percent".to_string(),
+        args: [("percent".to_string(), serde_json::json!(50))]
+            .into_iter()
+            .collect(),
+        when: None,
+    });
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+
+    harness.send_key(KeyCode::F(6), KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.cursor_position(), 35);
+}
+
+/// Config binding F6/F7/F8 to the cursor_to_viewport_top/middle/bottom actions
+fn viewport_jump_config() -> fresh::config::Config {
+    let mut config = fresh::config::Config::default();
+    for (key, action) in [
+        ("F6", "cursor_to_viewport_top"),
+        ("F7", "cursor_to_viewport_middle"),
+        ("F8", "cursor_to_viewport_bottom"),
+    ] {
+        config.keybindings.push(fresh::config::Keybinding {
+            key: key.to_string(),
+            modifiers: vec![],
+            keys: vec![],
+            action: action.to_string(),
+            args: Default::default(),
+            when: None,
+        });
+    }
+    config
+}
+
+/// Line and column (in bytes) of the primary cursor
+fn cursor_line_col(harness: &mut EditorTestHarness) -> (usize, usize) {
+    let pos = harness.cursor_position();
+    let buffer = &harness.editor().active_state().buffer;
+    let line = buffer.get_line_number(pos);
+    let line_start = buffer.line_start_offset(line).unwrap();
+    (line, pos - line_start)
+}
+
+/// Test that the cursor_to_viewport_* actions move to the first, middle and
+/// last visible line, keep the column and leave the viewport where it is
+#[test]
+fn test_cursor_to_viewport_top_middle_bottom() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("lines.txt");
+    let content: String = (0..200).map(|i| format!("line {i:03}\n")).collect();
+    std::fs::write(&file_path, &content).unwrap();
+
+    let mut harness = EditorTestHarness::with_config(80, 24, viewport_jump_config()).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    // Scroll well into the file and put the cursor on column 3
+    for _ in 0..3 {
+        harness
+            .send_key(KeyCode::PageDown, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    for _ in 0..3 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.render().unwrap();
+
+    let top = harness.top_line_number();
+    let rows = harness.editor().active_viewport().visible_line_count();
+    assert!(top > 0, "Viewport should be scrolled");
+
+    for (key, expected_line) in [
+        (6, top),
+        (7, top + (rows - 1) / 2),
+        (8, top + rows - 1),
+        (6, top),
+    ] {
+        harness
+            .send_key(KeyCode::F(key), KeyModifiers::NONE)
+            .unwrap();
+        harness.render().unwrap();
+        assert_eq!(
+            cursor_line_col(&mut harness),
+            (expected_line, 3),
+            "F{} should move to line {}",
+            key,
+            expected_line
+        );
+        assert_eq!(harness.top_line_number(), top, "F{} must not scroll", key);
+    }
+}
+
+/// Test that the middle and bottom targets only count lines that exist when
+/// the buffer is shorter than the viewport
+#[test]
+fn test_cursor_to_viewport_short_buffer() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("short.txt");
+    std::fs::write(&file_path, "one\ntwo\nthree\nfour\nfive").unwrap();
+
+    let mut config = viewport_jump_config();
+    config.editor.line_wrap = false;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness.send_key(KeyCode::F(8), KeyModifiers::NONE).unwrap();
+    assert_eq!(cursor_line_col(&mut harness), (4, 0));
+
+    harness.send_key(KeyCode::F(7), KeyModifiers::NONE).unwrap();
+    assert_eq!(cursor_line_col(&mut harness), (2, 0));
+}
+
+/// Test that with line wrapping the targets are display rows, not source lines
+#[test]
+fn test_cursor_to_viewport_wrapped_rows() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("wrapped.txt");
+    let content: String = (0..30)
+        .map(|i| format!("{i:02}{}\n", "x".repeat(98)))
+        .collect();
+    std::fs::write(&file_path, &content).unwrap();
+
+    let mut harness = EditorTestHarness::with_config(60, 24, viewport_jump_config()).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+    let (first_row, last_row) = harness.content_area_rows();
+
+    harness.send_key(KeyCode::F(8), KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.screen_cursor_position().1 as usize, last_row);
+    assert_eq!(harness.top_byte(), 0, "Bottom jump must not scroll");
+
+    harness.send_key(KeyCode::F(6), KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.screen_cursor_position().1 as usize, first_row);
+    assert_eq!(harness.cursor_position(), 0);
+}
+
+/// Test that the cursor keeps its visual column when the target row has
+/// double-width text
+#[test]
+fn test_cursor_to_viewport_keeps_visual_column() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("wide.txt");
+    std::fs::write(&file_path, "ab你好cd\ntwo\nthree\nabcdefgh").unwrap();
+
+    let mut config = viewport_jump_config();
+    config.editor.line_wrap = false;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    // Column 4 on the last line
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    for _ in 0..4 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+
+    // Visual column 4 on the first line is the start of "好", byte 5
+    harness.send_key(KeyCode::F(6), KeyModifiers::NONE).unwrap();
+    assert_eq!(cursor_line_col(&mut harness), (0, 5));
+
+    // Coming back, the goal column is still 4
+    harness.send_key(KeyCode::F(8), KeyModifiers::NONE).unwrap();
+    assert_eq!(cursor_line_col(&mut harness), (3, 4));
+}
@@ -7,6 +7,7 @@
 //! - $FILE placeholder substitution
 //! - Timeout handling
 //! - Action failure handling
+//! - insert_final_newline appending a line ending before the write
 
 use crate::common::harness::EditorTestHarness;
 use crossterm::event::{KeyCode, KeyModifiers};
@@ -47,6 +48,8 @@ fn test_format_on_save() {
             show_whitespace_tabs: true,
             use_tabs: false,
             tab_size: None,
+            line_wrap: None,
+            line_numbers: None,
             formatter: Some(formatter),
             format_on_save: true,
             on_save: vec![],
@@ -110,6 +113,8 @@ fn test_on_save_linter_style() {
             show_whitespace_tabs: true,
             use_tabs: false,
             tab_size: None,
+            line_wrap: None,
+            line_numbers: None,
             formatter: None,
             format_on_save: false,
             on_save: vec![action],
@@ -173,6 +178,8 @@ fn test_on_save_action_failure() {
             show_whitespace_tabs: true,
             use_tabs: false,
             tab_size: None,
+            line_wrap: None,
+            line_numbers: None,
             formatter: None,
             format_on_save: false,
             on_save: vec![action],
@@ -245,6 +252,8 @@ fn test_on_save_file_placeholder() {
             show_whitespace_tabs: true,
             use_tabs: false,
             tab_size: None,
+            line_wrap: None,
+            line_numbers: None,
             formatter: None,
             format_on_save: false,
             on_save: vec![action],
@@ -311,6 +320,8 @@ fn test_formatter_stdin_mode() {
             show_whitespace_tabs: true,
             use_tabs: false,
             tab_size: None,
+            line_wrap: None,
+            line_numbers: None,
             formatter: Some(formatter),
             format_on_save: true,
             on_save: vec![],
@@ -384,6 +395,8 @@ fn test_on_save_stops_on_failure() {
             show_whitespace_tabs: true,
             use_tabs: false,
             tab_size: None,
+            line_wrap: None,
+            line_numbers: None,
             formatter: None,
             format_on_save: false,
             on_save: vec![action1, action2],
@@ -478,6 +491,8 @@ fn test_formatter_not_found_shows_message() {
             show_whitespace_tabs: true,
             use_tabs: false,
             tab_size: None,
+            line_wrap: None,
+            line_numbers: None,
             formatter: Some(formatter),
             format_on_save: true,
             on_save: vec![],
@@ -506,3 +521,67 @@ fn test_formatter_not_found_shows_message() {
     // Should show a message about missing formatter
     harness.assert_screen_contains("Formatter");
 }
+
+/// Open `content` with insert_final_newline set to `enabled` and save it
+fn save_with_final_newline(
+    content: &str,
+    enabled: bool,
+) -> (TempDir, std::path::PathBuf, EditorTestHarness) {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("notes.txt");
+    std::fs::write(&file_path, content).unwrap();
+
+    let mut config = Config::default();
+    config.editor.insert_final_newline = enabled;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    (temp_dir, file_path, harness)
+}
+
+/// Test that a missing final newline is added to the buffer and the file, as an undoable edit
+#[test]
+fn test_insert_final_newline_on_save() {
+    let (_temp_dir, file_path, mut harness) = save_with_final_newline("one\ntwo", true);
+
+    harness.assert_buffer_content("one\ntwo\n");
+    assert_eq!(std::fs::read_to_string(&file_path).unwrap(), "one\ntwo\n");
+    // The cursor stays at the end of the last line
+    assert_eq!(harness.cursor_position(), "one\ntwo".len());
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("one\ntwo");
+}
+
+/// Test that a file already ending in a newline, or an empty one, is saved unchanged
+#[test]
+fn test_insert_final_newline_not_doubled() {
+    let (_temp_dir, file_path, harness) = save_with_final_newline("one\r\ntwo\r\n", true);
+    harness.assert_buffer_content("one\r\ntwo\r\n");
+    assert_eq!(
+        std::fs::read_to_string(&file_path).unwrap(),
+        "one\r\ntwo\r\n"
+    );
+
+    let (_temp_dir, file_path, harness) = save_with_final_newline("", true);
+    harness.assert_buffer_content("");
+    assert_eq!(std::fs::read_to_string(&file_path).unwrap(), "");
+}
+
+/// Test that the file is written as-is when insert_final_newline is off
+#[test]
+fn test_insert_final_newline_disabled() {
+    let (_temp_dir, file_path, harness) = save_with_final_newline("one\ntwo", false);
+
+    harness.assert_buffer_content("one\ntwo");
+    assert_eq!(std::fs::read_to_string(&file_path).unwrap(), "one\ntwo");
+}
@@ -0,0 +1,89 @@
+//! E2E tests for overwrite mode (Insert key toggles insert/overwrite)
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+/// Typing in overwrite mode replaces characters instead of pushing them right
+#[test]
+fn test_overwrite_mode_replaces_characters() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("abcd").unwrap();
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+
+    harness
+        .send_key(KeyCode::Insert, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("XY").unwrap();
+    harness.render().unwrap();
+
+    harness.assert_buffer_content("XYcd");
+    assert_eq!(harness.cursor_position(), 2);
+    assert!(harness.get_status_bar().contains("OVR"));
+
+    // Toggling back restores normal insertion
+    harness
+        .send_key(KeyCode::Insert, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("Z").unwrap();
+    harness.render().unwrap();
+    harness.assert_buffer_content("XYZcd");
+    assert!(!harness.get_status_bar().contains("OVR"));
+}
+
+/// At the end of a line there is nothing to replace, so characters are inserted
+#[test]
+fn test_overwrite_mode_inserts_at_end_of_line() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("ab").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("cd").unwrap();
+    harness.send_key(KeyCode::Up, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+
+    harness
+        .send_key(KeyCode::Insert, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("XY").unwrap();
+
+    // The newline is not overwritten
+    harness.assert_buffer_content("abXY\ncd");
+}
+
+/// Each replacement is a single undoable edit
+#[test]
+fn test_overwrite_mode_single_undo() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("abc").unwrap();
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+
+    harness
+        .send_key(KeyCode::Insert, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("X").unwrap();
+    harness.assert_buffer_content("Xbc");
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("abc");
+}
+
+/// Overwrite mode switches the cursor to a block
+#[test]
+fn test_overwrite_mode_uses_block_cursor() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.render().unwrap();
+    harness.take_escape_output();
+
+    harness
+        .send_key(KeyCode::Insert, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    // DECSCUSR 2 = steady block
+    assert_eq!(
+        String::from_utf8(harness.take_escape_output()).unwrap(),
+        "\x1b[2 q"
+    );
+}
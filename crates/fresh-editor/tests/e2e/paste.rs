@@ -4,11 +4,18 @@
 //! - Paste with selection (should replace selection)
 //! - Multi-cursor paste
 //! - Paste undo atomicity
+//! - Rectangular paste of block (column) selections
+//! - Reindenting pasted blocks to the cursor's indentation
+//! - Cycling earlier copies through the kill ring with yank-pop
+//! - Consecutive cuts accumulating into one kill ring entry
+//! - Copying into and pasting from named registers
+//! - Bracketed paste from the terminal inserted verbatim
 //!
 //! Issue #372: External paste should behave like internal paste
 
-use crate::common::harness::EditorTestHarness;
+use crate::common::harness::{EditorTestHarness, HarnessOptions};
 use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
 
 /// Test that paste replaces the current selection
 /// Bug: Current paste() doesn't delete selection before inserting
@@ -830,3 +837,634 @@ fn test_paste_crlf_into_prompt() {
     // Prompt should contain the text (newlines may be shown differently in prompt)
     harness.assert_screen_contains("line1");
 }
+
+/// Block-select columns 1..3 on the first three lines and copy them
+fn copy_three_line_block(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    for _ in 0..2 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::ALT | KeyModifiers::SHIFT)
+            .unwrap();
+    }
+    for _ in 0..2 {
+        harness
+            .send_key(KeyCode::Down, KeyModifiers::ALT | KeyModifiers::SHIFT)
+            .unwrap();
+    }
+    harness
+        .send_key(KeyCode::Char('c'), KeyModifiers::CONTROL)
+        .unwrap();
+}
+
+/// A copied block is pasted as a rectangle at the cursor column
+#[test]
+fn test_block_copy_pastes_as_rectangle() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness
+        .load_buffer_from_text("0123\n4567\n89ab\nxxxxxxxx\nyyyyyyyy\nzzzzzzzz")
+        .unwrap();
+
+    copy_three_line_block(&mut harness);
+    assert_eq!(harness.editor().clipboard_content_for_test(), "12\n56\n9a");
+
+    // Move to line 4, column 4 and paste
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    for _ in 0..3 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+    for _ in 0..4 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.editor_mut().paste_for_test();
+    harness.render().unwrap();
+
+    harness.assert_buffer_content("0123\n4567\n89ab\nxxxx12xxxx\nyyyy56yyyy\nzzzz9azzzz");
+
+    // The whole rectangle is a single undo step
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("0123\n4567\n89ab\nxxxxxxxx\nyyyyyyyy\nzzzzzzzz");
+}
+
+/// Short lines are padded and missing lines are appended
+#[test]
+fn test_block_paste_pads_short_and_missing_lines() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness
+        .load_buffer_from_text("0123\n4567\n89ab\nlong line\nab")
+        .unwrap();
+
+    copy_three_line_block(&mut harness);
+
+    // Paste at line 4, column 6
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    for _ in 0..3 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+    for _ in 0..6 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.editor_mut().paste_for_test();
+
+    harness.assert_buffer_content("0123\n4567\n89ab\nlong l12ine\nab    56\n      9a");
+}
+
+/// Block columns are visual columns, so multibyte text is neither split on
+/// copy nor on paste
+#[test]
+fn test_block_copy_and_paste_with_multibyte_text() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness
+        .load_buffer_from_text("0123\nxéz4\n89ab\nαβγδ\nabcd\nαβγδ")
+        .unwrap();
+
+    copy_three_line_block(&mut harness);
+    assert_eq!(harness.editor().clipboard_content_for_test(), "12\néz\n9a");
+
+    // Paste at line 4, after two Greek letters
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    for _ in 0..3 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+    for _ in 0..2 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.editor_mut().paste_for_test();
+
+    harness.assert_buffer_content("0123\nxéz4\n89ab\nαβ12γδ\nabézcd\nαβ9aγδ");
+}
+
+/// Block columns are visual columns, so double-width text lines up with
+/// the rows around it
+#[test]
+fn test_block_copy_and_paste_with_wide_text() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness
+        .load_buffer_from_text("0123\n你好\n89ab\n你好世界")
+        .unwrap();
+
+    // Block-select visual columns 2..4 on the first three lines
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    for _ in 0..2 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+    for _ in 0..2 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::ALT | KeyModifiers::SHIFT)
+            .unwrap();
+    }
+    for _ in 0..2 {
+        harness
+            .send_key(KeyCode::Down, KeyModifiers::ALT | KeyModifiers::SHIFT)
+            .unwrap();
+    }
+    harness
+        .send_key(KeyCode::Char('c'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(harness.editor().clipboard_content_for_test(), "23\n好\nab");
+
+    // Paste on the last line after one wide character (visual column 2)
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    harness.editor_mut().paste_for_test();
+
+    harness.assert_buffer_content("0123\n你好\n89ab\n你23好世界\n  好\n  ab");
+}
+
+/// Linear clipboard content still pastes as a single run
+#[test]
+fn test_linear_paste_after_block_copy() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness
+        .load_buffer_from_text("0123\n4567\n89ab\nxxxx")
+        .unwrap();
+
+    copy_three_line_block(&mut harness);
+
+    // Replacing the clipboard content drops the block shape
+    harness
+        .editor_mut()
+        .set_clipboard_for_test("12\n56".to_string());
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.editor_mut().paste_for_test();
+
+    harness.assert_buffer_content("0123\n4567\n89ab\nxxxx12\n56");
+}
+
+/// With reindent_on_paste, a block lands at the indentation of the target line
+#[test]
+fn test_reindent_on_paste_shifts_block_to_cursor_indent() {
+    let mut config = Config::default();
+    config.editor.reindent_on_paste = true;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness
+        .load_buffer_from_text("outer {\n        \n}")
+        .unwrap();
+
+    // Cursor at the end of the whitespace-only second line
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+
+    harness
+        .editor_mut()
+        .set_clipboard_for_test("    if y {\n        z();\n    }".to_string());
+    harness.editor_mut().paste_for_test();
+
+    harness.assert_buffer_content("outer {\n        if y {\n            z();\n        }\n}");
+}
+
+/// Without the option, paste inserts the text unchanged
+#[test]
+fn test_paste_without_reindent_keeps_indentation() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness
+        .load_buffer_from_text("outer {\n        \n}")
+        .unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+
+    harness
+        .editor_mut()
+        .set_clipboard_for_test("if y {\n    z();\n}".to_string());
+    harness.editor_mut().paste_for_test();
+
+    harness.assert_buffer_content("outer {\n        if y {\n    z();\n}\n}");
+}
+
+/// Paste and Indent reindents even with the option off, after code on the line
+#[test]
+fn test_paste_and_indent_action() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness
+        .load_buffer_from_text("fn main() {\n    let v = \n}")
+        .unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+
+    // A partial first line followed by lines carrying their original indentation
+    harness.editor_mut().set_clipboard_for_test(
+        "vec![\n            1,\n                2,\n        ];".to_string(),
+    );
+    harness
+        .send_key(
+            KeyCode::Char('v'),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+        )
+        .unwrap();
+
+    harness.assert_buffer_content(
+        "fn main() {\n    let v = vec![\n        1,\n            2,\n    ];\n}",
+    );
+
+    // The reindented paste is a single undo step
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("fn main() {\n    let v = \n}");
+}
+
+/// Copy each of the first three lines in turn, then move to the empty last line
+fn copy_three_lines(harness: &mut EditorTestHarness) {
+    harness
+        .load_buffer_from_text("alpha\nbeta\ngamma\n")
+        .unwrap();
+    // Enable internal-only clipboard to avoid system clipboard interference in parallel tests
+    harness.editor_mut().set_clipboard_for_test("".to_string());
+
+    for _ in 0..3 {
+        harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+        harness.send_key(KeyCode::End, KeyModifiers::SHIFT).unwrap();
+        harness
+            .send_key(KeyCode::Char('c'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+}
+
+/// Yank-pop replaces the paste with earlier copies, wrapping around the ring
+#[test]
+fn test_yank_pop_cycles_kill_ring() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    copy_three_lines(&mut harness);
+
+    harness
+        .send_key(KeyCode::Char('v'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("alpha\nbeta\ngamma\ngamma");
+
+    harness
+        .send_key(KeyCode::Char('y'), KeyModifiers::ALT)
+        .unwrap();
+    harness.assert_buffer_content("alpha\nbeta\ngamma\nbeta");
+
+    harness
+        .send_key(KeyCode::Char('y'), KeyModifiers::ALT)
+        .unwrap();
+    harness.assert_buffer_content("alpha\nbeta\ngamma\nalpha");
+    assert_eq!(harness.cursor_position(), 22);
+
+    harness
+        .send_key(KeyCode::Char('y'), KeyModifiers::ALT)
+        .unwrap();
+    harness.assert_buffer_content("alpha\nbeta\ngamma\ngamma");
+
+    // Each cycle is its own undo step
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("alpha\nbeta\ngamma\nalpha");
+}
+
+/// Yank-pop does nothing once the pasted text has been edited
+#[test]
+fn test_yank_pop_requires_preceding_paste() {
+    let mut harness = EditorTestHarness::new(140, 24).unwrap();
+    copy_three_lines(&mut harness);
+
+    harness
+        .send_key(KeyCode::Char('y'), KeyModifiers::ALT)
+        .unwrap();
+    harness.assert_buffer_content("alpha\nbeta\ngamma\n");
+
+    harness
+        .send_key(KeyCode::Char('v'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("!").unwrap();
+    harness
+        .send_key(KeyCode::Char('y'), KeyModifiers::ALT)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.assert_buffer_content("alpha\nbeta\ngamma\ngamma!");
+    harness.assert_screen_contains("Yank pop only works right after a paste");
+
+    // Any other command in between also ends the chance to yank-pop, even
+    // when the cursor comes back to the end of the paste
+    harness
+        .send_key(KeyCode::Char('v'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('y'), KeyModifiers::ALT)
+        .unwrap();
+    harness.assert_buffer_content("alpha\nbeta\ngamma\ngamma!gamma");
+}
+
+/// Cuts separated by other commands each get their own kill ring entry
+#[test]
+fn test_yank_pop_cycles_to_earlier_cut() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness
+        .load_buffer_from_text("alpha\nbeta\ngamma\n")
+        .unwrap();
+    harness.editor_mut().set_clipboard_for_test("".to_string());
+
+    // Cut "alpha", then move down and cut "beta"
+    harness.send_key(KeyCode::End, KeyModifiers::SHIFT).unwrap();
+    harness
+        .send_key(KeyCode::Char('x'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::SHIFT).unwrap();
+    harness
+        .send_key(KeyCode::Char('x'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("\n\ngamma\n");
+
+    // Yank pastes the latest kill, yank-pop swaps in the earlier one
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('v'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("\n\ngamma\nbeta");
+
+    harness
+        .send_key(KeyCode::Char('y'), KeyModifiers::ALT)
+        .unwrap();
+    harness.assert_buffer_content("\n\ngamma\nalpha");
+}
+
+/// Consecutive kill-line presses build one kill ring entry
+#[test]
+fn test_consecutive_kill_lines_yank_back_together() {
+    let mut config = Config::default();
+    config.editor.kill_line_to_clipboard = true;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness
+        .load_buffer_from_text("alpha\nbeta\ngamma\n")
+        .unwrap();
+    harness.editor_mut().set_clipboard_for_test("".to_string());
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+
+    // C-k C-k takes a line's text and then its line ending; do it for two lines
+    for _ in 0..4 {
+        harness
+            .send_key(KeyCode::Char('k'), KeyModifiers::CONTROL)
+            .unwrap();
+    }
+    harness.assert_buffer_content("gamma\n");
+
+    harness
+        .send_key(KeyCode::Char('v'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("alpha\nbeta\ngamma\n");
+}
+
+/// In the default keymap, deleting to the line end leaves the clipboard alone
+#[test]
+fn test_delete_to_line_end_keeps_clipboard_by_default() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.load_buffer_from_text("alpha\nbeta\n").unwrap();
+    harness
+        .editor_mut()
+        .set_clipboard_for_test("copied".to_string());
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+
+    harness
+        .send_key(KeyCode::Char('k'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("\nbeta\n");
+    assert_eq!(harness.editor().clipboard_content_for_test(), "copied");
+}
+
+/// A kill toward the line start goes in front of the previous kill
+#[test]
+fn test_kill_to_line_start_prepends_to_previous_kill() {
+    let config = Config {
+        active_keybinding_map: "macos".into(),
+        ..Default::default()
+    };
+    let mut harness = EditorTestHarness::create(
+        80,
+        24,
+        HarnessOptions::new()
+            .with_config(config)
+            .with_preserved_keybinding_map(),
+    )
+    .unwrap();
+    harness.load_buffer_from_text("alpha beta").unwrap();
+    harness.editor_mut().set_clipboard_for_test("".to_string());
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    for _ in 0..5 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+
+    // Ctrl+K kills " beta", then Ctrl+U kills "alpha" in front of it
+    harness
+        .send_key(KeyCode::Char('k'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('u'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("");
+
+    harness
+        .send_key(KeyCode::Char('v'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("alpha beta");
+}
+
+/// Consecutive cuts append to the same kill ring entry
+#[test]
+fn test_consecutive_cuts_append_to_one_kill() {
+    let mut harness = EditorTestHarness::new(120, 24).unwrap();
+    harness
+        .load_buffer_from_text("alpha\nbeta\ngamma\n")
+        .unwrap();
+    harness.editor_mut().set_clipboard_for_test("".to_string());
+
+    // Without a selection each cut takes the whole line
+    harness
+        .send_key(KeyCode::Char('x'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('x'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("gamma\n");
+
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('v'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("gamma\nalpha\nbeta\n");
+
+    // Both lines form a single entry, so there is nothing earlier to cycle to
+    harness
+        .send_key(KeyCode::Char('y'), KeyModifiers::ALT)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_buffer_content("gamma\nalpha\nbeta\n");
+    harness.assert_screen_contains("No earlier clipboard entries");
+}
+
+/// Select a register with the register prefix (Alt+')
+fn select_register(harness: &mut EditorTestHarness, name: char) {
+    harness
+        .send_key(KeyCode::Char('\''), KeyModifiers::ALT)
+        .unwrap();
+    harness.type_text(&name.to_string()).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+}
+
+/// Text copied into two registers pastes back independently, leaving the clipboard alone
+#[test]
+fn test_named_registers_paste_back_independently() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.load_buffer_from_text("alpha\nbeta\n").unwrap();
+    harness
+        .editor_mut()
+        .set_clipboard_for_test("clip".to_string());
+
+    for name in ['a', 'b'] {
+        harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+        harness.send_key(KeyCode::End, KeyModifiers::SHIFT).unwrap();
+        select_register(&mut harness, name);
+        harness
+            .send_key(KeyCode::Char('c'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+
+    select_register(&mut harness, 'b');
+    harness
+        .send_key(KeyCode::Char('v'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("alpha\nbeta\nbeta");
+
+    select_register(&mut harness, 'a');
+    harness
+        .send_key(KeyCode::Char('v'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("alpha\nbeta\nbetaalpha");
+
+    // The register is only used once; a plain paste uses the clipboard again
+    harness
+        .send_key(KeyCode::Char('v'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("alpha\nbeta\nbetaalphaclip");
+}
+
+/// Numbered registers read back recent copies, with 0 the most recent
+#[test]
+fn test_numbered_registers_paste_recent_copies() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    copy_three_lines(&mut harness);
+
+    select_register(&mut harness, '1');
+    harness
+        .send_key(KeyCode::Char('v'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("alpha\nbeta\ngamma\nbeta");
+
+    select_register(&mut harness, '0');
+    harness
+        .send_key(KeyCode::Char('v'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("alpha\nbeta\ngamma\nbetagamma");
+}
+
+/// Pasting from an empty register leaves the buffer unchanged
+#[test]
+fn test_paste_from_empty_register() {
+    let mut harness = EditorTestHarness::new(140, 24).unwrap();
+    harness.load_buffer_from_text("text").unwrap();
+    harness
+        .editor_mut()
+        .set_clipboard_for_test("clip".to_string());
+
+    select_register(&mut harness, 'q');
+    harness
+        .send_key(KeyCode::Char('v'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.assert_buffer_content("text");
+    harness.assert_screen_contains("Register \"q\" is empty");
+}
+
+/// A bracketed paste of indented code is inserted verbatim, without
+/// auto-indent or auto-close, and undoes in a single step
+#[test]
+fn test_bracketed_paste_inserts_verbatim_as_one_undo_step() {
+    let mut config = Config::default();
+    config.editor.auto_indent = true;
+    config.editor.auto_close = true;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+
+    harness.type_text("x").unwrap();
+    let code = "\nfn main() {\n    if ready {\n        go(\"now\");\n    }\n}\n";
+    harness.send_paste(code).unwrap();
+    harness.assert_buffer_content(&format!("x{}", code));
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("x");
+}
+
+/// A bracketed paste while the settings dialog is open does not reach the
+/// buffer behind it
+#[test]
+fn test_bracketed_paste_does_not_reach_buffer_behind_settings() {
+    let mut harness = EditorTestHarness::new(100, 40).unwrap();
+    harness.type_text("buffer").unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    assert!(harness.editor().is_settings_open());
+
+    harness.send_paste("pasted").unwrap();
+    harness.assert_buffer_content("buffer");
+}
@@ -0,0 +1,124 @@
+//! E2E tests for undo history kept across editor sessions
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use fresh::config_io::DirectoryContext;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn persistent_undo_config() -> Config {
+    let mut config = Config::default();
+    config.editor.persistent_undo = true;
+    config
+}
+
+fn start_editor(
+    config: Config,
+    project: &Path,
+    dir_context: &DirectoryContext,
+) -> EditorTestHarness {
+    EditorTestHarness::with_shared_dir_context(
+        80,
+        24,
+        config,
+        project.to_path_buf(),
+        dir_context.clone(),
+    )
+    .unwrap()
+}
+
+fn save(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+}
+
+fn undo(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+}
+
+/// Edit and save a file across two saves, returning its path
+fn edit_in_first_session(
+    config: Config,
+    project: &Path,
+    dir_context: &DirectoryContext,
+) -> std::path::PathBuf {
+    let file = project.join("notes.txt");
+    fs::write(&file, "").unwrap();
+
+    let mut harness = start_editor(config, project, dir_context);
+    harness.open_file(&file).unwrap();
+    harness.type_text("hello").unwrap();
+    save(&mut harness);
+    harness.type_text(" world").unwrap();
+    save(&mut harness);
+    assert_eq!(fs::read_to_string(&file).unwrap(), "hello world");
+    file
+}
+
+/// Test that undo after restarting the editor steps back through the earlier session's edits
+#[test]
+fn test_undo_history_survives_restart() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir_context = DirectoryContext::for_testing(&temp_dir.path().join("state"));
+    let project = temp_dir.path().join("project");
+    fs::create_dir_all(&project).unwrap();
+    let file = edit_in_first_session(persistent_undo_config(), &project, &dir_context);
+
+    let mut harness = start_editor(persistent_undo_config(), &project, &dir_context);
+    harness.open_file(&file).unwrap();
+    harness.assert_buffer_content("hello world");
+    assert!(!harness.editor().active_state().buffer.is_modified());
+
+    undo(&mut harness);
+    harness.assert_buffer_content("hello ");
+    undo(&mut harness);
+    harness.assert_buffer_content("hello");
+    assert!(harness.editor().active_state().buffer.is_modified());
+
+    // Redo walks forward to the saved content again
+    harness
+        .send_key(KeyCode::Char('y'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('y'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("hello world");
+    assert!(!harness.editor().active_state().buffer.is_modified());
+}
+
+/// Test that history is dropped when the file changed outside the editor
+#[test]
+fn test_undo_history_ignored_after_external_change() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir_context = DirectoryContext::for_testing(&temp_dir.path().join("state"));
+    let project = temp_dir.path().join("project");
+    fs::create_dir_all(&project).unwrap();
+    let file = edit_in_first_session(persistent_undo_config(), &project, &dir_context);
+    fs::write(&file, "hello there").unwrap();
+
+    let mut harness = start_editor(persistent_undo_config(), &project, &dir_context);
+    harness.open_file(&file).unwrap();
+    undo(&mut harness);
+    harness.assert_buffer_content("hello there");
+}
+
+/// Test that undo history is not kept unless enabled
+#[test]
+fn test_undo_history_not_kept_by_default() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir_context = DirectoryContext::for_testing(&temp_dir.path().join("state"));
+    let project = temp_dir.path().join("project");
+    fs::create_dir_all(&project).unwrap();
+    let file = edit_in_first_session(Config::default(), &project, &dir_context);
+    assert!(!dir_context.undo_history_dir().exists());
+
+    let mut harness = start_editor(persistent_undo_config(), &project, &dir_context);
+    harness.open_file(&file).unwrap();
+    undo(&mut harness);
+    harness.assert_buffer_content("hello world");
+}
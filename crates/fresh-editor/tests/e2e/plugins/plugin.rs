@@ -1653,6 +1653,185 @@ fn test_clangd_plugin_switch_source_header() -> anyhow::Result<()> {
     Ok(())
 }
 
+const LSP_HOVER_PLUGIN: &str = r#"
+const editor = getEditor();
+
+editor.registerCommand("Plugin Hover", "Hover via editor.lsp", "plugin_hover", null);
+
+globalThis.plugin_hover = async function(): Promise<void> {
+    const path = editor.getBufferPath(editor.getActiveBufferId());
+    try {
+        const hover = await editor.lsp("textDocument/hover", {
+            textDocument: { uri: "file://" + path },
+            position: { line: 0, character: 3 },
+        }) as { contents: { value: string } };
+        editor.setStatus("Hover result: " + hover.contents.value);
+    } catch (e) {
+        editor.setStatus("Hover failed: " + e);
+    }
+};
+"#;
+
+/// Run the "Plugin Hover" command from the command palette
+fn run_plugin_hover(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Plugin Hover").unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Hover via editor.lsp"))
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+}
+
+/// Test that editor.lsp sends a request to the active buffer's server and
+/// resolves with its response
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_plugin_lsp_hover_request() -> anyhow::Result<()> {
+    init_tracing_from_env();
+    let _fake_server = FakeLspServer::spawn()?;
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir_all(&plugins_dir).unwrap();
+    fs::write(plugins_dir.join("test_lsp_hover.ts"), LSP_HOVER_PLUGIN).unwrap();
+    let source_file = project_root.join("main.rs");
+    fs::write(&source_file, "fn main() {}\n").unwrap();
+
+    let mut config = Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        LspServerConfig {
+            command: FakeLspServer::script_path().to_string_lossy().to_string(),
+            args: vec![],
+            enabled: true,
+            auto_start: true,
+            process_limits: ProcessLimits::default(),
+            initialization_options: None,
+        },
+    );
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(120, 30, config, project_root.clone())?;
+    harness.open_file(&source_file)?;
+    harness.render()?;
+
+    run_plugin_hover(&mut harness);
+    harness.wait_for_screen_contains("Hover result: Test hover content")?;
+
+    Ok(())
+}
+
+/// Harness with `main.rs` open, served by a fake LSP server that never
+/// answers requests, and the editor.lsp hover plugin loaded
+fn blocking_lsp_hover_harness(temp_dir: &tempfile::TempDir) -> anyhow::Result<EditorTestHarness> {
+    let project_root = temp_dir.path().join("project_root");
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir_all(&plugins_dir).unwrap();
+    fs::write(plugins_dir.join("test_lsp_hover.ts"), LSP_HOVER_PLUGIN).unwrap();
+    let source_file = project_root.join("main.rs");
+    fs::write(&source_file, "fn main() {}\n").unwrap();
+
+    let mut config = Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        LspServerConfig {
+            command: FakeLspServer::blocking_script_path()
+                .to_string_lossy()
+                .to_string(),
+            args: vec![],
+            enabled: true,
+            auto_start: true,
+            process_limits: ProcessLimits::default(),
+            initialization_options: None,
+        },
+    );
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(120, 30, config, project_root.clone())?;
+    harness.open_file(&source_file)?;
+    harness.render()?;
+    Ok(harness)
+}
+
+/// Test that editor.lsp rejects once the server has not answered in time
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_plugin_lsp_request_times_out() -> anyhow::Result<()> {
+    init_tracing_from_env();
+    let _fake_server = FakeLspServer::spawn_blocking()?;
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let mut harness = blocking_lsp_hover_harness(&temp_dir)?;
+
+    run_plugin_hover(&mut harness);
+    // Logical time moves a second per poll, so the deadline passes without a real wait
+    harness.wait_until(|h| {
+        h.advance_time(std::time::Duration::from_secs(1));
+        h.screen_to_string().contains("Hover failed:")
+    })?;
+    harness.assert_screen_contains("timed out");
+
+    Ok(())
+}
+
+/// Test that a timed out editor.lsp request the server never answers is
+/// eventually forgotten
+#[test]
+#[cfg_attr(windows, ignore)] // Uses bash script for fake LSP server
+fn test_plugin_lsp_request_never_answered_is_forgotten() -> anyhow::Result<()> {
+    init_tracing_from_env();
+    let _fake_server = FakeLspServer::spawn_blocking()?;
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let mut harness = blocking_lsp_hover_harness(&temp_dir)?;
+
+    run_plugin_hover(&mut harness);
+    harness.wait_until(|h| h.editor().tracked_plugin_lsp_request_count() == 1)?;
+    harness.wait_until(|h| {
+        h.advance_time(std::time::Duration::from_secs(1));
+        h.screen_to_string().contains("Hover failed:")
+    })?;
+
+    // The rejected request is kept for a while to drop a late response, then dropped
+    assert_eq!(harness.editor().tracked_plugin_lsp_request_count(), 1);
+    harness.wait_until(|h| {
+        h.advance_time(std::time::Duration::from_secs(1));
+        h.editor().tracked_plugin_lsp_request_count() == 0
+    })?;
+
+    Ok(())
+}
+
+/// Test that editor.lsp rejects when the active buffer has no LSP server
+#[test]
+fn test_plugin_lsp_request_without_server_rejects() -> anyhow::Result<()> {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir_all(&plugins_dir).unwrap();
+    fs::write(plugins_dir.join("test_lsp_hover.ts"), LSP_HOVER_PLUGIN).unwrap();
+    let text_file = project_root.join("notes.txt");
+    fs::write(&text_file, "plain text\n").unwrap();
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        30,
+        Config::default(),
+        project_root.clone(),
+    )?;
+    harness.open_file(&text_file)?;
+    harness.render()?;
+
+    run_plugin_hover(&mut harness);
+    harness.wait_for_screen_contains("Hover failed:")?;
+    harness.assert_screen_contains("No LSP server configured");
+
+    Ok(())
+}
+
 /// Test that plugin commands show the plugin name as source in command palette
 #[test]
 fn test_plugin_command_source_in_palette() {
@@ -1923,3 +2102,960 @@ editor.setStatus("Test diagnostics plugin loaded");
 
     Ok(())
 }
+
+/// Test plugins can move between diagnostics with gotoNextDiagnostic/gotoPrevDiagnostic
+#[test]
+fn test_plugin_goto_diagnostic() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+
+    let test_plugin = r#"
+const editor = getEditor();
+
+editor.registerCommand("Plugin Next Diagnostic", "Jump to next diagnostic", "plugin_next_diagnostic", null);
+editor.registerCommand("Plugin Prev Diagnostic", "Jump to previous diagnostic", "plugin_prev_diagnostic", null);
+
+globalThis.plugin_next_diagnostic = function(): void {
+    editor.gotoNextDiagnostic();
+};
+
+globalThis.plugin_prev_diagnostic = function(): void {
+    editor.gotoPrevDiagnostic();
+};
+"#;
+    fs::write(plugins_dir.join("test_goto_diagnostic.ts"), test_plugin).unwrap();
+
+    let fixture = TestFixture::new("test.txt", "line1\nline2\nline3\n").unwrap();
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(80, 24, Default::default(), project_root)
+            .unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+
+    let diagnostic = |line: u32, message: &str| lsp_types::Diagnostic {
+        range: lsp_types::Range {
+            start: lsp_types::Position { line, character: 0 },
+            end: lsp_types::Position { line, character: 5 },
+        },
+        severity: Some(lsp_types::DiagnosticSeverity::ERROR),
+        message: message.to_string(),
+        ..Default::default()
+    };
+    let theme = fresh::view::theme::Theme::load_builtin(fresh::view::theme::THEME_DARK).unwrap();
+    fresh::services::lsp::diagnostics::apply_diagnostics_to_state(
+        harness.editor_mut().active_state_mut(),
+        &[diagnostic(1, "Second line"), diagnostic(2, "Third line")],
+        &theme,
+    );
+
+    let run_command = |harness: &mut EditorTestHarness, name: &str, position: usize| {
+        harness
+            .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.type_text(name).unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+        harness
+            .wait_until(|h| h.cursor_position() == position)
+            .unwrap();
+    };
+
+    run_command(&mut harness, "Plugin Next Diagnostic", 6);
+    assert_eq!(
+        harness.editor().get_status_message().map(String::as_str),
+        Some("Second line")
+    );
+
+    // Previous from the first diagnostic wraps to the last one
+    run_command(&mut harness, "Plugin Prev Diagnostic", 12);
+    assert_eq!(
+        harness.editor().get_status_message().map(String::as_str),
+        Some("Third line")
+    );
+}
+
+/// Test that plugins can load locations into the quickfix list
+#[test]
+fn test_plugin_set_quickfix_list() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+
+    fs::write(project_root.join("a.txt"), "alpha\nbeta\n").unwrap();
+    fs::write(project_root.join("b.txt"), "gamma\ndelta\n").unwrap();
+
+    let test_plugin = r#"
+const editor = getEditor();
+
+editor.registerCommand("Load Quickfix", "Load test locations", "load_quickfix", null);
+
+globalThis.load_quickfix = function(): void {
+    editor.setQuickfixList([
+        { file: "a.txt", line: 2, column: 1, text: "beta" },
+        { file: "b.txt", line: 2, column: 3, text: "delta" },
+    ]);
+};
+"#;
+    fs::write(plugins_dir.join("test_quickfix.ts"), test_plugin).unwrap();
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        80,
+        24,
+        Default::default(),
+        project_root.clone(),
+    )
+    .unwrap();
+    harness.open_file(&project_root.join("a.txt")).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Load Quickfix").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_until(|h| h.editor().quickfix_list().len() == 2)
+        .unwrap();
+
+    harness.send_key(KeyCode::F(4), KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.cursor_position(), 6);
+
+    // The second entry opens the other file
+    harness.send_key(KeyCode::F(4), KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "gamma\ndelta\n");
+    assert_eq!(harness.cursor_position(), 8);
+    harness.assert_screen_contains("(2 of 2) b.txt:2:3 delta");
+}
+
+/// Test that plugins can add, update and remove status bar items
+#[test]
+fn test_plugin_status_bar_items() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+
+    let test_plugin = r#"
+const editor = getEditor();
+
+editor.registerCommand("Show Status Items", "Add status bar items", "show_status_items", null);
+editor.registerCommand("Clear Status Items", "Remove status bar items", "clear_status_items", null);
+
+globalThis.show_status_items = function(): void {
+    editor.setStatusBarItem("words", "Words: 2");
+    editor.setStatusBarItem("build", "Build: running", { alignment: "right", priority: 1 });
+    editor.setStatusBarItem("build", "Build: ok", { alignment: "right", priority: 1 });
+};
+
+globalThis.clear_status_items = function(): void {
+    editor.removeStatusBarItem("words");
+    editor.removeStatusBarItem("build");
+};
+"#;
+    fs::write(plugins_dir.join("test_status_items.ts"), test_plugin).unwrap();
+    fs::write(project_root.join("a.txt"), "hello world\n").unwrap();
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        100,
+        24,
+        Default::default(),
+        project_root.clone(),
+    )
+    .unwrap();
+    harness.open_file(&project_root.join("a.txt")).unwrap();
+    harness.render().unwrap();
+
+    let run_command = |harness: &mut EditorTestHarness, name: &str| {
+        harness
+            .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.type_text(name).unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+    };
+
+    run_command(&mut harness, "Show Status Items");
+    harness.wait_for_screen_contains("Build: ok").unwrap();
+
+    let status_row = harness.get_screen_row(22);
+    assert!(
+        status_row.contains("Ln 1, Col 1 | Words: 2"),
+        "Left item should follow the built-in segments: {status_row}"
+    );
+    assert!(
+        status_row.contains(" Build: ok  LF "),
+        "Right item should precede the line ending indicator: {status_row}"
+    );
+    harness.assert_screen_not_contains("Build: running");
+
+    run_command(&mut harness, "Clear Status Items");
+    harness
+        .wait_until(|h| !h.screen_to_string().contains("Build: ok"))
+        .unwrap();
+    harness.assert_screen_not_contains("Words: 2");
+}
+
+/// Test that plugin storage is written to disk and survives a plugin reload
+#[test]
+fn test_plugin_storage_survives_reload() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+    let context_temp = tempfile::TempDir::new().unwrap();
+    let dir_context = fresh::config_io::DirectoryContext::for_testing(context_temp.path());
+    let storage_file = dir_context.plugin_storage_dir().join("test_storage.json");
+
+    let test_plugin = r#"
+const editor = getEditor();
+
+editor.registerCommand("Store Greeting", "Store a value", "store_greeting", null);
+editor.registerCommand("Show Greeting", "Show the stored value", "show_greeting", null);
+
+globalThis.store_greeting = function(): void {
+    editor.setStorage("greeting", { text: "hello", count: 2 });
+    const stored = editor.getStorage("greeting") as { text: string };
+    editor.setStatus("Stored " + stored.text);
+};
+
+globalThis.show_greeting = function(): void {
+    const stored = editor.getStorage("greeting") as { text: string, count: number } | null;
+    editor.setStatus(stored ? "Greeting: " + stored.text + " x" + stored.count : "Greeting: none");
+};
+"#;
+    fs::write(plugins_dir.join("test_storage.ts"), test_plugin).unwrap();
+    fs::write(project_root.join("a.txt"), "hello world\n").unwrap();
+
+    let mut harness = EditorTestHarness::with_shared_dir_context(
+        100,
+        24,
+        Default::default(),
+        project_root.clone(),
+        dir_context,
+    )
+    .unwrap();
+    harness.open_file(&project_root.join("a.txt")).unwrap();
+    harness.render().unwrap();
+
+    let run_command = |harness: &mut EditorTestHarness, name: &str| {
+        harness
+            .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.type_text(name).unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+    };
+
+    // The value can be read back straight after it is stored
+    run_command(&mut harness, "Store Greeting");
+    harness.wait_for_screen_contains("Stored hello").unwrap();
+    harness.wait_until(|_| storage_file.exists()).unwrap();
+    let on_disk: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&storage_file).unwrap()).unwrap();
+    assert_eq!(on_disk["greeting"]["text"], "hello");
+
+    harness
+        .editor()
+        .plugin_manager()
+        .reload_plugin("test_storage")
+        .unwrap();
+
+    run_command(&mut harness, "Show Greeting");
+    harness
+        .wait_for_screen_contains("Greeting: hello x2")
+        .unwrap();
+}
+
+/// Test that a plugin decoration is drawn with its theme color and can be cleared
+#[test]
+fn test_plugin_highlight_range_decoration() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+
+    let test_plugin = r#"
+const editor = getEditor();
+
+editor.registerCommand("Decorate World", "Decorate a word", "decorate_world", null);
+editor.registerCommand("Clear Decorations", "Clear decorations", "clear_decorations", null);
+
+globalThis.decorate_world = function(): void {
+    const bufferId = editor.getActiveBufferId();
+    editor.highlightRange(bufferId, 6, 11, "diagnostic.error_bg", "lint");
+    editor.setStatus("Decorated");
+};
+
+globalThis.clear_decorations = function(): void {
+    editor.clearHighlights(editor.getActiveBufferId(), "lint");
+    editor.setStatus("Cleared");
+};
+"#;
+    fs::write(plugins_dir.join("test_decorate.ts"), test_plugin).unwrap();
+    let file_path = project_root.join("a.txt");
+    fs::write(&file_path, "hello world\n").unwrap();
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 24, Default::default(), project_root)
+            .unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    let run_command = |harness: &mut EditorTestHarness, name: &str| {
+        harness
+            .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.type_text(name).unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+    };
+
+    let gutter_width = harness
+        .editor()
+        .active_viewport()
+        .gutter_width(&harness.editor().active_state().buffer) as u16;
+    let (first_row, _) = harness.content_area_rows();
+    let first_row = first_row as u16;
+    let (r, g, b) =
+        fresh::view::theme::color_to_rgb(harness.editor().theme().diagnostic_error_bg).unwrap();
+    let decoration_bg = Some(ratatui::style::Color::Rgb(r, g, b));
+    let bg_at = |harness: &EditorTestHarness, x: u16| {
+        harness
+            .get_cell_style(gutter_width + x, first_row)
+            .and_then(|style| style.bg)
+    };
+
+    run_command(&mut harness, "Decorate World");
+    harness.wait_for_screen_contains("Decorated").unwrap();
+    harness
+        .wait_until(|h| bg_at(h, 6) == decoration_bg)
+        .unwrap();
+    // "world" is decorated, "hello " is not
+    assert_eq!(bg_at(&harness, 10), decoration_bg);
+    assert_ne!(bg_at(&harness, 2), decoration_bg);
+
+    run_command(&mut harness, "Clear Decorations");
+    harness.wait_for_screen_contains("Cleared").unwrap();
+    harness
+        .wait_until(|h| bg_at(h, 6) != decoration_bg)
+        .unwrap();
+}
+
+/// Test that a plugin text object can be selected and deleted via actions
+#[test]
+fn test_plugin_text_object_select_and_delete() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+
+    let test_plugin = r#"
+const editor = getEditor();
+
+editor.registerTextObject("code fence", "match_code_fence");
+
+// Matches the lines between the ``` fences around the cursor (or selection start)
+globalThis.match_code_fence = async function(query: TextObjectQuery): Promise<TextObjectRange | null> {
+    const length = editor.getBufferLength(query.bufferId);
+    const text = await editor.getBufferText(query.bufferId, 0, length);
+    const from = query.anchor !== null ? Math.min(query.anchor, query.position) : query.position;
+    const open = text.lastIndexOf("```", from);
+    if (open < 0) {
+        return null;
+    }
+    const start = text.indexOf("\n", open) + 1;
+    const end = text.indexOf("```", start);
+    if (start <= 0 || end < 0 || from > end) {
+        return null;
+    }
+    return { start, end };
+};
+"#;
+    fs::write(plugins_dir.join("test_text_object.ts"), test_plugin).unwrap();
+    let content = "intro\n```\nlet a = 1;\nlet b = 2;\n```\noutro\n";
+    fs::write(project_root.join("a.md"), content).unwrap();
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        100,
+        24,
+        Default::default(),
+        project_root.clone(),
+    )
+    .unwrap();
+    harness.open_file(&project_root.join("a.md")).unwrap();
+    harness.render().unwrap();
+
+    let run_command = |harness: &mut EditorTestHarness, name: &str| {
+        harness
+            .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.type_text(name).unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+    };
+
+    // Put the cursor inside the fence, on "let b"
+    for _ in 0..3 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+
+    let inner_start = content.find("let a").unwrap();
+    let inner_end = content.rfind("```").unwrap();
+    run_command(&mut harness, "Select Text Object: code fence");
+    harness
+        .wait_until(|h| h.get_selection_range() == Some(inner_start..inner_end))
+        .unwrap();
+    assert_eq!(harness.get_selected_text(), "let a = 1;\nlet b = 2;\n");
+
+    run_command(&mut harness, "Delete Text Object: code fence");
+    harness
+        .wait_until(|h| h.get_buffer_content().as_deref() == Some("intro\n```\n```\noutro\n"))
+        .unwrap();
+}
+
+/// Test that expand-selection grows to the smallest plugin text object around the
+/// selection, and falls back to word expansion once the selection covers it
+#[test]
+fn test_plugin_text_object_expand_selection() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+
+    let test_plugin = r#"
+const editor = getEditor();
+
+editor.registerTextObject("line", "match_line");
+
+globalThis.match_line = async function(query: TextObjectQuery): Promise<TextObjectRange | null> {
+    const length = editor.getBufferLength(query.bufferId);
+    const text = await editor.getBufferText(query.bufferId, 0, length);
+    const from = query.anchor !== null ? Math.min(query.anchor, query.position) : query.position;
+    const start = text.lastIndexOf("\n", from - 1) + 1;
+    const newline = text.indexOf("\n", from);
+    return { start, end: newline < 0 ? length : newline };
+};
+editor.setStatus("text object ready");
+"#;
+    fs::write(plugins_dir.join("test_text_object.ts"), test_plugin).unwrap();
+    let content = "first line\nlet value = 1;\n";
+    fs::write(project_root.join("a.txt"), content).unwrap();
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        100,
+        24,
+        Default::default(),
+        project_root.clone(),
+    )
+    .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("text object ready"))
+        .unwrap();
+    harness.open_file(&project_root.join("a.txt")).unwrap();
+    harness.render().unwrap();
+
+    let expand = |harness: &mut EditorTestHarness| {
+        harness
+            .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.type_text("Expand Selection").unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+    };
+
+    // Cursor on "value": the whole line wins over the word
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    for _ in 0..4 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+    let line_start = content.find("let").unwrap();
+    let line_end = content.len() - 1;
+    expand(&mut harness);
+    harness
+        .wait_until(|h| h.get_selection_range() == Some(line_start..line_end))
+        .unwrap();
+
+    // The line no longer grows the selection, so the word expansion stays
+    expand(&mut harness);
+    harness
+        .wait_until(|h| {
+            h.get_selection_range()
+                .is_some_and(|r| r.start == line_start && r.end > line_end)
+        })
+        .unwrap();
+}
+
+/// Test that plugins can list, add and remove keybindings
+#[test]
+fn test_plugin_keybinding_crud() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+
+    let test_plugin = r#"
+const editor = getEditor();
+
+globalThis.keymap_list = async function(): Promise<void> {
+    const bindings = await editor.getKeybindings("normal");
+    const commandFor = (key: string) => {
+        const binding = bindings.find((b) => b.key === key);
+        return binding ? binding.command : "none";
+    };
+    editor.setStatus(`F7=${commandFor("F7")} End=${commandFor("End")}`);
+};
+
+globalThis.keymap_add = function(): void {
+    editor.setKeybinding("F7", "move_line_end");
+};
+
+globalThis.keymap_remove = function(): void {
+    editor.removeKeybinding("F7");
+    editor.removeKeybinding("End", "normal");
+};
+
+editor.registerCommand("Keymap List", "List bindings", "keymap_list", null);
+editor.registerCommand("Keymap Add", "Bind F7", "keymap_add", null);
+editor.registerCommand("Keymap Remove", "Unbind F7 and End", "keymap_remove", null);
+"#;
+    fs::write(plugins_dir.join("test_keymap.ts"), test_plugin).unwrap();
+    fs::write(project_root.join("a.txt"), "hello world\n").unwrap();
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        24,
+        Default::default(),
+        project_root.clone(),
+    )
+    .unwrap();
+    harness.open_file(&project_root.join("a.txt")).unwrap();
+    harness.render().unwrap();
+
+    let run_command = |harness: &mut EditorTestHarness, name: &str| {
+        harness
+            .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.type_text(name).unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+    };
+
+    // Listing shows the default End binding and nothing on F7
+    run_command(&mut harness, "Keymap List");
+    harness
+        .wait_until(|h| h.screen_to_string().contains("F7=none End=move_line_end"))
+        .unwrap();
+    harness.send_key(KeyCode::F(7), KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.cursor_position(), 0);
+
+    // After adding, F7 is listed and moves to the end of the line
+    run_command(&mut harness, "Keymap Add");
+    run_command(&mut harness, "Keymap List");
+    harness
+        .wait_until(|h| h.screen_to_string().contains("F7=move_line_end End="))
+        .unwrap();
+    harness.send_key(KeyCode::F(7), KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.cursor_position(), "hello world".len());
+
+    // After removing, neither F7 nor the default End binding move the cursor
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    run_command(&mut harness, "Keymap Remove");
+    run_command(&mut harness, "Keymap List");
+    harness
+        .wait_until(|h| h.screen_to_string().contains("F7=none End=none"))
+        .unwrap();
+    harness.send_key(KeyCode::F(7), KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.cursor_position(), 0);
+}
+
+/// Test that plugin keybindings survive switching the keybinding map
+#[test]
+fn test_plugin_keybinding_survives_keymap_switch() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+
+    let test_plugin = r#"
+const editor = getEditor();
+
+globalThis.keymap_customize = function(): void {
+    editor.setKeybinding("F7", "move_line_end");
+    editor.removeKeybinding("End", "normal");
+    editor.setStatus("Customized");
+};
+
+editor.registerCommand("Keymap Customize", "Bind F7 and unbind End", "keymap_customize", null);
+"#;
+    fs::write(plugins_dir.join("test_keymap_switch.ts"), test_plugin).unwrap();
+    fs::write(project_root.join("a.txt"), "hello world\n").unwrap();
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        24,
+        Default::default(),
+        project_root.clone(),
+    )
+    .unwrap();
+    harness.open_file(&project_root.join("a.txt")).unwrap();
+    harness.render().unwrap();
+
+    let run_command = |harness: &mut EditorTestHarness, name: &str| {
+        harness
+            .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.type_text(name).unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+    };
+
+    run_command(&mut harness, "Keymap Customize");
+    harness.wait_for_screen_contains("Customized").unwrap();
+
+    // Switch to another keybinding map, which rebuilds the resolver
+    run_command(&mut harness, "Select Keybinding Map");
+    for _ in 0.."default".len() {
+        harness
+            .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.type_text("vscode").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_for_screen_contains("Switched to 'vscode' keybindings")
+        .unwrap();
+
+    // End stays unbound and F7 still moves to the end of the line
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.cursor_position(), 0);
+    harness.send_key(KeyCode::F(7), KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.cursor_position(), "hello world".len());
+}
+
+/// Test that cursor_position_changed is coalesced to one event per frame
+#[test]
+fn test_plugin_cursor_position_changed_hook() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+
+    let test_plugin = r#"
+const editor = getEditor();
+
+const seen: number[] = [];
+
+globalThis.on_cursor_position_changed = function(data: {
+    buffer_id: number;
+    position: number;
+    line: number;
+    column: number;
+}): void {
+    seen.push(data.position);
+    editor.setStatus(`seen=${seen.join(",")} at ${data.line}:${data.column}`);
+};
+
+editor.on("cursor_position_changed", "on_cursor_position_changed");
+"#;
+    fs::write(plugins_dir.join("test_cursor_hook.ts"), test_plugin).unwrap();
+    fs::write(project_root.join("a.txt"), "hello world\nsecond line\n").unwrap();
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        24,
+        Default::default(),
+        project_root.clone(),
+    )
+    .unwrap();
+    harness.open_file(&project_root.join("a.txt")).unwrap();
+    harness.render().unwrap();
+
+    let seen_positions = |harness: &EditorTestHarness| -> Vec<String> {
+        let screen = harness.screen_to_string();
+        let start = screen.find("seen=").unwrap() + "seen=".len();
+        let end = start + screen[start..].find(" at ").unwrap();
+        screen[start..end].split(',').map(String::from).collect()
+    };
+
+    // Three moves within a single frame are reported once, with the final position
+    harness
+        .send_key_repeat(KeyCode::Right, KeyModifiers::NONE, 3)
+        .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("at 1:4"))
+        .unwrap();
+    let seen = seen_positions(&harness);
+    assert_eq!(seen.last().map(String::as_str), Some("3"));
+    assert!(
+        !seen.iter().any(|p| p == "1" || p == "2"),
+        "intermediate positions should be coalesced: {seen:?}"
+    );
+
+    // A move to the next line reports the new line and column
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("at 2:4"))
+        .unwrap();
+    assert_eq!(
+        seen_positions(&harness).last().map(String::as_str),
+        Some("15")
+    );
+}
+
+/// Test that getCursorDetails reports line, column and buffer size
+#[test]
+fn test_plugin_get_cursor_details() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+
+    let test_plugin = r#"
+const editor = getEditor();
+
+globalThis.show_cursor_details = function(): void {
+    const d = editor.getCursorDetails();
+    if (d) {
+        editor.setStatus(`details=${d.line}:${d.column} ${d.byte}/${d.totalBytes} lines=${d.totalLines}`);
+    }
+};
+
+editor.registerCommand("Show Cursor Details", "Show cursor details", "show_cursor_details", null);
+"#;
+    fs::write(plugins_dir.join("test_cursor_details.ts"), test_plugin).unwrap();
+    fs::write(project_root.join("a.txt"), "abc\ndefg\n").unwrap();
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        24,
+        Default::default(),
+        project_root.clone(),
+    )
+    .unwrap();
+    harness.open_file(&project_root.join("a.txt")).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key_repeat(KeyCode::Right, KeyModifiers::NONE, 2)
+        .unwrap();
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Show Cursor Details").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("details=1:2 6/9 lines=3"))
+        .unwrap();
+}
+
+/// Test that paths claimed by a plugin open handler get the plugin's content
+#[test]
+fn test_plugin_open_handler_provides_content() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+
+    let test_plugin = r#"
+const editor = getEditor();
+
+editor.registerOpenHandler("^mem://", "open_mem");
+
+globalThis.open_mem = async function(path: string): Promise<string> {
+    return "contents of " + path.slice("mem://".length) + "\n";
+};
+
+globalThis.open_mem_from_plugin = function(): void {
+    editor.openFile("mem://second", null, null);
+};
+
+editor.registerCommand("Open Mem Second", "Open a mem:// path", "open_mem_from_plugin", null);
+editor.setStatus("open handler ready");
+"#;
+    fs::write(plugins_dir.join("test_open_handler.ts"), test_plugin).unwrap();
+    fs::write(project_root.join("real.txt"), "from disk\n").unwrap();
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        100,
+        24,
+        Default::default(),
+        project_root.clone(),
+    )
+    .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("open handler ready"))
+        .unwrap();
+
+    // Opening a matching path uses the handler instead of the filesystem;
+    // the pattern sees the path as given, not resolved against the working dir
+    harness
+        .open_file(std::path::Path::new("mem://first"))
+        .unwrap();
+    harness
+        .wait_until(|h| h.get_buffer_content().as_deref() == Some("contents of first\n"))
+        .unwrap();
+
+    // editor.openFile goes through the handler too
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Open Mem Second").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_until(|h| h.get_buffer_content().as_deref() == Some("contents of second\n"))
+        .unwrap();
+
+    // Paths that don't match still open from disk
+    harness.open_file(&project_root.join("real.txt")).unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "from disk\n");
+}
+
+/// Test that open handler patterns for file names also match resolved paths
+#[test]
+fn test_plugin_open_handler_matches_resolved_path() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+
+    let test_plugin = r#"
+const editor = getEditor();
+
+editor.registerOpenHandler("/project_root/[^/]+\\.mem$", "open_mem");
+
+globalThis.open_mem = async function(path: string): Promise<string> {
+    if (path.endsWith("broken.mem")) {
+        throw new Error("no such mem");
+    }
+    return "contents of " + path.slice(path.lastIndexOf("/") + 1) + "\n";
+};
+
+editor.setStatus("open handler ready");
+"#;
+    fs::write(plugins_dir.join("test_open_handler.ts"), test_plugin).unwrap();
+    fs::write(project_root.join("real.txt"), "from disk\n").unwrap();
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        100,
+        24,
+        Default::default(),
+        project_root.clone(),
+    )
+    .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("open handler ready"))
+        .unwrap();
+
+    // A relative path only matches once resolved against the working dir
+    harness
+        .open_file(std::path::Path::new("first.mem"))
+        .unwrap();
+    harness
+        .wait_until(|h| h.get_buffer_content().as_deref() == Some("contents of first.mem\n"))
+        .unwrap();
+
+    // A failing handler closes its placeholder buffer and reports the error
+    harness.open_file(&project_root.join("broken.mem")).unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("no such mem"))
+        .unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "contents of first.mem\n"
+    );
+
+    // Paths that don't match still open from disk
+    harness.open_file(&project_root.join("real.txt")).unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "from disk\n");
+}
+
+/// Test that saveBufferToPath honors insert_final_newline, also for a buffer
+/// that is not the active one
+#[test]
+fn test_plugin_save_buffer_to_path_inserts_final_newline() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+
+    let test_plugin = r#"
+const editor = getEditor();
+
+globalThis.save_notes_copy = function(): void {
+    const notes = editor.listBuffers().find((b) => b.path.endsWith("notes.txt"));
+    if (notes) {
+        editor.saveBufferToPath(notes.id, editor.getCwd() + "/copy.txt");
+    }
+};
+
+editor.registerCommand("Save Notes Copy", "Save notes.txt to copy.txt", "save_notes_copy", null);
+"#;
+    fs::write(plugins_dir.join("test_save_to_path.ts"), test_plugin).unwrap();
+    fs::write(project_root.join("notes.txt"), "one\ntwo").unwrap();
+    fs::write(project_root.join("other.txt"), "other").unwrap();
+
+    let mut config = Config::default();
+    config.editor.insert_final_newline = true;
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 24, config, project_root.clone())
+            .unwrap();
+    harness.open_file(&project_root.join("notes.txt")).unwrap();
+    let notes_id = harness.editor().active_buffer_id();
+    harness.open_file(&project_root.join("other.txt")).unwrap();
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Save Notes Copy").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    let copy_path = project_root.join("copy.txt");
+    harness
+        .wait_until(|_| fs::read_to_string(&copy_path).ok().as_deref() == Some("one\ntwo\n"))
+        .unwrap();
+
+    // The saved buffer gets the line ending too; the active one is untouched
+    assert_eq!(
+        harness.editor().get_buffer_content(notes_id).as_deref(),
+        Some("one\ntwo\n")
+    );
+    harness.assert_buffer_content("other");
+}
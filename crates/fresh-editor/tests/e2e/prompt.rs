@@ -785,6 +785,102 @@ fn test_save_as_same_file_no_confirmation() {
     );
 }
 
+/// Open `docs/notes.txt` in a project with the given Save As directory setting,
+/// then start a new buffer from it and press Ctrl+S to bring up Save As
+fn save_as_new_buffer_prompt(
+    setting: fresh::config::SaveAsDirectory,
+) -> (EditorTestHarness, tempfile::TempDir) {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_dir = temp_dir.path().canonicalize().unwrap();
+    std::fs::create_dir(project_dir.join("docs")).unwrap();
+    std::fs::write(project_dir.join("docs/notes.txt"), "notes").unwrap();
+
+    let mut config = fresh::config::Config::default();
+    config.editor.save_as_default_dir = setting;
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(120, 24, config, project_dir.clone())
+            .unwrap();
+    harness
+        .open_file(&project_dir.join("docs/notes.txt"))
+        .unwrap();
+    harness.new_buffer().unwrap();
+    harness.type_text("draft").unwrap();
+
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_screen_contains("Save as:").unwrap();
+
+    (harness, temp_dir)
+}
+
+/// Test Save As for a new buffer starts in the directory of the file it was created from
+#[test]
+#[cfg_attr(windows, ignore)] // Path display format differs on Windows
+fn test_save_as_new_buffer_defaults_to_active_buffer_dir() {
+    let (harness, temp_dir) =
+        save_as_new_buffer_prompt(fresh::config::SaveAsDirectory::ActiveBuffer);
+    let docs_dir = temp_dir.path().canonicalize().unwrap().join("docs");
+
+    // The file browser title shows the directory it starts in
+    harness.assert_screen_contains(&format!(" {} ", docs_dir.display()));
+}
+
+/// Test Save As for a new buffer starts in the workspace root when configured
+#[test]
+#[cfg_attr(windows, ignore)] // Path display format differs on Windows
+fn test_save_as_new_buffer_uses_workspace_root_setting() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let (mut harness, temp_dir) =
+        save_as_new_buffer_prompt(fresh::config::SaveAsDirectory::WorkspaceRoot);
+    let project_dir = temp_dir.path().canonicalize().unwrap();
+
+    harness.assert_screen_contains(&format!(" {} ", project_dir.display()));
+
+    // A bare filename is saved into the pre-filled directory
+    harness.type_text("draft.txt").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    assert_eq!(
+        std::fs::read_to_string(project_dir.join("draft.txt")).unwrap(),
+        "draft"
+    );
+}
+
+/// Test Save As for a new buffer starts where the last file was saved when configured
+#[test]
+#[cfg_attr(windows, ignore)] // Path display format differs on Windows
+fn test_save_as_new_buffer_uses_last_used_dir_setting() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let (mut harness, temp_dir) =
+        save_as_new_buffer_prompt(fresh::config::SaveAsDirectory::LastUsed);
+    let project_dir = temp_dir.path().canonicalize().unwrap();
+    let out_dir = project_dir.join("out");
+    std::fs::create_dir(&out_dir).unwrap();
+
+    // Nothing has been saved yet, so the first prompt falls back to the usual directory
+    let saved_path = out_dir.join("first.txt");
+    harness.type_text(saved_path.to_str().unwrap()).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    assert!(saved_path.exists());
+
+    // The next new buffer starts in the directory just saved to
+    harness.new_buffer().unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_screen_contains("Save as:").unwrap();
+
+    harness.assert_screen_contains(&format!(" {} ", out_dir.display()));
+}
+
 /// Test that long paths are truncated in the Open File prompt
 ///
 /// When the path + input would exceed 90% of the prompt width, the path should be
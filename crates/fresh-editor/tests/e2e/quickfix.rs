@@ -0,0 +1,120 @@
+//! E2E tests for the quickfix list: loading results and stepping through them
+
+use crate::common::fake_lsp::FakeLspServer;
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+const SOURCE: &str = "fn get_value() -> i32 { 42 }\nfn main() {\n    get_value();\n    let x = 1;\n    get_value();\n}\n";
+
+/// Byte offsets of the three references the fake LSP server reports
+const REFERENCES: [usize; 3] = [3, 45, 77];
+
+/// Open SOURCE with the fake LSP server and load its references into the quickfix list
+fn harness_with_references(temp_dir: &tempfile::TempDir) -> anyhow::Result<EditorTestHarness> {
+    let test_file = temp_dir.path().join("test.rs");
+    std::fs::write(&test_file, SOURCE)?;
+
+    let mut config = fresh::config::Config::default();
+    config.lsp.insert(
+        "rust".to_string(),
+        fresh::services::lsp::LspServerConfig {
+            command: FakeLspServer::script_path().to_string_lossy().to_string(),
+            args: vec![],
+            enabled: true,
+            auto_start: true,
+            process_limits: fresh::services::process_limits::ProcessLimits::default(),
+            initialization_options: None,
+        },
+    );
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        100,
+        30,
+        config,
+        temp_dir.path().to_path_buf(),
+    )?;
+    harness.open_file(&test_file)?;
+    harness.render()?;
+
+    // Find references on `get_value`
+    for _ in 0..4 {
+        harness.send_key(KeyCode::Right, KeyModifiers::NONE)?;
+    }
+    harness.send_key(KeyCode::F(12), KeyModifiers::SHIFT)?;
+    harness.wait_until(|h| h.editor().quickfix_list().len() == REFERENCES.len())?;
+
+    // Dismiss the references picker; the results stay in the quickfix list
+    harness.wait_for_screen_contains("References to 'get_value'")?;
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE)?;
+
+    Ok(harness)
+}
+
+/// Find references fills the quickfix list, and next item visits each location in order
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_references_populate_quickfix_list() -> anyhow::Result<()> {
+    let _fake_server = FakeLspServer::spawn()?;
+    let temp_dir = tempfile::tempdir()?;
+    let mut harness = harness_with_references(&temp_dir)?;
+
+    let lines: Vec<usize> = harness
+        .editor()
+        .quickfix_list()
+        .items()
+        .iter()
+        .map(|item| item.line)
+        .collect();
+    assert_eq!(lines, vec![1, 3, 5]);
+
+    for &expected in &REFERENCES {
+        harness.send_key(KeyCode::F(4), KeyModifiers::NONE)?;
+        assert_eq!(harness.cursor_position(), expected);
+    }
+    harness.render()?;
+    harness.assert_screen_contains("(3 of 3) test.rs:5:5");
+
+    // Stepping past the last item wraps to the first
+    harness.send_key(KeyCode::F(4), KeyModifiers::NONE)?;
+    assert_eq!(harness.cursor_position(), REFERENCES[0]);
+
+    Ok(())
+}
+
+/// Previous item walks the list backwards and wraps to the last item
+#[test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "FakeLspServer uses a Bash script which is not available on Windows"
+)]
+fn test_quickfix_previous_item() -> anyhow::Result<()> {
+    let _fake_server = FakeLspServer::spawn()?;
+    let temp_dir = tempfile::tempdir()?;
+    let mut harness = harness_with_references(&temp_dir)?;
+
+    harness.send_key(KeyCode::F(4), KeyModifiers::NONE)?;
+    assert_eq!(harness.cursor_position(), REFERENCES[0]);
+
+    harness.send_key(KeyCode::F(4), KeyModifiers::SHIFT)?;
+    assert_eq!(harness.cursor_position(), REFERENCES[2]);
+    harness.send_key(KeyCode::F(4), KeyModifiers::SHIFT)?;
+    assert_eq!(harness.cursor_position(), REFERENCES[1]);
+
+    Ok(())
+}
+
+/// Stepping through an empty list reports it instead of moving
+#[test]
+fn test_quickfix_empty_list() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("hello").unwrap();
+
+    harness.send_key(KeyCode::F(4), KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(harness.cursor_position(), 5);
+    harness.assert_screen_contains("Quickfix list is empty");
+}
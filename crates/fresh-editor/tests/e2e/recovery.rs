@@ -217,9 +217,9 @@ fn test_undo_returns_to_saved_state_not_original() {
     // Content should now be "Hello World"
     assert_eq!(harness.get_buffer_content().unwrap(), "Hello World");
 
-    // 5. Undo " World" (6 characters) to return to saved state "Hello"
-    // Each character is a separate undo step
-    for _ in 0..6 {
+    // 5. Undo " World" to return to saved state "Hello"
+    // The space and the word are separate undo steps
+    for _ in 0..2 {
         harness
             .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
             .unwrap();
@@ -240,11 +240,9 @@ fn test_undo_returns_to_saved_state_not_original() {
 
     // 6. Now undo PAST the saved state (undo "Hello") -> should become modified again
     // because we're now different from the saved file content "Hello"
-    for _ in 0..5 {
-        harness
-            .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
-            .unwrap();
-    }
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
 
     // Content should be "" (empty, the original state)
     assert_eq!(
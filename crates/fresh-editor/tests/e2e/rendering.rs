@@ -590,3 +590,448 @@ fn test_ansi_rgb_color_rendering() {
         );
     }
 }
+
+/// Open a small block-structured file with the cursor on its most indented line
+fn open_indented_code(harness: &mut EditorTestHarness, temp_dir: &TempDir) {
+    let file_path = temp_dir.path().join("guides.txt");
+    std::fs::write(&file_path, "fn a() {\n    if x {\n        y();\n    }\n}\n").unwrap();
+    harness.open_file(&file_path).unwrap();
+    for _ in 0..2 {
+        harness
+            .send_key(
+                crossterm::event::KeyCode::Down,
+                crossterm::event::KeyModifiers::NONE,
+            )
+            .unwrap();
+    }
+    harness
+        .send_key(
+            crossterm::event::KeyCode::End,
+            crossterm::event::KeyModifiers::NONE,
+        )
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Test indent guides are drawn in leading whitespace at each indentation level,
+/// with the level enclosing the cursor highlighted
+#[test]
+fn test_indent_guides_rendering() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = fresh::config::Config::default();
+    config.editor.show_indent_guides = true;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    open_indented_code(&mut harness, &temp_dir);
+
+    let gutter_width = harness
+        .editor()
+        .active_viewport()
+        .gutter_width(&harness.editor().active_state().buffer) as u16;
+    let (first_row, _) = harness.content_area_rows();
+    let row = |line: usize| (first_row + line) as u16;
+    let guide_fg = harness.editor().theme().indent_guide_fg;
+    let active_fg = harness.editor().theme().indent_guide_active_fg;
+
+    // "        y();" has guides at columns 0 and 4; column 4 is the cursor's level
+    assert_eq!(harness.get_cell(gutter_width, row(2)).as_deref(), Some("│"));
+    assert_eq!(
+        harness.get_cell(gutter_width + 4, row(2)).as_deref(),
+        Some("│")
+    );
+    assert_eq!(
+        harness.get_cell_style(gutter_width, row(2)).unwrap().fg,
+        Some(guide_fg)
+    );
+    assert_eq!(
+        harness.get_cell_style(gutter_width + 4, row(2)).unwrap().fg,
+        Some(active_fg)
+    );
+    // Whitespace between guides stays blank
+    assert_eq!(
+        harness.get_cell(gutter_width + 2, row(2)).as_deref(),
+        Some(" ")
+    );
+
+    // "    if x {" and "    }" only have the outermost guide
+    for line in [1, 3] {
+        assert_eq!(
+            harness.get_cell(gutter_width, row(line)).as_deref(),
+            Some("│")
+        );
+        assert_eq!(
+            harness.get_cell_style(gutter_width, row(line)).unwrap().fg,
+            Some(guide_fg)
+        );
+        assert_ne!(
+            harness.get_cell(gutter_width + 4, row(line)).as_deref(),
+            Some("│")
+        );
+    }
+
+    // Unindented lines have no guides
+    assert_eq!(harness.get_cell(gutter_width, row(0)).as_deref(), Some("f"));
+    assert_eq!(harness.get_cell(gutter_width, row(4)).as_deref(), Some("}"));
+}
+
+/// Test indent guides are off by default and can be toggled from the command palette
+#[test]
+fn test_toggle_indent_guides() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let temp_dir = TempDir::new().unwrap();
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    open_indented_code(&mut harness, &temp_dir);
+    harness.assert_screen_not_contains("│   y();");
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Toggle Indent Guides").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert!(harness.config().editor.show_indent_guides);
+    harness.assert_screen_contains("│   │   y();");
+}
+
+/// Open a file with a repeated word, put the cursor on it and let the
+/// occurrence highlight debounce settle
+fn open_repeated_word(harness: &mut EditorTestHarness, temp_dir: &TempDir) {
+    let file_path = temp_dir.path().join("words.txt");
+    std::fs::write(&file_path, "alpha beta alpha gamma\nalpha\n").unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(
+        fresh::view::reference_highlight_overlay::DEFAULT_DEBOUNCE_MS + 50,
+    ));
+    harness.render().unwrap();
+}
+
+/// Test other occurrences of the word under the cursor are highlighted
+#[test]
+fn test_word_occurrences_highlighted() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    open_repeated_word(&mut harness, &temp_dir);
+
+    let gutter_width = harness
+        .editor()
+        .active_viewport()
+        .gutter_width(&harness.editor().active_state().buffer) as u16;
+    let (first_row, _) = harness.content_area_rows();
+    let first_row = first_row as u16;
+    let highlight_bg = harness.editor().theme().semantic_highlight_bg;
+    let bg_at = |x: u16, y: u16| harness.get_cell_style(gutter_width + x, y).unwrap().bg;
+
+    // "alpha" at column 11 of the first line and column 0 of the second
+    assert_eq!(bg_at(11, first_row), Some(highlight_bg));
+    assert_eq!(bg_at(15, first_row), Some(highlight_bg));
+    assert_eq!(bg_at(0, first_row + 1), Some(highlight_bg));
+
+    // "beta" and "gamma" are left alone
+    assert_ne!(bg_at(6, first_row), Some(highlight_bg));
+    assert_ne!(bg_at(17, first_row), Some(highlight_bg));
+}
+
+/// Test occurrence highlighting can be turned off in the config
+#[test]
+fn test_word_occurrences_highlight_disabled() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = fresh::config::Config::default();
+    config.editor.highlight_word_occurrences = false;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    open_repeated_word(&mut harness, &temp_dir);
+
+    let gutter_width = harness
+        .editor()
+        .active_viewport()
+        .gutter_width(&harness.editor().active_state().buffer) as u16;
+    let (first_row, _) = harness.content_area_rows();
+    let highlight_bg = harness.editor().theme().semantic_highlight_bg;
+
+    assert_ne!(
+        harness
+            .get_cell_style(gutter_width + 11, first_row as u16)
+            .unwrap()
+            .bg,
+        Some(highlight_bg)
+    );
+}
+
+/// Open a file with one long function and move the cursor deep into its body
+fn scroll_into_long_function(harness: &mut EditorTestHarness, temp_dir: &TempDir) {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let mut content = String::from("fn long_function(x: u32) -> u32 {\n");
+    for i in 0..60 {
+        content.push_str(&format!("    let value_{i} = x + {i};\n"));
+    }
+    content.push_str("    x\n}\n");
+    let file_path = temp_dir.path().join("long.rs");
+    std::fs::write(&file_path, content).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    for _ in 0..40 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+    harness.render().unwrap();
+    assert!(harness.editor().active_viewport().top_byte > 0);
+}
+
+/// Test sticky scroll pins the enclosing function signature to the top of the view
+#[test]
+fn test_sticky_scroll_shows_enclosing_function() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = fresh::config::Config::default();
+    config.editor.sticky_scroll = true;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    scroll_into_long_function(&mut harness, &temp_dir);
+
+    let (first_row, _) = harness.content_area_rows();
+    let header = harness.get_row_text(first_row as u16);
+    assert!(
+        header.contains("fn long_function(x: u32) -> u32 {"),
+        "Expected the function signature pinned at the top, got: {header:?}"
+    );
+    assert!(
+        header.contains(" 1 "),
+        "Header shows the signature's line number"
+    );
+}
+
+/// Test sticky scroll is off by default
+#[test]
+fn test_sticky_scroll_disabled_by_default() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    scroll_into_long_function(&mut harness, &temp_dir);
+
+    harness.assert_screen_not_contains("fn long_function");
+}
+
+/// Test control characters render in caret notation, styled apart from text,
+/// with the cursor still mapping to the right source bytes
+#[test]
+fn test_control_char_caret_notation() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("form_feed.txt");
+    std::fs::write(&file_path, b"ab\x0ccd\xffef\n").unwrap();
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("ab^Lcd\u{FFFD}ef");
+    harness.assert_screen_not_contains("<0C>");
+
+    let gutter_width = harness
+        .editor()
+        .active_viewport()
+        .gutter_width(&harness.editor().active_state().buffer) as u16;
+    let (first_row, _) = harness.content_area_rows();
+    let control_fg = harness.editor().theme().control_char_fg;
+    let fg_at = |x: u16| {
+        harness
+            .get_cell_style(gutter_width + x, first_row as u16)
+            .unwrap()
+            .fg
+    };
+    assert_eq!(fg_at(2), Some(control_fg));
+    assert_eq!(fg_at(3), Some(control_fg));
+    assert_eq!(fg_at(6), Some(control_fg));
+    assert_ne!(fg_at(4), Some(control_fg));
+
+    // Both caret cells map to the single \x0c byte: stepping over it moves
+    // the cursor two columns, and the invalid byte takes one byte and column
+    let mut expect = |bytes: usize, col: u16| {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+        harness.render().unwrap();
+        assert_eq!(harness.cursor_position(), bytes);
+        assert_eq!(harness.screen_cursor_position().0, gutter_width + col);
+    };
+    expect(1, 1);
+    expect(2, 2);
+    expect(3, 4);
+    expect(4, 5);
+    expect(5, 6);
+    expect(6, 7);
+}
+
+/// Test caret notation can be turned off in favor of hex codes
+#[test]
+fn test_control_char_caret_notation_disabled() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("form_feed.txt");
+    std::fs::write(&file_path, b"ab\x0ccd\xffef\n").unwrap();
+    let mut config = fresh::config::Config::default();
+    config.editor.caret_control_chars = false;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("ab<0C>cd<FF>ef");
+    harness.assert_screen_not_contains("^L");
+}
+
+/// Test whitespace is drawn as dim glyphs without changing the buffer
+#[test]
+fn test_render_whitespace() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("spaces.txt");
+    std::fs::write(&file_path, "x\tfoo bar  \n").unwrap();
+    let mut config = fresh::config::Config::default();
+    config.editor.render_whitespace = true;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    // The tab after `x` spans three columns, only the first gets an arrow
+    harness.assert_screen_contains("x→  foo·bar··");
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "x\tfoo bar  \n",
+        "Rendering whitespace must not change the buffer"
+    );
+
+    let gutter_width = harness
+        .editor()
+        .active_viewport()
+        .gutter_width(&harness.editor().active_state().buffer) as u16;
+    let (first_row, _) = harness.content_area_rows();
+    let whitespace_fg = harness.editor().theme().whitespace_indicator_fg;
+    let cell = |x: u16| {
+        let x = gutter_width + x;
+        (
+            harness.get_cell(x, first_row as u16).unwrap(),
+            harness.get_cell_style(x, first_row as u16).unwrap().fg,
+        )
+    };
+    assert_eq!(cell(1), ("→".to_string(), Some(whitespace_fg)));
+    assert_eq!(cell(2).0, " ");
+    assert_eq!(cell(7), ("·".to_string(), Some(whitespace_fg)));
+    assert_ne!(cell(6).1, Some(whitespace_fg));
+}
+
+/// Test trailing whitespace can be rendered on its own
+#[test]
+fn test_render_trailing_whitespace_only() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("spaces.txt");
+    std::fs::write(&file_path, "foo bar  \n  \nbaz\n").unwrap();
+    let mut config = fresh::config::Config::default();
+    config.editor.render_trailing_whitespace = true;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("foo bar··");
+    harness.assert_screen_not_contains("foo·bar");
+    // A whitespace-only line is all trailing whitespace
+    let (first_row, _) = harness.content_area_rows();
+    assert!(harness.get_screen_row(first_row + 1).contains("··"));
+}
+
+/// Test whitespace is not rendered by default and can be toggled from the
+/// command palette
+#[test]
+fn test_toggle_render_whitespace() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("spaces.txt");
+    std::fs::write(&file_path, "foo bar  \n").unwrap();
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("foo bar");
+    harness.assert_screen_not_contains("·");
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Toggle Render Whitespace").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert!(harness.config().editor.render_whitespace);
+    harness.assert_screen_contains("foo·bar··");
+}
+
+/// Test rulers are drawn at each configured column after the gutter, behind
+/// any text that crosses them
+#[test]
+fn test_rulers_at_configured_columns() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("rulers.txt");
+    std::fs::write(&file_path, format!("short\n{}\n", "x".repeat(30))).unwrap();
+    let mut config = fresh::config::Config::default();
+    config.editor.rulers = vec![10, 20];
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    let gutter_width = harness
+        .editor()
+        .active_viewport()
+        .gutter_width(&harness.editor().active_state().buffer) as u16;
+    let (first_row, _) = harness.content_area_rows();
+    let first_row = first_row as u16;
+    let ruler_fg = harness.editor().theme().ruler_fg;
+
+    for column in [10, 20] {
+        let x = gutter_width + column;
+        assert_eq!(harness.get_cell(x, first_row).as_deref(), Some("│"));
+        assert_eq!(
+            harness.get_cell_style(x, first_row).unwrap().fg,
+            Some(ruler_fg)
+        );
+        // The long line hides the ruler
+        assert_eq!(harness.get_cell(x, first_row + 1).as_deref(), Some("x"));
+    }
+    assert_eq!(
+        harness.get_cell(gutter_width + 15, first_row).as_deref(),
+        Some(" ")
+    );
+}
+
+/// Test rulers follow horizontal scrolling and disappear once scrolled past
+#[test]
+fn test_rulers_follow_horizontal_scroll() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("rulers.txt");
+    std::fs::write(&file_path, format!("{}\nb\n", "a".repeat(150))).unwrap();
+    let mut config = fresh::config::Config::default();
+    config.editor.line_wrap = false;
+    config.editor.rulers = vec![5, 120];
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+
+    let left_column = harness.editor().active_viewport().left_column as u16;
+    assert!(left_column > 5, "Expected to scroll past column 5");
+    let gutter_width = harness
+        .editor()
+        .active_viewport()
+        .gutter_width(&harness.editor().active_state().buffer) as u16;
+    let (first_row, _) = harness.content_area_rows();
+    let row = first_row as u16 + 1;
+
+    let x = gutter_width + 120 - left_column;
+    assert_eq!(harness.get_cell(x, row).as_deref(), Some("│"));
+    // The ruler at column 5 is scrolled out of view
+    for other in gutter_width..x {
+        assert_ne!(harness.get_cell(other, row).as_deref(), Some("│"));
+    }
+}
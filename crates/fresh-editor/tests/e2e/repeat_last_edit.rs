@@ -0,0 +1,82 @@
+//! Tests for Repeat Last Edit
+//!
+//! Tests that:
+//! - A run of typed characters is replayed at the new cursor position
+//! - Cursor movement between the edit and the repeat doesn't replace the stored edit
+//! - A later edit replaces the stored one
+//! - Repeating with nothing recorded reports it in the status bar
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+/// Helper to run a command from the command palette
+fn run_command(harness: &mut EditorTestHarness, command_name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.type_text(command_name).unwrap();
+    harness.render().unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Test typing text, moving, then repeating inserts the text again
+#[test]
+fn test_repeat_inserted_text_after_moving() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("one\ntwo").unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("ab").unwrap();
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    run_command(&mut harness, "Repeat Last Edit");
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "abone\ntwoab");
+}
+
+/// Test that deletions are repeated and a new edit replaces the stored one
+#[test]
+fn test_repeat_uses_most_recent_edit() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("abcdef").unwrap();
+    harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+
+    harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+    run_command(&mut harness, "Repeat Last Edit");
+
+    // Only the backspace is repeated, not the typing before it
+    assert_eq!(harness.get_buffer_content().unwrap(), "abdf");
+}
+
+/// Test that repeating can be done several times in a row
+#[test]
+fn test_repeat_twice() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("x").unwrap();
+
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    run_command(&mut harness, "Repeat Last Edit");
+    run_command(&mut harness, "Repeat Last Edit");
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "xxx");
+}
+
+/// Test repeating before any edit shows a status message
+#[test]
+fn test_repeat_without_edit() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    run_command(&mut harness, "Repeat Last Edit");
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "");
+    harness.assert_screen_contains("No edit to repeat");
+}
@@ -0,0 +1,102 @@
+//! Tests for reverting a buffer to its on-disk content
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use tempfile::TempDir;
+
+/// Harness with F6 bound to force_revert
+fn force_revert_harness() -> EditorTestHarness {
+    let mut config = fresh::config::Config::default();
+    config.keybindings.push(fresh::config::Keybinding {
+        key: "F6".to_string(),
+        modifiers: vec![],
+        keys: vec![],
+        action: "force_revert".to_string(),
+        args: Default::default(),
+        when: None,
+    });
+    EditorTestHarness::with_config(120, 24, config).unwrap()
+}
+
+/// Test that reverting a modified buffer asks first, then restores the file
+#[test]
+fn test_revert_discards_changes_after_confirmation() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "saved content\n").unwrap();
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.type_text("unsaved ").unwrap();
+    assert!(harness.editor().active_state().buffer.is_modified());
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Revert File").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("unsaved changes");
+
+    // Nothing is discarded until the prompt is confirmed
+    harness.assert_buffer_content("unsaved saved content\n");
+    harness.type_text("r").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    harness.assert_buffer_content("saved content\n");
+    assert!(!harness.editor().active_state().buffer.is_modified());
+
+    // The discarded edits can't be undone back into the buffer
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("saved content\n");
+}
+
+/// Test that force_revert reloads without asking and clamps the cursor
+#[test]
+fn test_force_revert_reloads_without_prompt() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "é").unwrap();
+
+    let mut harness = force_revert_harness();
+    harness.open_file(&file_path).unwrap();
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(" and a long unsaved tail").unwrap();
+
+    harness.send_key(KeyCode::F(6), KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+
+    harness.assert_buffer_content("é");
+    assert!(!harness.editor().active_state().buffer.is_modified());
+    assert_eq!(harness.cursor_position(), "é".len());
+    harness.assert_screen_contains("Reverted to saved file");
+}
+
+/// Test that a buffer without a file is left alone
+#[test]
+fn test_revert_refuses_buffer_without_file() {
+    let mut harness = force_revert_harness();
+    harness.type_text("scratch").unwrap();
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Revert File").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Buffer has no file to revert to");
+
+    harness.send_key(KeyCode::F(6), KeyModifiers::NONE).unwrap();
+    harness.assert_buffer_content("scratch");
+    assert!(harness.editor().active_state().buffer.is_modified());
+}
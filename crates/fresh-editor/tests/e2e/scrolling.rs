@@ -313,6 +313,110 @@ fn test_horizontal_scroll_with_arrows() {
     );
 }
 
+/// Harness without line wrapping and with the horizontal scroll actions bound
+fn horizontal_scroll_harness(line_wrap: bool) -> EditorTestHarness {
+    use fresh::config::Config;
+    let mut config = Config {
+        editor: fresh::config::EditorConfig {
+            line_wrap,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    for (key, action) in [("F7", "scroll_left"), ("F8", "scroll_right")] {
+        config.keybindings.push(fresh::config::Keybinding {
+            key: key.to_string(),
+            modifiers: vec![],
+            keys: vec![],
+            action: action.to_string(),
+            args: Default::default(),
+            when: None,
+        });
+    }
+    EditorTestHarness::with_config(80, 24, config).unwrap()
+}
+
+/// Test that the scroll left/right actions shift the view without moving the cursor
+#[test]
+fn test_horizontal_scroll_actions() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let mut harness = horizontal_scroll_harness(false);
+    let text = format!("0123456789{}END", "x".repeat(107));
+    let _fixture = harness.load_buffer_from_text(&text).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.cursor_position(), 0);
+
+    for _ in 0..5 {
+        harness.send_key(KeyCode::F(8), KeyModifiers::NONE).unwrap();
+    }
+    assert_eq!(harness.editor().active_viewport().left_column, 5);
+    assert_eq!(harness.cursor_position(), 0);
+    harness.assert_screen_contains("56789xxx");
+    harness.assert_screen_not_contains("01234");
+
+    harness.send_key(KeyCode::F(7), KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.editor().active_viewport().left_column, 4);
+
+    // Scrolling right stops once the end of the line is in view
+    for _ in 0..200 {
+        harness.send_key(KeyCode::F(8), KeyModifiers::NONE).unwrap();
+    }
+    let max_left = harness.editor().active_viewport().left_column;
+    assert!(max_left > 40 && max_left < 120, "left_column = {max_left}");
+    harness.assert_screen_contains("xxxEND");
+
+    // Moving the cursor brings it back into view
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    assert_eq!(harness.editor().active_viewport().left_column, 0);
+}
+
+/// Test that horizontal mouse wheel events scroll the view
+#[test]
+fn test_horizontal_mouse_wheel_scroll() {
+    use crossterm::event::{KeyModifiers, MouseEvent, MouseEventKind};
+
+    let mut harness = horizontal_scroll_harness(false);
+    let _fixture = harness.load_buffer_from_text(&"x".repeat(200)).unwrap();
+    harness.render().unwrap();
+
+    let wheel = |kind| MouseEvent {
+        kind,
+        column: 20,
+        row: 5,
+        modifiers: KeyModifiers::empty(),
+    };
+    harness
+        .send_mouse(wheel(MouseEventKind::ScrollRight))
+        .unwrap();
+    harness
+        .send_mouse(wheel(MouseEventKind::ScrollRight))
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.editor().active_viewport().left_column, 6);
+
+    harness
+        .send_mouse(wheel(MouseEventKind::ScrollLeft))
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.editor().active_viewport().left_column, 3);
+}
+
+/// Test that horizontal scrolling does nothing when lines wrap
+#[test]
+fn test_horizontal_scroll_ignored_with_line_wrap() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let mut harness = horizontal_scroll_harness(true);
+    let _fixture = harness.load_buffer_from_text(&"y".repeat(200)).unwrap();
+    harness.render().unwrap();
+
+    harness.send_key(KeyCode::F(8), KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.editor().active_viewport().left_column, 0);
+}
+
 /// Test cursor wrapping behavior when navigating horizontally on long lines
 /// This test verifies that when line wrap is disabled and a line extends beyond
 /// the viewport width, pressing right arrow at the end of the line moves directly
@@ -2514,3 +2618,205 @@ fn test_enter_resets_horizontal_scroll() {
 
     println!("\n✓ Enter correctly reset horizontal scroll");
 }
+
+/// Test that with smooth scrolling enabled, PageDown moves the viewport toward
+/// its target over several frames instead of snapping there
+#[test]
+fn test_smooth_scroll_animates_page_down() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use std::time::Duration;
+
+    let mut config = fresh::config::Config::default();
+    config.editor.smooth_scroll = true;
+    config.editor.smooth_scroll_duration_ms = 200;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    let content: String = (1..=200).map(|i| format!("line {i}\n")).collect();
+    let _fixture = harness.load_buffer_from_text(&content).unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(harness.top_line_number(), 0);
+
+    // Two pages down is well past the smooth scroll threshold
+    harness
+        .send_key(KeyCode::PageDown, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::PageDown, KeyModifiers::NONE)
+        .unwrap();
+    assert!(harness.editor().is_scroll_animating());
+    let mut previous = harness.top_line_number();
+
+    let mut frames = 0;
+    while harness.editor().is_scroll_animating() {
+        harness.advance_time(Duration::from_millis(40));
+        harness.render().unwrap();
+        let current = harness.top_line_number();
+        assert!(current >= previous, "Viewport should only move forward");
+        previous = current;
+        frames += 1;
+        assert!(frames < 20, "Animation should finish");
+    }
+    assert!(
+        frames > 2,
+        "Viewport should animate over several frames, took {frames}"
+    );
+
+    // The view lands where an instant scroll would have put it
+    let cursor_line = harness
+        .editor()
+        .active_state()
+        .buffer
+        .get_line_number(harness.cursor_position());
+    let final_top = harness.top_line_number();
+    assert!(final_top > 20);
+    assert!(cursor_line >= final_top && cursor_line < final_top + 22);
+}
+
+/// Test that without smooth scrolling PageDown moves the viewport in one frame
+#[test]
+fn test_page_down_snaps_without_smooth_scroll() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let content: String = (1..=200).map(|i| format!("line {i}\n")).collect();
+    let _fixture = harness.load_buffer_from_text(&content).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::PageDown, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::PageDown, KeyModifiers::NONE)
+        .unwrap();
+    assert!(!harness.editor().is_scroll_animating());
+    assert!(harness.editor().active_viewport().top_byte > 0);
+}
+
+/// Tall harness with 300 lines and the half-page actions bound to F7-F9 and F11
+fn half_page_harness() -> (EditorTestHarness, TestFixture) {
+    let mut config = fresh::config::Config::default();
+    for (key, action) in [
+        ("F7", "move_half_page_up"),
+        ("F8", "move_half_page_down"),
+        ("F9", "scroll_half_page_up"),
+        ("F11", "scroll_half_page_down"),
+    ] {
+        config.keybindings.push(fresh::config::Keybinding {
+            key: key.to_string(),
+            modifiers: vec![],
+            keys: vec![],
+            action: action.to_string(),
+            args: Default::default(),
+            when: None,
+        });
+    }
+    let mut harness = EditorTestHarness::with_config(80, 60, config).unwrap();
+    let content: String = (0..300).map(|i| format!("line {i}\n")).collect();
+    let fixture = harness.load_buffer_from_text(&content).unwrap();
+    harness.render().unwrap();
+    (harness, fixture)
+}
+
+/// Test that half-page moves step the cursor by half the viewport in a tall split
+#[test]
+fn test_half_page_move_lands_on_half_viewport_line() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let (mut harness, _fixture) = half_page_harness();
+    let half_page = harness.viewport_height() / 2;
+    assert!(half_page > 20, "viewport should be tall");
+    let cursor_line = |harness: &EditorTestHarness| {
+        harness
+            .editor()
+            .active_state()
+            .buffer
+            .get_line_number(harness.cursor_position())
+    };
+
+    harness.send_key(KeyCode::F(8), KeyModifiers::NONE).unwrap();
+    assert_eq!(cursor_line(&harness), half_page);
+    assert_eq!(harness.top_line_number(), 0);
+
+    harness.send_key(KeyCode::F(8), KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::F(8), KeyModifiers::NONE).unwrap();
+    assert_eq!(cursor_line(&harness), 3 * half_page);
+    let top = harness.top_line_number();
+    assert!(top > 0 && top <= 3 * half_page);
+
+    harness.send_key(KeyCode::F(7), KeyModifiers::NONE).unwrap();
+    assert_eq!(cursor_line(&harness), 2 * half_page);
+}
+
+/// Test that half-page scrolls move the view without moving the cursor
+#[test]
+fn test_half_page_scroll_keeps_cursor() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let (mut harness, _fixture) = half_page_harness();
+    let half_page = harness.viewport_height() / 2;
+
+    harness
+        .send_key(KeyCode::F(11), KeyModifiers::NONE)
+        .unwrap();
+    assert_eq!(harness.top_line_number(), half_page);
+    assert_eq!(harness.cursor_position(), 0);
+
+    harness.send_key(KeyCode::F(9), KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.top_line_number(), 0);
+    assert_eq!(harness.cursor_position(), 0);
+}
+
+/// Test that scrolling the cursor line to the top or bottom keeps the scroll margin
+/// and survives a following cursor move
+#[test]
+fn test_scroll_cursor_line_to_top_and_bottom() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let mut config = fresh::config::Config::default();
+    for (key, action) in [
+        ("F7", "scroll_cursor_to_top"),
+        ("F8", "scroll_cursor_to_bottom"),
+    ] {
+        config.keybindings.push(fresh::config::Keybinding {
+            key: key.to_string(),
+            modifiers: vec![],
+            keys: vec![],
+            action: action.to_string(),
+            args: Default::default(),
+            when: None,
+        });
+    }
+    let mut harness = EditorTestHarness::with_config(80, 40, config).unwrap();
+    let content: String = (0..300).map(|i| format!("line {i}\n")).collect();
+    let _fixture = harness.load_buffer_from_text(&content).unwrap();
+    harness.render().unwrap();
+
+    let visible = harness.viewport_height();
+    let margin = 3;
+    harness
+        .send_key(KeyCode::Char('g'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("101").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.send_key(KeyCode::F(7), KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.top_line_number(), 100 - margin);
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.top_line_number(), 100 - margin);
+
+    harness.send_key(KeyCode::F(8), KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.top_line_number(), 101 + margin + 1 - visible);
+    harness.send_key(KeyCode::Up, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.top_line_number(), 101 + margin + 1 - visible);
+
+    // At the end of the document the view can't scroll past the last line
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    let bottom_top = harness.top_line_number();
+    harness.send_key(KeyCode::F(7), KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.top_line_number(), bottom_top);
+}
@@ -1247,17 +1247,12 @@ fn test_toggle_case_sensitive_in_search() {
         .unwrap();
     harness.render().unwrap();
 
-    // Type search query (case-sensitive by default)
+    // Type search query (smart case by default)
     harness.type_text("hello").unwrap();
     harness.render().unwrap();
 
-    // By default, case-sensitive is ON, so search for "hello" should match only "hello" (lowercase)
-    // Verify the [x] checkbox is shown for case sensitive
-    let screen = harness.screen_to_string();
-    assert!(
-        screen.contains("[x] Case Sensitive") || screen.contains("[x]"),
-        "Case Sensitive should be checked by default"
-    );
+    // By default, case-sensitive is OFF and smart case decides from the query
+    harness.assert_screen_contains("[ ] Case Sensitive (Smart)");
 
     // Toggle case sensitivity with Alt+C
     harness
@@ -1265,12 +1260,9 @@ fn test_toggle_case_sensitive_in_search() {
         .unwrap();
     harness.render().unwrap();
 
-    // Verify checkbox is now unchecked
-    let screen_after_toggle = harness.screen_to_string();
-    assert!(
-        screen_after_toggle.contains("[ ] Case Sensitive") || screen_after_toggle.contains("[ ]"),
-        "Case Sensitive should be unchecked after Alt+C"
-    );
+    // Verify checkbox is now checked and smart case no longer applies
+    harness.assert_screen_contains("[x] Case Sensitive");
+    harness.assert_screen_not_contains("(Smart)");
 
     // Cancel search
     harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
@@ -2162,3 +2154,574 @@ fn test_f3_continues_searching_after_buffer_modification() {
         cursor_second
     );
 }
+
+/// Test that the status bar shows "Match N of M" while stepping through search results
+#[test]
+fn test_search_match_count_in_status_bar() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "foo one\nbar\nfoo two\nfoo three\n").unwrap();
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('f'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("foo").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.process_async_and_render().unwrap();
+
+    let status_row = |harness: &EditorTestHarness| harness.get_screen_row(22);
+    assert!(
+        status_row(&harness).contains("Ln 1, Col 1 | Match 1 of 3"),
+        "Status bar should show the first match: {}",
+        status_row(&harness)
+    );
+
+    for expected in ["Match 2 of 3", "Match 3 of 3", "Match 1 of 3"] {
+        harness.send_key(KeyCode::F(3), KeyModifiers::NONE).unwrap();
+        assert!(
+            status_row(&harness).contains(expected),
+            "Expected '{}' after find next: {}",
+            expected,
+            status_row(&harness)
+        );
+    }
+
+    harness
+        .send_key(KeyCode::F(3), KeyModifiers::SHIFT)
+        .unwrap();
+    assert!(
+        status_row(&harness).contains("Match 3 of 3"),
+        "Find previous should wrap to the last match: {}",
+        status_row(&harness)
+    );
+
+    // Cancelling a new search ends the search and clears the indicator
+    harness
+        .send_key(KeyCode::Char('f'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    assert!(
+        !status_row(&harness).contains("Match"),
+        "Indicator should be cleared when the search ends: {}",
+        status_row(&harness)
+    );
+}
+
+/// Test that the search match indicator can be turned off
+#[test]
+fn test_search_match_count_disabled() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "foo one\nbar\nfoo two\n").unwrap();
+
+    let mut config = Config::default();
+    config.editor.show_search_match_count = false;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('f'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("foo").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.process_async_and_render().unwrap();
+    assert!(
+        !harness.get_screen_row(22).contains("Match 1 of 2"),
+        "No persistent indicator when disabled: {}",
+        harness.get_screen_row(22)
+    );
+
+    // Stepping still reports the position as a status message
+    harness.send_key(KeyCode::F(3), KeyModifiers::NONE).unwrap();
+    harness.assert_screen_contains("Match 2 of 2");
+}
+
+/// Test that Escape and the Clear Search Highlight command remove match highlighting
+#[test]
+fn test_clear_search_highlight() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "foo one\nbar\nfoo two\n").unwrap();
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+    let search_bg = harness.editor().theme().search_match_bg;
+
+    let search = |harness: &mut EditorTestHarness| {
+        harness
+            .send_key(KeyCode::Char('f'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.type_text("foo").unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+        harness.process_async_and_render().unwrap();
+    };
+    // Whether any of the "foo" cells on screen still carry the search highlight
+    let highlighted = |harness: &EditorTestHarness| {
+        [2u16, 4].iter().any(|&y| {
+            let x = harness.get_screen_row(y as usize).find("foo").unwrap() as u16;
+            (x..x + 3).any(|x| harness.get_cell_style(x, y).and_then(|s| s.bg) == Some(search_bg))
+        })
+    };
+
+    search(&mut harness);
+    harness.send_key(KeyCode::F(3), KeyModifiers::NONE).unwrap();
+    let cursor = harness.cursor_position();
+    assert_eq!(cursor, "foo one\nbar\n".len());
+    assert!(highlighted(&harness), "Matches should be highlighted");
+
+    // Escape in the editor clears the highlights and ends the search
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    assert!(!highlighted(&harness), "Escape should clear highlights");
+    assert_eq!(harness.cursor_position(), cursor, "Cursor should not move");
+    harness.send_key(KeyCode::F(3), KeyModifiers::NONE).unwrap();
+    harness.assert_screen_contains("No active");
+
+    // The command does the same
+    search(&mut harness);
+    assert!(highlighted(&harness), "New search should highlight again");
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Clear Search Highlight").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    assert!(!highlighted(&harness), "Command should clear highlights");
+    harness.assert_screen_contains("Search highlig");
+}
+
+/// Test that center_on_jump centers the cursor line on every search jump,
+/// including matches that are already visible
+#[test]
+fn test_search_centers_match_when_center_on_jump_enabled() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    let content: String = (0..200)
+        .map(|i| {
+            if i == 100 || i == 105 {
+                format!("needle {}\n", i)
+            } else {
+                format!("line {}\n", i)
+            }
+        })
+        .collect();
+    std::fs::write(&file_path, content).unwrap();
+
+    let mut config = Config::default();
+    config.editor.center_on_jump = true;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    let assert_cursor_centered = |harness: &mut EditorTestHarness| {
+        let cursor_line = harness
+            .editor()
+            .active_state()
+            .buffer
+            .get_line_number(harness.cursor_position());
+        let rows_from_top = cursor_line - harness.top_line_number();
+        let center = harness.viewport_height() / 2;
+        assert!(
+            rows_from_top.abs_diff(center) <= 1,
+            "Line {} should be centered: {} rows from top, expected about {}",
+            cursor_line,
+            rows_from_top,
+            center
+        );
+    };
+
+    harness
+        .send_key(KeyCode::Char('f'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("needle").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.process_async_and_render().unwrap();
+    assert_cursor_centered(&mut harness);
+
+    // The next match is already on screen but still gets centered
+    harness.send_key(KeyCode::F(3), KeyModifiers::NONE).unwrap();
+    harness.process_async_and_render().unwrap();
+    assert_eq!(
+        harness
+            .editor()
+            .active_state()
+            .buffer
+            .get_line_number(harness.cursor_position()),
+        105
+    );
+    assert_cursor_centered(&mut harness);
+}
+
+/// Search for `query` with the search options toggled by `option_keys` (Alt+key)
+fn search_with_options(harness: &mut EditorTestHarness, query: &str, option_keys: &[char]) {
+    harness
+        .send_key(KeyCode::Char('f'), KeyModifiers::CONTROL)
+        .unwrap();
+    for &key in option_keys {
+        harness
+            .send_key(KeyCode::Char(key), KeyModifiers::ALT)
+            .unwrap();
+    }
+    harness.type_text(query).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+}
+
+/// Run Replace All from the command palette with `replacement`
+fn replace_all(harness: &mut EditorTestHarness, replacement: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Replace All").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text(replacement).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Replace All replaces every match of the current search, undone in one step
+#[test]
+fn test_replace_all_uses_current_search() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness
+        .load_buffer_from_text("foo bar foo\nbaz foo\n")
+        .unwrap();
+
+    search_with_options(&mut harness, "foo", &[]);
+    replace_all(&mut harness, "quux");
+
+    harness.assert_buffer_content("quux bar quux\nbaz quux\n");
+    harness.assert_screen_contains("Replaced 3 occurrence");
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("foo bar foo\nbaz foo\n");
+}
+
+/// Replace All asks for the search term when there is no current search
+#[test]
+fn test_replace_all_without_search_prompts_for_term() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness.load_buffer_from_text("one two one").unwrap();
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Replace All").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("one").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("1").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    harness.assert_buffer_content("1 two 1");
+}
+
+/// Replace All honours the case, whole-word and regex search options
+#[test]
+fn test_replace_all_respects_search_options() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness.load_buffer_from_text("Foo foo FOO food").unwrap();
+
+    // Case-sensitive, whole word
+    search_with_options(&mut harness, "foo", &['c', 'w']);
+    replace_all(&mut harness, "x");
+    harness.assert_buffer_content("Foo x FOO food");
+
+    // Options stay set, so toggle whole word back off along with enabling
+    // regex, then use a capture group in the replacement
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    search_with_options(&mut harness, r"(f)(o+)", &['w', 'r']);
+    replace_all(&mut harness, "$2$1");
+    harness.assert_buffer_content("Foo oof FOO oofd");
+}
+
+/// Replace All edits the matched bytes even after invalid UTF-8 in the file
+#[test]
+fn test_replace_all_after_invalid_utf8() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("invalid.txt");
+    std::fs::write(&file_path, b"\xff\xfe foo \xe2\x82 (foo)\n").unwrap();
+
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    search_with_options(&mut harness, r"(f)oo", &['r']);
+    replace_all(&mut harness, "${1}un");
+
+    let buffer = &mut harness.editor_mut().active_state_mut().buffer;
+    let len = buffer.len();
+    assert_eq!(
+        buffer.get_text_range_mut(0, len).unwrap(),
+        b"\xff\xfe fun \xe2\x82 (fun)\n"
+    );
+}
+
+/// Regex search matches a pattern and F3 cycles through the matches
+#[test]
+fn test_regex_search_cycles_matches() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness.load_buffer_from_text("id1 x id22 y id333").unwrap();
+
+    search_with_options(&mut harness, r"id\d+", &['r']);
+    assert_eq!(harness.cursor_position(), 0);
+
+    for expected in [6, 13, 0] {
+        harness.send_key(KeyCode::F(3), KeyModifiers::NONE).unwrap();
+        assert_eq!(harness.cursor_position(), expected);
+    }
+}
+
+/// An invalid regex is reported in the status line instead of searching
+#[test]
+fn test_invalid_regex_search_reports_error() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness.load_buffer_from_text("a(b").unwrap();
+
+    search_with_options(&mut harness, "a(", &['r']);
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("Invalid regex");
+    assert_eq!(harness.cursor_position(), 0);
+}
+
+/// Query replace in regex mode expands capture references in the replacement
+#[test]
+fn test_query_replace_with_backreference() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness.load_buffer_from_text("a=1\nbb=22\nc=3\n").unwrap();
+
+    harness
+        .send_key(
+            KeyCode::Char('r'),
+            KeyModifiers::CONTROL | KeyModifiers::ALT,
+        )
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('r'), KeyModifiers::ALT)
+        .unwrap();
+    harness.type_text(r"(\w+)=(\d+)").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("$2=$1").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    // Replace the first two matches one at a time, then skip the last
+    harness.type_text("y").unwrap();
+    harness.assert_buffer_content("1=a\nbb=22\nc=3\n");
+    harness.type_text("y").unwrap();
+    harness.assert_buffer_content("1=a\n22=bb\nc=3\n");
+    harness.type_text("n").unwrap();
+    harness.assert_buffer_content("1=a\n22=bb\nc=3\n");
+}
+
+/// Replacing the remaining matches at once also expands capture references
+#[test]
+fn test_query_replace_all_with_backreference() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness.load_buffer_from_text("a=1\nbb=22\nc=3\n").unwrap();
+
+    harness
+        .send_key(
+            KeyCode::Char('r'),
+            KeyModifiers::CONTROL | KeyModifiers::ALT,
+        )
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('r'), KeyModifiers::ALT)
+        .unwrap();
+    harness.type_text(r"(\w+)=(\d+)").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("$2=$1").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    harness.type_text("n").unwrap();
+    harness.type_text("a").unwrap();
+    harness.assert_buffer_content("a=1\n22=bb\n3=c\n");
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("a=1\nbb=22\nc=3\n");
+}
+
+/// Smart case: a lowercase query ignores case, while any uppercase letter
+/// makes the search case-sensitive
+#[test]
+fn test_search_smart_case() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness.load_buffer_from_text("hello Hello HELLO").unwrap();
+
+    search_with_options(&mut harness, "hello", &[]);
+    assert_eq!(harness.cursor_position(), 0);
+    for expected in [6, 12, 0] {
+        harness.send_key(KeyCode::F(3), KeyModifiers::NONE).unwrap();
+        assert_eq!(harness.cursor_position(), expected);
+    }
+
+    search_with_options(&mut harness, "Hello", &[]);
+    assert_eq!(harness.cursor_position(), 6);
+    harness.send_key(KeyCode::F(3), KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.cursor_position(), 6, "Only 'Hello' should match");
+}
+
+/// Smart case can be turned off to ignore case for any query
+#[test]
+fn test_search_smart_case_disabled() {
+    let mut config = Config::default();
+    config.editor.search_smart_case = false;
+    let mut harness = EditorTestHarness::with_config(100, 24, config).unwrap();
+    harness.load_buffer_from_text("hello Hello HELLO").unwrap();
+
+    search_with_options(&mut harness, "HELLO", &[]);
+    assert_eq!(harness.cursor_position(), 0);
+    harness.send_key(KeyCode::F(3), KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.cursor_position(), 6);
+}
+
+/// Whole word matching skips hits inside longer words
+#[test]
+fn test_search_whole_word_excludes_substrings() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness
+        .load_buffer_from_text("cat concat cat_x cats cat")
+        .unwrap();
+
+    search_with_options(&mut harness, "cat", &['w']);
+    assert_eq!(harness.cursor_position(), 0);
+    harness.send_key(KeyCode::F(3), KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.cursor_position(), 22);
+    harness.send_key(KeyCode::F(3), KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.cursor_position(), 0);
+}
+
+/// Every match on screen is highlighted, with the current match styled distinctly
+#[test]
+fn test_search_highlights_current_match_distinctly() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness
+        .load_buffer_from_text("foo one\nbar foo\nfoo end\n")
+        .unwrap();
+    let match_bg = harness.editor().theme().search_match_bg;
+    let current_bg = harness.editor().theme().search_current_match_bg;
+    assert_ne!(match_bg, current_bg);
+
+    // Background of the middle cell of the "foo" on each of the first three lines
+    let match_backgrounds = |harness: &EditorTestHarness| {
+        let (first_row, _) = harness.content_area_rows();
+        (first_row..first_row + 3)
+            .map(|y| {
+                let row = harness.get_screen_row(y);
+                let x = row[..row.find("foo").unwrap()].chars().count() as u16;
+                harness.get_cell_style(x + 1, y as u16).and_then(|s| s.bg)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    search_with_options(&mut harness, "foo", &[]);
+    harness.render().unwrap();
+    assert_eq!(
+        match_backgrounds(&harness),
+        vec![Some(current_bg), Some(match_bg), Some(match_bg)]
+    );
+
+    harness.send_key(KeyCode::F(3), KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert_eq!(
+        match_backgrounds(&harness),
+        vec![Some(match_bg), Some(current_bg), Some(match_bg)]
+    );
+
+    // Ending the search clears every highlight
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    for bg in match_backgrounds(&harness) {
+        assert!(bg != Some(match_bg) && bg != Some(current_bg));
+    }
+}
+
+/// Test that project search lists matches from every file and opens the selected one
+#[test]
+fn test_project_search_lists_matches_and_opens_selection() {
+    let mut harness = EditorTestHarness::with_temp_project(120, 30).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    std::fs::write(project_dir.join("a.txt"), "alpha\nneedle one\n").unwrap();
+    std::fs::create_dir(project_dir.join("sub")).unwrap();
+    std::fs::write(
+        project_dir.join("sub/b.txt"),
+        "beta\ngamma\n  x needle two\n",
+    )
+    .unwrap();
+    std::fs::write(project_dir.join(".gitignore"), "skipped.txt\n").unwrap();
+    std::fs::write(project_dir.join("skipped.txt"), "needle ignored\n").unwrap();
+
+    harness
+        .send_key(
+            KeyCode::Char('f'),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+        )
+        .unwrap();
+    harness.type_text("needle").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_until(|h| h.get_status_bar().contains("Found 2 match"))
+        .unwrap();
+
+    harness.assert_screen_contains("a.txt:2: needle one");
+    harness.assert_screen_contains("sub/b.txt:3: x needle two");
+    harness.assert_screen_not_contains("needle ignored");
+    assert_eq!(harness.editor().quickfix_list().len(), 2);
+
+    // Select the second result and open it
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "beta\ngamma\n  x needle two\n"
+    );
+    // Cursor sits on the match: line 3, column 5
+    assert_eq!(harness.cursor_position(), "beta\ngamma\n  x ".len());
+    harness.assert_screen_not_contains("a.txt:2: needle one");
+}
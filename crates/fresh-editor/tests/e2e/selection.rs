@@ -294,6 +294,65 @@ fn test_select_line_last() {
     );
 }
 
+/// Test that repeating select line extends the selection line by line
+#[test]
+fn test_select_line_repeat_extends() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    harness
+        .type_text("first line\nsecond line\nthird line")
+        .unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+
+    // First press selects the current line including its newline
+    harness
+        .send_key(KeyCode::Char('l'), KeyModifiers::CONTROL)
+        .unwrap();
+    let range = harness
+        .editor()
+        .active_state()
+        .cursors
+        .primary()
+        .selection_range()
+        .unwrap();
+    assert_eq!(range, 0..11, "Should select 'first line\\n'");
+
+    // Second press extends the selection to cover the next line too
+    harness
+        .send_key(KeyCode::Char('l'), KeyModifiers::CONTROL)
+        .unwrap();
+    let range = harness
+        .editor()
+        .active_state()
+        .cursors
+        .primary()
+        .selection_range()
+        .unwrap();
+    assert_eq!(range, 0..23, "Should select the first two lines");
+
+    // Extending past the last line stops at the end of the buffer
+    harness
+        .send_key(KeyCode::Char('l'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('l'), KeyModifiers::CONTROL)
+        .unwrap();
+    let range = harness
+        .editor()
+        .active_state()
+        .cursors
+        .primary()
+        .selection_range()
+        .unwrap();
+    assert_eq!(range, 0..33, "Should select the whole buffer");
+}
+
 /// Test select word with multiple cursors
 #[test]
 fn test_select_word_multi_cursor() {
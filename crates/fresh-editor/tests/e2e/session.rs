@@ -1286,3 +1286,131 @@ fn test_session_restores_file_explorer_hidden_and_gitignored_settings() {
         );
     }
 }
+
+/// Helper: Run a command from the command palette
+fn run_command(harness: &mut EditorTestHarness, command_name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.type_text(command_name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Test the Save Session and Restore Session commands with two buffers and cursors
+#[test]
+fn test_session_commands_save_and_restore_buffers_and_cursors() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+
+    let file1 = project_dir.join("first.txt");
+    let file2 = project_dir.join("second.txt");
+    std::fs::write(&file1, "one\ntwo\nthree\nfour").unwrap();
+    std::fs::write(&file2, "alpha\nbeta\ngamma").unwrap();
+
+    let first_cursor;
+    let second_cursor;
+
+    // First editor: one file per split, move both cursors and save via the command
+    {
+        let mut harness = EditorTestHarness::with_config_and_working_dir(
+            80,
+            24,
+            Config::default(),
+            project_dir.clone(),
+        )
+        .unwrap();
+
+        harness.open_file(&file1).unwrap();
+        harness
+            .send_key_repeat(KeyCode::Down, KeyModifiers::NONE, 2)
+            .unwrap();
+        first_cursor = harness.cursor_position();
+
+        split_vertical(&mut harness);
+        harness.open_file(&file2).unwrap();
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+        harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+        second_cursor = harness.cursor_position();
+
+        run_command(&mut harness, "Save Session");
+        harness.assert_screen_contains("Session saved");
+    }
+
+    // Second editor: restore via the command and compare
+    {
+        let mut harness = EditorTestHarness::with_config_and_working_dir(
+            80,
+            24,
+            Config::default(),
+            project_dir.clone(),
+        )
+        .unwrap();
+        harness.assert_buffer_content("");
+
+        run_command(&mut harness, "Restore Session");
+        harness.assert_screen_contains("Session restored");
+
+        // The split showing second.txt is active and keeps its cursor
+        harness.assert_buffer_content("alpha\nbeta\ngamma");
+        assert_eq!(harness.cursor_position(), second_cursor);
+
+        // The other split shows first.txt with its cursor
+        prev_split(&mut harness);
+        harness.assert_buffer_content("one\ntwo\nthree\nfour");
+        assert_eq!(harness.cursor_position(), first_cursor);
+    }
+}
+
+/// Test that the session commands report skipped scratch buffers and missing files
+#[test]
+fn test_session_commands_report_skipped_buffers_and_missing_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+
+    let kept = project_dir.join("kept.txt");
+    let removed = project_dir.join("removed.txt");
+    std::fs::write(&kept, "Kept content").unwrap();
+    std::fs::write(&removed, "Removed content").unwrap();
+
+    {
+        let mut harness = EditorTestHarness::with_config_and_working_dir(
+            120,
+            24,
+            Config::default(),
+            project_dir.clone(),
+        )
+        .unwrap();
+
+        harness.open_file(&removed).unwrap();
+        harness.open_file(&kept).unwrap();
+        harness.new_buffer().unwrap();
+        harness.type_text("scratch notes").unwrap();
+
+        run_command(&mut harness, "Save Session");
+        harness.assert_screen_contains("1 unsaved buffer(s) not included");
+    }
+
+    std::fs::remove_file(&removed).unwrap();
+
+    {
+        let mut harness = EditorTestHarness::with_config_and_working_dir(
+            120,
+            24,
+            Config::default(),
+            project_dir.clone(),
+        )
+        .unwrap();
+
+        run_command(&mut harness, "Restore Session");
+        harness.assert_screen_contains("1 missing file(s) skipped");
+
+        harness.open_file(&kept).unwrap();
+        harness.assert_buffer_content("Kept content");
+    }
+}
@@ -1087,6 +1087,15 @@ fn test_settings_descriptions_render_properly() {
     harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
     harness.render().unwrap();
 
+    // Move down until the "Whether to enable ..." settings are scrolled into view
+    for _ in 0..10 {
+        if harness.screen_to_string().contains("hether to enable") {
+            break;
+        }
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+        harness.render().unwrap();
+    }
+
     let screen = harness.screen_to_string();
 
     // Check that descriptions are NOT cut off mid-word at the start
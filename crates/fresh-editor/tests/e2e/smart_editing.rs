@@ -202,16 +202,17 @@ fn test_auto_close_before_whitespace() {
     );
 }
 
-/// Test auto-close is disabled when auto_indent config is false
+/// Test auto-close is disabled when auto_close config is false
 #[test]
 fn test_no_auto_close_when_config_disabled() {
     let temp_dir = TempDir::new().unwrap();
     let file_path = temp_dir.path().join("test.rs");
     std::fs::write(&file_path, "").unwrap();
 
-    // Create harness with auto_indent disabled
+    // Create harness with auto_close disabled but auto_indent still on
     let mut config = Config::default();
-    config.editor.auto_indent = false;
+    config.editor.auto_indent = true;
+    config.editor.auto_close = false;
     let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
     harness.open_file(&file_path).unwrap();
 
@@ -222,7 +223,155 @@ fn test_no_auto_close_when_config_disabled() {
     let content = harness.get_buffer_content().unwrap();
     assert_eq!(
         content, "(",
-        "Should NOT auto-close when auto_indent is disabled"
+        "Should NOT auto-close when auto_close is disabled"
+    );
+}
+
+/// Test that brackets and quotes are not auto-closed inside a comment
+#[test]
+fn test_no_auto_close_inside_comment() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.rs");
+    std::fs::write(&file_path, "// note ").unwrap();
+
+    let mut harness = harness_with_auto_indent();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    // Render between keys so highlighting covers each inserted character
+    for ch in ["(", "\""] {
+        harness.type_text(ch).unwrap();
+        harness.render().unwrap();
+    }
+
+    let content = harness.get_buffer_content().unwrap();
+    assert_eq!(
+        content, "// note (\"",
+        "Should NOT auto-close inside a comment"
+    );
+}
+
+/// Test that brackets are not auto-closed inside a string literal
+#[test]
+fn test_no_auto_close_inside_string() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.rs");
+    std::fs::write(&file_path, "let s = \"ab\";").unwrap();
+
+    let mut harness = harness_with_auto_indent();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    // Place the cursor between `a` and `b`
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    for _ in 0..3 {
+        harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+    }
+    harness.type_text("(").unwrap();
+    harness.render().unwrap();
+
+    let content = harness.get_buffer_content().unwrap();
+    assert_eq!(
+        content, "let s = \"a(b\";",
+        "Should NOT auto-close inside a string"
+    );
+}
+
+// =============================================================================
+// Tag Auto-Close Tests
+// =============================================================================
+
+/// Test that completing an opening tag inserts the matching closing tag
+#[test]
+fn test_auto_close_html_tag() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("index.html");
+    std::fs::write(&file_path, "").unwrap();
+
+    let mut harness = harness_with_auto_indent();
+    harness.open_file(&file_path).unwrap();
+
+    harness.type_text("<div>").unwrap();
+    harness.render().unwrap();
+
+    let content = harness.get_buffer_content().unwrap();
+    assert_eq!(
+        content, "<div></div>",
+        "Closing tag should be inserted after typing >"
+    );
+
+    // Cursor should be between the tags
+    let cursor_pos = harness.editor().active_state().cursors.primary().position;
+    assert_eq!(cursor_pos, 5, "Cursor should be between the tags");
+
+    // Typing continues inside the element
+    harness.type_text("hi").unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "<div>hi</div>");
+}
+
+/// Test that attributes, JSX and void elements are handled
+#[test]
+fn test_auto_close_tag_with_attributes_and_jsx() {
+    let temp_dir = TempDir::new().unwrap();
+    let html_path = temp_dir.path().join("page.html");
+    let jsx_path = temp_dir.path().join("App.jsx");
+    std::fs::write(&html_path, "").unwrap();
+    std::fs::write(&jsx_path, "").unwrap();
+
+    let mut harness = harness_with_auto_indent();
+    harness.open_file(&html_path).unwrap();
+
+    harness.type_text("<a href=\"x>y\">").unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "<a href=\"x>y\"></a>",
+        "A > inside a quoted attribute should not end the tag"
+    );
+
+    // Void elements are left alone
+    harness.type_text("<br>").unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "<a href=\"x>y\"><br></a>"
+    );
+
+    harness.open_file(&jsx_path).unwrap();
+    harness.type_text("<Button onClick={() => go()}>").unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "<Button onClick={() => go()}></Button>"
+    );
+}
+
+/// Test that closing tags are not inserted outside markup languages
+#[test]
+fn test_no_auto_close_tag_in_other_languages() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.rs");
+    std::fs::write(&file_path, "").unwrap();
+
+    let mut harness = harness_with_auto_indent();
+    harness.open_file(&file_path).unwrap();
+
+    harness.type_text("<div>").unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "<div>");
+}
+
+/// Test that a generic arrow in a plain .ts file does not get a closing tag
+#[test]
+fn test_no_auto_close_tag_for_ts_generic_arrow() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("util.ts");
+    std::fs::write(&file_path, "").unwrap();
+
+    let mut harness = harness_with_auto_indent();
+    harness.open_file(&file_path).unwrap();
+
+    harness.type_text("const f = <T>(x: T) => x;").unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "const f = <T>(x: T) => x;"
     );
 }
 
@@ -1113,6 +1262,70 @@ fn test_jump_through_multiple_errors() {
     );
 }
 
+/// Test next/previous error visit each diagnostic in order and show its message
+#[test]
+fn test_jump_to_errors_in_order() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.rs");
+    std::fs::write(&file_path, "line1\nline2\nline3\nline4\nline5").unwrap();
+
+    let mut harness = harness_with_auto_indent();
+    harness.open_file(&file_path).unwrap();
+
+    // Diagnostics are applied out of order on purpose
+    let diagnostics = vec![
+        create_diagnostic(4, 2, 4, 5, "Third"),
+        create_diagnostic(1, 0, 1, 5, "First"),
+        create_diagnostic(2, 3, 2, 5, "Second"),
+    ];
+    apply_test_diagnostics(&mut harness, diagnostics);
+
+    let expected = [(6, "First"), (15, "Second"), (26, "Third"), (6, "First")];
+    for (position, message) in expected {
+        harness.send_key(KeyCode::F(8), KeyModifiers::NONE).unwrap();
+        assert_eq!(harness.cursor_position(), position);
+        assert_eq!(
+            harness.editor().get_status_message().map(String::as_str),
+            Some(message)
+        );
+    }
+
+    // Backwards from the first diagnostic wraps to the last one
+    let expected = [(26, "Third"), (15, "Second"), (6, "First")];
+    for (position, message) in expected {
+        harness
+            .send_key(KeyCode::F(8), KeyModifiers::SHIFT)
+            .unwrap();
+        assert_eq!(harness.cursor_position(), position);
+        assert_eq!(
+            harness.editor().get_status_message().map(String::as_str),
+            Some(message)
+        );
+    }
+}
+
+/// Test jumping to an error records the position it jumped from
+#[test]
+fn test_jump_to_error_records_position_history() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.rs");
+    std::fs::write(&file_path, "line1\nline2\nline3").unwrap();
+
+    let mut harness = harness_with_auto_indent();
+    harness.open_file(&file_path).unwrap();
+    apply_test_diagnostics(&mut harness, vec![create_diagnostic(2, 0, 2, 5, "Error")]);
+
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    harness.send_key(KeyCode::F(8), KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.cursor_position(), 12);
+
+    // Navigate back returns to where the jump started
+    harness.send_key(KeyCode::Left, KeyModifiers::ALT).unwrap();
+    assert_eq!(harness.cursor_position(), 1);
+}
+
 // =============================================================================
 // Block/Rectangular Selection Tests
 // =============================================================================
@@ -1321,3 +1534,71 @@ fn test_block_selection_up() {
         .get_line_number(cursor.position);
     assert_eq!(cur_line, 1, "Cursor should be on line 1 after moving up");
 }
+
+// =============================================================================
+// Smart Backspace Tests
+// =============================================================================
+
+/// Helper to create a harness with smart backspace enabled (tab size 4, spaces)
+fn harness_with_smart_backspace() -> EditorTestHarness {
+    let mut config = Config::default();
+    config.editor.smart_backspace = true;
+    config.editor.tab_size = 4;
+    EditorTestHarness::with_config(80, 24, config).unwrap()
+}
+
+/// Test that backspace in leading whitespace removes a whole indent level
+#[test]
+fn test_smart_backspace_removes_indent_level() {
+    let mut harness = harness_with_smart_backspace();
+
+    harness.type_text("    ").unwrap();
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+
+    harness.assert_buffer_content("");
+    assert_eq!(harness.cursor_position(), 0);
+}
+
+/// Test that backspace goes back to the previous tab stop, not a full indent width
+#[test]
+fn test_smart_backspace_to_previous_tab_stop() {
+    let mut harness = harness_with_smart_backspace();
+
+    harness.type_text("      x").unwrap();
+    harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+
+    // Column 6 -> column 4
+    harness.assert_buffer_content("    x");
+    assert_eq!(harness.cursor_position(), 4);
+}
+
+/// Test that backspace after non-whitespace still deletes a single character
+#[test]
+fn test_smart_backspace_single_char_outside_indent() {
+    let mut harness = harness_with_smart_backspace();
+
+    harness.type_text("ab    ").unwrap();
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+
+    harness.assert_buffer_content("ab   ");
+}
+
+/// Test that backspace deletes a single space when smart backspace is disabled
+#[test]
+fn test_smart_backspace_disabled_by_default() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    harness.type_text("    ").unwrap();
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+
+    harness.assert_buffer_content("   ");
+}
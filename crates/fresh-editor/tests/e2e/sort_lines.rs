@@ -0,0 +1,102 @@
+//! Tests for Sort Lines and Unique Lines
+//!
+//! Tests that:
+//! - Selected lines are sorted as one undo step, leaving other lines alone
+//! - Duplicates are removed from the whole buffer when nothing is selected
+//! - The final newline survives the rewrite
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use tempfile::TempDir;
+
+/// Helper to run a command from the command palette
+fn run_command(harness: &mut EditorTestHarness, command_name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.type_text(command_name).unwrap();
+    harness.render().unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Open a file holding `content` in a 120x24 harness
+fn open_content(temp_dir: &TempDir, content: &str) -> EditorTestHarness {
+    let file_path = temp_dir.path().join("list.txt");
+    std::fs::write(&file_path, content).unwrap();
+
+    let mut harness = EditorTestHarness::new(120, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+    harness
+}
+
+/// Test sorting a shuffled selection leaves the line after it in place
+#[test]
+fn test_sort_selected_lines() {
+    let temp_dir = TempDir::new().unwrap();
+    let original = "pear\napple\nfig\nbanana\nzucchini\n";
+    let mut harness = open_content(&temp_dir, original);
+
+    for _ in 0..4 {
+        harness
+            .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+            .unwrap();
+    }
+    run_command(&mut harness, "Sort Lines");
+
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "apple\nbanana\nfig\npear\nzucchini\n"
+    );
+    harness.assert_screen_contains("Sorted 4 line(s)");
+
+    // A single undo restores the original order
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), original);
+}
+
+/// Test sorting in descending order across the whole buffer
+#[test]
+fn test_sort_lines_descending_whole_buffer() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut harness = open_content(&temp_dir, "b\nc\na\n");
+
+    run_command(&mut harness, "Sort Lines Descending");
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "c\nb\na\n");
+}
+
+/// Test de-duplicating a list with no selection keeps the first of each line
+#[test]
+fn test_unique_lines_whole_buffer() {
+    let temp_dir = TempDir::new().unwrap();
+    let original = "red\ngreen\nred\nblue\ngreen\n";
+    let mut harness = open_content(&temp_dir, original);
+
+    run_command(&mut harness, "Unique Lines");
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "red\ngreen\nblue\n");
+    harness.assert_screen_contains("Removed 2 duplicate line(s)");
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), original);
+}
+
+/// Test only neighbouring repeats are collapsed by Unique Adjacent Lines
+#[test]
+fn test_unique_adjacent_lines() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut harness = open_content(&temp_dir, "a\na\nb\na\na\n");
+
+    run_command(&mut harness, "Unique Adjacent Lines");
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "a\nb\na\n");
+}
@@ -197,6 +197,61 @@ fn test_buffer_cycling_within_split() {
     harness.assert_buffer_content("Content 2");
 }
 
+/// Test that switch_to_previous_tab toggles between the two most recent buffers
+#[test]
+fn test_switch_to_previous_tab_toggles_between_last_two() {
+    let temp_dir = TempDir::new().unwrap();
+    let file1 = temp_dir.path().join("file1.txt");
+    let file2 = temp_dir.path().join("file2.txt");
+    let file3 = temp_dir.path().join("file3.txt");
+    std::fs::write(&file1, "Content 1").unwrap();
+    std::fs::write(&file2, "Content 2").unwrap();
+    std::fs::write(&file3, "Content 3").unwrap();
+
+    let mut config = fresh::config::Config::default();
+    config.keybindings.push(fresh::config::Keybinding {
+        key: "F6".to_string(),
+        modifiers: vec![],
+        keys: vec![],
+        action: "switch_to_previous_tab".to_string(),
+        args: Default::default(),
+        when: None,
+    });
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+
+    // Activate file1, then file3, so file2 is not involved in the toggle
+    harness.open_file(&file2).unwrap();
+    harness.open_file(&file1).unwrap();
+    harness.open_file(&file3).unwrap();
+    harness.assert_buffer_content("Content 3");
+
+    harness.send_key(KeyCode::F(6), KeyModifiers::NONE).unwrap();
+    harness.assert_buffer_content("Content 1");
+
+    harness.send_key(KeyCode::F(6), KeyModifiers::NONE).unwrap();
+    harness.assert_buffer_content("Content 3");
+}
+
+/// Test that switch_to_previous_tab reports when there is no other buffer
+#[test]
+fn test_switch_to_previous_tab_with_single_buffer() {
+    let mut config = fresh::config::Config::default();
+    config.keybindings.push(fresh::config::Keybinding {
+        key: "F6".to_string(),
+        modifiers: vec![],
+        keys: vec![],
+        action: "switch_to_previous_tab".to_string(),
+        args: Default::default(),
+        when: None,
+    });
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.type_text("only").unwrap();
+
+    harness.send_key(KeyCode::F(6), KeyModifiers::NONE).unwrap();
+    harness.assert_buffer_content("only");
+    harness.assert_screen_contains("No previous tab");
+}
+
 /// Test that tab bar appears within each split area
 #[test]
 fn test_tab_bar_in_split_area() {
@@ -0,0 +1,111 @@
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::{Config, StatusBarSegment};
+
+fn harness_with_segments(
+    left: Vec<StatusBarSegment>,
+    right: Vec<StatusBarSegment>,
+) -> (EditorTestHarness, tempfile::TempDir) {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("notes.txt");
+    std::fs::write(&file_path, "hello\nworld\n").unwrap();
+
+    let mut config = Config::default();
+    config.status_bar.left = left;
+    config.status_bar.right = right;
+    let mut harness = EditorTestHarness::with_config(100, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+    (harness, temp_dir)
+}
+
+/// Test that the default layout shows the file, position, line ending and palette hint
+#[test]
+fn test_status_bar_default_segments() {
+    let (harness, _temp_dir) = harness_with_segments(
+        Config::default().status_bar.left,
+        Config::default().status_bar.right,
+    );
+
+    let status = harness.get_status_bar();
+    assert!(status.contains("notes.txt | Ln 1, Col 1"), "{status}");
+    assert!(status.contains(" LF "), "{status}");
+    assert!(status.contains("Palette:"), "{status}");
+}
+
+/// Test that segments render in the configured order and unlisted segments are hidden
+#[test]
+fn test_status_bar_configured_segment_order() {
+    let (harness, _temp_dir) = harness_with_segments(
+        vec![StatusBarSegment::Cursor, StatusBarSegment::Filename],
+        vec![StatusBarSegment::Palette, StatusBarSegment::LineEnding],
+    );
+
+    let status = harness.get_status_bar();
+    assert!(status.contains("Ln 1, Col 1 | "), "{status}");
+    let cursor = status.find("Ln 1, Col 1").unwrap();
+    let filename = status.find("notes.txt").expect(&status);
+    let palette = status.find("Palette:").expect(&status);
+    let line_ending = status.find(" LF ").expect(&status);
+    assert!(cursor < filename, "{status}");
+    assert!(filename < palette, "{status}");
+    assert!(palette < line_ending, "{status}");
+}
+
+/// Test that right-side segments can be moved to the left and others removed
+#[test]
+fn test_status_bar_segments_move_between_sides() {
+    let (harness, _temp_dir) = harness_with_segments(
+        vec![StatusBarSegment::Filename, StatusBarSegment::LineEnding],
+        vec![],
+    );
+
+    let status = harness.get_status_bar();
+    assert!(status.contains("notes.txt | LF"), "{status}");
+    assert!(!status.contains("Ln 1"), "{status}");
+    assert!(!status.contains("Palette:"), "{status}");
+}
+
+/// Test that the word count segment counts the whole buffer, then the selection
+#[test]
+fn test_status_bar_word_count_buffer_and_selection() {
+    let (mut harness, _temp_dir) = harness_with_segments(
+        vec![StatusBarSegment::Filename, StatusBarSegment::WordCount],
+        vec![],
+    );
+
+    harness.assert_screen_contains("notes.txt | 2 lines, 2 words, 12 chars");
+
+    // Select "hello\nwo"
+    harness
+        .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+        .unwrap();
+    harness
+        .send_key_repeat(KeyCode::Right, KeyModifiers::SHIFT, 2)
+        .unwrap();
+    harness.assert_screen_contains("Selected: 2 lines, 2 words, 8 chars");
+
+    // Counts follow edits once the selection is gone
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.type_text(" again").unwrap();
+    harness.assert_screen_contains("notes.txt [+] | 2 lines, 3 words, 18 chars");
+}
+
+/// Test that the Word Count command reports the counts without the segment
+#[test]
+fn test_word_count_command() {
+    let (mut harness, _temp_dir) = harness_with_segments(
+        Config::default().status_bar.left,
+        Config::default().status_bar.right,
+    );
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Word Count").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    harness.assert_screen_contains("2 lines, 2 words, 12 chars");
+}
@@ -262,3 +262,56 @@ fn test_tab_bar_toggle_with_multiple_buffers() {
     harness.assert_screen_contains("Tab bar shown");
     assert!(harness.editor().tab_bar_visible());
 }
+
+/// Test that the cursor details segment shows the byte offset, file size and line count
+#[test]
+fn test_toggle_cursor_details_segment() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("a.txt");
+    std::fs::write(&file_path, "abc\ndefg\n").unwrap();
+
+    let mut harness = EditorTestHarness::new(120, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key_repeat(KeyCode::Right, KeyModifiers::NONE, 2)
+        .unwrap();
+    harness.assert_screen_not_contains("Byte 6/9");
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Toggle Cursor Details").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    harness.assert_screen_contains("Ln 2, Col 3 | Byte 6/9, 3 lines");
+}
+
+/// Test that large files without a line count show the position as a percentage
+#[test]
+fn test_cursor_details_percentage_in_large_file_mode() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("large.txt");
+    let content: String = (0..50)
+        .map(|i| format!("This is line {i} with some content\n"))
+        .collect();
+    std::fs::write(&file_path, &content).unwrap();
+
+    let mut config = Config::default();
+    config.editor.large_file_threshold_bytes = 500;
+    config.editor.show_cursor_details = true;
+    let mut harness = EditorTestHarness::with_config(120, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness
+        .send_key_repeat(KeyCode::Down, KeyModifiers::NONE, 10)
+        .unwrap();
+
+    let position = harness.cursor_position();
+    let total = content.len();
+    harness.assert_screen_contains(&format!(
+        "Byte {position}/{total} ({}%)",
+        position * 100 / total
+    ));
+}
@@ -6,45 +6,45 @@ use crossterm::event::{KeyCode, KeyModifiers};
 /// This test demonstrates the expected behavior:
 /// 1. Type some text
 /// 2. Move cursor with arrow keys (readonly actions)
-/// 3. Undo once should undo the cursor movements AND the last typed character
+/// 3. Undo once should undo the cursor movements AND the last typed word
 #[test]
 fn test_undo_skips_readonly_movement_actions() {
     let mut harness = EditorTestHarness::new(80, 24).unwrap();
 
-    // Type "hello"
-    harness.type_text("hello").unwrap();
-    harness.assert_buffer_content("hello");
+    // Type "hi hello"
+    harness.type_text("hi hello").unwrap();
+    harness.assert_buffer_content("hi hello");
 
-    // Cursor should be at end (position 5)
+    // Cursor should be at end (position 8)
     assert_eq!(
         harness.editor().active_state().cursors.primary().position,
-        5
+        8
     );
 
     // Move cursor left twice with arrow keys (readonly movements)
     harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
     harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
 
-    // Now cursor should be between "hel" and "lo" (position 3)
+    // Now cursor should be between "hi hel" and "lo" (position 6)
     assert_eq!(
         harness.editor().active_state().cursors.primary().position,
-        3
+        6
     );
 
-    // Undo once - should undo the two cursor movements AND the last typed character 'o'
+    // Undo once - should undo the two cursor movements AND the last typed word
     harness
         .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
         .unwrap();
     harness.render().unwrap();
 
-    // Buffer should now be "hell" (last typed character removed)
-    harness.assert_buffer_content("hell");
+    // Buffer should now be "hi " (last typed word removed)
+    harness.assert_buffer_content("hi ");
 
-    // Cursor should be restored to where it was BEFORE the movements (position 4, end of "hell")
+    // Cursor should be restored to where it was BEFORE the word (position 3, end of "hi ")
     // This is the key difference: cursor movements should be undone too!
     assert_eq!(
         harness.editor().active_state().cursors.primary().position,
-        4,
+        3,
         "Cursor should be restored to position before movements"
     );
 }
@@ -54,9 +54,9 @@ fn test_undo_skips_readonly_movement_actions() {
 fn test_multiple_undo_skips_all_readonly_actions() {
     let mut harness = EditorTestHarness::new(80, 24).unwrap();
 
-    // Type "abc"
-    harness.type_text("abc").unwrap();
-    harness.assert_buffer_content("abc");
+    // Type three words
+    harness.type_text("a b c").unwrap();
+    harness.assert_buffer_content("a b c");
 
     // Do various readonly movements
     harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
@@ -70,15 +70,15 @@ fn test_multiple_undo_skips_all_readonly_actions() {
     harness
         .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
         .unwrap();
-    harness.assert_buffer_content("ab");
+    harness.assert_buffer_content("a b ");
 
-    // Undo again - should undo 'b'
+    // Undo again - should undo 'b '
     harness
         .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
         .unwrap();
-    harness.assert_buffer_content("a");
+    harness.assert_buffer_content("a ");
 
-    // Undo again - should undo 'a'
+    // Undo again - should undo 'a '
     harness
         .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
         .unwrap();
@@ -90,9 +90,9 @@ fn test_multiple_undo_skips_all_readonly_actions() {
 fn test_redo_skips_readonly_movement_actions() {
     let mut harness = EditorTestHarness::new(80, 24).unwrap();
 
-    // Type "xyz"
-    harness.type_text("xyz").unwrap();
-    harness.assert_buffer_content("xyz");
+    // Type "xy z"
+    harness.type_text("xy z").unwrap();
+    harness.assert_buffer_content("xy z");
 
     // Move cursor
     harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
@@ -101,13 +101,13 @@ fn test_redo_skips_readonly_movement_actions() {
     harness
         .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
         .unwrap();
-    harness.assert_buffer_content("xy");
+    harness.assert_buffer_content("xy ");
 
     // Redo - should skip the movement and redo 'z'
     harness
         .send_key(KeyCode::Char('y'), KeyModifiers::CONTROL)
         .unwrap();
-    harness.assert_buffer_content("xyz");
+    harness.assert_buffer_content("xy z");
 }
 
 /// Test undo/redo with mixed write and readonly actions
@@ -135,11 +135,11 @@ fn test_undo_redo_with_mixed_actions() {
         .unwrap();
     harness.assert_buffer_content("ab");
 
-    // Undo again should skip the Home movement and undo 'b'
+    // Undo again should skip the Home movement and undo "ab", typed as one run
     harness
         .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
         .unwrap();
-    harness.assert_buffer_content("a");
+    harness.assert_buffer_content("");
 }
 
 /// Test that undo to save point correctly marks buffer as not modified (issue #191)
@@ -503,3 +503,134 @@ fn test_undo_to_empty_after_save_as() {
         final_content
     );
 }
+
+/// Test that edits past the undo history limit drop the oldest history
+/// while recent undo keeps working, and that hitting the limit is reported
+#[test]
+fn test_undo_history_limit_drops_oldest_edits() {
+    let mut config = fresh::config::Config::default();
+    config.editor.undo_history_max_entries = 5;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+
+    // Five words of typing, undone one word at a time
+    harness.type_text("ab cd ef gh ij").unwrap();
+    harness.assert_buffer_content("ab cd ef gh ij");
+
+    // Undo everything that is still in the history
+    for _ in 0..10 {
+        harness
+            .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+            .unwrap();
+    }
+    harness.render().unwrap();
+
+    // Only the last five edits, "gh ij", could be undone
+    harness.assert_buffer_content("ab cd ef ");
+    harness.assert_screen_contains("Undo history limit");
+
+    // Redo still replays the kept edits
+    harness
+        .send_key(KeyCode::Char('y'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("ab cd ef gh ");
+}
+
+/// Test that a plugin applyEdits batch shifts later edits correctly and is
+/// reverted by a single undo
+#[test]
+fn test_plugin_apply_edits_undone_in_one_step() {
+    use fresh::services::plugins::api::{BufferEdit, PluginCommand};
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.type_text("one two three").unwrap();
+    harness.assert_buffer_content("one two three");
+
+    let buffer_id = harness.editor().active_buffer();
+    let edit = |start, end, text: &str| BufferEdit {
+        start,
+        end,
+        text: text.to_string(),
+    };
+    // Offsets refer to the original text; the edits are given out of order
+    harness
+        .editor_mut()
+        .handle_plugin_command(PluginCommand::ApplyEdits {
+            buffer_id,
+            edits: vec![edit(8, 13, "3"), edit(0, 3, "1"), edit(4, 7, "second")],
+        })
+        .unwrap();
+    harness.assert_buffer_content("1 second 3");
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("one two three");
+
+    // Overlapping edits are rejected without touching the buffer
+    harness
+        .editor_mut()
+        .handle_plugin_command(PluginCommand::ApplyEdits {
+            buffer_id,
+            edits: vec![edit(0, 5, "x"), edit(4, 7, "y")],
+        })
+        .unwrap();
+    harness.assert_buffer_content("one two three");
+}
+
+/// Test that a typed word is removed by a single undo, while a newline or a
+/// backspace run forms its own step
+#[test]
+fn test_typed_word_undone_in_one_step() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    harness.type_text("hello").unwrap();
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("");
+
+    harness
+        .send_key(KeyCode::Char('y'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("hello");
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("world").unwrap();
+    for _ in 0..3 {
+        harness
+            .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.assert_buffer_content("hello\nwo");
+
+    // The backspaces, then the word, then the newline
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("hello\nworld");
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("hello\n");
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("hello");
+}
+
+/// Test that a pause in typing starts a new undo step
+#[test]
+fn test_typing_pause_splits_undo_step() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    harness.type_text("abc").unwrap();
+    harness.sleep(std::time::Duration::from_secs(2));
+    harness.type_text("def").unwrap();
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("abc");
+}
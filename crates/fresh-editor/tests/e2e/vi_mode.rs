@@ -1153,3 +1153,27 @@ fn test_vi_colon_split() {
         })
         .unwrap();
 }
+
+/// Test Ctrl-^ (Ctrl-6) switches to the alternate buffer and back
+#[test]
+fn test_vi_alternate_buffer() {
+    let (mut harness, _temp_dir) = vi_mode_harness(80, 24);
+
+    let first = TestFixture::new("first.txt", "first\n").unwrap();
+    let second = TestFixture::new("second.txt", "second\n").unwrap();
+    harness.open_file(&first.path).unwrap();
+    harness.open_file(&second.path).unwrap();
+    harness.render().unwrap();
+
+    enable_vi_mode(&mut harness);
+
+    harness
+        .send_key(KeyCode::Char('6'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("first\n");
+
+    harness
+        .send_key(KeyCode::Char('6'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("second\n");
+}
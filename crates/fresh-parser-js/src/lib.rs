@@ -692,6 +692,54 @@ mod tests {
         assert!(result.contains("const id = 123"));
     }
 
+    #[test]
+    fn test_transpile_enum() {
+        let source = r#"
+            enum Direction {
+                Up,
+                Down,
+            }
+            const dir: Direction = Direction.Up;
+        "#;
+
+        let result = transpile_typescript(source, "test.ts").unwrap();
+        assert!(!result.contains("enum "));
+        assert!(result.contains("Direction[\"Up\"] = 0"));
+        assert!(result.contains("const dir = Direction.Up"));
+    }
+
+    #[test]
+    fn test_transpile_type_only_imports() {
+        let source = r#"
+            import type { EditorContext } from "./fresh";
+            import { type BufferInfo, helper } from "./lib";
+            const info: BufferInfo | null = helper();
+        "#;
+
+        let result = transpile_typescript(source, "test.ts").unwrap();
+        assert!(!result.contains("EditorContext"));
+        assert!(!result.contains("BufferInfo"));
+        assert!(!result.contains("import type"));
+        assert!(result.contains("import { helper } from \"./lib\""));
+        assert!(result.contains("const info = helper()"));
+    }
+
+    #[test]
+    fn test_transpile_annotated_export() {
+        let source = r#"
+            export function activate<T extends object>(ctx: EditorContext, opts?: T): void {}
+        "#;
+
+        let result = transpile_typescript(source, "test.ts").unwrap();
+        assert!(result.contains("export function activate(ctx, opts) {}"));
+    }
+
+    #[test]
+    fn test_transpile_reports_parse_errors() {
+        let err = transpile_typescript("const x: = 1;", "test.ts").unwrap_err();
+        assert!(err.to_string().starts_with("TypeScript parse errors:"));
+    }
+
     #[test]
     fn test_has_es_imports() {
         assert!(has_es_imports("import { foo } from './lib'"));
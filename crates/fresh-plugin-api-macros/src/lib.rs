@@ -452,6 +452,8 @@ fn rust_to_typescript(ty: &Type, attrs: &[Attribute]) -> String {
                 | "ViewTokenStyle"
                 | "LayoutHints"
                 | "FileExplorerDecoration"
+                | "QuickfixEntry"
+                | "StatusBarItemOptions"
                 | "TsCreateCompositeBufferOptions"
                 | "TsCompositeLayoutConfig"
                 | "TsCompositeSourceConfig"
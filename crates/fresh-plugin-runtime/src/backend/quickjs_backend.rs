@@ -5,8 +5,9 @@
 
 use anyhow::{anyhow, Result};
 use fresh_core::api::{
-    ActionSpec, BufferInfo, CompositeHunk, CreateCompositeBufferOptions, EditorStateSnapshot,
-    JsCallbackId, PluginCommand, PluginResponse,
+    plugin_storage_fits, ActionSpec, BufferEdit, BufferInfo, CompositeHunk,
+    CreateCompositeBufferOptions, EditorStateSnapshot, JsCallbackId, PluginCommand, PluginResponse,
+    QuickfixEntry, StatusBarItemOptions, TextObjectOperation, TextObjectQuery,
 };
 use fresh_core::command::Command;
 use fresh_core::overlay::OverlayNamespace;
@@ -392,6 +393,10 @@ pub struct JsEditorApi {
     #[qjs(skip_trace)]
     registered_actions: Rc<RefCell<HashMap<String, PluginHandler>>>,
     #[qjs(skip_trace)]
+    registered_text_objects: Rc<RefCell<HashMap<String, PluginHandler>>>,
+    #[qjs(skip_trace)]
+    registered_open_handlers: Rc<RefCell<HashMap<String, PluginHandler>>>,
+    #[qjs(skip_trace)]
     event_handlers: Rc<RefCell<HashMap<String, Vec<PluginHandler>>>>,
     #[qjs(skip_trace)]
     next_request_id: Rc<RefCell<u64>>,
@@ -461,6 +466,31 @@ impl JsEditorApi {
             .send(PluginCommand::SetStatus { message: msg });
     }
 
+    /// Add or update a status bar item, identified by `id`
+    ///
+    /// Options: `alignment` ("left" or "right") and `priority` (higher first)
+    pub fn set_status_bar_item(
+        &self,
+        id: String,
+        text: String,
+        options: rquickjs::function::Opt<StatusBarItemOptions>,
+    ) -> bool {
+        self.command_sender
+            .send(PluginCommand::SetStatusBarItem {
+                id,
+                text,
+                options: options.0.unwrap_or_default(),
+            })
+            .is_ok()
+    }
+
+    /// Remove a status bar item added with `setStatusBarItem`
+    pub fn remove_status_bar_item(&self, id: String) -> bool {
+        self.command_sender
+            .send(PluginCommand::RemoveStatusBarItem { id })
+            .is_ok()
+    }
+
     // === Clipboard ===
 
     pub fn copy_to_clipboard(&self, text: String) {
@@ -550,6 +580,76 @@ impl JsEditorApi {
             .is_ok()
     }
 
+    /// Register a text object - `handlerName` is a global function called with a
+    /// `TextObjectQuery` that returns a `TextObjectRange` (or a Promise of one), or null
+    /// when there is no match. Adds "Select"/"Delete" commands for the text object, and
+    /// expand-selection grows to the smallest text object around the selection
+    pub fn register_text_object(&self, name: String, handler_name: String) -> bool {
+        tracing::debug!(
+            "registerTextObject: plugin='{}', name='{}', handler='{}'",
+            self.plugin_name,
+            name,
+            handler_name
+        );
+
+        self.registered_text_objects.borrow_mut().insert(
+            name.clone(),
+            PluginHandler {
+                plugin_name: self.plugin_name.clone(),
+                handler_name,
+            },
+        );
+
+        self.command_sender
+            .send(PluginCommand::RegisterTextObject {
+                name,
+                plugin_name: self.plugin_name.clone(),
+            })
+            .is_ok()
+    }
+
+    /// Apply a range returned by a text object matcher (internal)
+    #[plugin_api(skip)]
+    #[qjs(rename = "_applyTextObject")]
+    pub fn apply_text_object(
+        &self,
+        buffer_id: u32,
+        start: u32,
+        end: u32,
+        operation: String,
+    ) -> bool {
+        let Ok(operation) =
+            serde_json::from_value::<TextObjectOperation>(serde_json::Value::String(operation))
+        else {
+            return false;
+        };
+        if start > end {
+            tracing::warn!(
+                "Ignoring text object range with start {} after end {}",
+                start,
+                end
+            );
+            return false;
+        }
+        self.command_sender
+            .send(PluginCommand::ApplyTextObject {
+                buffer_id: BufferId(buffer_id as usize),
+                range: start as usize..end as usize,
+                operation,
+            })
+            .is_ok()
+    }
+
+    /// Move the cursor to the next diagnostic in the active buffer (wraps around)
+    pub fn goto_next_diagnostic(&self) -> bool {
+        self.execute_action("jump_to_next_error".to_string())
+    }
+
+    /// Move the cursor to the previous diagnostic in the active buffer (wraps around)
+    pub fn goto_prev_diagnostic(&self) -> bool {
+        self.execute_action("jump_to_previous_error".to_string())
+    }
+
     // === Translation ===
 
     /// Translate a string - reads plugin name from __pluginName__ global
@@ -716,6 +816,18 @@ impl JsEditorApi {
         0
     }
 
+    /// Get line, column, byte offset and buffer size for the primary cursor
+    #[plugin_api(ts_return = "CursorDetails | null")]
+    pub fn get_cursor_details<'js>(&self, ctx: rquickjs::Ctx<'js>) -> rquickjs::Result<Value<'js>> {
+        let details = if let Ok(s) = self.state_snapshot.read() {
+            s.cursor_details.clone()
+        } else {
+            None
+        };
+        rquickjs_serde::to_value(ctx, &details)
+            .map_err(|e| rquickjs::Error::new_from_js_message("serialize", "", &e.to_string()))
+    }
+
     /// Get the byte offset of the start of a line (0-indexed line number)
     /// Returns null if the line number is out of range
     #[plugin_api(
@@ -802,6 +914,26 @@ impl JsEditorApi {
             .is_ok()
     }
 
+    /// Apply several `{start, end, text}` replacements as one undoable edit
+    ///
+    /// Offsets refer to the buffer before the batch; later edits are shifted
+    /// automatically. Returns false if any range is inverted or ranges overlap.
+    pub fn apply_edits(&self, buffer_id: u32, edits: Vec<BufferEdit>) -> bool {
+        let edits = match fresh_core::api::sort_buffer_edits(edits) {
+            Ok(edits) => edits,
+            Err(e) => {
+                tracing::warn!("applyEdits rejected: {}", e);
+                return false;
+            }
+        };
+        self.command_sender
+            .send(PluginCommand::ApplyEdits {
+                buffer_id: BufferId(buffer_id as usize),
+                edits,
+            })
+            .is_ok()
+    }
+
     /// Insert text at cursor position in active buffer
     pub fn insert_at_cursor(&self, text: String) -> bool {
         self.command_sender
@@ -834,6 +966,57 @@ impl JsEditorApi {
             .is_ok()
     }
 
+    /// Register an open handler - paths matching the `pattern` regex are not read
+    /// from disk; instead `handlerName` is a global function called with the path
+    /// that returns the buffer content (or a Promise of it)
+    pub fn register_open_handler(&self, pattern: String, handler_name: String) -> bool {
+        tracing::debug!(
+            "registerOpenHandler: plugin='{}', pattern='{}', handler='{}'",
+            self.plugin_name,
+            pattern,
+            handler_name
+        );
+
+        self.registered_open_handlers.borrow_mut().insert(
+            pattern.clone(),
+            PluginHandler {
+                plugin_name: self.plugin_name.clone(),
+                handler_name,
+            },
+        );
+
+        self.command_sender
+            .send(PluginCommand::RegisterOpenHandler {
+                pattern,
+                plugin_name: self.plugin_name.clone(),
+            })
+            .is_ok()
+    }
+
+    /// Fill a buffer with content returned by an open handler (internal)
+    #[plugin_api(skip)]
+    #[qjs(rename = "_setOpenedBufferContent")]
+    pub fn set_opened_buffer_content(&self, buffer_id: u32, content: String) -> bool {
+        self.command_sender
+            .send(PluginCommand::SetOpenedBufferContent {
+                buffer_id: BufferId(buffer_id as usize),
+                content,
+            })
+            .is_ok()
+    }
+
+    /// Report that an open handler failed to provide content (internal)
+    #[plugin_api(skip)]
+    #[qjs(rename = "_openHandlerFailed")]
+    pub fn open_handler_failed(&self, buffer_id: u32, error: String) -> bool {
+        self.command_sender
+            .send(PluginCommand::OpenHandlerFailed {
+                buffer_id: BufferId(buffer_id as usize),
+                error,
+            })
+            .is_ok()
+    }
+
     /// Show a buffer in the current split
     pub fn show_buffer(&self, buffer_id: u32) -> bool {
         self.command_sender
@@ -1016,6 +1199,74 @@ impl JsEditorApi {
             .map_err(|e| rquickjs::Error::new_from_js_message("serialize", "", &e.to_string()))
     }
 
+    // === Storage ===
+
+    /// Get a value stored by this plugin with `setStorage`, or null if unset
+    pub fn get_storage<'js>(
+        &self,
+        ctx: rquickjs::Ctx<'js>,
+        key: String,
+    ) -> rquickjs::Result<Value<'js>> {
+        let value = self
+            .state_snapshot
+            .read()
+            .ok()
+            .and_then(|s| {
+                s.plugin_storage
+                    .get(&self.plugin_name)
+                    .and_then(|storage| storage.get(&key).cloned())
+            })
+            .unwrap_or(serde_json::Value::Null);
+
+        rquickjs_serde::to_value(ctx, &value)
+            .map_err(|e| rquickjs::Error::new_from_js_message("serialize", "", &e.to_string()))
+    }
+
+    /// Store a JSON-serializable value for this plugin, kept across sessions
+    ///
+    /// Storing null or undefined removes the key. Returns false if the
+    /// plugin's storage would grow past its size limit (256 KiB)
+    pub fn set_storage<'js>(
+        &self,
+        ctx: rquickjs::Ctx<'js>,
+        key: String,
+        value: Value<'js>,
+    ) -> bool {
+        let value = js_to_json(&ctx, value);
+        {
+            // Update the snapshot right away so a following getStorage sees it
+            let Ok(mut snapshot) = self.state_snapshot.write() else {
+                return false;
+            };
+            let storage = snapshot
+                .plugin_storage
+                .entry(self.plugin_name.clone())
+                .or_default();
+            let mut updated = storage.clone();
+            if value.is_null() {
+                updated.remove(&key);
+            } else {
+                updated.insert(key.clone(), value.clone());
+            }
+            if !plugin_storage_fits(&updated) {
+                tracing::warn!(
+                    "setStorage: plugin '{}' storage limit reached, '{}' not stored",
+                    self.plugin_name,
+                    key
+                );
+                return false;
+            }
+            *storage = updated;
+        }
+        self.command_sender
+            .send(PluginCommand::SetPluginStorage {
+                plugin_name: self.plugin_name.clone(),
+                key,
+                value,
+            })
+            .is_ok()
+    }
+
     // === Config ===
 
     /// Get current config as JS object
@@ -1320,6 +1571,44 @@ impl JsEditorApi {
             .is_ok()
     }
 
+    // === Decorations ===
+
+    /// Highlight a byte range with a decoration style
+    ///
+    /// `style` is a predefined kind ("error", "warning", "info", "hint",
+    /// "highlight", "emphasis") or a theme color key such as
+    /// "diagnostic.error_bg". Decorations are grouped per plugin so
+    /// `clearHighlights` removes only this plugin's group
+    pub fn highlight_range(
+        &self,
+        buffer_id: u32,
+        start: u32,
+        end: u32,
+        style: String,
+        group: rquickjs::function::Opt<String>,
+    ) -> bool {
+        self.command_sender
+            .send(PluginCommand::HighlightRange {
+                buffer_id: BufferId(buffer_id as usize),
+                plugin_name: self.plugin_name.clone(),
+                group: group.0.unwrap_or_else(|| "default".to_string()),
+                range: (start as usize)..(end as usize),
+                style,
+            })
+            .is_ok()
+    }
+
+    /// Remove this plugin's decorations in a group (default group if omitted)
+    pub fn clear_highlights(&self, buffer_id: u32, group: rquickjs::function::Opt<String>) -> bool {
+        self.command_sender
+            .send(PluginCommand::ClearHighlights {
+                buffer_id: BufferId(buffer_id as usize),
+                plugin_name: self.plugin_name.clone(),
+                group: group.0.unwrap_or_else(|| "default".to_string()),
+            })
+            .is_ok()
+    }
+
     /// Clear all overlays from a buffer
     pub fn clear_all_overlays(&self, buffer_id: u32) -> bool {
         self.command_sender
@@ -1499,6 +1788,15 @@ impl JsEditorApi {
             .is_ok()
     }
 
+    /// Replace the quickfix list with the given locations
+    ///
+    /// The user steps through them with the next/previous quickfix item actions
+    pub fn set_quickfix_list(&self, entries: Vec<QuickfixEntry>) -> bool {
+        self.command_sender
+            .send(PluginCommand::SetQuickfixList { entries })
+            .is_ok()
+    }
+
     // === Virtual Text ===
 
     /// Add virtual text (inline text that doesn't exist in the buffer)
@@ -1726,6 +2024,64 @@ impl JsEditorApi {
             .and_then(|s| s.editor_mode.clone())
     }
 
+    // === Keybindings ===
+
+    /// List the single-key bindings of a context ("normal" when omitted)
+    #[plugin_api(
+        async_promise,
+        js_name = "getKeybindings",
+        ts_return = "KeybindingInfo[]"
+    )]
+    #[qjs(rename = "_getKeybindingsStart")]
+    pub fn get_keybindings_start(
+        &self,
+        _ctx: rquickjs::Ctx<'_>,
+        context: rquickjs::function::Opt<String>,
+    ) -> u64 {
+        let id = {
+            let mut id_ref = self.next_request_id.borrow_mut();
+            let id = *id_ref;
+            *id_ref += 1;
+            // Record context for this callback
+            self.callback_contexts
+                .borrow_mut()
+                .insert(id, self.plugin_name.clone());
+            id
+        };
+        let _ = self.command_sender.send(PluginCommand::GetKeybindings {
+            context: context.0.unwrap_or_else(|| "normal".to_string()),
+            request_id: id,
+        });
+        id
+    }
+
+    /// Bind a key (e.g. "C-k", "M-x", "F7") to an action or plugin command in a
+    /// context ("normal" when omitted), replacing any existing binding for the key
+    pub fn set_keybinding(
+        &self,
+        key: String,
+        command: String,
+        context: rquickjs::function::Opt<String>,
+    ) -> bool {
+        self.command_sender
+            .send(PluginCommand::SetKeybinding {
+                key,
+                command,
+                context: context.0.unwrap_or_else(|| "normal".to_string()),
+            })
+            .is_ok()
+    }
+
+    /// Unbind a key in a context ("normal" when omitted), including default bindings
+    pub fn remove_keybinding(&self, key: String, context: rquickjs::function::Opt<String>) -> bool {
+        self.command_sender
+            .send(PluginCommand::RemoveKeybinding {
+                key,
+                context: context.0.unwrap_or_else(|| "normal".to_string()),
+            })
+            .is_ok()
+    }
+
     // === Splits ===
 
     /// Close a split
@@ -2317,6 +2673,39 @@ impl JsEditorApi {
         Ok(id)
     }
 
+    /// Send an LSP request to the server for the active buffer's language
+    /// (async, returns request_id)
+    ///
+    /// Rejects if no server is available or it does not answer in time
+    #[plugin_api(async_promise, js_name = "lsp", ts_return = "unknown")]
+    #[qjs(rename = "_lspStart")]
+    pub fn lsp_start<'js>(
+        &self,
+        ctx: rquickjs::Ctx<'js>,
+        method: String,
+        params: rquickjs::function::Opt<Value<'js>>,
+    ) -> rquickjs::Result<u64> {
+        let id = {
+            let mut id_ref = self.next_request_id.borrow_mut();
+            let id = *id_ref;
+            *id_ref += 1;
+            self.callback_contexts
+                .borrow_mut()
+                .insert(id, self.plugin_name.clone());
+            id
+        };
+        let params = params
+            .0
+            .map(|val| js_to_json(&ctx, val))
+            .filter(|json| !json.is_null());
+        let _ = self.command_sender.send(PluginCommand::LspRequest {
+            request_id: id,
+            method,
+            params,
+        });
+        Ok(id)
+    }
+
     /// Spawn a background process (async, returns request_id which is also process_id)
     #[plugin_api(
         async_thenable,
@@ -2390,6 +2779,10 @@ pub struct QuickJsBackend {
     event_handlers: Rc<RefCell<HashMap<String, Vec<PluginHandler>>>>,
     /// Registered actions: action_name -> PluginHandler
     registered_actions: Rc<RefCell<HashMap<String, PluginHandler>>>,
+    /// Registered text objects: text object name -> matcher PluginHandler
+    registered_text_objects: Rc<RefCell<HashMap<String, PluginHandler>>>,
+    /// Registered open handlers: path pattern -> content provider PluginHandler
+    registered_open_handlers: Rc<RefCell<HashMap<String, PluginHandler>>>,
     /// Editor state snapshot (read-only access)
     state_snapshot: Arc<RwLock<EditorStateSnapshot>>,
     /// Command sender for write operations
@@ -2469,6 +2862,8 @@ impl QuickJsBackend {
         let plugin_contexts = Rc::new(RefCell::new(HashMap::new()));
         let event_handlers = Rc::new(RefCell::new(HashMap::new()));
         let registered_actions = Rc::new(RefCell::new(HashMap::new()));
+        let registered_text_objects = Rc::new(RefCell::new(HashMap::new()));
+        let registered_open_handlers = Rc::new(RefCell::new(HashMap::new()));
         let next_request_id = Rc::new(RefCell::new(1u64));
         let callback_contexts = Rc::new(RefCell::new(HashMap::new()));
 
@@ -2478,6 +2873,8 @@ impl QuickJsBackend {
             plugin_contexts,
             event_handlers,
             registered_actions,
+            registered_text_objects,
+            registered_open_handlers,
             state_snapshot,
             command_sender,
             pending_responses,
@@ -2513,6 +2910,8 @@ impl QuickJsBackend {
                 state_snapshot: Arc::clone(&state_snapshot),
                 command_sender: command_sender.clone(),
                 registered_actions: Rc::clone(&registered_actions),
+                registered_text_objects: Rc::clone(&self.registered_text_objects),
+                registered_open_handlers: Rc::clone(&self.registered_open_handlers),
                 event_handlers: Rc::clone(&event_handlers),
                 next_request_id: Rc::clone(&next_request_id),
                 callback_contexts: Rc::clone(&self.callback_contexts),
@@ -2636,11 +3035,13 @@ impl QuickJsBackend {
                 editor.createVirtualBufferInSplit = _wrapAsync("_createVirtualBufferInSplitStart", "createVirtualBufferInSplit");
                 editor.createVirtualBufferInExistingSplit = _wrapAsync("_createVirtualBufferInExistingSplitStart", "createVirtualBufferInExistingSplit");
                 editor.sendLspRequest = _wrapAsync("_sendLspRequestStart", "sendLspRequest");
+                editor.lsp = _wrapAsync("_lspStart", "lsp");
                 editor.spawnBackgroundProcess = _wrapAsyncThenable("_spawnBackgroundProcessStart", "spawnBackgroundProcess");
                 editor.spawnProcessWait = _wrapAsync("_spawnProcessWaitStart", "spawnProcessWait");
                 editor.getBufferText = _wrapAsync("_getBufferTextStart", "getBufferText");
                 editor.createCompositeBuffer = _wrapAsync("_createCompositeBufferStart", "createCompositeBuffer");
                 editor.getHighlights = _wrapAsync("_getHighlightsStart", "getHighlights");
+                editor.getKeybindings = _wrapAsync("_getKeybindingsStart", "getKeybindings");
 
                 // Wrapper for deleteTheme - wraps sync function in Promise
                 editor.deleteTheme = function(name) {
@@ -2918,6 +3319,130 @@ impl QuickJsBackend {
         Ok(())
     }
 
+    /// Run a registered text object matcher for the given cursor context.
+    /// The matched range is sent back to the editor as `ApplyTextObject`; async
+    /// matchers complete when the event loop is polled.
+    pub fn start_text_object(&mut self, name: &str, query: &TextObjectQuery) -> Result<()> {
+        let Some(handler) = self.registered_text_objects.borrow().get(name).cloned() else {
+            self.send_status(format!("Unknown text object: {}", name));
+            return Ok(());
+        };
+
+        let plugin_contexts = self.plugin_contexts.borrow();
+        let Some(context) = plugin_contexts.get(&handler.plugin_name) else {
+            return Ok(());
+        };
+
+        self.services.set_js_execution_state(format!(
+            "text object '{}' (fn: {})",
+            name, handler.handler_name
+        ));
+
+        // Double-encode the query so it becomes a JavaScript string literal
+        let js_string_literal = serde_json::to_string(&serde_json::to_string(query)?)?;
+        let code = format!(
+            r#"
+            (function() {{
+                const query = JSON.parse({query});
+                const matcher = globalThis["{fn}"];
+                if (typeof matcher !== 'function') {{
+                    console.error('Text object matcher {fn} is not defined as a global function');
+                    return;
+                }}
+                Promise.resolve(matcher(query)).then(function(range) {{
+                    if (range) {{
+                        editor._applyTextObject(query.bufferId, range.start, range.end, query.operation);
+                    }}
+                }}).catch(function(e) {{
+                    console.error('Text object matcher {fn} error:', e);
+                    throw e;
+                }});
+            }})();
+            "#,
+            query = js_string_literal,
+            fn = handler.handler_name
+        );
+
+        context.with(|ctx| {
+            if let Err(e) = ctx.eval::<(), _>(code.as_bytes()) {
+                log_js_error(&ctx, e, &format!("text object {}", name));
+            }
+            run_pending_jobs_checked(&ctx, &format!("text object {}", name));
+        });
+
+        self.services.clear_js_execution_state();
+        Ok(())
+    }
+
+    /// Run the open handler registered for `pattern` on `path`.
+    /// The content is sent back to the editor as `SetOpenedBufferContent`, or
+    /// `OpenHandlerFailed` when there is none; async handlers complete when the
+    /// event loop is polled.
+    pub fn start_open_handler(
+        &mut self,
+        pattern: &str,
+        path: &str,
+        buffer_id: BufferId,
+    ) -> Result<()> {
+        let Some(handler) = self.registered_open_handlers.borrow().get(pattern).cloned() else {
+            self.send_open_handler_failed(buffer_id, format!("unknown open handler {}", pattern));
+            return Ok(());
+        };
+
+        let plugin_contexts = self.plugin_contexts.borrow();
+        let Some(context) = plugin_contexts.get(&handler.plugin_name) else {
+            self.send_open_handler_failed(
+                buffer_id,
+                format!("plugin '{}' is not loaded", handler.plugin_name),
+            );
+            return Ok(());
+        };
+
+        self.services.set_js_execution_state(format!(
+            "open handler '{}' (fn: {})",
+            pattern, handler.handler_name
+        ));
+
+        let code = format!(
+            r#"
+            (function() {{
+                const path = {path};
+                const handler = globalThis["{fn}"];
+                if (typeof handler !== 'function') {{
+                    console.error('Open handler {fn} is not defined as a global function');
+                    editor._openHandlerFailed({buffer_id}, 'handler {fn} is not defined');
+                    return;
+                }}
+                new Promise(function(resolve) {{
+                    resolve(handler(path));
+                }}).then(function(content) {{
+                    if (typeof content === 'string') {{
+                        editor._setOpenedBufferContent({buffer_id}, content);
+                    }} else {{
+                        editor._openHandlerFailed({buffer_id}, 'handler {fn} did not return a string');
+                    }}
+                }}).catch(function(e) {{
+                    console.error('Open handler {fn} error:', e);
+                    editor._openHandlerFailed({buffer_id}, String(e));
+                }});
+            }})();
+            "#,
+            path = serde_json::to_string(path)?,
+            fn = handler.handler_name,
+            buffer_id = buffer_id.0
+        );
+
+        context.with(|ctx| {
+            if let Err(e) = ctx.eval::<(), _>(code.as_bytes()) {
+                log_js_error(&ctx, e, &format!("open handler {}", pattern));
+            }
+            run_pending_jobs_checked(&ctx, &format!("open handler {}", pattern));
+        });
+
+        self.services.clear_js_execution_state();
+        Ok(())
+    }
+
     /// Execute a registered action by name
     pub async fn execute_action(&mut self, action_name: &str) -> Result<()> {
         // First check if there's a registered command mapping
@@ -3015,6 +3540,24 @@ impl QuickJsBackend {
         had_work
     }
 
+    /// Tell the editor an open handler produced no content for `buffer_id`
+    fn send_open_handler_failed(&self, buffer_id: BufferId, error: String) {
+        let _ = self
+            .command_sender
+            .send(PluginCommand::OpenHandlerFailed { buffer_id, error });
+    }
+
+    /// Drop the open handlers registered by `plugin_name` and tell the editor
+    /// the plugin is gone so it can drop its own copies of the plugin's state
+    pub fn unregister_plugin(&self, plugin_name: &str) {
+        self.registered_open_handlers
+            .borrow_mut()
+            .retain(|_, handler| handler.plugin_name != plugin_name);
+        let _ = self.command_sender.send(PluginCommand::PluginUnloaded {
+            plugin_name: plugin_name.to_string(),
+        });
+    }
+
     /// Send a status message to the editor
     pub fn send_status(&self, message: String) {
         let _ = self
@@ -12,8 +12,9 @@
 use crate::backend::quickjs_backend::{PendingResponses, TsPluginInfo};
 use crate::backend::QuickJsBackend;
 use anyhow::{anyhow, Result};
-use fresh_core::api::{EditorStateSnapshot, PluginCommand};
+use fresh_core::api::{EditorStateSnapshot, PluginCommand, TextObjectQuery};
 use fresh_core::hooks::HookArgs;
+use fresh_core::BufferId;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
@@ -82,6 +83,19 @@ pub enum PluginRequest {
     /// Run a hook (fire-and-forget, no response needed)
     RunHook { hook_name: String, args: HookArgs },
 
+    /// Run a text object matcher (fire-and-forget, the range comes back as a PluginCommand)
+    MatchTextObject {
+        name: String,
+        query: TextObjectQuery,
+    },
+
+    /// Run an open handler (fire-and-forget, the content comes back as a PluginCommand)
+    OpenWithHandler {
+        pattern: String,
+        path: String,
+        buffer_id: BufferId,
+    },
+
     /// Check if any handlers are registered for a hook
     HasHookHandlers {
         hook_name: String,
@@ -483,6 +497,27 @@ impl PluginThreadHandle {
         }
     }
 
+    /// Run a registered text object matcher (non-blocking, fire-and-forget)
+    pub fn match_text_object(&self, name: &str, query: TextObjectQuery) {
+        if let Some(sender) = self.request_sender.as_ref() {
+            let _ = sender.send(PluginRequest::MatchTextObject {
+                name: name.to_string(),
+                query,
+            });
+        }
+    }
+
+    /// Run a registered open handler for a buffer (non-blocking, fire-and-forget)
+    pub fn open_with_handler(&self, pattern: &str, path: &str, buffer_id: BufferId) {
+        if let Some(sender) = self.request_sender.as_ref() {
+            let _ = sender.send(PluginRequest::OpenWithHandler {
+                pattern: pattern.to_string(),
+                path: path.to_string(),
+                buffer_id,
+            });
+        }
+    }
+
     /// Check if any handlers are registered for a hook (blocking)
     pub fn has_hook_handlers(&self, hook_name: &str) -> bool {
         let (tx, rx) = oneshot::channel();
@@ -893,6 +928,29 @@ async fn handle_request(
             }
         }
 
+        PluginRequest::MatchTextObject { name, query } => {
+            if let Err(e) = runtime.borrow_mut().start_text_object(&name, &query) {
+                let error_msg = format!("Plugin error in text object '{}': {}", name, e);
+                tracing::error!("{}", error_msg);
+                runtime.borrow_mut().send_status(error_msg);
+            }
+        }
+
+        PluginRequest::OpenWithHandler {
+            pattern,
+            path,
+            buffer_id,
+        } => {
+            if let Err(e) = runtime
+                .borrow_mut()
+                .start_open_handler(&pattern, &path, buffer_id)
+            {
+                let error_msg = format!("Plugin error in open handler '{}': {}", pattern, e);
+                tracing::error!("{}", error_msg);
+                runtime.borrow_mut().send_status(error_msg);
+            }
+        }
+
         PluginRequest::HasHookHandlers {
             hook_name,
             response,
@@ -1189,6 +1247,9 @@ fn unload_plugin_internal(
             .services
             .unregister_commands_by_prefix(&prefix);
 
+        // Drop its open handlers here and in the editor
+        runtime.borrow().unregister_plugin(name);
+
         Ok(())
     } else {
         Err(anyhow!("Plugin '{}' not found", name))
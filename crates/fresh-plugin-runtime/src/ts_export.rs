@@ -15,13 +15,14 @@ use oxc_span::SourceType;
 use ts_rs::TS;
 
 use fresh_core::api::{
-    ActionPopupAction, ActionPopupOptions, ActionSpec, BackgroundProcessResult, BufferInfo,
-    BufferSavedDiff, CompositeHunk, CompositeLayoutConfig, CompositePaneStyle,
+    ActionPopupAction, ActionPopupOptions, ActionSpec, BackgroundProcessResult, BufferEdit,
+    BufferInfo, BufferSavedDiff, CompositeHunk, CompositeLayoutConfig, CompositePaneStyle,
     CompositeSourceConfig, CreateCompositeBufferOptions, CreateVirtualBufferInExistingSplitOptions,
-    CreateVirtualBufferInSplitOptions, CreateVirtualBufferOptions, CursorInfo, DirEntry,
-    JsDiagnostic, JsPosition, JsRange, JsTextPropertyEntry, LayoutHints, SpawnResult,
-    TextPropertiesAtCursor, TsHighlightSpan, ViewTokenStyle, ViewTokenWire, ViewTokenWireKind,
-    ViewportInfo, VirtualBufferResult,
+    CreateVirtualBufferInSplitOptions, CreateVirtualBufferOptions, CursorDetails, CursorInfo,
+    DirEntry, JsDiagnostic, JsPosition, JsRange, JsTextPropertyEntry, KeybindingInfo, LayoutHints,
+    QuickfixEntry, SpawnResult, StatusBarAlignment, StatusBarItemOptions, TextObjectOperation,
+    TextObjectQuery, TextObjectRange, TextPropertiesAtCursor, TsHighlightSpan, ViewTokenStyle,
+    ViewTokenWire, ViewTokenWireKind, ViewportInfo, VirtualBufferResult,
 };
 use fresh_core::command::Suggestion;
 use fresh_core::file_explorer::FileExplorerDecoration;
@@ -36,9 +37,18 @@ fn get_type_decl(type_name: &str) -> Option<String> {
     match type_name {
         // Core types
         "BufferInfo" => Some(BufferInfo::decl()),
+        "BufferEdit" => Some(BufferEdit::decl()),
         "CursorInfo" => Some(CursorInfo::decl()),
+        "CursorDetails" => Some(CursorDetails::decl()),
         "ViewportInfo" => Some(ViewportInfo::decl()),
         "ActionSpec" => Some(ActionSpec::decl()),
+        "QuickfixEntry" => Some(QuickfixEntry::decl()),
+        "StatusBarAlignment" => Some(StatusBarAlignment::decl()),
+        "StatusBarItemOptions" => Some(StatusBarItemOptions::decl()),
+        "TextObjectOperation" => Some(TextObjectOperation::decl()),
+        "TextObjectQuery" => Some(TextObjectQuery::decl()),
+        "TextObjectRange" => Some(TextObjectRange::decl()),
+        "KeybindingInfo" => Some(KeybindingInfo::decl()),
         "BufferSavedDiff" => Some(BufferSavedDiff::decl()),
         "LayoutHints" => Some(LayoutHints::decl()),
 
@@ -109,6 +119,7 @@ const DEPENDENCY_TYPES: &[&str] = &[
     "PromptSuggestion",               // Used by plugins for prompt suggestions
     "DirEntry",                       // Used by plugins for directory entries
     "BufferInfo",                     // Used by listBuffers, getBufferInfo
+    "BufferEdit",                     // Used by applyEdits
     "JsDiagnostic",                   // Used by getAllDiagnostics
     "JsRange",                        // Used by JsDiagnostic
     "JsPosition",                     // Used by JsRange
@@ -116,6 +127,13 @@ const DEPENDENCY_TYPES: &[&str] = &[
     "TsActionPopupAction",            // Used by ActionPopupOptions.actions
     "ActionPopupOptions",             // Used by showActionPopup
     "FileExplorerDecoration",         // Used by setFileExplorerDecorations
+    "QuickfixEntry",                  // Used by setQuickfixList
+    "StatusBarAlignment",             // Used by StatusBarItemOptions.alignment
+    "StatusBarItemOptions",           // Used by setStatusBarItem
+    "TextObjectOperation",            // Used by TextObjectQuery.operation
+    "TextObjectQuery",                // Passed to registerTextObject matchers
+    "TextObjectRange",                // Returned by registerTextObject matchers
+    "KeybindingInfo",                 // Returned by getKeybindings
 ];
 
 /// Collect TypeScript type declarations based on referenced types from proc macro